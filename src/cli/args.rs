@@ -1,7 +1,7 @@
 //! CLI argument structures
 
 use crate::impl_mcp_tool;
-use clap::Args;
+use clap::{Args, Subcommand};
 
 /// Arguments for create_project command
 #[derive(Args, Debug)]
@@ -74,13 +74,15 @@ pub struct CreateProjectArgs {
 impl_mcp_tool! {
     name = "create_project",
     description = "Create new project structure with LLM-provided content. Creates ~/.foundry/PROJECT_NAME/ with vision.md, tech-stack.md, and summary.md",
+    output = crate::types::responses::CreateProjectResponse,
     struct CreateProjectArgs {
         project_name: String {
             description = "Descriptive project name using kebab-case (e.g., 'my-awesome-app')"
         },
         vision: String {
             description = "**CONTEXT FOR FUTURE IMPLEMENTATION**: High-level product vision (2-4 paragraphs, 200+ chars) that will serve as the COMPLETE implementation context for future LLMs who have NO prior knowledge of this project. Must include comprehensive problem definition, target users, unique value proposition, and key roadmap priorities. This document will be loaded as the PRIMARY reference for all future development work. Apply 'Cold Start Test': Could a skilled developer understand the project purpose using only this document? Use markdown with ## headers, bullet points, and clear structure. Include specific examples and architectural context. Goes into vision.md",
-            min_length = 200
+            min_length = 200,
+            default = ""
         },
         tech_stack: String {
             description = "**CONTEXT FOR FUTURE IMPLEMENTATION**: Comprehensive technology decisions (150+ chars) that will serve as the COMPLETE technical architecture guide for future LLMs with NO prior project knowledge. Must include languages, frameworks, databases, deployment platforms, and detailed rationale for each choice. This document will be the PRIMARY reference for all technical implementation decisions. Include integration patterns, dependencies, constraints, team standards, and architectural context. Future implementers must understand the complete technical landscape from this document alone. Use markdown with ## headers for categories, bullet points for technologies, and comprehensive explanations. Goes into tech-stack.md",
@@ -118,7 +120,7 @@ pub struct AnalyzeProjectArgs {
     /// - Write in present tense, referencing actual implementation
     ///
     /// Goes into vision.md
-    #[arg(long, required = true)]
+    #[arg(long, default_value = "")]
     pub vision: String,
 
     /// Technology stack and architecture decisions (150+ characters) based on your codebase exploration
@@ -137,7 +139,7 @@ pub struct AnalyzeProjectArgs {
     /// - Reference specific files or configurations discovered
     ///
     /// Goes into tech-stack.md
-    #[arg(long, required = true)]
+    #[arg(long, default_value = "")]
     pub tech_stack: String,
 
     /// Concise summary (100+ characters) combining vision and tech stack from your analysis
@@ -145,14 +147,22 @@ pub struct AnalyzeProjectArgs {
     /// Should capture key insights from your codebase exploration for quick context loading
     /// Use this to understand the project essence before diving into implementation
     /// Goes into summary.md
-    #[arg(long, required = true)]
+    #[arg(long, default_value = "")]
     pub summary: String,
+
+    /// Local repository to scan for languages, manifests, CI and container files
+    ///
+    /// Returns a tech-stack skeleton under `repo_scan`. Without vision, tech_stack
+    /// and summary only the scan runs; draft them from it and call again.
+    #[arg(long)]
+    pub repo_path: Option<String>,
 }
 
 // Generate MCP tool implementation for AnalyzeProjectArgs
 impl_mcp_tool! {
     name = "analyze_project",
-    description = "Create project structure by analyzing existing codebase. You analyze codebase and provide vision, tech-stack, and summary content as arguments.",
+    description = "Create project structure by analyzing existing codebase. You analyze codebase and provide vision, tech-stack, and summary content as arguments. Pass repo_path to have Foundry scan the repository and return a tech-stack skeleton to draft from.",
+    output = crate::types::responses::AnalyzeProjectResponse,
     struct AnalyzeProjectArgs {
         project_name: String {
             description = "Descriptive project name using kebab-case (e.g., 'my-analyzed-project')"
@@ -163,11 +173,16 @@ impl_mcp_tool! {
         },
         tech_stack: String {
             description = "Your detected technology stack (150+ chars) based on codebase analysis. Examine package files, configs, and code patterns. Structure with ## headers for categories, list technologies with versions, include rationale from observed patterns. Reference specific files discovered. Goes into tech-stack.md",
-            min_length = 150
+            min_length = 150,
+            default = ""
         },
         summary: String {
             description = "Your created concise summary (100+ chars) of analyzed project combining vision and tech-stack insights for quick context loading. Goes into summary.md",
-            min_length = 100
+            min_length = 100,
+            default = ""
+        },
+        repo_path: Option<String> {
+            description = "Optional local repository path to scan. Foundry detects languages by extension, reads Cargo.toml/package.json/pyproject.toml/requirements.txt/go.mod/Gemfile, and finds CI and Docker configs, returning repo_scan with a tech_stack_skeleton. Call with repo_path alone to get the scan first, then again with vision, tech_stack and summary drafted from it"
         }
     }
 }
@@ -247,12 +262,25 @@ pub struct CreateSpecArgs {
     /// Goes into task-list.md
     #[arg(long, required = true)]
     pub tasks: String,
+
+    /// Optional: spec template to render spec.md from
+    ///
+    /// Templates are Markdown files in ~/.foundry/.templates/specs/<name>.md.
+    /// They can use {{feature_name}}, {{feature_title}}, {{date}},
+    /// {{project_name}}, {{project_summary}} and {{spec}} (the spec content above)
+    #[arg(long)]
+    pub template: Option<String>,
+
+    /// Create the spec even if an existing spec has a similar feature name or overview
+    #[arg(long)]
+    pub allow_duplicate: bool,
 }
 
 // Generate MCP tool implementation for CreateSpecArgs
 impl_mcp_tool! {
     name = "create_spec",
     description = "Create timestamped specification for a feature. Creates YYYYMMDD_HHMMSS_FEATURE_NAME directory with spec.md, task-list.md, and notes.md. You provide complete specification content as arguments.",
+    output = crate::types::responses::CreateSpecResponse,
     struct CreateSpecArgs {
         project_name: String {
             description = "Name of the existing project to create spec for"
@@ -271,6 +299,13 @@ impl_mcp_tool! {
         tasks: String {
             description = "**CONTEXT FOR FUTURE IMPLEMENTATION**: Markdown checklist (100+ chars) of implementation steps that will guide future LLMs through COMPLETE feature implementation with NO prior knowledge. Must include comprehensive, actionable phases covering setup, development, testing, and deployment. This task list will be the PRIMARY implementation roadmap for future development sessions. Break feature into specific, measurable tasks that provide complete implementation guidance. Use ## headers for phases, - [ ] for uncompleted tasks, - [x] for completed. Include dependencies, prerequisites, and validation steps. Goes into task-list.md",
             min_length = 100
+        },
+        template: Option<String> {
+            description = "Optional: name of a user-defined spec template in ~/.foundry/.templates/specs/<name>.md. spec.md is rendered from it with feature_name, feature_title, date, project_name, project_summary and your 'spec' content as {{spec}}; the rendered result is what gets validated and written"
+        },
+        allow_duplicate: bool {
+            description = "Optional: create the spec even if an existing spec has a similar feature name or Overview section. Without it, a likely duplicate creates nothing and the call fails with ALREADY_EXISTS, listing the similar specs in error.details.possible_duplicates",
+            default = false
         }
     }
 }
@@ -295,10 +330,33 @@ pub struct LoadSpecArgs {
     /// If omitted, returns list of all available specs for the project
     /// Use 'mcp_foundry_list_specs PROJECT_NAME' for lightweight spec discovery
     pub spec_name: Option<String>,
+
+    /// Optional: trim the response to about this many tokens (minimum 100)
+    ///
+    /// Keeps the project summary first, then sections with open tasks, recent
+    /// notes, spec sections, and finally fully completed task sections
+    #[arg(long)]
+    pub max_tokens: Option<usize>,
+
+    /// Optional: return only one part of the spec (summary, spec, tasks or notes)
+    ///
+    /// Large specs can be fetched one part at a time; 'parts' in the response
+    /// lists the size of each
+    #[arg(long)]
+    pub part: Option<String>,
+
+    /// Optional: return only these spec files (spec, tasks, notes), without
+    /// the project summary
+    ///
+    /// E.g. `--files tasks` to update task status without loading the spec and notes
+    #[arg(long, value_delimiter = ',')]
+    pub files: Option<Vec<String>>,
 }
 
 // Manual MCP tool implementation for LoadSpecArgs (has optional field)
 impl crate::mcp::traits::McpToolDefinition for LoadSpecArgs {
+    const NAME: &'static str = "load_spec";
+
     fn tool_definition() -> rust_mcp_sdk::schema::Tool {
         let mut properties = std::collections::HashMap::new();
 
@@ -315,8 +373,34 @@ impl crate::mcp::traits::McpToolDefinition for LoadSpecArgs {
         spec_name_prop.insert("description".to_string(), serde_json::json!("Optional: specific spec to load. Supports exact spec names (YYYYMMDD_HHMMSS_feature_name format) or fuzzy matching with natural language queries like 'auth' or 'user management'. If omitted, lists available specs"));
         properties.insert("spec_name".to_string(), spec_name_prop);
 
+        let mut max_tokens_prop = serde_json::Map::new();
+        max_tokens_prop.insert("type".to_string(), serde_json::json!("integer"));
+        max_tokens_prop.insert("description".to_string(), serde_json::json!("Optional: trim the loaded content to about this many tokens (minimum 100). Keeps the project summary first, then task sections with open tasks, the most recent notes, spec sections, and finally fully completed task sections; the 'budget' field reports what was left out"));
+        properties.insert("max_tokens".to_string(), max_tokens_prop);
+
+        let mut part_prop = serde_json::Map::new();
+        part_prop.insert("type".to_string(), serde_json::json!("string"));
+        part_prop.insert(
+            "enum".to_string(),
+            serde_json::json!(crate::core::ops::load_spec::PARTS),
+        );
+        part_prop.insert("description".to_string(), serde_json::json!("Optional: return only this part of the spec: 'summary' (project summary), 'spec' (spec.md), 'tasks' (task-list.md) or 'notes' (notes.md). Requires spec_name. Use it to fetch large specs in pieces; 'parts' in every spec response lists the estimated tokens of each part"));
+        properties.insert("part".to_string(), part_prop);
+
+        let mut files_prop = serde_json::Map::new();
+        files_prop.insert("type".to_string(), serde_json::json!("array"));
+        files_prop.insert(
+            "items".to_string(),
+            serde_json::json!({
+                "type": "string",
+                "enum": crate::core::ops::load_spec::FILES,
+            }),
+        );
+        files_prop.insert("description".to_string(), serde_json::json!("Optional: return only these spec files, in any combination: 'spec' (spec.md), 'tasks' (task-list.md), 'notes' (notes.md). Requires spec_name; the project summary and the other files are not loaded. E.g. [\"tasks\"] to update task status without pulling the spec and notes into context"));
+        properties.insert("files".to_string(), files_prop);
+
         rust_mcp_sdk::schema::Tool {
-            name: "load_spec".to_string(),
+            name: Self::NAME.to_string(),
             description: Some("Load specific specification content with project context. Supports fuzzy matching on feature names (e.g., 'auth' matches 'user_authentication'). You can use this to review full specification details, task lists, and implementation notes. If spec_name is omitted, lists available specs.".to_string()),
             title: None,
             input_schema: rust_mcp_sdk::schema::ToolInputSchema::new(
@@ -325,7 +409,9 @@ impl crate::mcp::traits::McpToolDefinition for LoadSpecArgs {
             ),
             annotations: None,
             meta: None,
-            output_schema: None,
+            output_schema: Some(crate::mcp::traits::output_schema::<
+                crate::types::responses::LoadSpecResponse,
+            >()),
         }
     }
 
@@ -336,6 +422,14 @@ impl crate::mcp::traits::McpToolDefinition for LoadSpecArgs {
                 .ok_or_else(|| anyhow::anyhow!("Missing project_name parameter"))?
                 .to_string(),
             spec_name: params["spec_name"].as_str().map(|s| s.to_string()),
+            max_tokens: params["max_tokens"].as_u64().map(|n| n as usize),
+            part: params["part"].as_str().map(|s| s.to_string()),
+            files: params["files"].as_array().map(|files| {
+                files
+                    .iter()
+                    .filter_map(|file| file.as_str().map(|s| s.to_string()))
+                    .collect()
+            }),
         })
     }
 }
@@ -349,26 +443,92 @@ pub struct LoadProjectArgs {
     /// Essential for resuming work on existing projects
     /// Use 'mcp_foundry_list_projects' to see available project names
     pub project_name: String,
+
+    /// Optional: trim the response to about this many tokens (minimum 100)
+    ///
+    /// Keeps the project summary first, then vision and tech stack sections
+    #[arg(long)]
+    pub max_tokens: Option<usize>,
+
+    /// Optional: only load these parts (summary, vision, tech_stack, decisions,
+    /// active_specs, all_specs)
+    ///
+    /// Defaults to every document plus the most recently modified unfinished specs
+    #[arg(long, value_delimiter = ',')]
+    pub include: Option<Vec<String>>,
+
+    /// Optional: return at most this many active specs (default 5)
+    #[arg(long)]
+    pub active_limit: Option<usize>,
 }
 
 // Generate MCP tool implementation for LoadProjectArgs
 impl_mcp_tool! {
     name = "load_project",
     description = "Load complete project context (vision, tech-stack, summary) for LLM sessions. Essential for resuming work on existing projects. You can use this to get full project context before creating specifications or continuing development work.",
+    output = crate::types::responses::LoadProjectResponse,
     struct LoadProjectArgs {
         project_name: String {
             description = "Name of the existing project to load (must exist in ~/.foundry/)"
+        },
+        max_tokens: Option<usize> {
+            description = "Optional: trim the loaded context to about this many tokens (minimum 100). Keeps the project summary first, then vision sections, then tech stack sections; the 'budget' field reports what was left out"
+        },
+        include: Option<Vec<String>> {
+            description = "Optional: only load these parts, any of summary, vision, tech_stack, decisions, active_specs and all_specs. Defaults to every document plus 'active_specs', the most recently modified specs that still have work left; documents left out are returned empty"
+        },
+        active_limit: Option<usize> {
+            description = "Optional: return at most this many active specs (default 5)"
         }
     }
 }
 
-/// Arguments for list_projects command
-#[derive(Args, Debug)]
-pub struct ListProjectsArgs;
+crate::impl_mcp_tool! {
+    name = "clone_project",
+    description = "Create a new project from an existing one: vision, tech stack, summary, decision log and tags, plus copies of every spec unless 'skip_specs' is set. Copied specs get new ids and keep their status, tags and task state, with depends_on and wiki links pointed at the copies. You can use this to start a sibling project whose specs mostly match an existing one.",
+    output = crate::types::responses::CloneProjectResponse,
+    /// Arguments for clone_project command
+    #[derive(Args, Debug)]
+    pub struct CloneProjectArgs {
+        /// Project to clone
+        pub project_name: String,
+
+        /// Name of the new project in kebab-case
+        pub new_project_name: String,
+
+        /// Optional: copy only the project documents, decisions and tags, not the specs
+        #[arg(long)]
+        pub skip_specs: bool {
+            default = false
+        },
+    }
+}
 
-// Note: This command takes no arguments - it lists all projects in ~/.foundry/
+// Generate MCP tool implementation for ListProjectsArgs
 // Returns: project names, creation dates, spec counts, validation status
 // Use this to discover available projects before loading or creating specs
+impl_mcp_tool! {
+    name = "list_projects",
+    description = "List all available projects with metadata including creation dates, spec counts, and validation status. You can use this to discover available projects before loading or creating specifications.",
+    output = crate::types::responses::ListProjectsResponse,
+    /// Arguments for list_projects command
+    #[derive(Args, Debug)]
+    pub struct ListProjectsArgs {
+        /// Optional: 'next_cursor' from a previous list_projects response, to fetch the next page
+        #[arg(long)]
+        pub cursor: Option<String>,
+
+        /// Optional: return at most this many projects (1-200)
+        ///
+        /// Every project is returned when omitted; 'next_cursor' is set while more remain
+        #[arg(long)]
+        pub limit: Option<usize>,
+
+        /// Optional: only list projects with every one of these tags, e.g. ["quarter:Q3"]
+        #[arg(long = "tag")]
+        pub tags: Option<Vec<String>>,
+    }
+}
 
 /// Arguments for list_specs command
 #[derive(Args, Debug)]
@@ -383,15 +543,56 @@ pub struct ListSpecsArgs {
     ///
     /// **Performance**: ~90% reduction in data transfer compared to load_project
     pub project_name: String,
+
+    /// Optional: only list specs with this status (draft, active, completed, archived)
+    ///
+    /// Archived specs are hidden when omitted; pass "all" to list every spec
+    #[arg(long)]
+    pub status: Option<String>,
+
+    /// Optional: only list specs that depend on this spec
+    ///
+    /// Accepts a spec name or feature name; shows what finishing it unblocks
+    #[arg(long)]
+    pub blocked_by: Option<String>,
+
+    /// Optional: continue from the 'next_cursor' of a previous page
+    #[arg(long)]
+    pub cursor: Option<String>,
+
+    /// Optional: return at most this many specs (1-200)
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Optional: only list specs with every one of these tags
+    #[arg(long = "tag")]
+    pub tags: Option<Vec<String>>,
 }
 
 // Generate MCP tool implementation for ListSpecsArgs
 impl_mcp_tool! {
     name = "list_specs",
-    description = "List available specifications for a project without loading full context. Returns lightweight spec metadata including names, feature names, and creation dates for efficient spec discovery.",
+    description = "List available specifications for a project without loading full context. Returns lightweight spec metadata including names, feature names, creation dates and lifecycle status for efficient spec discovery. Archived specs are hidden unless requested with 'status'.",
+    output = crate::types::responses::ListSpecsResponse,
     struct ListSpecsArgs {
         project_name: String {
             description = "Name of the existing project to list specs for (must exist in ~/.foundry/)"
+        },
+        status: Option<String> {
+            description = "Optional: only list specs with this status: draft, active, completed or archived. Archived specs are hidden when omitted; pass 'all' to list every spec",
+            enum_values = ["draft", "active", "completed", "archived", "all"]
+        },
+        blocked_by: Option<String> {
+            description = "Optional: only list specs that depend on this spec (spec name or feature name), i.e. what finishing it unblocks. Each spec's open dependencies are always reported in 'blocked_by'"
+        },
+        cursor: Option<String> {
+            description = "Optional: 'next_cursor' from a previous list_specs response with the same filters, to fetch the next page"
+        },
+        limit: Option<usize> {
+            description = "Optional: return at most this many specs (1-200). Every matching spec is returned when omitted; 'next_cursor' is set while more remain and 'total_count' counts all pages"
+        },
+        tags: Option<Vec<String>> {
+            description = "Optional: only list specs with every one of these tags, e.g. [\"area:backend\"]. Tags compare case-insensitively and are set with add_tag"
         }
     }
 }
@@ -415,6 +616,8 @@ pub struct GetFoundryHelpArgs {
 
 // Manual MCP tool implementation for GetFoundryHelpArgs (has optional field)
 impl crate::mcp::traits::McpToolDefinition for GetFoundryHelpArgs {
+    const NAME: &'static str = "get_foundry_help";
+
     fn tool_definition() -> rust_mcp_sdk::schema::Tool {
         let mut properties = std::collections::HashMap::new();
 
@@ -424,7 +627,7 @@ impl crate::mcp::traits::McpToolDefinition for GetFoundryHelpArgs {
         properties.insert("topic".to_string(), topic_prop);
 
         rust_mcp_sdk::schema::Tool {
-            name: "get_foundry_help".to_string(),
+            name: Self::NAME.to_string(),
             description: Some("Get comprehensive workflow guidance, content examples, and usage patterns. You can use this to understand foundry workflows and content standards. Essential for effective tool selection and workflow optimization.".to_string()),
             title: None,
             input_schema: rust_mcp_sdk::schema::ToolInputSchema::new(
@@ -433,7 +636,9 @@ impl crate::mcp::traits::McpToolDefinition for GetFoundryHelpArgs {
             ),
             annotations: None,
             meta: None,
-            output_schema: None,
+            output_schema: Some(crate::mcp::traits::output_schema::<
+                crate::types::responses::GetFoundryHelpResponse,
+            >()),
         }
     }
 
@@ -461,18 +666,26 @@ pub struct ValidateContentArgs {
     /// Each type has specific length and quality requirements
     /// Use this to check content before creating projects/specs
     pub content_type: String,
+
+    /// Project whose .foundry.toml validation settings apply (default: global config only)
+    #[arg(long)]
+    pub project_name: Option<String>,
 }
 
 // Generate MCP tool implementation for ValidateContentArgs
 impl_mcp_tool! {
     name = "validate_content",
-    description = "Validate content against schema requirements with improvement suggestions. You can use this to ensure your content meets foundry standards before creating projects or specifications. Provides detailed feedback for content improvement.",
+    description = "Validate content against schema requirements with improvement suggestions. You can use this to ensure your content meets foundry standards before creating projects or specifications. Applies the configured validation profile (strict, standard, lenient) and custom rules, and reports which rules failed and why.",
+    output = crate::types::responses::ValidateContentResponse,
     struct ValidateContentArgs {
         content: String {
             description = "Content to validate against the specified type's requirements"
         },
         content_type: String {
             description = "Type of content to validate (vision, tech-stack, summary, spec, notes, tasks)"
+        },
+        project_name: Option<String> {
+            description = "Project whose .foundry.toml validation profile and rules apply; omit to use the global config"
         }
     }
 }
@@ -495,10 +708,22 @@ pub struct UpdateSpecArgs {
     /// Edit commands to apply (REQUIRED). Provide as JSON array via MCP.
     #[arg(long, required = true)]
     pub commands: String,
+
+    /// Optional: version of the targeted file from load_spec
+    ///
+    /// The update fails with a conflict if the file changed since that version
+    #[arg(long)]
+    pub expected_version: Option<String>,
+
+    /// Optional: return the unified diff of what the commands would change without writing
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 // Manual MCP tool implementation for UpdateSpecArgs (custom schema)
 impl crate::mcp::traits::McpToolDefinition for UpdateSpecArgs {
+    const NAME: &'static str = "update_spec";
+
     fn tool_definition() -> rust_mcp_sdk::schema::Tool {
         let mut properties = std::collections::HashMap::new();
 
@@ -529,11 +754,27 @@ impl crate::mcp::traits::McpToolDefinition for UpdateSpecArgs {
                 "type": "object"
             }),
         );
-        commands_prop.insert("description".to_string(), serde_json::json!("Array of edit commands to apply. Each command must include: target (spec|tasks|notes), command (set_task_status|upsert_task|append_to_section|remove_list_item|remove_from_section|remove_section|replace_list_item|replace_in_section|replace_section_content), selector (section|task_text|text_in_section), and required fields (status for set_task_status, content for upsert_task/append_to_section/remove_from_section/replace_*). See detailed examples in help content."));
+        commands_prop.insert("description".to_string(), serde_json::json!("Array of edit commands to apply. Each command must include: target (spec|tasks|notes; the project's decisions.md is edited with update_decisions), command (set_task_status|upsert_task|append_to_section|remove_list_item|remove_from_section|remove_section|replace_list_item|replace_in_section|replace_section_content|add_dependency|remove_dependency|add_phase|move_task_to_phase|set_tasks_status_matching|complete_phase), selector (section|task_text|text_in_section|spec_name|task_pattern), and required fields (status for set_task_status/set_tasks_status_matching, content for upsert_task/append_to_section/remove_from_section/replace_*). See detailed examples in help content."));
         properties.insert("commands".to_string(), commands_prop);
 
+        let mut expected_version_prop = serde_json::Map::new();
+        expected_version_prop.insert("type".to_string(), serde_json::json!("string"));
+        expected_version_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Optional: the version of the targeted file from load_spec's 'versions' (or a previous update_spec response). If the file changed since, the update fails with BACKEND_CONFLICT and a diff instead of overwriting the other edit. All commands must then target the same file"),
+        );
+        properties.insert("expected_version".to_string(), expected_version_prop);
+
+        let mut dry_run_prop = serde_json::Map::new();
+        dry_run_prop.insert("type".to_string(), serde_json::json!("boolean"));
+        dry_run_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Optional: when true, nothing is written; the response holds 'preview_diff' (unified diff of every changed file) and 'command_diffs' (each command's own diff) so the change can be confirmed before applying it. Defaults to false"),
+        );
+        properties.insert("dry_run".to_string(), dry_run_prop);
+
         rust_mcp_sdk::schema::Tool {
-            name: "update_spec".to_string(),
+            name: Self::NAME.to_string(),
             description: Some("Edit Foundry spec files using comprehensive content management commands: add, update, remove, and replace content with precise anchors and idempotent updates. Provide a 'commands' array of edit operations.".to_string()),
             title: None,
             input_schema: rust_mcp_sdk::schema::ToolInputSchema::new(
@@ -542,7 +783,9 @@ impl crate::mcp::traits::McpToolDefinition for UpdateSpecArgs {
             ),
             annotations: None,
             meta: None,
-            output_schema: None,
+            output_schema: Some(crate::mcp::traits::output_schema::<
+                crate::types::responses::EditCommandsResponsePayload,
+            >()),
         }
     }
 
@@ -561,6 +804,93 @@ impl crate::mcp::traits::McpToolDefinition for UpdateSpecArgs {
                     .as_array()
                     .ok_or_else(|| anyhow::anyhow!("Missing commands array"))?,
             )?,
+            expected_version: params["expected_version"].as_str().map(|s| s.to_string()),
+            dry_run: params["dry_run"].as_bool().unwrap_or(false),
+        })
+    }
+}
+
+/// Arguments for update_specs_batch command
+#[derive(Args, Debug)]
+pub struct UpdateSpecsBatchArgs {
+    /// Project name containing the specs to update
+    pub project_name: String,
+
+    /// Updates to apply: JSON array of {"spec_name": ..., "commands": [...]} entries
+    ///
+    /// Each entry's commands use the update_spec edit command format
+    #[arg(long, required = true)]
+    pub updates: String,
+}
+
+// Manual MCP tool implementation for UpdateSpecsBatchArgs (custom schema)
+impl crate::mcp::traits::McpToolDefinition for UpdateSpecsBatchArgs {
+    const NAME: &'static str = "update_specs_batch";
+
+    fn tool_definition() -> rust_mcp_sdk::schema::Tool {
+        let mut properties = std::collections::HashMap::new();
+
+        let mut project_name_prop = serde_json::Map::new();
+        project_name_prop.insert("type".to_string(), serde_json::json!("string"));
+        project_name_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Name of the existing project containing the specs"),
+        );
+        properties.insert("project_name".to_string(), project_name_prop);
+
+        let mut updates_prop = serde_json::Map::new();
+        updates_prop.insert("type".to_string(), serde_json::json!("array"));
+        updates_prop.insert(
+            "items".to_string(),
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "spec_name": {
+                        "type": "string",
+                        "description": "Name of the existing spec to update (YYYYMMDD_HHMMSS_feature_name format)"
+                    },
+                    "commands": {
+                        "type": "array",
+                        "items": { "type": "object" },
+                        "description": "Edit commands for this spec, in the same format as update_spec's 'commands'"
+                    }
+                },
+                "required": ["spec_name", "commands"]
+            }),
+        );
+        updates_prop.insert(
+            "description".to_string(),
+            serde_json::json!("One entry per spec (at most 50, each spec once). Each spec's commands apply together or not at all; other specs still apply when one fails"),
+        );
+        properties.insert("updates".to_string(), updates_prop);
+
+        rust_mcp_sdk::schema::Tool {
+            name: Self::NAME.to_string(),
+            description: Some("Apply edit commands to several specs of a project in one call, e.g. marking tasks done across specs after a refactor. Each spec's commands are applied atomically; returns a result per spec with applied counts, errors and new file versions.".to_string()),
+            title: None,
+            input_schema: rust_mcp_sdk::schema::ToolInputSchema::new(
+                vec!["project_name".to_string(), "updates".to_string()],
+                Some(properties),
+            ),
+            annotations: None,
+            meta: None,
+            output_schema: Some(crate::mcp::traits::output_schema::<
+                crate::types::responses::UpdateSpecsBatchResponse,
+            >()),
+        }
+    }
+
+    fn from_mcp_params(params: &serde_json::Value) -> anyhow::Result<Self> {
+        Ok(Self {
+            project_name: params["project_name"]
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("Missing project_name parameter"))?
+                .to_string(),
+            updates: serde_json::to_string(
+                params["updates"]
+                    .as_array()
+                    .ok_or_else(|| anyhow::anyhow!("Missing updates array"))?,
+            )?,
         })
     }
 }
@@ -593,6 +923,7 @@ pub struct DeleteSpecArgs {
 impl_mcp_tool! {
     name = "delete_spec",
     description = "Delete an existing specification and all its files (spec.md, task-list.md, notes.md). You can use this to permanently remove specifications that are no longer needed. This action cannot be undone.",
+    output = crate::types::responses::DeleteSpecResponse,
     struct DeleteSpecArgs {
         project_name: String {
             description = "Name of the existing project containing the spec"
@@ -606,112 +937,1418 @@ impl_mcp_tool! {
     }
 }
 
-/// Arguments for serve command
-#[derive(Args, Debug)]
-pub struct ServeArgs {
-    /// Enable verbose logging
-    #[arg(long, short)]
-    pub verbose: bool,
+crate::impl_mcp_tool! {
+    name = "update_project",
+    description = "Replace a project's vision, tech stack or summary after creation. Each given document replaces the stored file whole and is validated like create_project content; omitted documents are left unchanged. Load the project first to keep content you still need.",
+    output = crate::types::responses::UpdateProjectResponse,
+    /// Arguments for update_project command
+    #[derive(Args, Debug)]
+    pub struct UpdateProjectArgs {
+        /// Name of the existing project to update
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Optional: new product vision; replaces vision.md
+        #[arg(long)]
+        pub vision: Option<String>,
+
+        /// Optional: new technology stack and architecture decisions; replaces tech-stack.md
+        #[arg(long)]
+        pub tech_stack: Option<String>,
+
+        /// Optional: new concise project summary; replaces summary.md
+        #[arg(long)]
+        pub summary: Option<String>,
+    }
 }
 
-/// Arguments for install command
-#[derive(Args, Debug)]
-pub struct InstallArgs {
-    /// Target environment to install for
-    ///
-    /// Supported targets:
-    /// - claude-code: Install for Claude Code CLI environment (includes subagent template)
-    /// - cursor: Install for Cursor IDE environment (includes rules template)
-    ///
-    /// Installation creates both MCP server configuration and AI assistant guidance templates.
-    pub target: String,
-
-    /// Custom path to foundry binary (optional)
-    ///
-    /// If not provided, will attempt to detect the current binary path
-    /// Useful for installations where the binary is in a custom location
-    #[arg(long)]
-    pub binary_path: Option<String>,
+crate::impl_mcp_tool! {
+    name = "delete_project",
+    description = "Delete a project with its vision, tech stack, summary and decision log. Projects that still have specs are only deleted with 'force', which removes the specs too (each is backed up first). This action cannot be undone.",
+    output = crate::types::responses::DeleteProjectResponse,
+    /// Arguments for delete_project command
+    #[derive(Args, Debug)]
+    pub struct DeleteProjectArgs {
+        /// Name of the project to delete
+        ///
+        /// **Warning: This will permanently delete all project files**
+        pub project_name: String,
+
+        /// Confirmation flag - must be set to 'true' to proceed with deletion (safety mechanism)
+        #[arg(long, required = true)]
+        pub confirm: String,
+
+        /// Optional: also delete the project's specs; without it a project with specs is not deleted
+        #[arg(long)]
+        pub force: bool {
+            default = false
+        },
+    }
+}
 
-    /// Output installation information in JSON format
-    ///
-    /// When enabled, outputs structured JSON data instead of
-    /// human-readable formatted text with colors and status indicators
-    #[arg(long)]
-    pub json: bool,
+crate::impl_mcp_tool! {
+    name = "get_audit_log",
+    description = "Read a project's audit log: every call that changed the project (create, update, delete, edit commands applied), newest first, with the interface it came through (cli, mcp or rest), its outcome and when it finished. The log is append-only and kept after the project is deleted. Filter by spec, operation or time.",
+    output = crate::types::responses::AuditLogResponse,
+    /// Arguments for get_audit_log command
+    #[derive(Args, Debug)]
+    pub struct GetAuditLogArgs {
+        /// Project whose audit log to read
+        pub project_name: String,
+
+        /// Optional: only changes to this spec
+        #[arg(long)]
+        pub spec_name: Option<String>,
+
+        /// Optional: only this tool or command, e.g. "update_spec"
+        #[arg(long)]
+        pub operation: Option<String>,
+
+        /// Optional: only entries since a window back from now (24h, 7d), a date (YYYY-MM-DD) or an RFC 3339 timestamp
+        #[arg(long)]
+        pub since: Option<String>,
+
+        /// Optional: newest entries to return (default 50)
+        #[arg(long)]
+        pub limit: Option<usize>,
+    }
 }
 
-/// Arguments for uninstall command
+/// Arguments for export_tasks command
 #[derive(Args, Debug)]
-pub struct UninstallArgs {
-    /// Target environment to uninstall from
+pub struct ExportTasksArgs {
+    /// Project name to export tasks from
     ///
-    /// Supported targets:
-    /// - claude-code: Uninstall from Claude Code CLI environment
-    /// - cursor: Uninstall from Cursor IDE environment
-    pub target: String,
+    /// Must be an existing project in ~/.foundry/
+    /// Use 'mcp_foundry_list_projects' to see available projects
+    pub project_name: String,
 
-    /// Also remove configuration files
+    /// Export format (default: csv)
     ///
-    /// If set, will remove configuration files in addition to
-    /// unregistering the MCP server
+    /// **csv**: one row per task with columns spec, section, task, status, completed_at
+    /// **jira**: Jira CSV import layout with one Epic per spec and its tasks as children
     #[arg(long)]
-    pub remove_config: bool,
+    pub format: Option<String>,
 
-    /// Output uninstallation information in JSON format
+    /// Export a single spec instead of the whole project
     ///
-    /// When enabled, outputs structured JSON data instead of
-    /// human-readable formatted text with colors and status indicators
+    /// Must be an exact spec name (e.g., 20240824_120000_user_auth)
     #[arg(long)]
-    pub json: bool,
+    pub spec_name: Option<String>,
 }
 
-/// Arguments for status command
-#[derive(Args, Debug, Clone)]
-pub struct StatusArgs {
-    /// Show detailed configuration information
-    ///
-    /// Includes full paths, configuration file contents,
-    /// and detailed installation status
-    #[arg(long)]
-    pub detailed: bool,
+// Manual MCP tool implementation for ExportTasksArgs (has optional field)
+impl crate::mcp::traits::McpToolDefinition for ExportTasksArgs {
+    const NAME: &'static str = "export_tasks";
 
-    /// Check only specific target environment
-    ///
-    /// If provided, only show status for the specified environment
-    /// instead of all supported environments
-    #[arg(long)]
-    pub target: Option<String>,
+    fn tool_definition() -> rust_mcp_sdk::schema::Tool {
+        let mut properties = std::collections::HashMap::new();
 
-    /// Output status information in JSON format
-    ///
-    /// When enabled, outputs structured JSON data instead of
-    /// human-readable formatted text with colors
-    #[arg(long)]
-    pub json: bool,
-}
+        let mut project_name_prop = serde_json::Map::new();
+        project_name_prop.insert("type".to_string(), serde_json::json!("string"));
+        project_name_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Name of the existing project whose tasks should be exported"),
+        );
+        properties.insert("project_name".to_string(), project_name_prop);
+
+        let mut format_prop = serde_json::Map::new();
+        format_prop.insert("type".to_string(), serde_json::json!("string"));
+        format_prop.insert("enum".to_string(), serde_json::json!(["csv", "jira"]));
+        format_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Optional: export format. 'csv' (default) produces one row per task with columns spec, section, task, status, completed_at. 'jira' produces Jira CSV import rows (Issue Id, Issue Type, Summary, Description, Parent Id, Status) with one Epic per spec and its tasks linked via Parent Id"),
+        );
+        properties.insert("format".to_string(), format_prop);
 
-// MCP parameter conversion implementations
-// All structs now use auto-generated McpTool implementation via derive macro
-// Except ListProjectsArgs which is a unit struct and needs manual implementation
+        let mut spec_name_prop = serde_json::Map::new();
+        spec_name_prop.insert("type".to_string(), serde_json::json!("string"));
+        spec_name_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Optional: exact spec name to export (e.g., 20240824_120000_user_auth). Omit to export every spec in the project"),
+        );
+        properties.insert("spec_name".to_string(), spec_name_prop);
 
-impl crate::mcp::traits::McpToolDefinition for ListProjectsArgs {
-    fn tool_definition() -> rust_mcp_sdk::schema::Tool {
         rust_mcp_sdk::schema::Tool {
-            name: "list_projects".to_string(),
-            description: Some("List all available projects with metadata including creation dates, spec counts, and validation status. You can use this to discover available projects before loading or creating specifications.".to_string()),
+            name: Self::NAME.to_string(),
+            description: Some("Export tasks across a project's specs (or a single spec) as CSV for tracking progress in spreadsheets, or in Jira's CSV import format with one Epic per spec. Returns the document as text in 'content'.".to_string()),
             title: None,
             input_schema: rust_mcp_sdk::schema::ToolInputSchema::new(
-                vec![],
-                Some(std::collections::HashMap::new()),
+                vec!["project_name".to_string()],
+                Some(properties),
             ),
             annotations: None,
             meta: None,
-            output_schema: None,
+            output_schema: Some(crate::mcp::traits::output_schema::<
+                crate::types::responses::ExportTasksResponse,
+            >()),
         }
     }
 
-    fn from_mcp_params(_params: &serde_json::Value) -> anyhow::Result<Self> {
-        Ok(Self)
+    fn from_mcp_params(params: &serde_json::Value) -> anyhow::Result<Self> {
+        Ok(Self {
+            project_name: params["project_name"]
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("Missing project_name parameter"))?
+                .to_string(),
+            format: params["format"].as_str().map(|s| s.to_string()),
+            spec_name: params["spec_name"].as_str().map(|s| s.to_string()),
+        })
+    }
+}
+
+impl_mcp_tool! {
+    name = "diagram_specs",
+    description = "Render a project's spec dependency graph (from [[wiki-links]] between specs) as a Mermaid diagram, optionally with per-spec task phase flows. Returns Mermaid source ready to embed in notes.md or documentation.",
+    output = crate::types::responses::DiagramSpecsResponse,
+    /// Arguments for diagram_specs command
+    #[derive(Args, Debug)]
+    pub struct DiagramSpecsArgs {
+        /// Name of the existing project to render diagrams for
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Optional: spec name or feature name to focus on
+        ///
+        /// Only that spec and the specs it links to or is linked from are drawn
+        #[arg(long)]
+        pub spec_name: Option<String>,
+
+        /// Optional: also render each spec's task-list sections as a Mermaid flowchart with done/total counts
+        #[arg(long)]
+        pub include_phases: bool {
+            default = false
+        },
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "spec_graph",
+    description = "Report a project's spec dependency graph (from each spec's 'depends_on', managed with update_spec add_dependency/remove_dependency): every spec with its dependencies, dependents and open blockers, a build order, the specs ready to start next, and any dependency cycles. Use it to decide what to build next.",
+    output = crate::types::responses::SpecGraphResponse,
+    /// Arguments for spec_graph command
+    #[derive(Args, Debug)]
+    pub struct SpecGraphArgs {
+        /// Name of the existing project to report the dependency graph for
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+    }
+}
+
+impl_mcp_tool! {
+    name = "project_report",
+    description = "Generate a progress report for a project: tasks done vs. total, completion per spec, tasks completed recently (from inline 'completed:YYYY-MM-DD' tokens) and stale specs. Returns structured data plus a markdown summary ready for a weekly status update, without loading every spec.",
+    output = crate::types::responses::ProjectReportResponse,
+    /// Arguments for project_report command
+    #[derive(Args, Debug)]
+    pub struct ProjectReportArgs {
+        /// Name of the existing project to report on
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Optional: days of completed work to list, ending today (default 7)
+        #[arg(long)]
+        pub recent_days: Option<u32>,
+
+        /// Optional: days without activity before a spec with open tasks counts as stale (default: the project's '[stale] days' setting, or 14)
+        #[arg(long)]
+        pub stale_days: Option<u32>,
+    }
+}
+
+impl_mcp_tool! {
+    name = "project_analytics",
+    description = "Report a project's velocity and completion trends: tasks completed per week (from inline 'completed:YYYY-MM-DD' tokens), average spec cycle time from creation to last completed task, and stalled specs with open tasks and no recent activity. Returns structured data plus a markdown summary.",
+    output = crate::types::responses::ProjectAnalyticsResponse,
+    /// Arguments for project_analytics command
+    #[derive(Args, Debug)]
+    pub struct ProjectAnalyticsArgs {
+        /// Name of the existing project to analyze
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Optional: weeks of completion history to report, ending this week (default 8)
+        #[arg(long)]
+        pub weeks: Option<u32>,
+
+        /// Optional: days without activity before a spec with open tasks counts as stalled (default: the project's '[stale] days' setting, or 14)
+        #[arg(long)]
+        pub stall_days: Option<u32>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "needs_attention",
+    description = "Find stale specs that need attention: specs with open tasks and no activity (no file change recorded by foundry and no task completed with a 'completed:YYYY-MM-DD' token since creation) for a number of days, per project via '[stale] days' in .foundry.toml. Checks one project or every project, longest idle first, so unfinished work does not silently rot.",
+    output = crate::types::responses::NeedsAttentionResponse,
+    /// Arguments for needs_attention command
+    #[derive(Args, Debug)]
+    pub struct NeedsAttentionArgs {
+        /// Optional: project to check; every project when omitted
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        #[arg(long)]
+        pub project_name: Option<String>,
+
+        /// Optional: days without activity before a spec with open tasks counts as stale (default: the project's '[stale] days' setting, or 14)
+        #[arg(long)]
+        pub stale_days: Option<u32>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "activity_digest",
+    description = "Summarize activity across projects over a date range: specs created, tasks completed and notes added, per project. Returns counts, details and a markdown report suitable for a weekly update. Tasks and notes come from the activity journal, so only updates made through Foundry are counted.",
+    output = crate::types::responses::DigestResponse,
+    /// Arguments for activity_digest command
+    #[derive(Args, Debug)]
+    pub struct ActivityDigestArgs {
+        /// Optional: start of the range as a window back from now (24h, 7d), a date (YYYY-MM-DD) or an RFC 3339 timestamp (default 7d)
+        #[arg(long)]
+        pub since: Option<String>,
+
+        /// Optional: end of the range as a date (inclusive) or an RFC 3339 timestamp (default now)
+        #[arg(long)]
+        pub until: Option<String>,
+
+        /// Optional: project to summarize; every project when omitted
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        #[arg(long)]
+        pub project_name: Option<String>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "create_handoff",
+    description = "Assemble a condensed handoff packet for a spec so another agent or a person can pick up the work: progress, open tasks, recent decisions from notes.md, the project summary and as many spec sections as fit a target token budget. Returns the packet as markdown plus what was included or left out.",
+    output = crate::types::responses::CreateHandoffResponse,
+    /// Arguments for create_handoff command
+    #[derive(Args, Debug)]
+    pub struct CreateHandoffArgs {
+        /// Name of the existing project containing the spec
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Spec to hand off; a feature name or close match also works
+        ///
+        /// Use 'mcp_foundry_list_specs' to see available specs
+        pub spec_name: String,
+
+        /// Optional: target size of the packet in tokens (default 2000, minimum 200)
+        #[arg(long)]
+        pub max_tokens: Option<usize>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "review_spec",
+    description = "Review a spec before implementation starts: runs structural validation, checks that acceptance criteria exist and are covered by tasks, flags vague wording and terse tasks, and checks that testing is planned. Returns a scored checklist and whether the spec passes the quality gate. Scoring is deterministic.",
+    output = crate::types::responses::ReviewSpecResponse,
+    /// Arguments for review_spec command
+    #[derive(Args, Debug)]
+    pub struct ReviewSpecArgs {
+        /// Name of the existing project containing the spec
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Spec to review; a feature name or close match also works
+        ///
+        /// Use 'mcp_foundry_list_specs' to see available specs
+        pub spec_name: String,
+
+        /// Optional: score out of 100 the spec needs to pass (default 70)
+        #[arg(long)]
+        pub pass_threshold: Option<u32>,
     }
 }
+
+crate::impl_mcp_tool! {
+    name = "lint_spec",
+    description = "Lint a spec's structure: required sections in spec.md, non-empty acceptance criteria, a sane heading hierarchy, tasks written as '- [ ]' checkboxes and no duplicate tasks. Each finding has a rule, severity, file and line, plus 'fix' update_spec commands that would correct it, so malformed specs can be fixed without guesswork.",
+    output = crate::types::responses::LintSpecResponse,
+    /// Arguments for lint_spec command
+    #[derive(Args, Debug)]
+    pub struct LintSpecArgs {
+        /// Name of the existing project containing the spec
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Spec to lint; a feature name or close match also works
+        ///
+        /// Use 'mcp_foundry_list_specs' to see available specs
+        pub spec_name: String,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "export_project",
+    description = "Export an entire project (vision, tech stack, summary and every spec with its notes and tasks) to a portable .tar.gz archive on the server's filesystem, for sharing between machines or checking into a repository. Restore it with import_archive.",
+    output = crate::types::responses::ExportProjectResponse,
+    /// Arguments for export_project command
+    #[derive(Args, Debug)]
+    pub struct ExportProjectArgs {
+        /// Name of the existing project to export
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Optional: archive path to write (default: <project>.foundry.tar.gz in the working directory)
+        #[arg(long)]
+        pub output_path: Option<String>,
+
+        /// Optional: replace an existing file at the archive path
+        #[arg(long)]
+        pub overwrite: bool {
+            default = false
+        },
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "import_archive",
+    description = "Restore a project from an archive written by export_project. Creates the project and all its specs; specs get new timestamped names and links between them are updated. Fails if the project already exists, so pass project_name to import a copy under another name.",
+    output = crate::types::responses::ImportArchiveResponse,
+    /// Arguments for import_archive command
+    #[derive(Args, Debug)]
+    pub struct ImportArchiveArgs {
+        /// Path to a .foundry.tar.gz archive written by export_project or 'foundry export'
+        pub archive_path: String,
+
+        /// Optional: name for the restored project (default: the archived project's name)
+        #[arg(long)]
+        pub project_name: Option<String>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "search_specs",
+    description = "Full-text search across spec.md, notes.md and task-list.md in every project (or one project). Returns matching lines ranked by relevance, with project and spec names, file, line number and a snippet, so you can find the right spec without loading each one.",
+    output = crate::types::responses::SearchSpecsResponse,
+    /// Arguments for search_specs command
+    #[derive(Args, Debug)]
+    pub struct SearchSpecsArgs {
+        /// Words to search for; case-insensitive, lines matching more words rank higher
+        pub query: String,
+
+        /// Optional: project to search; every project when omitted
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        #[arg(long)]
+        pub project_name: Option<String>,
+
+        /// Optional: most matches to return (default 20, maximum 100)
+        #[arg(long)]
+        pub limit: Option<usize>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "set_spec_status",
+    description = "Move a spec through its lifecycle: draft → active → completed → archived. The status is stored with the spec, shown by list_specs, and completed or archived specs are no longer reported as stale. Any status can be set again to reopen a spec.",
+    output = crate::types::responses::SpecStatusResponse,
+    /// Arguments for set_spec_status command
+    #[derive(Args, Debug)]
+    pub struct SetSpecStatusArgs {
+        /// Project containing the spec
+        pub project_name: String,
+
+        /// Exact spec name (YYYYMMDD_HHMMSS_feature_name)
+        pub spec_name: String,
+
+        /// New status: draft, active, completed or archived
+        pub status: String {
+            enum_values = ["draft", "active", "completed", "archived"]
+        },
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "rename_spec",
+    description = "Rename a spec's feature while keeping its id prefix (timestamp or number), files, status and task state. Wiki links and depends_on entries in other specs are updated to the new name. Prefer this over deleting and recreating a spec.",
+    output = crate::types::responses::RenameSpecResponse,
+    /// Arguments for rename_spec command
+    #[derive(Args, Debug)]
+    pub struct RenameSpecArgs {
+        /// Project containing the spec
+        pub project_name: String,
+
+        /// Exact spec name (YYYYMMDD_HHMMSS_feature_name)
+        pub spec_name: String,
+
+        /// New feature name in snake_case; the id prefix is kept
+        pub new_feature_name: String,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "copy_spec",
+    description = "Copy a spec into another project (or the same one), or move it with 'delete_source'. The copy gets a new id and created_at and keeps the files, tags and summary; copies start as drafts while moves keep their status. depends_on is not copied since it names specs of the source project.",
+    output = crate::types::responses::CopySpecResponse,
+    /// Arguments for copy_spec command
+    #[derive(Args, Debug)]
+    pub struct CopySpecArgs {
+        /// Project containing the spec
+        pub project_name: String,
+
+        /// Exact spec name (YYYYMMDD_HHMMSS_feature_name)
+        pub spec_name: String,
+
+        /// Project to copy the spec into
+        pub target_project_name: String,
+
+        /// Optional: feature name for the copy in snake_case; the source's when omitted
+        #[arg(long)]
+        pub feature_name: Option<String>,
+
+        /// Optional: delete the source spec after copying it, i.e. move it (backed up first)
+        #[arg(long)]
+        pub delete_source: bool {
+            default = false
+        },
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "undo_last_update",
+    description = "Undo the most recent update_spec or update_specs_batch change to a spec by restoring the files it changed to their previous content. Call it again to step further back. Fails with a conflict if those files changed again since, unless 'force' is set.",
+    output = crate::types::responses::UndoLastUpdateResponse,
+    /// Arguments for undo_last_update command
+    #[derive(Args, Debug)]
+    pub struct UndoLastUpdateArgs {
+        /// Project containing the spec
+        pub project_name: String,
+
+        /// Exact spec name (YYYYMMDD_HHMMSS_feature_name)
+        pub spec_name: String,
+
+        /// Optional: undo even if the files changed again after the update, discarding those changes
+        #[arg(long)]
+        pub force: bool {
+            default = false
+        },
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "summarize_spec",
+    description = "Draft a short summary of a spec with your own model (MCP sampling) and store it in the spec's metadata, where list_specs returns it. The client may ask the user to approve the request. If spec.md is unchanged since the stored summary, that summary is returned without sampling unless 'force' is set. Fails with SAMPLING_UNAVAILABLE when the client does not support sampling.",
+    output = crate::types::responses::SummarizeSpecResponse,
+    /// Arguments for summarize_spec command
+    #[derive(Args, Debug)]
+    pub struct SummarizeSpecArgs {
+        /// Project containing the spec
+        pub project_name: String,
+
+        /// Exact spec name (YYYYMMDD_HHMMSS_feature_name)
+        pub spec_name: String,
+
+        /// Optional: draft a new summary even if spec.md is unchanged since the last one
+        #[arg(long)]
+        pub force: bool {
+            default = false
+        },
+
+        /// Optional: longest summary to ask for, in words (default 60)
+        #[arg(long)]
+        pub max_words: Option<usize>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "diff_spec",
+    description = "Compare a spec with another spec, a backup snapshot (backup_id from 'foundry backup list') or its own state before its last journaled updates (history_steps). Reports per file which sections were added, removed or changed, e.g. 'spec.md: Requirements changed; notes.md and task-list.md unchanged', plus a unified diff. Give exactly one of other_spec_name, backup_id or history_steps.",
+    output = crate::types::responses::DiffSpecResponse,
+    /// Arguments for diff_spec command
+    #[derive(Args, Debug)]
+    pub struct DiffSpecArgs {
+        /// Project containing the spec
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Spec to diff (the 'b/' side); a feature name or close match also works
+        ///
+        /// Use 'mcp_foundry_list_specs' to see available specs
+        pub spec_name: String,
+
+        /// Optional: spec to compare with (the 'a/' side)
+        #[arg(long)]
+        pub other_spec_name: Option<String>,
+
+        /// Optional: project of other_spec_name (default: project_name)
+        #[arg(long)]
+        pub other_project_name: Option<String>,
+
+        /// Optional: backup to compare with, as shown by 'foundry backup list'
+        #[arg(long)]
+        pub backup_id: Option<String>,
+
+        /// Optional: compare with the spec as it was before its last N update_spec/update_specs_batch changes
+        #[arg(long)]
+        pub history_steps: Option<usize>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "add_tag",
+    description = "Tag a spec, or a project when spec_name is omitted, with labels such as 'area:backend' or 'quarter:Q3'. list_specs and list_projects filter by tags, and tags are reported in their listings. Tags already present are left as they are.",
+    output = crate::types::responses::TagsResponse,
+    /// Arguments for add_tag command
+    #[derive(Args, Debug)]
+    pub struct AddTagArgs {
+        /// Project to tag, or containing the spec to tag
+        pub project_name: String,
+
+        /// Optional: exact spec name (YYYYMMDD_HHMMSS_feature_name); the project is tagged when omitted
+        #[arg(long)]
+        pub spec_name: Option<String>,
+
+        /// Tags to add, without spaces or commas, e.g. ["area:backend", "quarter:Q3"]
+        #[arg(long = "tag", required = true)]
+        pub tags: Vec<String>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "remove_tag",
+    description = "Remove tags from a spec, or from a project when spec_name is omitted. Tags compare case-insensitively; tags that are not present are ignored.",
+    output = crate::types::responses::TagsResponse,
+    /// Arguments for remove_tag command
+    #[derive(Args, Debug)]
+    pub struct RemoveTagArgs {
+        /// Project to untag, or containing the spec to untag
+        pub project_name: String,
+
+        /// Optional: exact spec name (YYYYMMDD_HHMMSS_feature_name); the project's tags are changed when omitted
+        #[arg(long)]
+        pub spec_name: Option<String>,
+
+        /// Tags to remove
+        #[arg(long = "tag", required = true)]
+        pub tags: Vec<String>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "archive_spec",
+    description = "Archive a finished or abandoned spec. Its files are kept, but list_specs hides it unless asked for archived specs, and it is no longer reported as stale. Reopen it with set_spec_status.",
+    output = crate::types::responses::SpecStatusResponse,
+    /// Arguments for archive_spec command
+    #[derive(Args, Debug)]
+    pub struct ArchiveSpecArgs {
+        /// Project containing the spec
+        pub project_name: String,
+
+        /// Exact spec name (YYYYMMDD_HHMMSS_feature_name)
+        pub spec_name: String,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "add_decision",
+    description = "Record an architectural decision in the project's decision log (decisions.md, ADR-style) so it is not buried in one spec's notes. Decisions are numbered ADR-001, ADR-002, ..., dated, and loaded with the project by load_project.",
+    output = crate::types::responses::AddDecisionResponse,
+    /// Arguments for add_decision command
+    #[derive(Args, Debug)]
+    pub struct AddDecisionArgs {
+        /// Project to record the decision in
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Short title of the decision, e.g. "Store sessions in Redis"
+        pub title: String,
+
+        /// Why a decision was needed: the problem, constraints and options considered
+        #[arg(long)]
+        pub context: String,
+
+        /// What was decided and why
+        #[arg(long)]
+        pub decision: String,
+
+        /// Optional: trade-offs and follow-up work the decision brings
+        #[arg(long)]
+        pub consequences: Option<String>,
+
+        /// Optional: proposed, accepted (default), rejected, deprecated or superseded
+        #[arg(long)]
+        pub status: Option<String> {
+            enum_values = crate::core::decisions::STATUSES
+        },
+
+        /// Optional: exact name of the spec the decision was made for
+        #[arg(long)]
+        pub spec_name: Option<String>,
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "list_decisions",
+    description = "List the decisions recorded in a project's decision log with their id, date, status, related spec, context, decision and consequences, optionally filtered by status or spec.",
+    output = crate::types::responses::ListDecisionsResponse,
+    /// Arguments for list_decisions command
+    #[derive(Args, Debug)]
+    pub struct ListDecisionsArgs {
+        /// Project whose decision log to list
+        ///
+        /// Use 'mcp_foundry_list_projects' to see available projects
+        pub project_name: String,
+
+        /// Optional: only decisions with this status (proposed, accepted, rejected, deprecated, superseded)
+        #[arg(long)]
+        pub status: Option<String> {
+            enum_values = crate::core::decisions::STATUSES
+        },
+
+        /// Optional: only decisions made for this spec
+        #[arg(long)]
+        pub spec_name: Option<String>,
+    }
+}
+
+/// Arguments for update_decisions command
+#[derive(Args, Debug)]
+pub struct UpdateDecisionsArgs {
+    /// Project whose decision log to edit
+    ///
+    /// Use 'mcp_foundry_list_projects' to see available projects
+    pub project_name: String,
+
+    /// Edit commands to apply (REQUIRED), all with target "decisions". Provide as JSON array via MCP.
+    #[arg(long, required = true)]
+    pub commands: String,
+}
+
+// Manual MCP tool implementation for UpdateDecisionsArgs (custom schema)
+impl crate::mcp::traits::McpToolDefinition for UpdateDecisionsArgs {
+    const NAME: &'static str = "update_decisions";
+
+    fn tool_definition() -> rust_mcp_sdk::schema::Tool {
+        let mut properties = std::collections::HashMap::new();
+
+        let mut project_name_prop = serde_json::Map::new();
+        project_name_prop.insert("type".to_string(), serde_json::json!("string"));
+        project_name_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Name of the existing project whose decision log to edit"),
+        );
+        properties.insert("project_name".to_string(), project_name_prop);
+
+        let mut commands_prop = serde_json::Map::new();
+        commands_prop.insert("type".to_string(), serde_json::json!("array"));
+        commands_prop.insert(
+            "items".to_string(),
+            serde_json::json!({
+                "type": "object"
+            }),
+        );
+        commands_prop.insert("description".to_string(), serde_json::json!("Array of edit commands to apply to decisions.md. Each command must include: target \"decisions\", command (append_to_section|remove_list_item|remove_from_section|remove_section|replace_list_item|replace_in_section|replace_section_content), selector (section|text_in_section, using exact decision headings such as \"## ADR-002: Use JWTs\"), and content where the command needs it. Example: set a decision's status with replace_in_section and content \"- **Status**: superseded\"."));
+        properties.insert("commands".to_string(), commands_prop);
+
+        rust_mcp_sdk::schema::Tool {
+            name: Self::NAME.to_string(),
+            description: Some("Edit a project's decision log (decisions.md) with the same section commands update_spec uses for notes: mark decisions superseded, extend their context or consequences, or remove them. Record new decisions with add_decision.".to_string()),
+            title: None,
+            input_schema: rust_mcp_sdk::schema::ToolInputSchema::new(
+                vec!["project_name".to_string(), "commands".to_string()],
+                Some(properties),
+            ),
+            annotations: None,
+            meta: None,
+            output_schema: Some(crate::mcp::traits::output_schema::<
+                crate::types::responses::UpdateDecisionsResponse,
+            >()),
+        }
+    }
+
+    fn from_mcp_params(params: &serde_json::Value) -> anyhow::Result<Self> {
+        Ok(Self {
+            project_name: params["project_name"]
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("Missing project_name parameter"))?
+                .to_string(),
+            commands: serde_json::to_string(
+                params["commands"]
+                    .as_array()
+                    .ok_or_else(|| anyhow::anyhow!("Missing commands array"))?,
+            )?,
+        })
+    }
+}
+
+/// Arguments for import_project command
+#[derive(Args, Debug)]
+pub struct ImportProjectArgs {
+    /// Project name to import into (kebab-case)
+    ///
+    /// A new project is created when it does not exist yet
+    /// Existing projects only receive new specs; vision/tech-stack/summary are left unchanged
+    pub project_name: String,
+
+    /// Directory to import from (e.g., a Taskmaster repo or a plain docs folder)
+    pub source_path: String,
+
+    /// Mapping preset: "docs" (default) or "taskmaster"
+    ///
+    /// **docs**: vision.md/README.md, tech-stack.md/ARCHITECTURE.md, summary.md, specs/
+    /// **taskmaster**: .taskmaster/docs/prd.txt as vision, .taskmaster/tasks/* as specs
+    #[arg(long)]
+    pub preset: Option<String>,
+
+    /// Mapping overrides as a JSON object
+    ///
+    /// Keys: vision, tech_stack, summary, spec_file, notes_file, tasks_file (arrays of
+    /// candidate paths) and specs_dir (string). Provided keys replace the preset's entries.
+    #[arg(long)]
+    pub mapping: Option<String>,
+
+    /// Report what would be imported without writing anything (default: true)
+    #[arg(long)]
+    pub dry_run: Option<bool>,
+}
+
+// Manual MCP tool implementation for ImportProjectArgs (optional and non-string fields)
+impl crate::mcp::traits::McpToolDefinition for ImportProjectArgs {
+    const NAME: &'static str = "import_project";
+
+    fn tool_definition() -> rust_mcp_sdk::schema::Tool {
+        let mut properties = std::collections::HashMap::new();
+
+        let mut project_name_prop = serde_json::Map::new();
+        project_name_prop.insert("type".to_string(), serde_json::json!("string"));
+        project_name_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Project to import into (kebab-case). Created if it does not exist; existing projects only receive new specs"),
+        );
+        properties.insert("project_name".to_string(), project_name_prop);
+
+        let mut source_path_prop = serde_json::Map::new();
+        source_path_prop.insert("type".to_string(), serde_json::json!("string"));
+        source_path_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Absolute path of the directory to import from (e.g., a Taskmaster repo or a docs folder)"),
+        );
+        properties.insert("source_path".to_string(), source_path_prop);
+
+        let mut preset_prop = serde_json::Map::new();
+        preset_prop.insert("type".to_string(), serde_json::json!("string"));
+        preset_prop.insert(
+            "enum".to_string(),
+            serde_json::json!(["docs", "taskmaster"]),
+        );
+        preset_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Optional: mapping preset. 'docs' (default) reads vision.md/README.md, tech-stack.md/ARCHITECTURE.md, summary.md and specs/; 'taskmaster' reads .taskmaster/docs/prd.txt and .taskmaster/tasks/*"),
+        );
+        properties.insert("preset".to_string(), preset_prop);
+
+        let mut mapping_prop = serde_json::Map::new();
+        mapping_prop.insert("type".to_string(), serde_json::json!("object"));
+        mapping_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Optional: overrides for the preset. Keys vision, tech_stack, summary, spec_file, notes_file, tasks_file take arrays of candidate relative paths (first existing wins); specs_dir takes a relative directory"),
+        );
+        properties.insert("mapping".to_string(), mapping_prop);
+
+        let mut dry_run_prop = serde_json::Map::new();
+        dry_run_prop.insert("type".to_string(), serde_json::json!("boolean"));
+        dry_run_prop.insert(
+            "description".to_string(),
+            serde_json::json!("Optional: when true (default) only report what would be imported. Set to false to write the project and specs"),
+        );
+        properties.insert("dry_run".to_string(), dry_run_prop);
+
+        rust_mcp_sdk::schema::Tool {
+            name: Self::NAME.to_string(),
+            description: Some("Import planning docs from other markdown-based tools (Taskmaster, plain docs folders) into a Foundry project and specs. Returns a dry-run report by default; re-run with dry_run=false to write.".to_string()),
+            title: None,
+            input_schema: rust_mcp_sdk::schema::ToolInputSchema::new(
+                vec!["project_name".to_string(), "source_path".to_string()],
+                Some(properties),
+            ),
+            annotations: None,
+            meta: None,
+            output_schema: Some(crate::mcp::traits::output_schema::<
+                crate::types::responses::ImportProjectResponse,
+            >()),
+        }
+    }
+
+    fn from_mcp_params(params: &serde_json::Value) -> anyhow::Result<Self> {
+        let mapping = match &params["mapping"] {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(s) => Some(s.clone()),
+            value @ serde_json::Value::Object(_) => Some(serde_json::to_string(value)?),
+            _ => return Err(anyhow::anyhow!("mapping must be an object")),
+        };
+
+        Ok(Self {
+            project_name: params["project_name"]
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("Missing project_name parameter"))?
+                .to_string(),
+            source_path: params["source_path"]
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("Missing source_path parameter"))?
+                .to_string(),
+            preset: params["preset"].as_str().map(|s| s.to_string()),
+            mapping,
+            dry_run: params["dry_run"].as_bool(),
+        })
+    }
+}
+
+crate::impl_mcp_tool! {
+    name = "import_spec",
+    description = "Create a spec from an existing markdown file or a directory of them (e.g. Notion or Confluence exports, plain docs) without pasting the content in pieces. Sections are sorted by their '##' headings: Tasks/TODO/Checklist sections become task-list.md (plain list items become checkboxes), Notes/Open Questions/Decisions sections become notes.md, and the rest becomes spec.md. Use dry_run to review the split first.",
+    output = crate::types::responses::ImportSpecResponse,
+    /// Arguments for import_spec command
+    #[derive(Args, Debug)]
+    pub struct ImportSpecArgs {
+        /// Project to create the spec in
+        pub project_name: String,
+
+        /// Absolute path of a .md/.markdown/.txt file, or a directory of them
+        pub source_path: String,
+
+        /// Optional: feature name in snake_case (default: derived from the first '#' title or the file name)
+        #[arg(long)]
+        pub feature_name: Option<String>,
+
+        /// Optional: only report how the content would be split, without creating the spec
+        #[arg(long)]
+        pub dry_run: bool {
+            default = false
+        },
+    }
+}
+
+/// Arguments for get_server_stats command
+#[derive(Args, Debug)]
+pub struct GetServerStatsArgs {}
+
+// Manual MCP tool implementation for GetServerStatsArgs (no parameters)
+impl crate::mcp::traits::McpToolDefinition for GetServerStatsArgs {
+    const NAME: &'static str = "get_server_stats";
+
+    fn tool_definition() -> rust_mcp_sdk::schema::Tool {
+        rust_mcp_sdk::schema::Tool {
+            name: Self::NAME.to_string(),
+            description: Some("Report what this server has handled since it started: calls, errors and latencies per tool, edit command success and failure counts, and the confidence of fuzzy spec_name matches. Only available when the server runs with --metrics or --metrics-port.".to_string()),
+            title: None,
+            input_schema: rust_mcp_sdk::schema::ToolInputSchema::new(
+                vec![],
+                Some(std::collections::HashMap::new()),
+            ),
+            annotations: None,
+            meta: None,
+            output_schema: Some(crate::mcp::traits::output_schema::<
+                crate::types::responses::ServerStatsResponse,
+            >()),
+        }
+    }
+
+    fn from_mcp_params(_params: &serde_json::Value) -> anyhow::Result<Self> {
+        Ok(Self {})
+    }
+}
+
+/// Arguments for serve command
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// Enable verbose logging
+    #[arg(long, short)]
+    pub verbose: bool,
+
+    /// Log output format, written to stderr
+    ///
+    /// - text: human-readable lines
+    /// - json: one JSON object per event, with a request_id per tool call
+    #[arg(long, default_value = "text")]
+    pub log_format: String,
+
+    /// Record every tool call and response to rotating files under ~/.foundry/.logs
+    ///
+    /// Secret-like values are redacted and long strings truncated
+    #[arg(long)]
+    pub wire_log: bool,
+
+    /// Tool calls taking at least this long get a timing warning in their response diagnostics
+    #[arg(long, default_value_t = 2000)]
+    pub slow_op_threshold_ms: u64,
+
+    /// Write a crash report (panic message, backtrace, recent tool calls, versions) to ~/.foundry/.crash on panic
+    #[arg(long)]
+    pub crash_reports: bool,
+
+    /// How much guidance text tool responses include
+    ///
+    /// - full: all next_steps and workflow_hints
+    /// - minimal: only the first next step, no workflow hints
+    /// - none: no next_steps or workflow_hints
+    #[arg(long, default_value = "full")]
+    pub guidance: String,
+
+    /// Workflow hint rules file (default: ~/.foundry/.hints.json when present)
+    #[arg(long)]
+    pub hint_rules: Option<String>,
+
+    /// On startup, log a warning for every spec with open tasks and no activity for this many days
+    #[arg(long)]
+    pub stale_check_days: Option<u32>,
+
+    /// Webhooks file for change notifications (default: ~/.foundry/.webhooks.json when present)
+    #[arg(long)]
+    pub webhooks: Option<String>,
+
+    /// Also serve a REST API for the core ops on this port (e.g., GET /projects)
+    #[arg(long)]
+    pub rest_port: Option<u16>,
+
+    /// Address the REST API listens on (IPv4 or IPv6, e.g. ::1)
+    #[arg(long, default_value = "127.0.0.1")]
+    pub rest_bind: String,
+
+    /// Only expose tools that never change projects, specs or files (list, load, search, reports)
+    #[arg(long)]
+    pub read_only: bool,
+
+    /// Only expose these tools, e.g. --allow-tools list_specs,load_spec (combine with
+    /// --read-only to make sure none of them write)
+    #[arg(long, value_delimiter = ',')]
+    pub allow_tools: Vec<String>,
+
+    /// Collect per-tool call counts and latencies, edit command outcomes and fuzzy match
+    /// confidence, reported by the get_server_stats tool
+    #[arg(long)]
+    pub metrics: bool,
+
+    /// Serve the collected metrics in Prometheus format at GET /metrics on this port
+    /// (implies --metrics)
+    #[arg(long)]
+    pub metrics_port: Option<u16>,
+
+    /// Address the metrics endpoint listens on (IPv4 or IPv6, e.g. ::1)
+    #[arg(long, default_value = "127.0.0.1")]
+    pub metrics_bind: String,
+
+    /// Notify the client (resources/list_changed) when project or spec files change on disk,
+    /// e.g. after a manual edit or a git pull
+    #[arg(long)]
+    pub watch: bool,
+}
+
+/// Arguments for install command
+#[derive(Args, Debug)]
+pub struct InstallArgs {
+    /// Target environment to install for
+    ///
+    /// Supported targets:
+    /// - claude-code: Install for Claude Code CLI environment (includes subagent template)
+    /// - cursor: Install for Cursor IDE environment (includes rules template)
+    /// - vscode: Install for VS Code's user MCP configuration (GitHub Copilot agent mode)
+    /// - windsurf: Install for Windsurf (includes project rules and workflows in .windsurf/)
+    /// - zed: Install as a context server in Zed's settings.json
+    ///
+    /// Installation creates both MCP server configuration and AI assistant guidance templates.
+    pub target: String,
+
+    /// Custom path to foundry binary (optional)
+    ///
+    /// If not provided, will attempt to detect the current binary path
+    /// Useful for installations where the binary is in a custom location
+    #[arg(long)]
+    pub binary_path: Option<String>,
+
+    /// Template variable for the installed guidance, as name=value (repeatable)
+    ///
+    /// Overrides variables.json in the templates directory, e.g. --var server_name=foundry-work
+    #[arg(long = "var", value_name = "NAME=VALUE")]
+    pub vars: Vec<String>,
+
+    /// Directory with variables.json and partials/*.md for the installed guidance
+    ///
+    /// Defaults to ~/.foundry/.templates
+    #[arg(long)]
+    pub templates_dir: Option<String>,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for uninstall command
+#[derive(Args, Debug)]
+pub struct UninstallArgs {
+    /// Target environment to uninstall from
+    ///
+    /// Supported targets:
+    /// - claude-code: Uninstall from Claude Code CLI environment
+    /// - cursor: Uninstall from Cursor IDE environment
+    /// - vscode: Uninstall from VS Code's user MCP configuration
+    /// - windsurf: Uninstall from Windsurf and remove the project rules and workflows
+    /// - zed: Uninstall from Zed's settings.json
+    pub target: String,
+
+    /// Also remove configuration files
+    ///
+    /// If set, will remove configuration files in addition to
+    /// unregistering the MCP server
+    #[arg(long)]
+    pub remove_config: bool,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for status command
+#[derive(Args, Debug, Clone)]
+pub struct StatusArgs {
+    /// Show detailed configuration information
+    ///
+    /// Includes full paths, configuration file contents,
+    /// and detailed installation status
+    #[arg(long)]
+    pub detailed: bool,
+
+    /// Check only specific target environment
+    ///
+    /// If provided, only show status for the specified environment
+    /// instead of all supported environments
+    #[arg(long)]
+    pub target: Option<String>,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for schema command
+#[derive(Args, Debug)]
+pub struct SchemaArgs {
+    /// Output format for the tool schema document
+    ///
+    /// - json-schema: list of tools with their input/output JSON Schemas
+    /// - openapi: OpenAPI 3.1 document with one POST operation per tool
+    #[arg(long, default_value = "json-schema")]
+    pub format: String,
+}
+
+/// Arguments for replay command
+#[derive(Args, Debug)]
+pub struct ReplayArgs {
+    /// Wire log file to replay, or a wire log directory (all files, oldest first)
+    pub wire_log: String,
+
+    /// Stop at the first call whose outcome differs from the recording
+    #[arg(long)]
+    pub stop_on_divergence: bool,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for export command
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    /// Project to export
+    pub project_name: String,
+
+    /// Archive path to write (default: <project>.foundry.tar.gz)
+    #[arg(long, short)]
+    pub output: Option<String>,
+
+    /// Replace an existing file at the archive path
+    #[arg(long)]
+    pub overwrite: bool,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for import command
+#[derive(Args, Debug)]
+pub struct ImportArgs {
+    /// Archive written by `foundry export`
+    pub archive_path: String,
+
+    /// Name for the restored project (default: the archived project's name)
+    #[arg(long)]
+    pub project_name: Option<String>,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for backup command
+#[derive(Args, Debug)]
+pub struct BackupArgs {
+    #[command(subcommand)]
+    pub command: BackupCommand,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Backup subcommands
+#[derive(Subcommand, Debug)]
+pub enum BackupCommand {
+    /// List spec backups, newest first
+    List {
+        /// Only list backups of this project
+        #[arg(long)]
+        project: Option<String>,
+
+        /// Only list backups of this spec
+        #[arg(long)]
+        spec: Option<String>,
+    },
+
+    /// Restore a spec from a backup
+    ///
+    /// Overwrites the spec if it still exists (backing up its current content
+    /// first); a deleted spec is created anew under a fresh timestamped name
+    Restore {
+        /// Backup id as shown by `foundry backup list`
+        id: String,
+    },
+
+    /// Remove old backups, keeping the newest ones of every spec
+    Prune {
+        /// Backups to keep per spec
+        #[arg(long, default_value_t = crate::core::backup::DEFAULT_KEEP)]
+        keep: usize,
+    },
+}
+
+/// Arguments for audit command
+#[derive(Args, Debug)]
+pub struct AuditArgs {
+    #[command(subcommand)]
+    pub command: AuditCommand,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Audit subcommands
+#[derive(Subcommand, Debug)]
+pub enum AuditCommand {
+    /// Show a project's recorded changes, newest first
+    Show {
+        /// Project whose audit log to show
+        project: String,
+
+        /// Only changes to this spec
+        #[arg(long)]
+        spec: Option<String>,
+
+        /// Only this tool or command, e.g. update_spec
+        #[arg(long)]
+        operation: Option<String>,
+
+        /// Only entries since a window back from now (24h, 7d), a date (YYYY-MM-DD) or an RFC 3339 timestamp
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Newest entries to show
+        #[arg(long, default_value_t = crate::core::ops::get_audit_log::DEFAULT_LIMIT)]
+        limit: usize,
+    },
+}
+
+/// Arguments for doctor command
+#[derive(Args, Debug)]
+pub struct DoctorArgs {
+    /// Repair the problems that can be repaired without losing content
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for encryption command
+#[derive(Args, Debug)]
+pub struct EncryptionArgs {
+    #[command(subcommand)]
+    pub command: EncryptionCommand,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Encryption subcommands
+#[derive(Subcommand, Debug)]
+pub enum EncryptionCommand {
+    /// Write a new random key file, readable only by you
+    Keygen {
+        /// Where to write the key, e.g. ~/.config/foundry/key
+        path: String,
+    },
+
+    /// Encrypt existing project, spec and backup files with the configured key
+    Encrypt,
+
+    /// Decrypt every encrypted file with the configured key, e.g. before
+    /// turning encryption off
+    Decrypt,
+}
+
+/// Arguments for tui command
+#[derive(Args, Debug)]
+pub struct TuiArgs {
+    /// Open this project's specs right away
+    #[arg(long)]
+    pub project: Option<String>,
+}
+
+/// Arguments for workspace command
+#[derive(Args, Debug)]
+pub struct WorkspaceArgs {
+    #[command(subcommand)]
+    pub command: WorkspaceCommand,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Workspace subcommands
+#[derive(Subcommand, Debug)]
+pub enum WorkspaceCommand {
+    /// List registered workspaces
+    List,
+
+    /// Register a foundry directory as a workspace
+    Add {
+        /// Workspace name, used with --workspace and the `workspace` MCP parameter
+        name: String,
+
+        /// Foundry directory, e.g. ~/work/.foundry; created on first use
+        path: String,
+    },
+
+    /// Unregister a workspace; its directory is left in place
+    Remove {
+        /// Workspace name
+        name: String,
+    },
+
+    /// Move ~/.foundry to the XDG data directory and leave a symlink behind
+    Migrate {
+        /// Directory to move to (default: $XDG_DATA_HOME/foundry or ~/.local/share/foundry)
+        #[arg(long)]
+        to: Option<String>,
+    },
+}
+
+/// Arguments for search command
+#[derive(Args, Debug)]
+pub struct SearchArgs {
+    /// Words to search for in spec.md, notes.md and task-list.md
+    pub query: String,
+
+    /// Only search this project; every project when omitted
+    #[arg(long)]
+    pub project: Option<String>,
+
+    /// Most matches to show (default 20, maximum 100)
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for stale command
+#[derive(Args, Debug)]
+pub struct StaleArgs {
+    /// Only check this project; every project when omitted
+    #[arg(long)]
+    pub project: Option<String>,
+
+    /// Days without activity before a spec with open tasks counts as stale (default: the project's '[stale] days' setting, or 14)
+    #[arg(long)]
+    pub days: Option<u32>,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for digest command
+#[derive(Args, Debug)]
+pub struct DigestArgs {
+    /// Start of the range: a window back from now (24h, 7d), a date (YYYY-MM-DD) or an RFC 3339 timestamp
+    #[arg(long, default_value = "7d")]
+    pub since: String,
+
+    /// End of the range: a date (inclusive) or an RFC 3339 timestamp (default now)
+    #[arg(long)]
+    pub until: Option<String>,
+
+    /// Only summarize this project; every project when omitted
+    #[arg(long)]
+    pub project: Option<String>,
+
+    /// Print the markdown report instead of the text summary
+    #[arg(long)]
+    pub markdown: bool,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for stats command
+#[derive(Args, Debug)]
+pub struct StatsArgs {
+    /// Only include calls from this recent window
+    ///
+    /// A number followed by m (minutes), h (hours) or d (days), e.g. 24h or 7d
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Output JSON instead of text; set from the global `--json` flag or `FOUNDRY_OUTPUT=json`
+    #[arg(skip)]
+    pub json: bool,
+}
+
+/// Arguments for completions command
+#[derive(Args, Debug)]
+pub struct CompletionsArgs {
+    /// Shell to generate completions for
+    pub shell: clap_complete::Shell,
+}
+
+/// Arguments for the hidden `foundry __complete` hook called by completion scripts
+#[derive(Args, Debug)]
+pub struct CompleteArgs {
+    /// Word under the cursor, possibly empty
+    #[arg(long, default_value = "")]
+    pub current: String,
+
+    /// Command line words before the cursor, starting with the program name
+    #[arg(last = true)]
+    pub words: Vec<String>,
+}
@@ -0,0 +1,88 @@
+//! Implementation of the audit command
+
+use crate::cli::args::{AuditArgs, AuditCommand};
+use crate::core::ops::get_audit_log;
+use crate::types::responses::{AuditInterface, AuditLogResponse, AuditOutcome};
+use crate::utils::formatting::format_count;
+use anyhow::Result;
+use console::style;
+
+pub async fn execute(args: AuditArgs) -> Result<String> {
+    match args.command {
+        AuditCommand::Show {
+            project,
+            spec,
+            operation,
+            since,
+            limit,
+        } => {
+            let response = get_audit_log::run(get_audit_log::Input {
+                project_name: project,
+                spec_name: spec,
+                operation,
+                since,
+                limit: Some(limit),
+            })
+            .await?;
+            if args.json {
+                Ok(serde_json::to_string_pretty(&response.data)?)
+            } else {
+                Ok(format_show_output(&response.data))
+            }
+        }
+    }
+}
+
+fn format_show_output(response: &AuditLogResponse) -> String {
+    let mut output = vec![format!(
+        "{} {}",
+        style("Audit Log").bold().cyan(),
+        style(&response.project_name).bold()
+    )];
+    output.push(format!(
+        "{}",
+        style(format!(
+            "newest {} of {}",
+            response.entries.len(),
+            format_count(response.total_matching, "change", "changes")
+        ))
+        .dim()
+    ));
+    output.push(String::new());
+
+    if response.entries.is_empty() {
+        output.push("No changes recorded".to_string());
+        return output.join("\n");
+    }
+
+    for entry in &response.entries {
+        let interface = match entry.interface {
+            AuditInterface::Cli => "cli",
+            AuditInterface::Mcp => "mcp",
+            AuditInterface::Rest => "rest",
+        };
+        let outcome = match (&entry.outcome, &entry.error_code) {
+            (AuditOutcome::Ok, _) => style("✓".to_string()).green(),
+            (AuditOutcome::Error, code) => {
+                style(format!("✗ {}", code.as_deref().unwrap_or("ERROR"))).red()
+            }
+        };
+        let spec = entry
+            .spec_name
+            .as_ref()
+            .map(|spec_name| format!(" {}", spec_name))
+            .unwrap_or_default();
+        output.push(format!(
+            "{} {} {}{} {}",
+            style(&entry.timestamp).dim(),
+            style(format!("[{}]", interface)).yellow(),
+            style(&entry.operation).bold(),
+            spec,
+            outcome
+        ));
+        for command in &entry.commands {
+            output.push(format!("    {}", command));
+        }
+    }
+    output.join("\n")
+}
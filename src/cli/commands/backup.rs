@@ -0,0 +1,115 @@
+//! Implementation of the backup command
+
+use crate::cli::args::{BackupArgs, BackupCommand};
+use crate::core::{audit, backup, foundry};
+use crate::types::responses::{
+    BackupInfo, BackupListResponse, BackupPruneResponse, BackupRestoreResponse,
+};
+use anyhow::Result;
+use console::style;
+
+pub async fn execute(args: BackupArgs) -> Result<String> {
+    let dir = backup::default_dir()?;
+    let backup_dir = dir.display().to_string();
+
+    match args.command {
+        BackupCommand::List { project, spec } => {
+            let response = BackupListResponse {
+                backups: backup::list(&dir, project.as_deref(), spec.as_deref())?,
+                backup_dir,
+            };
+            if args.json {
+                Ok(serde_json::to_string_pretty(&response)?)
+            } else {
+                Ok(format_list_output(&response))
+            }
+        }
+        BackupCommand::Restore { id } => {
+            let snapshot = backup::read(&dir, &id)?;
+            let foundry = foundry::get_default_foundry()?;
+            let restored = backup::restore(&foundry, &dir, &snapshot).await?;
+            audit::record_call(
+                &snapshot.project_name,
+                "backup_restore",
+                &serde_json::json!({"spec_name": restored.spec_name}),
+                None,
+                None,
+            )?;
+            let response = BackupRestoreResponse {
+                backup: BackupInfo {
+                    id,
+                    project_name: snapshot.project_name,
+                    spec_name: snapshot.spec_name,
+                    reason: snapshot.reason,
+                    taken_at: snapshot.taken_at,
+                },
+                spec_name: restored.spec_name,
+                recreated: restored.recreated,
+                replaced_backup: restored.replaced_backup,
+            };
+            if args.json {
+                Ok(serde_json::to_string_pretty(&response)?)
+            } else {
+                Ok(format_restore_output(&response))
+            }
+        }
+        BackupCommand::Prune { keep } => {
+            let response = BackupPruneResponse {
+                removed: backup::prune(&dir, keep)?,
+                keep,
+                backup_dir,
+            };
+            if args.json {
+                Ok(serde_json::to_string_pretty(&response)?)
+            } else {
+                Ok(format!(
+                    "{} Removed {}, keeping the newest {} per spec",
+                    style("✓").green(),
+                    crate::utils::formatting::format_count(response.removed, "backup", "backups"),
+                    response.keep
+                ))
+            }
+        }
+    }
+}
+
+fn format_list_output(response: &BackupListResponse) -> String {
+    let mut output = vec![format!("{}", style("Spec Backups").bold().cyan())];
+    output.push(format!("{}", style(&response.backup_dir).dim()));
+    output.push(String::new());
+
+    if response.backups.is_empty() {
+        output.push("No backups".to_string());
+        return output.join("\n");
+    }
+
+    for backup in &response.backups {
+        output.push(backup.id.clone());
+        output.push(format!(
+            "    {} before {}",
+            backup.taken_at,
+            style(&backup.reason).yellow()
+        ));
+    }
+    output.join("\n")
+}
+
+fn format_restore_output(response: &BackupRestoreResponse) -> String {
+    let mut output = vec![format!(
+        "{} Restored {}/{} from backup taken {}",
+        style("✓").green(),
+        response.backup.project_name,
+        style(&response.spec_name).bold(),
+        response.backup.taken_at
+    )];
+    if response.recreated {
+        output.push(format!(
+            "    {} was deleted, so it was recreated as {}",
+            response.backup.spec_name, response.spec_name
+        ));
+    }
+    if let Some(replaced) = &response.replaced_backup {
+        output.push(format!("    Previous content backed up as {}", replaced.id));
+    }
+    output.join("\n")
+}
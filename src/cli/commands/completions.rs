@@ -0,0 +1,281 @@
+//! Implementation of the completions command
+//!
+//! Scripts are generated by clap_complete from the command definition. For
+//! bash, zsh, fish and PowerShell they are extended to ask the hidden
+//! `foundry __complete` hook for project and spec names, read from the
+//! filesystem backend, whenever the cursor is on a project or spec argument.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use clap::{Arg, Command};
+use clap_complete::Shell;
+
+use crate::cli::args::{CompleteArgs, CompletionsArgs};
+use crate::core::backends::FoundryBackend;
+use crate::core::backends::filesystem::FilesystemBackend;
+
+/// Argument ids completed with project names
+const PROJECT_ARGS: &[&str] = &["project", "project_name", "other_project_name"];
+
+/// Argument ids completed with spec names, with the argument naming their project
+const SPEC_ARGS: &[(&str, &[&str])] = &[
+    ("spec", &["project", "project_name"]),
+    ("spec_name", &["project_name", "project"]),
+    ("other_spec_name", &["other_project_name", "project_name"]),
+];
+
+pub fn execute(args: CompletionsArgs, mut command: Command) -> Result<String> {
+    let name = command.get_name().to_string();
+    let mut buf = Vec::new();
+    clap_complete::generate(args.shell, &mut command, &name, &mut buf);
+    let script = String::from_utf8(buf)?;
+    Ok(with_dynamic_names(args.shell, &name, script))
+}
+
+/// Names to offer for the word under the cursor, one per line; empty when the
+/// cursor is not on a project or spec argument
+pub async fn complete(args: CompleteArgs, command: Command) -> Result<String> {
+    let backend = FilesystemBackend::new();
+    let current = args.current.as_str();
+    let names: Vec<String> = match target(command, &args.words, current) {
+        Some(Target::Project) => backend
+            .list_projects()
+            .await?
+            .into_iter()
+            .map(|project| project.name)
+            .filter(|name| name.starts_with(current))
+            .collect(),
+        Some(Target::Spec {
+            project_name: Some(project_name),
+        }) => backend
+            .list_specs(&project_name)
+            .await?
+            .into_iter()
+            // Timestamped names are matched by their feature part too
+            .filter(|spec| spec.name.starts_with(current) || spec.feature_name.starts_with(current))
+            .map(|spec| spec.name)
+            .collect(),
+        Some(Target::Spec { project_name: None }) | None => Vec::new(),
+    };
+    Ok(names.join("\n"))
+}
+
+/// What the word under the cursor names
+#[derive(Debug, PartialEq)]
+pub enum Target {
+    Project,
+    Spec { project_name: Option<String> },
+}
+
+/// Find the argument the cursor is on by walking `words` (starting with the
+/// program name) through `command`'s subcommands, options and positionals
+pub fn target(mut command: Command, words: &[String], current: &str) -> Option<Target> {
+    command.build();
+    let mut command = &command;
+    let mut values: HashMap<String, String> = HashMap::new();
+    let mut positional = 0;
+    let mut pending: Option<&Arg> = None;
+
+    for word in words.iter().skip(1) {
+        // bash splits `--project=name` around the `=`
+        if word == "=" {
+            continue;
+        }
+        if let Some(arg) = pending.take() {
+            values.insert(arg.get_id().to_string(), word.clone());
+            continue;
+        }
+        if let Some(long) = word.strip_prefix("--") {
+            let (name, value) = long
+                .split_once('=')
+                .map_or((long, None), |(n, v)| (n, Some(v)));
+            let arg = command
+                .get_arguments()
+                .find(|arg| arg.get_long() == Some(name))
+                .filter(|arg| arg.get_action().takes_values());
+            match (arg, value) {
+                (Some(arg), Some(value)) => {
+                    values.insert(arg.get_id().to_string(), value.to_string());
+                }
+                (Some(arg), None) => pending = Some(arg),
+                (None, _) => {}
+            }
+            continue;
+        }
+        if let Some(short) = word.strip_prefix('-').filter(|short| !short.is_empty()) {
+            pending = command
+                .get_arguments()
+                .find(|arg| short.chars().next() == arg.get_short())
+                .filter(|arg| short.len() == 1 && arg.get_action().takes_values());
+            continue;
+        }
+        if let Some(subcommand) = command.find_subcommand(word) {
+            command = subcommand;
+            positional = 0;
+            continue;
+        }
+        if let Some(arg) = command.get_positionals().nth(positional) {
+            values.insert(arg.get_id().to_string(), word.clone());
+        }
+        positional += 1;
+    }
+
+    let arg = match pending {
+        Some(arg) => arg,
+        None if current.starts_with('-') => return None,
+        None => command.get_positionals().nth(positional)?,
+    };
+    let id = arg.get_id().as_str();
+    if PROJECT_ARGS.contains(&id) {
+        return Some(Target::Project);
+    }
+    SPEC_ARGS
+        .iter()
+        .find(|(spec_arg, _)| *spec_arg == id)
+        .map(|(_, project_args)| Target::Spec {
+            project_name: project_args
+                .iter()
+                .find_map(|project_arg| values.get(*project_arg).cloned()),
+        })
+}
+
+/// Hook the `__complete` call into a generated script
+///
+/// The generated completion function is renamed to `_<name>_static` and
+/// wrapped, so installing the script as a file or sourcing it both pick up
+/// dynamic names. Elvish scripts are returned unchanged.
+fn with_dynamic_names(shell: Shell, name: &str, script: String) -> String {
+    let function = format!("_{}() {{", name);
+    let renamed = format!("_{}_static() {{", name);
+    match shell {
+        Shell::Bash if script.contains(&function) => script.replacen(
+            &function,
+            &format!(
+                r#"_{name}() {{
+    local candidates
+    candidates="$({name} __complete --current="${{COMP_WORDS[COMP_CWORD]}}" -- "${{COMP_WORDS[@]:0:COMP_CWORD}}" 2>/dev/null)"
+    if [[ -n "${{candidates}}" ]]; then
+        local IFS=$'\n'
+        COMPREPLY=( ${{candidates}} )
+        return 0
+    fi
+    _{name}_static "$@"
+}}
+
+{renamed}"#
+            ),
+            1,
+        ),
+        Shell::Zsh if script.contains(&function) => script.replacen(
+            &function,
+            &format!(
+                r#"_{name}() {{
+    local -a candidates
+    candidates=("${{(@f)$({name} __complete --current="${{words[CURRENT]}}" -- "${{(@)words[1,CURRENT-1]}}" 2>/dev/null)}}")
+    if [[ -n "${{candidates[1]}}" ]]; then
+        compadd -U -- "${{candidates[@]}}"
+    else
+        _{name}_static "$@"
+    fi
+}}
+
+{renamed}"#
+            ),
+            1,
+        ),
+        Shell::Fish => format!(
+            "{script}complete -c {name} -a '({name} __complete --current=(commandline -ct) -- (commandline -opc) 2>/dev/null)'\n"
+        ),
+        Shell::PowerShell => {
+            let param = "    param($wordToComplete, $commandAst, $cursorPosition)\n";
+            script.replacen(
+                param,
+                &format!(
+                    r#"{param}
+    $words = @($commandAst.CommandElements |
+        Where-Object {{ $_.Extent.EndOffset -lt $cursorPosition }} |
+        ForEach-Object {{ $_.ToString() }})
+    $names = @({name} __complete "--current=$wordToComplete" '--' @words 2>$null)
+    if ($names.Count -gt 0) {{
+        return $names | ForEach-Object {{
+            [CompletionResult]::new($_, $_, [CompletionResultType]::ParameterValue, $_)
+        }}
+    }}
+"#
+                ),
+                1,
+            )
+        }
+        _ => script,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{Parser, Subcommand};
+
+    #[derive(Parser)]
+    #[command(name = "foundry")]
+    struct Cli {
+        #[command(subcommand)]
+        command: Commands,
+
+        #[arg(long, global = true)]
+        backend: Option<String>,
+    }
+
+    #[derive(Subcommand)]
+    enum Commands {
+        Export(crate::cli::args::ExportArgs),
+        Audit(crate::cli::args::AuditArgs),
+        Backup(crate::cli::args::BackupArgs),
+    }
+
+    fn target_of(line: &str, current: &str) -> Option<Target> {
+        use clap::CommandFactory;
+        let words: Vec<String> = line.split_whitespace().map(str::to_string).collect();
+        target(Cli::command(), &words, current)
+    }
+
+    #[test]
+    fn test_target_follows_subcommands_and_options() {
+        assert_eq!(target_of("foundry export", ""), Some(Target::Project));
+        assert_eq!(target_of("foundry export my-app", ""), None);
+        assert_eq!(
+            target_of("foundry --backend git audit show", "my"),
+            Some(Target::Project)
+        );
+        assert_eq!(
+            target_of("foundry audit show my-app --spec", ""),
+            Some(Target::Spec {
+                project_name: Some("my-app".to_string())
+            })
+        );
+        assert_eq!(
+            target_of("foundry backup list --project=my-app --spec", ""),
+            Some(Target::Spec {
+                project_name: Some("my-app".to_string())
+            })
+        );
+        assert_eq!(
+            target_of("foundry backup list --spec", ""),
+            Some(Target::Spec { project_name: None })
+        );
+        assert_eq!(target_of("foundry backup list", "--"), None);
+        assert_eq!(target_of("foundry backup restore", ""), None);
+    }
+
+    #[test]
+    fn test_scripts_call_the_hook() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+            use clap::CommandFactory;
+            let script = execute(CompletionsArgs { shell }, Cli::command()).unwrap();
+            assert!(script.contains("foundry __complete"), "{shell}");
+            if matches!(shell, Shell::Bash | Shell::Zsh) {
+                assert!(script.contains("_foundry_static() {"), "{shell}");
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Implementation of the digest command
+
+use crate::cli::args::DigestArgs;
+use crate::core::ops::digest;
+use crate::types::responses::DigestResponse;
+use crate::utils::formatting::format_count;
+use anyhow::Result;
+use console::style;
+
+pub async fn execute(args: DigestArgs) -> Result<String> {
+    let response = digest::run(digest::Input {
+        since: Some(args.since),
+        until: args.until,
+        project_name: args.project,
+    })
+    .await?;
+
+    if args.json {
+        Ok(serde_json::to_string_pretty(&response.data)?)
+    } else if args.markdown {
+        Ok(response.data.markdown)
+    } else {
+        Ok(format_digest_output(&response.data))
+    }
+}
+
+fn format_digest_output(response: &DigestResponse) -> String {
+    let day = |value: &str| value.get(..10).unwrap_or(value).to_string();
+    let mut output = vec![format!("{}", style("Foundry Digest").bold().cyan())];
+    output.push(format!(
+        "{}",
+        style(format!(
+            "{} to {} ({} checked)",
+            day(&response.since),
+            day(&response.until),
+            format_count(response.projects_checked, "project", "projects")
+        ))
+        .dim()
+    ));
+    output.push(String::new());
+
+    if response.projects.is_empty() {
+        output.push(format!("{} No activity in this range", style("·").dim()));
+        return output.join("\n");
+    }
+
+    output.push(format!(
+        "{} created, {} completed, {}",
+        format_count(response.specs_created, "spec", "specs"),
+        format_count(response.tasks_completed, "task", "tasks"),
+        format_count(response.notes_added, "notes update", "notes updates")
+    ));
+    for project in &response.projects {
+        output.push(String::new());
+        output.push(format!("{}", style(&project.project_name).bold()));
+        for spec in &project.specs_created {
+            output.push(format!("  {} created {}", style("+").green(), spec));
+        }
+        for task in &project.tasks_completed {
+            output.push(format!(
+                "  {} {} ({})",
+                style("✓").green(),
+                task.task,
+                task.spec_name
+            ));
+        }
+        for note in &project.notes_added {
+            output.push(format!(
+                "  {} notes on {}: {}",
+                style("✎").blue(),
+                note.spec_name,
+                format_count(note.lines_added, "line", "lines")
+            ));
+        }
+    }
+
+    output.join("\n")
+}
@@ -0,0 +1,81 @@
+//! Implementation of the doctor command
+
+use crate::cli::args::DoctorArgs;
+use crate::core::doctor;
+use crate::types::responses::{DoctorResponse, DoctorStatus};
+use crate::utils::formatting::format_count;
+use anyhow::Result;
+use console::style;
+
+pub async fn execute(args: DoctorArgs) -> Result<String> {
+    let response = doctor::run(args.fix).await?;
+    if args.json {
+        Ok(serde_json::to_string_pretty(&response)?)
+    } else {
+        Ok(format_doctor_output(&response, args.fix))
+    }
+}
+
+fn format_doctor_output(response: &DoctorResponse, fix: bool) -> String {
+    let mut output = vec![format!("{}", style("Foundry Doctor").bold().cyan())];
+    output.push(format!("{}", style(&response.foundry_dir).dim()));
+
+    let mut category = "";
+    for check in &response.checks {
+        if check.category != category {
+            category = &check.category;
+            output.push(String::new());
+            output.push(format!("{}", style(category).bold()));
+        }
+        let marker = match (check.fixed, check.status) {
+            (true, _) | (false, DoctorStatus::Ok) => style("✓").green(),
+            (false, DoctorStatus::Warning) => style("!").yellow(),
+            (false, DoctorStatus::Error) => style("✗").red(),
+        };
+        output.push(format!("{} {}: {}", marker, check.subject, check.message));
+        if check.fixed {
+            output.push(format!("    {}", style("fixed").green()));
+        } else if let Some(fix) = &check.fix {
+            let auto = if check.fixable && !fix_requested(fix) {
+                " (foundry doctor --fix)"
+            } else {
+                ""
+            };
+            output.push(format!("    {} {}{}", style("→").dim(), fix, auto));
+        }
+    }
+
+    output.push(String::new());
+    let fixable = response
+        .checks
+        .iter()
+        .filter(|check| check.fixable && !check.fixed)
+        .count();
+    if response.errors == 0 && response.warnings == 0 {
+        output.push(format!("{} No problems found", style("✓").green()));
+    } else {
+        output.push(format!(
+            "{}, {}",
+            format_count(response.errors, "error", "errors"),
+            format_count(response.warnings, "warning", "warnings")
+        ));
+    }
+    if response.fixed > 0 {
+        output.push(format!(
+            "Fixed {}",
+            format_count(response.fixed, "problem", "problems")
+        ));
+    }
+    if fixable > 0 && !fix {
+        output.push(format!(
+            "Run `foundry doctor --fix` to repair {} of them",
+            fixable
+        ));
+    }
+    output.join("\n")
+}
+
+/// Whether the suggested fix is already a command to run
+fn fix_requested(fix: &str) -> bool {
+    fix.starts_with("foundry ")
+}
@@ -0,0 +1,73 @@
+//! Implementation of the encryption command
+
+use crate::cli::args::{EncryptionArgs, EncryptionCommand};
+use crate::core::{encryption, filesystem};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{EncryptionKeygenResponse, EncryptionResponse};
+use crate::utils::{formatting, paths};
+use anyhow::Result;
+use console::style;
+
+pub async fn execute(args: EncryptionArgs) -> Result<String> {
+    match args.command {
+        EncryptionCommand::Keygen { path } => {
+            let key_file = encryption::generate_key_file(&paths::expand_home(&path))?;
+            let response = EncryptionKeygenResponse {
+                config: format!(
+                    "[encryption]\nkey_file = {:?}\n",
+                    key_file.display().to_string()
+                ),
+                key_file: key_file.display().to_string(),
+            };
+            if args.json {
+                Ok(serde_json::to_string_pretty(&response)?)
+            } else {
+                Ok(format!(
+                    "{} Wrote a new key to {}\n\nAdd this to ~/.foundry/config.toml, then run `foundry encryption encrypt`:\n\n{}\n{}",
+                    style("✓").green(),
+                    response.key_file,
+                    response.config,
+                    style("Keep a copy of the key somewhere safe: encrypted files cannot be read without it.").yellow()
+                ))
+            }
+        }
+        EncryptionCommand::Encrypt => convert(args.json, true),
+        EncryptionCommand::Decrypt => convert(args.json, false),
+    }
+}
+
+fn convert(json: bool, encrypt: bool) -> Result<String> {
+    let key = encryption::configured_key()?.ok_or_else(|| {
+        ErrorCode::InvalidParams.error(
+            "No encryption key configured. Set [encryption] key_file or passphrase_env in ~/.foundry/config.toml",
+        )
+    })?;
+    let dir = filesystem::foundry_dir()?;
+    let changed = if encrypt {
+        encryption::encrypt_all(&dir, &key)?
+    } else {
+        encryption::decrypt_all(&dir, &key)?
+    };
+    let response = EncryptionResponse {
+        foundry_dir: dir.display().to_string(),
+        files: changed
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&dir)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect(),
+    };
+    if json {
+        return Ok(serde_json::to_string_pretty(&response)?);
+    }
+    Ok(format!(
+        "{} {} {} in {}",
+        style("✓").green(),
+        if encrypt { "Encrypted" } else { "Decrypted" },
+        formatting::format_count(response.files.len(), "file", "files"),
+        response.foundry_dir
+    ))
+}
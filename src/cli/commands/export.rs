@@ -0,0 +1,40 @@
+//! Implementation of the export command
+
+use crate::cli::args::ExportArgs;
+use crate::core::ops::export_project;
+use crate::types::responses::ExportProjectResponse;
+use anyhow::Result;
+use console::style;
+
+pub async fn execute(args: ExportArgs) -> Result<String> {
+    let response = export_project::run(export_project::Input {
+        project_name: args.project_name,
+        output_path: args.output,
+        overwrite: args.overwrite,
+    })
+    .await?;
+
+    if args.json {
+        Ok(serde_json::to_string_pretty(&response.data)?)
+    } else {
+        Ok(format_export_output(&response.data))
+    }
+}
+
+fn format_export_output(response: &ExportProjectResponse) -> String {
+    [
+        format!(
+            "{} Exported {}",
+            style("✓").green(),
+            style(&response.project_name).bold()
+        ),
+        format!(
+            "    {}, {} files, {} bytes",
+            crate::utils::formatting::format_count(response.spec_count, "spec", "specs"),
+            response.file_count,
+            response.bytes
+        ),
+        format!("    {}", style(&response.archive_path).cyan()),
+    ]
+    .join("\n")
+}
@@ -43,6 +43,7 @@ pub async fn execute(args: GetFoundryHelpArgs) -> Result<FoundryResponse<GetFoun
         next_steps,
         validation_status: ValidationStatus::Complete,
         workflow_hints,
+        diagnostics: None,
     })
 }
 
@@ -308,6 +309,7 @@ pub fn create_project_structure_help() -> HelpContent {
             "├── vision.md      # High-level product vision and roadmap".to_string(),
             "├── tech-stack.md  # Technology choices and architecture decisions".to_string(),
             "├── summary.md     # Concise summary for quick context loading".to_string(),
+            "├── decisions.md   # Project decision log (add_decision), created with the first decision".to_string(),
             "└── specs/".to_string(),
             "    ├── 20250823_143052_user_auth/".to_string(),
             "    │   ├── spec.md        # Feature specification and requirements".to_string(),
@@ -326,6 +328,7 @@ pub fn create_project_structure_help() -> HelpContent {
             "Spec files contain feature-specific implementation guidance".to_string(),
             "Task-list.md serves as living checklist - update as work progresses".to_string(),
             "Notes.md captures design decisions and context for future reference".to_string(),
+            "Decisions that outlive one spec belong in decisions.md: record them with add_decision".to_string(),
         ],
     }
 }
@@ -467,8 +470,8 @@ pub fn create_edit_commands_help() -> HelpContent {
         description: "Use update_spec with a 'commands' array to perform comprehensive content management "
             .to_string()
             + "with precise targeting and idempotent updates. Each command requires: target (spec|tasks|notes), "
-            + "command (set_task_status|upsert_task|append_to_section|remove_list_item|remove_from_section|remove_section|replace_list_item|replace_in_section|replace_section_content), "
-            + "selector (section|task_text|text_in_section), and required fields (status for set_task_status, content for others).",
+            + "command (set_task_status|upsert_task|append_to_section|remove_list_item|remove_from_section|remove_section|replace_list_item|replace_in_section|replace_section_content|add_dependency|remove_dependency|add_phase|move_task_to_phase|set_tasks_status_matching|complete_phase), "
+            + "selector (section|task_text|text_in_section|spec_name|task_pattern), and required fields (status for set_task_status/set_tasks_status_matching, content for others).",
         examples: vec![
             "# TASK MANAGEMENT COMMANDS".to_string(),
             "".to_string(),
@@ -558,13 +561,15 @@ pub fn create_edit_commands_help() -> HelpContent {
             "# SELECTOR TYPES REFERENCE".to_string(),
             "".to_string(),
             "# section: Case-insensitive header matching (## Requirements)".to_string(),
-            "# task_text: Normalized task text (ignores checkbox, whitespace, periods)".to_string(),
+            "# task_text: Normalized task text (ignores checkbox, whitespace, periods; metadata tokens optional)".to_string(),
             "# text_in_section: Precise text within specific section".to_string(),
+            "# spec_name: Exact name of another spec in the project (dependency commands)".to_string(),
+            "# task_pattern: Glob (*, ?) over the whole task text, or /regex/; optional section limits it to one heading".to_string(),
             "".to_string(),
             "# COMMAND RESTRICTIONS".to_string(),
             "".to_string(),
             "# set_task_status: tasks target only, requires status field".to_string(),
-            "# upsert_task: tasks target only, requires content field".to_string(),
+            "# upsert_task: tasks target only, requires content field; updates the metadata tokens (@owner ~3d due:YYYY-MM-DD) of an existing task".to_string(),
             "# append_to_section: spec/notes targets only, requires content field".to_string(),
             "# remove_list_item: any target, no additional fields".to_string(),
             "# remove_from_section: spec/notes targets only, requires content field".to_string(),
@@ -572,6 +577,11 @@ pub fn create_edit_commands_help() -> HelpContent {
             "# replace_list_item: any target, requires content field".to_string(),
             "# replace_in_section: spec/notes targets only, requires content field".to_string(),
             "# replace_section_content: spec/notes targets only, requires content field".to_string(),
+            "# add_dependency/remove_dependency: spec target only, spec_name selector, no additional fields".to_string(),
+            "# add_phase: tasks target only, section selector naming the phase (e.g. 'Phase 2: Rollout'), optional content with its first tasks".to_string(),
+            "# move_task_to_phase: tasks target only, task_text selector, content names the phase (e.g. 'Phase 2')".to_string(),
+            "# set_tasks_status_matching: tasks target only, requires status; task_pattern selector or section selector (every task under the heading)".to_string(),
+            "# complete_phase: tasks target only, section selector naming the phase; marks all its tasks done (or status, if given)".to_string(),
         ],
         workflow_guide: vec![
             "CRITICAL: Always load current content before editing; copy exact task text and section headers"
@@ -0,0 +1,58 @@
+//! Implementation of the import command
+
+use crate::cli::args::ImportArgs;
+use crate::core::audit;
+use crate::core::ops::import_archive;
+use crate::types::responses::ImportArchiveResponse;
+use anyhow::Result;
+use console::style;
+
+pub async fn execute(args: ImportArgs) -> Result<String> {
+    let response = import_archive::run(import_archive::Input {
+        archive_path: args.archive_path,
+        project_name: args.project_name,
+    })
+    .await?;
+    audit::record_call(
+        &response.data.project_name,
+        "import_archive",
+        &serde_json::json!({}),
+        None,
+        None,
+    )?;
+
+    if args.json {
+        Ok(serde_json::to_string_pretty(&response.data)?)
+    } else {
+        Ok(format_import_output(&response.data))
+    }
+}
+
+fn format_import_output(response: &ImportArchiveResponse) -> String {
+    let mut output = vec![format!(
+        "{} Imported {} from {}",
+        style("✓").green(),
+        style(&response.project_name).bold(),
+        response.archive_path
+    )];
+    output.push(format!(
+        "    {}, exported {}",
+        crate::utils::formatting::format_count(response.specs.len(), "spec", "specs"),
+        response.exported_at
+    ));
+    for spec in &response.specs {
+        output.push(format!(
+            "    {} {} {}",
+            spec.original_name,
+            style("→").dim(),
+            spec.spec_name
+        ));
+    }
+    if response.links_rewritten > 0 {
+        output.push(format!(
+            "    {} updated",
+            crate::utils::formatting::format_count(response.links_rewritten, "link", "links")
+        ));
+    }
+    output.join("\n")
+}
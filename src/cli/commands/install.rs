@@ -2,16 +2,42 @@
 
 use crate::{
     cli::args::InstallArgs,
+    core::config,
     core::installation,
+    core::templates::render::{self, TemplateContext},
     types::responses::{InstallResponse, InstallationStatus},
     utils::formatting::format_install_output,
 };
 use anyhow::Result;
+use std::path::PathBuf;
 
 pub async fn execute(args: InstallArgs) -> Result<String> {
     // Validate installation target
     validate_target(&args.target)?;
 
+    let templates_dir = match &args.templates_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => render::default_dir()?,
+    };
+    let config = config::global()?;
+    let context = TemplateContext::load(&templates_dir)?
+        .with_defaults([
+            (
+                "binary_path",
+                args.binary_path
+                    .clone()
+                    .unwrap_or_else(|| "foundry".to_string()),
+            ),
+            (
+                "default_spec_template",
+                config.templates.spec.clone().unwrap_or_default(),
+            ),
+            ("validation_profile", config.profile().name().to_string()),
+        ])
+        .with_default_partials(&config.templates.guidance)
+        .with_assignments(&args.vars)?;
+    render::init(context);
+
     // Handle installation and response building in a single match statement
     let (result, binary_path) = match args.target.as_str() {
         "claude-code" => {
@@ -28,9 +54,27 @@ pub async fn execute(args: InstallArgs) -> Result<String> {
                 .map_err(|e| enhance_installation_error("cursor", &e))?;
             (result, "foundry (from PATH)".to_string())
         }
+        "vscode" => {
+            let result = installation::install_for_vscode()
+                .await
+                .map_err(|e| enhance_installation_error("vscode", &e))?;
+            (result, "foundry (from PATH)".to_string())
+        }
+        "windsurf" => {
+            let result = installation::install_for_windsurf()
+                .await
+                .map_err(|e| enhance_installation_error("windsurf", &e))?;
+            (result, "foundry (from PATH)".to_string())
+        }
+        "zed" => {
+            let result = installation::install_for_zed()
+                .await
+                .map_err(|e| enhance_installation_error("zed", &e))?;
+            (result, "foundry (from PATH)".to_string())
+        }
         _ => {
             return Err(anyhow::anyhow!(
-                "Unsupported installation target: {}. Supported targets: claude-code, cursor",
+                "Unsupported installation target: {}. Supported targets: claude-code, cursor, vscode, windsurf, zed",
                 args.target
             ));
         }
@@ -68,9 +112,9 @@ pub async fn execute(args: InstallArgs) -> Result<String> {
 /// Validate the installation target
 fn validate_target(target: &str) -> Result<()> {
     match target {
-        "claude-code" | "cursor" => Ok(()),
+        "claude-code" | "cursor" | "vscode" | "windsurf" | "zed" => Ok(()),
         _ => Err(anyhow::anyhow!(
-            "Unsupported installation target: {}. Supported targets: claude-code, cursor",
+            "Unsupported installation target: {}. Supported targets: claude-code, cursor, vscode, windsurf, zed",
             target
         )),
     }
@@ -167,7 +211,7 @@ mod tests {
 
     #[test]
     fn test_validate_target_valid() {
-        let valid_targets = vec!["claude-code", "cursor"];
+        let valid_targets = vec!["claude-code", "cursor", "vscode", "windsurf", "zed"];
 
         for target in valid_targets {
             assert!(
@@ -180,7 +224,7 @@ mod tests {
 
     #[test]
     fn test_validate_target_invalid() {
-        let invalid_targets = vec!["", "vscode", "claude-desktop"];
+        let invalid_targets = vec!["", "vs-code", "claude-desktop"];
 
         for target in invalid_targets {
             assert!(
@@ -196,6 +240,8 @@ mod tests {
         let args = InstallArgs {
             target: "invalid-target".to_string(),
             binary_path: None,
+            vars: Vec::new(),
+            templates_dir: None,
             json: false,
         };
 
@@ -215,6 +261,8 @@ mod tests {
         let args = InstallArgs {
             target: "claude-code".to_string(),
             binary_path: Some("/custom/path/foundry".to_string()),
+            vars: Vec::new(),
+            templates_dir: None,
             json: false,
         };
 
@@ -227,6 +275,8 @@ mod tests {
         let args = InstallArgs {
             target: "cursor".to_string(),
             binary_path: None,
+            vars: Vec::new(),
+            templates_dir: None,
             json: false,
         };
 
@@ -261,6 +311,8 @@ mod tests {
             let args = InstallArgs {
                 target: "cursor".to_string(),
                 binary_path: Some(binary_path.to_string_lossy().to_string()),
+                vars: Vec::new(),
+                templates_dir: None,
                 json: false,
             };
 
@@ -285,6 +337,8 @@ mod tests {
             let _args = InstallArgs {
                 target: "cursor".to_string(),
                 binary_path: Some(binary_path.to_string_lossy().to_string()),
+                vars: Vec::new(),
+                templates_dir: None,
                 json: false,
             };
 
@@ -305,7 +359,7 @@ mod tests {
     #[test]
     fn test_validate_target_comprehensive() {
         // Test all supported targets
-        let supported_targets = vec!["claude-code", "cursor"];
+        let supported_targets = vec!["claude-code", "cursor", "vscode", "windsurf", "zed"];
         for target in supported_targets {
             assert!(
                 validate_target(target).is_ok(),
@@ -317,7 +371,7 @@ mod tests {
         // Test unsupported targets
         let unsupported_targets = vec![
             "",
-            "vscode",
+            "vs-code",
             "claude-desktop",
             "intellij",
             "vim",
@@ -350,6 +404,8 @@ mod tests {
         let args1 = InstallArgs {
             target: "claude-code".to_string(),
             binary_path: None,
+            vars: Vec::new(),
+            templates_dir: None,
             json: false,
         };
         assert_eq!(args1.target, "claude-code");
@@ -358,6 +414,8 @@ mod tests {
         let args2 = InstallArgs {
             target: "cursor".to_string(),
             binary_path: Some("/custom/path".to_string()),
+            vars: Vec::new(),
+            templates_dir: None,
             json: true,
         };
         assert_eq!(args2.target, "cursor");
@@ -1,6 +1,21 @@
 //! Individual command implementations
 
+pub mod audit;
+pub mod backup;
+pub mod completions;
+pub mod digest;
+pub mod doctor;
+pub mod encryption;
+pub mod export;
 pub mod get_foundry_help;
+pub mod import;
 pub mod install;
+pub mod replay;
+pub mod schema;
+pub mod search;
+pub mod stale;
+pub mod stats;
 pub mod status;
+pub mod tui;
 pub mod uninstall; // kept for help content builders used by ops
+pub mod workspace;
@@ -0,0 +1,264 @@
+//! Implementation of the replay command
+
+use crate::cli::args::ReplayArgs;
+use crate::core::backends::memory::InMemoryBackend;
+use crate::core::backup;
+use crate::core::foundry;
+use crate::mcp::tools::FoundryTools;
+use crate::mcp::wire_log::{self, WireLogEntry};
+use crate::types::responses::{ReplayResponse, ReplayStep};
+use anyhow::Result;
+use console::style;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+pub async fn execute(args: ReplayArgs) -> Result<String> {
+    let source = Path::new(&args.wire_log);
+    let files = if source.is_dir() {
+        wire_log::log_files(source)?
+    } else {
+        vec![source.to_path_buf()]
+    };
+
+    let mut entries = Vec::new();
+    for path in &files {
+        entries.extend(wire_log::read_entries(path)?);
+    }
+
+    let response_data = replay(&entries, args.wire_log.clone(), args.stop_on_divergence).await;
+
+    if args.json {
+        Ok(serde_json::to_string_pretty(&response_data)?)
+    } else {
+        Ok(format_replay_output(&response_data))
+    }
+}
+
+/// Re-run `entries` in order against a fresh in-memory backend
+async fn replay(
+    entries: &[WireLogEntry],
+    source: String,
+    stop_on_divergence: bool,
+) -> ReplayResponse {
+    let backend = Arc::new(InMemoryBackend::new());
+    let mut steps = Vec::new();
+    let mut stopped_early = false;
+
+    // Backups are off too, so replayed deletes never write to ~/.foundry
+    let replayed = foundry::with_backend(backend, async {
+        // Spec names embed a creation timestamp, so a replayed create_spec names
+        // its spec differently; later calls are rewritten to the replayed name
+        let mut renamed_specs: HashMap<String, String> = HashMap::new();
+
+        for (position, entry) in entries.iter().enumerate() {
+            let params = rename_values(&entry.params, &renamed_specs);
+            let outcome = FoundryTools::dispatch(&entry.tool, &params).await;
+
+            if matches!(entry.tool.as_str(), "create_spec" | "rename_spec")
+                && let (Some(recorded), Ok(replayed)) =
+                    (entry.response.as_ref().and_then(spec_name_of), &outcome)
+                && let Some(replayed) = spec_name_of(replayed)
+                && recorded != replayed
+            {
+                renamed_specs.insert(recorded, replayed);
+            }
+
+            let replayed_outcome = if outcome.is_ok() { "ok" } else { "error" };
+            let matches = replayed_outcome == entry.outcome;
+            steps.push(ReplayStep {
+                index: position + 1,
+                request_id: entry.request_id.clone(),
+                tool: entry.tool.clone(),
+                recorded_outcome: entry.outcome.clone(),
+                replayed_outcome: replayed_outcome.to_string(),
+                matches,
+                error: outcome.err().map(|e| e.to_string()),
+                note: fidelity_note(&entry.params),
+            });
+
+            if !matches && stop_on_divergence {
+                stopped_early = position + 1 < entries.len();
+                break;
+            }
+        }
+    });
+    backup::with_backup_dir(None, replayed).await;
+
+    let matched = steps.iter().filter(|step| step.matches).count();
+    ReplayResponse {
+        source,
+        total_calls: steps.len(),
+        matched,
+        diverged: steps.len() - matched,
+        stopped_early,
+        steps,
+    }
+}
+
+/// `data.spec_name` of a create_spec or rename_spec response
+fn spec_name_of(response: &Value) -> Option<String> {
+    response["data"]["spec_name"].as_str().map(str::to_string)
+}
+
+/// Copy of `value` with every string equal to a key of `renames` replaced
+fn rename_values(value: &Value, renames: &HashMap<String, String>) -> Value {
+    match value {
+        Value::String(s) => renames
+            .get(s)
+            .map_or_else(|| value.clone(), |renamed| Value::String(renamed.clone())),
+        Value::Array(items) => {
+            Value::Array(items.iter().map(|v| rename_values(v, renames)).collect())
+        }
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, v)| (key.clone(), rename_values(v, renames)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Note for params the wire log did not record verbatim
+fn fidelity_note(params: &Value) -> Option<String> {
+    let recorded = params.to_string();
+    let mut reasons = Vec::new();
+    if recorded.contains("[REDACTED]") {
+        reasons.push("redacted");
+    }
+    if recorded.contains("… [truncated") {
+        reasons.push("truncated");
+    }
+    (!reasons.is_empty()).then(|| {
+        format!(
+            "Params were {} in the wire log; the replayed call may differ",
+            reasons.join(" and ")
+        )
+    })
+}
+
+fn format_replay_output(response: &ReplayResponse) -> String {
+    let mut output = vec![format!("{}", style("Foundry Replay").bold().cyan())];
+    output.push(format!(
+        "{}",
+        style(format!("{} (in-memory backend)", response.source)).dim()
+    ));
+    output.push(String::new());
+
+    if response.total_calls == 0 {
+        output.push("No tool calls found in the wire log.".to_string());
+        return output.join("\n");
+    }
+
+    for step in &response.steps {
+        let marker = if step.matches {
+            style("✓").green()
+        } else {
+            style("✗").red()
+        };
+        output.push(format!(
+            "{} {:>3}. {:<20} recorded {:<5} replayed {}",
+            marker, step.index, step.tool, step.recorded_outcome, step.replayed_outcome
+        ));
+        if let Some(error) = &step.error {
+            output.push(format!("       {}", style(error).dim()));
+        }
+        if let Some(note) = &step.note {
+            output.push(format!("       {}", style(note).yellow()));
+        }
+    }
+
+    output.push(String::new());
+    let summary = format!(
+        "Replayed {} calls: {} matched, {} diverged",
+        response.total_calls, response.matched, response.diverged
+    );
+    output.push(format!("{}", style(summary).bold()));
+    if response.stopped_early {
+        output.push("Stopped at the first divergence (--stop-on-divergence)".to_string());
+    }
+
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn entry(tool: &str, params: Value, outcome: &str, response: Option<Value>) -> WireLogEntry {
+        WireLogEntry {
+            timestamp: "2025-01-01T00:00:00+00:00".to_string(),
+            request_id: format!("1-{}", tool),
+            tool: tool.to_string(),
+            duration_ms: 1,
+            params,
+            outcome: outcome.to_string(),
+            response,
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_runs_in_memory_and_follows_renamed_specs() {
+        let project = json!({
+            "project_name": "replay-demo",
+            "vision": "Replay demo vision describing the problem, users and goals. ".repeat(4),
+            "tech_stack": "Rust with tokio, serde and clap for the command line. ".repeat(3),
+            "summary": "Replay demo project used to test the replay command. ".repeat(2),
+        });
+        let recorded_spec = "20200101_000000_login";
+        let entries = vec![
+            entry("create_project", project, "ok", None),
+            entry(
+                "create_spec",
+                json!({
+                    "project_name": "replay-demo",
+                    "feature_name": "login",
+                    "spec": "Login spec",
+                    "notes": "Notes",
+                    "tasks": "- [ ] Build login",
+                }),
+                "ok",
+                Some(json!({"data": {"spec_name": recorded_spec}})),
+            ),
+            entry(
+                "load_spec",
+                json!({"project_name": "replay-demo", "spec_name": recorded_spec}),
+                "ok",
+                None,
+            ),
+            entry(
+                "load_project",
+                json!({"project_name": "missing"}),
+                "ok",
+                None,
+            ),
+        ];
+
+        let report = replay(&entries, "wire.jsonl".to_string(), false).await;
+
+        assert_eq!(report.total_calls, 4);
+        assert!(
+            report.steps[..3].iter().all(|step| step.matches),
+            "{:?}",
+            report.steps
+        );
+        assert!(!report.steps[3].matches);
+        assert_eq!(report.diverged, 1);
+        assert!(!report.stopped_early);
+    }
+
+    #[test]
+    fn test_fidelity_note_flags_redacted_and_truncated_params() {
+        assert!(fidelity_note(&json!({"project_name": "demo"})).is_none());
+        let note =
+            fidelity_note(&json!({"api_key": "[REDACTED]", "spec": "x… [truncated 5 chars]"}));
+        assert_eq!(
+            note.unwrap(),
+            "Params were redacted and truncated in the wire log; the replayed call may differ"
+        );
+    }
+}
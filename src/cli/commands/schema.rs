@@ -0,0 +1,159 @@
+//! Implementation of the schema command
+
+use crate::cli::args::SchemaArgs;
+use crate::mcp::tools::FoundryTools;
+use anyhow::{Context, Result};
+use rust_mcp_sdk::schema::Tool as McpTool;
+use serde_json::{Map, Value, json};
+
+pub async fn execute(args: SchemaArgs) -> Result<String> {
+    let tools = FoundryTools::all_tools();
+
+    let document = match args.format.as_str() {
+        "json-schema" => build_json_schema_document(&tools)?,
+        "openapi" => build_openapi_document(&tools)?,
+        other => {
+            return Err(anyhow::anyhow!(
+                "Unsupported schema format: {}. Supported formats: json-schema, openapi",
+                other
+            ));
+        }
+    };
+
+    Ok(serde_json::to_string_pretty(&document)?)
+}
+
+/// Tool surface as a list of input/output JSON Schemas keyed by tool name
+pub fn build_json_schema_document(tools: &[McpTool]) -> Result<Value> {
+    let entries = tools
+        .iter()
+        .map(|tool| {
+            Ok(json!({
+                "name": tool.name,
+                "description": tool.description,
+                "input_schema": tool_input_schema(tool)?,
+                "output_schema": tool_output_schema(tool)?,
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "server": {
+            "name": "foundry-mcp",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "tools": entries,
+    }))
+}
+
+/// Tool surface as an OpenAPI 3.1 document with one POST operation per tool
+pub fn build_openapi_document(tools: &[McpTool]) -> Result<Value> {
+    let mut paths = Map::new();
+    for tool in tools {
+        let mut operation = json!({
+            "operationId": tool.name,
+            "summary": tool.description,
+            "requestBody": {
+                "required": true,
+                "content": {
+                    "application/json": { "schema": tool_input_schema(tool)? }
+                }
+            },
+            "responses": {
+                "200": {
+                    "description": "Foundry response envelope",
+                    "content": {
+                        "application/json": {
+                            "schema": tool_output_schema(tool)?.unwrap_or_else(|| json!({ "type": "object" }))
+                        }
+                    }
+                }
+            }
+        });
+        if let Some(obj) = operation.as_object_mut() {
+            obj.retain(|_, v| !v.is_null());
+        }
+        paths.insert(
+            format!("/tools/{}", tool.name),
+            json!({ "post": operation }),
+        );
+    }
+
+    Ok(json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "Foundry MCP tools",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "paths": paths,
+    }))
+}
+
+fn tool_input_schema(tool: &McpTool) -> Result<Value> {
+    serde_json::to_value(&tool.input_schema)
+        .with_context(|| format!("Failed to serialize input schema for {}", tool.name))
+}
+
+fn tool_output_schema(tool: &McpTool) -> Result<Option<Value>> {
+    tool.output_schema
+        .as_ref()
+        .map(serde_json::to_value)
+        .transpose()
+        .with_context(|| format!("Failed to serialize output schema for {}", tool.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_schema_document_covers_all_tools() {
+        let tools = FoundryTools::all_tools();
+        let document = build_json_schema_document(&tools).unwrap();
+
+        let entries = document["tools"].as_array().unwrap();
+        assert_eq!(entries.len(), tools.len());
+
+        let create_project = entries
+            .iter()
+            .find(|t| t["name"] == "create_project")
+            .unwrap();
+        assert_eq!(create_project["input_schema"]["type"], "object");
+        assert_eq!(
+            create_project["input_schema"]["properties"]["vision"]["minLength"],
+            200
+        );
+
+        // Every tool documents its response envelope
+        for entry in entries {
+            let output = &entry["output_schema"];
+            assert_eq!(output["type"], "object", "{} output schema", entry["name"]);
+            assert!(output["properties"]["data"].is_object());
+        }
+        assert_eq!(
+            create_project["output_schema"]["properties"]["data"]["properties"]["files_created"]["type"],
+            "array"
+        );
+    }
+
+    #[test]
+    fn test_openapi_document_has_path_per_tool() {
+        let tools = FoundryTools::all_tools();
+        let document = build_openapi_document(&tools).unwrap();
+
+        assert_eq!(document["openapi"], "3.1.0");
+        let paths = document["paths"].as_object().unwrap();
+        assert_eq!(paths.len(), tools.len());
+        assert!(paths.contains_key("/tools/update_spec"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_unknown_format() {
+        let result = execute(SchemaArgs {
+            format: "yaml".to_string(),
+        })
+        .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! Implementation of the search command
+
+use crate::cli::args::SearchArgs;
+use crate::core::ops::search_specs;
+use crate::types::responses::SearchSpecsResponse;
+use anyhow::Result;
+use console::style;
+
+pub async fn execute(args: SearchArgs) -> Result<String> {
+    let response = search_specs::run(search_specs::Input {
+        query: args.query,
+        project_name: args.project,
+        limit: args.limit,
+    })
+    .await?;
+
+    if args.json {
+        Ok(serde_json::to_string_pretty(&response.data)?)
+    } else {
+        Ok(format_search_output(&response.data))
+    }
+}
+
+fn format_search_output(response: &SearchSpecsResponse) -> String {
+    let mut output = vec![format!(
+        "{}",
+        style(format!("Search: {}", response.query)).bold().cyan()
+    )];
+    output.push(format!(
+        "{}",
+        style(format!(
+            "{} in {} across {}",
+            crate::utils::formatting::format_count(response.total_matches, "match", "matches"),
+            crate::utils::formatting::format_count(response.specs_searched, "spec", "specs"),
+            crate::utils::formatting::format_count(
+                response.projects_searched,
+                "project",
+                "projects"
+            )
+        ))
+        .dim()
+    ));
+    output.push(String::new());
+
+    if response.matches.is_empty() {
+        output.push("No matches".to_string());
+        return output.join("\n");
+    }
+
+    for found in &response.matches {
+        output.push(format!(
+            "{} {}",
+            style(format!(
+                "{}/{}/{}:{}",
+                found.project_name, found.spec_name, found.file, found.line_number
+            ))
+            .green(),
+            style(format!("({})", found.score)).dim()
+        ));
+        output.push(format!("    {}", found.snippet));
+    }
+
+    if response.truncated {
+        output.push(String::new());
+        output.push(format!(
+            "{}",
+            style(format!(
+                "Showing {} of {} matches; use --limit or --project to see more",
+                response.matches.len(),
+                response.total_matches
+            ))
+            .dim()
+        ));
+    }
+
+    output.join("\n")
+}
@@ -0,0 +1,62 @@
+//! Implementation of the stale command
+
+use crate::cli::args::StaleArgs;
+use crate::core::ops::needs_attention;
+use crate::types::responses::NeedsAttentionResponse;
+use anyhow::Result;
+use console::style;
+
+pub async fn execute(args: StaleArgs) -> Result<String> {
+    let response = needs_attention::run(needs_attention::Input {
+        project_name: args.project,
+        stale_days: args.days,
+    })
+    .await?;
+
+    if args.json {
+        Ok(serde_json::to_string_pretty(&response.data)?)
+    } else {
+        Ok(format_stale_output(&response.data))
+    }
+}
+
+fn format_stale_output(response: &NeedsAttentionResponse) -> String {
+    let mut output = vec![format!(
+        "{}",
+        style("Specs Needing Attention").bold().cyan()
+    )];
+    output.push(format!(
+        "{}",
+        style(format!(
+            "Open tasks and no activity for {}+ days ({} checked)",
+            response.stale_days,
+            crate::utils::formatting::format_count(
+                response.projects_checked,
+                "project",
+                "projects"
+            )
+        ))
+        .dim()
+    ));
+    output.push(String::new());
+
+    if response.specs.is_empty() {
+        output.push(format!("{} No stale specs", style("✓").green()));
+        return output.join("\n");
+    }
+
+    for spec in &response.specs {
+        output.push(format!(
+            "{} {}/{}",
+            style("!").yellow(),
+            spec.project_name,
+            spec.spec_name
+        ));
+        output.push(format!(
+            "    {} open, last activity {} ({} days ago)",
+            spec.open_tasks, spec.last_activity, spec.days_idle
+        ));
+    }
+
+    output.join("\n")
+}
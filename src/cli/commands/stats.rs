@@ -0,0 +1,190 @@
+//! Implementation of the stats command
+
+use crate::cli::args::StatsArgs;
+use crate::mcp::wire_log::{self, WireLogEntry};
+use crate::types::responses::{StatsResponse, ToolUsageStats};
+use crate::utils::timestamp::parse_window;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use console::style;
+use std::collections::BTreeMap;
+
+pub async fn execute(args: StatsArgs) -> Result<String> {
+    let log_dir = wire_log::default_dir()?;
+    let cutoff = args
+        .since
+        .as_deref()
+        .map(|window| parse_window(window).map(|window| Utc::now() - window))
+        .transpose()?;
+
+    let mut entries = Vec::new();
+    for path in wire_log::log_files(&log_dir)? {
+        entries.extend(wire_log::read_entries(&path)?);
+    }
+
+    let response_data = summarize(
+        &entries,
+        cutoff,
+        log_dir.display().to_string(),
+        args.since.clone(),
+    );
+
+    if args.json {
+        Ok(serde_json::to_string_pretty(&response_data)?)
+    } else {
+        Ok(format_stats_output(&response_data))
+    }
+}
+
+/// Aggregate entries recorded at or after `cutoff` into per-tool stats
+fn summarize(
+    entries: &[WireLogEntry],
+    cutoff: Option<DateTime<Utc>>,
+    log_dir: String,
+    window: Option<String>,
+) -> StatsResponse {
+    let in_window: Vec<&WireLogEntry> = entries
+        .iter()
+        .filter(|entry| {
+            cutoff.is_none_or(|cutoff| {
+                DateTime::parse_from_rfc3339(&entry.timestamp).is_ok_and(|at| at >= cutoff)
+            })
+        })
+        .collect();
+
+    // tool -> (calls, errors, total duration, max duration)
+    let mut totals: BTreeMap<&str, (usize, usize, u64, u64)> = BTreeMap::new();
+    for entry in &in_window {
+        let (calls, errors, total_ms, max_ms) = totals.entry(&entry.tool).or_default();
+        *calls += 1;
+        *errors += usize::from(entry.is_error());
+        *total_ms += entry.duration_ms;
+        *max_ms = (*max_ms).max(entry.duration_ms);
+    }
+
+    let mut tools: Vec<ToolUsageStats> = totals
+        .into_iter()
+        .map(|(tool, (calls, errors, total_ms, max_ms))| ToolUsageStats {
+            tool: tool.to_string(),
+            calls,
+            errors,
+            error_rate: errors as f64 / calls as f64,
+            avg_duration_ms: total_ms as f64 / calls as f64,
+            max_duration_ms: max_ms,
+        })
+        .collect();
+    // Stable sort keeps ties in name order
+    tools.sort_by_key(|t| std::cmp::Reverse(t.calls));
+
+    StatsResponse {
+        log_dir,
+        window,
+        total_calls: in_window.len(),
+        error_count: in_window.iter().filter(|e| e.is_error()).count(),
+        tools,
+    }
+}
+
+fn format_stats_output(response: &StatsResponse) -> String {
+    let mut output = vec![format!("{}", style("Foundry Tool Usage").bold().cyan())];
+    output.push(format!(
+        "{}",
+        style(format!(
+            "{} ({})",
+            response.log_dir,
+            response
+                .window
+                .as_deref()
+                .map(|w| format!("last {}", w))
+                .unwrap_or_else(|| "all recorded calls".to_string())
+        ))
+        .dim()
+    ));
+    output.push(String::new());
+
+    if response.total_calls == 0 {
+        output.push(
+            "No tool calls recorded. Start the server with `foundry serve --wire-log` to collect usage."
+                .to_string(),
+        );
+        return output.join("\n");
+    }
+
+    output.push(format!(
+        "{:<20} {:>7} {:>7} {:>7} {:>9} {:>9}",
+        "Tool", "Calls", "Errors", "Err %", "Avg ms", "Max ms"
+    ));
+    for tool in &response.tools {
+        output.push(format!(
+            "{:<20} {:>7} {:>7} {:>6.0}% {:>9.1} {:>9}",
+            tool.tool,
+            tool.calls,
+            tool.errors,
+            tool.error_rate * 100.0,
+            tool.avg_duration_ms,
+            tool.max_duration_ms
+        ));
+    }
+    output.push(String::new());
+    output.push(format!(
+        "{}",
+        style(format!(
+            "Total: {} calls, {} errors",
+            response.total_calls, response.error_count
+        ))
+        .bold()
+    ));
+
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn entry(tool: &str, timestamp: &str, duration_ms: u64, outcome: &str) -> WireLogEntry {
+        WireLogEntry {
+            timestamp: timestamp.to_string(),
+            request_id: "1-1".to_string(),
+            tool: tool.to_string(),
+            duration_ms,
+            params: serde_json::Value::Null,
+            outcome: outcome.to_string(),
+            response: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_window() {
+        assert_eq!(parse_window("30m").unwrap(), Duration::minutes(30));
+        assert_eq!(parse_window("7d").unwrap(), Duration::days(7));
+        assert!(parse_window("7w").is_err());
+        assert!(parse_window("").is_err());
+    }
+
+    #[test]
+    fn test_summarize_groups_by_tool_within_window() {
+        let entries = vec![
+            entry("load_spec", "2025-01-01T00:00:00+00:00", 40, "ok"),
+            entry("load_spec", "2025-01-03T00:00:00+00:00", 10, "ok"),
+            entry("load_spec", "2025-01-03T01:00:00+00:00", 30, "error"),
+            entry("update_spec", "2025-01-03T02:00:00+00:00", 5, "ok"),
+        ];
+        let cutoff = DateTime::parse_from_rfc3339("2025-01-02T00:00:00+00:00")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let stats = summarize(&entries, Some(cutoff), "logs".to_string(), None);
+
+        assert_eq!(stats.total_calls, 3);
+        assert_eq!(stats.error_count, 1);
+        assert_eq!(stats.tools[0].tool, "load_spec");
+        assert_eq!(stats.tools[0].calls, 2);
+        assert_eq!(stats.tools[0].avg_duration_ms, 20.0);
+        assert_eq!(stats.tools[0].max_duration_ms, 30);
+        assert_eq!(stats.tools[0].error_rate, 0.5);
+        assert_eq!(stats.tools[1].tool, "update_spec");
+    }
+}
@@ -51,9 +51,9 @@ pub async fn execute(args: StatusArgs) -> Result<String> {
 /// Validate the status target
 fn validate_target(target: &str) -> Result<()> {
     match target {
-        "claude-code" | "cursor" => Ok(()),
+        "claude-code" | "cursor" | "vscode" | "windsurf" | "zed" => Ok(()),
         _ => Err(anyhow::anyhow!(
-            "Unsupported status target: {}. Supported targets: claude-code, cursor",
+            "Unsupported status target: {}. Supported targets: claude-code, cursor, vscode, windsurf, zed",
             target
         )),
     }
@@ -66,7 +66,7 @@ mod tests {
 
     #[test]
     fn test_validate_target_valid() {
-        let valid_targets = vec!["claude-code", "cursor"];
+        let valid_targets = vec!["claude-code", "cursor", "vscode", "windsurf", "zed"];
 
         for target in valid_targets {
             assert!(
@@ -79,7 +79,7 @@ mod tests {
 
     #[test]
     fn test_validate_target_invalid() {
-        let invalid_targets = vec!["", "vscode", "claude-desktop"];
+        let invalid_targets = vec!["", "vs-code", "claude-desktop"];
 
         for target in invalid_targets {
             assert!(
@@ -0,0 +1,121 @@
+//! Implementation of the tui command
+
+use anyhow::Result;
+use ratatui::DefaultTerminal;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+
+use crate::cli::args::TuiArgs;
+use crate::cli::tui::{Action, App, view};
+use crate::core::ops::{project_report, update_spec};
+use crate::core::{audit, foundry};
+use crate::types::errors::ErrorCode;
+
+pub async fn execute(args: TuiArgs) -> Result<String> {
+    let foundry = foundry::get_default_foundry()?;
+    let mut app = App::new(foundry.list_projects().await?);
+    if let Some(project_name) = &args.project {
+        app.selected_project = app
+            .projects
+            .iter()
+            .position(|project| &project.name == project_name)
+            .ok_or_else(|| {
+                ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+            })?;
+        perform(
+            &mut app,
+            Action::LoadSpecs {
+                project_name: project_name.clone(),
+            },
+        )
+        .await;
+    }
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, &mut app).await;
+    ratatui::restore();
+    result?;
+    Ok(String::new())
+}
+
+async fn run(terminal: &mut DefaultTerminal, app: &mut App) -> Result<()> {
+    loop {
+        terminal.draw(|frame| view::render(app, frame))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match app.handle_key(key.code) {
+            Action::Quit => return Ok(()),
+            action => perform(app, action).await,
+        }
+    }
+}
+
+/// Run `action` against the backend and show its result, or its error in the footer
+async fn perform(app: &mut App, action: Action) {
+    if let Err(e) = try_perform(app, action).await {
+        app.status = format!("Error: {:#}", e);
+    }
+}
+
+async fn try_perform(app: &mut App, action: Action) -> Result<()> {
+    let foundry = foundry::get_default_foundry()?;
+    match action {
+        Action::None | Action::Quit => {}
+        Action::LoadSpecs { project_name } => {
+            app.show_specs(foundry.list_specs(&project_name).await?);
+            app.status.clear();
+        }
+        Action::LoadSpec {
+            project_name,
+            spec_name,
+        } => {
+            app.show_spec(foundry.load_spec(&project_name, &spec_name).await?);
+            app.status.clear();
+        }
+        Action::LoadRecent { project_name } => {
+            let report = project_report::run(project_report::Input {
+                project_name,
+                recent_days: None,
+                stale_days: None,
+            })
+            .await?;
+            app.show_recent(report.data);
+            app.status.clear();
+        }
+        Action::ToggleTask {
+            project_name,
+            spec_name,
+            task_text,
+            done,
+        } => {
+            let status = if done { "done" } else { "todo" };
+            let commands = serde_json::json!([{
+                "target": "tasks",
+                "command": "set_task_status",
+                "selector": {"type": "task_text", "value": task_text},
+                "status": status,
+            }]);
+            update_spec::run(update_spec::Input {
+                project_name: project_name.clone(),
+                spec_name: spec_name.clone(),
+                commands_json: commands.to_string(),
+                expected_version: None,
+                dry_run: false,
+            })
+            .await?;
+            audit::record_call(
+                &project_name,
+                "update_spec",
+                &serde_json::json!({"spec_name": spec_name, "commands": commands}),
+                None,
+                None,
+            )?;
+            app.show_spec(foundry.load_spec(&project_name, &spec_name).await?);
+            app.status = format!("Marked '{}' {}", task_text, status);
+        }
+    }
+    Ok(())
+}
@@ -18,10 +18,19 @@ pub async fn execute(args: UninstallArgs) -> Result<String> {
         "cursor" => installation::uninstall_from_cursor(args.remove_config)
             .await
             .context("Failed to uninstall from Cursor")?,
+        "vscode" => installation::uninstall_from_vscode(args.remove_config)
+            .await
+            .context("Failed to uninstall from VS Code")?,
+        "windsurf" => installation::uninstall_from_windsurf(args.remove_config)
+            .await
+            .context("Failed to uninstall from Windsurf")?,
+        "zed" => installation::uninstall_from_zed(args.remove_config)
+            .await
+            .context("Failed to uninstall from Zed")?,
 
         _ => {
             return Err(anyhow::anyhow!(
-                "Unsupported uninstallation target: {}. Supported targets: claude-code, cursor",
+                "Unsupported uninstallation target: {}. Supported targets: claude-code, cursor, vscode, windsurf, zed",
                 args.target
             ));
         }
@@ -59,9 +68,9 @@ pub async fn execute(args: UninstallArgs) -> Result<String> {
 /// Validate the uninstallation target
 fn validate_target(target: &str) -> Result<()> {
     match target {
-        "claude-code" | "cursor" => Ok(()),
+        "claude-code" | "cursor" | "vscode" | "windsurf" | "zed" => Ok(()),
         _ => Err(anyhow::anyhow!(
-            "Unsupported uninstallation target: {}. Supported targets: claude-code, cursor",
+            "Unsupported uninstallation target: {}. Supported targets: claude-code, cursor, vscode, windsurf, zed",
             target
         )),
     }
@@ -74,7 +83,7 @@ mod tests {
 
     #[test]
     fn test_validate_target_valid() {
-        let valid_targets = vec!["claude-code", "cursor"];
+        let valid_targets = vec!["claude-code", "cursor", "vscode", "windsurf", "zed"];
 
         for target in valid_targets {
             assert!(
@@ -87,7 +96,7 @@ mod tests {
 
     #[test]
     fn test_validate_target_invalid() {
-        let invalid_targets = vec!["", "vscode", "claude-desktop"];
+        let invalid_targets = vec!["", "vs-code", "claude-desktop"];
 
         for target in invalid_targets {
             assert!(
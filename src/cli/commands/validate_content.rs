@@ -4,7 +4,8 @@
 //! and provides improvement suggestions.
 
 use crate::cli::args::ValidateContentArgs;
-use crate::core::validation::{parse_content_type, validate_content};
+use crate::core::config;
+use crate::core::validation::parse_content_type;
 use crate::types::responses::{FoundryResponse, ValidateContentResponse, ValidationStatus};
 use anyhow::{Context, Result};
 
@@ -53,8 +54,8 @@ pub async fn execute(
             )
         })?;
 
-    // Validate content with context
-    let validation_result = validate_content(content_type, &args.content);
+    // Validate content with context, honoring minimum lengths from ~/.foundry/config.toml
+    let validation_result = config::global()?.validate(content_type, &args.content);
 
     // Prepare enhanced response with additional context
     let response_data = ValidateContentResponse {
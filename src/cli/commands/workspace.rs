@@ -0,0 +1,82 @@
+//! Implementation of the workspace command
+
+use crate::cli::args::{WorkspaceArgs, WorkspaceCommand};
+use crate::core::workspace;
+use crate::types::responses::{WorkspaceInfo, WorkspaceListResponse};
+use anyhow::Result;
+use console::style;
+use std::path::Path;
+
+pub async fn execute(args: WorkspaceArgs) -> Result<String> {
+    let message = match args.command {
+        WorkspaceCommand::List => None,
+        WorkspaceCommand::Add { name, path } => {
+            let dir = workspace::add(&name, Path::new(&path))?;
+            Some(format!(
+                "{} Registered workspace {} at {}",
+                style("✓").green(),
+                style(&name).bold(),
+                dir.display()
+            ))
+        }
+        WorkspaceCommand::Remove { name } => {
+            let dir = workspace::remove(&name)?;
+            Some(format!(
+                "{} Removed workspace {}; {} was left in place",
+                style("✓").green(),
+                style(&name).bold(),
+                dir.display()
+            ))
+        }
+        WorkspaceCommand::Migrate { to } => {
+            let migration = workspace::migrate_home(to.as_deref().map(Path::new))?;
+            let link = if migration.symlinked {
+                format!("{} now links to it", migration.from.display())
+            } else {
+                format!(
+                    "could not link {} to it; set FOUNDRY_HOME if the new location is not found",
+                    migration.from.display()
+                )
+            };
+            Some(format!(
+                "{} Moved foundry data to {}; {}",
+                style("✓").green(),
+                style(migration.to.display()).bold(),
+                link
+            ))
+        }
+    };
+
+    let current = workspace::current_dir()?;
+    let response = WorkspaceListResponse {
+        registry_path: workspace::registry_path()?.display().to_string(),
+        workspaces: workspace::list()?
+            .into_iter()
+            .map(|(name, dir)| WorkspaceInfo {
+                name,
+                current: dir == current,
+                path: dir.display().to_string(),
+            })
+            .collect(),
+    };
+    if args.json {
+        return Ok(serde_json::to_string_pretty(&response)?);
+    }
+    Ok(message.unwrap_or_else(|| format_list_output(&response)))
+}
+
+fn format_list_output(response: &WorkspaceListResponse) -> String {
+    let mut output = vec![format!("{}", style("Workspaces").bold().cyan())];
+    output.push(format!("{}", style(&response.registry_path).dim()));
+    output.push(String::new());
+    for workspace in &response.workspaces {
+        let marker = if workspace.current { "*" } else { " " };
+        output.push(format!(
+            "{} {}  {}",
+            marker,
+            style(&workspace.name).bold(),
+            workspace.path
+        ));
+    }
+    output.join("\n")
+}
@@ -2,6 +2,8 @@
 
 pub mod args;
 pub mod commands;
+pub mod output;
+pub mod tui;
 
 pub use args::*;
 pub use commands::*;
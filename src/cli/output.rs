@@ -0,0 +1,60 @@
+//! Output mode shared by every CLI command
+//!
+//! `--json` (or `FOUNDRY_OUTPUT=json`) switches commands from human-readable
+//! text to the JSON form of their `types::responses` data, and failures to the
+//! same `{"error": {"code", "message"}}` body MCP tools return.
+
+use crate::types::errors::{ErrorCode, ErrorPayload};
+
+/// Environment variable selecting the output mode (`json` or `text`)
+pub const OUTPUT_ENV_VAR: &str = "FOUNDRY_OUTPUT";
+
+/// Whether to print JSON: `--json`, else `FOUNDRY_OUTPUT=json`
+pub fn json_requested(flag: bool) -> bool {
+    flag || std::env::var(OUTPUT_ENV_VAR)
+        .is_ok_and(|value| value.trim().eq_ignore_ascii_case("json"))
+}
+
+/// `error` as `{"error": {"code", "message"}}`
+pub fn error_json(error: &anyhow::Error) -> String {
+    let body = serde_json::json!({
+        "error": ErrorPayload {
+            code: ErrorCode::of(error),
+            message: format!("{:#}", error),
+            rule: ErrorCode::rule_of(error).map(str::to_string),
+            details: ErrorCode::details_of(error).cloned(),
+        }
+    });
+    serde_json::to_string_pretty(&body).unwrap_or_else(|_| body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_requested_by_flag_or_env() {
+        temp_env::with_var(OUTPUT_ENV_VAR, None::<&str>, || {
+            assert!(json_requested(true));
+            assert!(!json_requested(false));
+        });
+        temp_env::with_var(OUTPUT_ENV_VAR, Some("JSON"), || {
+            assert!(json_requested(false));
+        });
+        temp_env::with_var(OUTPUT_ENV_VAR, Some("text"), || {
+            assert!(!json_requested(false));
+        });
+    }
+
+    #[test]
+    fn test_error_json_carries_code_and_message() {
+        let error = ErrorCode::ProjectNotFound.error("Project 'demo' not found");
+        let body: serde_json::Value = serde_json::from_str(&error_json(&error)).unwrap();
+        assert_eq!(body["error"]["code"], "PROJECT_NOT_FOUND");
+        assert_eq!(body["error"]["message"], "Project 'demo' not found");
+
+        let body: serde_json::Value =
+            serde_json::from_str(&error_json(&anyhow::anyhow!("boom"))).unwrap();
+        assert_eq!(body["error"]["code"], "INTERNAL_ERROR");
+    }
+}
@@ -0,0 +1,368 @@
+//! Interactive terminal UI for `foundry tui`
+//!
+//! [`App`] holds what is on screen and turns key presses into [`Action`]s; the
+//! loop in `cli::commands::tui` performs the actions against the backend and
+//! feeds the results back, so navigation is testable without a terminal.
+//! Rendering lives in [`view`].
+
+pub mod view;
+
+use ratatui::crossterm::event::KeyCode;
+
+use crate::core::tasks::{self, TaskEntry};
+use crate::types::project::ProjectMetadata;
+use crate::types::responses::ProjectReportResponse;
+use crate::types::spec::{Spec, SpecMetadata};
+
+/// Screens, from the project list down to a single spec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Screen {
+    Projects,
+    Specs,
+    Spec,
+    Recent,
+}
+
+/// Files of a spec shown in the spec screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecTab {
+    Spec,
+    Tasks,
+    Notes,
+}
+
+impl SpecTab {
+    pub const ALL: [SpecTab; 3] = [SpecTab::Spec, SpecTab::Tasks, SpecTab::Notes];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            SpecTab::Spec => "Spec",
+            SpecTab::Tasks => "Tasks",
+            SpecTab::Notes => "Notes",
+        }
+    }
+
+    fn next(self) -> SpecTab {
+        match self {
+            SpecTab::Spec => SpecTab::Tasks,
+            SpecTab::Tasks => SpecTab::Notes,
+            SpecTab::Notes => SpecTab::Spec,
+        }
+    }
+}
+
+/// Work a key press asks for that needs the backend
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    None,
+    Quit,
+    LoadSpecs {
+        project_name: String,
+    },
+    LoadSpec {
+        project_name: String,
+        spec_name: String,
+    },
+    LoadRecent {
+        project_name: String,
+    },
+    /// Check or uncheck the task with `task_text`
+    ToggleTask {
+        project_name: String,
+        spec_name: String,
+        task_text: String,
+        done: bool,
+    },
+}
+
+/// State of the terminal UI
+#[derive(Debug)]
+pub struct App {
+    pub screen: Screen,
+    pub projects: Vec<ProjectMetadata>,
+    pub selected_project: usize,
+    pub specs: Vec<SpecMetadata>,
+    pub selected_spec: usize,
+    pub spec: Option<Spec>,
+    pub tasks: Vec<TaskEntry>,
+    pub selected_task: usize,
+    pub tab: SpecTab,
+    /// Lines scrolled in the spec and notes tabs and the recent screen
+    pub scroll: u16,
+    pub report: Option<ProjectReportResponse>,
+    /// Message shown in the footer, e.g. the outcome of the last action
+    pub status: String,
+}
+
+impl App {
+    pub fn new(projects: Vec<ProjectMetadata>) -> Self {
+        Self {
+            screen: Screen::Projects,
+            projects,
+            selected_project: 0,
+            specs: Vec::new(),
+            selected_spec: 0,
+            spec: None,
+            tasks: Vec::new(),
+            selected_task: 0,
+            tab: SpecTab::Spec,
+            scroll: 0,
+            report: None,
+            status: String::new(),
+        }
+    }
+
+    /// Name of the highlighted (or open) project
+    pub fn project_name(&self) -> Option<&str> {
+        self.projects
+            .get(self.selected_project)
+            .map(|project| project.name.as_str())
+    }
+
+    /// Show the specs of the open project
+    pub fn show_specs(&mut self, specs: Vec<SpecMetadata>) {
+        self.specs = specs;
+        self.selected_spec = self.selected_spec.min(self.specs.len().saturating_sub(1));
+        self.screen = Screen::Specs;
+    }
+
+    /// Show `spec`, keeping the tab and task selection when it is reloaded
+    pub fn show_spec(&mut self, spec: Spec) {
+        let reloaded = self
+            .spec
+            .as_ref()
+            .is_some_and(|open| open.name == spec.name);
+        self.tasks = tasks::parse_task_list(&spec.content.tasks);
+        if reloaded {
+            self.selected_task = self.selected_task.min(self.tasks.len().saturating_sub(1));
+        } else {
+            self.tab = SpecTab::Spec;
+            self.selected_task = 0;
+            self.scroll = 0;
+        }
+        self.spec = Some(spec);
+        self.screen = Screen::Spec;
+    }
+
+    /// Show the recent-changes report of the open project
+    pub fn show_recent(&mut self, report: ProjectReportResponse) {
+        self.report = Some(report);
+        self.scroll = 0;
+        self.screen = Screen::Recent;
+    }
+
+    /// Update state for `key` and return the work it needs
+    pub fn handle_key(&mut self, key: KeyCode) -> Action {
+        match key {
+            KeyCode::Char('q') => return Action::Quit,
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::PageUp => self.move_selection(-10),
+            KeyCode::PageDown => self.move_selection(10),
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left | KeyCode::Char('h') => {
+                if self.screen == Screen::Projects {
+                    return Action::Quit;
+                }
+                self.back();
+            }
+            KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => return self.open(),
+            KeyCode::Tab if self.screen == Screen::Spec => {
+                self.tab = self.tab.next();
+                self.scroll = 0;
+            }
+            KeyCode::Char(' ') | KeyCode::Char('x')
+                if self.screen == Screen::Spec && self.tab == SpecTab::Tasks =>
+            {
+                return self.toggle_task();
+            }
+            KeyCode::Char('r') if matches!(self.screen, Screen::Specs | Screen::Spec) => {
+                if let Some(project_name) = self.project_name() {
+                    return Action::LoadRecent {
+                        project_name: project_name.to_string(),
+                    };
+                }
+            }
+            _ => {}
+        }
+        Action::None
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        let (selected, len) = match self.screen {
+            Screen::Projects => (&mut self.selected_project, self.projects.len()),
+            Screen::Specs => (&mut self.selected_spec, self.specs.len()),
+            Screen::Spec if self.tab == SpecTab::Tasks => {
+                (&mut self.selected_task, self.tasks.len())
+            }
+            Screen::Spec | Screen::Recent => {
+                self.scroll = self.scroll.saturating_add_signed(delta as i16);
+                return;
+            }
+        };
+        if len == 0 {
+            return;
+        }
+        *selected = (*selected as i64 + delta as i64).clamp(0, len as i64 - 1) as usize;
+    }
+
+    fn back(&mut self) {
+        self.status.clear();
+        self.screen = match self.screen {
+            Screen::Projects | Screen::Specs => Screen::Projects,
+            Screen::Spec | Screen::Recent => Screen::Specs,
+        };
+        self.scroll = 0;
+    }
+
+    fn open(&mut self) -> Action {
+        let Some(project_name) = self.project_name().map(str::to_string) else {
+            return Action::None;
+        };
+        match self.screen {
+            Screen::Projects => {
+                self.selected_spec = 0;
+                self.spec = None;
+                Action::LoadSpecs { project_name }
+            }
+            Screen::Specs => self
+                .specs
+                .get(self.selected_spec)
+                .map_or(Action::None, |spec| Action::LoadSpec {
+                    project_name,
+                    spec_name: spec.name.clone(),
+                }),
+            Screen::Spec | Screen::Recent => Action::None,
+        }
+    }
+
+    fn toggle_task(&self) -> Action {
+        match (self.spec.as_ref(), self.tasks.get(self.selected_task)) {
+            (Some(spec), Some(task)) => Action::ToggleTask {
+                project_name: spec.project_name.clone(),
+                spec_name: spec.name.clone(),
+                task_text: task.text.clone(),
+                done: !task.done,
+            },
+            _ => Action::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::spec::{SpecContentData, SpecStatus};
+
+    fn project(name: &str) -> ProjectMetadata {
+        ProjectMetadata {
+            name: name.to_string(),
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            spec_count: 1,
+            last_modified: "2025-01-02T00:00:00Z".to_string(),
+            tags: Vec::new(),
+        }
+    }
+
+    fn spec(tasks: &str) -> Spec {
+        Spec {
+            name: "20250101_000000_login".to_string(),
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            path: std::path::PathBuf::new(),
+            project_name: "app".to_string(),
+            location_hint: None,
+            locator: None,
+            content: SpecContentData {
+                spec: "# Login".to_string(),
+                notes: String::new(),
+                tasks: tasks.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_navigation_requests_loads() {
+        let mut app = App::new(vec![project("app"), project("other")]);
+        assert_eq!(app.handle_key(KeyCode::Down), Action::None);
+        assert_eq!(app.handle_key(KeyCode::Down), Action::None);
+        assert_eq!(app.selected_project, 1);
+        app.handle_key(KeyCode::Up);
+        assert_eq!(
+            app.handle_key(KeyCode::Enter),
+            Action::LoadSpecs {
+                project_name: "app".to_string()
+            }
+        );
+
+        app.show_specs(vec![SpecMetadata {
+            name: "20250101_000000_login".to_string(),
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            feature_name: "login".to_string(),
+            project_name: "app".to_string(),
+            status: SpecStatus::Draft,
+            depends_on: Vec::new(),
+            tags: Vec::new(),
+            task_counts: Default::default(),
+            updated_at: None,
+            modified_at: Default::default(),
+            summary: None,
+        }]);
+        assert_eq!(
+            app.handle_key(KeyCode::Enter),
+            Action::LoadSpec {
+                project_name: "app".to_string(),
+                spec_name: "20250101_000000_login".to_string()
+            }
+        );
+        app.handle_key(KeyCode::Esc);
+        assert_eq!(app.screen, Screen::Projects);
+        assert_eq!(app.handle_key(KeyCode::Esc), Action::Quit);
+    }
+
+    #[test]
+    fn test_toggle_task_on_tasks_tab() {
+        let mut app = App::new(vec![project("app")]);
+        app.show_spec(spec("- [x] Design\n- [ ] Build it"));
+        assert_eq!(app.handle_key(KeyCode::Char(' ')), Action::None);
+
+        app.handle_key(KeyCode::Tab);
+        assert_eq!(app.tab, SpecTab::Tasks);
+        app.handle_key(KeyCode::Down);
+        assert_eq!(
+            app.handle_key(KeyCode::Char(' ')),
+            Action::ToggleTask {
+                project_name: "app".to_string(),
+                spec_name: "20250101_000000_login".to_string(),
+                task_text: "Build it".to_string(),
+                done: true,
+            }
+        );
+
+        // Reloading the same spec keeps the tab and selection
+        app.show_spec(spec("- [x] Design\n- [x] Build it"));
+        assert_eq!(app.tab, SpecTab::Tasks);
+        assert_eq!(app.selected_task, 1);
+        assert!(app.tasks[1].done);
+    }
+
+    #[test]
+    fn test_renders_task_checkboxes() {
+        let mut app = App::new(vec![project("app")]);
+        app.show_spec(spec("- [x] Design\n- [ ] Build it"));
+        app.handle_key(KeyCode::Tab);
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(60, 10)).unwrap();
+        terminal.draw(|frame| view::render(&app, frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Foundry › app › 20250101_000000_login"));
+        assert!(screen.contains("task-list.md (1/2 done)"));
+        assert!(screen.contains("[x] Design"));
+        assert!(screen.contains("[ ] Build it"));
+    }
+}
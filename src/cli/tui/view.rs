@@ -0,0 +1,196 @@
+//! Rendering of the terminal UI
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Tabs, Wrap};
+
+use super::{App, Screen, SpecTab};
+
+/// Draw `app` into `frame`
+pub fn render(app: &App, frame: &mut Frame) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    frame.render_widget(Line::from(breadcrumb(app)).bold().cyan(), header);
+    match app.screen {
+        Screen::Projects => render_projects(app, frame, body),
+        Screen::Specs => render_specs(app, frame, body),
+        Screen::Spec => render_spec(app, frame, body),
+        Screen::Recent => render_recent(app, frame, body),
+    }
+
+    let footer_text = if app.status.is_empty() {
+        key_help(app).to_string()
+    } else {
+        format!("{}  |  {}", app.status, key_help(app))
+    };
+    frame.render_widget(Line::from(footer_text).dim(), footer);
+}
+
+fn breadcrumb(app: &App) -> String {
+    let mut parts = vec!["Foundry".to_string()];
+    if app.screen != Screen::Projects
+        && let Some(project_name) = app.project_name()
+    {
+        parts.push(project_name.to_string());
+    }
+    match app.screen {
+        Screen::Spec => parts.extend(app.spec.as_ref().map(|spec| spec.name.clone())),
+        Screen::Recent => parts.push("recent changes".to_string()),
+        Screen::Projects | Screen::Specs => {}
+    }
+    parts.join(" › ")
+}
+
+fn key_help(app: &App) -> &'static str {
+    match app.screen {
+        Screen::Projects => "↑↓ select  enter open  q quit",
+        Screen::Specs => "↑↓ select  enter open  r recent  esc back  q quit",
+        Screen::Spec if app.tab == SpecTab::Tasks => {
+            "↑↓ select  space toggle  tab next file  r recent  esc back  q quit"
+        }
+        Screen::Spec => "↑↓ scroll  tab next file  r recent  esc back  q quit",
+        Screen::Recent => "↑↓ scroll  esc back  q quit",
+    }
+}
+
+fn selectable_list<'a>(
+    frame: &mut Frame,
+    area: Rect,
+    title: &'a str,
+    items: Vec<ListItem<'a>>,
+    selected: usize,
+) {
+    let empty = items.is_empty();
+    let list = List::new(items)
+        .block(Block::bordered().title(title))
+        .highlight_style(Style::new().reversed())
+        .highlight_symbol("› ");
+    let mut state = ListState::default().with_selected((!empty).then_some(selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn render_projects(app: &App, frame: &mut Frame, area: Rect) {
+    if app.projects.is_empty() {
+        frame.render_widget(
+            Paragraph::new(
+                "No projects yet. Create one from your AI assistant with create_project.",
+            )
+            .block(Block::bordered().title("Projects")),
+            area,
+        );
+        return;
+    }
+    let items = app
+        .projects
+        .iter()
+        .map(|project| {
+            ListItem::new(format!(
+                "{:<32} {:>3} specs   modified {}",
+                project.name, project.spec_count, project.last_modified
+            ))
+        })
+        .collect();
+    selectable_list(frame, area, "Projects", items, app.selected_project);
+}
+
+fn render_specs(app: &App, frame: &mut Frame, area: Rect) {
+    if app.specs.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No specs in this project yet.").block(Block::bordered().title("Specs")),
+            area,
+        );
+        return;
+    }
+    let items = app
+        .specs
+        .iter()
+        .map(|spec| {
+            let tasks = format!("{}/{}", spec.task_counts.completed, spec.task_counts.total);
+            ListItem::new(format!(
+                "{:<10} {:>7}  {}",
+                spec.status.to_string(),
+                tasks,
+                spec.name
+            ))
+        })
+        .collect();
+    selectable_list(frame, area, "Specs", items, app.selected_spec);
+}
+
+fn render_spec(app: &App, frame: &mut Frame, area: Rect) {
+    let Some(spec) = &app.spec else {
+        return;
+    };
+    let [tabs_area, content] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+    let selected = SpecTab::ALL
+        .iter()
+        .position(|tab| *tab == app.tab)
+        .unwrap_or_default();
+    frame.render_widget(
+        Tabs::new(SpecTab::ALL.iter().map(|tab| tab.title()))
+            .select(selected)
+            .highlight_style(Style::new().bold().reversed()),
+        tabs_area,
+    );
+
+    match app.tab {
+        SpecTab::Spec => render_text(frame, content, "spec.md", &spec.content.spec, app.scroll),
+        SpecTab::Notes => render_text(frame, content, "notes.md", &spec.content.notes, app.scroll),
+        SpecTab::Tasks => {
+            let done = app.tasks.iter().filter(|task| task.done).count();
+            let title = format!("task-list.md ({}/{} done)", done, app.tasks.len());
+            let items: Vec<ListItem> = app
+                .tasks
+                .iter()
+                .map(|task| {
+                    let checkbox = if task.done { "[x]" } else { "[ ]" };
+                    let line = if task.section.is_empty() {
+                        format!("{} {}", checkbox, task.text)
+                    } else {
+                        format!("{} {}  ({})", checkbox, task.text, task.section)
+                    };
+                    if task.done {
+                        ListItem::new(line).dim()
+                    } else {
+                        ListItem::new(line)
+                    }
+                })
+                .collect();
+            let empty = app.tasks.is_empty();
+            let list = List::new(items)
+                .block(Block::bordered().title(title))
+                .highlight_style(Style::new().reversed())
+                .highlight_symbol("› ");
+            let mut state =
+                ListState::default().with_selected((!empty).then_some(app.selected_task));
+            frame.render_stateful_widget(list, content, &mut state);
+        }
+    }
+}
+
+fn render_text(frame: &mut Frame, area: Rect, title: &str, text: &str, scroll: u16) {
+    frame.render_widget(
+        Paragraph::new(text.to_string())
+            .block(Block::bordered().title(title.to_string()))
+            .wrap(Wrap { trim: false })
+            .scroll((scroll, 0)),
+        area,
+    );
+}
+
+fn render_recent(app: &App, frame: &mut Frame, area: Rect) {
+    let text = app
+        .report
+        .as_ref()
+        .map(|report| report.summary_markdown.as_str())
+        .unwrap_or_default();
+    render_text(frame, area, "Recent changes", text, app.scroll);
+}
@@ -0,0 +1,141 @@
+//! Activity journal for digests
+//!
+//! Spec updates that complete tasks or add notes record an event in a
+//! per-project journal under `~/.foundry/.activity`:
+//!
+//! ```text
+//! .activity/<project>.json
+//! ```
+//!
+//! Only the newest [`MAX_EVENTS`] events of each project are kept. Spec
+//! creation is not journaled; digests read it from spec metadata. Updates of
+//! different specs record into the same file, which `core::journal` locks
+//! while an event is added.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use similar::{ChangeTag, TextDiff};
+
+use crate::core::{journal, tasks};
+use crate::types::errors::ErrorCode;
+use crate::types::spec::SpecContentData;
+use crate::utils::paths;
+
+/// Events remembered per project; older ones are dropped
+pub const MAX_EVENTS: usize = 1000;
+
+/// Directory below `~/.foundry` holding the journals
+pub const ACTIVITY_DIR_NAME: &str = ".activity";
+
+/// What one spec update did
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEvent {
+    /// When the update was made (RFC 3339)
+    pub timestamp: String,
+    pub spec_name: String,
+    /// Operation that made the update, e.g. "update_spec"
+    pub operation: String,
+    /// Tasks checked off by the update, without metadata tokens
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tasks_completed: Vec<String>,
+    /// Non-blank lines added to notes.md
+    #[serde(default)]
+    pub notes_lines_added: usize,
+}
+
+/// Journal what an update of a spec from `before` to `after` did, if anything
+pub async fn record_update(
+    project_name: &str,
+    spec_name: &str,
+    operation: &str,
+    before: &SpecContentData,
+    after: &SpecContentData,
+) -> Result<()> {
+    let Some(dir) = journal::dir(ACTIVITY_DIR_NAME)? else {
+        return Ok(());
+    };
+    let event = ActivityEvent {
+        timestamp: Utc::now().to_rfc3339(),
+        spec_name: spec_name.to_string(),
+        operation: operation.to_string(),
+        tasks_completed: completed_tasks(&before.tasks, &after.tasks),
+        notes_lines_added: added_lines(&before.notes, &after.notes),
+    };
+    if event.tasks_completed.is_empty() && event.notes_lines_added == 0 {
+        return Ok(());
+    }
+
+    journal::push(&journal_path(&dir, project_name)?, event, MAX_EVENTS)
+        .await
+        .context("Failed to write activity journal")
+}
+
+/// Journaled events of a project made in `since..until`, oldest first
+pub fn events(
+    project_name: &str,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Result<Vec<ActivityEvent>> {
+    let Some(dir) = journal::dir(ACTIVITY_DIR_NAME)? else {
+        return Ok(Vec::new());
+    };
+    Ok(
+        journal::read::<ActivityEvent>(&journal_path(&dir, project_name)?)?
+            .into_iter()
+            .filter(|event| {
+                DateTime::parse_from_rfc3339(&event.timestamp)
+                    .is_ok_and(|at| at >= since && at < until)
+            })
+            .collect(),
+    )
+}
+
+/// Tasks done in `after` that were open or missing in `before`
+pub fn completed_tasks(before: &str, after: &str) -> Vec<String> {
+    let done_before: Vec<String> = tasks::parse_task_list(before)
+        .iter()
+        .filter(|task| task.done)
+        .map(tasks::TaskEntry::title)
+        .collect();
+    tasks::parse_task_list(after)
+        .iter()
+        .filter(|task| task.done)
+        .map(tasks::TaskEntry::title)
+        .filter(|title| !done_before.contains(title))
+        .collect()
+}
+
+fn added_lines(before: &str, after: &str) -> usize {
+    TextDiff::from_lines(before, after)
+        .iter_all_changes()
+        .filter(|change| change.tag() == ChangeTag::Insert && !change.value().trim().is_empty())
+        .count()
+}
+
+fn journal_path(dir: &Path, project_name: &str) -> Result<PathBuf> {
+    if !paths::is_safe_component(project_name) {
+        return Err(
+            ErrorCode::InvalidParams.error(format!("Invalid project name '{}'", project_name))
+        );
+    }
+    Ok(dir.join(format!("{}.json", project_name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_activity_is_derived_from_content() {
+        let before = "## Phase 1\n\n- [ ] Add login @alice\n- [x] Write schema\n- [ ] Ship";
+        let after = "## Phase 1\n\n- [x] Add login @alice completed:2025-06-02\n- [x] Write schema\n- [ ] Ship\n- [x] Hotfix";
+        assert_eq!(completed_tasks(before, after), ["Add login", "Hotfix"]);
+        assert!(completed_tasks(after, before).is_empty());
+
+        assert_eq!(added_lines("Context\n", "Context\n\nDecided on JWT\n"), 1);
+        assert_eq!(added_lines("a\nb\n", "a\n"), 0);
+    }
+}
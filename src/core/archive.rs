@@ -0,0 +1,501 @@
+//! Portable project archives
+//!
+//! A project archive is a `.tar.gz` holding one project in the same layout as
+//! `~/.foundry`, plus a manifest:
+//!
+//! ```text
+//! <project>/manifest.json
+//! <project>/vision.md
+//! <project>/tech-stack.md
+//! <project>/summary.md
+//! <project>/decisions.md              (only when the project has one)
+//! <project>/specs/<spec>/spec.md
+//! <project>/specs/<spec>/notes.md
+//! <project>/specs/<spec>/task-list.md
+//! ```
+//!
+//! Archives are collected and restored through `Foundry`, so they work with
+//! any `FoundryBackend`. Restoring creates each spec anew, so specs get fresh
+//! timestamped names and `[[wiki-links]]` between them are rewritten to match.
+
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+
+use anyhow::{Context, Result};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+
+use crate::core::backends::FoundryBackend;
+use crate::core::foundry::Foundry;
+use crate::core::links;
+use crate::types::errors::ErrorCode;
+use crate::types::project::ProjectConfig;
+use crate::types::spec::{SpecConfig, SpecContentData, SpecFileType, SpecStatus};
+
+/// Version of the archive layout written by this build
+pub const FORMAT_VERSION: u32 = 1;
+
+/// File extension for project archives
+pub const ARCHIVE_EXTENSION: &str = "foundry.tar.gz";
+
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Largest total of file contents read from an archive
+const MAX_ARCHIVE_CONTENT_BYTES: u64 = 50 * 1024 * 1024;
+
+/// Describes the archived project and its specs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    pub format_version: u32,
+    pub project_name: String,
+    pub exported_at: String,
+    /// Foundry version that wrote the archive
+    pub foundry_version: String,
+    /// The project's tags, as exported
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    pub specs: Vec<ArchivedSpecEntry>,
+}
+
+/// A spec as listed in the manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedSpecEntry {
+    pub name: String,
+    pub feature_name: String,
+    pub created_at: String,
+    /// Lifecycle status; archives written before statuses existed hold drafts
+    #[serde(default)]
+    pub status: SpecStatus,
+    /// Names of the specs this spec depends on, as exported
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// A project read from storage or an archive
+#[derive(Debug, Clone)]
+pub struct ProjectArchive {
+    pub manifest: ArchiveManifest,
+    pub vision: String,
+    pub tech_stack: String,
+    pub summary: String,
+    pub decisions: Option<String>,
+    /// Spec content in manifest order
+    pub specs: Vec<(ArchivedSpecEntry, SpecContentData)>,
+}
+
+/// A spec restored from an archive under a new name
+#[derive(Debug, Clone)]
+pub struct RestoredSpec {
+    pub original_name: String,
+    pub spec_name: String,
+}
+
+impl ProjectArchive {
+    /// Number of files the archive holds, including the manifest
+    pub fn file_count(&self) -> usize {
+        4 + usize::from(self.decisions.is_some()) + 3 * self.specs.len()
+    }
+}
+
+/// Read a whole project through `foundry`
+pub async fn collect<B: FoundryBackend>(
+    foundry: &Foundry<B>,
+    project_name: &str,
+) -> Result<ProjectArchive> {
+    let project = foundry
+        .load_project(project_name)
+        .await
+        .with_context(|| format!("Failed to load project '{}'", project_name))?;
+
+    let mut spec_entries = foundry
+        .list_specs(project_name)
+        .await
+        .with_context(|| format!("Failed to list specs for project '{}'", project_name))?;
+    spec_entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut specs = Vec::with_capacity(spec_entries.len());
+    for entry in spec_entries {
+        let spec = foundry
+            .load_spec(project_name, &entry.name)
+            .await
+            .with_context(|| format!("Failed to load spec '{}'", entry.name))?;
+        specs.push((
+            ArchivedSpecEntry {
+                name: entry.name,
+                feature_name: entry.feature_name,
+                created_at: entry.created_at,
+                status: entry.status,
+                depends_on: entry.depends_on,
+                tags: entry.tags,
+            },
+            spec.content,
+        ));
+    }
+
+    let tags = foundry
+        .list_projects()
+        .await?
+        .into_iter()
+        .find(|metadata| metadata.name == project_name)
+        .map(|metadata| metadata.tags)
+        .unwrap_or_default();
+
+    Ok(ProjectArchive {
+        manifest: ArchiveManifest {
+            format_version: FORMAT_VERSION,
+            project_name: project.name,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            foundry_version: env!("CARGO_PKG_VERSION").to_string(),
+            tags,
+            specs: specs.iter().map(|(entry, _)| entry.clone()).collect(),
+        },
+        vision: project.vision.unwrap_or_default(),
+        tech_stack: project.tech_stack.unwrap_or_default(),
+        summary: project.summary.unwrap_or_default(),
+        decisions: project.decisions,
+        specs,
+    })
+}
+
+/// Write `archive` as a gzipped tarball
+pub fn write_tar_gz<W: Write>(archive: &ProjectArchive, writer: W) -> Result<W> {
+    let root = &archive.manifest.project_name;
+    let mut builder = tar::Builder::new(GzEncoder::new(writer, Compression::default()));
+
+    let manifest = serde_json::to_string_pretty(&archive.manifest)?;
+    let mut files = vec![
+        (
+            format!("{}/{}", root, MANIFEST_FILE_NAME),
+            manifest.as_str(),
+        ),
+        (format!("{}/vision.md", root), archive.vision.as_str()),
+        (
+            format!("{}/tech-stack.md", root),
+            archive.tech_stack.as_str(),
+        ),
+        (format!("{}/summary.md", root), archive.summary.as_str()),
+    ];
+    if let Some(decisions) = &archive.decisions {
+        files.push((format!("{}/decisions.md", root), decisions.as_str()));
+    }
+    for (entry, content) in &archive.specs {
+        let dir = format!("{}/specs/{}", root, entry.name);
+        files.push((format!("{}/spec.md", dir), content.spec.as_str()));
+        files.push((format!("{}/notes.md", dir), content.notes.as_str()));
+        files.push((format!("{}/task-list.md", dir), content.tasks.as_str()));
+    }
+
+    let mtime = chrono::Utc::now().timestamp().max(0) as u64;
+    for (path, content) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(mtime);
+        builder
+            .append_data(&mut header, &path, content.as_bytes())
+            .with_context(|| format!("Failed to add {} to archive", path))?;
+    }
+
+    builder
+        .into_inner()
+        .context("Failed to finish archive")?
+        .finish()
+        .context("Failed to compress archive")
+}
+
+/// Read a gzipped tarball written by `write_tar_gz`
+///
+/// Only the files of the archive layout are read; anything else is ignored.
+pub fn read_tar_gz<R: Read>(reader: R) -> Result<ProjectArchive> {
+    let mut tarball = tar::Archive::new(GzDecoder::new(reader));
+    let mut files: BTreeMap<String, String> = BTreeMap::new();
+    let mut total: u64 = 0;
+
+    for entry in tarball.entries().context("Failed to read archive")? {
+        let mut entry = entry.context("Failed to read archive entry")?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path()?.to_string_lossy().replace('\\', "/");
+        total += entry.size();
+        if total > MAX_ARCHIVE_CONTENT_BYTES {
+            anyhow::bail!(
+                "Archive content exceeds {} MB",
+                MAX_ARCHIVE_CONTENT_BYTES / (1024 * 1024)
+            );
+        }
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .with_context(|| format!("Archive entry {} is not UTF-8 text", path))?;
+        files.insert(path, content);
+    }
+
+    let (manifest_path, manifest) = files
+        .iter()
+        .find(|(path, _)| {
+            path.split_once('/')
+                .is_some_and(|(_, file)| file == MANIFEST_FILE_NAME)
+        })
+        .context("Archive has no manifest.json; was it created by `foundry export`?")?;
+    let manifest: ArchiveManifest = serde_json::from_str(manifest)
+        .with_context(|| format!("Invalid archive manifest: {}", manifest_path))?;
+    if manifest.format_version > FORMAT_VERSION {
+        anyhow::bail!(
+            "Archive format version {} is newer than this Foundry supports ({}); upgrade Foundry to import it",
+            manifest.format_version,
+            FORMAT_VERSION
+        );
+    }
+    let root = manifest_path
+        .split_once('/')
+        .map_or("", |(root, _)| root)
+        .to_string();
+
+    let mut take = |relative: &str| -> Result<String> {
+        files
+            .remove(&format!("{}/{}", root, relative))
+            .with_context(|| format!("Archive is missing {}", relative))
+    };
+
+    let vision = take("vision.md")?;
+    let tech_stack = take("tech-stack.md")?;
+    let summary = take("summary.md")?;
+    let decisions = take("decisions.md").ok();
+    let mut specs = Vec::with_capacity(manifest.specs.len());
+    for entry in &manifest.specs {
+        Foundry::<crate::core::backends::SharedBackend>::validate_spec_name(&entry.name)
+            .with_context(|| format!("Archive lists an invalid spec name '{}'", entry.name))?;
+        let content = SpecContentData {
+            spec: take(&format!("specs/{}/spec.md", entry.name))?,
+            notes: take(&format!("specs/{}/notes.md", entry.name))?,
+            tasks: take(&format!("specs/{}/task-list.md", entry.name))?,
+        };
+        specs.push((entry.clone(), content));
+    }
+
+    Ok(ProjectArchive {
+        manifest,
+        vision,
+        tech_stack,
+        summary,
+        decisions,
+        specs,
+    })
+}
+
+/// Create `project_name` from `archive` through `foundry`
+///
+/// Specs are created in their original order and links between them are
+/// rewritten to the new spec names.
+pub async fn restore<B: FoundryBackend>(
+    foundry: &Foundry<B>,
+    archive: &ProjectArchive,
+    project_name: &str,
+) -> Result<(Vec<RestoredSpec>, usize)> {
+    if foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::AlreadyExists.error(format!(
+            "Project '{}' already exists; import under another name with 'project_name'",
+            project_name
+        )));
+    }
+
+    foundry
+        .create_project(ProjectConfig {
+            name: project_name.to_string(),
+            vision: archive.vision.clone(),
+            tech_stack: archive.tech_stack.clone(),
+            summary: archive.summary.clone(),
+        })
+        .await
+        .with_context(|| format!("Failed to create project '{}'", project_name))?;
+    if !archive.manifest.tags.is_empty() {
+        foundry
+            .set_project_tags(project_name, &archive.manifest.tags)
+            .await
+            .context("Failed to restore the project's tags")?;
+    }
+
+    let mut restored = Vec::with_capacity(archive.specs.len());
+    for (entry, content) in &archive.specs {
+        let spec = foundry
+            .create_spec(SpecConfig {
+                project_name: project_name.to_string(),
+                feature_name: entry.feature_name.clone(),
+                content: content.clone(),
+            })
+            .await
+            .with_context(|| format!("Failed to restore spec '{}'", entry.name))?;
+        if entry.status != SpecStatus::default() {
+            foundry
+                .set_spec_status(project_name, &spec.name, entry.status)
+                .await
+                .with_context(|| format!("Failed to restore status of spec '{}'", entry.name))?;
+        }
+        if !entry.tags.is_empty() {
+            foundry
+                .set_spec_tags(project_name, &spec.name, &entry.tags)
+                .await
+                .with_context(|| format!("Failed to restore tags of spec '{}'", entry.name))?;
+        }
+        restored.push((
+            RestoredSpec {
+                original_name: entry.name.clone(),
+                spec_name: spec.name,
+            },
+            content,
+        ));
+    }
+
+    // Dependencies name the original specs, so map them to the restored names
+    for ((entry, _), (spec, _)) in archive.specs.iter().zip(&restored) {
+        if entry.depends_on.is_empty() {
+            continue;
+        }
+        let depends_on: Vec<String> = entry
+            .depends_on
+            .iter()
+            .map(|dependency| {
+                restored
+                    .iter()
+                    .find(|(renamed, _)| &renamed.original_name == dependency)
+                    .map_or_else(
+                        || dependency.clone(),
+                        |(renamed, _)| renamed.spec_name.clone(),
+                    )
+            })
+            .collect();
+        foundry
+            .set_spec_dependencies(project_name, &spec.spec_name, &depends_on)
+            .await
+            .with_context(|| format!("Failed to restore dependencies of spec '{}'", entry.name))?;
+    }
+
+    // Decisions name the specs they came from, so point them at the restored names
+    if let Some(decisions) = &archive.decisions {
+        let decisions = restored.iter().fold(decisions.clone(), |text, (spec, _)| {
+            text.replace(&spec.original_name, &spec.spec_name)
+        });
+        foundry
+            .update_decisions(project_name, &decisions)
+            .await
+            .context("Failed to restore the decision log")?;
+    }
+
+    let mut links_rewritten = 0;
+    for (spec, content) in &restored {
+        for (file_type, original) in [
+            (SpecFileType::Spec, &content.spec),
+            (SpecFileType::Notes, &content.notes),
+            (SpecFileType::TaskList, &content.tasks),
+        ] {
+            let mut updated = original.clone();
+            let mut count = 0;
+            for (renamed, _) in &restored {
+                let (rewritten, changed) = links::rewrite_wiki_links(
+                    &updated,
+                    &[renamed.original_name.as_str()],
+                    &renamed.spec_name,
+                );
+                updated = rewritten;
+                count += changed;
+            }
+            if count > 0 {
+                foundry
+                    .update_spec_content(project_name, &spec.spec_name, file_type, &updated)
+                    .await?;
+                links_rewritten += count;
+            }
+        }
+    }
+
+    Ok((
+        restored.into_iter().map(|(spec, _)| spec).collect(),
+        links_rewritten,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_archive() -> ProjectArchive {
+        let entry = ArchivedSpecEntry {
+            name: "20250101_120000_auth".to_string(),
+            feature_name: "auth".to_string(),
+            created_at: "2025-01-01T12:00:00Z".to_string(),
+            status: SpecStatus::Completed,
+            depends_on: Vec::new(),
+            tags: Vec::new(),
+        };
+        ProjectArchive {
+            manifest: ArchiveManifest {
+                format_version: FORMAT_VERSION,
+                project_name: "demo".to_string(),
+                exported_at: "2025-01-02T00:00:00Z".to_string(),
+                foundry_version: "0.0.0".to_string(),
+                tags: Vec::new(),
+                specs: vec![entry.clone()],
+            },
+            vision: "Vision".to_string(),
+            tech_stack: "Rust".to_string(),
+            summary: "Summary".to_string(),
+            decisions: None,
+            specs: vec![(
+                entry,
+                SpecContentData {
+                    spec: "# Auth".to_string(),
+                    notes: "Notes".to_string(),
+                    tasks: "- [ ] Login".to_string(),
+                },
+            )],
+        }
+    }
+
+    #[test]
+    fn test_tar_gz_round_trip() {
+        let archive = sample_archive();
+        let bytes = write_tar_gz(&archive, Vec::new()).unwrap();
+
+        let read = read_tar_gz(bytes.as_slice()).unwrap();
+        assert_eq!(read.manifest.project_name, "demo");
+        assert_eq!(read.tech_stack, "Rust");
+        assert_eq!(read.specs.len(), 1);
+        assert_eq!(read.specs[0].0.feature_name, "auth");
+        assert_eq!(read.specs[0].1.tasks, "- [ ] Login");
+        assert_eq!(read.file_count(), 7);
+        assert!(read.decisions.is_none());
+
+        let mut archive = sample_archive();
+        archive.decisions = Some("## ADR-001: Use Rust".to_string());
+        let bytes = write_tar_gz(&archive, Vec::new()).unwrap();
+        let read = read_tar_gz(bytes.as_slice()).unwrap();
+        assert_eq!(read.decisions.as_deref(), Some("## ADR-001: Use Rust"));
+        assert_eq!(read.file_count(), 8);
+    }
+
+    #[test]
+    fn test_read_rejects_incomplete_and_newer_archives() {
+        let mut archive = sample_archive();
+        archive.manifest.format_version = FORMAT_VERSION + 1;
+        let bytes = write_tar_gz(&archive, Vec::new()).unwrap();
+        let error = read_tar_gz(bytes.as_slice()).unwrap_err();
+        assert!(error.to_string().contains("newer"));
+
+        let mut archive = sample_archive();
+        archive.manifest.specs[0].name = "20250101_120000_missing".to_string();
+        let bytes = write_tar_gz(&archive, Vec::new()).unwrap();
+        // The manifest names a spec whose files are stored under another name
+        let error = read_tar_gz(bytes.as_slice()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Archive is missing specs/20250101_120000_missing/spec.md"
+        );
+
+        assert!(read_tar_gz(&b"not an archive"[..]).is_err());
+    }
+}
@@ -0,0 +1,205 @@
+//! Audit log of changes
+//!
+//! Every call that changes a project appends one entry to the project's audit
+//! log under `~/.foundry/.audit`:
+//!
+//! ```text
+//! .audit/<project>.jsonl
+//! ```
+//!
+//! The log is append-only: entries are never rewritten or dropped. The MCP
+//! server records every call to a tool outside its read-only set (REST calls
+//! included), and CLI commands that change data record themselves. When
+//! encryption at rest is on, each line is encrypted on its own; like the
+//! activity journal, nothing is recorded while backups are turned off.
+
+use std::fs::OpenOptions;
+use std::future::Future;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde_json::Value;
+
+use crate::core::{backup, encryption};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{AuditEntry, AuditInterface, AuditOutcome};
+use crate::utils::paths;
+
+/// Directory below `~/.foundry` holding the audit logs
+pub const AUDIT_DIR_NAME: &str = ".audit";
+
+tokio::task_local! {
+    static INTERFACE: AuditInterface;
+}
+
+/// Run `future` with the changes it makes attributed to `interface`
+pub async fn with_interface<F: Future>(interface: AuditInterface, future: F) -> F::Output {
+    INTERFACE.scope(interface, future).await
+}
+
+/// Interface of the current call; the CLI unless a server set one
+pub fn current_interface() -> AuditInterface {
+    INTERFACE
+        .try_with(|interface| *interface)
+        .unwrap_or(AuditInterface::Cli)
+}
+
+/// The audit log directory in the current context; `None` when backups are turned off
+pub fn current_dir() -> Result<Option<PathBuf>> {
+    if backup::current_dir()?.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(
+        crate::core::filesystem::foundry_dir()?.join(AUDIT_DIR_NAME),
+    ))
+}
+
+/// Record a call to `operation` with tool-style `params` in a project's audit log
+///
+/// The spec and edit commands are taken from `params` (`spec_name` with
+/// `commands`/`commands_json`); an `update_specs_batch` call gets one entry
+/// per updated spec. `error_code` is `None` for a call that succeeded.
+pub fn record_call(
+    project_name: &str,
+    operation: &str,
+    params: &Value,
+    error_code: Option<ErrorCode>,
+    request_id: Option<&str>,
+) -> Result<()> {
+    let Some(dir) = current_dir()? else {
+        return Ok(());
+    };
+    let entry = |spec_name: Option<String>, commands: Vec<Value>| AuditEntry {
+        timestamp: Utc::now().to_rfc3339(),
+        interface: current_interface(),
+        operation: operation.to_string(),
+        spec_name,
+        commands,
+        outcome: if error_code.is_some() {
+            AuditOutcome::Error
+        } else {
+            AuditOutcome::Ok
+        },
+        error_code: error_code.map(|code| code.as_str().to_string()),
+        request_id: request_id.map(str::to_string),
+    };
+
+    let updates = json_array(&params["updates"]);
+    let entries: Vec<AuditEntry> = if updates.is_empty() {
+        vec![entry(spec_name(params), commands(params))]
+    } else {
+        updates
+            .iter()
+            .map(|update| entry(spec_name(update), commands(update)))
+            .collect()
+    };
+    append(&log_path(&dir, project_name)?, &entries)
+}
+
+/// Every entry of a project's audit log, oldest first
+pub fn entries(project_name: &str) -> Result<Vec<AuditEntry>> {
+    let Some(dir) = current_dir()? else {
+        return Ok(Vec::new());
+    };
+    let path = log_path(&dir, project_name)?;
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read audit log {:?}", path))?;
+    let key = encryption::configured_key()?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(index, line)| {
+            let line = if encryption::is_encrypted(line) {
+                let key = key.as_ref().ok_or_else(|| {
+                    ErrorCode::InvalidParams.error(format!(
+                        "{:?} is encrypted but no key is configured. Set [encryption] in config.toml",
+                        path
+                    ))
+                })?;
+                encryption::decrypt(key, &path, line)?
+            } else {
+                line.to_string()
+            };
+            serde_json::from_str(&line)
+                .with_context(|| format!("Invalid entry {} in audit log {:?}", index + 1, path))
+        })
+        .collect()
+}
+
+fn append(path: &Path, entries: &[AuditEntry]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        crate::core::filesystem::create_dir_all(parent)?;
+    }
+    let key = encryption::configured_key()?;
+    let mut lines = String::new();
+    for entry in entries {
+        let line = serde_json::to_string(entry)?;
+        let line = match &key {
+            Some(key) => encryption::encrypt(key, path, &line)?,
+            None => line,
+        };
+        lines.push_str(&line);
+        lines.push('\n');
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .with_context(|| format!("Failed to append to audit log {:?}", path))
+}
+
+fn spec_name(params: &Value) -> Option<String> {
+    params["spec_name"].as_str().map(str::to_string)
+}
+
+/// Edit commands in `params`, given as an array or a JSON string of one
+fn commands(params: &Value) -> Vec<Value> {
+    ["commands", "commands_json"]
+        .iter()
+        .map(|key| json_array(&params[*key]))
+        .find(|commands| !commands.is_empty())
+        .unwrap_or_default()
+}
+
+fn json_array(value: &Value) -> Vec<Value> {
+    match value {
+        Value::Array(items) => items.clone(),
+        Value::String(json) => match serde_json::from_str(json) {
+            Ok(Value::Array(items)) => items,
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    }
+}
+
+fn log_path(dir: &Path, project_name: &str) -> Result<PathBuf> {
+    if !paths::is_safe_component(project_name) {
+        return Err(
+            ErrorCode::InvalidParams.error(format!("Invalid project name '{}'", project_name))
+        );
+    }
+    Ok(dir.join(format!("{}.jsonl", project_name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_commands_are_read_from_arrays_and_json_strings() {
+        let expected = vec![json!({"target": "tasks", "command": "upsert_task"})];
+        let array = json!({"commands": [{"target": "tasks", "command": "upsert_task"}]});
+        let string = json!({"commands_json": json!(expected).to_string()});
+        assert_eq!(commands(&array), expected);
+        assert_eq!(commands(&string), expected);
+        assert!(commands(&json!({"commands": "not json"})).is_empty());
+    }
+}
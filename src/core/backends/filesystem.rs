@@ -6,37 +6,185 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::warn;
 
 use crate::core::backends::{BackendCapabilities, FoundryBackend, ResourceLocator};
-use crate::core::filesystem;
+use crate::core::lock::{self, WriteLock};
+use crate::core::{encryption, filesystem, spec_name, tasks};
+use crate::types::errors::ErrorCode;
 use crate::types::{
-    project::{Project, ProjectConfig, ProjectMetadata},
-    spec::{Spec, SpecConfig, SpecContentData, SpecFileType, SpecMetadata},
+    project::{Project, ProjectConfig, ProjectFileType, ProjectMetadata},
+    spec::{
+        Spec, SpecConfig, SpecContentData, SpecFileType, SpecMetadata, SpecStatus, SpecSummary,
+        TaskCounts,
+    },
 };
 use crate::utils::timestamp;
 
+/// Per-spec metadata file, next to spec.md
+pub const SPEC_METADATA_FILE: &str = "metadata.json";
+
+/// Drafted spec summary, next to spec.md; spec content, so it is written
+/// encrypted when encryption at rest is on, unlike metadata.json
+pub const SPEC_SUMMARY_FILE: &str = "summary.json";
+
+/// Project decision log, next to vision.md
+pub const DECISIONS_FILE: &str = "decisions.md";
+
+/// Per-project metadata file, next to vision.md
+pub const PROJECT_METADATA_FILE: &str = "metadata.json";
+
+/// Contents of a spec's metadata.json
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct StoredSpecMetadata {
+    #[serde(default)]
+    status: SpecStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    status_updated_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    depends_on: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    /// Creation time, kept for spec names that do not record one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<String>,
+    /// Summary written by earlier versions; moved to [`SPEC_SUMMARY_FILE`]
+    /// the next time the summary is set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    summary: Option<SpecSummary>,
+    /// When foundry last changed each file, by file name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    modified_at: BTreeMap<String, String>,
+}
+
+/// Contents of a project's metadata.json
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct StoredProjectMetadata {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+}
+
 /// Filesystem backend implementation
 ///
 /// Implements the FoundryBackend trait using direct filesystem operations.
 /// Preserves existing directory structure, atomic writes, and timestamp formats.
-pub struct FilesystemBackend;
+pub struct FilesystemBackend {
+    /// Directory holding the projects; `~/.foundry` when not set
+    root: Option<PathBuf>,
+}
 
 impl FilesystemBackend {
     pub fn new() -> Self {
-        Self
+        Self { root: None }
+    }
+
+    /// Store projects under `root` instead of `~/.foundry`
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: Some(root.into()),
+        }
+    }
+
+    /// Directory holding the projects
+    pub fn root(&self) -> Result<PathBuf> {
+        self.root.clone().map_or_else(filesystem::foundry_dir, Ok)
     }
 
     fn get_project_path(&self, name: &str) -> Result<PathBuf> {
-        let foundry_dir = filesystem::foundry_dir()?;
-        Ok(foundry_dir.join(name))
+        Ok(self.root()?.join(name))
     }
 
     fn get_spec_path(&self, project_name: &str, spec_name: &str) -> Result<PathBuf> {
-        let foundry_dir = filesystem::foundry_dir()?;
-        Ok(foundry_dir.join(project_name).join("specs").join(spec_name))
+        Ok(self
+            .root()?
+            .join(project_name)
+            .join("specs")
+            .join(spec_name))
+    }
+
+    /// Metadata stored for the spec at `spec_path`; defaults when there is none
+    fn read_spec_metadata(spec_path: &std::path::Path) -> Result<StoredSpecMetadata> {
+        read_metadata(&spec_path.join(SPEC_METADATA_FILE))
+    }
+
+    /// Summary of the spec at `spec_path`, from [`SPEC_SUMMARY_FILE`] or, for
+    /// specs summarized before it existed, from its metadata
+    fn read_spec_summary(
+        spec_path: &Path,
+        metadata: &StoredSpecMetadata,
+    ) -> Result<Option<SpecSummary>> {
+        let path = spec_path.join(SPEC_SUMMARY_FILE);
+        if !path.exists() {
+            return Ok(metadata.summary.clone());
+        }
+        serde_json::from_str(&filesystem::read_file(&path)?)
+            .map(Some)
+            .map_err(|e| {
+                ErrorCode::ValidationFailed
+                    .error(format!("Spec summary {:?} is not valid: {}", path, e))
+            })
+    }
+
+    /// Creation time of the spec at `spec_path`: from its name, else its
+    /// metadata, else the directory's creation time
+    fn spec_created_at(
+        spec_path: &Path,
+        parsed: &spec_name::ParsedSpecName,
+        metadata: &StoredSpecMetadata,
+    ) -> String {
+        parsed
+            .created_at()
+            .or_else(|| metadata.created_at.clone())
+            .or_else(|| {
+                let created = fs::metadata(spec_path).and_then(|m| m.created()).ok()?;
+                Some(DateTime::<Utc>::from(created).to_rfc3339())
+            })
+            .unwrap_or_else(timestamp::iso_timestamp)
+    }
+
+    /// Names of the directories in `specs_dir`; empty when it does not exist
+    fn spec_dir_names(specs_dir: &Path) -> Result<Vec<String>> {
+        if !specs_dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut names = Vec::new();
+        for entry in fs::read_dir(specs_dir)
+            .with_context(|| format!("Failed to read specs directory {:?}", specs_dir))?
+        {
+            let entry = entry?;
+            if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+                names.push(entry.file_name().to_string_lossy().to_string());
+            }
+        }
+        Ok(names)
+    }
+
+    /// Metadata stored for the project at `project_path`; defaults when there is none
+    fn read_project_metadata(project_path: &std::path::Path) -> Result<StoredProjectMetadata> {
+        read_metadata(&project_path.join(PROJECT_METADATA_FILE))
+    }
+
+    /// Task counts of the spec at `spec_path`; zero when task-list.md cannot be read
+    fn read_task_counts(spec_path: &std::path::Path) -> TaskCounts {
+        match filesystem::read_file(spec_path.join("task-list.md")) {
+            Ok(content) => tasks::count_tasks(&content),
+            Err(e) => {
+                warn!("Failed to count tasks in {:?}: {:#}", spec_path, e);
+                TaskCounts::default()
+            }
+        }
+    }
+
+    /// Newest modification time of the spec's markdown files, as RFC 3339
+    fn content_modified_at(spec_path: &std::path::Path) -> Option<String> {
+        ["spec.md", "notes.md", "task-list.md"]
+            .iter()
+            .filter_map(|file| fs::metadata(spec_path.join(file)).ok()?.modified().ok())
+            .max()
+            .map(|modified| DateTime::<Utc>::from(modified).to_rfc3339())
     }
 
     fn capabilities() -> BackendCapabilities {
@@ -67,9 +215,9 @@ impl FoundryBackend for FilesystemBackend {
         filesystem::create_dir_all(project_path.join("specs"))?;
 
         // Write project files
-        filesystem::write_file_atomic(project_path.join("vision.md"), &config.vision)?;
-        filesystem::write_file_atomic(project_path.join("tech-stack.md"), &config.tech_stack)?;
-        filesystem::write_file_atomic(project_path.join("summary.md"), &config.summary)?;
+        filesystem::write_content_atomic(project_path.join("vision.md"), &config.vision)?;
+        filesystem::write_content_atomic(project_path.join("tech-stack.md"), &config.tech_stack)?;
+        filesystem::write_content_atomic(project_path.join("summary.md"), &config.summary)?;
 
         let path_string = project_path.to_string_lossy().to_string();
         Ok(Project {
@@ -81,33 +229,46 @@ impl FoundryBackend for FilesystemBackend {
             vision: Some(config.vision),
             tech_stack: Some(config.tech_stack),
             summary: Some(config.summary),
+            decisions: None,
         })
     }
 
     async fn project_exists(&self, name: &str) -> Result<bool> {
         let project_path = self.get_project_path(name)?;
-        Ok(project_path.exists())
+        Ok(is_project_dir(&project_path))
     }
 
     async fn list_projects(&self) -> Result<Vec<ProjectMetadata>> {
-        let foundry_dir = filesystem::foundry_dir()?;
+        let foundry_dir = self.root()?;
 
         if !foundry_dir.exists() {
             return Ok(Vec::new());
         }
 
+        let mut unreadable_count = 0;
         let projects: Vec<ProjectMetadata> = fs::read_dir(foundry_dir)?
             .filter_map(|entry| {
                 let entry = entry.ok()?;
-                if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                // Dot directories hold Foundry's own data (logs), not projects
+                let hidden = entry.file_name().to_string_lossy().starts_with('.');
+                if !hidden && is_project_dir(&entry.path()) {
                     Some(entry)
                 } else {
                     None
                 }
             })
-            .map(|entry| {
+            .filter_map(|entry| {
                 let project_name = entry.file_name().to_string_lossy().to_string();
                 let project_path = entry.path();
+                // One corrupt metadata file must not hide every other project
+                let metadata = match Self::read_project_metadata(&project_path) {
+                    Ok(metadata) => metadata,
+                    Err(e) => {
+                        unreadable_count += 1;
+                        warn!("Skipping project '{}': {:#}", project_name, e);
+                        return None;
+                    }
+                };
 
                 // Count specs using fold
                 let specs_dir = project_path.join("specs");
@@ -133,15 +294,23 @@ impl FoundryBackend for FilesystemBackend {
                     .map(|dt| dt.to_rfc3339())
                     .unwrap_or_else(|| Utc::now().to_rfc3339());
 
-                ProjectMetadata {
+                Some(ProjectMetadata {
                     name: project_name,
                     created_at: created_at.clone(),
                     spec_count,
                     last_modified: created_at, // TODO: Use actual last modified time
-                }
+                    tags: metadata.tags,
+                })
             })
             .collect();
 
+        if unreadable_count > 0 {
+            warn!(
+                "Skipped {} projects with unreadable metadata",
+                unreadable_count
+            );
+        }
+
         Ok(projects)
     }
 
@@ -149,13 +318,14 @@ impl FoundryBackend for FilesystemBackend {
         let project_path = self.get_project_path(name)?;
 
         if !project_path.exists() {
-            return Err(anyhow::anyhow!("Project '{}' not found", name));
+            return Err(ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", name)));
         }
 
         // Read project files
         let vision = filesystem::read_file(project_path.join("vision.md")).ok();
         let tech_stack = filesystem::read_file(project_path.join("tech-stack.md")).ok();
         let summary = filesystem::read_file(project_path.join("summary.md")).ok();
+        let decisions = filesystem::read_file(project_path.join(DECISIONS_FILE)).ok();
 
         // Get creation time from directory metadata
         let created_at =
@@ -171,15 +341,72 @@ impl FoundryBackend for FilesystemBackend {
             vision,
             tech_stack,
             summary,
+            decisions,
         })
     }
 
+    async fn update_decisions(&self, project_name: &str, content: &str) -> Result<()> {
+        let project_path = self.get_project_path(project_name)?;
+        if !project_path.exists() {
+            return Err(
+                ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+            );
+        }
+        filesystem::write_content_atomic(project_path.join(DECISIONS_FILE), content)
+    }
+
+    async fn update_project_content(
+        &self,
+        project_name: &str,
+        file_type: ProjectFileType,
+        content: &str,
+    ) -> Result<()> {
+        let project_path = self.get_project_path(project_name)?;
+        if !is_project_dir(&project_path) {
+            return Err(
+                ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+            );
+        }
+        filesystem::write_content_atomic(project_path.join(file_type.file_name()), content)
+    }
+
+    async fn delete_project(&self, name: &str) -> Result<()> {
+        let project_path = self.get_project_path(name)?;
+        if !is_project_dir(&project_path) {
+            return Err(ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", name)));
+        }
+        fs::remove_dir_all(&project_path)
+            .with_context(|| format!("Failed to delete project '{}'", name))?;
+        lock::remove_project_locks(&self.root()?, name)
+    }
+
+    async fn set_project_tags(&self, project_name: &str, tags: &[String]) -> Result<()> {
+        let project_path = self.get_project_path(project_name)?;
+        if !is_project_dir(&project_path) {
+            return Err(
+                ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+            );
+        }
+
+        let mut metadata = Self::read_project_metadata(&project_path)?;
+        metadata.tags = tags.to_vec();
+        filesystem::write_file_atomic(
+            project_path.join(PROJECT_METADATA_FILE),
+            &serde_json::to_string_pretty(&metadata)?,
+        )
+        .with_context(|| format!("Failed to update tags for project '{}'", project_name))
+    }
+
     async fn create_spec(&self, config: SpecConfig) -> Result<Spec> {
-        let foundry_dir = filesystem::foundry_dir()?;
+        let foundry_dir = self.root()?;
         let project_path = foundry_dir.join(&config.project_name);
         let specs_dir = project_path.join("specs");
-        let spec_name =
-            crate::core::foundry::Foundry::<Self>::generate_spec_name(&config.feature_name);
+        let existing = Self::spec_dir_names(&specs_dir)?;
+        let spec_name = crate::core::spec::generate_spec_name(
+            &config.project_name,
+            &config.feature_name,
+            &existing,
+        )?;
         let spec_path = specs_dir.join(&spec_name);
         let created_at = Utc::now().to_rfc3339();
 
@@ -187,9 +414,19 @@ impl FoundryBackend for FilesystemBackend {
         filesystem::create_dir_all(&spec_path)?;
 
         // Write spec files
-        filesystem::write_file_atomic(spec_path.join("spec.md"), &config.content.spec)?;
-        filesystem::write_file_atomic(spec_path.join("notes.md"), &config.content.notes)?;
-        filesystem::write_file_atomic(spec_path.join("task-list.md"), &config.content.tasks)?;
+        filesystem::write_content_atomic(spec_path.join("spec.md"), &config.content.spec)?;
+        filesystem::write_content_atomic(spec_path.join("notes.md"), &config.content.notes)?;
+        filesystem::write_content_atomic(spec_path.join("task-list.md"), &config.content.tasks)?;
+        if spec_name::parse(&spec_name).is_some_and(|parsed| parsed.created_at().is_none()) {
+            let metadata = StoredSpecMetadata {
+                created_at: Some(created_at.clone()),
+                ..StoredSpecMetadata::default()
+            };
+            filesystem::write_file_atomic(
+                spec_path.join(SPEC_METADATA_FILE),
+                &serde_json::to_string_pretty(&metadata)?,
+            )?;
+        }
 
         let path_string = spec_path.to_string_lossy().to_string();
         Ok(Spec {
@@ -204,7 +441,7 @@ impl FoundryBackend for FilesystemBackend {
     }
 
     async fn list_specs(&self, project_name: &str) -> Result<Vec<SpecMetadata>> {
-        let foundry_dir = filesystem::foundry_dir()?;
+        let foundry_dir = self.root()?;
         let specs_dir = foundry_dir.join(project_name).join("specs");
 
         if !specs_dir.exists() {
@@ -227,24 +464,41 @@ impl FoundryBackend for FilesystemBackend {
                 if file_type.is_dir() {
                     let spec_name = entry.file_name().to_string_lossy().to_string();
 
-                    // Use enhanced timestamp parsing with better error handling
-                    match (
-                        timestamp::parse_spec_timestamp(&spec_name),
-                        timestamp::extract_feature_name(&spec_name),
-                    ) {
-                        (Some(timestamp_str), Some(feature_name)) => {
-                            // Convert timestamp to ISO format for consistent storage
-                            let created_at = timestamp::spec_timestamp_to_iso(&timestamp_str)
-                                .unwrap_or_else(|_| timestamp::iso_timestamp());
-
+                    match spec_name::parse(&spec_name) {
+                        Some(parsed) => {
+                            // One corrupt metadata file must not hide the rest of the project
+                            let metadata = match Self::read_spec_metadata(&entry.path()) {
+                                Ok(metadata) => metadata,
+                                Err(e) => {
+                                    malformed_count += 1;
+                                    warn!("Skipping spec '{}': {:#}", spec_name, e);
+                                    continue;
+                                }
+                            };
+                            let summary = Self::read_spec_summary(&entry.path(), &metadata)
+                                .unwrap_or_else(|e| {
+                                    warn!("Ignoring summary of spec '{}': {:#}", spec_name, e);
+                                    None
+                                });
                             specs.push(SpecMetadata {
                                 name: spec_name.clone(),
-                                created_at,
-                                feature_name,
+                                created_at: Self::spec_created_at(
+                                    &entry.path(),
+                                    &parsed,
+                                    &metadata,
+                                ),
+                                feature_name: parsed.feature_name,
                                 project_name: project_name.to_string(),
+                                status: metadata.status,
+                                depends_on: metadata.depends_on,
+                                tags: metadata.tags,
+                                task_counts: Self::read_task_counts(&entry.path()),
+                                updated_at: Self::content_modified_at(&entry.path()),
+                                modified_at: metadata.modified_at,
+                                summary,
                             });
                         }
-                        _ => {
+                        None => {
                             malformed_count += 1;
                             warn!("Skipping malformed spec directory: '{}'", spec_name);
                         }
@@ -261,7 +515,7 @@ impl FoundryBackend for FilesystemBackend {
         // Log summary of malformed specs if any were found
         if malformed_count > 0 {
             warn!(
-                "Skipped {} malformed spec directories in project '{}'",
+                "Skipped {} malformed spec directories or specs with unreadable metadata in project '{}'",
                 malformed_count, project_name
             );
         }
@@ -272,50 +526,44 @@ impl FoundryBackend for FilesystemBackend {
         Ok(specs)
     }
 
-    async fn load_spec(&self, project_name: &str, spec_name: &str) -> Result<Spec> {
+    async fn load_spec_files(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        files: &[SpecFileType],
+    ) -> Result<Spec> {
         // Validate spec name format first
         crate::core::foundry::Foundry::<Self>::validate_spec_name(spec_name)
             .with_context(|| format!("Invalid spec name: {}", spec_name))?;
 
-        let foundry_dir = filesystem::foundry_dir()?;
+        let foundry_dir = self.root()?;
         let spec_path = foundry_dir.join(project_name).join("specs").join(spec_name);
 
         if !spec_path.exists() {
-            return Err(anyhow::anyhow!(
+            return Err(ErrorCode::SpecNotFound.error(format!(
                 "Spec '{}' not found in project '{}'",
-                spec_name,
-                project_name
-            ));
+                spec_name, project_name
+            )));
         }
 
-        // Read spec files
-        let spec_content = filesystem::read_file(spec_path.join("spec.md"))?;
-        let notes = filesystem::read_file(spec_path.join("notes.md"))?;
-        let task_list = filesystem::read_file(spec_path.join("task-list.md"))?;
-
-        // Get creation time from spec name timestamp (more reliable than filesystem metadata)
-        let created_at = timestamp::parse_spec_timestamp(spec_name).map_or_else(
-            || {
-                // Fallback to filesystem metadata if timestamp parsing fails
-                fs::metadata(&spec_path)
-                    .and_then(|metadata| metadata.created())
-                    .map_err(anyhow::Error::from)
-                    .and_then(|time| {
-                        time.duration_since(std::time::UNIX_EPOCH)
-                            .map_err(anyhow::Error::from)
-                    })
-                    .map(|duration| {
-                        chrono::DateTime::from_timestamp(duration.as_secs() as i64, 0)
-                            .unwrap_or_else(chrono::Utc::now)
-                            .to_rfc3339()
-                    })
-                    .unwrap_or_else(|_| timestamp::iso_timestamp())
-            },
-            |timestamp_str| {
-                timestamp::spec_timestamp_to_iso(&timestamp_str)
-                    .unwrap_or_else(|_| timestamp::iso_timestamp())
-            },
-        );
+        // Read the requested spec files
+        let read = |file_type: SpecFileType| -> Result<String> {
+            if files.contains(&file_type) {
+                filesystem::read_file(spec_path.join(file_type.file_name()))
+            } else {
+                Ok(String::new())
+            }
+        };
+        let spec_content = read(SpecFileType::Spec)?;
+        let notes = read(SpecFileType::Notes)?;
+        let task_list = read(SpecFileType::TaskList)?;
+
+        let created_at = match spec_name::parse(spec_name) {
+            Some(parsed) => {
+                Self::spec_created_at(&spec_path, &parsed, &Self::read_spec_metadata(&spec_path)?)
+            }
+            None => timestamp::iso_timestamp(),
+        };
 
         let path_string = spec_path.to_string_lossy().to_string();
         Ok(Spec {
@@ -343,14 +591,13 @@ impl FoundryBackend for FilesystemBackend {
         // Validate spec exists
         crate::core::foundry::Foundry::<Self>::validate_spec_name(spec_name)?;
         if !self.spec_exists(project_name, spec_name).await? {
-            return Err(anyhow::anyhow!(
+            return Err(ErrorCode::SpecNotFound.error(format!(
                 "Spec '{}' not found in project '{}'",
-                spec_name,
-                project_name
-            ));
+                spec_name, project_name
+            )));
         }
 
-        let foundry_dir = filesystem::foundry_dir()?;
+        let foundry_dir = self.root()?;
         let spec_path = foundry_dir.join(project_name).join("specs").join(spec_name);
 
         let file_path = match file_type {
@@ -358,11 +605,29 @@ impl FoundryBackend for FilesystemBackend {
             SpecFileType::Notes => spec_path.join("notes.md"),
             SpecFileType::TaskList => spec_path.join("task-list.md"),
         };
+        let changed = filesystem::read_file(&file_path).ok().as_deref() != Some(new_content);
+        // Read the metadata before touching the file, so corrupt metadata fails
+        // the update instead of being rewritten from defaults
+        let mut metadata = Self::read_spec_metadata(&spec_path)
+            .with_context(|| format!("Failed to record the change to spec '{}'", spec_name))?;
 
-        filesystem::write_file_atomic(&file_path, new_content).with_context(|| {
+        filesystem::write_content_atomic(&file_path, new_content).with_context(|| {
             format!("Failed to update {:?} for spec '{}'", file_type, spec_name)
         })?;
 
+        // Rewriting identical content is not activity
+        if changed {
+            metadata.modified_at.insert(
+                file_type.file_name().to_string(),
+                timestamp::iso_timestamp(),
+            );
+            filesystem::write_file_atomic(
+                spec_path.join(SPEC_METADATA_FILE),
+                &serde_json::to_string_pretty(&metadata)?,
+            )
+            .with_context(|| format!("Failed to record the change to spec '{}'", spec_name))?;
+        }
+
         Ok(())
     }
 
@@ -372,11 +637,10 @@ impl FoundryBackend for FilesystemBackend {
         let spec_path = self.get_spec_path(project_name, spec_name)?;
 
         if !spec_path.exists() {
-            return Err(anyhow::anyhow!(
+            return Err(ErrorCode::SpecNotFound.error(format!(
                 "Spec '{}' not found in project '{}'",
-                spec_name,
-                project_name
-            ));
+                spec_name, project_name
+            )));
         }
 
         std::fs::remove_dir_all(&spec_path).with_context(|| {
@@ -389,6 +653,167 @@ impl FoundryBackend for FilesystemBackend {
         Ok(())
     }
 
+    async fn rename_spec(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        new_spec_name: &str,
+    ) -> Result<Spec> {
+        crate::core::foundry::Foundry::<Self>::validate_spec_name(spec_name)?;
+        crate::core::foundry::Foundry::<Self>::validate_spec_name(new_spec_name)?;
+
+        let spec_path = self.get_spec_path(project_name, spec_name)?;
+        let new_spec_path = self.get_spec_path(project_name, new_spec_name)?;
+        if !spec_path.exists() {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                spec_name, project_name
+            )));
+        }
+        if new_spec_path.exists() {
+            return Err(ErrorCode::AlreadyExists.error(format!(
+                "Spec '{}' already exists in project '{}'",
+                new_spec_name, project_name
+            )));
+        }
+
+        fs::rename(&spec_path, &new_spec_path).with_context(|| {
+            format!(
+                "Failed to rename spec '{}' to '{}' in project '{}'",
+                spec_name, new_spec_name, project_name
+            )
+        })?;
+        // Encrypted files are bound to their spec's path
+        encryption::reencrypt_moved(&spec_path, &new_spec_path)?;
+
+        self.load_spec(project_name, new_spec_name).await
+    }
+
+    async fn set_spec_status(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        status: SpecStatus,
+    ) -> Result<()> {
+        crate::core::foundry::Foundry::<Self>::validate_spec_name(spec_name)?;
+        let spec_path = self.get_spec_path(project_name, spec_name)?;
+        if !spec_path.exists() {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                spec_name, project_name
+            )));
+        }
+
+        let mut metadata = Self::read_spec_metadata(&spec_path)?;
+        metadata.status = status;
+        metadata.status_updated_at = Some(timestamp::iso_timestamp());
+        filesystem::write_file_atomic(
+            spec_path.join(SPEC_METADATA_FILE),
+            &serde_json::to_string_pretty(&metadata)?,
+        )
+        .with_context(|| format!("Failed to update status for spec '{}'", spec_name))
+    }
+
+    async fn set_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()> {
+        crate::core::foundry::Foundry::<Self>::validate_spec_name(spec_name)?;
+        let spec_path = self.get_spec_path(project_name, spec_name)?;
+        if !spec_path.exists() {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                spec_name, project_name
+            )));
+        }
+
+        let mut metadata = Self::read_spec_metadata(&spec_path)?;
+        metadata.depends_on = depends_on.to_vec();
+        filesystem::write_file_atomic(
+            spec_path.join(SPEC_METADATA_FILE),
+            &serde_json::to_string_pretty(&metadata)?,
+        )
+        .with_context(|| format!("Failed to update dependencies for spec '{}'", spec_name))
+    }
+
+    async fn set_spec_tags(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        tags: &[String],
+    ) -> Result<()> {
+        crate::core::foundry::Foundry::<Self>::validate_spec_name(spec_name)?;
+        let spec_path = self.get_spec_path(project_name, spec_name)?;
+        if !spec_path.exists() {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                spec_name, project_name
+            )));
+        }
+
+        let mut metadata = Self::read_spec_metadata(&spec_path)?;
+        metadata.tags = tags.to_vec();
+        filesystem::write_file_atomic(
+            spec_path.join(SPEC_METADATA_FILE),
+            &serde_json::to_string_pretty(&metadata)?,
+        )
+        .with_context(|| format!("Failed to update tags for spec '{}'", spec_name))
+    }
+
+    async fn set_spec_summary(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        summary: &SpecSummary,
+    ) -> Result<()> {
+        crate::core::foundry::Foundry::<Self>::validate_spec_name(spec_name)?;
+        let spec_path = self.get_spec_path(project_name, spec_name)?;
+        if !spec_path.exists() {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                spec_name, project_name
+            )));
+        }
+
+        let mut metadata = Self::read_spec_metadata(&spec_path)?;
+        filesystem::write_content_atomic(
+            spec_path.join(SPEC_SUMMARY_FILE),
+            &serde_json::to_string_pretty(summary)?,
+        )
+        .with_context(|| format!("Failed to update summary for spec '{}'", spec_name))?;
+        // Drop a summary earlier versions left in the plaintext metadata
+        if metadata.summary.take().is_some() {
+            filesystem::write_file_atomic(
+                spec_path.join(SPEC_METADATA_FILE),
+                &serde_json::to_string_pretty(&metadata)?,
+            )
+            .with_context(|| format!("Failed to update summary for spec '{}'", spec_name))?;
+        }
+        Ok(())
+    }
+
+    async fn lock_spec(&self, project_name: &str, spec_name: &str) -> Result<WriteLock> {
+        let path = lock::spec_lock_path(&self.root()?, project_name, spec_name)?;
+        lock::acquire(
+            &path,
+            &format!("Spec '{}' in project '{}'", spec_name, project_name),
+            lock::LOCK_TIMEOUT,
+        )
+        .await
+    }
+
+    async fn lock_project(&self, project_name: &str) -> Result<WriteLock> {
+        let path = lock::project_lock_path(&self.root()?, project_name)?;
+        lock::acquire(
+            &path,
+            &format!("Project '{}'", project_name),
+            lock::LOCK_TIMEOUT,
+        )
+        .await
+    }
+
     async fn get_latest_spec(&self, project_name: &str) -> Result<Option<SpecMetadata>> {
         let specs = self.list_specs(project_name).await?;
         Ok(specs.into_iter().next()) // Already sorted by creation time (newest first)
@@ -404,6 +829,33 @@ impl FoundryBackend for FilesystemBackend {
     }
 }
 
+/// Metadata JSON at `path`; defaults only when the file is missing
+///
+/// A file that cannot be read or parsed is an error, so read-modify-write
+/// callers never write defaults over metadata they failed to understand.
+fn read_metadata<T: Default + serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    if !path.exists() {
+        return Ok(T::default());
+    }
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read metadata {:?}", path))?;
+    serde_json::from_str(&content).map_err(|e| {
+        ErrorCode::ValidationFailed.error(format!(
+            "Metadata {:?} is not valid: {}. Run 'foundry doctor --fix' to set it aside",
+            path, e
+        ))
+    })
+}
+
+/// Whether `path` holds a project rather than nothing or only a `.foundry.toml`
+fn is_project_dir(path: &Path) -> bool {
+    fs::read_dir(path).is_ok_and(|mut entries| {
+        entries.any(|entry| {
+            entry.is_ok_and(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+        })
+    })
+}
+
 impl FilesystemBackend {
     /// Check if a spec exists
     pub async fn spec_exists(&self, project_name: &str, spec_name: &str) -> Result<bool> {
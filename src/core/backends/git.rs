@@ -0,0 +1,485 @@
+//! Git backend implementation
+//!
+//! Stores projects in a git working tree using the `~/.foundry` layout and
+//! commits every mutation, so teams get version history and can share specs
+//! through a remote. Reads and writes go through a `FilesystemBackend` rooted
+//! at the working tree; this backend adds the commits.
+//!
+//! Each commit has a one-line subject and trailers that tools can parse:
+//!
+//! ```text
+//! foundry: update spec my-app/20250101_120000_auth (notes.md)
+//!
+//! Foundry-Action: update_spec
+//! Foundry-Project: my-app
+//! Foundry-Spec: 20250101_120000_auth
+//! ```
+//!
+//! With a remote, the working tree is cloned (or fast-forwarded) when the
+//! backend is opened and each commit is pushed. A failed push leaves the
+//! commit in the local tree and is logged rather than failing the change,
+//! which is why a remote-backed store does not claim strong consistency.
+//!
+//! Staging and committing hold a lock file in `.git`, so concurrent writes to
+//! different specs (from this process or another) neither collide on git's
+//! index lock nor end up in each other's commits.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+
+use crate::core::backends::filesystem::{DECISIONS_FILE, FilesystemBackend};
+use crate::core::backends::{BackendCapabilities, FoundryBackend};
+use crate::core::lock::{self, WriteLock};
+use crate::types::errors::ErrorCode;
+use crate::types::{
+    project::{Project, ProjectConfig, ProjectFileType, ProjectMetadata},
+    spec::{Spec, SpecConfig, SpecFileType, SpecMetadata, SpecStatus, SpecSummary},
+};
+
+/// Committer identity used when the repository has none configured
+const DEFAULT_AUTHOR_NAME: &str = "Foundry";
+const DEFAULT_AUTHOR_EMAIL: &str = "foundry@localhost";
+
+/// Keeps Foundry's own dot-directories (logs, crash reports) out of the repository
+const GITIGNORE: &str = "/.*/\n";
+
+/// Lock file below `.git` held while a change is staged and committed
+const COMMIT_LOCK_FILE: &str = "foundry-commit.lock";
+
+/// Git backend implementation
+pub struct GitBackend {
+    files: FilesystemBackend,
+    repo_dir: PathBuf,
+    remote: Option<String>,
+}
+
+/// A mutation to record as a commit
+struct Change<'a> {
+    action: &'a str,
+    subject: String,
+    project_name: &'a str,
+    spec_name: Option<&'a str>,
+    /// Path to stage, relative to the working tree
+    path: PathBuf,
+}
+
+impl GitBackend {
+    /// Use the repository at `repo_dir`, creating and initializing it if needed
+    pub fn open(repo_dir: impl Into<PathBuf>) -> Result<Self> {
+        let repo_dir = repo_dir.into();
+        std::fs::create_dir_all(&repo_dir)
+            .with_context(|| format!("Failed to create git backend directory: {:?}", repo_dir))?;
+        if !repo_dir.join(".git").exists() {
+            git(&repo_dir, &["init", "--quiet"])?;
+        }
+        let backend = Self {
+            files: FilesystemBackend::with_root(&repo_dir),
+            repo_dir,
+            remote: None,
+        };
+        backend.ensure_gitignore()?;
+        Ok(backend)
+    }
+
+    /// Clone `remote` into `repo_dir`, or fast-forward an existing clone, and
+    /// push every commit back to it
+    pub fn clone_or_open(remote: &str, repo_dir: impl Into<PathBuf>) -> Result<Self> {
+        let repo_dir = repo_dir.into();
+        if repo_dir.join(".git").exists() {
+            // An empty remote has nothing to pull yet
+            if has_upstream(&repo_dir) {
+                git(&repo_dir, &["pull", "--ff-only", "--quiet"])
+                    .context("Failed to update the git backend from its remote")?;
+            }
+        } else {
+            if let Some(parent) = repo_dir.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+            }
+            let target = repo_dir.to_string_lossy().to_string();
+            // `--` keeps a remote starting with '-' from being read as an option
+            git(Path::new("."), &["clone", "--quiet", "--", remote, &target])
+                .with_context(|| format!("Failed to clone '{}'", remote))?;
+        }
+        let backend = Self {
+            files: FilesystemBackend::with_root(&repo_dir),
+            repo_dir,
+            remote: Some(remote.to_string()),
+        };
+        backend.ensure_gitignore()?;
+        Ok(backend)
+    }
+
+    /// The working tree holding the projects
+    pub fn repo_dir(&self) -> &Path {
+        &self.repo_dir
+    }
+
+    fn ensure_gitignore(&self) -> Result<()> {
+        let path = self.repo_dir.join(".gitignore");
+        if path.exists() {
+            return Ok(());
+        }
+        crate::core::filesystem::write_file_atomic(&path, GITIGNORE)?;
+        self.commit(&Change {
+            action: "init",
+            subject: "foundry: initialize repository".to_string(),
+            project_name: "",
+            spec_name: None,
+            path: PathBuf::from(".gitignore"),
+        })
+    }
+
+    /// Stage `change.path` and commit it, then push when there is a remote
+    fn commit(&self, change: &Change) -> Result<()> {
+        let _lock = lock::acquire_blocking(
+            &self.repo_dir.join(".git").join(COMMIT_LOCK_FILE),
+            "The git backend repository",
+            lock::LOCK_TIMEOUT,
+        )?;
+        let path = change.path.to_string_lossy().to_string();
+        git(&self.repo_dir, &["add", "--all", "--", &path])?;
+        if git(&self.repo_dir, &["diff", "--cached", "--quiet"]).is_ok() {
+            // Nothing changed, e.g. content rewritten with identical text
+            return Ok(());
+        }
+
+        let mut message = format!("{}\n\nFoundry-Action: {}\n", change.subject, change.action);
+        if !change.project_name.is_empty() {
+            message.push_str(&format!("Foundry-Project: {}\n", change.project_name));
+        }
+        if let Some(spec_name) = change.spec_name {
+            message.push_str(&format!("Foundry-Spec: {}\n", spec_name));
+        }
+
+        let mut args = Vec::new();
+        if git(&self.repo_dir, &["config", "user.email"]).is_err() {
+            args.extend([
+                "-c".to_string(),
+                format!("user.name={}", DEFAULT_AUTHOR_NAME),
+                "-c".to_string(),
+                format!("user.email={}", DEFAULT_AUTHOR_EMAIL),
+            ]);
+        }
+        args.extend(["commit", "--quiet", "--no-verify", "-m"].map(String::from));
+        args.push(message);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        git(&self.repo_dir, &args)
+            .with_context(|| format!("Failed to commit '{}'", change.subject))?;
+
+        if self.remote.is_some()
+            && let Err(e) = git(&self.repo_dir, &["push", "--quiet", "origin", "HEAD"])
+        {
+            tracing::warn!(
+                "Committed '{}' locally but could not push: {:#}",
+                change.subject,
+                e
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Run git in `dir`, returning stdout or an error carrying stderr
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| {
+            ErrorCode::BackendUnavailable.error(format!(
+                "Failed to run git ({}); is it installed and on PATH?",
+                e
+            ))
+        })?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn has_upstream(dir: &Path) -> bool {
+    git(dir, &["rev-parse", "--abbrev-ref", "@{upstream}"]).is_ok()
+}
+
+#[async_trait]
+impl FoundryBackend for GitBackend {
+    async fn create_project(&self, config: ProjectConfig) -> Result<Project> {
+        let name = config.name.clone();
+        let project = self.files.create_project(config).await?;
+        self.commit(&Change {
+            action: "create_project",
+            subject: format!("foundry: create project {}", name),
+            project_name: &name,
+            spec_name: None,
+            path: PathBuf::from(&name),
+        })?;
+        Ok(project)
+    }
+
+    async fn project_exists(&self, name: &str) -> Result<bool> {
+        self.files.project_exists(name).await
+    }
+
+    async fn list_projects(&self) -> Result<Vec<ProjectMetadata>> {
+        self.files.list_projects().await
+    }
+
+    async fn load_project(&self, name: &str) -> Result<Project> {
+        self.files.load_project(name).await
+    }
+
+    async fn update_decisions(&self, project_name: &str, content: &str) -> Result<()> {
+        self.files.update_decisions(project_name, content).await?;
+        self.commit(&Change {
+            action: "update_decisions",
+            subject: format!("foundry: update decision log {}", project_name),
+            project_name,
+            spec_name: None,
+            path: Path::new(project_name).join(DECISIONS_FILE),
+        })
+    }
+
+    async fn update_project_content(
+        &self,
+        project_name: &str,
+        file_type: ProjectFileType,
+        content: &str,
+    ) -> Result<()> {
+        self.files
+            .update_project_content(project_name, file_type, content)
+            .await?;
+        self.commit(&Change {
+            action: "update_project",
+            subject: format!(
+                "foundry: update project {} ({})",
+                project_name,
+                file_type.file_name()
+            ),
+            project_name,
+            spec_name: None,
+            path: Path::new(project_name).join(file_type.file_name()),
+        })
+    }
+
+    async fn delete_project(&self, name: &str) -> Result<()> {
+        self.files.delete_project(name).await?;
+        self.commit(&Change {
+            action: "delete_project",
+            subject: format!("foundry: delete project {}", name),
+            project_name: name,
+            spec_name: None,
+            path: PathBuf::from(name),
+        })
+    }
+
+    async fn set_project_tags(&self, project_name: &str, tags: &[String]) -> Result<()> {
+        self.files.set_project_tags(project_name, tags).await?;
+        self.commit(&Change {
+            action: "set_project_tags",
+            subject: format!("foundry: set project tags {}", project_name),
+            project_name,
+            spec_name: None,
+            path: PathBuf::from(project_name),
+        })
+    }
+
+    async fn create_spec(&self, config: SpecConfig) -> Result<Spec> {
+        let project_name = config.project_name.clone();
+        let spec = self.files.create_spec(config).await?;
+        self.commit(&Change {
+            action: "create_spec",
+            subject: format!("foundry: create spec {}/{}", project_name, spec.name),
+            project_name: &project_name,
+            spec_name: Some(&spec.name),
+            path: Path::new(&project_name).join("specs").join(&spec.name),
+        })?;
+        Ok(spec)
+    }
+
+    async fn list_specs(&self, project_name: &str) -> Result<Vec<SpecMetadata>> {
+        self.files.list_specs(project_name).await
+    }
+
+    async fn load_spec_files(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        files: &[SpecFileType],
+    ) -> Result<Spec> {
+        self.files
+            .load_spec_files(project_name, spec_name, files)
+            .await
+    }
+
+    async fn update_spec_content(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        file_type: SpecFileType,
+        content: &str,
+    ) -> Result<()> {
+        let file_name = file_type.file_name();
+        self.files
+            .update_spec_content(project_name, spec_name, file_type, content)
+            .await?;
+        self.commit(&Change {
+            action: "update_spec",
+            subject: format!(
+                "foundry: update spec {}/{} ({})",
+                project_name, spec_name, file_name
+            ),
+            project_name,
+            spec_name: Some(spec_name),
+            // The directory, so the change time recorded in its metadata is committed too
+            path: Path::new(project_name).join("specs").join(spec_name),
+        })
+    }
+
+    async fn delete_spec(&self, project_name: &str, spec_name: &str) -> Result<()> {
+        self.files.delete_spec(project_name, spec_name).await?;
+        self.commit(&Change {
+            action: "delete_spec",
+            subject: format!("foundry: delete spec {}/{}", project_name, spec_name),
+            project_name,
+            spec_name: Some(spec_name),
+            path: Path::new(project_name).join("specs").join(spec_name),
+        })
+    }
+
+    async fn rename_spec(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        new_spec_name: &str,
+    ) -> Result<Spec> {
+        let spec = self
+            .files
+            .rename_spec(project_name, spec_name, new_spec_name)
+            .await?;
+        // Staging the specs directory records the move as a rename
+        self.commit(&Change {
+            action: "rename_spec",
+            subject: format!(
+                "foundry: rename spec {}/{} to {}",
+                project_name, spec_name, new_spec_name
+            ),
+            project_name,
+            spec_name: Some(new_spec_name),
+            path: Path::new(project_name).join("specs"),
+        })?;
+        Ok(spec)
+    }
+
+    async fn set_spec_status(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        status: SpecStatus,
+    ) -> Result<()> {
+        self.files
+            .set_spec_status(project_name, spec_name, status)
+            .await?;
+        self.commit(&Change {
+            action: "set_spec_status",
+            subject: format!(
+                "foundry: set spec status {}/{} ({})",
+                project_name, spec_name, status
+            ),
+            project_name,
+            spec_name: Some(spec_name),
+            path: Path::new(project_name).join("specs").join(spec_name),
+        })
+    }
+
+    async fn set_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()> {
+        self.files
+            .set_spec_dependencies(project_name, spec_name, depends_on)
+            .await?;
+        self.commit(&Change {
+            action: "set_spec_dependencies",
+            subject: format!(
+                "foundry: set spec dependencies {}/{}",
+                project_name, spec_name
+            ),
+            project_name,
+            spec_name: Some(spec_name),
+            path: Path::new(project_name).join("specs").join(spec_name),
+        })
+    }
+
+    async fn set_spec_tags(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        tags: &[String],
+    ) -> Result<()> {
+        self.files
+            .set_spec_tags(project_name, spec_name, tags)
+            .await?;
+        self.commit(&Change {
+            action: "set_spec_tags",
+            subject: format!("foundry: set spec tags {}/{}", project_name, spec_name),
+            project_name,
+            spec_name: Some(spec_name),
+            path: Path::new(project_name).join("specs").join(spec_name),
+        })
+    }
+
+    async fn set_spec_summary(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        summary: &SpecSummary,
+    ) -> Result<()> {
+        self.files
+            .set_spec_summary(project_name, spec_name, summary)
+            .await?;
+        self.commit(&Change {
+            action: "set_spec_summary",
+            subject: format!("foundry: set spec summary {}/{}", project_name, spec_name),
+            project_name,
+            spec_name: Some(spec_name),
+            path: Path::new(project_name).join("specs").join(spec_name),
+        })
+    }
+
+    async fn lock_spec(&self, project_name: &str, spec_name: &str) -> Result<WriteLock> {
+        self.files.lock_spec(project_name, spec_name).await
+    }
+
+    async fn lock_project(&self, project_name: &str) -> Result<WriteLock> {
+        self.files.lock_project(project_name).await
+    }
+
+    async fn get_latest_spec(&self, project_name: &str) -> Result<Option<SpecMetadata>> {
+        self.files.get_latest_spec(project_name).await
+    }
+
+    async fn count_specs(&self, project_name: &str) -> Result<usize> {
+        self.files.count_specs(project_name).await
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            supports_documents: true,
+            supports_subtasks: true,
+            url_deeplinks: false,
+            atomic_replace: true,
+            // Pushes can fail or race other clones; only a local repository is authoritative
+            strong_consistency: self.remote.is_none(),
+        }
+    }
+}
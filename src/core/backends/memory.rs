@@ -3,16 +3,20 @@
 //! This backend provides a lightweight, fast implementation of FoundryBackend
 //! that stores all data in memory for contract testing and development.
 
-use anyhow::{Result, anyhow};
-use chrono::{Datelike, Timelike, Utc};
+use anyhow::Result;
+use chrono::Utc;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::core::backends::{BackendCapabilities, FoundryBackend, ResourceLocator};
+use crate::core::lock::WriteLock;
+use crate::core::spec_name::{self, SpecNameStrategy};
+use crate::core::tasks;
+use crate::types::errors::ErrorCode;
 use crate::types::{
-    project::{Project, ProjectConfig, ProjectMetadata},
-    spec::{Spec, SpecConfig, SpecFileType, SpecMetadata},
+    project::{Project, ProjectConfig, ProjectFileType, ProjectMetadata},
+    spec::{Spec, SpecConfig, SpecFileType, SpecMetadata, SpecStatus, SpecSummary},
 };
 
 /// In-memory storage for projects and specs
@@ -20,12 +24,19 @@ use crate::types::{
 struct MemoryStore {
     projects: HashMap<String, Project>,
     specs: HashMap<String, HashMap<String, Spec>>, // project_name -> spec_name -> spec
+    statuses: HashMap<(String, String), SpecStatus>, // (project_name, spec_name) -> status
+    dependencies: HashMap<(String, String), Vec<String>>, // (project_name, spec_name) -> depends_on
+    spec_tags: HashMap<(String, String), Vec<String>>, // (project_name, spec_name) -> tags
+    spec_summaries: HashMap<(String, String), SpecSummary>, // (project_name, spec_name) -> summary
+    spec_updated_at: HashMap<(String, String), String>, // (project_name, spec_name) -> last content change
+    project_tags: HashMap<String, Vec<String>>,
 }
 
 /// In-memory backend implementation for testing
 #[derive(Debug, Clone)]
 pub struct InMemoryBackend {
     store: Arc<RwLock<MemoryStore>>,
+    capabilities: BackendCapabilities,
 }
 
 impl InMemoryBackend {
@@ -33,6 +44,22 @@ impl InMemoryBackend {
     pub fn new() -> Self {
         Self {
             store: Arc::new(RwLock::new(MemoryStore::default())),
+            capabilities: BackendCapabilities {
+                supports_documents: true,
+                supports_subtasks: true,
+                url_deeplinks: false,
+                atomic_replace: true,
+                strong_consistency: true,
+            },
+        }
+    }
+
+    /// An empty backend reporting `capabilities`, for exercising how the
+    /// façade degrades on backends that lack some of them
+    pub fn with_capabilities(capabilities: BackendCapabilities) -> Self {
+        Self {
+            capabilities,
+            ..Self::new()
         }
     }
 
@@ -41,6 +68,12 @@ impl InMemoryBackend {
         let mut store = self.store.write().await;
         store.projects.clear();
         store.specs.clear();
+        store.statuses.clear();
+        store.dependencies.clear();
+        store.spec_tags.clear();
+        store.spec_summaries.clear();
+        store.spec_updated_at.clear();
+        store.project_tags.clear();
     }
 
     /// Get project count (useful for testing)
@@ -74,7 +107,9 @@ impl FoundryBackend for InMemoryBackend {
 
         // Check if project already exists
         if store.projects.contains_key(&config.name) {
-            return Err(anyhow!("Project '{}' already exists", config.name));
+            return Err(
+                ErrorCode::AlreadyExists.error(format!("Project '{}' already exists", config.name))
+            );
         }
 
         let created_at = Utc::now().to_rfc3339();
@@ -90,6 +125,7 @@ impl FoundryBackend for InMemoryBackend {
             vision: Some(config.vision),
             tech_stack: Some(config.tech_stack),
             summary: Some(config.summary),
+            decisions: None,
         };
 
         store.projects.insert(config.name.clone(), project.clone());
@@ -117,6 +153,11 @@ impl FoundryBackend for InMemoryBackend {
                     .get(&project.name)
                     .map(|specs| specs.len())
                     .unwrap_or(0),
+                tags: store
+                    .project_tags
+                    .get(&project.name)
+                    .cloned()
+                    .unwrap_or_default(),
             })
             .collect();
 
@@ -127,11 +168,69 @@ impl FoundryBackend for InMemoryBackend {
 
     async fn load_project(&self, name: &str) -> Result<Project> {
         let store = self.store.read().await;
+        store.projects.get(name).cloned().ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", name))
+        })
+    }
+
+    async fn update_decisions(&self, project_name: &str, content: &str) -> Result<()> {
+        let mut store = self.store.write().await;
+        let project = store.projects.get_mut(project_name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+        })?;
+        project.decisions = Some(content.to_string());
+        Ok(())
+    }
+
+    async fn update_project_content(
+        &self,
+        project_name: &str,
+        file_type: ProjectFileType,
+        content: &str,
+    ) -> Result<()> {
+        let mut store = self.store.write().await;
+        let project = store.projects.get_mut(project_name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+        })?;
+        let document = match file_type {
+            ProjectFileType::Vision => &mut project.vision,
+            ProjectFileType::TechStack => &mut project.tech_stack,
+            ProjectFileType::Summary => &mut project.summary,
+        };
+        *document = Some(content.to_string());
+        Ok(())
+    }
+
+    async fn delete_project(&self, name: &str) -> Result<()> {
+        let mut store = self.store.write().await;
+        store.projects.remove(name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", name))
+        })?;
+        store.specs.remove(name);
+        store.statuses.retain(|(project, _), _| project != name);
+        store.dependencies.retain(|(project, _), _| project != name);
+        store.spec_tags.retain(|(project, _), _| project != name);
         store
-            .projects
-            .get(name)
-            .cloned()
-            .ok_or_else(|| anyhow!("Project '{}' not found", name))
+            .spec_summaries
+            .retain(|(project, _), _| project != name);
+        store
+            .spec_updated_at
+            .retain(|(project, _), _| project != name);
+        store.project_tags.remove(name);
+        Ok(())
+    }
+
+    async fn set_project_tags(&self, project_name: &str, tags: &[String]) -> Result<()> {
+        let mut store = self.store.write().await;
+        if !store.projects.contains_key(project_name) {
+            return Err(
+                ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+            );
+        }
+        store
+            .project_tags
+            .insert(project_name.to_string(), tags.to_vec());
+        Ok(())
     }
 
     // Spec operations
@@ -140,21 +239,20 @@ impl FoundryBackend for InMemoryBackend {
 
         // Check if project exists
         if !store.projects.contains_key(&config.project_name) {
-            return Err(anyhow!("Project '{}' not found", config.project_name));
+            return Err(ErrorCode::ProjectNotFound
+                .error(format!("Project '{}' not found", config.project_name)));
         }
 
         // Generate spec name
         let now = Utc::now();
-        let spec_name = format!(
-            "{:04}{:02}{:02}_{:02}{:02}{:02}_{}",
-            now.year(),
-            now.month(),
-            now.day(),
-            now.hour(),
-            now.minute(),
-            now.second(),
-            config.feature_name
-        );
+        let existing: Vec<String> = store.specs[&config.project_name].keys().cloned().collect();
+        let spec_name = spec_name::generate(
+            SpecNameStrategy::Timestamp,
+            None,
+            &config.feature_name,
+            &existing,
+            now,
+        )?;
 
         let created_at = now.to_rfc3339();
         let spec = Spec {
@@ -190,21 +288,51 @@ impl FoundryBackend for InMemoryBackend {
 
         // Check if project exists
         if !store.projects.contains_key(project_name) {
-            return Err(anyhow!("Project '{}' not found", project_name));
+            return Err(
+                ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+            );
         }
 
         let specs = store.specs.get(project_name).unwrap();
+        let statuses = &store.statuses;
+        let dependencies = &store.dependencies;
+        let spec_tags = &store.spec_tags;
+        let spec_summaries = &store.spec_summaries;
+        let spec_updated_at = &store.spec_updated_at;
         let mut spec_list: Vec<SpecMetadata> = specs
             .values()
             .map(|spec| {
-                // Extract feature name from spec name (format: YYYYMMDD_HHMMSS_feature_name)
-                let feature_name = spec.name.split('_').skip(2).collect::<Vec<_>>().join("_");
+                let feature_name = spec_name::parse(&spec.name)
+                    .map_or_else(|| spec.name.clone(), |parsed| parsed.feature_name);
 
                 SpecMetadata {
                     name: spec.name.clone(),
                     created_at: spec.created_at.clone(),
                     feature_name,
                     project_name: spec.project_name.clone(),
+                    status: statuses
+                        .get(&(spec.project_name.clone(), spec.name.clone()))
+                        .copied()
+                        .unwrap_or_default(),
+                    depends_on: dependencies
+                        .get(&(spec.project_name.clone(), spec.name.clone()))
+                        .cloned()
+                        .unwrap_or_default(),
+                    tags: spec_tags
+                        .get(&(spec.project_name.clone(), spec.name.clone()))
+                        .cloned()
+                        .unwrap_or_default(),
+                    task_counts: tasks::count_tasks(&spec.content.tasks),
+                    updated_at: Some(
+                        spec_updated_at
+                            .get(&(spec.project_name.clone(), spec.name.clone()))
+                            .unwrap_or(&spec.created_at)
+                            .clone(),
+                    ),
+                    modified_at: Default::default(),
+                    summary: spec_summaries
+                        .get(&(spec.project_name.clone(), spec.name.clone()))
+                        .cloned(),
                 }
             })
             .collect();
@@ -214,21 +342,26 @@ impl FoundryBackend for InMemoryBackend {
         Ok(spec_list)
     }
 
-    async fn load_spec(&self, project_name: &str, spec_name: &str) -> Result<Spec> {
+    async fn load_spec_files(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        files: &[SpecFileType],
+    ) -> Result<Spec> {
         let store = self.store.read().await;
 
-        let specs = store
-            .specs
-            .get(project_name)
-            .ok_or_else(|| anyhow!("Project '{}' not found", project_name))?;
+        let specs = store.specs.get(project_name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+        })?;
 
-        specs.get(spec_name).cloned().ok_or_else(|| {
-            anyhow!(
+        let mut spec = specs.get(spec_name).cloned().ok_or_else(|| {
+            ErrorCode::SpecNotFound.error(format!(
                 "Spec '{}' not found in project '{}'",
-                spec_name,
-                project_name
-            )
-        })
+                spec_name, project_name
+            ))
+        })?;
+        spec.content.retain(files);
+        Ok(spec)
     }
 
     async fn update_spec_content(
@@ -240,17 +373,15 @@ impl FoundryBackend for InMemoryBackend {
     ) -> Result<()> {
         let mut store = self.store.write().await;
 
-        let specs = store
-            .specs
-            .get_mut(project_name)
-            .ok_or_else(|| anyhow!("Project '{}' not found", project_name))?;
+        let specs = store.specs.get_mut(project_name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+        })?;
 
         let spec = specs.get_mut(spec_name).ok_or_else(|| {
-            anyhow!(
+            ErrorCode::SpecNotFound.error(format!(
                 "Spec '{}' not found in project '{}'",
-                spec_name,
-                project_name
-            )
+                spec_name, project_name
+            ))
         })?;
 
         match file_type {
@@ -258,6 +389,10 @@ impl FoundryBackend for InMemoryBackend {
             SpecFileType::Notes => spec.content.notes = content.to_string(),
             SpecFileType::TaskList => spec.content.tasks = content.to_string(),
         }
+        store.spec_updated_at.insert(
+            (project_name.to_string(), spec_name.to_string()),
+            Utc::now().to_rfc3339(),
+        );
 
         Ok(())
     }
@@ -265,22 +400,196 @@ impl FoundryBackend for InMemoryBackend {
     async fn delete_spec(&self, project_name: &str, spec_name: &str) -> Result<()> {
         let mut store = self.store.write().await;
 
-        let specs = store
-            .specs
-            .get_mut(project_name)
-            .ok_or_else(|| anyhow!("Project '{}' not found", project_name))?;
+        let specs = store.specs.get_mut(project_name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+        })?;
 
         specs.remove(spec_name).ok_or_else(|| {
-            anyhow!(
+            ErrorCode::SpecNotFound.error(format!(
                 "Spec '{}' not found in project '{}'",
-                spec_name,
-                project_name
-            )
+                spec_name, project_name
+            ))
         })?;
+        store
+            .statuses
+            .remove(&(project_name.to_string(), spec_name.to_string()));
+        store
+            .dependencies
+            .remove(&(project_name.to_string(), spec_name.to_string()));
+        store
+            .spec_tags
+            .remove(&(project_name.to_string(), spec_name.to_string()));
+        store
+            .spec_summaries
+            .remove(&(project_name.to_string(), spec_name.to_string()));
+        store
+            .spec_updated_at
+            .remove(&(project_name.to_string(), spec_name.to_string()));
 
         Ok(())
     }
 
+    async fn rename_spec(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        new_spec_name: &str,
+    ) -> Result<Spec> {
+        let mut store = self.store.write().await;
+
+        let specs = store.specs.get_mut(project_name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+        })?;
+        if specs.contains_key(new_spec_name) {
+            return Err(ErrorCode::AlreadyExists.error(format!(
+                "Spec '{}' already exists in project '{}'",
+                new_spec_name, project_name
+            )));
+        }
+        let mut spec = specs.remove(spec_name).ok_or_else(|| {
+            ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                spec_name, project_name
+            ))
+        })?;
+
+        let location = format!("memory://{}/specs/{}", project_name, new_spec_name);
+        spec.name = new_spec_name.to_string();
+        spec.path =
+            std::path::PathBuf::from(format!("/memory/{}/specs/{}", project_name, new_spec_name));
+        spec.location_hint = Some(location.clone());
+        spec.locator = Some(ResourceLocator::FilesystemPath(location));
+        specs.insert(new_spec_name.to_string(), spec.clone());
+
+        let old_key = (project_name.to_string(), spec_name.to_string());
+        let new_key = (project_name.to_string(), new_spec_name.to_string());
+        if let Some(status) = store.statuses.remove(&old_key) {
+            store.statuses.insert(new_key.clone(), status);
+        }
+        if let Some(depends_on) = store.dependencies.remove(&old_key) {
+            store.dependencies.insert(new_key.clone(), depends_on);
+        }
+        if let Some(tags) = store.spec_tags.remove(&old_key) {
+            store.spec_tags.insert(new_key.clone(), tags);
+        }
+        if let Some(summary) = store.spec_summaries.remove(&old_key) {
+            store.spec_summaries.insert(new_key.clone(), summary);
+        }
+        if let Some(updated_at) = store.spec_updated_at.remove(&old_key) {
+            store.spec_updated_at.insert(new_key, updated_at);
+        }
+
+        Ok(spec)
+    }
+
+    async fn set_spec_status(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        status: SpecStatus,
+    ) -> Result<()> {
+        let mut store = self.store.write().await;
+
+        let specs = store.specs.get(project_name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+        })?;
+        if !specs.contains_key(spec_name) {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                spec_name, project_name
+            )));
+        }
+
+        store
+            .statuses
+            .insert((project_name.to_string(), spec_name.to_string()), status);
+        Ok(())
+    }
+
+    async fn set_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()> {
+        let mut store = self.store.write().await;
+
+        let specs = store.specs.get(project_name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+        })?;
+        if !specs.contains_key(spec_name) {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                spec_name, project_name
+            )));
+        }
+
+        store.dependencies.insert(
+            (project_name.to_string(), spec_name.to_string()),
+            depends_on.to_vec(),
+        );
+        Ok(())
+    }
+
+    async fn set_spec_tags(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        tags: &[String],
+    ) -> Result<()> {
+        let mut store = self.store.write().await;
+
+        let specs = store.specs.get(project_name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+        })?;
+        if !specs.contains_key(spec_name) {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                spec_name, project_name
+            )));
+        }
+
+        store.spec_tags.insert(
+            (project_name.to_string(), spec_name.to_string()),
+            tags.to_vec(),
+        );
+        Ok(())
+    }
+
+    async fn set_spec_summary(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        summary: &SpecSummary,
+    ) -> Result<()> {
+        let mut store = self.store.write().await;
+
+        let specs = store.specs.get(project_name).ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+        })?;
+        if !specs.contains_key(spec_name) {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                spec_name, project_name
+            )));
+        }
+
+        store.spec_summaries.insert(
+            (project_name.to_string(), spec_name.to_string()),
+            summary.clone(),
+        );
+        Ok(())
+    }
+
+    async fn lock_spec(&self, _project_name: &str, _spec_name: &str) -> Result<WriteLock> {
+        // The store lives in this process, so no other process can write to it
+        Ok(WriteLock::unguarded())
+    }
+
+    async fn lock_project(&self, _project_name: &str) -> Result<WriteLock> {
+        Ok(WriteLock::unguarded())
+    }
+
     // Helper operations
     async fn get_latest_spec(&self, project_name: &str) -> Result<Option<SpecMetadata>> {
         let specs = self.list_specs(project_name).await?;
@@ -292,7 +601,9 @@ impl FoundryBackend for InMemoryBackend {
 
         // Check if project exists
         if !store.projects.contains_key(project_name) {
-            return Err(anyhow!("Project '{}' not found", project_name));
+            return Err(
+                ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", project_name))
+            );
         }
 
         let count = store
@@ -306,13 +617,7 @@ impl FoundryBackend for InMemoryBackend {
 
     // Capabilities introspection
     fn capabilities(&self) -> BackendCapabilities {
-        BackendCapabilities {
-            supports_documents: true,
-            supports_subtasks: true,
-            url_deeplinks: false,
-            atomic_replace: true,
-            strong_consistency: true,
-        }
+        self.capabilities.clone()
     }
 }
 
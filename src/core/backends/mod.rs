@@ -1,11 +1,18 @@
 //! Backend abstraction for pluggable storage systems
 
-use anyhow::Result;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::core::config::{self, BackendConfig};
+use crate::core::lock::WriteLock;
+use crate::types::errors::ErrorCode;
 use crate::types::{
-    project::{Project, ProjectConfig, ProjectMetadata},
-    spec::{Spec, SpecConfig, SpecFileType, SpecMetadata},
+    project::{Project, ProjectConfig, ProjectFileType, ProjectMetadata},
+    spec::{Spec, SpecConfig, SpecFileType, SpecMetadata, SpecStatus, SpecSummary},
 };
 
 /// Core backend trait defining storage contracts
@@ -16,11 +23,34 @@ pub trait FoundryBackend: Send + Sync {
     async fn project_exists(&self, name: &str) -> Result<bool>;
     async fn list_projects(&self) -> Result<Vec<ProjectMetadata>>;
     async fn load_project(&self, name: &str) -> Result<Project>;
+    /// Replace the project's decision log, returned by `load_project` as `decisions`
+    async fn update_decisions(&self, project_name: &str, content: &str) -> Result<()>;
+    /// Replace the project's vision, tech stack or summary
+    async fn update_project_content(
+        &self,
+        project_name: &str,
+        file_type: ProjectFileType,
+        content: &str,
+    ) -> Result<()>;
+    /// Remove the project with all of its documents and specs
+    async fn delete_project(&self, name: &str) -> Result<()>;
+    /// Store the project's tags, reported by `list_projects`
+    async fn set_project_tags(&self, project_name: &str, tags: &[String]) -> Result<()>;
 
     // Spec operations
     async fn create_spec(&self, config: SpecConfig) -> Result<Spec>;
     async fn list_specs(&self, project_name: &str) -> Result<Vec<SpecMetadata>>;
-    async fn load_spec(&self, project_name: &str, spec_name: &str) -> Result<Spec>;
+    async fn load_spec(&self, project_name: &str, spec_name: &str) -> Result<Spec> {
+        self.load_spec_files(project_name, spec_name, SpecFileType::ALL)
+            .await
+    }
+    /// Load the spec reading only `files`; the content of the others is empty
+    async fn load_spec_files(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        files: &[SpecFileType],
+    ) -> Result<Spec>;
     async fn update_spec_content(
         &self,
         project_name: &str,
@@ -29,6 +59,48 @@ pub trait FoundryBackend: Send + Sync {
         content: &str,
     ) -> Result<()>;
     async fn delete_spec(&self, project_name: &str, spec_name: &str) -> Result<()>;
+    /// Move a spec to `new_spec_name`, keeping its files, status and dependencies
+    async fn rename_spec(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        new_spec_name: &str,
+    ) -> Result<Spec>;
+    /// Store the spec's lifecycle status, reported by `list_specs`
+    async fn set_spec_status(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        status: SpecStatus,
+    ) -> Result<()>;
+    /// Store the specs this spec depends on, reported by `list_specs`
+    async fn set_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()>;
+    /// Store the spec's tags, reported by `list_specs`
+    async fn set_spec_tags(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        tags: &[String],
+    ) -> Result<()>;
+    /// Store the spec's drafted summary, reported by `list_specs`
+    async fn set_spec_summary(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        summary: &SpecSummary,
+    ) -> Result<()>;
+
+    /// Lock the spec against writes from other foundry processes until the
+    /// returned guard is dropped; fails with `RESOURCE_BUSY` if it stays held
+    async fn lock_spec(&self, project_name: &str, spec_name: &str) -> Result<WriteLock>;
+
+    /// Lock the project's own files (tags, decision log) like [`Self::lock_spec`]
+    async fn lock_project(&self, project_name: &str) -> Result<WriteLock>;
 
     // Helper operations
     async fn get_latest_spec(&self, project_name: &str) -> Result<Option<SpecMetadata>>;
@@ -36,6 +108,153 @@ pub trait FoundryBackend: Send + Sync {
 
     // Capabilities introspection
     fn capabilities(&self) -> BackendCapabilities;
+
+    /// Capabilities of the backend storing `project_name`; the same as
+    /// [`Self::capabilities`] unless projects are routed to different backends
+    fn project_capabilities(&self, project_name: &str) -> BackendCapabilities {
+        let _ = project_name;
+        self.capabilities()
+    }
+}
+
+/// Backend chosen at runtime, as returned by `get_default_foundry`
+pub type SharedBackend = Arc<dyn FoundryBackend>;
+
+#[async_trait::async_trait]
+impl<T: FoundryBackend + ?Sized> FoundryBackend for Arc<T> {
+    async fn create_project(&self, config: ProjectConfig) -> Result<Project> {
+        (**self).create_project(config).await
+    }
+    async fn project_exists(&self, name: &str) -> Result<bool> {
+        (**self).project_exists(name).await
+    }
+    async fn list_projects(&self) -> Result<Vec<ProjectMetadata>> {
+        (**self).list_projects().await
+    }
+    async fn load_project(&self, name: &str) -> Result<Project> {
+        (**self).load_project(name).await
+    }
+    async fn update_decisions(&self, project_name: &str, content: &str) -> Result<()> {
+        (**self).update_decisions(project_name, content).await
+    }
+    async fn update_project_content(
+        &self,
+        project_name: &str,
+        file_type: ProjectFileType,
+        content: &str,
+    ) -> Result<()> {
+        (**self)
+            .update_project_content(project_name, file_type, content)
+            .await
+    }
+    async fn delete_project(&self, name: &str) -> Result<()> {
+        (**self).delete_project(name).await
+    }
+    async fn set_project_tags(&self, project_name: &str, tags: &[String]) -> Result<()> {
+        (**self).set_project_tags(project_name, tags).await
+    }
+
+    async fn create_spec(&self, config: SpecConfig) -> Result<Spec> {
+        (**self).create_spec(config).await
+    }
+    async fn list_specs(&self, project_name: &str) -> Result<Vec<SpecMetadata>> {
+        (**self).list_specs(project_name).await
+    }
+    async fn load_spec(&self, project_name: &str, spec_name: &str) -> Result<Spec> {
+        (**self).load_spec(project_name, spec_name).await
+    }
+    async fn load_spec_files(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        files: &[SpecFileType],
+    ) -> Result<Spec> {
+        (**self)
+            .load_spec_files(project_name, spec_name, files)
+            .await
+    }
+    async fn update_spec_content(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        file_type: SpecFileType,
+        content: &str,
+    ) -> Result<()> {
+        (**self)
+            .update_spec_content(project_name, spec_name, file_type, content)
+            .await
+    }
+    async fn delete_spec(&self, project_name: &str, spec_name: &str) -> Result<()> {
+        (**self).delete_spec(project_name, spec_name).await
+    }
+    async fn rename_spec(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        new_spec_name: &str,
+    ) -> Result<Spec> {
+        (**self)
+            .rename_spec(project_name, spec_name, new_spec_name)
+            .await
+    }
+    async fn set_spec_status(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        status: SpecStatus,
+    ) -> Result<()> {
+        (**self)
+            .set_spec_status(project_name, spec_name, status)
+            .await
+    }
+    async fn set_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()> {
+        (**self)
+            .set_spec_dependencies(project_name, spec_name, depends_on)
+            .await
+    }
+    async fn set_spec_tags(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        tags: &[String],
+    ) -> Result<()> {
+        (**self).set_spec_tags(project_name, spec_name, tags).await
+    }
+    async fn set_spec_summary(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        summary: &SpecSummary,
+    ) -> Result<()> {
+        (**self)
+            .set_spec_summary(project_name, spec_name, summary)
+            .await
+    }
+    async fn lock_spec(&self, project_name: &str, spec_name: &str) -> Result<WriteLock> {
+        (**self).lock_spec(project_name, spec_name).await
+    }
+    async fn lock_project(&self, project_name: &str) -> Result<WriteLock> {
+        (**self).lock_project(project_name).await
+    }
+
+    async fn get_latest_spec(&self, project_name: &str) -> Result<Option<SpecMetadata>> {
+        (**self).get_latest_spec(project_name).await
+    }
+    async fn count_specs(&self, project_name: &str) -> Result<usize> {
+        (**self).count_specs(project_name).await
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        (**self).capabilities()
+    }
+    fn project_capabilities(&self, project_name: &str) -> BackendCapabilities {
+        (**self).project_capabilities(project_name)
+    }
 }
 
 /// Backend capability flags
@@ -48,11 +267,53 @@ pub struct BackendCapabilities {
     pub strong_consistency: bool,
 }
 
+impl BackendCapabilities {
+    /// Whether the flag for `capability` is set
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::Documents => self.supports_documents,
+            Capability::Subtasks => self.supports_subtasks,
+            Capability::UrlDeeplinks => self.url_deeplinks,
+            Capability::AtomicReplace => self.atomic_replace,
+            Capability::StrongConsistency => self.strong_consistency,
+        }
+    }
+}
+
+/// One of the [`BackendCapabilities`] flags, for asking about it by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// Vision, tech stack and summary are stored as separate documents
+    Documents,
+    /// Task lists keep nested tasks
+    Subtasks,
+    /// Projects and specs have URLs that open them
+    UrlDeeplinks,
+    /// A document is replaced in one step, never left partly written
+    AtomicReplace,
+    /// A read always sees the latest write
+    StrongConsistency,
+}
+
+impl Capability {
+    /// The flag's field name in [`BackendCapabilities`], e.g. `atomic_replace`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Documents => "supports_documents",
+            Self::Subtasks => "supports_subtasks",
+            Self::UrlDeeplinks => "url_deeplinks",
+            Self::AtomicReplace => "atomic_replace",
+            Self::StrongConsistency => "strong_consistency",
+        }
+    }
+}
+
 /// Resource locator for different backend types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ResourceLocator {
     FilesystemPath(String),
+    Notion { page_id: String, url: String },
     // Future: Linear { project_id: String, issue_id: String, urls: Vec<String> },
 }
 
@@ -81,16 +342,130 @@ pub trait SpecContentStore: Send + Sync {
         file_type: SpecFileType,
         new_content: &str,
     ) -> Result<bool>;
+
+    async fn read_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+    ) -> Result<Vec<String>>;
+
+    async fn write_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()>;
+}
+
+/// Backends selectable with `--backend`
+pub const BACKEND_KINDS: &[&str] = &["filesystem", "git", "notion"];
+
+/// Working tree for the git backend when `--git-dir` is not given
+const DEFAULT_GIT_DIR_NAME: &str = ".git-backend";
+
+/// Open the backend described by `config`
+///
+/// `git_dir` and `git_remote` only apply to the git backend; its working tree
+/// defaults to `~/.foundry/.git-backend`. The notion backend needs
+/// `notion_page` and a token in `NOTION_TOKEN`, and does not contact Notion
+/// until first used.
+pub fn open_backend(config: &BackendConfig) -> Result<SharedBackend> {
+    match config.kind() {
+        "filesystem" => Ok(Arc::new(filesystem::FilesystemBackend::new())),
+        "git" => {
+            let repo_dir = storage_dir(config.kind(), config.git_dir.as_deref())?;
+            let backend = match config.git_remote.as_deref() {
+                Some(remote) => git::GitBackend::clone_or_open(remote, repo_dir)?,
+                None => git::GitBackend::open(repo_dir)?,
+            };
+            Ok(Arc::new(backend))
+        }
+        "notion" => {
+            let page = config.notion_page.as_deref().ok_or_else(|| {
+                ErrorCode::InvalidParams.error(
+                    "The notion backend needs a root page: pass --notion-page or set notion_page under [backend]",
+                )
+            })?;
+            Ok(Arc::new(notion::NotionBackend::open(page)?))
+        }
+        other => Err(ErrorCode::InvalidParams.error(format!(
+            "Unknown backend '{}'. Supported backends: {}",
+            other,
+            BACKEND_KINDS.join(", ")
+        ))),
+    }
+}
+
+/// Backend settings from the command line (`cli`) over `~/.foundry/config.toml`
+pub fn resolve_backend_config(cli: BackendConfig) -> Result<BackendConfig> {
+    Ok(config::global()?.backend.merged(cli).normalized())
+}
+
+/// Open the backend described by `default`, routing every project whose
+/// `.foundry.toml` picks a different backend to that one
+pub fn open_configured_backend(default: &BackendConfig) -> Result<SharedBackend> {
+    let default_backend = open_backend(default)?;
+
+    // Projects sharing a backend share one instance of it
+    let mut opened: HashMap<BackendConfig, SharedBackend> = HashMap::new();
+    let mut routes = HashMap::new();
+    for (project_name, overrides) in config::project_overrides()? {
+        let project_config = default.clone().merged(overrides.backend).normalized();
+        if &project_config == default {
+            continue;
+        }
+        let backend = match opened.get(&project_config) {
+            Some(backend) => Arc::clone(backend),
+            None => {
+                let backend = open_backend(&project_config).with_context(|| {
+                    format!("Failed to open the backend for project '{}'", project_name)
+                })?;
+                opened.insert(project_config, Arc::clone(&backend));
+                backend
+            }
+        };
+        routes.insert(project_name, backend);
+    }
+
+    if routes.is_empty() {
+        Ok(default_backend)
+    } else {
+        Ok(Arc::new(routing::RoutingBackend::new(
+            default_backend,
+            routes,
+        )))
+    }
+}
+
+/// Directory holding the project files of the backend named by `kind`
+///
+/// `~/.foundry` for the filesystem backend; the git working tree (`git_dir`,
+/// default `~/.foundry/.git-backend`) for the git backend.
+pub fn storage_dir(kind: &str, git_dir: Option<&Path>) -> Result<PathBuf> {
+    match (kind, git_dir) {
+        ("git", Some(dir)) => Ok(dir.to_path_buf()),
+        ("git", None) => Ok(crate::core::filesystem::foundry_dir()?.join(DEFAULT_GIT_DIR_NAME)),
+        _ => crate::core::filesystem::foundry_dir(),
+    }
 }
 
 // Re-export filesystem backend
 pub mod filesystem;
 
+// Git working tree backend, committing every change
+pub mod git;
+
+// Notion pages and databases, over the Notion API
+pub mod notion;
+
 // Re-export memory backend for testing
 pub mod memory;
 
+// Per-project backend selection from `.foundry.toml`
+pub mod routing;
+
 // Backend testing infrastructure
 mod tests;
 
 // Re-export factory functions
-pub use crate::core::foundry::get_default_foundry;
+pub use crate::core::foundry::{get_default_foundry, with_backend};
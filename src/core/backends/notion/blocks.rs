@@ -0,0 +1,398 @@
+//! Conversion between markdown documents and Notion blocks
+//!
+//! Headings, bulleted and numbered items, to-dos, quotes, code fences and
+//! dividers map to the matching block types; runs of other lines become one
+//! paragraph with the line breaks kept, so tables and inline markdown survive
+//! as plain text. Indented list items nest below the item before them.
+//! Headings deeper than `###` are stored as `###`, since Notion has three
+//! heading levels.
+
+use serde_json::{Value, json};
+
+/// Deepest list nesting Notion accepts when creating blocks
+const MAX_DEPTH: usize = 2;
+
+/// Longest text Notion accepts in one rich text object
+const MAX_TEXT_LENGTH: usize = 2000;
+
+/// Code languages Notion knows; other fences are stored as "plain text"
+const LANGUAGES: &[&str] = &[
+    "bash",
+    "c",
+    "c++",
+    "css",
+    "diff",
+    "docker",
+    "go",
+    "graphql",
+    "html",
+    "java",
+    "javascript",
+    "json",
+    "kotlin",
+    "markdown",
+    "mermaid",
+    "python",
+    "ruby",
+    "rust",
+    "shell",
+    "sql",
+    "swift",
+    "typescript",
+    "yaml",
+];
+
+/// One block of a markdown document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Heading(u8, String),
+    Paragraph(String),
+    Bullet(String),
+    Numbered(String),
+    ToDo(bool, String),
+    Quote(String),
+    /// Language (empty when none) and code
+    Code(String, String),
+    Divider,
+}
+
+/// A block and the blocks nested below it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub block: Block,
+    pub children: Vec<Node>,
+}
+
+impl Block {
+    fn is_list_item(&self) -> bool {
+        matches!(
+            self,
+            Block::Bullet(_) | Block::Numbered(_) | Block::ToDo(..)
+        )
+    }
+}
+
+/// Blocks of a markdown document
+pub fn parse_markdown(markdown: &str) -> Vec<Node> {
+    // Open list items by indentation, innermost last
+    let mut nodes: Vec<Node> = Vec::new();
+    let mut open: Vec<usize> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut previous_was_quote = false;
+    let mut lines = markdown.lines();
+
+    let flush = |paragraph: &mut Vec<&str>, nodes: &mut Vec<Node>| {
+        if !paragraph.is_empty() {
+            nodes.push(leaf(Block::Paragraph(paragraph.join("\n"))));
+            paragraph.clear();
+        }
+    };
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        let indent = (line.len() - trimmed.len()) / 2;
+
+        if let Some(info) = trimmed.strip_prefix("```") {
+            flush(&mut paragraph, &mut nodes);
+            open.clear();
+            let code: Vec<&str> = lines
+                .by_ref()
+                .take_while(|line| line.trim_start() != "```")
+                .collect();
+            nodes.push(leaf(Block::Code(info.trim().to_string(), code.join("\n"))));
+            continue;
+        }
+        if trimmed.is_empty() {
+            flush(&mut paragraph, &mut nodes);
+            previous_was_quote = false;
+            continue;
+        }
+
+        let Some(block) = line_block(trimmed) else {
+            open.clear();
+            previous_was_quote = false;
+            paragraph.push(line);
+            continue;
+        };
+        flush(&mut paragraph, &mut nodes);
+        if let (
+            Block::Quote(text),
+            Some(Node {
+                block: Block::Quote(quote),
+                ..
+            }),
+        ) = (&block, nodes.last_mut())
+            && previous_was_quote
+        {
+            quote.push('\n');
+            quote.push_str(text);
+            continue;
+        }
+        previous_was_quote = matches!(block, Block::Quote(_));
+        if !block.is_list_item() {
+            open.clear();
+            nodes.push(leaf(block));
+            continue;
+        }
+
+        // Nest below the nearest open item with less indentation, at most
+        // as deep as Notion accepts in one request
+        while open
+            .last()
+            .is_some_and(|open_indent| *open_indent >= indent)
+        {
+            open.pop();
+        }
+        open.truncate(MAX_DEPTH);
+        push_nested(&mut nodes, open.len(), leaf(block));
+        open.push(indent);
+    }
+    flush(&mut paragraph, &mut nodes);
+    nodes
+}
+
+/// Push `node` `depth` levels down, below the last node of each level
+fn push_nested(nodes: &mut Vec<Node>, depth: usize, node: Node) {
+    match nodes.last_mut() {
+        Some(parent) if depth > 0 => push_nested(&mut parent.children, depth - 1, node),
+        _ => nodes.push(node),
+    }
+}
+
+/// Markdown for `nodes`: blank lines between blocks, none between list items
+pub fn render_markdown(nodes: &[Node]) -> String {
+    let mut output = String::new();
+    render_into(nodes, 0, &mut output);
+    output.trim_end().to_string()
+}
+
+fn render_into(nodes: &[Node], depth: usize, output: &mut String) {
+    let indent = "  ".repeat(depth);
+    let mut number = 0;
+    for (index, node) in nodes.iter().enumerate() {
+        if index > 0 && !(node.block.is_list_item() && nodes[index - 1].block.is_list_item()) {
+            output.push('\n');
+        }
+        number = if matches!(node.block, Block::Numbered(_)) {
+            number + 1
+        } else {
+            0
+        };
+        let line = match &node.block {
+            Block::Heading(level, text) => format!("{} {}", "#".repeat(*level as usize), text),
+            Block::Paragraph(text) => text.clone(),
+            Block::Bullet(text) => format!("- {}", text),
+            Block::Numbered(text) => format!("{}. {}", number, text),
+            Block::ToDo(checked, text) => {
+                format!("- [{}] {}", if *checked { "x" } else { " " }, text)
+            }
+            Block::Quote(text) => text
+                .lines()
+                .map(|line| format!("> {}", line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Block::Code(language, code) => format!("```{}\n{}\n```", language, code),
+            Block::Divider => "---".to_string(),
+        };
+        for text_line in line.lines() {
+            output.push_str(&indent);
+            output.push_str(text_line);
+            output.push('\n');
+        }
+        render_into(&node.children, depth + 1, output);
+    }
+}
+
+/// The Notion block object for `node`, with its children
+pub fn to_notion(node: &Node) -> Value {
+    let (kind, mut body) = match &node.block {
+        Block::Heading(level, text) => (
+            format!("heading_{}", level.clamp(&1, &3)),
+            json!({"rich_text": rich_text(text)}),
+        ),
+        Block::Paragraph(text) => (
+            "paragraph".to_string(),
+            json!({"rich_text": rich_text(text)}),
+        ),
+        Block::Bullet(text) => (
+            "bulleted_list_item".to_string(),
+            json!({"rich_text": rich_text(text)}),
+        ),
+        Block::Numbered(text) => (
+            "numbered_list_item".to_string(),
+            json!({"rich_text": rich_text(text)}),
+        ),
+        Block::ToDo(checked, text) => (
+            "to_do".to_string(),
+            json!({"rich_text": rich_text(text), "checked": checked}),
+        ),
+        Block::Quote(text) => ("quote".to_string(), json!({"rich_text": rich_text(text)})),
+        Block::Code(language, code) => {
+            let known = LANGUAGES.contains(&language.to_lowercase().as_str());
+            (
+                "code".to_string(),
+                json!({
+                    "rich_text": rich_text(code),
+                    "language": if known { language.to_lowercase() } else { "plain text".to_string() },
+                    // Keeps the fence's info string when Notion does not know the language
+                    "caption": if known || language.is_empty() { json!([]) } else { rich_text(language) },
+                }),
+            )
+        }
+        Block::Divider => ("divider".to_string(), json!({})),
+    };
+    if !node.children.is_empty() {
+        body["children"] = Value::Array(node.children.iter().map(to_notion).collect());
+    }
+    json!({"object": "block", "type": kind, kind: body})
+}
+
+/// The block for a Notion block object; `None` for types with no markdown
+/// form, such as child pages and databases
+pub fn from_notion(block: &Value) -> Option<Block> {
+    let kind = block["type"].as_str()?;
+    let body = &block[kind];
+    let text = plain_text(&body["rich_text"]);
+    Some(match kind {
+        "heading_1" => Block::Heading(1, text),
+        "heading_2" => Block::Heading(2, text),
+        "heading_3" => Block::Heading(3, text),
+        "paragraph" => Block::Paragraph(text),
+        "bulleted_list_item" => Block::Bullet(text),
+        "numbered_list_item" => Block::Numbered(text),
+        "to_do" => Block::ToDo(body["checked"].as_bool().unwrap_or(false), text),
+        "quote" | "callout" => Block::Quote(text),
+        "code" => {
+            let caption = plain_text(&body["caption"]);
+            let language = match body["language"].as_str().unwrap_or_default() {
+                "plain text" => caption,
+                language => language.to_string(),
+            };
+            Block::Code(language, text)
+        }
+        "divider" => Block::Divider,
+        _ => return None,
+    })
+}
+
+/// Rich text objects holding `text`, split at Notion's length limit
+pub fn rich_text(text: &str) -> Value {
+    let chars: Vec<char> = text.chars().collect();
+    Value::Array(
+        chars
+            .chunks(MAX_TEXT_LENGTH)
+            .map(|chunk| {
+                json!({"type": "text", "text": {"content": chunk.iter().collect::<String>()}})
+            })
+            .collect(),
+    )
+}
+
+/// The text of a rich text array
+pub fn plain_text(rich_text: &Value) -> String {
+    rich_text
+        .as_array()
+        .map(|parts| {
+            parts
+                .iter()
+                .filter_map(|part| {
+                    part["plain_text"]
+                        .as_str()
+                        .or_else(|| part["text"]["content"].as_str())
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn leaf(block: Block) -> Node {
+    Node {
+        block,
+        children: Vec::new(),
+    }
+}
+
+/// The block a single non-paragraph line starts, if any
+fn line_block(line: &str) -> Option<Block> {
+    let level = line.len() - line.trim_start_matches('#').len();
+    if level > 0 && line[level..].starts_with(' ') {
+        return Some(Block::Heading(
+            level.min(3) as u8,
+            line[level..].trim().to_string(),
+        ));
+    }
+    if line == "---" || line == "***" {
+        return Some(Block::Divider);
+    }
+    if let Some(text) = line.strip_prefix("> ").or_else(|| line.strip_prefix('>')) {
+        return Some(Block::Quote(text.to_string()));
+    }
+    if let Some(rest) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        if let Some(text) = rest.strip_prefix("[ ] ") {
+            return Some(Block::ToDo(false, text.to_string()));
+        }
+        if let Some(text) = rest
+            .strip_prefix("[x] ")
+            .or_else(|| rest.strip_prefix("[X] "))
+        {
+            return Some(Block::ToDo(true, text.to_string()));
+        }
+        return Some(Block::Bullet(rest.to_string()));
+    }
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    if digits > 0
+        && let Some(text) = line[digits..].strip_prefix(". ")
+    {
+        return Some(Block::Numbered(text.to_string()));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_round_trips_through_blocks() {
+        let markdown = "# Auth\n\nLogin for the web app.\nSecond line stays with it.\n\n## Requirements\n\n- Email login\n  - Magic links\n- [x] SSO\n1. First\n2. Second\n\n> Keep it simple\n> and small\n\n```rust\nfn main() {}\n```\n\n```ini\nkey = value\n```\n\n| a | b |\n|---|---|\n\n---";
+        let nodes = parse_markdown(markdown);
+        assert_eq!(
+            nodes[3].children,
+            [leaf(Block::Bullet("Magic links".to_string()))]
+        );
+
+        let blocks: Vec<Value> = nodes.iter().map(to_notion).collect();
+        assert_eq!(blocks[3]["type"], "bulleted_list_item");
+        assert_eq!(
+            blocks[3]["bulleted_list_item"]["children"][0]["type"],
+            "bulleted_list_item"
+        );
+        assert_eq!(blocks[8]["code"]["language"], "rust");
+        assert_eq!(blocks[9]["code"]["language"], "plain text");
+
+        let restored: Vec<Node> = blocks
+            .iter()
+            .map(|block| Node {
+                block: from_notion(block).unwrap(),
+                children: block[block["type"].as_str().unwrap()]["children"]
+                    .as_array()
+                    .map(|children| {
+                        children
+                            .iter()
+                            .map(|child| leaf(from_notion(child).unwrap()))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            })
+            .collect();
+        assert_eq!(render_markdown(&restored), markdown);
+    }
+
+    #[test]
+    fn test_long_text_is_split_into_rich_text_chunks() {
+        let text = "x".repeat(MAX_TEXT_LENGTH + 5);
+        let parts = rich_text(&text);
+        assert_eq!(parts.as_array().unwrap().len(), 2);
+        assert_eq!(plain_text(&parts), text);
+    }
+}
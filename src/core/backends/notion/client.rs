@@ -0,0 +1,396 @@
+//! Minimal Notion API client: pages, blocks and databases
+//!
+//! Only the endpoints the backend needs are wrapped. List endpoints are paged
+//! through until `has_more` is false, and rate-limited (429) requests are
+//! retried after `Retry-After` or a short backoff. Failed (5xx) requests are
+//! retried only when repeating them is harmless: not for page or database
+//! creation, nor for appending blocks, which Notion may have applied already.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use reqwest::{Method, StatusCode};
+use serde_json::{Value, json};
+
+use crate::types::errors::ErrorCode;
+
+/// Notion REST API root
+pub const API_URL: &str = "https://api.notion.com/v1";
+
+/// API version sent as `Notion-Version`
+const NOTION_VERSION: &str = "2022-06-28";
+
+/// Most blocks Notion accepts in one append or page creation
+pub const MAX_BLOCKS_PER_REQUEST: usize = 100;
+
+/// Retries of a rate-limited or failed request before giving up
+const MAX_RETRIES: u32 = 3;
+
+/// Wait before a retry when the response names none
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Longest `Retry-After` honoured; longer waits are cut to this
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Authenticated client for one Notion integration
+#[derive(Debug, Clone)]
+pub struct NotionClient {
+    http: reqwest::Client,
+    token: String,
+    base_url: String,
+}
+
+impl NotionClient {
+    /// Client for `base_url` (normally [`API_URL`]) authenticated with `token`
+    pub fn new(token: &str, base_url: &str) -> Result<Self> {
+        Ok(Self {
+            http: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .context("Failed to create HTTP client")?,
+            token: token.to_string(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Send one request, retrying rate limits and, for idempotent requests,
+    /// server errors
+    pub async fn request(&self, method: Method, path: &str, body: Option<&Value>) -> Result<Value> {
+        let url = format!("{}/{}", self.base_url, path.trim_start_matches('/'));
+        let mut attempt = 0;
+        loop {
+            let mut request = self
+                .http
+                .request(method.clone(), &url)
+                .bearer_auth(&self.token)
+                .header("Notion-Version", NOTION_VERSION);
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+            let response = request.send().await.map_err(|e| {
+                ErrorCode::BackendUnavailable
+                    .error(format!("Notion request {} {} failed: {}", method, path, e))
+            })?;
+            let status = response.status();
+            let retryable = status == StatusCode::TOO_MANY_REQUESTS
+                || (status.is_server_error() && is_idempotent(&method, path));
+            if retryable && attempt < MAX_RETRIES {
+                let wait = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(retry_after)
+                    .unwrap_or(RETRY_BACKOFF * 2u32.pow(attempt));
+                tracing::debug!(
+                    "Notion answered {} to {}; retrying in {:?}",
+                    status,
+                    path,
+                    wait
+                );
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+                continue;
+            }
+
+            let text = response.text().await.unwrap_or_default();
+            if status.is_success() {
+                return serde_json::from_str(&text)
+                    .with_context(|| format!("Invalid JSON from Notion for {}", path));
+            }
+            return Err(api_error(status, &text, path));
+        }
+    }
+
+    /// Every child block of `block_id` (a page or block), in order
+    pub async fn children(&self, block_id: &str) -> Result<Vec<Value>> {
+        let mut blocks = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut path = format!("blocks/{}/children?page_size=100", block_id);
+            if let Some(cursor) = &cursor {
+                path.push_str(&format!("&start_cursor={}", cursor));
+            }
+            let page = self.request(Method::GET, &path, None).await?;
+            blocks.extend(results(&page));
+            match next_cursor(&page) {
+                Some(next) => cursor = Some(next),
+                None => return Ok(blocks),
+            }
+        }
+    }
+
+    /// Every row of `database_id` matching `filter`, sorted by `sorts`
+    pub async fn query(
+        &self,
+        database_id: &str,
+        filter: Option<Value>,
+        sorts: Option<Value>,
+    ) -> Result<Vec<Value>> {
+        let mut rows = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut body = json!({"page_size": 100});
+            if let Some(filter) = &filter {
+                body["filter"] = filter.clone();
+            }
+            if let Some(sorts) = &sorts {
+                body["sorts"] = sorts.clone();
+            }
+            if let Some(cursor) = &cursor {
+                body["start_cursor"] = json!(cursor);
+            }
+            let page = self
+                .request(
+                    Method::POST,
+                    &format!("databases/{}/query", database_id),
+                    Some(&body),
+                )
+                .await?;
+            rows.extend(results(&page));
+            match next_cursor(&page) {
+                Some(next) => cursor = Some(next),
+                None => return Ok(rows),
+            }
+        }
+    }
+
+    /// Create a page below a page or database; `children` beyond the first
+    /// [`MAX_BLOCKS_PER_REQUEST`] are appended afterwards
+    pub async fn create_page(
+        &self,
+        parent: Value,
+        properties: Value,
+        mut children: Vec<Value>,
+    ) -> Result<Value> {
+        let rest = children.split_off(children.len().min(MAX_BLOCKS_PER_REQUEST));
+        let page = self
+            .request(
+                Method::POST,
+                "pages",
+                Some(&json!({"parent": parent, "properties": properties, "children": children})),
+            )
+            .await?;
+        self.append(id(&page)?, rest).await?;
+        Ok(page)
+    }
+
+    /// Set some of a page's properties
+    pub async fn update_page(&self, page_id: &str, properties: Value) -> Result<()> {
+        self.request(
+            Method::PATCH,
+            &format!("pages/{}", page_id),
+            Some(&json!({"properties": properties})),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Move a page (and everything below it) to the trash
+    pub async fn archive_page(&self, page_id: &str) -> Result<()> {
+        self.request(
+            Method::PATCH,
+            &format!("pages/{}", page_id),
+            Some(&json!({"archived": true})),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Append `blocks` to the end of `block_id`'s children
+    pub async fn append(&self, block_id: &str, blocks: Vec<Value>) -> Result<()> {
+        for chunk in blocks.chunks(MAX_BLOCKS_PER_REQUEST) {
+            self.request(
+                Method::PATCH,
+                &format!("blocks/{}/children", block_id),
+                Some(&json!({"children": chunk})),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn delete_block(&self, block_id: &str) -> Result<()> {
+        self.request(Method::DELETE, &format!("blocks/{}", block_id), None)
+            .await?;
+        Ok(())
+    }
+
+    /// Create an inline database titled `title` on page `parent_page_id`
+    pub async fn create_database(
+        &self,
+        parent_page_id: &str,
+        title: &str,
+        properties: Value,
+    ) -> Result<String> {
+        let database = self
+            .request(
+                Method::POST,
+                "databases",
+                Some(&json!({
+                    "parent": {"type": "page_id", "page_id": parent_page_id},
+                    "title": [{"type": "text", "text": {"content": title}}],
+                    "is_inline": true,
+                    "properties": properties,
+                })),
+            )
+            .await?;
+        Ok(id(&database)?.to_string())
+    }
+
+    /// Add `properties` to a database; properties it already has are kept
+    pub async fn update_database(&self, database_id: &str, properties: Value) -> Result<()> {
+        self.request(
+            Method::PATCH,
+            &format!("databases/{}", database_id),
+            Some(&json!({"properties": properties})),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Whether sending `method` to `path` twice has the same effect as once:
+/// POSTs create pages and databases and appends add blocks each time
+fn is_idempotent(method: &Method, path: &str) -> bool {
+    match *method {
+        Method::POST => false,
+        Method::PATCH => !path.ends_with("/children"),
+        _ => true,
+    }
+}
+
+/// Wait named by a `Retry-After` header in seconds, capped at
+/// [`MAX_RETRY_AFTER`]; `None` for values that are not a valid duration
+fn retry_after(value: &str) -> Option<Duration> {
+    let seconds = value.trim().parse::<f64>().ok()?;
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .map(|wait| wait.min(MAX_RETRY_AFTER))
+}
+
+/// The `id` of a page, block or database
+pub fn id(object: &Value) -> Result<&str> {
+    object["id"]
+        .as_str()
+        .context("Notion response is missing an object id")
+}
+
+fn results(page: &Value) -> Vec<Value> {
+    page["results"].as_array().cloned().unwrap_or_default()
+}
+
+fn next_cursor(page: &Value) -> Option<String> {
+    if page["has_more"].as_bool() != Some(true) {
+        return None;
+    }
+    page["next_cursor"].as_str().map(str::to_string)
+}
+
+/// Error for a failed call, with the Notion error code and message
+fn api_error(status: StatusCode, body: &str, path: &str) -> anyhow::Error {
+    let parsed: Value = serde_json::from_str(body).unwrap_or_default();
+    let message = parsed["message"].as_str().unwrap_or(body);
+    let detail = format!(
+        "Notion answered {} to {} ({}): {}",
+        status,
+        path,
+        parsed["code"].as_str().unwrap_or("unknown"),
+        message
+    );
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => anyhow::anyhow!(
+            "{}. Check NOTION_TOKEN and share the root page with the integration",
+            detail
+        ),
+        StatusCode::TOO_MANY_REQUESTS => ErrorCode::ResourceBusy.error(format!(
+            "{}. Notion is rate limiting requests; retry in a few seconds",
+            detail
+        )),
+        StatusCode::CONFLICT => ErrorCode::BackendConflict.error(detail),
+        status if status.is_server_error() => ErrorCode::BackendUnavailable
+            .error(format!("{}. Notion is having trouble; retry later", detail)),
+        _ => anyhow::anyhow!(detail),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[tokio::test]
+    async fn test_rate_limited_requests_are_retried() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for response in [
+                "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 26\r\nConnection: close\r\n\r\n{\"results\":[{\"id\":\"b1\"}]}\n",
+            ] {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buffer = vec![0; 4096];
+                let read = stream.read(&mut buffer).await.unwrap();
+                requests.push(String::from_utf8_lossy(&buffer[..read]).to_string());
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+            requests
+        });
+
+        let client = NotionClient::new("secret-token", &base_url).unwrap();
+        let blocks = client.children("page-1").await.unwrap();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(id(&blocks[0]).unwrap(), "b1");
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].starts_with("GET /blocks/page-1/children?page_size=100 "));
+        let lowercase = requests[1].to_lowercase();
+        assert!(lowercase.contains("authorization: bearer secret-token"));
+        assert!(lowercase.contains("notion-version: 2022-06-28"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_page_creation_is_not_retried() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = vec![0; 4096];
+            let _ = stream.read(&mut buffer).await.unwrap();
+            stream
+                .write_all(
+                    b"HTTP/1.1 502 Bad Gateway\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                )
+                .await
+                .unwrap();
+            // A retry would be a second connection
+            tokio::time::timeout(Duration::from_secs(2), listener.accept())
+                .await
+                .is_err()
+        });
+
+        let client = NotionClient::new("secret-token", &base_url).unwrap();
+        let error = client
+            .create_page(json!({"page_id": "p"}), json!({}), Vec::new())
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::BackendUnavailable);
+        assert!(server.await.unwrap());
+    }
+
+    #[test]
+    fn test_retry_after_rejects_invalid_waits() {
+        assert_eq!(retry_after("1.5"), Some(Duration::from_millis(1500)));
+        assert_eq!(retry_after("100000"), Some(MAX_RETRY_AFTER));
+        for invalid in ["-1", "NaN", "inf", "soon"] {
+            assert_eq!(retry_after(invalid), None, "{}", invalid);
+        }
+        assert!(!is_idempotent(&Method::POST, "pages"));
+        assert!(!is_idempotent(&Method::PATCH, "blocks/b1/children"));
+        assert!(is_idempotent(&Method::PATCH, "pages/p1"));
+        assert!(is_idempotent(&Method::GET, "blocks/b1/children"));
+    }
+}
@@ -0,0 +1,1012 @@
+//! Notion backend: projects and specs as Notion pages
+//!
+//! Everything lives below one root page (`--notion-page` or `[backend]
+//! notion_page`) shared with a Notion integration whose token is read from
+//! `NOTION_TOKEN`:
+//!
+//! ```text
+//! <root page>
+//!   Foundry Projects      database: Name, Tags
+//!     <project>           Vision, Tech Stack, Summary and Decisions pages
+//!       Specs             database: Name, Feature, Status, Depends On, Tags,
+//!                         Created, Updated, Tasks Done, Tasks Total
+//!         <spec>          Spec and Notes pages
+//!           Tasks         database: Task, Done, Section, Position
+//! ```
+//!
+//! Documents are stored as blocks (see [`blocks`]). A task list is stored as
+//! one database row per checklist item, so only its headings and checklist
+//! items are kept, and nested tasks are flattened. Notion has no way to
+//! replace a page's content in one request: an update deletes the old blocks
+//! and appends the new ones, and a failure in between leaves the document
+//! partly written, so `atomic_replace` is not reported. Writes from one
+//! machine are serialized with the same lock files as the filesystem backend.
+
+pub mod blocks;
+pub mod client;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde_json::{Value, json};
+use tokio::sync::OnceCell;
+
+use crate::core::backends::{BackendCapabilities, FoundryBackend, ResourceLocator};
+use crate::core::lock::{self, WriteLock};
+use crate::core::spec_name;
+use crate::core::tasks;
+use crate::types::errors::ErrorCode;
+use crate::types::{
+    project::{Project, ProjectConfig, ProjectFileType, ProjectMetadata},
+    spec::{
+        Spec, SpecConfig, SpecContentData, SpecFileType, SpecMetadata, SpecStatus, SpecSummary,
+        TaskCounts,
+    },
+};
+
+use blocks::{Node, plain_text, rich_text};
+use client::{NotionClient, id};
+
+/// Environment variable holding the integration token
+pub const TOKEN_ENV: &str = "NOTION_TOKEN";
+
+const PROJECTS_DATABASE: &str = "Foundry Projects";
+const SPECS_DATABASE: &str = "Specs";
+const TASKS_DATABASE: &str = "Tasks";
+const DECISIONS_PAGE: &str = "Decisions";
+
+/// Notion backend rooted at one page
+#[derive(Debug)]
+pub struct NotionBackend {
+    client: NotionClient,
+    root_page_id: String,
+    projects_database: OnceCell<String>,
+}
+
+/// One checklist item of a task list, stored as a row of the Tasks database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskRow {
+    /// Nearest heading above the item, empty when none
+    pub section: String,
+    pub text: String,
+    pub done: bool,
+}
+
+/// Child pages and databases of a page, by title
+#[derive(Debug, Default)]
+struct PageChildren {
+    pages: HashMap<String, String>,
+    databases: HashMap<String, String>,
+}
+
+impl NotionBackend {
+    /// Backend for the root page `page` (an id or page URL), authenticated
+    /// with the token in `NOTION_TOKEN`
+    pub fn open(page: &str) -> Result<Self> {
+        let token = std::env::var(TOKEN_ENV).map_err(|_| {
+            ErrorCode::InvalidParams.error(format!(
+                "The notion backend needs an integration token in {}",
+                TOKEN_ENV
+            ))
+        })?;
+        Self::with_client(NotionClient::new(&token, client::API_URL)?, page)
+    }
+
+    /// Backend using `client`, e.g. one pointed at a test server
+    pub fn with_client(client: NotionClient, page: &str) -> Result<Self> {
+        Ok(Self {
+            client,
+            root_page_id: page_id(page)?,
+            projects_database: OnceCell::new(),
+        })
+    }
+
+    /// The Foundry Projects database, created below the root page on first use
+    async fn projects_database(&self) -> Result<&str> {
+        self.projects_database
+            .get_or_try_init(|| async {
+                let children = self.page_children(&self.root_page_id).await?;
+                if let Some(database_id) = children.databases.get(PROJECTS_DATABASE) {
+                    return Ok(database_id.clone());
+                }
+                self.client
+                    .create_database(
+                        &self.root_page_id,
+                        PROJECTS_DATABASE,
+                        json!({"Name": {"title": {}}, "Tags": {"multi_select": {}}}),
+                    )
+                    .await
+            })
+            .await
+            .map(String::as_str)
+    }
+
+    async fn page_children(&self, page_id: &str) -> Result<PageChildren> {
+        let mut children = PageChildren::default();
+        for block in self.client.children(page_id).await? {
+            let block_id = id(&block)?.to_string();
+            match block["type"].as_str() {
+                Some("child_page") => {
+                    let title = block["child_page"]["title"].as_str().unwrap_or_default();
+                    children.pages.insert(title.to_string(), block_id);
+                }
+                Some("child_database") => {
+                    let title = block["child_database"]["title"]
+                        .as_str()
+                        .unwrap_or_default();
+                    children.databases.insert(title.to_string(), block_id);
+                }
+                _ => {}
+            }
+        }
+        Ok(children)
+    }
+
+    async fn find_row(&self, database_id: &str, name: &str) -> Result<Option<Value>> {
+        let rows = self
+            .client
+            .query(
+                database_id,
+                Some(json!({"property": "Name", "title": {"equals": name}})),
+                None,
+            )
+            .await?;
+        Ok(rows.into_iter().next())
+    }
+
+    async fn find_project(&self, name: &str) -> Result<Option<Value>> {
+        let database_id = self.projects_database().await?;
+        self.find_row(database_id, name).await
+    }
+
+    async fn project_page(&self, name: &str) -> Result<Value> {
+        self.find_project(name).await?.ok_or_else(|| {
+            ErrorCode::ProjectNotFound.error(format!("Project '{}' not found", name))
+        })
+    }
+
+    async fn specs_database(&self, project_name: &str) -> Result<String> {
+        let project = self.project_page(project_name).await?;
+        let children = self.page_children(id(&project)?).await?;
+        children
+            .databases
+            .get(SPECS_DATABASE)
+            .cloned()
+            .with_context(|| {
+                format!(
+                    "Project '{}' has no '{}' database in Notion",
+                    project_name, SPECS_DATABASE
+                )
+            })
+    }
+
+    async fn spec_page(&self, project_name: &str, spec_name: &str) -> Result<Value> {
+        let database_id = self.specs_database(project_name).await?;
+        self.find_row(&database_id, spec_name)
+            .await?
+            .ok_or_else(|| {
+                ErrorCode::SpecNotFound.error(format!(
+                    "Spec '{}' not found in project '{}'",
+                    spec_name, project_name
+                ))
+            })
+    }
+
+    /// Create a page titled `title` below `parent_id` holding `markdown`
+    async fn create_document(&self, parent_id: &str, title: &str, markdown: &str) -> Result<()> {
+        self.client
+            .create_page(
+                json!({"type": "page_id", "page_id": parent_id}),
+                json!({"title": {"title": rich_text(title)}}),
+                document_blocks(markdown),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn read_document(&self, page_id: &str) -> Result<String> {
+        Ok(blocks::render_markdown(
+            &self.read_nodes(page_id.to_string(), 0).await?,
+        ))
+    }
+
+    /// Blocks below `block_id` with the children of nested list items
+    fn read_nodes(
+        &self,
+        block_id: String,
+        depth: usize,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Node>>> + Send + '_>> {
+        Box::pin(async move {
+            let mut nodes = Vec::new();
+            for block in self.client.children(&block_id).await? {
+                let Some(converted) = blocks::from_notion(&block) else {
+                    continue;
+                };
+                let children = if block["has_children"].as_bool() == Some(true) && depth < 2 {
+                    self.read_nodes(id(&block)?.to_string(), depth + 1).await?
+                } else {
+                    Vec::new()
+                };
+                nodes.push(Node {
+                    block: converted,
+                    children,
+                });
+            }
+            Ok(nodes)
+        })
+    }
+
+    /// Replace the content of the document page `page_id`
+    async fn write_document(&self, page_id: &str, markdown: &str) -> Result<()> {
+        for block in self.client.children(page_id).await? {
+            if !matches!(
+                block["type"].as_str(),
+                Some("child_page" | "child_database")
+            ) {
+                self.client.delete_block(id(&block)?).await?;
+            }
+        }
+        self.client.append(page_id, document_blocks(markdown)).await
+    }
+
+    /// The document titled `title` below `parent_id`, created empty if missing
+    async fn document_page(&self, parent_id: &str, title: &str) -> Result<String> {
+        if let Some(page_id) = self.page_children(parent_id).await?.pages.remove(title) {
+            return Ok(page_id);
+        }
+        let page = self
+            .client
+            .create_page(
+                json!({"type": "page_id", "page_id": parent_id}),
+                json!({"title": {"title": rich_text(title)}}),
+                Vec::new(),
+            )
+            .await?;
+        Ok(id(&page)?.to_string())
+    }
+
+    async fn read_tasks(&self, database_id: &str) -> Result<String> {
+        let rows: Vec<TaskRow> = self
+            .client
+            .query(
+                database_id,
+                None,
+                Some(json!([{"property": "Position", "direction": "ascending"}])),
+            )
+            .await?
+            .iter()
+            .map(task_row)
+            .collect();
+        Ok(render_tasks(&rows))
+    }
+
+    /// Make the rows of the Tasks database match `markdown`, updating rows in
+    /// place where possible
+    async fn write_tasks(&self, database_id: &str, markdown: &str) -> Result<()> {
+        let wanted = task_rows(markdown);
+        let existing = self
+            .client
+            .query(
+                database_id,
+                None,
+                Some(json!([{"property": "Position", "direction": "ascending"}])),
+            )
+            .await?;
+
+        for (position, row) in wanted.iter().enumerate() {
+            match existing.get(position) {
+                Some(page)
+                    if task_row(page) == *row
+                        && page["properties"]["Position"]["number"].as_u64()
+                            == Some(position as u64) => {}
+                Some(page) => {
+                    self.client
+                        .update_page(id(page)?, task_properties(row, position))
+                        .await?;
+                }
+                None => {
+                    self.client
+                        .create_page(
+                            json!({"type": "database_id", "database_id": database_id}),
+                            task_properties(row, position),
+                            Vec::new(),
+                        )
+                        .await?;
+                }
+            }
+        }
+        for page in existing.iter().skip(wanted.len()) {
+            self.client.archive_page(id(page)?).await?;
+        }
+        Ok(())
+    }
+
+    async fn create_tasks_database(&self, spec_page_id: &str) -> Result<String> {
+        self.client
+            .create_database(
+                spec_page_id,
+                TASKS_DATABASE,
+                json!({
+                    "Task": {"title": {}},
+                    "Done": {"checkbox": {}},
+                    "Section": {"rich_text": {}},
+                    "Position": {"number": {}},
+                }),
+            )
+            .await
+    }
+
+    async fn spec_metadata(&self, project_name: &str, row: &Value) -> SpecMetadata {
+        let properties = &row["properties"];
+        let name = plain_text(&properties["Name"]["title"]);
+        let total = number(&properties["Tasks Total"]);
+        let completed = number(&properties["Tasks Done"]).min(total);
+        SpecMetadata {
+            feature_name: feature_name(&name),
+            name,
+            created_at: created_at(row),
+            project_name: project_name.to_string(),
+            status: properties["Status"]["select"]["name"]
+                .as_str()
+                .and_then(|status| status.parse().ok())
+                .unwrap_or_default(),
+            depends_on: split_list(&plain_text(&properties["Depends On"]["rich_text"])),
+            tags: multi_select(&properties["Tags"]),
+            task_counts: TaskCounts {
+                total,
+                completed,
+                remaining: total - completed,
+            },
+            updated_at: Some(plain_text(&properties["Updated"]["rich_text"]))
+                .filter(|updated| !updated.is_empty()),
+            modified_at: Default::default(),
+            summary: spec_summary(properties),
+        }
+    }
+
+    /// Record a content change on the spec row, with task counts after a task list write
+    async fn touch_spec(&self, row_id: &str, tasks_markdown: Option<&str>) -> Result<()> {
+        let mut properties = json!({
+            "Updated": {"rich_text": rich_text(&Utc::now().to_rfc3339())},
+        });
+        if let Some(markdown) = tasks_markdown {
+            let counts = tasks::count_tasks(markdown);
+            properties["Tasks Done"] = json!({"number": counts.completed});
+            properties["Tasks Total"] = json!({"number": counts.total});
+        }
+        self.client.update_page(row_id, properties).await
+    }
+}
+
+#[async_trait::async_trait]
+impl FoundryBackend for NotionBackend {
+    async fn create_project(&self, config: ProjectConfig) -> Result<Project> {
+        if self.find_project(&config.name).await?.is_some() {
+            return Err(
+                ErrorCode::AlreadyExists.error(format!("Project '{}' already exists", config.name))
+            );
+        }
+        let database_id = self.projects_database().await?;
+        let page = self
+            .client
+            .create_page(
+                json!({"type": "database_id", "database_id": database_id}),
+                json!({"Name": {"title": rich_text(&config.name)}}),
+                Vec::new(),
+            )
+            .await?;
+        let page_id = id(&page)?;
+        for (file_type, content) in [
+            (ProjectFileType::Vision, &config.vision),
+            (ProjectFileType::TechStack, &config.tech_stack),
+            (ProjectFileType::Summary, &config.summary),
+        ] {
+            self.create_document(page_id, project_document_title(&file_type), content)
+                .await?;
+        }
+        self.client
+            .create_database(
+                page_id,
+                SPECS_DATABASE,
+                json!({
+                    "Name": {"title": {}},
+                    "Feature": {"rich_text": {}},
+                    "Status": {"select": {}},
+                    "Depends On": {"rich_text": {}},
+                    "Tags": {"multi_select": {}},
+                    "Created": {"rich_text": {}},
+                    "Updated": {"rich_text": {}},
+                    "Tasks Done": {"number": {}},
+                    "Tasks Total": {"number": {}},
+                    "Summary": {"rich_text": {}},
+                    "Summary Info": {"rich_text": {}},
+                }),
+            )
+            .await?;
+
+        Ok(Project {
+            name: config.name,
+            created_at: created_at(&page),
+            path: std::path::PathBuf::new(),
+            location_hint: url(&page),
+            locator: Some(locator(&page)?),
+            vision: Some(config.vision),
+            tech_stack: Some(config.tech_stack),
+            summary: Some(config.summary),
+            decisions: None,
+        })
+    }
+
+    async fn project_exists(&self, name: &str) -> Result<bool> {
+        Ok(self.find_project(name).await?.is_some())
+    }
+
+    async fn list_projects(&self) -> Result<Vec<ProjectMetadata>> {
+        let database_id = self.projects_database().await?;
+        let mut projects = Vec::new();
+        for row in self.client.query(database_id, None, None).await? {
+            let name = plain_text(&row["properties"]["Name"]["title"]);
+            let spec_count = self.count_specs(&name).await.unwrap_or(0);
+            projects.push(ProjectMetadata {
+                created_at: created_at(&row),
+                last_modified: row["last_edited_time"]
+                    .as_str()
+                    .map_or_else(|| created_at(&row), str::to_string),
+                spec_count,
+                tags: multi_select(&row["properties"]["Tags"]),
+                name,
+            });
+        }
+        projects.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(projects)
+    }
+
+    async fn load_project(&self, name: &str) -> Result<Project> {
+        let page = self.project_page(name).await?;
+        let children = self.page_children(id(&page)?).await?;
+        let read = async |title: &str| -> Result<Option<String>> {
+            match children.pages.get(title) {
+                Some(page_id) => Ok(Some(self.read_document(page_id).await?)),
+                None => Ok(None),
+            }
+        };
+        Ok(Project {
+            name: name.to_string(),
+            created_at: created_at(&page),
+            path: std::path::PathBuf::new(),
+            location_hint: url(&page),
+            locator: Some(locator(&page)?),
+            vision: read(project_document_title(&ProjectFileType::Vision)).await?,
+            tech_stack: read(project_document_title(&ProjectFileType::TechStack)).await?,
+            summary: read(project_document_title(&ProjectFileType::Summary)).await?,
+            decisions: read(DECISIONS_PAGE).await?,
+        })
+    }
+
+    async fn update_decisions(&self, project_name: &str, content: &str) -> Result<()> {
+        let project = self.project_page(project_name).await?;
+        let page_id = self.document_page(id(&project)?, DECISIONS_PAGE).await?;
+        self.write_document(&page_id, content).await
+    }
+
+    async fn update_project_content(
+        &self,
+        project_name: &str,
+        file_type: ProjectFileType,
+        content: &str,
+    ) -> Result<()> {
+        let project = self.project_page(project_name).await?;
+        let page_id = self
+            .document_page(id(&project)?, project_document_title(&file_type))
+            .await?;
+        self.write_document(&page_id, content).await
+    }
+
+    async fn delete_project(&self, name: &str) -> Result<()> {
+        let project = self.project_page(name).await?;
+        self.client.archive_page(id(&project)?).await?;
+        lock::remove_project_locks(&crate::core::filesystem::foundry_dir()?, name)
+    }
+
+    async fn set_project_tags(&self, project_name: &str, tags: &[String]) -> Result<()> {
+        let project = self.project_page(project_name).await?;
+        self.client
+            .update_page(id(&project)?, json!({"Tags": multi_select_value(tags)}))
+            .await
+    }
+
+    async fn create_spec(&self, config: SpecConfig) -> Result<Spec> {
+        let database_id = self.specs_database(&config.project_name).await?;
+        let existing: Vec<String> = self
+            .client
+            .query(&database_id, None, None)
+            .await?
+            .iter()
+            .map(|row| plain_text(&row["properties"]["Name"]["title"]))
+            .collect();
+        let spec_name = crate::core::spec::generate_spec_name(
+            &config.project_name,
+            &config.feature_name,
+            &existing,
+        )?;
+        let created_at = Utc::now().to_rfc3339();
+        let counts = tasks::count_tasks(&config.content.tasks);
+        let row = self
+            .client
+            .create_page(
+                json!({"type": "database_id", "database_id": database_id}),
+                json!({
+                    "Name": {"title": rich_text(&spec_name)},
+                    "Feature": {"rich_text": rich_text(&config.feature_name)},
+                    "Status": {"select": {"name": SpecStatus::Draft.as_str()}},
+                    "Created": {"rich_text": rich_text(&created_at)},
+                    "Updated": {"rich_text": rich_text(&created_at)},
+                    "Tasks Done": {"number": counts.completed},
+                    "Tasks Total": {"number": counts.total},
+                }),
+                Vec::new(),
+            )
+            .await?;
+        let row_id = id(&row)?;
+        self.create_document(row_id, "Spec", &config.content.spec)
+            .await?;
+        self.create_document(row_id, "Notes", &config.content.notes)
+            .await?;
+        let tasks_database = self.create_tasks_database(row_id).await?;
+        self.write_tasks(&tasks_database, &config.content.tasks)
+            .await?;
+
+        Ok(Spec {
+            name: spec_name,
+            created_at,
+            path: std::path::PathBuf::new(),
+            project_name: config.project_name,
+            location_hint: url(&row),
+            locator: Some(locator(&row)?),
+            content: config.content,
+        })
+    }
+
+    async fn list_specs(&self, project_name: &str) -> Result<Vec<SpecMetadata>> {
+        let database_id = self.specs_database(project_name).await?;
+        let mut specs = Vec::new();
+        for row in self.client.query(&database_id, None, None).await? {
+            specs.push(self.spec_metadata(project_name, &row).await);
+        }
+        specs.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(specs)
+    }
+
+    async fn load_spec_files(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        files: &[SpecFileType],
+    ) -> Result<Spec> {
+        let row = self.spec_page(project_name, spec_name).await?;
+        let children = self.page_children(id(&row)?).await?;
+        let mut content = SpecContentData {
+            spec: String::new(),
+            notes: String::new(),
+            tasks: String::new(),
+        };
+        // Unrequested files are not fetched at all
+        if files.contains(&SpecFileType::Spec)
+            && let Some(page_id) = children.pages.get("Spec")
+        {
+            content.spec = self.read_document(page_id).await?;
+        }
+        if files.contains(&SpecFileType::Notes)
+            && let Some(page_id) = children.pages.get("Notes")
+        {
+            content.notes = self.read_document(page_id).await?;
+        }
+        if files.contains(&SpecFileType::TaskList)
+            && let Some(database_id) = children.databases.get(TASKS_DATABASE)
+        {
+            content.tasks = self.read_tasks(database_id).await?;
+        }
+
+        Ok(Spec {
+            name: spec_name.to_string(),
+            created_at: created_at(&row),
+            path: std::path::PathBuf::new(),
+            project_name: project_name.to_string(),
+            location_hint: url(&row),
+            locator: Some(locator(&row)?),
+            content,
+        })
+    }
+
+    async fn update_spec_content(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        file_type: SpecFileType,
+        content: &str,
+    ) -> Result<()> {
+        let row = self.spec_page(project_name, spec_name).await?;
+        let row_id = id(&row)?;
+        match file_type {
+            SpecFileType::Spec | SpecFileType::Notes => {
+                let title = if file_type == SpecFileType::Spec {
+                    "Spec"
+                } else {
+                    "Notes"
+                };
+                let page_id = self.document_page(row_id, title).await?;
+                self.write_document(&page_id, content).await?;
+                self.touch_spec(row_id, None).await
+            }
+            SpecFileType::TaskList => {
+                let database_id = match self
+                    .page_children(row_id)
+                    .await?
+                    .databases
+                    .remove(TASKS_DATABASE)
+                {
+                    Some(database_id) => database_id,
+                    None => self.create_tasks_database(row_id).await?,
+                };
+                self.write_tasks(&database_id, content).await?;
+                self.touch_spec(row_id, Some(content)).await
+            }
+        }
+    }
+
+    async fn delete_spec(&self, project_name: &str, spec_name: &str) -> Result<()> {
+        let row = self.spec_page(project_name, spec_name).await?;
+        self.client.archive_page(id(&row)?).await
+    }
+
+    async fn rename_spec(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        new_spec_name: &str,
+    ) -> Result<Spec> {
+        let database_id = self.specs_database(project_name).await?;
+        if self.find_row(&database_id, new_spec_name).await?.is_some() {
+            return Err(ErrorCode::AlreadyExists.error(format!(
+                "Spec '{}' already exists in project '{}'",
+                new_spec_name, project_name
+            )));
+        }
+        let row = self.spec_page(project_name, spec_name).await?;
+        self.client
+            .update_page(
+                id(&row)?,
+                json!({
+                    "Name": {"title": rich_text(new_spec_name)},
+                    "Feature": {"rich_text": rich_text(&feature_name(new_spec_name))},
+                }),
+            )
+            .await?;
+        self.load_spec(project_name, new_spec_name).await
+    }
+
+    async fn set_spec_status(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        status: SpecStatus,
+    ) -> Result<()> {
+        let row = self.spec_page(project_name, spec_name).await?;
+        self.client
+            .update_page(
+                id(&row)?,
+                json!({"Status": {"select": {"name": status.as_str()}}}),
+            )
+            .await
+    }
+
+    async fn set_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()> {
+        let row = self.spec_page(project_name, spec_name).await?;
+        self.client
+            .update_page(
+                id(&row)?,
+                json!({"Depends On": {"rich_text": rich_text(&depends_on.join(", "))}}),
+            )
+            .await
+    }
+
+    async fn set_spec_tags(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        tags: &[String],
+    ) -> Result<()> {
+        let row = self.spec_page(project_name, spec_name).await?;
+        self.client
+            .update_page(id(&row)?, json!({"Tags": multi_select_value(tags)}))
+            .await
+    }
+
+    async fn set_spec_summary(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        summary: &SpecSummary,
+    ) -> Result<()> {
+        // Specs databases created before summaries existed lack the properties
+        let database_id = self.specs_database(project_name).await?;
+        self.client
+            .update_database(
+                &database_id,
+                json!({"Summary": {"rich_text": {}}, "Summary Info": {"rich_text": {}}}),
+            )
+            .await?;
+        let row = self.spec_page(project_name, spec_name).await?;
+        let info = json!({
+            "generated_at": summary.generated_at,
+            "model": summary.model,
+            "spec_version": summary.spec_version,
+        });
+        self.client
+            .update_page(
+                id(&row)?,
+                json!({
+                    "Summary": {"rich_text": rich_text(&summary.text)},
+                    "Summary Info": {"rich_text": rich_text(&info.to_string())},
+                }),
+            )
+            .await
+    }
+
+    async fn lock_spec(&self, project_name: &str, spec_name: &str) -> Result<WriteLock> {
+        // Only guards processes on this machine; Notion itself has no locks
+        let path = lock::spec_lock_path(
+            &crate::core::filesystem::foundry_dir()?,
+            project_name,
+            spec_name,
+        )?;
+        lock::acquire(
+            &path,
+            &format!("Spec '{}' in project '{}'", spec_name, project_name),
+            lock::LOCK_TIMEOUT,
+        )
+        .await
+    }
+
+    async fn lock_project(&self, project_name: &str) -> Result<WriteLock> {
+        let path = lock::project_lock_path(&crate::core::filesystem::foundry_dir()?, project_name)?;
+        lock::acquire(
+            &path,
+            &format!("Project '{}'", project_name),
+            lock::LOCK_TIMEOUT,
+        )
+        .await
+    }
+
+    async fn get_latest_spec(&self, project_name: &str) -> Result<Option<SpecMetadata>> {
+        let specs = self.list_specs(project_name).await?;
+        Ok(specs.into_iter().next()) // Already sorted by created_at desc
+    }
+
+    async fn count_specs(&self, project_name: &str) -> Result<usize> {
+        let database_id = self.specs_database(project_name).await?;
+        Ok(self.client.query(&database_id, None, None).await?.len())
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            supports_documents: true,
+            supports_subtasks: false,
+            url_deeplinks: true,
+            // Page content is replaced block by block
+            atomic_replace: false,
+            // Notion's search and query indexes can lag behind writes
+            strong_consistency: false,
+        }
+    }
+}
+
+/// Checklist items of task list markdown, in order
+pub fn task_rows(markdown: &str) -> Vec<TaskRow> {
+    tasks::parse_task_list(markdown)
+        .into_iter()
+        .map(|task| TaskRow {
+            section: task.section,
+            text: task.text,
+            done: task.done,
+        })
+        .collect()
+}
+
+/// Task list markdown for `rows`: a `##` heading per section, then its items
+pub fn render_tasks(rows: &[TaskRow]) -> String {
+    let mut output = String::new();
+    let mut section: Option<&str> = None;
+    for row in rows {
+        if section != Some(row.section.as_str()) {
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            if !row.section.is_empty() {
+                output.push_str(&format!("## {}\n\n", row.section));
+            }
+            section = Some(&row.section);
+        }
+        output.push_str(&format!(
+            "- [{}] {}\n",
+            if row.done { "x" } else { " " },
+            row.text
+        ));
+    }
+    output.trim_end().to_string()
+}
+
+/// The id in a page id or Notion page URL, with or without dashes
+pub fn page_id(page: &str) -> Result<String> {
+    let last = page
+        .trim()
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    let last = last.split(['?', '#']).next().unwrap_or_default();
+    let hex: String = last.chars().filter(|c| *c != '-').collect();
+    let id = hex
+        .get(hex.len().saturating_sub(32)..)
+        .filter(|id| id.len() == 32 && id.chars().all(|c| c.is_ascii_hexdigit()));
+    id.map(str::to_string).ok_or_else(|| {
+        ErrorCode::InvalidParams.error(format!(
+            "'{}' is not a Notion page id or URL; copy the root page's link from Notion",
+            page
+        ))
+    })
+}
+
+fn document_blocks(markdown: &str) -> Vec<Value> {
+    blocks::parse_markdown(markdown)
+        .iter()
+        .map(blocks::to_notion)
+        .collect()
+}
+
+fn project_document_title(file_type: &ProjectFileType) -> &'static str {
+    match file_type {
+        ProjectFileType::Vision => "Vision",
+        ProjectFileType::TechStack => "Tech Stack",
+        ProjectFileType::Summary => "Summary",
+    }
+}
+
+fn task_row(page: &Value) -> TaskRow {
+    let properties = &page["properties"];
+    TaskRow {
+        section: plain_text(&properties["Section"]["rich_text"]),
+        text: plain_text(&properties["Task"]["title"]),
+        done: properties["Done"]["checkbox"].as_bool().unwrap_or(false),
+    }
+}
+
+fn task_properties(row: &TaskRow, position: usize) -> Value {
+    json!({
+        "Task": {"title": rich_text(&row.text)},
+        "Done": {"checkbox": row.done},
+        "Section": {"rich_text": rich_text(&row.section)},
+        "Position": {"number": position},
+    })
+}
+
+/// Feature part of a spec name
+fn feature_name(spec_name: &str) -> String {
+    spec_name::parse(spec_name).map_or_else(|| spec_name.to_string(), |parsed| parsed.feature_name)
+}
+
+/// The stored `Created` property, else Notion's own creation time
+fn created_at(row: &Value) -> String {
+    Some(plain_text(&row["properties"]["Created"]["rich_text"]))
+        .filter(|created| !created.is_empty())
+        .or_else(|| row["created_time"].as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn url(page: &Value) -> Option<String> {
+    page["url"].as_str().map(str::to_string)
+}
+
+fn locator(page: &Value) -> Result<ResourceLocator> {
+    Ok(ResourceLocator::Notion {
+        page_id: id(page)?.to_string(),
+        url: url(page).unwrap_or_default(),
+    })
+}
+
+fn number(property: &Value) -> usize {
+    property["number"].as_u64().unwrap_or(0) as usize
+}
+
+fn multi_select(property: &Value) -> Vec<String> {
+    property["multi_select"]
+        .as_array()
+        .map(|options| {
+            options
+                .iter()
+                .filter_map(|option| option["name"].as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn multi_select_value(tags: &[String]) -> Value {
+    json!({"multi_select": tags.iter().map(|tag| json!({"name": tag})).collect::<Vec<_>>()})
+}
+
+/// The summary stored in a spec row's Summary and Summary Info properties
+fn spec_summary(properties: &Value) -> Option<SpecSummary> {
+    let text = plain_text(&properties["Summary"]["rich_text"]);
+    if text.is_empty() {
+        return None;
+    }
+    let info: Value =
+        serde_json::from_str(&plain_text(&properties["Summary Info"]["rich_text"])).ok()?;
+    Some(SpecSummary {
+        text,
+        generated_at: info["generated_at"].as_str()?.to_string(),
+        model: info["model"].as_str().map(str::to_string),
+        spec_version: info["spec_version"].as_str()?.to_string(),
+    })
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_list_round_trips_through_rows() {
+        let markdown = "# Tasks\n\n## Phase 1\n\nSet up first.\n\n- [ ] Add login @alice ~2d\n- [x] Write schema\n\n## Phase 2\n\n- [ ] Ship it";
+        let rows = task_rows(markdown);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[0],
+            TaskRow {
+                section: "Phase 1".to_string(),
+                text: "Add login @alice ~2d".to_string(),
+                done: false,
+            }
+        );
+
+        // Only headings with items and the items themselves are kept
+        let rendered = render_tasks(&rows);
+        assert_eq!(
+            rendered,
+            "## Phase 1\n\n- [ ] Add login @alice ~2d\n- [x] Write schema\n\n## Phase 2\n\n- [ ] Ship it"
+        );
+        assert_eq!(task_rows(&rendered), rows);
+        assert_eq!(
+            task_row(&json!({"properties": task_properties(&rows[1], 1)})),
+            rows[1]
+        );
+    }
+
+    #[test]
+    fn test_page_id_accepts_ids_and_urls() {
+        let id = "0123456789abcdef0123456789abcdef";
+        assert_eq!(page_id(id).unwrap(), id);
+        assert_eq!(page_id("01234567-89ab-cdef-0123-456789abcdef").unwrap(), id);
+        assert_eq!(
+            page_id(&format!("https://www.notion.so/team/Foundry-{}?pvs=4", id)).unwrap(),
+            id
+        );
+        assert!(page_id("https://www.notion.so/team/Foundry").is_err());
+    }
+}
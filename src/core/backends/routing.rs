@@ -0,0 +1,269 @@
+//! Per-project backend routing
+//!
+//! When a project's `.foundry.toml` picks a different backend than the
+//! default, calls for that project go to its own backend and every other
+//! project stays on the default. `list_projects` merges both, so routed
+//! projects still show up in listings.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::core::backends::{BackendCapabilities, FoundryBackend, SharedBackend};
+use crate::core::lock::WriteLock;
+use crate::types::{
+    project::{Project, ProjectConfig, ProjectFileType, ProjectMetadata},
+    spec::{Spec, SpecConfig, SpecFileType, SpecMetadata, SpecStatus, SpecSummary},
+};
+
+/// Sends each project's calls to the backend configured for it
+pub struct RoutingBackend {
+    default: SharedBackend,
+    routes: HashMap<String, SharedBackend>,
+}
+
+impl RoutingBackend {
+    /// Route the projects in `routes` to their backends and the rest to `default`
+    pub fn new(default: SharedBackend, routes: HashMap<String, SharedBackend>) -> Self {
+        Self { default, routes }
+    }
+
+    fn backend_for(&self, project_name: &str) -> &SharedBackend {
+        self.routes.get(project_name).unwrap_or(&self.default)
+    }
+}
+
+#[async_trait]
+impl FoundryBackend for RoutingBackend {
+    async fn create_project(&self, config: ProjectConfig) -> Result<Project> {
+        self.backend_for(&config.name).create_project(config).await
+    }
+    async fn project_exists(&self, name: &str) -> Result<bool> {
+        self.backend_for(name).project_exists(name).await
+    }
+    async fn list_projects(&self) -> Result<Vec<ProjectMetadata>> {
+        // A routed project's directory in the default store only holds its config
+        let mut projects: Vec<ProjectMetadata> = self
+            .default
+            .list_projects()
+            .await?
+            .into_iter()
+            .filter(|project| !self.routes.contains_key(&project.name))
+            .collect();
+        for (name, backend) in &self.routes {
+            projects.extend(
+                backend
+                    .list_projects()
+                    .await?
+                    .into_iter()
+                    .filter(|project| &project.name == name),
+            );
+        }
+        projects.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(projects)
+    }
+    async fn load_project(&self, name: &str) -> Result<Project> {
+        self.backend_for(name).load_project(name).await
+    }
+    async fn update_decisions(&self, project_name: &str, content: &str) -> Result<()> {
+        self.backend_for(project_name)
+            .update_decisions(project_name, content)
+            .await
+    }
+    async fn update_project_content(
+        &self,
+        project_name: &str,
+        file_type: ProjectFileType,
+        content: &str,
+    ) -> Result<()> {
+        self.backend_for(project_name)
+            .update_project_content(project_name, file_type, content)
+            .await
+    }
+    async fn delete_project(&self, name: &str) -> Result<()> {
+        self.backend_for(name).delete_project(name).await
+    }
+    async fn set_project_tags(&self, project_name: &str, tags: &[String]) -> Result<()> {
+        self.backend_for(project_name)
+            .set_project_tags(project_name, tags)
+            .await
+    }
+
+    async fn create_spec(&self, config: SpecConfig) -> Result<Spec> {
+        self.backend_for(&config.project_name)
+            .create_spec(config)
+            .await
+    }
+    async fn list_specs(&self, project_name: &str) -> Result<Vec<SpecMetadata>> {
+        self.backend_for(project_name)
+            .list_specs(project_name)
+            .await
+    }
+    async fn load_spec_files(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        files: &[SpecFileType],
+    ) -> Result<Spec> {
+        self.backend_for(project_name)
+            .load_spec_files(project_name, spec_name, files)
+            .await
+    }
+    async fn update_spec_content(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        file_type: SpecFileType,
+        content: &str,
+    ) -> Result<()> {
+        self.backend_for(project_name)
+            .update_spec_content(project_name, spec_name, file_type, content)
+            .await
+    }
+    async fn delete_spec(&self, project_name: &str, spec_name: &str) -> Result<()> {
+        self.backend_for(project_name)
+            .delete_spec(project_name, spec_name)
+            .await
+    }
+    async fn rename_spec(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        new_spec_name: &str,
+    ) -> Result<Spec> {
+        self.backend_for(project_name)
+            .rename_spec(project_name, spec_name, new_spec_name)
+            .await
+    }
+    async fn set_spec_status(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        status: SpecStatus,
+    ) -> Result<()> {
+        self.backend_for(project_name)
+            .set_spec_status(project_name, spec_name, status)
+            .await
+    }
+    async fn set_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()> {
+        self.backend_for(project_name)
+            .set_spec_dependencies(project_name, spec_name, depends_on)
+            .await
+    }
+    async fn set_spec_tags(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        tags: &[String],
+    ) -> Result<()> {
+        self.backend_for(project_name)
+            .set_spec_tags(project_name, spec_name, tags)
+            .await
+    }
+    async fn set_spec_summary(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        summary: &SpecSummary,
+    ) -> Result<()> {
+        self.backend_for(project_name)
+            .set_spec_summary(project_name, spec_name, summary)
+            .await
+    }
+
+    async fn lock_spec(&self, project_name: &str, spec_name: &str) -> Result<WriteLock> {
+        self.backend_for(project_name)
+            .lock_spec(project_name, spec_name)
+            .await
+    }
+
+    async fn lock_project(&self, project_name: &str) -> Result<WriteLock> {
+        self.backend_for(project_name)
+            .lock_project(project_name)
+            .await
+    }
+
+    async fn get_latest_spec(&self, project_name: &str) -> Result<Option<SpecMetadata>> {
+        self.backend_for(project_name)
+            .get_latest_spec(project_name)
+            .await
+    }
+    async fn count_specs(&self, project_name: &str) -> Result<usize> {
+        self.backend_for(project_name)
+            .count_specs(project_name)
+            .await
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        self.default.capabilities()
+    }
+    fn project_capabilities(&self, project_name: &str) -> BackendCapabilities {
+        self.backend_for(project_name)
+            .project_capabilities(project_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::backends::memory::InMemoryBackend;
+    use std::sync::Arc;
+
+    fn project(name: &str) -> ProjectConfig {
+        ProjectConfig {
+            name: name.to_string(),
+            vision: "Vision".to_string(),
+            tech_stack: "Rust".to_string(),
+            summary: "Summary".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routed_projects_use_their_own_backend() {
+        let default: SharedBackend = Arc::new(InMemoryBackend::new());
+        let routed: SharedBackend = Arc::new(InMemoryBackend::new());
+        let backend = RoutingBackend::new(
+            Arc::clone(&default),
+            HashMap::from([("team-app".to_string(), Arc::clone(&routed))]),
+        );
+
+        backend.create_project(project("my-app")).await.unwrap();
+        backend.create_project(project("team-app")).await.unwrap();
+
+        assert!(default.project_exists("my-app").await.unwrap());
+        assert!(!default.project_exists("team-app").await.unwrap());
+        assert!(routed.project_exists("team-app").await.unwrap());
+
+        let names: Vec<String> = backend
+            .list_projects()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|project| project.name)
+            .collect();
+        assert_eq!(names, vec!["my-app", "team-app"]);
+    }
+
+    #[test]
+    fn test_project_capabilities_follow_the_route() {
+        let routed: SharedBackend =
+            Arc::new(InMemoryBackend::with_capabilities(BackendCapabilities {
+                atomic_replace: false,
+                ..InMemoryBackend::new().capabilities()
+            }));
+        let backend = RoutingBackend::new(
+            Arc::new(InMemoryBackend::new()),
+            HashMap::from([("team-app".to_string(), routed)]),
+        );
+
+        assert!(backend.capabilities().atomic_replace);
+        assert!(backend.project_capabilities("my-app").atomic_replace);
+        assert!(!backend.project_capabilities("team-app").atomic_replace);
+    }
+}
@@ -42,7 +42,12 @@ mod test_utils {
             let home_dir = self.temp_dir.path().to_string_lossy().to_string();
 
             // Use temp-env for scoped environment variables
-            temp_env::with_var("HOME", Some(&home_dir), || {
+            let vars = [
+                ("HOME", Some(home_dir.as_str())),
+                ("FOUNDRY_HOME", None),
+                ("XDG_DATA_HOME", None),
+            ];
+            temp_env::with_vars(vars, || {
                 // Create a new single-threaded runtime for simplicity and isolation
                 let rt = tokio::runtime::Builder::new_current_thread()
                     .enable_all()
@@ -81,9 +86,10 @@ mod test_utils {
 mod contract_tests {
     use super::test_utils::TestEnvironment;
     use crate::core::backends::{
-        FoundryBackend, filesystem::FilesystemBackend, memory::InMemoryBackend,
+        FoundryBackend, filesystem::FilesystemBackend, git::GitBackend, memory::InMemoryBackend,
     };
-    use crate::types::spec::SpecFileType;
+    use crate::types::project::ProjectFileType;
+    use crate::types::spec::{SpecFileType, SpecStatus};
     use anyhow::Result;
 
     /// Contract test that verifies FoundryBackend trait conformance
@@ -168,6 +174,26 @@ mod contract_tests {
             "Updated spec content for testing purposes"
         );
 
+        // Test task counts and last change in listings
+        backend
+            .update_spec_content(
+                "contract-test",
+                &spec.name,
+                SpecFileType::TaskList,
+                "## Tasks\n- [x] Design\n- [ ] Build\n- [ ] Ship",
+            )
+            .await?;
+        let listed = &backend.list_specs("contract-test").await?[0];
+        assert_eq!(
+            listed.task_counts,
+            crate::types::spec::TaskCounts {
+                total: 3,
+                completed: 1,
+                remaining: 2
+            }
+        );
+        assert!(listed.updated_at.is_some());
+
         // Test helper operations
         let latest_spec = backend.get_latest_spec("contract-test").await?;
         assert!(latest_spec.is_some());
@@ -176,11 +202,97 @@ mod contract_tests {
         let spec_count = backend.count_specs("contract-test").await?;
         assert_eq!(spec_count, 1);
 
+        // Test spec status
+        assert_eq!(specs[0].status, SpecStatus::Draft);
+        backend
+            .set_spec_status("contract-test", &spec.name, SpecStatus::Completed)
+            .await?;
+        let specs_with_status = backend.list_specs("contract-test").await?;
+        assert_eq!(specs_with_status[0].status, SpecStatus::Completed);
+        assert!(
+            backend
+                .set_spec_status(
+                    "contract-test",
+                    "20240101_000000_missing",
+                    SpecStatus::Active
+                )
+                .await
+                .is_err()
+        );
+
+        // Test spec dependencies; the status set above is kept
+        assert!(specs[0].depends_on.is_empty());
+        let depends_on = vec!["20240101_000000_other".to_string()];
+        backend
+            .set_spec_dependencies("contract-test", &spec.name, &depends_on)
+            .await?;
+        let specs_with_dependencies = backend.list_specs("contract-test").await?;
+        assert_eq!(specs_with_dependencies[0].depends_on, depends_on);
+        assert_eq!(specs_with_dependencies[0].status, SpecStatus::Completed);
+
+        // Test spec and project tags; other metadata is kept
+        let tags = vec!["area:backend".to_string(), "quarter:Q3".to_string()];
+        backend
+            .set_spec_tags("contract-test", &spec.name, &tags)
+            .await?;
+        let specs_with_tags = backend.list_specs("contract-test").await?;
+        assert_eq!(specs_with_tags[0].tags, tags);
+        assert_eq!(specs_with_tags[0].depends_on, depends_on);
+        backend
+            .set_project_tags("contract-test", &tags[..1])
+            .await?;
+        assert_eq!(backend.list_projects().await?[0].tags, tags[..1]);
+        assert!(
+            backend
+                .set_spec_tags("contract-test", "20240101_000000_missing", &tags)
+                .await
+                .is_err()
+        );
+
+        // Test spec locks are released when the guard drops
+        let lock = backend.lock_spec("contract-test", &spec.name).await?;
+        drop(lock);
+        drop(backend.lock_spec("contract-test", &spec.name).await?);
+
         // Test delete spec
         backend.delete_spec("contract-test", &spec.name).await?;
         let specs_after_delete = backend.list_specs("contract-test").await?;
         assert_eq!(specs_after_delete.len(), 0);
 
+        // Test update project content
+        backend
+            .update_project_content(
+                "contract-test",
+                ProjectFileType::Vision,
+                "Updated vision content for testing purposes",
+            )
+            .await?;
+        let updated_project = backend.load_project("contract-test").await?;
+        assert_eq!(
+            updated_project.vision.as_deref(),
+            Some("Updated vision content for testing purposes")
+        );
+        assert_eq!(updated_project.summary, project.summary);
+
+        // Test delete project, including its remaining specs
+        backend
+            .create_spec(crate::types::spec::SpecConfig {
+                project_name: "contract-test".to_string(),
+                feature_name: "doomed_feature".to_string(),
+                content: crate::types::spec::SpecContentData::default(),
+            })
+            .await?;
+        backend.delete_project("contract-test").await?;
+        assert!(!backend.project_exists("contract-test").await?);
+        assert!(backend.list_projects().await?.is_empty());
+        assert!(backend.delete_project("contract-test").await.is_err());
+        assert!(
+            backend
+                .update_project_content("contract-test", ProjectFileType::Summary, "Gone")
+                .await
+                .is_err()
+        );
+
         // Test capabilities
         let capabilities = backend.capabilities();
         assert!(capabilities.supports_documents);
@@ -208,6 +320,15 @@ mod contract_tests {
         });
     }
 
+    #[test]
+    fn test_git_backend_contract() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let backend = GitBackend::open(env.temp_dir.path().join("specs-repo")).unwrap();
+            test_backend_contract(backend).await.unwrap();
+        });
+    }
+
     #[test]
     fn test_backend_error_handling() {
         let env = TestEnvironment::new().unwrap();
@@ -348,6 +469,40 @@ mod filesystem_backend_tests {
         });
     }
 
+    #[test]
+    fn test_filesystem_backend_spec_locks_stay_out_of_listings() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let backend = FilesystemBackend::new();
+            backend
+                .create_project(env.create_project_config("lock-test"))
+                .await
+                .unwrap();
+            let spec = backend
+                .create_spec(env.create_spec_config("lock-test", "locked_feature"))
+                .await
+                .unwrap();
+
+            let _lock = backend.lock_spec("lock-test", &spec.name).await.unwrap();
+            assert!(
+                env.foundry_dir()
+                    .join(".locks/lock-test")
+                    .join(format!("{}.lock", spec.name))
+                    .is_file()
+            );
+            let project_lock = backend.lock_project("lock-test").await.unwrap();
+            assert!(env.foundry_dir().join(".locks/lock-test.lock").is_file());
+            assert_eq!(backend.list_projects().await.unwrap().len(), 1);
+            assert_eq!(backend.list_specs("lock-test").await.unwrap().len(), 1);
+
+            // Deleting the project removes its lock files
+            drop((_lock, project_lock));
+            backend.delete_project("lock-test").await.unwrap();
+            assert!(!env.foundry_dir().join(".locks/lock-test").exists());
+            assert!(!env.foundry_dir().join(".locks/lock-test.lock").exists());
+        });
+    }
+
     #[test]
     fn test_filesystem_backend_file_validation() {
         let env = TestEnvironment::new().unwrap();
@@ -370,11 +525,166 @@ mod filesystem_backend_tests {
     }
 }
 
+#[cfg(test)]
+mod git_backend_tests {
+    use super::test_utils::TestEnvironment;
+    use crate::core::backends::{FoundryBackend, git::GitBackend, open_backend};
+    use crate::core::config::BackendConfig;
+    use crate::types::errors::ErrorCode;
+    use crate::types::spec::SpecFileType;
+    use std::process::Command;
+
+    fn git_log(repo_dir: &std::path::Path, format: &str) -> String {
+        let output = Command::new("git")
+            .args(["log", &format!("--format={}", format)])
+            .current_dir(repo_dir)
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[test]
+    fn test_git_backend_commits_each_mutation() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let repo_dir = env.temp_dir.path().join("specs-repo");
+            let backend = GitBackend::open(&repo_dir).unwrap();
+
+            backend
+                .create_project(env.create_project_config("git-test"))
+                .await
+                .unwrap();
+            let spec = backend
+                .create_spec(env.create_spec_config("git-test", "git_feature"))
+                .await
+                .unwrap();
+            backend
+                .update_spec_content("git-test", &spec.name, SpecFileType::Notes, "New notes")
+                .await
+                .unwrap();
+            // Identical content leaves nothing to commit
+            backend
+                .update_spec_content("git-test", &spec.name, SpecFileType::Notes, "New notes")
+                .await
+                .unwrap();
+            backend.delete_spec("git-test", &spec.name).await.unwrap();
+
+            let subjects: Vec<String> =
+                git_log(&repo_dir, "%s").lines().map(String::from).collect();
+            assert_eq!(
+                subjects,
+                vec![
+                    format!("foundry: delete spec git-test/{}", spec.name),
+                    format!("foundry: update spec git-test/{} (notes.md)", spec.name),
+                    format!("foundry: create spec git-test/{}", spec.name),
+                    "foundry: create project git-test".to_string(),
+                    "foundry: initialize repository".to_string(),
+                ]
+            );
+
+            let trailers = git_log(&repo_dir, "%(trailers:only,unfold)");
+            assert!(trailers.contains("Foundry-Action: update_spec"));
+            assert!(trailers.contains("Foundry-Project: git-test"));
+            assert!(trailers.contains(&format!("Foundry-Spec: {}", spec.name)));
+
+            // Reopening keeps the history and reads the stored projects
+            let reopened = GitBackend::open(&repo_dir).unwrap();
+            assert!(reopened.project_exists("git-test").await.unwrap());
+            assert_eq!(git_log(&repo_dir, "%s").lines().count(), 5);
+            assert!(reopened.capabilities().strong_consistency);
+        });
+    }
+
+    #[test]
+    fn test_git_backend_serializes_concurrent_commits() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let repo_dir = env.temp_dir.path().join("specs-repo");
+            let backend = GitBackend::open(&repo_dir).unwrap();
+            backend
+                .create_project(env.create_project_config("git-test"))
+                .await
+                .unwrap();
+            let mut specs = Vec::new();
+            for feature in ["first_feature", "second_feature", "third_feature"] {
+                let spec = backend
+                    .create_spec(env.create_spec_config("git-test", feature))
+                    .await
+                    .unwrap();
+                specs.push(spec.name);
+            }
+
+            std::thread::scope(|scope| {
+                for spec_name in &specs {
+                    let backend = &backend;
+                    scope.spawn(move || {
+                        tokio::runtime::Builder::new_current_thread()
+                            .build()
+                            .unwrap()
+                            .block_on(backend.update_spec_content(
+                                "git-test",
+                                spec_name,
+                                SpecFileType::Notes,
+                                &format!("Notes for {}", spec_name),
+                            ))
+                            .unwrap();
+                    });
+                }
+            });
+
+            // Each write landed in its own commit touching only its spec
+            for spec_name in &specs {
+                let output = Command::new("git")
+                    .args(["log", "--format=", "--name-only", "-1", "--grep"])
+                    .arg(format!("update spec git-test/{} ", spec_name))
+                    .current_dir(&repo_dir)
+                    .output()
+                    .unwrap();
+                let files = String::from_utf8(output.stdout).unwrap();
+                let files: Vec<&str> = files.lines().filter(|l| !l.is_empty()).collect();
+                assert!(!files.is_empty());
+                assert!(
+                    files.iter().all(|file| file.contains(spec_name.as_str())),
+                    "{}: {:?}",
+                    spec_name,
+                    files
+                );
+            }
+            assert!(!repo_dir.join(".git").join("index.lock").exists());
+        });
+    }
+
+    #[test]
+    fn test_open_backend_rejects_unknown_kind() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let config = |kind: &str| BackendConfig {
+                kind: Some(kind.to_string()),
+                ..BackendConfig::default()
+            };
+            let error = open_backend(&config("bogus")).err().unwrap();
+            assert!(error.to_string().contains("bogus"));
+            assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+
+            let error = open_backend(&config("notion")).err().unwrap();
+            assert!(error.to_string().contains("--notion-page"));
+        });
+    }
+}
+
 #[cfg(test)]
 mod facade_integration_tests {
     use super::test_utils::TestEnvironment;
     use crate::core::backends::memory::InMemoryBackend;
+    use crate::core::backends::{
+        BackendCapabilities, Capability, FoundryBackend, SpecContentStore,
+    };
     use crate::core::foundry::Foundry;
+    use crate::types::edit_commands::{
+        EditCommand, EditCommandName, EditCommandTarget, EditSelector,
+    };
+    use crate::types::errors::ErrorCode;
+    use crate::types::project::ProjectFileType;
     use crate::types::spec::SpecFileType;
 
     #[test]
@@ -434,7 +744,6 @@ mod facade_integration_tests {
             let spec = foundry.create_spec(spec_config).await.unwrap();
 
             // Test SpecContentStore implementation
-            use crate::core::backends::SpecContentStore;
             let original_content = foundry
                 .read_spec_file("store-test", &spec.name, SpecFileType::Spec)
                 .await
@@ -471,6 +780,103 @@ mod facade_integration_tests {
             assert!(!is_not_modified);
         });
     }
+
+    fn degraded_backend() -> InMemoryBackend {
+        InMemoryBackend::with_capabilities(BackendCapabilities {
+            supports_documents: false,
+            supports_subtasks: false,
+            url_deeplinks: false,
+            atomic_replace: false,
+            strong_consistency: false,
+        })
+    }
+
+    #[test]
+    fn test_facade_inlines_documents_without_document_support() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let backend = degraded_backend();
+            let foundry = Foundry::new(backend.clone());
+            let config = env.create_project_config("inline-test");
+            foundry.create_project(config.clone()).await.unwrap();
+
+            // The backend only holds the summary
+            let stored = backend.load_project("inline-test").await.unwrap();
+            assert_eq!(stored.vision.as_deref(), Some(""));
+            assert!(stored.summary.unwrap().contains(&config.vision));
+
+            let project = foundry.load_project("inline-test").await.unwrap();
+            assert_eq!(project.vision, Some(config.vision.clone()));
+            assert_eq!(project.tech_stack, Some(config.tech_stack.clone()));
+            assert_eq!(project.summary, Some(config.summary.clone()));
+
+            foundry
+                .update_project_content("inline-test", ProjectFileType::Vision, "A new vision")
+                .await
+                .unwrap();
+            let project = foundry.load_project("inline-test").await.unwrap();
+            assert_eq!(project.vision.as_deref(), Some("A new vision"));
+            assert_eq!(project.tech_stack, Some(config.tech_stack));
+            assert_eq!(project.summary, Some(config.summary));
+        });
+    }
+
+    #[test]
+    fn test_facade_degrades_edits_without_subtasks_or_atomic_replace() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let foundry = Foundry::new(degraded_backend());
+            foundry
+                .create_project(env.create_project_config("degraded-test"))
+                .await
+                .unwrap();
+            let mut spec_config = env.create_spec_config("degraded-test", "nested");
+            spec_config.content.tasks =
+                "## Tasks\n- [ ] Parent task\n  - [ ] Child task\n  Plain note".to_string();
+            let spec = foundry.create_spec(spec_config).await.unwrap();
+            let tasks = foundry
+                .load_spec("degraded-test", &spec.name)
+                .await
+                .unwrap()
+                .content
+                .tasks;
+            assert_eq!(
+                tasks,
+                "## Tasks\n- [ ] Parent task\n- [ ] Child task\n  Plain note"
+            );
+
+            // replace_section_content goes through the read-modify-verify flow
+            let commands = vec![EditCommand {
+                target: EditCommandTarget::Spec,
+                command: EditCommandName::ReplaceSectionContent,
+                selector: EditSelector::Section {
+                    value: "## Requirements".to_string(),
+                },
+                status: None,
+                content: Some("Replaced requirements".to_string()),
+            }];
+            let result = foundry
+                .apply_edit_commands("degraded-test", &spec.name, &commands)
+                .await
+                .unwrap();
+            assert_eq!(result.applied_count, 1);
+            let spec_content = foundry
+                .read_spec_file("degraded-test", &spec.name, SpecFileType::Spec)
+                .await
+                .unwrap();
+            assert!(spec_content.contains("## Requirements\nReplaced requirements"));
+
+            let error = foundry
+                .require(
+                    "degraded-test",
+                    Capability::UrlDeeplinks,
+                    "Sharing a spec link",
+                )
+                .unwrap_err();
+            assert_eq!(ErrorCode::of(&error), ErrorCode::CapabilityUnsupported);
+            assert!(error.to_string().contains("url_deeplinks"));
+        });
+    }
 }
 
 #[cfg(test)]
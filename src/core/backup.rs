@@ -0,0 +1,499 @@
+//! Spec backups taken before destructive operations
+//!
+//! `delete_spec` and edits that discard content (`replace_section_content`,
+//! `remove_section`) first snapshot the spec's files and metadata into
+//! `~/.foundry/.backups`:
+//!
+//! ```text
+//! .backups/<project>/<spec>/<YYYYMMDD_HHMMSS_micros>-<reason>.json
+//! ```
+//!
+//! A backup's id is its path below `.backups` without the extension. Only the
+//! newest [`DEFAULT_KEEP`] backups of each spec are kept. Snapshots are read
+//! and restored through `Foundry`, so they work with any `FoundryBackend`;
+//! restoring a deleted spec creates it anew under a fresh timestamped name.
+
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::core::backends::FoundryBackend;
+use crate::core::foundry::Foundry;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::BackupInfo;
+use crate::types::spec::{SpecConfig, SpecContentData, SpecFileType, SpecStatus};
+use crate::utils::paths;
+
+/// Version of the snapshot format written by this build
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Backups kept per spec; older ones are removed after each snapshot
+pub const DEFAULT_KEEP: usize = 20;
+
+/// Directory below `~/.foundry` holding backups
+pub const BACKUP_DIR_NAME: &str = ".backups";
+
+const SNAPSHOT_EXTENSION: &str = "json";
+
+/// A spec's files and metadata at one point in time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecSnapshot {
+    pub format_version: u32,
+    pub project_name: String,
+    pub spec_name: String,
+    pub feature_name: String,
+    pub created_at: String,
+    #[serde(default)]
+    pub status: SpecStatus,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Operation the snapshot was taken before, e.g. "delete_spec"
+    pub reason: String,
+    /// When the snapshot was taken (RFC 3339)
+    pub taken_at: String,
+    pub content: SpecContentData,
+}
+
+/// Outcome of restoring a backup
+#[derive(Debug, Clone)]
+pub struct RestoredBackup {
+    /// Spec holding the restored content
+    pub spec_name: String,
+    /// Whether the spec no longer existed and was created anew
+    pub recreated: bool,
+    /// Backup of the content the restore overwrote
+    pub replaced_backup: Option<BackupInfo>,
+}
+
+tokio::task_local! {
+    static BACKUP_DIR_OVERRIDE: Option<PathBuf>;
+}
+
+/// Run `future` with backups written to `dir`, or not at all for `None`
+///
+/// `foundry replay` turns backups off so replayed deletes never touch
+/// `~/.foundry`.
+pub async fn with_backup_dir<F: Future>(dir: Option<PathBuf>, future: F) -> F::Output {
+    BACKUP_DIR_OVERRIDE.scope(dir, future).await
+}
+
+/// The default backup directory, `~/.foundry/.backups`
+pub fn default_dir() -> Result<PathBuf> {
+    Ok(crate::core::filesystem::foundry_dir()?.join(BACKUP_DIR_NAME))
+}
+
+/// Directory snapshots go to in the current context; `None` when turned off
+pub fn current_dir() -> Result<Option<PathBuf>> {
+    BACKUP_DIR_OVERRIDE
+        .try_with(Clone::clone)
+        .map_or_else(|_| default_dir().map(Some), Ok)
+}
+
+/// Snapshot a spec before `reason` changes it, unless backups are turned off
+pub async fn snapshot_before<B: FoundryBackend>(
+    foundry: &Foundry<B>,
+    project_name: &str,
+    spec_name: &str,
+    reason: &str,
+) -> Result<Option<BackupInfo>> {
+    let Some(dir) = current_dir()? else {
+        return Ok(None);
+    };
+    snapshot_spec(foundry, &dir, project_name, spec_name, reason)
+        .await
+        .map(Some)
+        .with_context(|| format!("Failed to back up spec '{}' before {}", spec_name, reason))
+}
+
+/// Write a snapshot of a spec to `dir` and prune that spec's old backups
+pub async fn snapshot_spec<B: FoundryBackend>(
+    foundry: &Foundry<B>,
+    dir: &Path,
+    project_name: &str,
+    spec_name: &str,
+    reason: &str,
+) -> Result<BackupInfo> {
+    let spec = foundry.load_spec(project_name, spec_name).await?;
+    let metadata = foundry
+        .list_specs(project_name)
+        .await?
+        .into_iter()
+        .find(|entry| entry.name == spec_name);
+
+    let now = chrono::Utc::now();
+    let snapshot = SpecSnapshot {
+        format_version: FORMAT_VERSION,
+        project_name: project_name.to_string(),
+        spec_name: spec_name.to_string(),
+        feature_name: metadata
+            .as_ref()
+            .map_or_else(|| feature_name_of(spec_name), |m| m.feature_name.clone()),
+        created_at: spec.created_at,
+        status: metadata.as_ref().map(|m| m.status).unwrap_or_default(),
+        depends_on: metadata
+            .as_ref()
+            .map(|m| m.depends_on.clone())
+            .unwrap_or_default(),
+        tags: metadata.map(|m| m.tags).unwrap_or_default(),
+        reason: reason.to_string(),
+        taken_at: now.to_rfc3339(),
+        content: spec.content,
+    };
+
+    let id = format!(
+        "{}/{}/{}-{}",
+        project_name,
+        spec_name,
+        now.format("%Y%m%d_%H%M%S_%6f"),
+        reason
+    );
+    let path = snapshot_path(dir, &id)?;
+    crate::core::filesystem::write_content_atomic(&path, &serde_json::to_string_pretty(&snapshot)?)
+        .with_context(|| format!("Failed to write backup {:?}", path))?;
+
+    prune_spec(&dir.join(project_name).join(spec_name), DEFAULT_KEEP)?;
+    Ok(info(id, &snapshot))
+}
+
+/// Backups in `dir`, newest first, optionally only for one project or spec
+pub fn list(
+    dir: &Path,
+    project_name: Option<&str>,
+    spec_name: Option<&str>,
+) -> Result<Vec<BackupInfo>> {
+    let mut backups = Vec::new();
+    for project_dir in subdirs(dir)? {
+        let project = file_name(&project_dir);
+        if project_name.is_some_and(|name| name != project) {
+            continue;
+        }
+        for spec_dir in subdirs(&project_dir)? {
+            let spec = file_name(&spec_dir);
+            if spec_name.is_some_and(|name| name != spec) {
+                continue;
+            }
+            for path in snapshot_files(&spec_dir)? {
+                let id = format!("{}/{}/{}", project, spec, stem(&path));
+                match read_snapshot(&path) {
+                    Ok(snapshot) => backups.push(info(id, &snapshot)),
+                    Err(e) => tracing::warn!("Skipping unreadable backup {:?}: {:#}", path, e),
+                }
+            }
+        }
+    }
+    backups.sort_by(|a, b| b.taken_at.cmp(&a.taken_at).then(b.id.cmp(&a.id)));
+    Ok(backups)
+}
+
+/// Read the backup with `id` from `dir`
+pub fn read(dir: &Path, id: &str) -> Result<SpecSnapshot> {
+    let path = snapshot_path(dir, id)?;
+    if !path.is_file() {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Backup '{}' not found. Run 'foundry backup list' to see available backups",
+            id
+        )));
+    }
+    read_snapshot(&path)
+}
+
+/// Put a snapshot's content, status, dependencies and tags back
+///
+/// An existing spec is backed up to `dir` and overwritten in place; a deleted
+/// one is created anew. Dependencies on specs that no longer exist are dropped.
+pub async fn restore<B: FoundryBackend>(
+    foundry: &Foundry<B>,
+    dir: &Path,
+    snapshot: &SpecSnapshot,
+) -> Result<RestoredBackup> {
+    let project_name = &snapshot.project_name;
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found; create it before restoring its specs",
+            project_name
+        )));
+    }
+
+    let existing = foundry.list_specs(project_name).await?;
+    let exists = existing.iter().any(|spec| spec.name == snapshot.spec_name);
+
+    let (spec_name, replaced_backup) = if exists {
+        let replaced =
+            snapshot_spec(foundry, dir, project_name, &snapshot.spec_name, "restore").await?;
+        for (file_type, content) in [
+            (SpecFileType::Spec, &snapshot.content.spec),
+            (SpecFileType::Notes, &snapshot.content.notes),
+            (SpecFileType::TaskList, &snapshot.content.tasks),
+        ] {
+            foundry
+                .update_spec_content(project_name, &snapshot.spec_name, file_type, content)
+                .await?;
+        }
+        (snapshot.spec_name.clone(), Some(replaced))
+    } else {
+        let spec = foundry
+            .create_spec(SpecConfig {
+                project_name: project_name.clone(),
+                feature_name: snapshot.feature_name.clone(),
+                content: snapshot.content.clone(),
+            })
+            .await
+            .with_context(|| format!("Failed to recreate spec '{}'", snapshot.spec_name))?;
+        (spec.name, None)
+    };
+
+    foundry
+        .set_spec_status(project_name, &spec_name, snapshot.status)
+        .await?;
+    let depends_on: Vec<String> = snapshot
+        .depends_on
+        .iter()
+        .filter(|name| existing.iter().any(|spec| &spec.name == *name))
+        .cloned()
+        .collect();
+    foundry
+        .set_spec_dependencies(project_name, &spec_name, &depends_on)
+        .await?;
+    foundry
+        .set_spec_tags(project_name, &spec_name, &snapshot.tags)
+        .await?;
+
+    Ok(RestoredBackup {
+        spec_name,
+        recreated: !exists,
+        replaced_backup,
+    })
+}
+
+/// Keep only the newest `keep` backups of every spec in `dir`; returns how many were removed
+pub fn prune(dir: &Path, keep: usize) -> Result<usize> {
+    let mut removed = 0;
+    for project_dir in subdirs(dir)? {
+        for spec_dir in subdirs(&project_dir)? {
+            removed += prune_spec(&spec_dir, keep)?;
+        }
+    }
+    Ok(removed)
+}
+
+fn prune_spec(spec_dir: &Path, keep: usize) -> Result<usize> {
+    // File names start with the timestamp, so name order is age order
+    let mut files = snapshot_files(spec_dir)?;
+    files.sort();
+    let excess = files.len().saturating_sub(keep);
+    for path in &files[..excess] {
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to remove old backup {:?}", path))?;
+    }
+    Ok(excess)
+}
+
+fn snapshot_path(dir: &Path, id: &str) -> Result<PathBuf> {
+    let parts: Vec<&str> = id.split('/').collect();
+    let valid = parts.len() == 3 && parts.iter().all(|part| paths::is_safe_component(part));
+    if !valid {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Invalid backup id '{}'; expected <project>/<spec>/<timestamp>-<reason>",
+            id
+        )));
+    }
+    Ok(dir.join(format!("{}.{}", id, SNAPSHOT_EXTENSION)))
+}
+
+fn read_snapshot(path: &Path) -> Result<SpecSnapshot> {
+    let content = crate::core::filesystem::read_file(path)
+        .with_context(|| format!("Failed to read backup {:?}", path))?;
+    serde_json::from_str(&content).with_context(|| format!("Invalid backup {:?}", path))
+}
+
+fn info(id: String, snapshot: &SpecSnapshot) -> BackupInfo {
+    BackupInfo {
+        id,
+        project_name: snapshot.project_name.clone(),
+        spec_name: snapshot.spec_name.clone(),
+        reason: snapshot.reason.clone(),
+        taken_at: snapshot.taken_at.clone(),
+    }
+}
+
+/// Feature name from a `YYYYMMDD_HHMMSS_feature` spec name
+fn feature_name_of(spec_name: &str) -> String {
+    spec_name
+        .splitn(3, '_')
+        .nth(2)
+        .unwrap_or(spec_name)
+        .to_string()
+}
+
+fn subdirs(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut dirs = Vec::new();
+    for entry in std::fs::read_dir(dir).with_context(|| format!("Failed to read {:?}", dir))? {
+        let path = entry?.path();
+        if path.is_dir() && !file_name(&path).starts_with('.') {
+            dirs.push(path);
+        }
+    }
+    Ok(dirs)
+}
+
+fn snapshot_files(spec_dir: &Path) -> Result<Vec<PathBuf>> {
+    if !spec_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    for entry in
+        std::fs::read_dir(spec_dir).with_context(|| format!("Failed to read {:?}", spec_dir))?
+    {
+        let path = entry?.path();
+        if path.is_file()
+            && path
+                .extension()
+                .is_some_and(|ext| ext == SNAPSHOT_EXTENSION)
+        {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::backends::memory::InMemoryBackend;
+    use crate::test_environment::TestEnvironment;
+    use crate::types::project::ProjectConfig;
+
+    async fn foundry_with_spec() -> (Foundry<InMemoryBackend>, String) {
+        let foundry = Foundry::new(InMemoryBackend::new());
+        foundry
+            .create_project(ProjectConfig {
+                name: "demo".to_string(),
+                vision: "Vision".to_string(),
+                tech_stack: "Rust".to_string(),
+                summary: "Summary".to_string(),
+            })
+            .await
+            .unwrap();
+        let spec = foundry
+            .create_spec(SpecConfig {
+                project_name: "demo".to_string(),
+                feature_name: "auth".to_string(),
+                content: SpecContentData {
+                    spec: "# Auth".to_string(),
+                    notes: "Notes".to_string(),
+                    tasks: "- [ ] Login".to_string(),
+                },
+            })
+            .await
+            .unwrap();
+        foundry
+            .set_spec_status("demo", &spec.name, SpecStatus::Active)
+            .await
+            .unwrap();
+        (foundry, spec.name)
+    }
+
+    #[test]
+    fn test_restore_recreates_deleted_spec() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let dir = env.temp_dir.path().join("backups");
+            let (foundry, spec_name) = foundry_with_spec().await;
+
+            let backup = snapshot_spec(&foundry, &dir, "demo", &spec_name, "delete_spec")
+                .await
+                .unwrap();
+            foundry.delete_spec("demo", &spec_name).await.unwrap();
+
+            let listed = list(&dir, Some("demo"), None).unwrap();
+            assert_eq!(listed.len(), 1);
+            assert_eq!(listed[0].id, backup.id);
+            assert_eq!(listed[0].reason, "delete_spec");
+
+            let snapshot = read(&dir, &backup.id).unwrap();
+            let restored = restore(&foundry, &dir, &snapshot).await.unwrap();
+            assert!(restored.recreated);
+            assert!(restored.spec_name.ends_with("_auth"));
+
+            let spec = foundry
+                .load_spec("demo", &restored.spec_name)
+                .await
+                .unwrap();
+            assert_eq!(spec.content.tasks, "- [ ] Login");
+            let metadata = foundry.list_specs("demo").await.unwrap();
+            assert_eq!(metadata[0].status, SpecStatus::Active);
+        });
+    }
+
+    #[test]
+    fn test_restore_overwrites_existing_spec_and_backs_it_up() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let dir = env.temp_dir.path().join("backups");
+            let (foundry, spec_name) = foundry_with_spec().await;
+
+            let backup = snapshot_spec(&foundry, &dir, "demo", &spec_name, "update_spec")
+                .await
+                .unwrap();
+            foundry
+                .update_spec_content("demo", &spec_name, SpecFileType::Spec, "# Replaced")
+                .await
+                .unwrap();
+
+            let snapshot = read(&dir, &backup.id).unwrap();
+            let restored = restore(&foundry, &dir, &snapshot).await.unwrap();
+            assert!(!restored.recreated);
+            assert_eq!(restored.spec_name, spec_name);
+            assert_eq!(restored.replaced_backup.unwrap().reason, "restore");
+
+            let spec = foundry.load_spec("demo", &spec_name).await.unwrap();
+            assert_eq!(spec.content.spec, "# Auth");
+        });
+    }
+
+    #[test]
+    fn test_prune_keeps_newest_backups() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let dir = env.temp_dir.path().join("backups");
+            let (foundry, spec_name) = foundry_with_spec().await;
+            for _ in 0..3 {
+                snapshot_spec(&foundry, &dir, "demo", &spec_name, "update_spec")
+                    .await
+                    .unwrap();
+            }
+            let newest = list(&dir, None, Some(&spec_name)).unwrap()[0].clone();
+
+            assert_eq!(prune(&dir, 1).unwrap(), 2);
+            assert_eq!(list(&dir, None, None).unwrap(), vec![newest]);
+        });
+    }
+
+    #[test]
+    fn test_backup_ids_cannot_escape_backup_dir() {
+        let dir = Path::new("/tmp/backups");
+        assert!(snapshot_path(dir, "demo/spec/20250101_120000_000000-delete_spec").is_ok());
+        assert!(snapshot_path(dir, "../demo/20250101").is_err());
+        assert!(snapshot_path(dir, "demo/20250101").is_err());
+        assert!(snapshot_path(dir, "demo/spec/").is_err());
+    }
+}
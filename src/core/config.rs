@@ -0,0 +1,450 @@
+//! Global and per-project configuration
+//!
+//! `~/.foundry/config.toml` sets defaults for every project, and a project's
+//! `~/.foundry/<project>/.foundry.toml` overrides them field by field:
+//!
+//! ```toml
+//! [backend]
+//! kind = "git"
+//! git_dir = "~/work/specs"
+//! git_remote = "git@github.com:team/specs.git"
+//! # or: kind = "notion" with notion_page = "<root page URL>"
+//!
+//! [validation]
+//! profile = "strict"
+//!
+//! [validation.min_length]
+//! vision = 100
+//! spec = 50
+//!
+//! [validation.rules.spec]
+//! required_sections = ["Requirements", "Acceptance Criteria"]
+//! banned_phrases = ["lorem ipsum"]
+//! max_length = 20000
+//!
+//! [templates]
+//! spec = "rfc"
+//!
+//! [templates.guidance]
+//! conventions = "- Link the ticket in every spec"
+//!
+//! [spec_names]
+//! strategy = "prefix"          # timestamp (default), date, sequential, ulid or prefix
+//! prefix = "RFC"
+//!
+//! [stale]
+//! days = 30                    # idle days before a spec with open tasks is stale (default 14)
+//!
+//! [linear]
+//! team = "ENG"
+//!
+//! [encryption]
+//! key_file = "~/.config/foundry/key"
+//! ```
+//!
+//! Project files live under `~/.foundry` whatever backend stores the project,
+//! so a project can pick a backend of its own. Command-line flags take
+//! precedence over the global file; project files take precedence over both
+//! for their project. `[encryption]` is only read from the global file.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::core::backends::BACKEND_KINDS;
+use crate::core::spec_name::{self, SpecNameStrategy};
+use crate::core::validation::{
+    self, ContentRules, ContentType, ValidationProfile, ValidationResult,
+};
+use crate::types::errors::ErrorCode;
+use crate::utils::paths;
+
+/// Global config file in `~/.foundry`
+pub const GLOBAL_CONFIG_FILE: &str = "config.toml";
+
+/// Per-project config file in the project directory
+pub const PROJECT_CONFIG_FILE: &str = ".foundry.toml";
+
+/// Settings read from `config.toml` or `.foundry.toml`; unset fields fall back
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FoundryConfig {
+    pub backend: BackendConfig,
+    pub validation: ValidationConfig,
+    pub templates: TemplatesConfig,
+    pub spec_names: SpecNamesConfig,
+    pub stale: StaleConfig,
+    pub linear: LinearConfig,
+    pub encryption: EncryptionConfig,
+}
+
+/// Storage backend, as with `--backend`, `--git-dir`, `--git-remote` and `--notion-page`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BackendConfig {
+    /// filesystem (default), git or notion
+    pub kind: Option<String>,
+    pub git_dir: Option<PathBuf>,
+    pub git_remote: Option<String>,
+    /// Root page id or URL for the notion backend
+    pub notion_page: Option<String>,
+}
+
+/// Content validation settings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ValidationConfig {
+    /// strict, standard (default) or lenient
+    pub profile: Option<ValidationProfile>,
+    /// Minimum characters per content type (vision, tech_stack, summary, spec, notes, tasks),
+    /// overriding the profile
+    pub min_length: BTreeMap<String, usize>,
+    /// Custom rules per content type
+    pub rules: BTreeMap<String, ContentRules>,
+}
+
+/// Templates used when a call names none, and guidance for installed templates
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TemplatesConfig {
+    /// Spec template for `create_spec` calls without `template`
+    pub spec: Option<String>,
+    /// Partials for installed guidance by name, e.g. `conventions`; files in
+    /// `.templates/partials` take precedence
+    pub guidance: BTreeMap<String, String>,
+}
+
+/// How new spec names are made (see [`crate::core::spec_name`])
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpecNamesConfig {
+    /// timestamp (default), date, sequential, ulid or prefix
+    pub strategy: Option<SpecNameStrategy>,
+    /// Id prefix for the prefix strategy, e.g. "RFC" for `RFC-0001_feature`
+    pub prefix: Option<String>,
+}
+
+/// When specs count as stale (see [`crate::core::ops::needs_attention`])
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StaleConfig {
+    /// Days without activity after which a spec with open tasks is stale
+    pub days: Option<u32>,
+}
+
+/// Linear settings, kept for a Linear backend (see `docs/backends.md`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LinearConfig {
+    /// Linear team key projects map to, e.g. "ENG"
+    pub team: Option<String>,
+}
+
+/// Encryption at rest (see [`crate::core::encryption`]); global only
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EncryptionConfig {
+    /// File holding a 32-byte key in hex
+    pub key_file: Option<PathBuf>,
+    /// Environment variable holding a passphrase to derive the key from
+    pub passphrase_env: Option<String>,
+}
+
+impl FoundryConfig {
+    /// `self` with every field set in `overrides` replaced
+    pub fn merged(self, overrides: FoundryConfig) -> FoundryConfig {
+        let mut min_length = self.validation.min_length;
+        min_length.extend(overrides.validation.min_length);
+        let mut rules = self.validation.rules;
+        rules.extend(overrides.validation.rules);
+        let mut guidance = self.templates.guidance;
+        guidance.extend(overrides.templates.guidance);
+        FoundryConfig {
+            backend: self.backend.merged(overrides.backend),
+            validation: ValidationConfig {
+                profile: overrides.validation.profile.or(self.validation.profile),
+                min_length,
+                rules,
+            },
+            templates: TemplatesConfig {
+                spec: overrides.templates.spec.or(self.templates.spec),
+                guidance,
+            },
+            spec_names: SpecNamesConfig {
+                strategy: overrides.spec_names.strategy.or(self.spec_names.strategy),
+                prefix: overrides.spec_names.prefix.or(self.spec_names.prefix),
+            },
+            stale: StaleConfig {
+                days: overrides.stale.days.or(self.stale.days),
+            },
+            linear: LinearConfig {
+                team: overrides.linear.team.or(self.linear.team),
+            },
+            // Keys must not differ between projects sharing a foundry directory
+            encryption: self.encryption,
+        }
+    }
+
+    /// Validation profile, `standard` when unset
+    pub fn profile(&self) -> ValidationProfile {
+        self.validation.profile.unwrap_or_default()
+    }
+
+    /// Minimum length for `content_type`, configured or from the profile
+    pub fn min_length(&self, content_type: ContentType) -> usize {
+        self.validation
+            .min_length
+            .get(content_type.key())
+            .copied()
+            .unwrap_or_else(|| self.profile().min_length(content_type))
+    }
+
+    /// Validate `content` with the profile and the configured rules
+    pub fn validate(&self, content_type: ContentType, content: &str) -> ValidationResult {
+        let mut result = validation::validate_content_with_min_length(
+            content_type,
+            content,
+            self.min_length(content_type),
+        );
+        result.add_failures(self.profile().rules().check(content));
+        if let Some(rules) = self.validation.rules.get(content_type.key()) {
+            result.add_failures(rules.check(content));
+        }
+        result
+    }
+
+    fn check(&self) -> Result<()> {
+        if let Some(kind) = &self.backend.kind
+            && !BACKEND_KINDS.contains(&kind.as_str())
+        {
+            anyhow::bail!(
+                "Unknown backend '{}'. Supported backends: {}",
+                kind,
+                BACKEND_KINDS.join(", ")
+            );
+        }
+        if let Some(prefix) = &self.spec_names.prefix
+            && !spec_name::valid_prefix(prefix)
+        {
+            anyhow::bail!(
+                "Invalid spec_names.prefix '{}': use a letter followed by letters or digits",
+                prefix
+            );
+        }
+        if self.stale.days == Some(0) {
+            anyhow::bail!("stale.days must be at least 1");
+        }
+        if self.encryption.key_file.is_some() && self.encryption.passphrase_env.is_some() {
+            anyhow::bail!("Set either encryption.key_file or encryption.passphrase_env, not both");
+        }
+        let keys = self
+            .validation
+            .min_length
+            .keys()
+            .map(|key| (key, "validation.min_length"))
+            .chain(
+                self.validation
+                    .rules
+                    .keys()
+                    .map(|key| (key, "validation.rules")),
+            );
+        for (key, table) in keys {
+            if !ContentType::ALL.iter().any(|t| t.key() == key) {
+                anyhow::bail!(
+                    "Unknown content type '{}' in {}. Expected one of: {}",
+                    key,
+                    table,
+                    ContentType::ALL
+                        .iter()
+                        .map(|t| t.key())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BackendConfig {
+    /// `self` with every field set in `overrides` replaced
+    pub fn merged(self, overrides: BackendConfig) -> BackendConfig {
+        BackendConfig {
+            kind: overrides.kind.or(self.kind),
+            git_dir: overrides.git_dir.or(self.git_dir),
+            git_remote: overrides.git_remote.or(self.git_remote),
+            notion_page: overrides.notion_page.or(self.notion_page),
+        }
+    }
+
+    /// Backend kind, `filesystem` when unset
+    pub fn kind(&self) -> &str {
+        self.kind.as_deref().unwrap_or("filesystem")
+    }
+
+    /// `self` with the kind filled in and settings the kind ignores dropped,
+    /// so equal backends compare equal
+    pub fn normalized(self) -> BackendConfig {
+        let kind = self.kind().to_string();
+        match kind.as_str() {
+            "git" => BackendConfig {
+                kind: Some(kind),
+                notion_page: None,
+                ..self
+            },
+            "notion" => BackendConfig {
+                kind: Some(kind),
+                git_dir: None,
+                git_remote: None,
+                ..self
+            },
+            _ => BackendConfig {
+                kind: Some(kind),
+                git_dir: None,
+                git_remote: None,
+                notion_page: None,
+            },
+        }
+    }
+}
+
+/// Read a config file; a missing file is an empty config
+pub fn read(path: &Path) -> Result<FoundryConfig> {
+    if !path.exists() {
+        return Ok(FoundryConfig::default());
+    }
+    let content =
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))?;
+    let mut config: FoundryConfig = toml::from_str(&content).map_err(|e| {
+        ErrorCode::ValidationFailed.error(format!("Invalid config {:?}: {}", path, e))
+    })?;
+    config.check().map_err(|e| {
+        ErrorCode::ValidationFailed.error(format!("Invalid config {:?}: {}", path, e))
+    })?;
+    config.backend.git_dir = config.backend.git_dir.map(|dir| paths::expand_home(&dir));
+    config.encryption.key_file = config
+        .encryption
+        .key_file
+        .map(|file| paths::expand_home(&file));
+    Ok(config)
+}
+
+/// `~/.foundry/config.toml`
+pub fn global_config_path() -> Result<PathBuf> {
+    Ok(crate::core::filesystem::foundry_dir()?.join(GLOBAL_CONFIG_FILE))
+}
+
+/// `~/.foundry/<project>/.foundry.toml`
+pub fn project_config_path(project_name: &str) -> Result<PathBuf> {
+    if !paths::is_safe_component(project_name) {
+        return Err(
+            ErrorCode::InvalidParams.error(format!("Invalid project name '{}'", project_name))
+        );
+    }
+    Ok(crate::core::filesystem::foundry_dir()?
+        .join(project_name)
+        .join(PROJECT_CONFIG_FILE))
+}
+
+/// The global config
+pub fn global() -> Result<FoundryConfig> {
+    read(&global_config_path()?)
+}
+
+/// The global config with `project_name`'s overrides applied
+pub fn for_project(project_name: &str) -> Result<FoundryConfig> {
+    Ok(global()?.merged(read(&project_config_path(project_name)?)?))
+}
+
+/// Every project with a `.foundry.toml`, with the overrides it holds
+pub fn project_overrides() -> Result<Vec<(String, FoundryConfig)>> {
+    let dir = crate::core::filesystem::foundry_dir()?;
+    let mut overrides = Vec::new();
+    for entry in std::fs::read_dir(&dir).with_context(|| format!("Failed to read {:?}", dir))? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let path = entry.path().join(PROJECT_CONFIG_FILE);
+        if !name.starts_with('.') && path.is_file() {
+            overrides.push((name, read(&path)?));
+        }
+    }
+    overrides.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(overrides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Result<FoundryConfig> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(GLOBAL_CONFIG_FILE);
+        std::fs::write(&path, content).unwrap();
+        read(&path)
+    }
+
+    #[test]
+    fn test_project_config_overrides_global_fields() {
+        let global = parse(
+            "[backend]\nkind = \"git\"\ngit_remote = \"git@example.com:specs.git\"\n\n[validation.min_length]\nvision = 100\nspec = 40\n\n[templates]\nspec = \"rfc\"\n",
+        )
+        .unwrap();
+        let project = parse(
+            "[backend]\nkind = \"filesystem\"\n\n[validation.min_length]\nspec = 10\n\n[stale]\ndays = 30\n\n[linear]\nteam = \"ENG\"\n",
+        )
+        .unwrap();
+
+        let config = global.merged(project);
+        assert_eq!(config.backend.kind(), "filesystem");
+        assert_eq!(
+            config.backend.git_remote.as_deref(),
+            Some("git@example.com:specs.git")
+        );
+        assert_eq!(config.min_length(ContentType::Vision), 100);
+        assert_eq!(config.min_length(ContentType::Spec), 10);
+        assert_eq!(config.min_length(ContentType::Notes), 50);
+        assert_eq!(config.templates.spec.as_deref(), Some("rfc"));
+        assert_eq!(config.stale.days, Some(30));
+        assert_eq!(config.linear.team.as_deref(), Some("ENG"));
+
+        // Encryption keys come from the global file only
+        let config = parse("[encryption]\npassphrase_env = \"PASS\"\n")
+            .unwrap()
+            .merged(parse("[encryption]\nkey_file = \"/k\"\n").unwrap());
+        assert_eq!(config.encryption.passphrase_env.as_deref(), Some("PASS"));
+        assert_eq!(config.encryption.key_file, None);
+    }
+
+    #[test]
+    fn test_profile_and_rules_apply_to_validation() {
+        let config = parse(
+            "[validation]\nprofile = \"lenient\"\n\n[validation.rules.spec]\nrequired_sections = [\"Requirements\"]\n",
+        )
+        .unwrap()
+        .merged(parse("[validation.min_length]\nnotes = 5\n").unwrap());
+        assert_eq!(config.profile(), ValidationProfile::Lenient);
+        assert_eq!(config.min_length(ContentType::Vision), 1);
+        assert_eq!(config.min_length(ContentType::Notes), 5);
+
+        let result = config.validate(ContentType::Spec, "# Login\n\nShort");
+        assert!(!result.is_valid);
+        assert_eq!(result.failed_rules.len(), 1);
+        assert_eq!(result.failed_rules[0].rule, "required_section");
+        assert!(config.validate(ContentType::Notes, "Short").is_valid);
+    }
+
+    #[test]
+    fn test_invalid_config_is_rejected() {
+        assert!(read(Path::new("/nonexistent/config.toml")).unwrap() == FoundryConfig::default());
+        assert!(parse("[backend]\nkind = \"linear\"\n").is_err());
+        assert!(parse("[validation.min_length]\nreadme = 10\n").is_err());
+        assert!(parse("[validation]\nprofile = \"picky\"\n").is_err());
+        assert!(parse("[validation.rules.readme]\nmax_length = 10\n").is_err());
+        assert!(parse("[templats]\nspec = \"rfc\"\n").is_err());
+        assert!(parse("[stale]\ndays = 0\n").is_err());
+        assert!(parse("[encryption]\nkey_file = \"/k\"\npassphrase_env = \"PASS\"\n").is_err());
+        assert!(parse("backend = ").is_err());
+    }
+}
@@ -0,0 +1,216 @@
+//! Fitting loaded project and spec context into a token budget
+//!
+//! Documents are split into markdown sections that compete for the budget in
+//! priority order. Whatever fits is put back together in document order, so a
+//! trimmed file still reads like the original with its lowest-priority
+//! sections left out.
+
+use anyhow::Result;
+
+use crate::types::errors::ErrorCode;
+use crate::types::responses::ContextBudget;
+use crate::utils::formatting::estimate_tokens;
+
+/// Smallest `max_tokens` accepted when loading context
+pub const MIN_MAX_TOKENS: usize = 100;
+
+/// Sections cut to fit the budget are dropped rather than cut below this size
+pub const MIN_TRUNCATED_TOKENS: usize = 40;
+
+const TRUNCATION_MARKER: &str = "… (truncated to fit max_tokens)";
+
+/// One markdown section of a document competing for the budget
+#[derive(Debug, Clone)]
+pub struct BudgetSection<K> {
+    /// Document the section belongs to
+    pub document: K,
+    /// Position of the section within its document
+    pub position: usize,
+    /// Label reported in `included_sections` / `omitted_sections`
+    pub title: String,
+    /// Section text, header line included
+    pub text: String,
+}
+
+/// Sections kept within the budget and what was left out
+#[derive(Debug, Clone)]
+pub struct FittedContext<K> {
+    max_tokens: usize,
+    kept: Vec<BudgetSection<K>>,
+    omitted: Vec<String>,
+    truncated: bool,
+}
+
+impl<K: PartialEq> FittedContext<K> {
+    /// The kept sections of `document` in their original order
+    pub fn document(&self, document: &K) -> String {
+        let mut sections: Vec<&BudgetSection<K>> = self
+            .kept
+            .iter()
+            .filter(|section| &section.document == document)
+            .collect();
+        sections.sort_by_key(|section| section.position);
+        sections
+            .iter()
+            .map(|section| section.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Summary of the fit for responses
+    pub fn report(&self) -> ContextBudget {
+        ContextBudget {
+            max_tokens: self.max_tokens,
+            estimated_tokens: self
+                .kept
+                .iter()
+                .map(|section| estimate_tokens(&section.text) + 1)
+                .sum(),
+            included_sections: self
+                .kept
+                .iter()
+                .map(|section| section.title.clone())
+                .collect(),
+            omitted_sections: self.omitted.clone(),
+            truncated: self.truncated,
+        }
+    }
+}
+
+/// Reject budgets too small to hold a useful summary
+pub fn validate_max_tokens(max_tokens: Option<usize>) -> Result<()> {
+    match max_tokens {
+        Some(max_tokens) if max_tokens < MIN_MAX_TOKENS => Err(ErrorCode::InvalidParams
+            .error(format!("'max_tokens' must be at least {}", MIN_MAX_TOKENS))),
+        _ => Ok(()),
+    }
+}
+
+/// `(title, text)` of each markdown section, header lines kept in `text`;
+/// text before the first header is "Overview"
+pub fn split_sections(content: &str) -> Vec<(String, String)> {
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('#') {
+            let title = trimmed.trim_start_matches('#').trim().to_string();
+            sections.push((title, vec![line]));
+        } else if let Some((_, lines)) = sections.last_mut() {
+            lines.push(line);
+        } else if !line.trim().is_empty() {
+            sections.push(("Overview".to_string(), vec![line]));
+        }
+    }
+    sections
+        .into_iter()
+        .map(|(title, lines)| (title, lines.join("\n").trim().to_string()))
+        .filter(|(_, text)| !text.is_empty())
+        .collect()
+}
+
+/// Keep `sections`, given highest priority first, until `max_tokens` is spent
+///
+/// The first section that does not fit is cut short when enough budget is
+/// left, and everything after it is left out so a lower-priority section
+/// never displaces part of a higher-priority one.
+pub fn fit<K>(sections: Vec<BudgetSection<K>>, max_tokens: usize) -> FittedContext<K> {
+    let mut remaining = max_tokens;
+    let mut kept = Vec::new();
+    let mut omitted = Vec::new();
+    let mut truncated = false;
+    let mut full = false;
+    for mut section in sections {
+        // Blank line between sections
+        let cost = estimate_tokens(&section.text) + 1;
+        if !full && cost <= remaining {
+            remaining -= cost;
+            kept.push(section);
+            continue;
+        }
+        if !full && remaining >= MIN_TRUNCATED_TOKENS {
+            section.text = truncate_to_tokens(&section.text, remaining - 1, TRUNCATION_MARKER);
+            kept.push(section);
+            truncated = true;
+        } else {
+            omitted.push(section.title);
+        }
+        full = true;
+    }
+    FittedContext {
+        max_tokens,
+        kept,
+        omitted,
+        truncated,
+    }
+}
+
+/// Whole lines of `text` fitting in `max_tokens`, ending with `marker`
+pub fn truncate_to_tokens(text: &str, max_tokens: usize, marker: &str) -> String {
+    let budget = max_tokens.saturating_sub(estimate_tokens(marker) + 1);
+    let mut kept = Vec::new();
+    let mut used = 0;
+    for line in text.lines() {
+        let cost = estimate_tokens(line) + 1;
+        if used + cost > budget {
+            break;
+        }
+        used += cost;
+        kept.push(line);
+    }
+    kept.push(marker);
+    kept.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sections(document: &'static str, content: &str) -> Vec<BudgetSection<&'static str>> {
+        split_sections(content)
+            .into_iter()
+            .enumerate()
+            .map(|(position, (title, text))| BudgetSection {
+                document,
+                position,
+                title,
+                text,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_split_sections_keeps_headers() {
+        let split = split_sections("Intro line\n\n## First\nBody one\n\n### Second\nBody two\n");
+        assert_eq!(
+            split,
+            vec![
+                ("Overview".to_string(), "Intro line".to_string()),
+                ("First".to_string(), "## First\nBody one".to_string()),
+                ("Second".to_string(), "### Second\nBody two".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fit_drops_low_priority_sections_and_restores_order() {
+        let mut parts = sections("spec", "## A\nshort\n\n## B\nshort too");
+        parts.reverse();
+        parts.extend(sections("notes", &format!("## Log\n{}", "x".repeat(2000))));
+
+        let fitted = fit(parts, 60);
+        let report = fitted.report();
+
+        assert_eq!(fitted.document(&"spec"), "## A\nshort\n\n## B\nshort too");
+        assert_eq!(report.included_sections, vec!["B", "A", "Log"]);
+        assert!(report.truncated);
+        assert!(fitted.document(&"notes").ends_with(TRUNCATION_MARKER));
+        assert!(report.estimated_tokens <= 60, "{}", report.estimated_tokens);
+
+        let fitted = fit(
+            sections("notes", &format!("## Log\n{}", "x".repeat(2000))),
+            10,
+        );
+        assert_eq!(fitted.report().omitted_sections, vec!["Log"]);
+        assert!(fitted.document(&"notes").is_empty());
+    }
+}
@@ -0,0 +1,274 @@
+//! Project decision log (decisions.md)
+//!
+//! Architectural decisions are kept per project as ADR-style records under
+//! one title, so they outlive the spec whose notes they came up in:
+//!
+//! ```markdown
+//! # Decision Log
+//!
+//! ## ADR-001: Store sessions in Redis
+//! - **Date**: 2025-06-01
+//! - **Status**: accepted
+//! - **Spec**: 20250601_120000_user_auth
+//!
+//! ### Context
+//! Sessions must survive restarts and be shared across instances.
+//!
+//! ### Decision
+//! Use Redis with a 24h TTL.
+//!
+//! ### Consequences
+//! Adds Redis to the deployment.
+//! ```
+//!
+//! Every level-2 heading is a decision, so hand-edited entries without an
+//! `ADR-NNN:` prefix or some of the fields are still listed.
+
+use crate::types::errors::ErrorCode;
+use anyhow::Result;
+
+/// Title written at the top of a new decision log
+pub const TITLE: &str = "# Decision Log";
+
+/// Statuses a decision can be recorded with
+pub const STATUSES: &[&str] = &[
+    "proposed",
+    "accepted",
+    "rejected",
+    "deprecated",
+    "superseded",
+];
+
+/// Status of a decision recorded without one
+pub const DEFAULT_STATUS: &str = "accepted";
+
+/// One decision read from the log
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Decision {
+    /// `ADR-NNN` from the heading, when it has one
+    pub id: Option<String>,
+    pub title: String,
+    pub date: Option<String>,
+    pub status: Option<String>,
+    /// Spec the decision was made for
+    pub spec_name: Option<String>,
+    pub context: String,
+    pub decision: String,
+    pub consequences: String,
+}
+
+/// A decision to append to the log
+#[derive(Debug, Clone)]
+pub struct NewDecision {
+    pub title: String,
+    pub context: String,
+    pub decision: String,
+    pub consequences: Option<String>,
+    pub status: String,
+    pub spec_name: Option<String>,
+    /// `YYYY-MM-DD`
+    pub date: String,
+}
+
+/// Fail unless `status` is one of [`STATUSES`]
+pub fn validate_status(status: &str) -> Result<()> {
+    if STATUSES.contains(&status) {
+        Ok(())
+    } else {
+        Err(ErrorCode::InvalidParams.error(format!(
+            "Unknown decision status '{}'; use one of: {}",
+            status,
+            STATUSES.join(", ")
+        )))
+    }
+}
+
+/// Subsection of a decision
+#[derive(Clone, Copy)]
+enum Part {
+    Context,
+    Decision,
+    Consequences,
+}
+
+/// Every decision in `content`, in document order
+pub fn parse(content: &str) -> Vec<Decision> {
+    let mut decisions: Vec<Decision> = Vec::new();
+    // Subsection of the current decision the following lines belong to
+    let mut part: Option<Part> = None;
+
+    for line in content.lines() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            let (id, title) = split_id(heading.trim());
+            decisions.push(Decision {
+                id,
+                title,
+                ..Decision::default()
+            });
+            part = None;
+            continue;
+        }
+        let Some(current) = decisions.last_mut() else {
+            continue;
+        };
+        if let Some(heading) = line.strip_prefix("### ") {
+            part = match heading.trim().to_lowercase().as_str() {
+                "context" => Some(Part::Context),
+                "decision" => Some(Part::Decision),
+                "consequences" => Some(Part::Consequences),
+                _ => None,
+            };
+            continue;
+        }
+        if line.starts_with("# ") {
+            part = None;
+            continue;
+        }
+        if let Some(part) = part {
+            let text = match part {
+                Part::Context => &mut current.context,
+                Part::Decision => &mut current.decision,
+                Part::Consequences => &mut current.consequences,
+            };
+            text.push_str(line);
+            text.push('\n');
+        } else if let Some((key, value)) = field(line) {
+            let value = Some(value.to_string());
+            match key.to_lowercase().as_str() {
+                "date" => current.date = value,
+                "status" => current.status = value.map(|status| status.to_lowercase()),
+                "spec" => current.spec_name = value,
+                _ => {}
+            }
+        }
+    }
+
+    for decision in &mut decisions {
+        for text in [
+            &mut decision.context,
+            &mut decision.decision,
+            &mut decision.consequences,
+        ] {
+            *text = text.trim().to_string();
+        }
+    }
+    decisions
+}
+
+/// `content` with `decision` appended as the next `ADR-NNN`, and that id
+pub fn append(content: &str, decision: &NewDecision) -> (String, String) {
+    let next = parse(content)
+        .iter()
+        .filter_map(|existing| existing.id.as_deref())
+        .filter_map(|id| id.strip_prefix("ADR-")?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let id = format!("ADR-{:03}", next);
+
+    let mut updated = if content.trim().is_empty() {
+        format!("{}\n", TITLE)
+    } else {
+        format!("{}\n", content.trim_end())
+    };
+    updated.push('\n');
+    updated.push_str(&render(&id, decision));
+    (updated, id)
+}
+
+/// Markdown for one decision, ending in a newline
+fn render(id: &str, decision: &NewDecision) -> String {
+    let mut lines = vec![
+        format!("## {}: {}", id, decision.title.trim()),
+        format!("- **Date**: {}", decision.date),
+        format!("- **Status**: {}", decision.status),
+    ];
+    if let Some(spec_name) = &decision.spec_name {
+        lines.push(format!("- **Spec**: {}", spec_name));
+    }
+    for (heading, text) in [
+        ("Context", decision.context.as_str()),
+        ("Decision", decision.decision.as_str()),
+        (
+            "Consequences",
+            decision.consequences.as_deref().unwrap_or(""),
+        ),
+    ] {
+        if text.trim().is_empty() {
+            continue;
+        }
+        lines.push(String::new());
+        lines.push(format!("### {}", heading));
+        lines.push(text.trim().to_string());
+    }
+    format!("{}\n", lines.join("\n"))
+}
+
+/// `ADR-NNN` and the rest of a decision heading
+fn split_id(heading: &str) -> (Option<String>, String) {
+    heading
+        .split_once(':')
+        .filter(|(id, _)| {
+            id.strip_prefix("ADR-").is_some_and(|number| {
+                !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit())
+            })
+        })
+        .map_or_else(
+            || (None, heading.to_string()),
+            |(id, title)| (Some(id.to_string()), title.trim().to_string()),
+        )
+}
+
+/// Key and value of a `- **Key**: value` line
+fn field(line: &str) -> Option<(&str, &str)> {
+    let rest = line.trim().strip_prefix("- **")?;
+    let (key, value) = rest.split_once("**:")?;
+    Some((key.trim(), value.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_decision(title: &str) -> NewDecision {
+        NewDecision {
+            title: title.to_string(),
+            context: "Sessions must survive restarts.".to_string(),
+            decision: "Use Redis.".to_string(),
+            consequences: None,
+            status: DEFAULT_STATUS.to_string(),
+            spec_name: Some("20250601_120000_user_auth".to_string()),
+            date: "2025-06-01".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_append_numbers_decisions_and_parse_reads_them_back() {
+        let (content, id) = append("", &new_decision("Store sessions in Redis"));
+        assert_eq!(id, "ADR-001");
+        assert!(content.starts_with("# Decision Log\n\n## ADR-001: Store sessions in Redis\n"));
+        assert!(!content.contains("### Consequences"));
+
+        // Hand-written entries keep their place and do not break numbering
+        let content = format!("{}\n## Prefer plain SQL\nNo ORM.\n", content);
+        let (content, id) = append(&content, &new_decision("Use JWTs"));
+        assert_eq!(id, "ADR-002");
+
+        let decisions = parse(&content);
+        assert_eq!(decisions.len(), 3);
+        assert_eq!(decisions[0].id.as_deref(), Some("ADR-001"));
+        assert_eq!(decisions[0].title, "Store sessions in Redis");
+        assert_eq!(decisions[0].status.as_deref(), Some("accepted"));
+        assert_eq!(decisions[0].date.as_deref(), Some("2025-06-01"));
+        assert_eq!(
+            decisions[0].spec_name.as_deref(),
+            Some("20250601_120000_user_auth")
+        );
+        assert_eq!(decisions[0].context, "Sessions must survive restarts.");
+        assert_eq!(decisions[0].decision, "Use Redis.");
+        assert_eq!(decisions[1].id, None);
+        assert_eq!(decisions[1].title, "Prefer plain SQL");
+        assert_eq!(decisions[1].status, None);
+        assert_eq!(decisions[2].id.as_deref(), Some("ADR-002"));
+    }
+}
@@ -0,0 +1,190 @@
+//! Spec dependency graph
+//!
+//! Each spec lists the specs it depends on in its metadata (`depends_on`), so
+//! edges point from a spec to the specs that must be finished first. A spec is
+//! blocked while any dependency is still open (not completed or archived);
+//! dependencies naming specs that no longer exist are ignored.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::types::spec::SpecMetadata;
+
+/// Dependencies of `spec` that exist and are not yet completed or archived
+pub fn open_dependencies(spec: &SpecMetadata, specs: &[SpecMetadata]) -> Vec<String> {
+    spec.depends_on
+        .iter()
+        .filter(|dependency| {
+            specs
+                .iter()
+                .any(|other| &other.name == *dependency && !other.status.is_closed())
+        })
+        .cloned()
+        .collect()
+}
+
+/// Dependencies of `spec` naming specs that are not in `specs`
+pub fn missing_dependencies(spec: &SpecMetadata, specs: &[SpecMetadata]) -> Vec<String> {
+    spec.depends_on
+        .iter()
+        .filter(|dependency| !specs.iter().any(|other| &other.name == *dependency))
+        .cloned()
+        .collect()
+}
+
+/// Names of the specs that list `spec_name` as a dependency
+pub fn dependents(spec_name: &str, specs: &[SpecMetadata]) -> Vec<String> {
+    specs
+        .iter()
+        .filter(|spec| {
+            spec.depends_on
+                .iter()
+                .any(|dependency| dependency == spec_name)
+        })
+        .map(|spec| spec.name.clone())
+        .collect()
+}
+
+/// Every dependency cycle, each as the spec names along it with the first
+/// repeated at the end (e.g. `a → b → a`)
+pub fn find_cycles(specs: &[SpecMetadata]) -> Vec<Vec<String>> {
+    let graph = adjacency(specs);
+    let mut visited = BTreeSet::new();
+    let mut cycles = Vec::new();
+    for start in graph.keys() {
+        let mut path = Vec::new();
+        visit(start, &graph, &mut visited, &mut path, &mut cycles);
+    }
+    cycles
+}
+
+/// Spec names ordered so every spec comes after its dependencies, or `None`
+/// when there is a cycle
+///
+/// Ties are broken by name, which for timestamped spec names is creation order.
+pub fn build_order(specs: &[SpecMetadata]) -> Option<Vec<String>> {
+    let graph = adjacency(specs);
+    let mut remaining: BTreeMap<&str, usize> = graph
+        .iter()
+        .map(|(name, dependencies)| (*name, dependencies.len()))
+        .collect();
+    let mut order = Vec::with_capacity(graph.len());
+    while let Some(next) = remaining
+        .iter()
+        .find(|(_, count)| **count == 0)
+        .map(|(name, _)| *name)
+    {
+        remaining.remove(next);
+        order.push(next.to_string());
+        for (name, dependencies) in &graph {
+            if dependencies.contains(next)
+                && let Some(count) = remaining.get_mut(name)
+            {
+                *count -= 1;
+            }
+        }
+    }
+    remaining.is_empty().then_some(order)
+}
+
+/// Spec name → its dependencies that exist in `specs`
+fn adjacency(specs: &[SpecMetadata]) -> BTreeMap<&str, BTreeSet<&str>> {
+    let names: BTreeSet<&str> = specs.iter().map(|spec| spec.name.as_str()).collect();
+    specs
+        .iter()
+        .map(|spec| {
+            let dependencies = spec
+                .depends_on
+                .iter()
+                .map(String::as_str)
+                .filter(|dependency| names.contains(dependency))
+                .collect();
+            (spec.name.as_str(), dependencies)
+        })
+        .collect()
+}
+
+fn visit<'a>(
+    node: &'a str,
+    graph: &BTreeMap<&'a str, BTreeSet<&'a str>>,
+    visited: &mut BTreeSet<&'a str>,
+    path: &mut Vec<&'a str>,
+    cycles: &mut Vec<Vec<String>>,
+) {
+    if let Some(position) = path.iter().position(|step| *step == node) {
+        let mut cycle: Vec<String> = path[position..].iter().map(|s| s.to_string()).collect();
+        cycle.push(node.to_string());
+        cycles.push(cycle);
+        return;
+    }
+    if !visited.insert(node) {
+        return;
+    }
+    path.push(node);
+    for dependency in &graph[node] {
+        visit(dependency, graph, visited, path, cycles);
+    }
+    path.pop();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::spec::SpecStatus;
+
+    fn spec(name: &str, depends_on: &[&str], status: SpecStatus) -> SpecMetadata {
+        SpecMetadata {
+            name: name.to_string(),
+            created_at: String::new(),
+            feature_name: name.to_string(),
+            project_name: "proj".to_string(),
+            status,
+            depends_on: depends_on.iter().map(|d| d.to_string()).collect(),
+            tags: Vec::new(),
+            task_counts: Default::default(),
+            updated_at: None,
+            modified_at: Default::default(),
+            summary: None,
+        }
+    }
+
+    #[test]
+    fn test_build_order_and_open_dependencies() {
+        let specs = vec![
+            spec("c", &["a", "b"], SpecStatus::Draft),
+            spec("b", &["a", "gone"], SpecStatus::Active),
+            spec("a", &[], SpecStatus::Completed),
+        ];
+
+        assert_eq!(
+            build_order(&specs),
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+        assert!(find_cycles(&specs).is_empty());
+        assert_eq!(open_dependencies(&specs[0], &specs), vec!["b"]);
+        assert!(open_dependencies(&specs[1], &specs).is_empty());
+        assert_eq!(missing_dependencies(&specs[1], &specs), vec!["gone"]);
+        assert_eq!(dependents("a", &specs), vec!["c", "b"]);
+    }
+
+    #[test]
+    fn test_find_cycles() {
+        let specs = vec![
+            spec("a", &["b"], SpecStatus::Draft),
+            spec("b", &["c"], SpecStatus::Draft),
+            spec("c", &["a"], SpecStatus::Draft),
+            spec("d", &["d"], SpecStatus::Draft),
+        ];
+
+        assert_eq!(
+            find_cycles(&specs),
+            vec![
+                vec!["a", "b", "c", "a"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect::<Vec<_>>(),
+                vec!["d".to_string(), "d".to_string()],
+            ]
+        );
+        assert_eq!(build_order(&specs), None);
+    }
+}
@@ -0,0 +1,130 @@
+//! Mermaid diagram rendering for spec dependencies and task phases
+//!
+//! Dependencies are the `[[wiki-links]]` between specs: a spec that links to
+//! another spec points at it. Phase flow follows the `##` sections of a
+//! task list in document order.
+
+use crate::core::tasks::TaskEntry;
+
+/// A spec in the dependency graph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagramNode {
+    pub spec_name: String,
+    pub label: String,
+}
+
+/// Render a `graph TD` diagram with one node per spec and an edge per link
+///
+/// Edges are `(from_spec_name, to_spec_name)` pairs; edges referring to specs
+/// that are not in `nodes` are ignored.
+pub fn render_dependency_graph(nodes: &[DiagramNode], edges: &[(String, String)]) -> String {
+    let mut lines = vec!["graph TD".to_string()];
+
+    for (index, node) in nodes.iter().enumerate() {
+        lines.push(format!("    s{}[\"{}\"]", index, escape_label(&node.label)));
+    }
+
+    let position = |name: &str| nodes.iter().position(|n| n.spec_name == name);
+    for (from, to) in edges {
+        if let (Some(from), Some(to)) = (position(from), position(to)) {
+            lines.push(format!("    s{} --> s{}", from, to));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Render a `flowchart LR` diagram of a task list's sections with done/total counts
+///
+/// Tasks outside any section are grouped under "Tasks".
+pub fn render_phase_flow(tasks: &[TaskEntry]) -> String {
+    let mut phases: Vec<(&str, usize, usize)> = Vec::new();
+    for task in tasks {
+        let section = if task.section.is_empty() {
+            "Tasks"
+        } else {
+            task.section.as_str()
+        };
+        match phases.iter_mut().find(|(name, _, _)| *name == section) {
+            Some((_, done, total)) => {
+                *total += 1;
+                *done += usize::from(task.done);
+            }
+            None => phases.push((section, usize::from(task.done), 1)),
+        }
+    }
+
+    let mut lines = vec!["flowchart LR".to_string()];
+    for (index, (name, done, total)) in phases.iter().enumerate() {
+        lines.push(format!(
+            "    p{}[\"{} ({}/{})\"]",
+            index,
+            escape_label(name),
+            done,
+            total
+        ));
+    }
+    for index in 1..phases.len() {
+        lines.push(format!("    p{} --> p{}", index - 1, index));
+    }
+
+    lines.join("\n")
+}
+
+/// Mermaid labels are quoted, so quotes and brackets need entity escapes
+fn escape_label(label: &str) -> String {
+    label
+        .replace('"', "#quot;")
+        .replace('[', "#91;")
+        .replace(']', "#93;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, label: &str) -> DiagramNode {
+        DiagramNode {
+            spec_name: name.to_string(),
+            label: label.to_string(),
+        }
+    }
+
+    fn task(section: &str, done: bool) -> TaskEntry {
+        TaskEntry {
+            section: section.to_string(),
+            phase: None,
+            text: "task".to_string(),
+            done,
+            completed_at: None,
+            metadata: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_render_dependency_graph_skips_unknown_edges() {
+        let nodes = vec![node("a", "Auth"), node("b", "Billing \"v2\"")];
+        let edges = vec![
+            ("b".to_string(), "a".to_string()),
+            ("b".to_string(), "missing".to_string()),
+        ];
+        assert_eq!(
+            render_dependency_graph(&nodes, &edges),
+            "graph TD\n    s0[\"Auth\"]\n    s1[\"Billing #quot;v2#quot;\"]\n    s1 --> s0"
+        );
+    }
+
+    #[test]
+    fn test_render_phase_flow_counts_tasks_per_section() {
+        let tasks = vec![
+            task("Setup", true),
+            task("Setup", false),
+            task("Build", false),
+            task("", true),
+        ];
+        assert_eq!(
+            render_phase_flow(&tasks),
+            "flowchart LR\n    p0[\"Setup (1/2)\"]\n    p1[\"Build (0/1)\"]\n    p2[\"Tasks (1/1)\"]\n    p0 --> p1\n    p1 --> p2"
+        );
+    }
+}
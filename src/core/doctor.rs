@@ -0,0 +1,572 @@
+//! Environment and data diagnostics for `foundry doctor`
+//!
+//! Checks the project directories of every configured storage backend
+//! (missing files, orphaned specs, malformed metadata, invalid timestamps,
+//! leftovers of interrupted writes), the config files, Linear credentials when
+//! `[linear]` is configured, and the MCP installs that `foundry status`
+//! reports. Each problem comes with a fix; the ones that can be repaired
+//! without losing content are applied with `--fix`.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::core::backends::filesystem::SPEC_METADATA_FILE;
+use crate::core::backends::{self, storage_dir};
+use crate::core::config::{self, BackendConfig, FoundryConfig};
+use crate::core::{encryption, filesystem, installation, spec_name};
+use crate::types::responses::{DoctorCheck, DoctorResponse, DoctorStatus};
+use crate::types::spec::SpecStatus;
+
+/// Environment variable holding the Linear API key
+pub const LINEAR_API_KEY_VAR: &str = "LINEAR_API_KEY";
+
+/// Suffix given to metadata files set aside by `--fix`
+const CORRUPT_SUFFIX: &str = "corrupt";
+
+const PROJECT_FILES: [&str; 3] = ["vision.md", "tech-stack.md", "summary.md"];
+const SPEC_FILES: [&str; 2] = ["notes.md", "task-list.md"];
+
+/// A repair `--fix` can make
+#[derive(Debug, Clone, PartialEq)]
+enum Repair {
+    CreateDir(PathBuf),
+    CreateFile(PathBuf),
+    RemoveDir(PathBuf),
+    RemoveFile(PathBuf),
+    /// Rename an unreadable metadata file so the spec falls back to defaults
+    SetAside(PathBuf),
+    /// Write corrected metadata
+    WriteJson(PathBuf, serde_json::Value),
+    Reinstall(String),
+}
+
+/// A problem found, with the repair `--fix` makes for it
+struct Finding {
+    check: DoctorCheck,
+    repair: Option<Repair>,
+}
+
+impl Finding {
+    fn new(category: &str, status: DoctorStatus, subject: String, message: String) -> Self {
+        Self {
+            check: DoctorCheck {
+                category: category.to_string(),
+                status,
+                subject,
+                message,
+                fix: None,
+                fixable: false,
+                fixed: false,
+            },
+            repair: None,
+        }
+    }
+
+    fn ok(category: &str, subject: String, message: &str) -> Self {
+        Self::new(category, DoctorStatus::Ok, subject, message.to_string())
+    }
+
+    fn fix(mut self, fix: impl Into<String>) -> Self {
+        self.check.fix = Some(fix.into());
+        self
+    }
+
+    fn repair(mut self, repair: Repair) -> Self {
+        self.check.fixable = true;
+        self.repair = Some(repair);
+        self
+    }
+}
+
+/// Run every check, repairing what can be repaired when `fix` is set
+pub async fn run(fix: bool) -> Result<DoctorResponse> {
+    let foundry_dir = filesystem::foundry_dir()?;
+    let mut findings = check_config();
+    for dir in storage_dirs()? {
+        findings.extend(check_data(&dir, &foundry_dir)?);
+    }
+    findings.extend(check_linear());
+    findings.extend(check_installations().await);
+
+    let mut checks = Vec::with_capacity(findings.len());
+    for Finding { mut check, repair } in findings {
+        if let (true, Some(repair)) = (fix, repair) {
+            match apply(&repair).await {
+                Ok(()) => check.fixed = true,
+                Err(e) => check.message = format!("{} (fix failed: {:#})", check.message, e),
+            }
+        }
+        checks.push(check);
+    }
+    let count = |status| {
+        checks
+            .iter()
+            .filter(|check| check.status == status && !check.fixed)
+            .count()
+    };
+    Ok(DoctorResponse {
+        foundry_dir: foundry_dir.display().to_string(),
+        errors: count(DoctorStatus::Error),
+        warnings: count(DoctorStatus::Warning),
+        fixed: checks.iter().filter(|check| check.fixed).count(),
+        checks,
+    })
+}
+
+async fn apply(repair: &Repair) -> Result<()> {
+    match repair {
+        Repair::CreateDir(path) => filesystem::create_dir_all(path),
+        Repair::CreateFile(path) => filesystem::write_content_atomic(path, ""),
+        Repair::RemoveDir(path) => {
+            fs::remove_dir(path).with_context(|| format!("Failed to remove {:?}", path))
+        }
+        Repair::RemoveFile(path) => {
+            fs::remove_file(path).with_context(|| format!("Failed to remove {:?}", path))
+        }
+        Repair::SetAside(path) => {
+            let target = path.with_extension(format!("json.{}", CORRUPT_SUFFIX));
+            fs::rename(path, &target).with_context(|| format!("Failed to rename {:?}", path))
+        }
+        Repair::WriteJson(path, value) => {
+            filesystem::write_file_atomic(path, &serde_json::to_string_pretty(value)?)
+        }
+        Repair::Reinstall(target) => installation::install_for_target(target).await.map(|_| ()),
+    }
+}
+
+/// Directories holding project files: the default backend's and those of
+/// backends picked in `.foundry.toml` files
+fn storage_dirs() -> Result<Vec<PathBuf>> {
+    let default = backends::resolve_backend_config(BackendConfig::default())
+        .unwrap_or_else(|_| BackendConfig::default().normalized());
+    let mut configs = vec![default.clone()];
+    if let Ok(overrides) = config::project_overrides() {
+        configs.extend(
+            overrides
+                .into_iter()
+                .map(|(_, project)| default.clone().merged(project.backend).normalized()),
+        );
+    }
+    let mut dirs = BTreeSet::new();
+    for config in configs {
+        dirs.insert(storage_dir(config.kind(), config.git_dir.as_deref())?);
+    }
+    Ok(dirs.into_iter().collect())
+}
+
+fn check_config() -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let subject = config::GLOBAL_CONFIG_FILE.to_string();
+    match config::global().and_then(|_| config::project_overrides()) {
+        Ok(_) => findings.push(Finding::ok("config", subject, "Config files are valid")),
+        Err(e) => findings.push(
+            Finding::new("config", DoctorStatus::Error, subject, format!("{:#}", e))
+                .fix("Correct the file named in the message; unknown keys are rejected"),
+        ),
+    }
+    findings
+}
+
+fn check_linear() -> Vec<Finding> {
+    let configured = config::global()
+        .ok()
+        .into_iter()
+        .chain(
+            config::project_overrides()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(_, config)| config),
+        )
+        .any(|config: FoundryConfig| config.linear.team.is_some());
+    if !configured {
+        return Vec::new();
+    }
+    let subject = LINEAR_API_KEY_VAR.to_string();
+    let has_key = std::env::var(LINEAR_API_KEY_VAR).is_ok_and(|key| !key.trim().is_empty());
+    vec![if has_key {
+        Finding::ok("linear", subject, "Linear API key is set")
+    } else {
+        Finding::new(
+            "linear",
+            DoctorStatus::Warning,
+            subject,
+            format!(
+                "[linear] is configured but ${} is not set",
+                LINEAR_API_KEY_VAR
+            ),
+        )
+        .fix(format!(
+            "Create a personal API key in Linear (Settings › API) and export it as {}",
+            LINEAR_API_KEY_VAR
+        ))
+    }]
+}
+
+async fn check_installations() -> Vec<Finding> {
+    let statuses = match installation::get_all_environment_statuses(false).await {
+        Ok(statuses) => statuses,
+        Err(e) => {
+            return vec![Finding::new(
+                "installation",
+                DoctorStatus::Warning,
+                "status".to_string(),
+                format!("Could not check installations: {:#}", e),
+            )];
+        }
+    };
+    statuses
+        .into_iter()
+        .filter(|status| status.installed || status.config_exists)
+        .map(|status| {
+            let name = status.name.clone();
+            if !status.installed || !status.binary_accessible {
+                let mut problems = status.issues.clone();
+                if !status.binary_accessible {
+                    problems.push(format!(
+                        "Configured binary {} is not accessible",
+                        status.binary_path
+                    ));
+                }
+                Finding::new(
+                    "installation",
+                    DoctorStatus::Error,
+                    name.clone(),
+                    problems.join("; "),
+                )
+                .fix(format!("foundry install {}", name))
+                .repair(Repair::Reinstall(name))
+            } else if !status.issues.is_empty() {
+                Finding::new(
+                    "installation",
+                    DoctorStatus::Warning,
+                    name.clone(),
+                    status.issues.join("; "),
+                )
+                .fix(format!("foundry install {}", name))
+                .repair(Repair::Reinstall(name))
+            } else {
+                Finding::ok("installation", name, "Installed")
+            }
+        })
+        .collect()
+}
+
+/// Check the projects stored in `root`; subjects are relative to `foundry_dir`
+fn check_data(root: &Path, foundry_dir: &Path) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+    if !root.exists() {
+        return Ok(findings);
+    }
+    let subject = |path: &Path| {
+        path.strip_prefix(foundry_dir)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+    let key_configured = encryption::configured_key().ok().flatten().is_some();
+    let mut projects = 0;
+    for project_path in sorted_entries(root)? {
+        let name = encryption::file_name(&project_path);
+        if name.starts_with('.') || !project_path.is_dir() || !has_visible_entries(&project_path) {
+            continue;
+        }
+        projects += 1;
+        for file in PROJECT_FILES {
+            let path = project_path.join(file);
+            if !path.exists() {
+                findings.push(
+                    Finding::new(
+                        "data",
+                        DoctorStatus::Warning,
+                        subject(&path),
+                        format!("Project '{}' has no {}", name, file),
+                    )
+                    .fix("Restore the file; project context is incomplete without it"),
+                );
+            }
+        }
+        findings.extend(check_files(&project_path, key_configured, &subject));
+
+        let specs_dir = project_path.join("specs");
+        if !specs_dir.is_dir() {
+            findings.push(
+                Finding::new(
+                    "data",
+                    DoctorStatus::Warning,
+                    subject(&specs_dir),
+                    format!("Project '{}' has no specs directory", name),
+                )
+                .fix("Create the directory")
+                .repair(Repair::CreateDir(specs_dir)),
+            );
+            continue;
+        }
+        let spec_names: BTreeSet<String> = sorted_entries(&specs_dir)?
+            .iter()
+            .filter(|path| path.is_dir())
+            .map(|path| encryption::file_name(path))
+            .collect();
+        for spec_path in sorted_entries(&specs_dir)? {
+            if spec_path.is_dir() {
+                findings.extend(check_spec(
+                    &spec_path,
+                    &spec_names,
+                    key_configured,
+                    &subject,
+                )?);
+            }
+        }
+    }
+    findings.push(Finding::ok(
+        "data",
+        subject(root),
+        &format!(
+            "Checked {}",
+            crate::utils::formatting::format_count(projects, "project", "projects")
+        ),
+    ));
+    Ok(findings)
+}
+
+fn check_spec(
+    spec_path: &Path,
+    spec_names: &BTreeSet<String>,
+    key_configured: bool,
+    subject: &dyn Fn(&Path) -> String,
+) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+    let spec_name = encryption::file_name(spec_path);
+    let valid_name = spec_name::parse(&spec_name).is_some_and(|parsed| {
+        parsed.kind != spec_name::SpecIdKind::Timestamp || parsed.created_at().is_some()
+    });
+    if !valid_name {
+        findings.push(
+            Finding::new(
+                "data",
+                DoctorStatus::Error,
+                subject(spec_path),
+                format!(
+                    "Spec directory '{}' has no valid <id>_<feature> name (e.g. YYYYMMDD_HHMMSS_<feature>), so it is not listed",
+                    spec_name
+                ),
+            )
+            .fix("Rename the directory to YYYYMMDD_HHMMSS_<feature_name>, using the date it was written"),
+        );
+        // Repairs inside a directory Foundry cannot list would only hide it further
+        return Ok(findings);
+    }
+
+    if !spec_path.join("spec.md").exists() {
+        if sorted_entries(spec_path)?.is_empty() {
+            findings.push(
+                Finding::new(
+                    "data",
+                    DoctorStatus::Warning,
+                    subject(spec_path),
+                    "Empty spec directory".to_string(),
+                )
+                .fix("Remove the directory")
+                .repair(Repair::RemoveDir(spec_path.to_path_buf())),
+            );
+            return Ok(findings);
+        }
+        findings.push(
+            Finding::new(
+                "data",
+                DoctorStatus::Error,
+                subject(spec_path),
+                "Orphaned spec: the directory has no spec.md".to_string(),
+            )
+            .fix("Restore spec.md from a backup (`foundry backup list`) or delete the directory"),
+        );
+    }
+    for file in SPEC_FILES {
+        let path = spec_path.join(file);
+        if !path.exists() {
+            findings.push(
+                Finding::new(
+                    "data",
+                    DoctorStatus::Warning,
+                    subject(&path),
+                    format!("Spec has no {}", file),
+                )
+                .fix(format!("Create an empty {}", file))
+                .repair(Repair::CreateFile(path)),
+            );
+        }
+    }
+    findings.extend(check_metadata(spec_path, spec_names, subject));
+    findings.extend(check_files(spec_path, key_configured, subject));
+    Ok(findings)
+}
+
+fn check_metadata(
+    spec_path: &Path,
+    spec_names: &BTreeSet<String>,
+    subject: &dyn Fn(&Path) -> String,
+) -> Vec<Finding> {
+    let path = spec_path.join(SPEC_METADATA_FILE);
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+    let set_aside = |message: String| {
+        vec![
+            Finding::new("data", DoctorStatus::Error, subject(&path), message)
+                .fix(format!(
+                    "Set the file aside as {}.{}; the spec falls back to draft with no dependencies",
+                    SPEC_METADATA_FILE, CORRUPT_SUFFIX
+                ))
+                .repair(Repair::SetAside(path.clone())),
+        ]
+    };
+    let mut value = match serde_json::from_str::<serde_json::Value>(&content) {
+        Ok(serde_json::Value::Object(map)) => map,
+        Ok(_) => return set_aside("Spec metadata is not a JSON object".to_string()),
+        Err(e) => return set_aside(format!("Spec metadata is not valid JSON: {}", e)),
+    };
+    if let Some(status) = value.get("status")
+        && serde_json::from_value::<SpecStatus>(status.clone()).is_err()
+    {
+        return set_aside(format!("Unknown spec status {}", status));
+    }
+
+    let mut problems = Vec::new();
+    if let Some(updated) = value.get("status_updated_at")
+        && updated
+            .as_str()
+            .is_none_or(|updated| chrono::DateTime::parse_from_rfc3339(updated).is_err())
+    {
+        problems.push(format!("invalid status_updated_at {}", updated));
+        value.remove("status_updated_at");
+    }
+    if let Some(serde_json::Value::Array(depends_on)) = value.get_mut("depends_on") {
+        let before = depends_on.len();
+        depends_on.retain(|dependency| {
+            let known = dependency
+                .as_str()
+                .is_some_and(|name| spec_names.contains(name));
+            if !known {
+                problems.push(format!("dependency on missing spec {}", dependency));
+            }
+            known
+        });
+        if depends_on.len() != before && depends_on.is_empty() {
+            value.remove("depends_on");
+        }
+    }
+    if problems.is_empty() {
+        return Vec::new();
+    }
+    vec![
+        Finding::new(
+            "data",
+            DoctorStatus::Warning,
+            subject(&path),
+            format!("Spec metadata has {}", problems.join(", ")),
+        )
+        .fix("Drop the invalid entries")
+        .repair(Repair::WriteJson(path, serde_json::Value::Object(value))),
+    ]
+}
+
+/// Leftover temporary files and encrypted files that cannot be read in `dir`
+fn check_files(
+    dir: &Path,
+    key_configured: bool,
+    subject: &dyn Fn(&Path) -> String,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for path in sorted_entries(dir).unwrap_or_default() {
+        if !path.is_file() {
+            continue;
+        }
+        let name = encryption::file_name(&path);
+        if name.ends_with(".tmp") {
+            findings.push(
+                Finding::new(
+                    "data",
+                    DoctorStatus::Warning,
+                    subject(&path),
+                    "Temporary file left by an interrupted write".to_string(),
+                )
+                .fix("Remove it")
+                .repair(Repair::RemoveFile(path)),
+            );
+        } else if !key_configured
+            && encryption::CONTENT_FILES.contains(&name.as_str())
+            && fs::read_to_string(&path).is_ok_and(|content| encryption::is_encrypted(&content))
+        {
+            findings.push(
+                Finding::new(
+                    "data",
+                    DoctorStatus::Error,
+                    subject(&path),
+                    "File is encrypted but no key is configured".to_string(),
+                )
+                .fix("Set [encryption] key_file or passphrase_env in config.toml"),
+            );
+        }
+    }
+    findings
+}
+
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {:?}", dir))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+fn has_visible_entries(dir: &Path) -> bool {
+    sorted_entries(dir).is_ok_and(|entries| {
+        entries
+            .iter()
+            .any(|path| !encryption::file_name(path).starts_with('.'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_problems_are_repaired_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let spec_path = dir.path().join("20250101_000000_auth");
+        fs::create_dir_all(&spec_path).unwrap();
+        let names = BTreeSet::from(["20250101_000000_auth".to_string()]);
+        let subject = |path: &Path| path.display().to_string();
+
+        fs::write(
+            spec_path.join(SPEC_METADATA_FILE),
+            r#"{"status": "active", "status_updated_at": "yesterday", "depends_on": ["20240101_000000_gone", "20250101_000000_auth"]}"#,
+        )
+        .unwrap();
+        let findings = check_metadata(&spec_path, &names, &subject);
+        assert_eq!(findings.len(), 1);
+        assert!(
+            findings[0]
+                .check
+                .message
+                .contains("invalid status_updated_at")
+        );
+        assert!(findings[0].check.message.contains("20240101_000000_gone"));
+        let Some(Repair::WriteJson(_, value)) = &findings[0].repair else {
+            panic!("expected a metadata rewrite");
+        };
+        assert_eq!(
+            value,
+            &serde_json::json!({"status": "active", "depends_on": ["20250101_000000_auth"]})
+        );
+
+        fs::write(spec_path.join(SPEC_METADATA_FILE), "{\"status\": \"done\"}").unwrap();
+        let findings = check_metadata(&spec_path, &names, &subject);
+        assert_eq!(findings[0].check.status, DoctorStatus::Error);
+        assert!(matches!(findings[0].repair, Some(Repair::SetAside(_))));
+    }
+}
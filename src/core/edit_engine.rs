@@ -1,10 +1,14 @@
 use crate::core::backends::SpecContentStore;
+use crate::core::spec_diff::unified_diff;
+use crate::core::tasks::{self, TaskMetadata};
 use crate::types::edit_commands::{
-    EditCommand, EditCommandError, EditCommandName, EditCommandTarget, EditSelector,
+    CommandDiff, EditCommand, EditCommandError, EditCommandName, EditCommandTarget, EditSelector,
     FileUpdateSummary, SelectorCandidate, TaskStatus,
 };
-use crate::types::spec::SpecFileType;
+use crate::types::errors::ErrorCode;
+use crate::types::spec::{SpecContentData, SpecFileType};
 use anyhow::{Result, anyhow};
+use regex::Regex;
 
 pub struct EditEngine;
 
@@ -16,47 +20,239 @@ pub struct EditCommandsResult {
     pub next_steps: Vec<String>,
     pub workflow_hints: Vec<String>,
     pub preview_diff: Option<String>,
+    /// Per-command diffs, filled in by dry runs
+    pub command_diffs: Vec<CommandDiff>,
+}
+
+/// A spec's editable files and dependencies
+#[derive(Clone)]
+struct SpecFiles {
+    spec: String,
+    tasks: String,
+    notes: String,
+    dependencies: Vec<String>,
+}
+
+impl SpecFiles {
+    async fn read<S: SpecContentStore>(
+        project_name: &str,
+        spec_name: &str,
+        store: &S,
+    ) -> Result<Self> {
+        let read = |file_type| async move {
+            store
+                .read_spec_file(project_name, spec_name, file_type)
+                .await
+                .unwrap_or_else(|_| String::new())
+        };
+        Ok(Self {
+            spec: read(SpecFileType::Spec).await,
+            tasks: read(SpecFileType::TaskList).await,
+            notes: read(SpecFileType::Notes).await,
+            dependencies: store
+                .read_spec_dependencies(project_name, spec_name)
+                .await?,
+        })
+    }
+
+    fn process(&mut self, commands: &[EditCommand]) -> Result<EditCommandsResult> {
+        EditEngine::process_edit_commands(
+            commands,
+            &mut self.spec,
+            &mut self.tasks,
+            &mut self.notes,
+            &mut self.dependencies,
+        )
+    }
+
+    /// Unified diff from `self` to `after`, one file after another
+    fn diff(&self, after: &Self) -> String {
+        let dependencies = |files: &Self| {
+            files
+                .dependencies
+                .iter()
+                .map(|name| format!("{}\n", name))
+                .collect::<String>()
+        };
+        [
+            unified_diff("spec.md", &self.spec, &after.spec),
+            unified_diff("task-list.md", &self.tasks, &after.tasks),
+            unified_diff("notes.md", &self.notes, &after.notes),
+            unified_diff("depends_on", &dependencies(self), &dependencies(after)),
+        ]
+        .concat()
+    }
 }
 
 impl EditEngine {
+    #[tracing::instrument(
+        level = "debug",
+        name = "edit_engine.apply",
+        skip(commands, store),
+        fields(commands = commands.len())
+    )]
     pub async fn apply_edit_commands_with_store<S: SpecContentStore>(
         project_name: &str,
         spec_name: &str,
         commands: &[EditCommand],
         store: &S,
+    ) -> Result<EditCommandsResult> {
+        Self::apply_with_store(project_name, spec_name, commands, store, false).await
+    }
+
+    /// Like [`Self::apply_edit_commands_with_store`], but writes nothing when any
+    /// command fails, so the spec gets either every edit or none
+    #[tracing::instrument(
+        level = "debug",
+        name = "edit_engine.apply_atomic",
+        skip(commands, store),
+        fields(commands = commands.len())
+    )]
+    pub async fn apply_edit_commands_atomic_with_store<S: SpecContentStore>(
+        project_name: &str,
+        spec_name: &str,
+        commands: &[EditCommand],
+        store: &S,
+    ) -> Result<EditCommandsResult> {
+        Self::apply_with_store(project_name, spec_name, commands, store, true).await
+    }
+
+    /// Work out what `commands` would change without writing anything
+    ///
+    /// The result holds the usual counts and errors, `preview_diff` with the
+    /// unified diff of every file and `command_diffs` with each command's own diff.
+    #[tracing::instrument(
+        level = "debug",
+        name = "edit_engine.preview",
+        skip(commands, store),
+        fields(commands = commands.len())
+    )]
+    pub async fn preview_edit_commands_with_store<S: SpecContentStore>(
+        project_name: &str,
+        spec_name: &str,
+        commands: &[EditCommand],
+        store: &S,
     ) -> Result<EditCommandsResult> {
         if commands.is_empty() {
             return Err(anyhow!("commands must be a non-empty array"));
         }
 
-        // Load current contents via SpecContentStore
-        let mut spec_content = store
-            .read_spec_file(project_name, spec_name, SpecFileType::Spec)
-            .await
-            .unwrap_or_else(|_| String::new());
-        let mut tasks_content = store
-            .read_spec_file(project_name, spec_name, SpecFileType::TaskList)
-            .await
-            .unwrap_or_else(|_| String::new());
-        let mut notes_content = store
-            .read_spec_file(project_name, spec_name, SpecFileType::Notes)
-            .await
-            .unwrap_or_else(|_| String::new());
-
-        let result = Self::process_edit_commands(
-            commands,
-            &mut spec_content,
-            &mut tasks_content,
-            &mut notes_content,
+        let original = SpecFiles::read(project_name, spec_name, store).await?;
+        let mut result = original.clone().process(commands)?;
+
+        // Commands apply in order, so running them one at a time gives the same end state
+        let mut files = original.clone();
+        for (command_index, command) in commands.iter().enumerate() {
+            let before = files.clone();
+            files.process(std::slice::from_ref(command))?;
+            result.command_diffs.push(CommandDiff {
+                command_index,
+                target: command.target.clone(),
+                diff: before.diff(&files),
+            });
+        }
+        result.preview_diff = Some(original.diff(&files));
+        Ok(result)
+    }
+
+    /// Content of spec.md, notes.md and task-list.md once `commands` are
+    /// applied, without writing anything
+    pub async fn edited_content_with_store<S: SpecContentStore>(
+        project_name: &str,
+        spec_name: &str,
+        commands: &[EditCommand],
+        store: &S,
+    ) -> Result<SpecContentData> {
+        let mut files = SpecFiles::read(project_name, spec_name, store).await?;
+        files.process(commands)?;
+        Ok(SpecContentData {
+            spec: files.spec,
+            notes: files.notes,
+            tasks: files.tasks,
+        })
+    }
+
+    /// Apply `commands`, which must all target `decisions`, to a project's
+    /// decision log, returning the new content
+    ///
+    /// decisions.md takes the same section commands as notes.md.
+    pub fn apply_to_decisions(
+        content: &str,
+        commands: &[EditCommand],
+    ) -> Result<(String, EditCommandsResult)> {
+        if commands.is_empty() {
+            return Err(anyhow!("commands must be a non-empty array"));
+        }
+        if let Some(index) = commands
+            .iter()
+            .position(|command| command.target != EditCommandTarget::Decisions)
+        {
+            return Err(ErrorCode::InvalidParams.error(format!(
+                "Command {} does not target 'decisions'; edit spec files with update_spec",
+                index
+            )));
+        }
+
+        let as_notes: Vec<EditCommand> = commands
+            .iter()
+            .map(|command| EditCommand {
+                target: EditCommandTarget::Notes,
+                ..command.clone()
+            })
+            .collect();
+        let mut decisions = content.to_string();
+        let mut result = Self::process_edit_commands(
+            &as_notes,
+            &mut String::new(),
+            &mut String::new(),
+            &mut decisions,
+            &mut Vec::new(),
         )?;
+        for update in &mut result.file_updates {
+            update.target = EditCommandTarget::Decisions;
+        }
+        for error in &mut result.errors {
+            error.target = EditCommandTarget::Decisions;
+        }
+        result.next_steps =
+            vec!["List the decision log with list_decisions to verify changes".to_string()];
+        result.workflow_hints =
+            vec!["Copy exact decision headings from list_decisions before editing".to_string()];
+        Ok((decisions, result))
+    }
+
+    async fn apply_with_store<S: SpecContentStore>(
+        project_name: &str,
+        spec_name: &str,
+        commands: &[EditCommand],
+        store: &S,
+        atomic: bool,
+    ) -> Result<EditCommandsResult> {
+        if commands.is_empty() {
+            return Err(anyhow!("commands must be a non-empty array"));
+        }
+
+        let original = SpecFiles::read(project_name, spec_name, store).await?;
+        let mut files = original.clone();
+        let result = files.process(commands)?;
+        if atomic && !result.errors.is_empty() {
+            return Ok(result);
+        }
+
+        // Dependencies are validated on write, so store them before touching any file
+        if files.dependencies != original.dependencies {
+            store
+                .write_spec_dependencies(project_name, spec_name, &files.dependencies)
+                .await?;
+        }
 
         // Write back only if modified via SpecContentStore
         if store
-            .is_file_modified(project_name, spec_name, SpecFileType::Spec, &spec_content)
+            .is_file_modified(project_name, spec_name, SpecFileType::Spec, &files.spec)
             .await?
         {
             store
-                .write_spec_file(project_name, spec_name, SpecFileType::Spec, &spec_content)
+                .write_spec_file(project_name, spec_name, SpecFileType::Spec, &files.spec)
                 .await?;
         }
         if store
@@ -64,7 +260,7 @@ impl EditEngine {
                 project_name,
                 spec_name,
                 SpecFileType::TaskList,
-                &tasks_content,
+                &files.tasks,
             )
             .await?
         {
@@ -73,16 +269,16 @@ impl EditEngine {
                     project_name,
                     spec_name,
                     SpecFileType::TaskList,
-                    &tasks_content,
+                    &files.tasks,
                 )
                 .await?;
         }
         if store
-            .is_file_modified(project_name, spec_name, SpecFileType::Notes, &notes_content)
+            .is_file_modified(project_name, spec_name, SpecFileType::Notes, &files.notes)
             .await?
         {
             store
-                .write_spec_file(project_name, spec_name, SpecFileType::Notes, &notes_content)
+                .write_spec_file(project_name, spec_name, SpecFileType::Notes, &files.notes)
                 .await?;
         }
 
@@ -94,6 +290,7 @@ impl EditEngine {
         spec_content: &mut String,
         tasks_content: &mut String,
         notes_content: &mut String,
+        dependencies: &mut Vec<String>,
     ) -> Result<EditCommandsResult> {
         let mut applied_total = 0usize;
         let mut skipped_total = 0usize;
@@ -146,8 +343,9 @@ impl EditEngine {
                             applied_total += applied;
                             skipped_total += skipped;
                         }
-                        Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                             target: EditCommandTarget::Tasks,
+                            code,
                             command_index: idx,
                             message: "Ambiguous or no matching task_text selector".to_string(),
                             candidates: Some(candidates),
@@ -179,8 +377,9 @@ impl EditEngine {
                             applied_total += applied;
                             skipped_total += skipped;
                         }
-                        Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                             target: EditCommandTarget::Tasks,
+                            code,
                             command_index: idx,
                             message: "Ambiguous task_text selector".to_string(),
                             candidates: Some(candidates),
@@ -227,12 +426,13 @@ impl EditEngine {
                             applied_total += applied;
                             skipped_total += skipped;
                         }
-                        Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                             target: if is_spec {
                                 EditCommandTarget::Spec
                             } else {
                                 EditCommandTarget::Notes
                             },
+                            code,
                             command_index: idx,
                             message: "Section not found or ambiguous".to_string(),
                             candidates: Some(candidates),
@@ -242,6 +442,7 @@ impl EditEngine {
                 (EditCommandTarget::Tasks, EditCommandName::AppendToSection, _) => {
                     errors.push(EditCommandError {
                         target: EditCommandTarget::Tasks,
+                        code: ErrorCode::ValidationFailed,
                         command_index: idx,
                         message: "append_to_section is invalid for tasks".to_string(),
                         candidates: None,
@@ -267,8 +468,9 @@ impl EditEngine {
                         applied_total += applied;
                         skipped_total += skipped;
                     }
-                    Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                    Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                         target: EditCommandTarget::Tasks,
+                        code,
                         command_index: idx,
                         message: "List item not found or ambiguous".to_string(),
                         candidates: Some(candidates),
@@ -310,12 +512,13 @@ impl EditEngine {
                             applied_total += applied;
                             skipped_total += skipped;
                         }
-                        Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                             target: if is_spec {
                                 EditCommandTarget::Spec
                             } else {
                                 EditCommandTarget::Notes
                             },
+                            code,
                             command_index: idx,
                             message: "List item not found or ambiguous".to_string(),
                             candidates: Some(candidates),
@@ -362,12 +565,13 @@ impl EditEngine {
                             applied_total += applied;
                             skipped_total += skipped;
                         }
-                        Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                             target: if is_spec {
                                 EditCommandTarget::Spec
                             } else {
                                 EditCommandTarget::Notes
                             },
+                            code,
                             command_index: idx,
                             message: "Section not found or content not found in section"
                                 .to_string(),
@@ -411,12 +615,13 @@ impl EditEngine {
                             applied_total += applied;
                             skipped_total += skipped;
                         }
-                        Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                             target: if is_spec {
                                 EditCommandTarget::Spec
                             } else {
                                 EditCommandTarget::Notes
                             },
+                            code,
                             command_index: idx,
                             message: "Section not found or ambiguous".to_string(),
                             candidates: Some(candidates),
@@ -448,8 +653,9 @@ impl EditEngine {
                             applied_total += applied;
                             skipped_total += skipped;
                         }
-                        Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                             target: EditCommandTarget::Tasks,
+                            code,
                             command_index: idx,
                             message: "List item not found or ambiguous".to_string(),
                             candidates: Some(candidates),
@@ -496,12 +702,13 @@ impl EditEngine {
                             applied_total += applied;
                             skipped_total += skipped;
                         }
-                        Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                             target: if is_spec {
                                 EditCommandTarget::Spec
                             } else {
                                 EditCommandTarget::Notes
                             },
+                            code,
                             command_index: idx,
                             message: "List item not found or ambiguous".to_string(),
                             candidates: Some(candidates),
@@ -548,12 +755,13 @@ impl EditEngine {
                             applied_total += applied;
                             skipped_total += skipped;
                         }
-                        Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                             target: if is_spec {
                                 EditCommandTarget::Spec
                             } else {
                                 EditCommandTarget::Notes
                             },
+                            code,
                             command_index: idx,
                             message: "Section not found or old text not found in section"
                                 .to_string(),
@@ -600,20 +808,215 @@ impl EditEngine {
                             applied_total += applied;
                             skipped_total += skipped;
                         }
-                        Err(EditAmbiguity { candidates }) => errors.push(EditCommandError {
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
                             target: if is_spec {
                                 EditCommandTarget::Spec
                             } else {
                                 EditCommandTarget::Notes
                             },
+                            code,
                             command_index: idx,
                             message: "Section not found or ambiguous".to_string(),
                             candidates: Some(candidates),
                         }),
                     }
                 }
+                (
+                    EditCommandTarget::Spec,
+                    EditCommandName::AddDependency,
+                    EditSelector::SpecName { value },
+                ) => {
+                    let dependency = value.trim();
+                    let (applied, skipped) = if dependencies.iter().any(|d| d == dependency) {
+                        (0, 1)
+                    } else {
+                        dependencies.push(dependency.to_string());
+                        (1, 0)
+                    };
+                    update_counts(
+                        file_updates.as_mut_slice(),
+                        EditCommandTarget::Spec,
+                        applied,
+                        skipped,
+                    );
+                    applied_total += applied;
+                    skipped_total += skipped;
+                }
+                (
+                    EditCommandTarget::Spec,
+                    EditCommandName::RemoveDependency,
+                    EditSelector::SpecName { value },
+                ) => {
+                    let before = dependencies.len();
+                    dependencies.retain(|d| d != value.trim());
+                    let applied = before - dependencies.len();
+                    let skipped = usize::from(applied == 0);
+                    update_counts(
+                        file_updates.as_mut_slice(),
+                        EditCommandTarget::Spec,
+                        applied,
+                        skipped,
+                    );
+                    applied_total += applied;
+                    skipped_total += skipped;
+                }
+                (
+                    EditCommandTarget::Tasks,
+                    EditCommandName::AddPhase,
+                    EditSelector::Section { value },
+                ) => {
+                    let name = value.trim().trim_start_matches('#').trim();
+                    if !tasks::is_phase_heading(name) {
+                        errors.push(EditCommandError {
+                            target: EditCommandTarget::Tasks,
+                            code: ErrorCode::ValidationFailed,
+                            command_index: idx,
+                            message: format!(
+                                "Phase names start with 'Phase', e.g. 'Phase 2: Rollout'; got '{}'",
+                                name
+                            ),
+                            candidates: None,
+                        });
+                        continue;
+                    }
+                    let EditOutcome {
+                        content,
+                        applied,
+                        skipped,
+                    } = add_phase(tasks_content, name, command.content.as_deref());
+                    *tasks_content = content;
+                    update_counts(
+                        file_updates.as_mut_slice(),
+                        EditCommandTarget::Tasks,
+                        applied,
+                        skipped,
+                    );
+                    applied_total += applied;
+                    skipped_total += skipped;
+                }
+                (
+                    EditCommandTarget::Tasks,
+                    EditCommandName::MoveTaskToPhase,
+                    EditSelector::TaskText { value, .. },
+                ) => {
+                    let phase = command.content.clone().ok_or_else(|| {
+                        anyhow!("content (the phase, e.g. \"Phase 2\") is required for move_task_to_phase")
+                    })?;
+                    match move_task_to_phase(tasks_content, value, &phase) {
+                        Ok(EditOutcome {
+                            content,
+                            applied,
+                            skipped,
+                        }) => {
+                            *tasks_content = content;
+                            update_counts(
+                                file_updates.as_mut_slice(),
+                                EditCommandTarget::Tasks,
+                                applied,
+                                skipped,
+                            );
+                            applied_total += applied;
+                            skipped_total += skipped;
+                        }
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
+                            target: EditCommandTarget::Tasks,
+                            code,
+                            command_index: idx,
+                            message: "Task or phase not found or ambiguous".to_string(),
+                            candidates: Some(candidates),
+                        }),
+                    }
+                }
+                (
+                    EditCommandTarget::Tasks,
+                    EditCommandName::SetTasksStatusMatching | EditCommandName::CompletePhase,
+                    EditSelector::TaskPattern { .. } | EditSelector::Section { .. },
+                ) => {
+                    let complete_phase = matches!(command.command, EditCommandName::CompletePhase);
+                    let status = match (&command.status, complete_phase) {
+                        (Some(status), _) => status.clone(),
+                        (None, true) => TaskStatus::Done,
+                        (None, false) => {
+                            return Err(anyhow!(
+                                "status is required for set_tasks_status_matching"
+                            ));
+                        }
+                    };
+                    let (section, pattern) = match &command.selector {
+                        EditSelector::TaskPattern { value, section } if !complete_phase => {
+                            (section.as_deref(), Some(value.as_str()))
+                        }
+                        EditSelector::Section { value } => (Some(value.as_str()), None),
+                        _ => {
+                            errors.push(EditCommandError {
+                                target: EditCommandTarget::Tasks,
+                                code: ErrorCode::ValidationFailed,
+                                command_index: idx,
+                                message: "complete_phase takes a section selector naming the phase"
+                                    .to_string(),
+                                candidates: None,
+                            });
+                            continue;
+                        }
+                    };
+                    let pattern = match pattern.map(task_pattern).transpose() {
+                        Ok(pattern) => pattern,
+                        Err(message) => {
+                            errors.push(EditCommandError {
+                                target: EditCommandTarget::Tasks,
+                                code: ErrorCode::ValidationFailed,
+                                command_index: idx,
+                                message,
+                                candidates: None,
+                            });
+                            continue;
+                        }
+                    };
+                    let scope = TaskScope {
+                        section,
+                        phases_only: complete_phase,
+                        pattern: pattern.as_ref(),
+                    };
+                    match set_tasks_status_matching(tasks_content, &scope, status) {
+                        Ok(EditOutcome {
+                            content,
+                            applied,
+                            skipped,
+                        }) => {
+                            *tasks_content = content;
+                            update_counts(
+                                file_updates.as_mut_slice(),
+                                EditCommandTarget::Tasks,
+                                applied,
+                                skipped,
+                            );
+                            applied_total += applied;
+                            skipped_total += skipped;
+                        }
+                        Err(EditAmbiguity { code, candidates }) => errors.push(EditCommandError {
+                            target: EditCommandTarget::Tasks,
+                            code,
+                            command_index: idx,
+                            message: if code == ErrorCode::SelectorAmbiguous {
+                                "Section name matches more than one heading".to_string()
+                            } else {
+                                "No tasks match the pattern and section".to_string()
+                            },
+                            candidates: Some(candidates),
+                        }),
+                    }
+                }
+                (EditCommandTarget::Decisions, _, _) => errors.push(EditCommandError {
+                    target: EditCommandTarget::Decisions,
+                    code: ErrorCode::ValidationFailed,
+                    command_index: idx,
+                    message: "decisions.md belongs to the project; edit it with update_decisions"
+                        .to_string(),
+                    candidates: None,
+                }),
                 _ => errors.push(EditCommandError {
                     target: command.target.clone(),
+                    code: ErrorCode::ValidationFailed,
                     command_index: idx,
                     message: "Unsupported command/selector combination".to_string(),
                     candidates: None,
@@ -636,6 +1039,7 @@ impl EditEngine {
                 "Always copy exact task text and headers from load_spec before editing".to_string(),
             ],
             preview_diff: None,
+            command_diffs: Vec::new(),
         })
     }
 }
@@ -647,6 +1051,7 @@ struct EditOutcome {
 }
 
 struct EditAmbiguity {
+    code: ErrorCode,
     candidates: Vec<SelectorCandidate>,
 }
 
@@ -718,26 +1123,17 @@ fn set_task_status(
         TaskStatus::Done => "- [x] ",
         TaskStatus::Todo => "- [ ] ",
     };
-    let wanted_norm = normalize_task_text(task_text);
     let mut lines: Vec<String> = current.lines().map(|l| l.to_string()).collect();
-    let match_indices: Vec<usize> = lines
-        .iter()
-        .enumerate()
-        .filter_map(|(i, line)| {
-            if line.trim_start().starts_with("- [") && normalize_task_text(line) == wanted_norm {
-                Some(i)
-            } else {
-                None
-            }
-        })
-        .collect();
+    let match_indices = matching_task_lines(&lines, task_text, true);
     if match_indices.is_empty() {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorNotFound,
             candidates: task_candidates(current),
         });
     }
     if match_indices.len() > 1 {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorAmbiguous,
             candidates: task_candidates(current),
         });
     }
@@ -751,7 +1147,11 @@ fn set_task_status(
         });
     }
     let normalized = normalize_task_text(&lines[idx]);
-    lines[idx] = format!("{}{}", desired_prefix, normalized);
+    lines[idx] = format!(
+        "{}{}",
+        desired_prefix,
+        tasks::with_completion(&normalized, status == TaskStatus::Done, today())
+    );
     Ok(EditOutcome {
         content: lines.join("\n"),
         applied: 1,
@@ -759,33 +1159,59 @@ fn set_task_status(
     })
 }
 
+/// Add `new_task_line` unless a task matching `task_text` exists
+///
+/// An existing checkbox task takes the metadata tokens (`@owner`, `~3d`,
+/// `due:`) of `new_task_line` when they differ, keeping its status and text.
 fn upsert_task(
     current: &str,
     task_text: &str,
     new_task_line: &str,
 ) -> Result<EditOutcome, EditAmbiguity> {
-    let wanted_norm = normalize_task_text(task_text);
-    let matches = current
-        .lines()
-        .filter(|line| normalize_task_text(line) == wanted_norm)
-        .count();
-    if matches > 1 {
+    let mut lines: Vec<String> = current.lines().map(|l| l.to_string()).collect();
+    let matches = matching_task_lines(&lines, task_text, false);
+    if matches.len() > 1 {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorAmbiguous,
             candidates: task_candidates(current),
         });
     }
-    if matches == 1 {
+    if let [idx] = matches[..] {
+        let wanted = tasks::parse_metadata(new_task_line);
+        let is_task = lines[idx].trim_start().starts_with("- [");
+        if !is_task || wanted.is_empty() || wanted == tasks::parse_metadata(&lines[idx]) {
+            return Ok(EditOutcome {
+                content: current.to_string(),
+                applied: 0,
+                skipped: 1,
+            });
+        }
+        lines[idx] = with_metadata(&lines[idx], &wanted);
         return Ok(EditOutcome {
-            content: current.to_string(),
-            applied: 0,
-            skipped: 1,
+            content: lines.join("\n"),
+            applied: 1,
+            skipped: 0,
         });
     }
     let mut content = current.to_string();
     if !content.is_empty() && !content.ends_with('\n') {
         content.push('\n');
     }
-    content.push_str(new_task_line);
+    let trimmed = new_task_line.trim_start();
+    match ["- [x] ", "- [X] "]
+        .iter()
+        .find_map(|checkbox| trimmed.strip_prefix(checkbox))
+    {
+        Some(text) => {
+            let indent = &new_task_line[..new_task_line.len() - trimmed.len()];
+            content.push_str(&format!(
+                "{}- [x] {}",
+                indent,
+                tasks::with_completion(text, true, today())
+            ));
+        }
+        None => content.push_str(new_task_line),
+    }
     Ok(EditOutcome {
         content,
         applied: 1,
@@ -793,6 +1219,294 @@ fn upsert_task(
     })
 }
 
+/// Append a `name` phase heading, followed by `tasks` when given, unless the
+/// phase exists; the heading level follows existing phases (`##` otherwise)
+fn add_phase(current: &str, name: &str, tasks: Option<&str>) -> EditOutcome {
+    let existing: Vec<(usize, &str)> = current
+        .lines()
+        .filter_map(tasks::heading)
+        .filter(|(_, text)| tasks::is_phase_heading(text))
+        .collect();
+    if existing
+        .iter()
+        .any(|(_, phase)| tasks::phase_matches(phase, name))
+    {
+        return EditOutcome {
+            content: current.to_string(),
+            applied: 0,
+            skipped: 1,
+        };
+    }
+    let level = existing.first().map_or(2, |(level, _)| *level);
+    let mut content = current.trim_end().to_string();
+    if !content.is_empty() {
+        content.push_str("\n\n");
+    }
+    content.push_str(&format!("{} {}", "#".repeat(level), name));
+    if let Some(tasks) = tasks.map(str::trim).filter(|tasks| !tasks.is_empty()) {
+        content.push('\n');
+        content.push_str(tasks);
+    }
+    EditOutcome {
+        content,
+        applied: 1,
+        skipped: 0,
+    }
+}
+
+/// Move the checkbox task matching `task_text` to the end of `phase`
+fn move_task_to_phase(
+    current: &str,
+    task_text: &str,
+    phase: &str,
+) -> Result<EditOutcome, EditAmbiguity> {
+    let mut lines: Vec<String> = current.lines().map(|l| l.to_string()).collect();
+    let matches = matching_task_lines(&lines, task_text, true);
+    if matches.len() != 1 {
+        return Err(EditAmbiguity {
+            code: if matches.is_empty() {
+                ErrorCode::SelectorNotFound
+            } else {
+                ErrorCode::SelectorAmbiguous
+            },
+            candidates: task_candidates(current),
+        });
+    }
+    let task_idx = matches[0];
+    let (start, end) = phase_range(&lines, phase, current)?;
+    if (start + 1..end).contains(&task_idx) {
+        return Ok(EditOutcome {
+            content: current.to_string(),
+            applied: 0,
+            skipped: 1,
+        });
+    }
+    let task_line = lines.remove(task_idx).trim_start().to_string();
+    let (start, end) = phase_range(&lines, phase, current)?;
+    let mut at = end;
+    while at > start + 1 && lines[at - 1].trim().is_empty() {
+        at -= 1;
+    }
+    lines.insert(at, task_line);
+    Ok(EditOutcome {
+        content: lines.join("\n"),
+        applied: 1,
+        skipped: 0,
+    })
+}
+
+/// Line range of `phase`: its heading up to the next heading of the same or a
+/// higher level
+fn phase_range(
+    lines: &[String],
+    phase: &str,
+    current: &str,
+) -> Result<(usize, usize), EditAmbiguity> {
+    section_range(lines, phase, current, true)
+}
+
+/// Line range of the heading named `section`, like [`phase_range`] but for any
+/// heading unless `phases_only`
+fn section_range(
+    lines: &[String],
+    section: &str,
+    current: &str,
+    phases_only: bool,
+) -> Result<(usize, usize), EditAmbiguity> {
+    let headings: Vec<(usize, usize, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| tasks::heading(line).map(|(level, text)| (i, level, text)))
+        .collect();
+    let found: Vec<&(usize, usize, &str)> = headings
+        .iter()
+        .filter(|(_, _, text)| {
+            (!phases_only || tasks::is_phase_heading(text)) && tasks::phase_matches(text, section)
+        })
+        .collect();
+    let [&(start, level, _)] = found[..] else {
+        return Err(EditAmbiguity {
+            code: if found.is_empty() {
+                ErrorCode::SelectorNotFound
+            } else {
+                ErrorCode::SelectorAmbiguous
+            },
+            candidates: heading_candidates(current, phases_only),
+        });
+    };
+    let end = headings
+        .iter()
+        .find(|(i, next_level, _)| *i > start && *next_level <= level)
+        .map_or(lines.len(), |(i, _, _)| *i);
+    Ok((start, end))
+}
+
+fn heading_candidates(current: &str, phases_only: bool) -> Vec<SelectorCandidate> {
+    current
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| {
+            tasks::heading(l).is_some_and(|(_, text)| !phases_only || tasks::is_phase_heading(text))
+        })
+        .map(|(i, l)| SelectorCandidate {
+            selector_suggestion: EditSelector::Section {
+                value: l.trim().to_string(),
+            },
+            preview: preview_excerpt(current, i),
+        })
+        .collect()
+}
+
+/// Which tasks `set_tasks_status_matching` and `complete_phase` change
+struct TaskScope<'a> {
+    /// Heading the tasks must be under; every task when `None`
+    section: Option<&'a str>,
+    /// Only phase headings count as `section`
+    phases_only: bool,
+    /// Pattern the task text must match; every task when `None`
+    pattern: Option<&'a Regex>,
+}
+
+/// A task pattern: `/regex/`, or a glob (`*`, `?`) matching the whole task
+/// text, ignoring case
+fn task_pattern(pattern: &str) -> Result<Regex, String> {
+    let source = pattern
+        .strip_prefix('/')
+        .and_then(|rest| rest.strip_suffix('/'))
+        .map_or_else(
+            || {
+                let glob: String = task_title(pattern)
+                    .chars()
+                    .map(|c| match c {
+                        '*' => ".*".to_string(),
+                        '?' => ".".to_string(),
+                        c => regex::escape(&c.to_string()),
+                    })
+                    .collect();
+                format!("(?i)^{}$", glob)
+            },
+            str::to_string,
+        );
+    Regex::new(&source).map_err(|error| format!("Invalid task pattern '{}': {}", pattern, error))
+}
+
+/// Set every task in `scope` to `status`, keeping indentation and metadata
+fn set_tasks_status_matching(
+    current: &str,
+    scope: &TaskScope,
+    status: TaskStatus,
+) -> Result<EditOutcome, EditAmbiguity> {
+    let checkbox = match &status {
+        TaskStatus::Done => "- [x]",
+        TaskStatus::Todo => "- [ ]",
+    };
+    let mut lines: Vec<String> = current.lines().map(|l| l.to_string()).collect();
+    let (start, end) = match scope.section {
+        Some(section) => section_range(&lines, section, current, scope.phases_only)?,
+        None => (0, lines.len()),
+    };
+    let matched: Vec<usize> = (start..end)
+        .filter(|&i| {
+            let trimmed = lines[i].trim_start();
+            ["- [ ]", "- [x]", "- [X]"]
+                .iter()
+                .any(|box_| trimmed.starts_with(box_))
+                && scope
+                    .pattern
+                    .is_none_or(|pattern| pattern.is_match(&task_title(&lines[i])))
+        })
+        .collect();
+    if matched.is_empty() {
+        return Err(EditAmbiguity {
+            code: ErrorCode::SelectorNotFound,
+            candidates: task_candidates(current),
+        });
+    }
+    let mut applied = 0;
+    for i in matched {
+        let indent = lines[i].len() - lines[i].trim_start().len();
+        let done = !lines[i][indent..].starts_with("- [ ]");
+        if done == (status == TaskStatus::Done) {
+            continue;
+        }
+        lines[i] = format!(
+            "{}{} {}",
+            &lines[i][..indent],
+            checkbox,
+            tasks::with_completion(
+                lines[i][indent + 5..].trim_start(),
+                status == TaskStatus::Done,
+                today()
+            )
+        );
+        applied += 1;
+    }
+    if applied == 0 {
+        return Ok(EditOutcome {
+            content: current.to_string(),
+            applied: 0,
+            skipped: 1,
+        });
+    }
+    Ok(EditOutcome {
+        content: lines.join("\n"),
+        applied,
+        skipped: 0,
+    })
+}
+
+/// Indices of lines matching `task_text`: exact matches when there are any,
+/// otherwise lines with the same text once metadata tokens are ignored
+fn matching_task_lines(lines: &[String], task_text: &str, checkbox_only: bool) -> Vec<usize> {
+    let candidates = || {
+        lines
+            .iter()
+            .enumerate()
+            .filter(move |(_, line)| !checkbox_only || line.trim_start().starts_with("- ["))
+    };
+    let wanted_norm = normalize_task_text(task_text);
+    let exact: Vec<usize> = candidates()
+        .filter(|(_, line)| normalize_task_text(line) == wanted_norm)
+        .map(|(i, _)| i)
+        .collect();
+    let wanted_title = task_title(task_text);
+    if !exact.is_empty() || wanted_title.is_empty() {
+        return exact;
+    }
+    candidates()
+        .filter(|(_, line)| task_title(line) == wanted_title)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Date stamped into `completed:` tokens
+fn today() -> chrono::NaiveDate {
+    chrono::Utc::now().date_naive()
+}
+
+/// Normalized task text without metadata tokens
+fn task_title(line: &str) -> String {
+    let title = tasks::strip_metadata(&normalize_task_text(line));
+    title.strip_suffix('.').unwrap_or(&title).to_string()
+}
+
+/// Checkbox `line` with its metadata tokens replaced by `metadata`
+///
+/// Indentation, status and any `completed:` token are kept.
+fn with_metadata(line: &str, metadata: &TaskMetadata) -> String {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    let checkbox = if trimmed.starts_with("- [ ]") {
+        "- [ ] "
+    } else {
+        "- [x] "
+    };
+    let mut words = vec![tasks::strip_metadata(&normalize_task_text(line))];
+    words.extend(metadata.tokens());
+    words.extend(tasks::completed_token(line).map(str::to_string));
+    format!("{}{}{}", indent, checkbox, words.join(" "))
+}
+
 fn append_to_section(
     current: &str,
     header: &str,
@@ -813,11 +1527,13 @@ fn append_to_section(
         .collect();
     if header_indices.is_empty() {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorNotFound,
             candidates: header_candidates(current),
         });
     }
     if header_indices.len() > 1 {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorAmbiguous,
             candidates: header_candidates(current),
         });
     }
@@ -918,11 +1634,13 @@ fn remove_list_item(current: &str, item_text: &str) -> Result<EditOutcome, EditA
 
     if match_indices.is_empty() {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorNotFound,
             candidates: list_item_candidates(current),
         });
     }
     if match_indices.len() > 1 {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorAmbiguous,
             candidates: list_item_candidates(current),
         });
     }
@@ -960,11 +1678,13 @@ fn remove_from_section(
 
     if header_indices.is_empty() {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorNotFound,
             candidates: header_candidates(current),
         });
     }
     if header_indices.len() > 1 {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorAmbiguous,
             candidates: header_candidates(current),
         });
     }
@@ -1033,11 +1753,13 @@ fn remove_section(current: &str, section_header: &str) -> Result<EditOutcome, Ed
 
     if header_indices.is_empty() {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorNotFound,
             candidates: header_candidates(current),
         });
     }
     if header_indices.len() > 1 {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorAmbiguous,
             candidates: header_candidates(current),
         });
     }
@@ -1108,11 +1830,13 @@ fn replace_list_item(
 
     if match_indices.is_empty() {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorNotFound,
             candidates: list_item_candidates(current),
         });
     }
     if match_indices.len() > 1 {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorAmbiguous,
             candidates: list_item_candidates(current),
         });
     }
@@ -1202,11 +1926,13 @@ fn replace_in_section(
 
     if header_indices.is_empty() {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorNotFound,
             candidates: header_candidates(current),
         });
     }
     if header_indices.len() > 1 {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorAmbiguous,
             candidates: header_candidates(current),
         });
     }
@@ -1224,6 +1950,7 @@ fn replace_in_section(
     let section_content = lines[(start_idx + 1)..end_idx].join("\n");
     if !section_content.contains(old_text) {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorNotFound,
             candidates: header_candidates(current),
         });
     }
@@ -1278,11 +2005,13 @@ fn replace_section_content(
 
     if header_indices.is_empty() {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorNotFound,
             candidates: header_candidates(current),
         });
     }
     if header_indices.len() > 1 {
         return Err(EditAmbiguity {
+            code: ErrorCode::SelectorAmbiguous,
             candidates: header_candidates(current),
         });
     }
@@ -0,0 +1,509 @@
+//! Encryption at rest for project and spec content
+//!
+//! Off unless `~/.foundry/config.toml` names a key:
+//!
+//! ```toml
+//! [encryption]
+//! key_file = "~/.config/foundry/key"     # 32-byte key in hex, see `foundry encryption keygen`
+//! # or
+//! passphrase_env = "FOUNDRY_PASSPHRASE"  # key derived from the passphrase in this variable
+//! ```
+//!
+//! With a key configured, project files, spec files and backups are written
+//! as ChaCha20-Poly1305 ciphertext and decrypted transparently by
+//! [`crate::core::filesystem::read_file`]. Each write uses a fresh nonce and
+//! the file's path below its project or journal directory as associated data
+//! (see [`associated_data`]), so ciphertext copied onto another spec's or
+//! project's file fails to decrypt. Files written before encryption was turned
+//! on, or by `v1` (which bound only the file name), stay readable;
+//! `foundry encryption encrypt` converts them.
+
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::{Context, Result};
+use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::core::config;
+use crate::types::errors::ErrorCode;
+
+/// Prefix of every encrypted file; the rest is hex of nonce + ciphertext + tag
+pub const HEADER: &str = "foundry-encrypted:v2:";
+
+/// Prefix of files encrypted with only their file name as associated data
+const LEGACY_HEADER: &str = "foundry-encrypted:v1:";
+
+/// Key length in bytes
+pub const KEY_LEN: usize = 32;
+
+/// Salt for passphrase-derived keys, created next to the projects on first use
+pub const SALT_FILE: &str = ".encryption-salt";
+
+/// Files holding project or spec content
+pub const CONTENT_FILES: &[&str] = &[
+    "vision.md",
+    "tech-stack.md",
+    "summary.md",
+    "decisions.md",
+    "spec.md",
+    "notes.md",
+    "task-list.md",
+    "summary.json",
+];
+
+const PBKDF2_ITERATIONS: u32 = 100_000;
+
+/// A content encryption key
+pub type Key = [u8; KEY_LEN];
+
+/// Keys derived from passphrases, by passphrase and salt, so each is derived once
+static DERIVED_KEYS: OnceLock<Mutex<HashMap<(String, String), Key>>> = OnceLock::new();
+
+/// Whether `content` was written encrypted
+pub fn is_encrypted(content: &str) -> bool {
+    content.starts_with(HEADER) || content.starts_with(LEGACY_HEADER)
+}
+
+/// Encrypt `plaintext` for the file at `path`
+pub fn encrypt(key: &Key, path: &Path, plaintext: &str) -> Result<String> {
+    let associated_data = associated_data(path);
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| anyhow::anyhow!("Failed to generate a nonce"))?;
+    let mut sealed = plaintext.as_bytes().to_vec();
+    cipher(key)?
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(associated_data.as_bytes()),
+            &mut sealed,
+        )
+        .map_err(|_| anyhow::anyhow!("Failed to encrypt {:?}", path))?;
+    Ok(format!("{}{}{}", HEADER, to_hex(&nonce), to_hex(&sealed)))
+}
+
+/// Decrypt `content` written by [`encrypt`] for the file at `path`
+pub fn decrypt(key: &Key, path: &Path, content: &str) -> Result<String> {
+    let (hex, associated_data) = if content.starts_with(HEADER) {
+        (content.strip_prefix(HEADER), associated_data(path))
+    } else {
+        (content.strip_prefix(LEGACY_HEADER), file_name(path))
+    };
+    let bytes = hex
+        .and_then(|hex| from_hex(hex.trim_end()))
+        .filter(|bytes| bytes.len() >= NONCE_LEN)
+        .ok_or_else(|| {
+            ErrorCode::ValidationFailed.error(format!("{:?} is not a valid encrypted file", path))
+        })?;
+    let (nonce, sealed) = bytes.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| anyhow::anyhow!("Invalid nonce in {:?}", path))?;
+    let mut sealed = sealed.to_vec();
+    let plaintext = cipher(key)?
+        .open_in_place(nonce, Aad::from(associated_data.as_bytes()), &mut sealed)
+        .map_err(|_| {
+            ErrorCode::ValidationFailed.error(format!(
+                "Could not decrypt {:?}: wrong key, corrupted file, or content copied from another file",
+                path
+            ))
+        })?;
+    String::from_utf8(plaintext.to_vec())
+        .with_context(|| format!("Decrypted {:?} is not UTF-8", path))
+}
+
+fn cipher(key: &Key) -> Result<LessSafeKey> {
+    UnboundKey::new(&CHACHA20_POLY1305, key)
+        .map(LessSafeKey::new)
+        .map_err(|_| anyhow::anyhow!("Invalid encryption key"))
+}
+
+/// The key from `[encryption]` in the global config; `None` when encryption is off
+pub fn configured_key() -> Result<Option<Key>> {
+    let encryption = config::global()?.encryption;
+    if let Some(path) = &encryption.key_file {
+        return read_key_file(path).map(Some);
+    }
+    let Some(var) = &encryption.passphrase_env else {
+        return Ok(None);
+    };
+    let passphrase = std::env::var(var)
+        .ok()
+        .filter(|p| !p.is_empty())
+        .ok_or_else(|| {
+            ErrorCode::InvalidParams.error(format!(
+                "Encryption uses passphrase_env = \"{}\", but ${} is not set",
+                var, var
+            ))
+        })?;
+    passphrase_key(&passphrase).map(Some)
+}
+
+/// Read a key file holding the key in hex
+pub fn read_key_file(path: &Path) -> Result<Key> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read encryption key file {:?}", path))?;
+    from_hex(content.trim())
+        .and_then(|bytes| Key::try_from(bytes).ok())
+        .ok_or_else(|| {
+            ErrorCode::ValidationFailed.error(format!(
+                "Encryption key file {:?} must hold {} bytes in hex",
+                path, KEY_LEN
+            ))
+        })
+}
+
+/// Write a new random key to `path`, readable only by the owner
+pub fn generate_key_file(path: &Path) -> Result<PathBuf> {
+    if path.exists() {
+        return Err(ErrorCode::AlreadyExists.error(format!(
+            "{:?} already exists; keys are never overwritten",
+            path
+        )));
+    }
+    let mut key = [0u8; KEY_LEN];
+    SystemRandom::new()
+        .fill(&mut key)
+        .map_err(|_| anyhow::anyhow!("Failed to generate a key"))?;
+    crate::core::filesystem::write_file_atomic(path, &format!("{}\n", to_hex(&key)))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+            .with_context(|| format!("Failed to restrict permissions of {:?}", path))?;
+    }
+    Ok(path.to_path_buf())
+}
+
+/// Key derived from `passphrase` with PBKDF2-HMAC-SHA256 and the workspace salt
+fn passphrase_key(passphrase: &str) -> Result<Key> {
+    let salt = salt()?;
+    let cache = DERIVED_KEYS.get_or_init(Default::default);
+    let cache_key = (passphrase.to_string(), salt.clone());
+    if let Some(key) = cache.lock().unwrap().get(&cache_key) {
+        return Ok(*key);
+    }
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        NonZeroU32::new(PBKDF2_ITERATIONS).expect("iterations are non-zero"),
+        salt.as_bytes(),
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    cache.lock().unwrap().insert(cache_key, key);
+    Ok(key)
+}
+
+fn salt() -> Result<String> {
+    let path = crate::core::filesystem::foundry_dir()?.join(SALT_FILE);
+    if path.exists() {
+        return Ok(std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {:?}", path))?
+            .trim()
+            .to_string());
+    }
+    let mut salt = [0u8; 16];
+    SystemRandom::new()
+        .fill(&mut salt)
+        .map_err(|_| anyhow::anyhow!("Failed to generate a salt"))?;
+    let salt = to_hex(&salt);
+    crate::core::filesystem::write_file_atomic(&path, &salt)?;
+    Ok(salt)
+}
+
+/// Associated data `path` is encrypted under: its path below the journal or
+/// project it belongs to, e.g. `.history/app.json`, `app/vision.md` or
+/// `app/specs/20250101_000000_login/spec.md`
+///
+/// Paths are relative so a storage directory can be moved, but name the
+/// project and spec, so renaming a spec re-encrypts its files
+/// ([`reencrypt_moved`]).
+pub fn associated_data(path: &Path) -> String {
+    let parts: Vec<String> = path
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    let start = match parts
+        .iter()
+        .rposition(|part| part == ".logs" || journal_extension_of(part).is_some())
+    {
+        Some(journal) => journal,
+        None if parts.len() >= 4 && parts[parts.len() - 3] == "specs" => parts.len() - 4,
+        None => parts.len().saturating_sub(2),
+    };
+    parts[start..].join("/")
+}
+
+/// Re-encrypt the encrypted content files that moved from below `from` to
+/// below `to` for their new paths
+pub fn reencrypt_moved(from: &Path, to: &Path) -> Result<()> {
+    let mut key = None;
+    for path in content_files(to)? {
+        let content = read_raw(&path)?;
+        if !is_encrypted(&content) {
+            continue;
+        }
+        let key = match key {
+            Some(key) => key,
+            None => *key.insert(configured_key()?.ok_or_else(|| {
+                ErrorCode::InvalidParams.error(format!(
+                    "{:?} is encrypted but no key is configured. Set [encryption] in config.toml",
+                    path
+                ))
+            })?),
+        };
+        let old_path = from.join(path.strip_prefix(to)?);
+        let plaintext = decrypt(&key, &old_path, &content)?;
+        crate::core::filesystem::write_file_atomic(&path, &encrypt(&key, &path, &plaintext)?)?;
+    }
+    Ok(())
+}
+
+/// Final component of `path`
+pub fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Every file under `root` that holds content, a backup snapshot or a journal
+/// (update history, activity, audit log)
+pub fn content_files(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![(root.to_path_buf(), None)];
+    while let Some((dir, journal_extension)) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries {
+            let path = entry?.path();
+            let name = file_name(&path);
+            if path.is_dir() {
+                if !matches!(name.as_str(), ".git" | ".templates" | ".locales" | ".logs") {
+                    pending.push((path, journal_extension.or(journal_extension_of(&name))));
+                }
+            } else if CONTENT_FILES.contains(&name.as_str())
+                || journal_extension
+                    .is_some_and(|extension| path.extension().is_some_and(|ext| ext == extension))
+            {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Extension of the files kept below the storage directory `name`, if it
+/// holds backups or journals
+fn journal_extension_of(name: &str) -> Option<&'static str> {
+    use crate::core::{activity, audit, backup, history};
+    match name {
+        backup::BACKUP_DIR_NAME | history::HISTORY_DIR_NAME | activity::ACTIVITY_DIR_NAME => {
+            Some("json")
+        }
+        audit::AUDIT_DIR_NAME => Some("jsonl"),
+        _ => None,
+    }
+}
+
+/// Encrypt every plain content file under `root` with `key`, and re-encrypt
+/// files written by `v1`; returns the files changed
+pub fn encrypt_all(root: &Path, key: &Key) -> Result<Vec<PathBuf>> {
+    convert_all(root, |path, content| {
+        if content.starts_with(HEADER) {
+            return Ok(None);
+        }
+        let plaintext = if is_encrypted(content) {
+            decrypt(key, path, content)?
+        } else {
+            content.to_string()
+        };
+        encrypt(key, path, &plaintext).map(Some)
+    })
+}
+
+/// Decrypt every encrypted content file under `root` with `key`; returns the files changed
+pub fn decrypt_all(root: &Path, key: &Key) -> Result<Vec<PathBuf>> {
+    convert_all(root, |path, content| {
+        if !is_encrypted(content) {
+            return Ok(None);
+        }
+        decrypt(key, path, content).map(Some)
+    })
+}
+
+/// Rewrite every content file under `root` that `convert` changes
+///
+/// Audit logs (`.jsonl`) are converted line by line, since each line is
+/// encrypted on its own so the log stays appendable.
+fn convert_all(
+    root: &Path,
+    convert: impl Fn(&Path, &str) -> Result<Option<String>>,
+) -> Result<Vec<PathBuf>> {
+    let mut changed = Vec::new();
+    for path in content_files(root)? {
+        let content = read_raw(&path)?;
+        let converted = if path.extension().is_some_and(|ext| ext == "jsonl") {
+            let mut any_changed = false;
+            let mut lines = String::new();
+            for line in content.lines().filter(|line| !line.trim().is_empty()) {
+                match convert(&path, line)? {
+                    Some(line) => {
+                        any_changed = true;
+                        lines.push_str(&line);
+                    }
+                    None => lines.push_str(line),
+                }
+                lines.push('\n');
+            }
+            any_changed.then_some(lines)
+        } else {
+            convert(&path, &content)?
+        };
+        if let Some(converted) = converted {
+            crate::core::filesystem::write_file_atomic(&path, &converted)?;
+            changed.push(path);
+        }
+    }
+    Ok(changed)
+}
+
+fn read_raw(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_and_tamper_detection() {
+        let key = [7u8; KEY_LEN];
+        let spec = Path::new("/home/me/.foundry/app/specs/20250101_000000_login/spec.md");
+        let encrypted = encrypt(&key, spec, "# Secret plan").unwrap();
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.contains("Secret"));
+        assert_eq!(decrypt(&key, spec, &encrypted).unwrap(), "# Secret plan");
+
+        // Same content encrypts differently each time
+        assert_ne!(encrypt(&key, spec, "# Secret plan").unwrap(), encrypted);
+        // The storage directory may move
+        let moved = Path::new("/srv/foundry/app/specs/20250101_000000_login/spec.md");
+        assert_eq!(decrypt(&key, moved, &encrypted).unwrap(), "# Secret plan");
+        // Wrong key, another spec's or project's file, or altered bytes fail
+        assert!(decrypt(&[8u8; KEY_LEN], spec, &encrypted).is_err());
+        for other in [
+            "/home/me/.foundry/app/specs/20250101_000000_login/notes.md",
+            "/home/me/.foundry/app/specs/20250101_000000_signup/spec.md",
+            "/home/me/.foundry/other/specs/20250101_000000_login/spec.md",
+        ] {
+            assert!(decrypt(&key, Path::new(other), &encrypted).is_err());
+        }
+        let mut tampered = encrypted;
+        tampered.replace_range(tampered.len() - 2.., "00");
+        assert!(decrypt(&key, spec, &tampered).is_err());
+    }
+
+    #[test]
+    fn test_associated_data_names_project_spec_and_journal() {
+        for (path, expected) in [
+            ("/f/app/vision.md", "app/vision.md"),
+            (
+                "/f/app/specs/20250101_000000_login/spec.md",
+                "app/specs/20250101_000000_login/spec.md",
+            ),
+            (
+                "/f/.history/app/20250101_000000_login.json",
+                ".history/app/20250101_000000_login.json",
+            ),
+            ("/f/.audit/app.jsonl", ".audit/app.jsonl"),
+            (
+                "/f/.backups/app/20250101_000000_login/1-delete.json",
+                ".backups/app/20250101_000000_login/1-delete.json",
+            ),
+            ("/f/.logs/wire.jsonl", ".logs/wire.jsonl"),
+        ] {
+            assert_eq!(associated_data(Path::new(path)), expected);
+        }
+    }
+
+    #[test]
+    fn test_legacy_files_decrypt_with_their_file_name() {
+        let key = [5u8; KEY_LEN];
+        let path = Path::new("/f/app/vision.md");
+        let nonce = [1u8; NONCE_LEN];
+        let mut sealed = b"Vision".to_vec();
+        cipher(&key)
+            .unwrap()
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(b"vision.md"),
+                &mut sealed,
+            )
+            .unwrap();
+        let legacy = format!("{}{}{}", LEGACY_HEADER, to_hex(&nonce), to_hex(&sealed));
+        assert!(is_encrypted(&legacy));
+        assert_eq!(decrypt(&key, path, &legacy).unwrap(), "Vision");
+
+        // `encrypt_all` re-encrypts them bound to their path
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("app")).unwrap();
+        let path = dir.path().join("app/vision.md");
+        std::fs::write(&path, &legacy).unwrap();
+        assert_eq!(encrypt_all(dir.path(), &key).unwrap().len(), 1);
+        let upgraded = read_raw(&path).unwrap();
+        assert!(upgraded.starts_with(HEADER));
+        assert_eq!(decrypt(&key, &path, &upgraded).unwrap(), "Vision");
+    }
+
+    #[test]
+    fn test_key_file_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("key");
+        generate_key_file(&path).unwrap();
+        assert_eq!(read_key_file(&path).unwrap().len(), KEY_LEN);
+        assert!(generate_key_file(&path).is_err());
+
+        std::fs::write(&path, "not hex").unwrap();
+        assert!(read_key_file(&path).is_err());
+    }
+
+    #[test]
+    fn test_encrypt_all_and_decrypt_all_convert_content_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let spec_dir = dir.path().join("app/specs/20250101_000000_login");
+        std::fs::create_dir_all(&spec_dir).unwrap();
+        std::fs::write(spec_dir.join("spec.md"), "# Login").unwrap();
+        std::fs::write(spec_dir.join("metadata.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("app/vision.md"), "Vision").unwrap();
+        let key = [3u8; KEY_LEN];
+
+        assert_eq!(encrypt_all(dir.path(), &key).unwrap().len(), 2);
+        assert!(encrypt_all(dir.path(), &key).unwrap().is_empty());
+        assert!(is_encrypted(&read_raw(&spec_dir.join("spec.md")).unwrap()));
+        // Spec metadata (status, tags, dependencies) is not content and stays plain
+        assert_eq!(read_raw(&spec_dir.join("metadata.json")).unwrap(), "{}");
+
+        assert_eq!(decrypt_all(dir.path(), &key).unwrap().len(), 2);
+        assert_eq!(read_raw(&spec_dir.join("spec.md")).unwrap(), "# Login");
+    }
+}
@@ -4,11 +4,15 @@ use anyhow::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
 
-/// Ensure the foundry directory exists
+use crate::core::encryption;
+use crate::types::errors::ErrorCode;
+
+/// Ensure the foundry directory of the current workspace exists
+///
+/// `~/.foundry` unless another workspace is in effect (see
+/// [`crate::core::workspace`]).
 pub fn ensure_foundry_dir() -> Result<PathBuf> {
-    let foundry_dir = dirs::home_dir()
-        .context("Could not determine home directory")?
-        .join(".foundry");
+    let foundry_dir = crate::core::workspace::current_dir()?;
 
     if !foundry_dir.exists() {
         fs::create_dir_all(&foundry_dir)
@@ -48,10 +52,31 @@ pub fn write_file_atomic<P: AsRef<Path>>(path: P, content: &str) -> Result<()> {
     Ok(())
 }
 
-/// Read file content
+/// Write project or spec content atomically, encrypted when `[encryption]`
+/// configures a key
+pub fn write_content_atomic<P: AsRef<Path>>(path: P, content: &str) -> Result<()> {
+    let path = path.as_ref();
+    match encryption::configured_key()? {
+        Some(key) => write_file_atomic(path, &encryption::encrypt(&key, path, content)?),
+        None => write_file_atomic(path, content),
+    }
+}
+
+/// Read file content, decrypting files written encrypted
 pub fn read_file<P: AsRef<Path>>(path: P) -> Result<String> {
     let path = path.as_ref();
-    fs::read_to_string(path).with_context(|| format!("Failed to read file: {:?}", path))
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read file: {:?}", path))?;
+    if !encryption::is_encrypted(&content) {
+        return Ok(content);
+    }
+    let key = encryption::configured_key()?.ok_or_else(|| {
+        ErrorCode::InvalidParams.error(format!(
+            "{:?} is encrypted but no key is configured. Set [encryption] in config.toml",
+            path
+        ))
+    })?;
+    encryption::decrypt(&key, path, &content)
 }
 
 /// Check if a file exists
@@ -1,14 +1,35 @@
 //! Foundry façade providing storage-agnostic domain logic
-
-use crate::core::backends::{FoundryBackend, SpecContentStore};
+//!
+//! The façade negotiates with the backend's [`BackendCapabilities`] so ops do
+//! not have to: where a capability is missing it degrades instead of failing.
+//!
+//! - Without `supports_documents`, the vision and tech stack are stored inline
+//!   in the summary and split out again on load.
+//! - Without `supports_subtasks`, nested tasks are flattened before writing.
+//! - Without `atomic_replace`, edits that replace whole sections check that
+//!   nothing changed since they read the file, then read each write back.
+//!
+//! Operations with no fallback call [`Foundry::require`], which fails with
+//! `CAPABILITY_UNSUPPORTED` rather than a backend-specific error.
+
+use crate::core::backends::{
+    BackendCapabilities, Capability, FoundryBackend, SharedBackend, SpecContentStore,
+};
 use crate::core::edit_engine::{EditCommandsResult, EditEngine};
+use crate::core::lock::WriteLock;
 use crate::core::spec::SpecMatchStrategy;
+use crate::core::timing::{self, Phase};
 use crate::types::edit_commands::EditCommand;
+use crate::types::errors::ErrorCode;
 use crate::types::{
-    project::{Project, ProjectConfig, ProjectMetadata},
-    spec::{Spec, SpecConfig, SpecFileType, SpecMetadata},
+    project::{Project, ProjectConfig, ProjectFileType, ProjectMetadata},
+    spec::{
+        Spec, SpecConfig, SpecContentData, SpecFileType, SpecMetadata, SpecStatus, SpecSummary,
+    },
 };
 use anyhow::Result;
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
 
 /// Foundry façade providing storage-agnostic domain logic
 pub struct Foundry<B: FoundryBackend> {
@@ -21,35 +42,125 @@ impl<B: FoundryBackend> Foundry<B> {
     }
 
     // Project operations - thin delegation
-    pub async fn create_project(&self, config: ProjectConfig) -> Result<Project> {
-        self.backend.create_project(config).await
+    #[tracing::instrument(level = "debug", name = "backend.create_project", skip_all, fields(project = %config.name))]
+    pub async fn create_project(&self, mut config: ProjectConfig) -> Result<Project> {
+        let _backend = timing::start(Phase::Backend);
+        if !self.supports(&config.name, Capability::Documents) {
+            config.summary = inline_documents(&config.summary, &config.vision, &config.tech_stack);
+            config.vision.clear();
+            config.tech_stack.clear();
+        }
+        let project = self.backend.create_project(config).await?;
+        Ok(self.split_inline_documents(project))
     }
 
+    #[tracing::instrument(level = "debug", name = "backend.project_exists", skip(self))]
     pub async fn project_exists(&self, name: &str) -> Result<bool> {
+        let _backend = timing::start(Phase::Backend);
         self.backend.project_exists(name).await
     }
 
+    #[tracing::instrument(level = "debug", name = "backend.list_projects", skip(self))]
     pub async fn list_projects(&self) -> Result<Vec<ProjectMetadata>> {
+        let _backend = timing::start(Phase::Backend);
         self.backend.list_projects().await
     }
 
+    #[tracing::instrument(level = "debug", name = "backend.load_project", skip(self))]
     pub async fn load_project(&self, name: &str) -> Result<Project> {
-        self.backend.load_project(name).await
+        let _backend = timing::start(Phase::Backend);
+        let project = self.backend.load_project(name).await?;
+        Ok(self.split_inline_documents(project))
+    }
+
+    #[tracing::instrument(level = "debug", name = "backend.update_decisions", skip(self, content), fields(bytes = content.len()))]
+    pub async fn update_decisions(&self, project_name: &str, content: &str) -> Result<()> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend.update_decisions(project_name, content).await
+    }
+
+    #[tracing::instrument(level = "debug", name = "backend.update_project_content", skip(self, content), fields(bytes = content.len()))]
+    pub async fn update_project_content(
+        &self,
+        project_name: &str,
+        file_type: ProjectFileType,
+        content: &str,
+    ) -> Result<()> {
+        if !self.supports(project_name, Capability::Documents) {
+            // The summary holds all three documents, so rewrite it whole
+            let project = self.load_project(project_name).await?;
+            let document = |own: ProjectFileType, stored: Option<String>| {
+                if own == file_type {
+                    content.to_string()
+                } else {
+                    stored.unwrap_or_default()
+                }
+            };
+            let summary = inline_documents(
+                &document(ProjectFileType::Summary, project.summary),
+                &document(ProjectFileType::Vision, project.vision),
+                &document(ProjectFileType::TechStack, project.tech_stack),
+            );
+            let _backend = timing::start(Phase::Backend);
+            return self
+                .backend
+                .update_project_content(project_name, ProjectFileType::Summary, &summary)
+                .await;
+        }
+        let _backend = timing::start(Phase::Backend);
+        self.backend
+            .update_project_content(project_name, file_type, content)
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", name = "backend.delete_project", skip(self))]
+    pub async fn delete_project(&self, name: &str) -> Result<()> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend.delete_project(name).await
+    }
+
+    #[tracing::instrument(level = "debug", name = "backend.set_project_tags", skip(self))]
+    pub async fn set_project_tags(&self, project_name: &str, tags: &[String]) -> Result<()> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend.set_project_tags(project_name, tags).await
     }
 
     // Spec operations - thin delegation
-    pub async fn create_spec(&self, config: SpecConfig) -> Result<Spec> {
+    #[tracing::instrument(level = "debug", name = "backend.create_spec", skip_all, fields(project = %config.project_name, feature = %config.feature_name))]
+    pub async fn create_spec(&self, mut config: SpecConfig) -> Result<Spec> {
+        let _backend = timing::start(Phase::Backend);
+        if !self.supports(&config.project_name, Capability::Subtasks) {
+            config.content.tasks = flatten_subtasks(&config.content.tasks);
+        }
         self.backend.create_spec(config).await
     }
 
+    #[tracing::instrument(level = "debug", name = "backend.list_specs", skip(self))]
     pub async fn list_specs(&self, project_name: &str) -> Result<Vec<SpecMetadata>> {
+        let _backend = timing::start(Phase::Backend);
         self.backend.list_specs(project_name).await
     }
 
+    #[tracing::instrument(level = "debug", name = "backend.load_spec", skip(self))]
     pub async fn load_spec(&self, project_name: &str, spec_name: &str) -> Result<Spec> {
+        let _backend = timing::start(Phase::Backend);
         self.backend.load_spec(project_name, spec_name).await
     }
 
+    #[tracing::instrument(level = "debug", name = "backend.load_spec_files", skip(self))]
+    pub async fn load_spec_files(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        files: &[SpecFileType],
+    ) -> Result<Spec> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend
+            .load_spec_files(project_name, spec_name, files)
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", name = "backend.update_spec_content", skip(self, content), fields(bytes = content.len()))]
     pub async fn update_spec_content(
         &self,
         project_name: &str,
@@ -57,77 +168,163 @@ impl<B: FoundryBackend> Foundry<B> {
         file_type: SpecFileType,
         content: &str,
     ) -> Result<()> {
+        let _backend = timing::start(Phase::Backend);
+        if file_type == SpecFileType::TaskList && !self.supports(project_name, Capability::Subtasks)
+        {
+            return self
+                .backend
+                .update_spec_content(
+                    project_name,
+                    spec_name,
+                    file_type,
+                    &flatten_subtasks(content),
+                )
+                .await;
+        }
         self.backend
             .update_spec_content(project_name, spec_name, file_type, content)
             .await
     }
 
+    #[tracing::instrument(level = "debug", name = "backend.delete_spec", skip(self))]
     pub async fn delete_spec(&self, project_name: &str, spec_name: &str) -> Result<()> {
+        let _backend = timing::start(Phase::Backend);
         self.backend.delete_spec(project_name, spec_name).await
     }
 
+    #[tracing::instrument(level = "debug", name = "backend.rename_spec", skip(self))]
+    pub async fn rename_spec(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        new_spec_name: &str,
+    ) -> Result<Spec> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend
+            .rename_spec(project_name, spec_name, new_spec_name)
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", name = "backend.set_spec_status", skip(self))]
+    pub async fn set_spec_status(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        status: SpecStatus,
+    ) -> Result<()> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend
+            .set_spec_status(project_name, spec_name, status)
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", name = "backend.set_spec_dependencies", skip(self))]
+    pub async fn set_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend
+            .set_spec_dependencies(project_name, spec_name, depends_on)
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", name = "backend.set_spec_tags", skip(self))]
+    pub async fn set_spec_tags(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        tags: &[String],
+    ) -> Result<()> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend
+            .set_spec_tags(project_name, spec_name, tags)
+            .await
+    }
+
+    #[tracing::instrument(
+        level = "debug",
+        name = "backend.set_spec_summary",
+        skip(self, summary)
+    )]
+    pub async fn set_spec_summary(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        summary: &SpecSummary,
+    ) -> Result<()> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend
+            .set_spec_summary(project_name, spec_name, summary)
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", name = "backend.lock_spec", skip(self))]
+    pub async fn lock_spec(&self, project_name: &str, spec_name: &str) -> Result<WriteLock> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend.lock_spec(project_name, spec_name).await
+    }
+
+    #[tracing::instrument(level = "debug", name = "backend.lock_project", skip(self))]
+    pub async fn lock_project(&self, project_name: &str) -> Result<WriteLock> {
+        let _backend = timing::start(Phase::Backend);
+        self.backend.lock_project(project_name).await
+    }
+
     // Helper operations - thin delegation
+    #[tracing::instrument(level = "debug", name = "backend.get_latest_spec", skip(self))]
     pub async fn get_latest_spec(&self, project_name: &str) -> Result<Option<SpecMetadata>> {
+        let _backend = timing::start(Phase::Backend);
         self.backend.get_latest_spec(project_name).await
     }
 
+    #[tracing::instrument(level = "debug", name = "backend.count_specs", skip(self))]
     pub async fn count_specs(&self, project_name: &str) -> Result<usize> {
+        let _backend = timing::start(Phase::Backend);
         self.backend.count_specs(project_name).await
     }
 
     // Domain logic - centralized here
+
+    /// Spec name in the default `YYYYMMDD_HHMMSS_feature_name` format; see
+    /// [`crate::core::spec::generate_spec_name`] for the configured strategy
     pub fn generate_spec_name(feature_name: &str) -> String {
-        // This will be moved from spec.rs in Phase 1
-        use chrono::{Datelike, Timelike, Utc};
-        let now = Utc::now();
         format!(
-            "{:04}{:02}{:02}_{:02}{:02}{:02}_{}",
-            now.year(),
-            now.month(),
-            now.day(),
-            now.hour(),
-            now.minute(),
-            now.second(),
+            "{}_{}",
+            chrono::Utc::now().format("%Y%m%d_%H%M%S"),
             feature_name
         )
     }
 
+    /// Check that `spec_name` is `<id>_<feature_name>` with an id of any
+    /// naming strategy and a snake_case feature name
     pub fn validate_spec_name(spec_name: &str) -> Result<()> {
-        // This will be moved from spec.rs in Phase 1
-        use crate::utils::timestamp;
-
-        if timestamp::parse_spec_timestamp(spec_name).is_none() {
-            return Err(anyhow::anyhow!(
-                "Invalid spec name format. Expected: YYYYMMDD_HHMMSS_feature_name, got: {}",
+        let Some(parsed) = crate::core::spec_name::parse(spec_name) else {
+            return Err(ErrorCode::ValidationFailed.error_with_rule(
+                "spec_name_format",
+                format!(
+                "Invalid spec name format. Expected: <id>_feature_name with an id like YYYYMMDD_HHMMSS, YYYYMMDD, 0001, a ULID or PREFIX-0001, got: {}",
                 spec_name
-            ));
-        }
-
-        // Validate feature name part
-        if let Some(feature_name) = timestamp::extract_feature_name(spec_name) {
-            if feature_name.is_empty() {
-                return Err(anyhow::anyhow!(
-                    "Spec name must include a feature name after the timestamp"
-                ));
-            }
+            )));
+        };
 
-            // Validate feature name follows snake_case convention
-            if !feature_name
-                .chars()
-                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
-                || feature_name.starts_with('_')
-                || feature_name.ends_with('_')
-                || feature_name.contains("__")
-            {
-                return Err(anyhow::anyhow!(
+        // Validate feature name follows snake_case convention
+        let feature_name = parsed.feature_name;
+        if !feature_name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            || feature_name.starts_with('_')
+            || feature_name.ends_with('_')
+            || feature_name.contains("__")
+        {
+            return Err(ErrorCode::ValidationFailed.error_with_rule(
+                "spec_name_format",
+                format!(
                     "Feature name must be in snake_case format: {}",
                     feature_name
-                ));
-            }
-        } else {
-            return Err(anyhow::anyhow!(
-                "Could not extract feature name from spec name: {}",
-                spec_name
+                ),
             ));
         }
 
@@ -144,11 +341,11 @@ impl<B: FoundryBackend> Foundry<B> {
 
         // Validate inputs
         if query.trim().is_empty() {
-            return Err(anyhow::anyhow!("Query cannot be empty"));
+            return Err(ErrorCode::InvalidParams.error("Query cannot be empty"));
         }
 
         if project_name.trim().is_empty() {
-            return Err(anyhow::anyhow!("Project name cannot be empty"));
+            return Err(ErrorCode::InvalidParams.error("Project name cannot be empty"));
         }
 
         let available_specs = self.list_specs(project_name).await?;
@@ -232,6 +429,161 @@ impl<B: FoundryBackend> Foundry<B> {
         Ok(SpecMatchStrategy::None)
     }
 
+    /// Rewrite `[[wiki-links]]` pointing at any of `old_targets` across every spec in a project
+    ///
+    /// Used when a spec is renamed so links keep resolving. Each spec is locked
+    /// while it is read and rewritten. Returns the number of links rewritten.
+    pub async fn rewrite_wiki_links(
+        &self,
+        project_name: &str,
+        old_targets: &[&str],
+        new_target: &str,
+    ) -> Result<usize> {
+        let mut total = 0;
+        for spec_meta in self.list_specs(project_name).await? {
+            let _lock = self.lock_spec(project_name, &spec_meta.name).await?;
+            let spec = self.load_spec(project_name, &spec_meta.name).await?;
+            for (file_type, content) in [
+                (SpecFileType::Spec, &spec.content.spec),
+                (SpecFileType::Notes, &spec.content.notes),
+                (SpecFileType::TaskList, &spec.content.tasks),
+            ] {
+                let (updated, count) =
+                    crate::core::links::rewrite_wiki_links(content, old_targets, new_target);
+                if count > 0 {
+                    self.update_spec_content(project_name, &spec.name, file_type, &updated)
+                        .await?;
+                    total += count;
+                }
+            }
+        }
+        Ok(total)
+    }
+
+    /// Specs among `specs` whose files link to `spec_name`, in the order given
+    ///
+    /// A spec that fails to load is skipped with a warning rather than failing the lookup.
+    pub async fn backlinks(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        specs: &[SpecMetadata],
+    ) -> Result<Vec<String>> {
+        let mut backlinks = Vec::new();
+        for spec_meta in specs.iter().filter(|spec| spec.name != spec_name) {
+            match self.load_spec(project_name, &spec_meta.name).await {
+                Ok(spec) if crate::core::links::links_to(&spec.content, spec_name, specs) => {
+                    backlinks.push(spec.name);
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!(
+                    "Skipping '{}' when looking for links to '{}': {:#}",
+                    spec_meta.name,
+                    spec_name,
+                    e
+                ),
+            }
+        }
+        Ok(backlinks)
+    }
+
+    /// Check that `spec_name` may depend on `depends_on`: every dependency is
+    /// another spec in the project and the graph stays free of cycles
+    pub async fn validate_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()> {
+        if depends_on.iter().any(|dependency| dependency == spec_name) {
+            return Err(ErrorCode::ValidationFailed
+                .error(format!("Spec '{}' cannot depend on itself", spec_name)));
+        }
+
+        let mut specs = self.list_specs(project_name).await?;
+        if let Some(missing) = depends_on
+            .iter()
+            .find(|dependency| !specs.iter().any(|spec| &spec.name == *dependency))
+        {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Dependency '{}' is not a spec in project '{}'. Use the full spec name from list_specs",
+                missing, project_name
+            )));
+        }
+
+        if let Some(spec) = specs.iter_mut().find(|spec| spec.name == spec_name) {
+            spec.depends_on = depends_on.to_vec();
+        }
+        if let Some(cycle) = crate::core::dependencies::find_cycles(&specs)
+            .into_iter()
+            .find(|cycle| cycle.iter().any(|name| name == spec_name))
+        {
+            return Err(ErrorCode::ValidationFailed.error_with_rule(
+                "dependency_cycle",
+                format!(
+                    "Dependencies of '{}' would create a cycle: {}",
+                    spec_name,
+                    cycle.join(" → ")
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    // Capability negotiation
+    /// Capabilities of the backend storing `project_name`
+    pub fn capabilities(&self, project_name: &str) -> BackendCapabilities {
+        self.backend.project_capabilities(project_name)
+    }
+
+    /// Whether the backend storing `project_name` has `capability`
+    pub fn supports(&self, project_name: &str, capability: Capability) -> bool {
+        self.capabilities(project_name).supports(capability)
+    }
+
+    /// Fail with `CAPABILITY_UNSUPPORTED` unless the backend storing
+    /// `project_name` has `capability`, for an `operation` that has no
+    /// fallback without it
+    pub fn require(
+        &self,
+        project_name: &str,
+        capability: Capability,
+        operation: &str,
+    ) -> Result<()> {
+        if self.supports(project_name, capability) {
+            return Ok(());
+        }
+        Err(ErrorCode::CapabilityUnsupported.error(format!(
+            "{} needs a backend with '{}', which the backend of project '{}' does not support",
+            operation,
+            capability.as_str(),
+            project_name
+        )))
+    }
+
+    /// The project with documents stored inline in its summary split back out
+    fn split_inline_documents(&self, mut project: Project) -> Project {
+        if self.supports(&project.name, Capability::Documents) {
+            return project;
+        }
+        if let Some(summary) = project.summary.take() {
+            let (summary, vision, tech_stack) = split_documents(&summary);
+            project.summary = Some(summary);
+            project.vision = vision.or(project.vision);
+            project.tech_stack = tech_stack.or(project.tech_stack);
+        }
+        project
+    }
+
+    /// Whether `commands` need the read-modify-verify flow: they replace whole
+    /// sections and the backend could leave a replaced document partly written
+    fn needs_verified_writes(&self, project_name: &str, commands: &[EditCommand]) -> bool {
+        !self.supports(project_name, Capability::AtomicReplace)
+            && commands
+                .iter()
+                .any(|command| command.command.discards_content())
+    }
+
     // Edit commands integration
     pub async fn apply_edit_commands(
         &self,
@@ -239,8 +591,261 @@ impl<B: FoundryBackend> Foundry<B> {
         spec_name: &str,
         commands: &[EditCommand],
     ) -> Result<EditCommandsResult> {
+        if self.needs_verified_writes(project_name, commands) {
+            let store = VerifiedStore::new(self);
+            return EditEngine::apply_edit_commands_with_store(
+                project_name,
+                spec_name,
+                commands,
+                &store,
+            )
+            .await;
+        }
         EditEngine::apply_edit_commands_with_store(project_name, spec_name, commands, self).await
     }
+
+    /// Work out what edit commands would change in a spec without writing anything
+    pub async fn preview_edit_commands(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        commands: &[EditCommand],
+    ) -> Result<EditCommandsResult> {
+        EditEngine::preview_edit_commands_with_store(project_name, spec_name, commands, self).await
+    }
+
+    /// A spec's content as it would be after edit commands, without writing anything
+    pub async fn edited_content(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        commands: &[EditCommand],
+    ) -> Result<SpecContentData> {
+        EditEngine::edited_content_with_store(project_name, spec_name, commands, self).await
+    }
+
+    /// Apply edit commands to a spec only if every command succeeds
+    pub async fn apply_edit_commands_atomic(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        commands: &[EditCommand],
+    ) -> Result<EditCommandsResult> {
+        if self.needs_verified_writes(project_name, commands) {
+            let store = VerifiedStore::new(self);
+            return EditEngine::apply_edit_commands_atomic_with_store(
+                project_name,
+                spec_name,
+                commands,
+                &store,
+            )
+            .await;
+        }
+        EditEngine::apply_edit_commands_atomic_with_store(project_name, spec_name, commands, self)
+            .await
+    }
+}
+
+/// Vision and tech stack folded into the summary between marker comments,
+/// for backends without separate documents; [`split_documents`] reverses it
+fn inline_documents(summary: &str, vision: &str, tech_stack: &str) -> String {
+    let mut content = summary.trim_end().to_string();
+    for (name, document) in [("vision", vision), ("tech-stack", tech_stack)] {
+        if !document.trim().is_empty() {
+            content.push_str(&format!(
+                "\n\n<!-- foundry:{name} -->\n{}\n<!-- /foundry:{name} -->",
+                document.trim_end()
+            ));
+        }
+    }
+    content
+}
+
+/// Summary, vision and tech stack from content written by [`inline_documents`]
+fn split_documents(content: &str) -> (String, Option<String>, Option<String>) {
+    let mut rest = content.to_string();
+    let mut take = |name: &str| {
+        let open = format!("<!-- foundry:{} -->\n", name);
+        let close = format!("\n<!-- /foundry:{} -->", name);
+        let start = rest.find(&open)?;
+        let end = start + rest[start..].find(&close)?;
+        let document = rest[start + open.len()..end].to_string();
+        rest.replace_range(start..end + close.len(), "");
+        Some(document)
+    };
+    let vision = take("vision");
+    let tech_stack = take("tech-stack");
+    (rest.trim_end().to_string(), vision, tech_stack)
+}
+
+/// Task list with nested checklist items moved to the top level
+fn flatten_subtasks(tasks: &str) -> String {
+    tasks
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            if trimmed.len() < line.len() && crate::core::tasks::is_task_line(trimmed) {
+                trimmed
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Content store for backends without `atomic_replace`
+///
+/// Before writing spec.md or notes.md it checks the file still holds what the
+/// edit read, and after writing it reads the file back, writing once more if
+/// the result does not match. Either failing is a `BACKEND_CONFLICT`.
+struct VerifiedStore<'a, B: FoundryBackend> {
+    foundry: &'a Foundry<B>,
+    /// Each file's content when the edit first read it
+    read: Mutex<Vec<(SpecFileType, String)>>,
+}
+
+impl<'a, B: FoundryBackend> VerifiedStore<'a, B> {
+    fn new(foundry: &'a Foundry<B>) -> Self {
+        Self {
+            foundry,
+            read: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn first_read(&self, file_type: &SpecFileType) -> Option<String> {
+        self.read
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .find(|(read_type, _)| read_type == file_type)
+            .map(|(_, content)| content.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl<B: FoundryBackend> SpecContentStore for VerifiedStore<'_, B> {
+    async fn read_spec_file(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        file_type: SpecFileType,
+    ) -> Result<String> {
+        let content = self
+            .foundry
+            .read_spec_file(project_name, spec_name, file_type.clone())
+            .await?;
+        if self.first_read(&file_type).is_none() {
+            self.read
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push((file_type, content.clone()));
+        }
+        Ok(content)
+    }
+
+    async fn write_spec_file(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        file_type: SpecFileType,
+        content: &str,
+    ) -> Result<()> {
+        // Task lists are stored item by item, so there is no document to verify
+        if file_type == SpecFileType::TaskList {
+            return self
+                .foundry
+                .write_spec_file(project_name, spec_name, file_type, content)
+                .await;
+        }
+        let file_name = file_type.file_name();
+        let current = self
+            .foundry
+            .read_spec_file(project_name, spec_name, file_type.clone())
+            .await?;
+        if self
+            .first_read(&file_type)
+            .is_some_and(|read| read != current)
+        {
+            return Err(ErrorCode::BackendConflict.error(format!(
+                "{} of '{}' changed while it was being edited; load the spec and retry",
+                file_name, spec_name
+            )));
+        }
+
+        for _ in 0..2 {
+            self.foundry
+                .write_spec_file(project_name, spec_name, file_type.clone(), content)
+                .await?;
+            let written = self
+                .foundry
+                .read_spec_file(project_name, spec_name, file_type.clone())
+                .await?;
+            if same_text(&written, content) {
+                return Ok(());
+            }
+            tracing::warn!(
+                file = file_name,
+                spec = spec_name,
+                "Write did not read back, retrying"
+            );
+        }
+        Err(ErrorCode::BackendConflict.error(format!(
+            "{} of '{}' did not read back as written and may be partly updated; load the spec and retry",
+            file_name, spec_name
+        )))
+    }
+
+    async fn is_file_modified(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        file_type: SpecFileType,
+        new_content: &str,
+    ) -> Result<bool> {
+        self.foundry
+            .is_file_modified(project_name, spec_name, file_type, new_content)
+            .await
+    }
+
+    async fn read_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+    ) -> Result<Vec<String>> {
+        self.foundry
+            .read_spec_dependencies(project_name, spec_name)
+            .await
+    }
+
+    async fn write_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()> {
+        self.foundry
+            .write_spec_dependencies(project_name, spec_name, depends_on)
+            .await
+    }
+}
+
+/// Whether two documents have the same words line by line, ignoring the
+/// markdown a backend may normalize (list markers, spacing, blank lines)
+fn same_text(a: &str, b: &str) -> bool {
+    let words = |content: &str| -> Vec<String> {
+        content
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .filter(|c| c.is_alphanumeric())
+                    .flat_map(char::to_lowercase)
+                    .collect::<String>()
+            })
+            .filter(|line| !line.is_empty())
+            .collect()
+    };
+    words(a) == words(b)
 }
 
 /// SpecContentStore implementation for the Foundry façade
@@ -287,13 +892,65 @@ impl<B: FoundryBackend> SpecContentStore for Foundry<B> {
             .await?;
         Ok(current_content != new_content)
     }
+
+    async fn read_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+    ) -> Result<Vec<String>> {
+        Ok(self
+            .list_specs(project_name)
+            .await?
+            .into_iter()
+            .find(|spec| spec.name == spec_name)
+            .map(|spec| spec.depends_on)
+            .unwrap_or_default())
+    }
+
+    async fn write_spec_dependencies(
+        &self,
+        project_name: &str,
+        spec_name: &str,
+        depends_on: &[String],
+    ) -> Result<()> {
+        self.validate_spec_dependencies(project_name, spec_name, depends_on)
+            .await?;
+        self.set_spec_dependencies(project_name, spec_name, depends_on)
+            .await
+    }
+}
+
+tokio::task_local! {
+    static BACKEND_OVERRIDE: SharedBackend;
+}
+
+/// Run `future` with every `get_default_foundry` call inside it using `backend`
+///
+/// Lets a whole sequence of ops run against another store, e.g. `foundry replay`
+/// against an `InMemoryBackend`.
+pub async fn with_backend<F: Future>(backend: SharedBackend, future: F) -> F::Output {
+    BACKEND_OVERRIDE.scope(backend, future).await
+}
+
+static DEFAULT_BACKEND: OnceLock<SharedBackend> = OnceLock::new();
+
+/// Install the process-wide backend, e.g. the one chosen with `--backend`;
+/// later calls keep the first backend
+pub fn set_default_backend(backend: SharedBackend) {
+    if DEFAULT_BACKEND.set(backend).is_err() {
+        tracing::debug!("Default backend already set");
+    }
 }
 
-/// Get the default Foundry instance with filesystem backend
+/// Get the default Foundry instance
 ///
-/// Returns a Foundry instance using the FilesystemBackend as the default storage backend.
-pub fn get_default_foundry() -> Result<Foundry<crate::core::backends::filesystem::FilesystemBackend>>
-{
-    let backend = crate::core::backends::filesystem::FilesystemBackend::new();
+/// Uses the backend installed by [`with_backend`] when called inside it, then
+/// the one from [`set_default_backend`], and the FilesystemBackend otherwise.
+pub fn get_default_foundry() -> Result<Foundry<SharedBackend>> {
+    let backend = BACKEND_OVERRIDE
+        .try_with(Arc::clone)
+        .ok()
+        .or_else(|| DEFAULT_BACKEND.get().cloned())
+        .unwrap_or_else(|| Arc::new(crate::core::backends::filesystem::FilesystemBackend::new()));
     Ok(Foundry::new(backend))
 }
@@ -0,0 +1,142 @@
+//! Journal of spec updates for `undo_last_update`
+//!
+//! Every `update_spec` and `update_specs_batch` call that changes a spec
+//! records the previous content of the files it changed in a per-spec journal
+//! under `~/.foundry/.history`:
+//!
+//! ```text
+//! .history/<project>/<spec>.json
+//! ```
+//!
+//! Undoing pops the newest entry and writes its content back, so repeated
+//! undos step further back. Only the newest [`MAX_ENTRIES`] updates of each
+//! spec are kept; see `core::journal` for locking, encryption and when nothing
+//! is recorded.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::core::{journal, versioning};
+use crate::types::errors::ErrorCode;
+use crate::types::spec::{SpecContentData, SpecFileType};
+use crate::utils::paths;
+
+/// Updates remembered per spec; older ones are dropped
+pub const MAX_ENTRIES: usize = 20;
+
+/// Directory below `~/.foundry` holding the journals
+pub const HISTORY_DIR_NAME: &str = ".history";
+
+/// One update of a spec that can be undone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperationHistoryEntry {
+    /// Operation that made the update, e.g. "update_spec"
+    pub operation: String,
+    /// When the update was made (RFC 3339)
+    pub updated_at: String,
+    /// The files the update changed
+    pub files: Vec<FileChange>,
+}
+
+/// A file changed by an update
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileChange {
+    pub file_type: SpecFileType,
+    /// Content before the update
+    pub before: String,
+    /// Version of the content the update wrote
+    pub after_version: String,
+}
+
+/// Journal an update of a spec from `before` to `after`, if it changed anything
+pub async fn record(
+    project_name: &str,
+    spec_name: &str,
+    operation: &str,
+    before: &SpecContentData,
+    after: &SpecContentData,
+) -> Result<()> {
+    let Some(dir) = journal::dir(HISTORY_DIR_NAME)? else {
+        return Ok(());
+    };
+    let files: Vec<FileChange> = [
+        (SpecFileType::Spec, &before.spec, &after.spec),
+        (SpecFileType::Notes, &before.notes, &after.notes),
+        (SpecFileType::TaskList, &before.tasks, &after.tasks),
+    ]
+    .into_iter()
+    .filter(|(_, before, after)| before != after)
+    .map(|(file_type, before, after)| FileChange {
+        file_type,
+        before: before.clone(),
+        after_version: versioning::content_version(after),
+    })
+    .collect();
+    if files.is_empty() {
+        return Ok(());
+    }
+
+    let entry = OperationHistoryEntry {
+        operation: operation.to_string(),
+        updated_at: chrono::Utc::now().to_rfc3339(),
+        files,
+    };
+    journal::push(
+        &journal_path(&dir, project_name, spec_name)?,
+        entry,
+        MAX_ENTRIES,
+    )
+    .await
+    .with_context(|| format!("Failed to journal {} of spec '{}'", operation, spec_name))
+}
+
+/// Journaled updates of a spec, oldest first
+pub fn entries(project_name: &str, spec_name: &str) -> Result<Vec<OperationHistoryEntry>> {
+    let Some(dir) = journal::dir(HISTORY_DIR_NAME)? else {
+        return Ok(Vec::new());
+    };
+    journal::read(&journal_path(&dir, project_name, spec_name)?)
+}
+
+/// Drop the newest journaled update of a spec once it has been undone
+pub async fn remove_last(project_name: &str, spec_name: &str) -> Result<()> {
+    let Some(dir) = journal::dir(HISTORY_DIR_NAME)? else {
+        return Ok(());
+    };
+    journal::update(
+        &journal_path(&dir, project_name, spec_name)?,
+        |entries: &mut Vec<OperationHistoryEntry>| {
+            entries.pop();
+        },
+    )
+    .await
+}
+
+fn journal_path(dir: &Path, project_name: &str, spec_name: &str) -> Result<PathBuf> {
+    if !paths::is_safe_component(project_name) || !paths::is_safe_component(spec_name) {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Invalid project or spec name '{}/{}'",
+            project_name, spec_name
+        )));
+    }
+    Ok(dir.join(project_name).join(format!("{}.json", spec_name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_journal_paths_stay_in_history_dir() {
+        let dir = Path::new("/tmp/history");
+        assert_eq!(
+            journal_path(dir, "demo", "20250101_120000_auth").unwrap(),
+            dir.join("demo/20250101_120000_auth.json")
+        );
+        assert!(journal_path(dir, "..", "spec").is_err());
+        assert!(journal_path(dir, "demo", "a/b").is_err());
+        assert!(journal_path(dir, "demo", "").is_err());
+    }
+}
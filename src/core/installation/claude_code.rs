@@ -10,6 +10,7 @@ use crate::core::templates::claude_subagent::ClaudeSubagentTemplate;
 use crate::core::templates::commands::{
     claude_commands_dir, install_claude_commands, remove_commands,
 };
+use crate::core::templates::render::render_installed;
 use crate::types::responses::EnvironmentStatus;
 use anyhow::{Context, Result};
 use std::fs;
@@ -36,7 +37,6 @@ async fn execute_claude_command(args: &[&str]) -> Result<std::process::Output> {
 
     // If direct execution fails or claude not found in PATH,
     // try to execute through shell to handle aliases and shell functions
-    let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/sh".to_string());
     let current_path = std::env::var("PATH").unwrap_or_default();
 
     // Build the command string
@@ -44,13 +44,19 @@ async fn execute_claude_command(args: &[&str]) -> Result<std::process::Output> {
     cmd_parts.extend(args);
     let cmd_string = cmd_parts.join(" ");
 
-    // Try interactive shell to load user configuration and aliases
-    match Command::new(&shell)
-        .args(["-i", "-c", &cmd_string])
-        .env("PATH", &current_path)
-        .output()
-        .await
-    {
+    // Try interactive shell to load user configuration and aliases; Windows has
+    // no such shell, so cmd.exe resolves claude.cmd/claude.exe through PATHEXT
+    let mut shell_command = if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", &cmd_string]);
+        command
+    } else {
+        let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/sh".to_string());
+        let mut command = Command::new(shell);
+        command.args(["-i", "-c", &cmd_string]);
+        command
+    };
+    match shell_command.env("PATH", &current_path).output().await {
         Ok(output) => {
             // Return the output regardless of exit status - let calling functions handle specific error cases
             return Ok(output);
@@ -372,10 +378,14 @@ async fn install_claude_subagent_template() -> Result<String> {
     }
 
     // Get the embedded template content
-    let content = ClaudeSubagentTemplate::content();
+    let content = render_installed(
+        "claude/subagent",
+        ClaudeSubagentTemplate::content(),
+        "claude",
+    )?;
 
     // Write template content atomically
-    write_file_atomic(&template_path, content).with_context(|| {
+    write_file_atomic(&template_path, &content).with_context(|| {
         format!(
             "Failed to write Claude subagent template: {:?}",
             template_path
@@ -12,6 +12,7 @@ use crate::core::templates::commands::{
     cursor_commands_dir, install_cursor_commands, remove_commands,
 };
 use crate::core::templates::cursor_rules::CursorRulesTemplate;
+use crate::core::templates::render::render_installed;
 use crate::types::responses::EnvironmentStatus;
 use anyhow::{Context, Result};
 use std::fs;
@@ -207,21 +208,13 @@ pub async fn get_cursor_status(detailed: bool) -> Result<EnvironmentStatus> {
                     if let Some(server_config) =
                         crate::core::installation::get_server_config(&config, "foundry")
                     {
-                        // Check if binary is accessible (different logic for PATH vs absolute paths)
-                        let command_path = std::path::Path::new(&server_config.command);
-                        if command_path.is_absolute() {
-                            // For absolute paths, check if file exists
-                            binary_accessible = command_path.exists();
-                            if !binary_accessible {
-                                issues.push(format!(
-                                    "Configured binary does not exist: {}",
-                                    server_config.command
-                                ));
-                            }
-                        } else {
-                            // For PATH-based commands, assume accessible (validation happens at runtime)
-                            binary_accessible = true;
-                        }
+                        // Paths must exist (foundry.exe on Windows); PATH-based
+                        // commands are resolved when Cursor starts the server
+                        binary_accessible =
+                            crate::core::installation::json_config::check_server_command(
+                                &server_config.command,
+                                &mut issues,
+                            );
                     }
                 } else {
                     issues.push("Foundry MCP server not found in configuration".to_string());
@@ -275,10 +268,10 @@ async fn install_cursor_rules_template(config_path: &std::path::Path) -> Result<
     }
 
     // Get the embedded template content
-    let content = CursorRulesTemplate::content();
+    let content = render_installed("cursor/rules", CursorRulesTemplate::content(), "cursor")?;
 
     // Write template content atomically
-    write_file_atomic(&template_path, content)
+    write_file_atomic(&template_path, &content)
         .with_context(|| format!("Failed to write Cursor rules template: {:?}", template_path))?;
 
     // Return success message
@@ -1,7 +1,10 @@
 //! JSON configuration file management utilities
 
+use crate::core::filesystem::write_file_atomic;
+use crate::core::installation::utils::{is_missing_path_command, normalize_command_path};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::collections::HashMap;
 use std::path::Path;
 
@@ -23,7 +26,7 @@ pub struct McpConfig {
 /// Create a new MCP server configuration entry
 pub fn create_server_config(binary_path: &str) -> McpServerConfig {
     McpServerConfig {
-        command: binary_path.to_string(),
+        command: normalize_command_path(binary_path),
         args: vec!["serve".to_string()],
         env: Some(HashMap::from([(
             "FOUNDRY_LOG_LEVEL".to_string(),
@@ -138,10 +141,9 @@ pub fn validate_config(config: &McpConfig) -> Result<()> {
             ));
         }
 
-        // Check if command path exists (only for absolute paths)
+        // Check if command path exists (only for paths)
         // Commands like "foundry" are meant to be found in PATH, so skip validation
-        let command_path = Path::new(&server_config.command);
-        if command_path.is_absolute() && !command_path.exists() {
+        if is_missing_path_command(&server_config.command) {
             return Err(anyhow::anyhow!(
                 "Server '{}' command does not exist: {}",
                 server_name,
@@ -153,6 +155,91 @@ pub fn validate_config(config: &McpConfig) -> Result<()> {
     Ok(())
 }
 
+/// Parse a config file's content as a JSON object
+///
+/// Used for configs that hold more than MCP servers (VS Code's mcp.json, Zed's
+/// settings.json), so every key Foundry does not own can be written back.
+pub fn parse_json_object(content: &str, config_path: &Path) -> Result<Map<String, Value>> {
+    if content.trim().is_empty() {
+        return Ok(Map::new());
+    }
+    match serde_json::from_str(content) {
+        Ok(Value::Object(config)) => Ok(config),
+        Ok(_) => Err(anyhow::anyhow!(
+            "Config file is not a JSON object: {}",
+            config_path.display()
+        )),
+        Err(e) => Err(anyhow::anyhow!(
+            "Failed to parse config file: {} ({}). Comments and trailing commas are not supported; remove them or add the 'foundry' server by hand",
+            config_path.display(),
+            e
+        )),
+    }
+}
+
+/// Read a config file as a JSON object; a missing or empty file is an empty object
+pub fn read_json_object(config_path: &Path) -> Result<Map<String, Value>> {
+    if !config_path.exists() {
+        return Ok(Map::new());
+    }
+    let content = std::fs::read_to_string(config_path)
+        .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
+    parse_json_object(&content, config_path)
+}
+
+/// Write a JSON object config file atomically, after `prefix` (e.g. leading comments)
+pub fn write_json_object(
+    config_path: &Path,
+    prefix: &str,
+    config: &Map<String, Value>,
+) -> Result<()> {
+    if let Some(parent) = config_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create config directory: {}", parent.display()))?;
+    }
+    let content =
+        serde_json::to_string_pretty(config).context("Failed to serialize config to JSON")?;
+    write_file_atomic(config_path, &format!("{}{}", prefix, content))
+        .with_context(|| format!("Failed to write config file: {}", config_path.display()))
+}
+
+/// The object under `key`, created when missing
+pub fn object_entry_mut<'a>(
+    config: &'a mut Map<String, Value>,
+    key: &str,
+) -> Result<&'a mut Map<String, Value>> {
+    config
+        .entry(key)
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .with_context(|| format!("'{}' in the MCP configuration is not an object", key))
+}
+
+/// Whether the configuration holds nothing but empty collections
+pub fn is_empty_object(config: &Map<String, Value>) -> bool {
+    config.values().all(|value| match value {
+        Value::Object(map) => map.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    })
+}
+
+/// Whether a configured server command can be run, recording an issue when not
+///
+/// Commands found through PATH are assumed accessible; they are resolved when
+/// the client starts the server.
+pub fn check_server_command(command: &str, issues: &mut Vec<String>) -> bool {
+    if command.is_empty() {
+        issues.push("Foundry server entry has no command".to_string());
+        false
+    } else if is_missing_path_command(command) {
+        issues.push(format!("Configured binary does not exist: {}", command));
+        false
+    } else {
+        true
+    }
+}
+
 /// Format configuration for display
 pub fn format_config_for_display(config: &McpConfig) -> String {
     if config.mcp_servers.is_empty() {
@@ -5,6 +5,9 @@ pub mod cursor;
 pub mod json_config;
 pub mod paths;
 pub mod utils;
+pub mod vscode;
+pub mod windsurf;
+pub mod zed;
 
 // Selective reexports from submodules
 pub use claude_code::{
@@ -21,15 +24,24 @@ pub use json_config::{
 
 pub use paths::{
     get_all_config_paths, get_claude_code_config_dir, get_cursor_config_dir,
-    get_cursor_mcp_config_path, validate_config_dir_writable,
+    get_cursor_mcp_config_path, get_vscode_config_dir, get_vscode_mcp_config_path,
+    get_windsurf_config_dir, get_windsurf_mcp_config_path, get_windsurf_workspace_dir,
+    get_zed_config_dir, get_zed_settings_path, validate_config_dir_writable,
 };
 
 pub use utils::{
-    InstallationResult, UninstallationResult, check_binary_accessible, create_installation_result,
-    create_uninstallation_result, detect_binary_path, ensure_directory_exists, get_home_dir,
-    validate_binary_path,
+    InstallationResult, UninstallationResult, binary_file_name, check_binary_accessible,
+    create_installation_result, create_uninstallation_result, detect_binary_path,
+    ensure_directory_exists, get_home_dir, is_missing_path_command, normalize_command_path,
+    resolve_command, validate_binary_path,
 };
 
+pub use vscode::{get_vscode_status, install_for_vscode, uninstall_from_vscode};
+
+pub use windsurf::{get_windsurf_status, install_for_windsurf, uninstall_from_windsurf};
+
+pub use zed::{get_zed_status, install_for_zed, uninstall_from_zed};
+
 // Re-export types for convenience
 pub use crate::types::responses::EnvironmentStatus;
 
@@ -38,6 +50,9 @@ pub async fn install_for_target(target: &str) -> anyhow::Result<InstallationResu
     match target {
         "claude-code" => install_for_claude_code().await,
         "cursor" => install_for_cursor().await,
+        "vscode" => install_for_vscode().await,
+        "windsurf" => install_for_windsurf().await,
+        "zed" => install_for_zed().await,
         _ => Err(anyhow::anyhow!(
             "Unsupported installation target: {}",
             target
@@ -53,6 +68,9 @@ pub async fn uninstall_from_target(
     match target {
         "claude-code" => uninstall_from_claude_code().await,
         "cursor" => uninstall_from_cursor(remove_config).await,
+        "vscode" => uninstall_from_vscode(remove_config).await,
+        "windsurf" => uninstall_from_windsurf(remove_config).await,
+        "zed" => uninstall_from_zed(remove_config).await,
         _ => Err(anyhow::anyhow!(
             "Unsupported uninstallation target: {}",
             target
@@ -100,6 +118,57 @@ pub async fn get_all_environment_statuses(
         }
     }
 
+    // Get status for VS Code
+    match get_vscode_status(detailed).await {
+        Ok(status) => statuses.push(status),
+        Err(e) => {
+            statuses.push(EnvironmentStatus {
+                name: "vscode".to_string(),
+                installed: false,
+                config_path: "".to_string(),
+                config_exists: false,
+                binary_path: "unknown".to_string(),
+                binary_accessible: false,
+                config_content: None,
+                issues: vec![format!("Failed to get status: {}", e)],
+            });
+        }
+    }
+
+    // Get status for Windsurf
+    match get_windsurf_status(detailed).await {
+        Ok(status) => statuses.push(status),
+        Err(e) => {
+            statuses.push(EnvironmentStatus {
+                name: "windsurf".to_string(),
+                installed: false,
+                config_path: "".to_string(),
+                config_exists: false,
+                binary_path: "unknown".to_string(),
+                binary_accessible: false,
+                config_content: None,
+                issues: vec![format!("Failed to get status: {}", e)],
+            });
+        }
+    }
+
+    // Get status for Zed
+    match get_zed_status(detailed).await {
+        Ok(status) => statuses.push(status),
+        Err(e) => {
+            statuses.push(EnvironmentStatus {
+                name: "zed".to_string(),
+                installed: false,
+                config_path: "".to_string(),
+                config_exists: false,
+                binary_path: "unknown".to_string(),
+                binary_accessible: false,
+                config_content: None,
+                issues: vec![format!("Failed to get status: {}", e)],
+            });
+        }
+    }
+
     Ok(statuses)
 }
 
@@ -111,6 +180,9 @@ pub async fn get_environment_status(
     match target {
         "claude-code" => get_claude_code_status(detailed).await,
         "cursor" => get_cursor_status(detailed).await,
+        "vscode" => get_vscode_status(detailed).await,
+        "windsurf" => get_windsurf_status(detailed).await,
+        "zed" => get_zed_status(detailed).await,
         _ => Err(anyhow::anyhow!("Unsupported status target: {}", target)),
     }
 }
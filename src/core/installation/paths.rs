@@ -7,7 +7,8 @@ use std::path::{Path, PathBuf};
 
 /// Get the configuration directory path for Claude Code
 ///
-/// Claude Code stores user settings in ~/.claude/
+/// Claude Code stores user settings in ~/.claude/ on every platform
+/// (%USERPROFILE%\.claude on Windows, not under %APPDATA%)
 /// Can be overridden with CLAUDE_CONFIG_DIR environment variable for testing
 pub fn get_claude_code_config_dir() -> Result<PathBuf> {
     if let Ok(test_dir) = env::var("CLAUDE_CONFIG_DIR") {
@@ -21,6 +22,7 @@ pub fn get_claude_code_config_dir() -> Result<PathBuf> {
 ///
 /// Claude Code uses ~/.claude.json for MCP server configurations.
 /// This returns the MCP config file at ~/.claude.json
+/// (%USERPROFILE%\.claude.json on Windows)
 pub fn get_claude_code_mcp_config_path() -> Result<PathBuf> {
     let home = get_home_dir()?;
     Ok(home.join(".claude.json"))
@@ -45,9 +47,92 @@ pub fn get_cursor_mcp_config_path() -> Result<PathBuf> {
     Ok(config_dir.join("mcp.json"))
 }
 
+/// Get the user configuration directory for VS Code
+///
+/// VS Code keeps user-level MCP servers in its `User` settings directory:
+/// - macOS: ~/Library/Application Support/Code/User
+/// - Linux: $XDG_CONFIG_HOME/Code/User (default ~/.config/Code/User)
+/// - Windows: %APPDATA%\Code\User
+///
+/// Can be overridden with VSCODE_CONFIG_DIR environment variable for testing
+/// or for other VS Code builds (e.g., `Code - Insiders`)
+pub fn get_vscode_config_dir() -> Result<PathBuf> {
+    if let Ok(test_dir) = env::var("VSCODE_CONFIG_DIR") {
+        return Ok(PathBuf::from(test_dir));
+    }
+    let config_dir =
+        dirs::config_dir().context("Failed to determine user configuration directory")?;
+    Ok(config_dir.join("Code").join("User"))
+}
+
+/// Get the MCP configuration file path for VS Code
+///
+/// VS Code reads user-level MCP servers from `mcp.json` in its user directory.
+pub fn get_vscode_mcp_config_path() -> Result<PathBuf> {
+    Ok(get_vscode_config_dir()?.join("mcp.json"))
+}
+
+/// Get the configuration directory for Windsurf
+///
+/// Windsurf keeps its MCP servers in ~/.codeium/windsurf/mcp_config.json.
+/// Can be overridden with WINDSURF_CONFIG_DIR environment variable for testing
+pub fn get_windsurf_config_dir() -> Result<PathBuf> {
+    if let Ok(test_dir) = env::var("WINDSURF_CONFIG_DIR") {
+        return Ok(PathBuf::from(test_dir));
+    }
+    Ok(get_home_dir()?.join(".codeium").join("windsurf"))
+}
+
+/// Get the MCP configuration file path for Windsurf
+pub fn get_windsurf_mcp_config_path() -> Result<PathBuf> {
+    Ok(get_windsurf_config_dir()?.join("mcp_config.json"))
+}
+
+/// Get the workspace directory for Windsurf rules and workflows
+///
+/// Windsurf reads rules and workflows from ./.windsurf/ (project-local directory).
+/// Can be overridden with WINDSURF_WORKSPACE_DIR environment variable for testing
+pub fn get_windsurf_workspace_dir() -> Result<PathBuf> {
+    if let Ok(test_dir) = env::var("WINDSURF_WORKSPACE_DIR") {
+        return Ok(PathBuf::from(test_dir));
+    }
+    let current_dir = std::env::current_dir().context("Failed to get current working directory")?;
+    Ok(current_dir.join(".windsurf"))
+}
+
+/// Get the configuration directory for Zed
+///
+/// Zed keeps its settings, including MCP context servers, in:
+/// - macOS and Linux: $XDG_CONFIG_HOME/zed (default ~/.config/zed)
+/// - Windows: %APPDATA%\Zed
+///
+/// Can be overridden with ZED_CONFIG_DIR environment variable for testing
+pub fn get_zed_config_dir() -> Result<PathBuf> {
+    if let Ok(test_dir) = env::var("ZED_CONFIG_DIR") {
+        return Ok(PathBuf::from(test_dir));
+    }
+    if cfg!(windows) {
+        let config_dir =
+            dirs::config_dir().context("Failed to determine user configuration directory")?;
+        return Ok(config_dir.join("Zed"));
+    }
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute());
+    Ok(config_home
+        .map_or_else(|| get_home_dir().map(|home| home.join(".config")), Ok)?
+        .join("zed"))
+}
+
+/// Get the settings file path for Zed, which holds its `context_servers`
+pub fn get_zed_settings_path() -> Result<PathBuf> {
+    Ok(get_zed_config_dir()?.join("settings.json"))
+}
+
 /// Get all supported MCP configuration paths
 ///
-/// Returns the configuration file paths for both Claude Code and Cursor.
+/// Returns the configuration file paths for Claude Code, Cursor, VS Code,
+/// Windsurf and Zed.
 /// Claude Code uses ~/.claude.json for MCP server configurations.
 pub fn get_all_config_paths() -> Vec<(String, PathBuf)> {
     vec![
@@ -59,6 +144,18 @@ pub fn get_all_config_paths() -> Vec<(String, PathBuf)> {
             "cursor".to_string(),
             get_cursor_mcp_config_path().unwrap_or_default(),
         ),
+        (
+            "vscode".to_string(),
+            get_vscode_mcp_config_path().unwrap_or_default(),
+        ),
+        (
+            "windsurf".to_string(),
+            get_windsurf_mcp_config_path().unwrap_or_default(),
+        ),
+        (
+            "zed".to_string(),
+            get_zed_settings_path().unwrap_or_default(),
+        ),
     ]
 }
 
@@ -148,14 +245,57 @@ mod tests {
         assert!(path.ends_with("mcp.json"));
     }
 
+    #[test]
+    fn test_get_vscode_mcp_config_path() {
+        let path = get_vscode_mcp_config_path().unwrap();
+        assert!(path.ends_with("mcp.json"));
+        if env::var_os("VSCODE_CONFIG_DIR").is_none() {
+            assert!(path.parent().unwrap().ends_with("Code/User"));
+        }
+    }
+
+    #[test]
+    fn test_get_windsurf_and_zed_config_paths() {
+        let windsurf = get_windsurf_mcp_config_path().unwrap();
+        assert!(windsurf.ends_with("mcp_config.json"));
+        let zed = get_zed_settings_path().unwrap();
+        assert!(zed.ends_with("settings.json"));
+        if env::var_os("ZED_CONFIG_DIR").is_none() && cfg!(not(windows)) {
+            assert!(zed.parent().unwrap().ends_with("zed"));
+        }
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_windows_config_locations() {
+        let appdata = PathBuf::from(env::var_os("APPDATA").unwrap());
+        if env::var_os("VSCODE_CONFIG_DIR").is_none() {
+            assert_eq!(
+                get_vscode_config_dir().unwrap(),
+                appdata.join("Code").join("User")
+            );
+        }
+        if env::var_os("ZED_CONFIG_DIR").is_none() {
+            assert_eq!(get_zed_config_dir().unwrap(), appdata.join("Zed"));
+        }
+        let profile = PathBuf::from(env::var_os("USERPROFILE").unwrap());
+        assert_eq!(
+            get_claude_code_mcp_config_path().unwrap(),
+            profile.join(".claude.json")
+        );
+    }
+
     #[test]
     fn test_get_all_config_paths() {
         let paths = get_all_config_paths();
-        assert_eq!(paths.len(), 2, "Should return paths for both environments");
+        assert_eq!(paths.len(), 5, "Should return paths for all environments");
 
         let environment_names: Vec<&String> = paths.iter().map(|(name, _)| name).collect();
         assert!(environment_names.contains(&&"claude-code".to_string()));
         assert!(environment_names.contains(&&"cursor".to_string()));
+        assert!(environment_names.contains(&&"vscode".to_string()));
+        assert!(environment_names.contains(&&"windsurf".to_string()));
+        assert!(environment_names.contains(&&"zed".to_string()));
     }
 
     #[test]
@@ -2,7 +2,7 @@
 
 use anyhow::{Context, Result};
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Result of an installation operation
 #[derive(Debug, Clone)]
@@ -21,6 +21,11 @@ pub struct UninstallationResult {
     pub files_removed: Vec<String>,
 }
 
+/// File name of the foundry binary on this platform (`foundry.exe` on Windows)
+pub fn binary_file_name() -> String {
+    format!("foundry{}", env::consts::EXE_SUFFIX)
+}
+
 /// Detect the current binary path
 ///
 /// Attempts to detect the path of the currently running foundry binary
@@ -30,16 +35,73 @@ pub fn detect_binary_path() -> Result<String> {
 
     let binary_path = current_exe
         .to_str()
-        .context("Binary path contains invalid Unicode characters")?
-        .to_string();
+        .context("Binary path contains invalid Unicode characters")?;
+
+    Ok(normalize_command_path(binary_path))
+}
+
+/// Normalize a command path for MCP configuration files
+///
+/// On Windows this drops the `\\?\` verbatim prefix (which many clients cannot
+/// spawn) and uses backslashes throughout; elsewhere the path is unchanged.
+pub fn normalize_command_path(command: &str) -> String {
+    if cfg!(windows) {
+        normalize_windows_path(command)
+    } else {
+        command.to_string()
+    }
+}
+
+/// Windows form of a path: no verbatim prefix, backslash separators
+pub fn normalize_windows_path(path: &str) -> String {
+    let path = path.replace('/', "\\");
+    path.strip_prefix(r"\\?\UNC\").map_or_else(
+        || path.strip_prefix(r"\\?\").unwrap_or(&path).to_string(),
+        |share| format!(r"\\{}", share),
+    )
+}
 
-    Ok(binary_path)
+/// Whether a configured command names a file rather than a program on PATH
+///
+/// Root-relative paths such as `/usr/bin/foundry` count on Windows too, where
+/// they are not absolute (no drive) but still never resolve through PATH.
+pub fn is_path_command(command: &str) -> bool {
+    let path = Path::new(command);
+    path.is_absolute() || path.has_root()
+}
+
+/// The file a command runs, if it can be found
+///
+/// Path commands are checked directly (with `.exe` appended on Windows when
+/// they have no extension, as Windows resolves them); other commands are
+/// looked up on PATH.
+pub fn resolve_command(command: &str) -> Option<PathBuf> {
+    if command.trim().is_empty() {
+        return None;
+    }
+    if !is_path_command(command) {
+        return which::which(command).ok();
+    }
+    let path = PathBuf::from(command);
+    if path.is_file() {
+        return Some(path);
+    }
+    let with_suffix = path.with_extension(env::consts::EXE_EXTENSION);
+    (!env::consts::EXE_EXTENSION.is_empty() && path.extension().is_none() && with_suffix.is_file())
+        .then_some(with_suffix)
+}
+
+/// Whether a configured command is a path to a file that does not exist
+///
+/// Commands found through PATH are not checked here; they are resolved when
+/// the client starts the server.
+pub fn is_missing_path_command(command: &str) -> bool {
+    is_path_command(command) && resolve_command(command).is_none()
 }
 
 /// Check if the binary at the given path is accessible
 pub fn check_binary_accessible(binary_path: &str) -> bool {
-    let path = PathBuf::from(binary_path);
-    path.exists() && path.is_file()
+    resolve_command(binary_path).is_some()
 }
 
 /// Validate that a binary path exists and is executable
@@ -152,6 +214,45 @@ mod tests {
         assert!(result.is_err(), "Nonexistent path should fail validation");
     }
 
+    #[test]
+    fn test_normalize_windows_path() {
+        assert_eq!(
+            normalize_windows_path(r"\\?\C:\Users\dev\.cargo\bin\foundry.exe"),
+            r"C:\Users\dev\.cargo\bin\foundry.exe"
+        );
+        assert_eq!(
+            normalize_windows_path(r"\\?\UNC\server\tools\foundry.exe"),
+            r"\\server\tools\foundry.exe"
+        );
+        assert_eq!(
+            normalize_windows_path("C:/tools/foundry.exe"),
+            r"C:\tools\foundry.exe"
+        );
+    }
+
+    #[test]
+    fn test_path_commands_are_checked_and_path_lookups_are_not() {
+        assert!(is_path_command("/usr/local/bin/foundry"));
+        assert!(!is_path_command("foundry"));
+        assert!(is_missing_path_command("/nonexistent/foundry"));
+        assert!(!is_missing_path_command("foundry"));
+        assert!(check_binary_accessible(&detect_binary_path().unwrap()));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_windows_binary_detection() {
+        assert_eq!(binary_file_name(), "foundry.exe");
+        let binary_path = detect_binary_path().unwrap();
+        assert!(!binary_path.starts_with(r"\\?\"));
+        assert!(!binary_path.contains('/'));
+
+        // Configs may name the binary without its extension, as cmd.exe allows
+        let without_suffix = binary_path.strip_suffix(".exe").unwrap();
+        assert!(check_binary_accessible(without_suffix));
+        assert!(!is_missing_path_command(without_suffix));
+    }
+
     #[test]
     fn test_get_home_dir() {
         let result = get_home_dir();
@@ -0,0 +1,312 @@
+//! VS Code MCP server installation and management
+//!
+//! VS Code keeps user-level MCP servers in `mcp.json` under a `servers` key,
+//! next to other settings such as `inputs`. GitHub Copilot agent mode and other
+//! extensions that use VS Code's MCP support read servers from this file.
+//! Unlike Cursor's `mcpServers` file, entries carry a `type`, so the file is
+//! edited as JSON and every key Foundry does not own is left untouched.
+
+use crate::core::installation::json_config::{
+    check_server_command, is_empty_object, object_entry_mut, read_json_object, write_json_object,
+};
+use crate::core::installation::{
+    InstallationResult, UninstallationResult, create_installation_result,
+    create_uninstallation_result, get_vscode_mcp_config_path, validate_config_dir_writable,
+};
+use crate::types::responses::EnvironmentStatus;
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+
+/// Name of the Foundry entry under `servers`
+const SERVER_NAME: &str = "foundry";
+
+/// Key holding MCP servers in VS Code's mcp.json
+const SERVERS_KEY: &str = "servers";
+
+/// Install Foundry MCP server for VS Code
+pub async fn install_for_vscode() -> Result<InstallationResult> {
+    let config_path = get_vscode_mcp_config_path()?;
+    let config_path_str = config_path.to_string_lossy().to_string();
+
+    validate_config_dir_writable(config_path.as_path())?;
+
+    let mut actions_taken = Vec::new();
+
+    let mut config =
+        read_json_object(&config_path).context("Failed to read existing MCP configuration")?;
+    let servers = object_entry_mut(&mut config, SERVERS_KEY)?;
+
+    let was_already_configured = servers.contains_key(SERVER_NAME);
+    servers.insert(SERVER_NAME.to_string(), foundry_server_entry());
+
+    if was_already_configured {
+        actions_taken
+            .push("Updated existing Foundry MCP server in VS Code configuration".to_string());
+    } else {
+        actions_taken.push("Added Foundry MCP server to VS Code configuration".to_string());
+    }
+
+    write_json_object(&config_path, "", &config).context("Failed to write MCP configuration")?;
+    actions_taken.push(format!("Updated configuration file: {}", config_path_str));
+    actions_taken.push(
+        "Start the 'foundry' server from the MCP Servers view or Copilot Chat's tool picker"
+            .to_string(),
+    );
+
+    Ok(create_installation_result(
+        true,
+        config_path_str,
+        actions_taken,
+    ))
+}
+
+/// Uninstall Foundry MCP server from VS Code
+pub async fn uninstall_from_vscode(remove_config: bool) -> Result<UninstallationResult> {
+    let config_path = get_vscode_mcp_config_path()?;
+    let config_path_str = config_path.to_string_lossy().to_string();
+
+    let mut actions_taken = Vec::new();
+    let mut files_removed = Vec::new();
+
+    let mut config = read_json_object(&config_path)?;
+    let servers = object_entry_mut(&mut config, SERVERS_KEY)?;
+
+    if servers.remove(SERVER_NAME).is_none() {
+        return Err(anyhow::anyhow!(
+            "Foundry MCP server is not configured for VS Code"
+        ));
+    }
+    actions_taken.push("Removed Foundry MCP server from VS Code configuration".to_string());
+
+    // Only remove the file when nothing else is left in it
+    if remove_config && is_empty_object(&config) {
+        if config_path.exists() {
+            std::fs::remove_file(&config_path).context("Failed to remove configuration file")?;
+            files_removed.push(config_path_str.clone());
+            actions_taken.push(format!("Removed configuration file: {}", config_path_str));
+        }
+    } else {
+        write_json_object(&config_path, "", &config)
+            .context("Failed to write updated MCP configuration")?;
+        actions_taken.push(format!("Updated configuration file: {}", config_path_str));
+    }
+
+    Ok(create_uninstallation_result(
+        true,
+        config_path_str,
+        actions_taken,
+        files_removed,
+    ))
+}
+
+/// Get environment status for VS Code
+pub async fn get_vscode_status(detailed: bool) -> Result<EnvironmentStatus> {
+    let config_path = get_vscode_mcp_config_path()?;
+    let config_path_str = config_path.to_string_lossy().to_string();
+
+    let mut issues = Vec::new();
+    let mut installed = false;
+    let mut binary_accessible = false;
+    let mut config_content = None;
+
+    let config_exists = config_path.exists();
+    if config_exists {
+        if detailed {
+            config_content = Some(
+                std::fs::read_to_string(&config_path)
+                    .unwrap_or_else(|_| "Error reading config file".to_string()),
+            );
+        }
+
+        match read_json_object(&config_path) {
+            Ok(config) => match config
+                .get(SERVERS_KEY)
+                .and_then(|servers| servers.get(SERVER_NAME))
+            {
+                Some(server) => {
+                    installed = true;
+                    binary_accessible = check_server_command(
+                        server["command"].as_str().unwrap_or_default(),
+                        &mut issues,
+                    );
+                }
+                None => issues.push("Foundry MCP server not found in configuration".to_string()),
+            },
+            Err(e) => issues.push(format!("Failed to read configuration: {:#}", e)),
+        }
+    } else {
+        issues.push("MCP configuration file does not exist".to_string());
+    }
+
+    Ok(EnvironmentStatus {
+        name: "vscode".to_string(),
+        installed,
+        config_path: config_path_str,
+        config_exists,
+        binary_path: if installed {
+            crate::core::installation::detect_binary_path()
+                .unwrap_or_else(|_| "unknown".to_string())
+        } else {
+            "unknown".to_string()
+        },
+        binary_accessible,
+        config_content,
+        issues,
+    })
+}
+
+/// Server entry written for Foundry, using `foundry` from PATH
+fn foundry_server_entry() -> Value {
+    json!({
+        "type": "stdio",
+        "command": "foundry",
+        "args": ["serve"],
+        "env": { "FOUNDRY_LOG_LEVEL": "info" }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_environment::TestEnvironment;
+    use std::path::Path;
+
+    fn read_json(path: &Path) -> Value {
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_install_for_vscode_preserves_other_settings() {
+        let env = TestEnvironment::new().unwrap();
+
+        env.with_env_async(|| async {
+            let config_path = env.vscode_config_path();
+            std::fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+            std::fs::write(
+                &config_path,
+                r#"{"inputs": [{"id": "token", "type": "promptString"}], "servers": {"github": {"type": "http", "url": "https://api.githubcopilot.com/mcp/"}}}"#,
+            )
+            .unwrap();
+
+            let result = install_for_vscode().await.unwrap();
+            assert!(result.success);
+            assert!(
+                result
+                    .actions_taken
+                    .iter()
+                    .any(|action| action.contains("Added Foundry MCP server"))
+            );
+
+            let config = read_json(&config_path);
+            assert_eq!(config["servers"]["foundry"]["type"], "stdio");
+            assert_eq!(config["servers"]["foundry"]["command"], "foundry");
+            assert_eq!(config["servers"]["foundry"]["args"], json!(["serve"]));
+            assert_eq!(config["servers"]["github"]["type"], "http");
+            assert_eq!(config["inputs"][0]["id"], "token");
+
+            // Installing again updates the entry in place
+            let result = install_for_vscode().await.unwrap();
+            assert!(
+                result
+                    .actions_taken
+                    .iter()
+                    .any(|action| action.contains("Updated existing Foundry MCP server"))
+            );
+        });
+    }
+
+    #[test]
+    fn test_install_for_vscode_rejects_unparseable_config() {
+        let env = TestEnvironment::new().unwrap();
+
+        env.with_env_async(|| async {
+            let config_path = env.vscode_config_path();
+            std::fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+            std::fs::write(&config_path, "{\n  // my servers\n  \"servers\": {}\n}").unwrap();
+
+            let error = install_for_vscode().await.unwrap_err();
+            assert!(format!("{:#}", error).contains("Comments and trailing commas"));
+            // The file is left as it was
+            assert!(
+                std::fs::read_to_string(&config_path)
+                    .unwrap()
+                    .contains("// my servers")
+            );
+        });
+    }
+
+    #[test]
+    fn test_uninstall_from_vscode() {
+        let env = TestEnvironment::new().unwrap();
+
+        env.with_env_async(|| async {
+            assert!(
+                uninstall_from_vscode(false)
+                    .await
+                    .unwrap_err()
+                    .to_string()
+                    .contains("not configured")
+            );
+
+            install_for_vscode().await.unwrap();
+            let result = uninstall_from_vscode(true).await.unwrap();
+            assert!(result.success);
+            assert!(
+                result
+                    .files_removed
+                    .iter()
+                    .any(|file| file.contains("mcp.json"))
+            );
+            assert!(!env.vscode_config_path().exists());
+        });
+    }
+
+    #[test]
+    fn test_uninstall_from_vscode_keeps_other_servers() {
+        let env = TestEnvironment::new().unwrap();
+
+        env.with_env_async(|| async {
+            let config_path = env.vscode_config_path();
+            std::fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+            std::fs::write(
+                &config_path,
+                r#"{"servers": {"other": {"type": "stdio", "command": "other"}}}"#,
+            )
+            .unwrap();
+            install_for_vscode().await.unwrap();
+
+            let result = uninstall_from_vscode(true).await.unwrap();
+            assert!(result.files_removed.is_empty());
+
+            let config = read_json(&config_path);
+            assert!(config["servers"].get("foundry").is_none());
+            assert_eq!(config["servers"]["other"]["command"], "other");
+        });
+    }
+
+    #[test]
+    fn test_get_vscode_status() {
+        let env = TestEnvironment::new().unwrap();
+
+        env.with_env_async(|| async {
+            let status = get_vscode_status(false).await.unwrap();
+            assert_eq!(status.name, "vscode");
+            assert!(!status.installed);
+            assert!(!status.config_exists);
+            assert!(
+                status
+                    .issues
+                    .iter()
+                    .any(|issue| issue.contains("does not exist"))
+            );
+
+            install_for_vscode().await.unwrap();
+            let status = get_vscode_status(true).await.unwrap();
+            assert!(status.installed);
+            assert!(status.config_exists);
+            assert!(status.binary_accessible);
+            assert!(status.issues.is_empty());
+            assert!(status.config_content.unwrap().contains("\"servers\""));
+        });
+    }
+}
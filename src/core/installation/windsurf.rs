@@ -0,0 +1,307 @@
+//! Windsurf MCP server installation and management
+//!
+//! Windsurf keeps MCP servers for all workspaces in
+//! `~/.codeium/windsurf/mcp_config.json` under `mcpServers`, like Cursor's
+//! mcp.json, but entries may also be remote servers with a `serverUrl` and no
+//! command, so the file is edited as JSON and other entries are left
+//! untouched. Rules and workflows are project-local, in `./.windsurf/`.
+
+use crate::core::filesystem::write_file_atomic;
+use crate::core::installation::json_config::{
+    check_server_command, is_empty_object, object_entry_mut, read_json_object, write_json_object,
+};
+use crate::core::installation::paths::{get_windsurf_mcp_config_path, get_windsurf_workspace_dir};
+use crate::core::installation::{
+    InstallationResult, UninstallationResult, create_installation_result,
+    create_uninstallation_result, validate_config_dir_writable,
+};
+use crate::core::templates::ClientTemplate;
+use crate::core::templates::commands::{
+    install_windsurf_workflows, remove_commands, windsurf_workflows_dir,
+};
+use crate::core::templates::render::render_installed;
+use crate::core::templates::windsurf_rules::WindsurfRulesTemplate;
+use crate::types::responses::EnvironmentStatus;
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use std::fs;
+
+/// Name of the Foundry entry under `mcpServers`
+const SERVER_NAME: &str = "foundry";
+
+/// Key holding MCP servers in Windsurf's mcp_config.json
+const SERVERS_KEY: &str = "mcpServers";
+
+/// Install Foundry MCP server for Windsurf
+pub async fn install_for_windsurf() -> Result<InstallationResult> {
+    let config_path = get_windsurf_mcp_config_path()?;
+    let config_path_str = config_path.to_string_lossy().to_string();
+
+    validate_config_dir_writable(config_path.as_path())?;
+
+    let mut actions_taken = Vec::new();
+
+    let mut config =
+        read_json_object(&config_path).context("Failed to read existing MCP configuration")?;
+    let servers = object_entry_mut(&mut config, SERVERS_KEY)?;
+
+    let was_already_configured = servers.contains_key(SERVER_NAME);
+    servers.insert(SERVER_NAME.to_string(), foundry_server_entry());
+
+    if was_already_configured {
+        actions_taken
+            .push("Updated existing Foundry MCP server in Windsurf configuration".to_string());
+    } else {
+        actions_taken.push("Added Foundry MCP server to Windsurf configuration".to_string());
+    }
+
+    write_json_object(&config_path, "", &config).context("Failed to write MCP configuration")?;
+    actions_taken.push(format!("Updated configuration file: {}", config_path_str));
+    actions_taken
+        .push("Press refresh in Cascade's MCP servers panel to load the server".to_string());
+
+    // Rules and workflows are project-level; failures are non-fatal
+    match get_windsurf_workspace_dir().and_then(|dir| install_windsurf_rules_template(&dir)) {
+        Ok(message) => actions_taken.push(message),
+        Err(e) => actions_taken.push(format!(
+            "Warning: Failed to install Windsurf rules template: {}",
+            e
+        )),
+    }
+    match get_windsurf_workspace_dir()
+        .and_then(|dir| install_windsurf_workflows(&windsurf_workflows_dir(&dir)))
+    {
+        Ok(message) => actions_taken.push(message),
+        Err(e) => actions_taken.push(format!(
+            "Warning: Failed to install Windsurf workflows: {}",
+            e
+        )),
+    }
+
+    Ok(create_installation_result(
+        true,
+        config_path_str,
+        actions_taken,
+    ))
+}
+
+/// Uninstall Foundry MCP server from Windsurf
+pub async fn uninstall_from_windsurf(remove_config: bool) -> Result<UninstallationResult> {
+    let config_path = get_windsurf_mcp_config_path()?;
+    let config_path_str = config_path.to_string_lossy().to_string();
+
+    let mut actions_taken = Vec::new();
+    let mut files_removed = Vec::new();
+
+    let mut config = read_json_object(&config_path)?;
+    let servers = object_entry_mut(&mut config, SERVERS_KEY)?;
+
+    if servers.remove(SERVER_NAME).is_none() {
+        return Err(anyhow::anyhow!(
+            "Foundry MCP server is not configured for Windsurf"
+        ));
+    }
+    actions_taken.push("Removed Foundry MCP server from Windsurf configuration".to_string());
+
+    // Only remove the file when nothing else is left in it
+    if remove_config && is_empty_object(&config) {
+        if config_path.exists() {
+            fs::remove_file(&config_path).context("Failed to remove configuration file")?;
+            files_removed.push(config_path_str.clone());
+            actions_taken.push(format!("Removed configuration file: {}", config_path_str));
+        }
+    } else {
+        write_json_object(&config_path, "", &config)
+            .context("Failed to write updated MCP configuration")?;
+        actions_taken.push(format!("Updated configuration file: {}", config_path_str));
+    }
+
+    let workspace_dir = get_windsurf_workspace_dir()?;
+    let rules_path = WindsurfRulesTemplate::file_path(&workspace_dir)?;
+    if rules_path.exists() {
+        match fs::remove_file(&rules_path) {
+            Ok(()) => {
+                actions_taken.push(format!(
+                    "Removed Windsurf rules template: {}",
+                    rules_path.to_string_lossy()
+                ));
+                files_removed.push(rules_path.to_string_lossy().to_string());
+            }
+            Err(e) => actions_taken.push(format!(
+                "Warning: Failed to remove Windsurf rules template: {}",
+                e
+            )),
+        }
+    }
+    match remove_commands(&windsurf_workflows_dir(&workspace_dir)) {
+        Ok(Some(message)) => {
+            actions_taken.push(message);
+            files_removed.push("Windsurf workflows".to_string());
+        }
+        Ok(None) => {}
+        Err(e) => actions_taken.push(format!(
+            "Warning: Failed to remove Windsurf workflows: {}",
+            e
+        )),
+    }
+
+    Ok(create_uninstallation_result(
+        true,
+        config_path_str,
+        actions_taken,
+        files_removed,
+    ))
+}
+
+/// Get environment status for Windsurf
+pub async fn get_windsurf_status(detailed: bool) -> Result<EnvironmentStatus> {
+    let config_path = get_windsurf_mcp_config_path()?;
+    let config_path_str = config_path.to_string_lossy().to_string();
+
+    let mut issues = Vec::new();
+    let mut installed = false;
+    let mut binary_accessible = false;
+    let mut config_content = None;
+
+    let config_exists = config_path.exists();
+    if config_exists {
+        if detailed {
+            config_content = Some(
+                fs::read_to_string(&config_path)
+                    .unwrap_or_else(|_| "Error reading config file".to_string()),
+            );
+        }
+
+        match read_json_object(&config_path) {
+            Ok(config) => match config
+                .get(SERVERS_KEY)
+                .and_then(|servers| servers.get(SERVER_NAME))
+            {
+                Some(server) => {
+                    installed = true;
+                    binary_accessible = check_server_command(
+                        server["command"].as_str().unwrap_or_default(),
+                        &mut issues,
+                    );
+                    if server["disabled"].as_bool() == Some(true) {
+                        issues.push(
+                            "Foundry MCP server is disabled in Windsurf's MCP settings".to_string(),
+                        );
+                    }
+                }
+                None => issues.push("Foundry MCP server not found in configuration".to_string()),
+            },
+            Err(e) => issues.push(format!("Failed to read configuration: {:#}", e)),
+        }
+    } else {
+        issues.push("MCP configuration file does not exist".to_string());
+    }
+
+    Ok(EnvironmentStatus {
+        name: "windsurf".to_string(),
+        installed,
+        config_path: config_path_str,
+        config_exists,
+        binary_path: if installed {
+            crate::core::installation::detect_binary_path()
+                .unwrap_or_else(|_| "unknown".to_string())
+        } else {
+            "unknown".to_string()
+        },
+        binary_accessible,
+        config_content,
+        issues,
+    })
+}
+
+/// Server entry written for Foundry, using `foundry` from PATH
+fn foundry_server_entry() -> Value {
+    json!({
+        "command": "foundry",
+        "args": ["serve"],
+        "env": { "FOUNDRY_LOG_LEVEL": "info" }
+    })
+}
+
+/// Install the Windsurf rules template into the workspace's .windsurf directory
+fn install_windsurf_rules_template(workspace_dir: &std::path::Path) -> Result<String> {
+    let template_path = WindsurfRulesTemplate::file_path(workspace_dir)
+        .context("Failed to resolve Windsurf rules template path")?;
+    if let Some(parent) = template_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create template directory: {:?}", parent))?;
+    }
+
+    let content = render_installed(
+        "windsurf/rules",
+        WindsurfRulesTemplate::content(),
+        "windsurf",
+    )?;
+    write_file_atomic(&template_path, &content).with_context(|| {
+        format!(
+            "Failed to write Windsurf rules template: {:?}",
+            template_path
+        )
+    })?;
+
+    Ok(format!(
+        "Created Windsurf rules template: {}",
+        template_path.to_string_lossy()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_environment::TestEnvironment;
+
+    #[test]
+    fn test_install_for_windsurf_keeps_remote_servers() {
+        let env = TestEnvironment::new().unwrap();
+
+        env.with_env_async(|| async {
+            let config_path = env.windsurf_config_path();
+            fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+            fs::write(
+                &config_path,
+                r#"{"mcpServers": {"remote": {"serverUrl": "https://example.com/mcp"}}}"#,
+            )
+            .unwrap();
+
+            let result = install_for_windsurf().await.unwrap();
+            assert!(result.success);
+
+            let config: Value =
+                serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+            assert_eq!(config["mcpServers"]["foundry"]["command"], "foundry");
+            assert_eq!(config["mcpServers"]["foundry"]["args"], json!(["serve"]));
+            assert_eq!(
+                config["mcpServers"]["remote"]["serverUrl"],
+                "https://example.com/mcp"
+            );
+
+            let workspace = env.windsurf_workspace_dir();
+            let rules = fs::read_to_string(workspace.join("rules/foundry.md")).unwrap();
+            assert!(rules.starts_with("---\ntrigger: always_on\n---"));
+            assert!(
+                rules.len() < 12_000,
+                "Windsurf caps rule files at 12,000 characters"
+            );
+            assert!(workspace.join("workflows/foundry_create_spec.md").exists());
+
+            let status = get_windsurf_status(false).await.unwrap();
+            assert!(status.installed);
+            assert!(status.issues.is_empty());
+
+            let result = uninstall_from_windsurf(true).await.unwrap();
+            assert!(result.success);
+            assert!(!workspace.join("rules/foundry.md").exists());
+            assert!(!workspace.join("workflows/foundry_create_spec.md").exists());
+            // The remote server keeps the file alive
+            let config: Value =
+                serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+            assert!(config["mcpServers"].get("foundry").is_none());
+            assert!(config["mcpServers"].get("remote").is_some());
+        });
+    }
+}
@@ -0,0 +1,271 @@
+//! Zed MCP server installation and management
+//!
+//! Zed reads MCP servers ("context servers") from `context_servers` in its
+//! user settings.json, next to every other editor setting. The file is
+//! edited as JSON and every key Foundry does not own is left untouched. Zed
+//! writes a comment header above the settings object in new files; that
+//! header is kept, but comments inside the object cannot be preserved, so
+//! such files are left alone with instructions to add the server by hand.
+//!
+//! Zed has no per-tool rules or command files to install, and its project
+//! `.rules` file belongs to the user, so only the server is configured.
+
+use crate::core::installation::json_config::{
+    check_server_command, is_empty_object, object_entry_mut, parse_json_object, write_json_object,
+};
+use crate::core::installation::paths::get_zed_settings_path;
+use crate::core::installation::{
+    InstallationResult, UninstallationResult, create_installation_result,
+    create_uninstallation_result, validate_config_dir_writable,
+};
+use crate::types::responses::EnvironmentStatus;
+use anyhow::{Context, Result};
+use serde_json::{Map, Value, json};
+use std::fs;
+use std::path::Path;
+
+/// Name of the Foundry entry under `context_servers`
+const SERVER_NAME: &str = "foundry";
+
+/// Key holding MCP servers in Zed's settings.json
+const SERVERS_KEY: &str = "context_servers";
+
+/// Install Foundry MCP server for Zed
+pub async fn install_for_zed() -> Result<InstallationResult> {
+    let settings_path = get_zed_settings_path()?;
+    let settings_path_str = settings_path.to_string_lossy().to_string();
+
+    validate_config_dir_writable(settings_path.as_path())?;
+
+    let mut actions_taken = Vec::new();
+
+    let (header, mut settings) =
+        read_zed_settings(&settings_path).context("Failed to read existing Zed settings")?;
+    let servers = object_entry_mut(&mut settings, SERVERS_KEY)?;
+
+    let was_already_configured = servers.contains_key(SERVER_NAME);
+    servers.insert(SERVER_NAME.to_string(), foundry_server_entry());
+
+    if was_already_configured {
+        actions_taken.push("Updated existing Foundry MCP server in Zed settings".to_string());
+    } else {
+        actions_taken.push("Added Foundry MCP server to Zed settings".to_string());
+    }
+
+    write_json_object(&settings_path, &header, &settings)
+        .context("Failed to write Zed settings")?;
+    actions_taken.push(format!("Updated configuration file: {}", settings_path_str));
+    actions_taken.push(
+        "Zed starts the server on its own; check it in the agent panel's settings".to_string(),
+    );
+
+    Ok(create_installation_result(
+        true,
+        settings_path_str,
+        actions_taken,
+    ))
+}
+
+/// Uninstall Foundry MCP server from Zed
+///
+/// settings.json holds all of Zed's settings, so it is only removed with
+/// `remove_config` when nothing else, not even a comment, is left in it.
+pub async fn uninstall_from_zed(remove_config: bool) -> Result<UninstallationResult> {
+    let settings_path = get_zed_settings_path()?;
+    let settings_path_str = settings_path.to_string_lossy().to_string();
+
+    let mut actions_taken = Vec::new();
+    let mut files_removed = Vec::new();
+
+    let (header, mut settings) = read_zed_settings(&settings_path)?;
+    let servers = object_entry_mut(&mut settings, SERVERS_KEY)?;
+
+    if servers.remove(SERVER_NAME).is_none() {
+        return Err(anyhow::anyhow!(
+            "Foundry MCP server is not configured for Zed"
+        ));
+    }
+    actions_taken.push("Removed Foundry MCP server from Zed settings".to_string());
+
+    if remove_config && header.trim().is_empty() && is_empty_object(&settings) {
+        if settings_path.exists() {
+            fs::remove_file(&settings_path).context("Failed to remove configuration file")?;
+            files_removed.push(settings_path_str.clone());
+            actions_taken.push(format!("Removed configuration file: {}", settings_path_str));
+        }
+    } else {
+        write_json_object(&settings_path, &header, &settings)
+            .context("Failed to write updated Zed settings")?;
+        actions_taken.push(format!("Updated configuration file: {}", settings_path_str));
+    }
+
+    Ok(create_uninstallation_result(
+        true,
+        settings_path_str,
+        actions_taken,
+        files_removed,
+    ))
+}
+
+/// Get environment status for Zed
+pub async fn get_zed_status(detailed: bool) -> Result<EnvironmentStatus> {
+    let settings_path = get_zed_settings_path()?;
+    let settings_path_str = settings_path.to_string_lossy().to_string();
+
+    let mut issues = Vec::new();
+    let mut installed = false;
+    let mut binary_accessible = false;
+    let mut config_content = None;
+
+    let config_exists = settings_path.exists();
+    if config_exists {
+        if detailed {
+            config_content = Some(
+                fs::read_to_string(&settings_path)
+                    .unwrap_or_else(|_| "Error reading config file".to_string()),
+            );
+        }
+
+        match read_zed_settings(&settings_path) {
+            Ok((_, settings)) => match settings
+                .get(SERVERS_KEY)
+                .and_then(|servers| servers.get(SERVER_NAME))
+            {
+                Some(server) => {
+                    installed = true;
+                    binary_accessible = check_server_command(server_command(server), &mut issues);
+                }
+                None => issues.push("Foundry MCP server not found in configuration".to_string()),
+            },
+            Err(e) => issues.push(format!("Failed to read configuration: {:#}", e)),
+        }
+    } else {
+        issues.push("Zed settings file does not exist".to_string());
+    }
+
+    Ok(EnvironmentStatus {
+        name: "zed".to_string(),
+        installed,
+        config_path: settings_path_str,
+        config_exists,
+        binary_path: if installed {
+            crate::core::installation::detect_binary_path()
+                .unwrap_or_else(|_| "unknown".to_string())
+        } else {
+            "unknown".to_string()
+        },
+        binary_accessible,
+        config_content,
+        issues,
+    })
+}
+
+/// Server entry written for Foundry, using `foundry` from PATH
+fn foundry_server_entry() -> Value {
+    json!({
+        "source": "custom",
+        "command": "foundry",
+        "args": ["serve"],
+        "env": { "FOUNDRY_LOG_LEVEL": "info" }
+    })
+}
+
+/// Command of a context server entry; older Zed versions nest it as `command.path`
+fn server_command(server: &Value) -> &str {
+    server["command"]
+        .as_str()
+        .or_else(|| server["command"]["path"].as_str())
+        .unwrap_or_default()
+}
+
+/// Read Zed's settings.json as its comment header and settings object; a
+/// missing or empty file is an empty object
+fn read_zed_settings(settings_path: &Path) -> Result<(String, Map<String, Value>)> {
+    if !settings_path.exists() {
+        return Ok((String::new(), Map::new()));
+    }
+    let content = fs::read_to_string(settings_path)
+        .with_context(|| format!("Failed to read config file: {}", settings_path.display()))?;
+    let (header, body) = split_comment_header(&content);
+    Ok((header.to_string(), parse_json_object(body, settings_path)?))
+}
+
+/// Split off the blank and `//` comment lines before the settings object
+fn split_comment_header(content: &str) -> (&str, &str) {
+    let mut header_len = 0;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if !trimmed.is_empty() && !trimmed.starts_with("//") {
+            break;
+        }
+        header_len += line.len();
+    }
+    content.split_at(header_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_environment::TestEnvironment;
+
+    const DEFAULT_SETTINGS: &str = "// Zed settings\n//\n// For information on how to configure Zed, see the Zed\n// documentation: https://zed.dev/docs/configuring-zed\n{\n  \"theme\": \"One Dark\",\n  \"context_servers\": {\n    \"other\": {\"command\": {\"path\": \"other\", \"args\": []}}\n  }\n}\n";
+
+    #[test]
+    fn test_install_for_zed_keeps_header_and_settings() {
+        let env = TestEnvironment::new().unwrap();
+
+        env.with_env_async(|| async {
+            let settings_path = env.zed_settings_path();
+            fs::create_dir_all(settings_path.parent().unwrap()).unwrap();
+            fs::write(&settings_path, DEFAULT_SETTINGS).unwrap();
+
+            let result = install_for_zed().await.unwrap();
+            assert!(result.success);
+
+            let content = fs::read_to_string(&settings_path).unwrap();
+            assert!(content.starts_with("// Zed settings\n//\n"));
+            let (_, settings) = read_zed_settings(&settings_path).unwrap();
+            assert_eq!(settings["theme"], "One Dark");
+            assert_eq!(settings["context_servers"]["foundry"]["command"], "foundry");
+            assert_eq!(settings["context_servers"]["foundry"]["source"], "custom");
+            assert_eq!(
+                settings["context_servers"]["other"]["command"]["path"],
+                "other"
+            );
+
+            let status = get_zed_status(false).await.unwrap();
+            assert!(status.installed);
+            assert!(status.binary_accessible);
+
+            uninstall_from_zed(true).await.unwrap();
+            // Other settings keep the file
+            assert!(settings_path.exists());
+            let (_, settings) = read_zed_settings(&settings_path).unwrap();
+            assert!(settings["context_servers"].get("foundry").is_none());
+            assert!(uninstall_from_zed(false).await.is_err());
+        });
+    }
+
+    #[test]
+    fn test_install_for_zed_rejects_comments_inside_settings() {
+        let env = TestEnvironment::new().unwrap();
+
+        env.with_env_async(|| async {
+            let settings_path = env.zed_settings_path();
+            fs::create_dir_all(settings_path.parent().unwrap()).unwrap();
+            fs::write(
+                &settings_path,
+                "{\n  // my theme\n  \"theme\": \"One Dark\"\n}",
+            )
+            .unwrap();
+
+            let error = install_for_zed().await.unwrap_err();
+            assert!(format!("{:#}", error).contains("Comments and trailing commas"));
+            assert!(
+                fs::read_to_string(&settings_path)
+                    .unwrap()
+                    .contains("// my theme")
+            );
+        });
+    }
+}
@@ -0,0 +1,112 @@
+//! Bounded JSON journals below `~/.foundry`
+//!
+//! The update journal (`core::history`) and the activity journal
+//! (`core::activity`) each keep their newest entries as a JSON array in one
+//! file. Changing a journal reads the array, edits it and writes it back while
+//! holding a lock file next to it, so two processes recording at once do not
+//! drop each other's entries:
+//!
+//! ```text
+//! .activity/<project>.json
+//! .activity/<project>.lock
+//! ```
+//!
+//! Journals are encrypted when encryption at rest is on, and none are kept
+//! while backups are turned off (e.g. during `foundry replay`).
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::core::{backup, lock};
+
+/// The journal directory `name` below `~/.foundry` in the current context;
+/// `None` when backups are turned off
+pub fn dir(name: &str) -> Result<Option<PathBuf>> {
+    if backup::current_dir()?.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(crate::core::filesystem::foundry_dir()?.join(name)))
+}
+
+/// Entries of the journal at `path`, oldest first; empty if it does not exist
+pub fn read<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>> {
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let content = crate::core::filesystem::read_file(path)
+        .with_context(|| format!("Failed to read journal {:?}", path))?;
+    serde_json::from_str(&content).with_context(|| format!("Invalid journal {:?}", path))
+}
+
+/// Append `entry`, keeping only the newest `max_entries`
+pub async fn push<T: Serialize + DeserializeOwned>(
+    path: &Path,
+    entry: T,
+    max_entries: usize,
+) -> Result<()> {
+    update(path, |entries: &mut Vec<T>| {
+        entries.push(entry);
+        let excess = entries.len().saturating_sub(max_entries);
+        entries.drain(..excess);
+    })
+    .await
+}
+
+/// Apply `edit` to the entries of the journal at `path` under its lock; the
+/// file is removed once no entries are left
+pub async fn update<T: Serialize + DeserializeOwned>(
+    path: &Path,
+    edit: impl FnOnce(&mut Vec<T>),
+) -> Result<()> {
+    let _lock = lock::acquire(
+        &path.with_extension("lock"),
+        &format!("Journal {:?}", path),
+        lock::LOCK_TIMEOUT,
+    )
+    .await?;
+    let mut entries = read(path)?;
+    edit(&mut entries);
+    if entries.is_empty() {
+        if path.is_file() {
+            std::fs::remove_file(path)
+                .with_context(|| format!("Failed to remove journal {:?}", path))?;
+        }
+        return Ok(());
+    }
+    crate::core::filesystem::write_content_atomic(path, &serde_json::to_string_pretty(&entries)?)
+        .with_context(|| format!("Failed to write journal {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_pushes_keep_every_entry() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("demo.json");
+        let pushes: Vec<_> = (0..16)
+            .map(|i| {
+                let path = path.clone();
+                tokio::spawn(async move { push(&path, i, 100).await })
+            })
+            .collect();
+        for handle in pushes {
+            handle.await.unwrap().unwrap();
+        }
+
+        let mut entries: Vec<i32> = read(&path).unwrap();
+        entries.sort();
+        assert_eq!(entries, (0..16).collect::<Vec<_>>());
+
+        push(&path, 16, 10).await.unwrap();
+        assert_eq!(read::<i32>(&path).unwrap().len(), 10);
+        update(&path, |entries: &mut Vec<i32>| entries.clear())
+            .await
+            .unwrap();
+        assert!(!path.exists());
+    }
+}
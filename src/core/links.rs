@@ -0,0 +1,240 @@
+//! Obsidian-style `[[wiki-links]]` between foundry documents
+//!
+//! Links may point at a spec (full spec name or feature name) or at one of the
+//! project documents (`vision`, `tech-stack`, `summary`). An optional alias is
+//! allowed after a pipe: `[[user_auth|the auth spec]]`. A `spec:` prefix makes
+//! a link an explicit spec reference, `[[spec:20250101_000000_auth]]`, which
+//! only resolves to a spec.
+
+use crate::core::timing::{self, Phase};
+use crate::types::responses::WikiLinkInfo;
+use crate::types::spec::{SpecContentData, SpecMetadata};
+
+/// Project-level documents that can be linked by name
+const PROJECT_DOCUMENTS: [(&str, &str); 3] = [
+    ("vision", "vision.md"),
+    ("tech-stack", "tech-stack.md"),
+    ("summary", "summary.md"),
+];
+
+/// Prefix of an explicit spec reference
+pub const SPEC_PREFIX: &str = "spec:";
+
+/// A parsed `[[target|alias]]` link
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WikiLink {
+    pub target: String,
+    pub alias: Option<String>,
+}
+
+/// Extract links in document order, without duplicates
+pub fn extract_wiki_links(content: &str) -> Vec<WikiLink> {
+    let _parsing = timing::start(Phase::Parsing);
+    let mut links: Vec<WikiLink> = Vec::new();
+    let mut rest = content;
+
+    while let Some(start) = rest.find("[[") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("]]") else {
+            break;
+        };
+        let inner = &after[..end];
+        rest = &after[end + 2..];
+
+        if inner.contains('\n') || inner.contains("[[") {
+            continue;
+        }
+        let (target, alias) = match inner.split_once('|') {
+            Some((target, alias)) => (target.trim(), Some(alias.trim().to_string())),
+            None => (inner.trim(), None),
+        };
+        if target.is_empty() || links.iter().any(|l| l.target == target) {
+            continue;
+        }
+        links.push(WikiLink {
+            target: target.to_string(),
+            alias,
+        });
+    }
+
+    links
+}
+
+/// Resolve a link target to a spec name or project document file name
+///
+/// `specs` is expected newest-first (as returned by `list_specs`), so a feature
+/// name resolves to its most recent spec.
+pub fn resolve_link(target: &str, specs: &[SpecMetadata]) -> Option<String> {
+    if let Some(name) = target.strip_prefix(SPEC_PREFIX) {
+        return resolve_spec(name.trim(), specs);
+    }
+    if let Some((_, file)) = PROJECT_DOCUMENTS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(target))
+    {
+        return Some(file.to_string());
+    }
+    resolve_spec(target, specs)
+}
+
+fn resolve_spec(target: &str, specs: &[SpecMetadata]) -> Option<String> {
+    specs
+        .iter()
+        .find(|s| s.name == target)
+        .or_else(|| specs.iter().find(|s| s.feature_name == target))
+        .map(|s| s.name.clone())
+}
+
+/// Collect and resolve links across all three spec files
+pub fn collect_spec_links(content: &SpecContentData, specs: &[SpecMetadata]) -> Vec<WikiLinkInfo> {
+    [
+        ("spec", &content.spec),
+        ("notes", &content.notes),
+        ("tasks", &content.tasks),
+    ]
+    .into_iter()
+    .flat_map(|(file, text)| {
+        extract_wiki_links(text)
+            .into_iter()
+            .map(move |link| WikiLinkInfo {
+                resolved_to: resolve_link(&link.target, specs),
+                target: link.target,
+                found_in: file.to_string(),
+            })
+    })
+    .collect()
+}
+
+/// Targets of the links in a spec's files that resolve to nothing, in document order
+pub fn resolve_references(content: &SpecContentData, specs: &[SpecMetadata]) -> Vec<String> {
+    collect_spec_links(content, specs)
+        .into_iter()
+        .filter(|link| link.resolved_to.is_none())
+        .map(|link| link.target)
+        .collect()
+}
+
+/// Whether any file of `content` links to the spec named `spec_name`
+pub fn links_to(content: &SpecContentData, spec_name: &str, specs: &[SpecMetadata]) -> bool {
+    collect_spec_links(content, specs)
+        .iter()
+        .any(|link| link.resolved_to.as_deref() == Some(spec_name))
+}
+
+/// Rewrite every link whose target is one of `old_targets` to point at `new_target`
+///
+/// Aliases and `spec:` prefixes are preserved. Returns the rewritten content and the number of links changed.
+pub fn rewrite_wiki_links(
+    content: &str,
+    old_targets: &[&str],
+    new_target: &str,
+) -> (String, usize) {
+    let mut output = String::with_capacity(content.len());
+    let mut rewritten = 0;
+    let mut rest = content;
+
+    while let Some(start) = rest.find("[[") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("]]") else {
+            break;
+        };
+        let inner = &after[..end];
+        output.push_str(&rest[..start]);
+
+        let (target, alias) = match inner.split_once('|') {
+            Some((target, alias)) => (target.trim(), Some(alias)),
+            None => (inner.trim(), None),
+        };
+        let (prefix, name) = target
+            .strip_prefix(SPEC_PREFIX)
+            .map_or(("", target), |name| (SPEC_PREFIX, name.trim()));
+        if !inner.contains('\n') && old_targets.contains(&name) {
+            output.push_str("[[");
+            output.push_str(prefix);
+            output.push_str(new_target);
+            if let Some(alias) = alias {
+                output.push('|');
+                output.push_str(alias);
+            }
+            output.push_str("]]");
+            rewritten += 1;
+        } else {
+            output.push_str("[[");
+            output.push_str(inner);
+            output.push_str("]]");
+        }
+        rest = &after[end + 2..];
+    }
+    output.push_str(rest);
+
+    (output, rewritten)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec_meta(name: &str, feature: &str) -> SpecMetadata {
+        SpecMetadata {
+            name: name.to_string(),
+            created_at: String::new(),
+            feature_name: feature.to_string(),
+            project_name: "proj".to_string(),
+            status: Default::default(),
+            depends_on: Vec::new(),
+            tags: Vec::new(),
+            task_counts: Default::default(),
+            updated_at: None,
+            modified_at: Default::default(),
+            summary: None,
+        }
+    }
+
+    #[test]
+    fn test_extract_wiki_links_with_alias_and_duplicates() {
+        let links = extract_wiki_links(
+            "See [[user_auth]] and [[billing|Billing spec]].\nAgain [[user_auth]]. Broken [[ ]] and [[open",
+        );
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].target, "user_auth");
+        assert_eq!(links[1].alias.as_deref(), Some("Billing spec"));
+    }
+
+    #[test]
+    fn test_resolve_link_prefers_spec_name_then_newest_feature() {
+        let specs = vec![
+            spec_meta("20250102_000000_user_auth", "user_auth"),
+            spec_meta("20250101_000000_user_auth", "user_auth"),
+        ];
+        assert_eq!(
+            resolve_link("user_auth", &specs).as_deref(),
+            Some("20250102_000000_user_auth")
+        );
+        assert_eq!(
+            resolve_link("20250101_000000_user_auth", &specs).as_deref(),
+            Some("20250101_000000_user_auth")
+        );
+        assert_eq!(resolve_link("Vision", &specs).as_deref(), Some("vision.md"));
+        assert!(resolve_link("missing", &specs).is_none());
+        // Spec references never resolve to project documents
+        assert_eq!(
+            resolve_link("spec:user_auth", &specs).as_deref(),
+            Some("20250102_000000_user_auth")
+        );
+        assert!(resolve_link("spec:vision", &specs).is_none());
+    }
+
+    #[test]
+    fn test_rewrite_wiki_links_preserves_alias_and_other_links() {
+        let (content, count) = rewrite_wiki_links(
+            "[[old_spec]] then [[old_spec|alias]], [[spec:old_spec]] and [[other]]",
+            &["old_spec"],
+            "new_spec",
+        );
+        assert_eq!(count, 3);
+        assert_eq!(
+            content,
+            "[[new_spec]] then [[new_spec|alias]], [[spec:new_spec]] and [[other]]"
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Advisory locks that keep foundry processes from interleaving spec writes
+//!
+//! An MCP server and a CLI call (or two MCP servers) can share one store.
+//! Ops that read a spec, edit it and write it back hold the spec's lock for
+//! the whole cycle, so a second process waits for the first instead of
+//! overwriting its edit. File backends lock one file per spec, and one per
+//! project for its own files (tags, decision log), below `.locks` in their
+//! root:
+//!
+//! ```text
+//! .locks/<project>.lock
+//! .locks/<project>/<spec>.lock
+//! ```
+//!
+//! These are OS file locks (`flock` on Unix, `LockFileEx` on Windows), so they
+//! are released when the holder exits, even after a crash. They are advisory:
+//! editors and other tools writing the files directly are not blocked.
+
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+
+use crate::types::errors::ErrorCode;
+use crate::utils::paths;
+
+/// Directory below a backend's root holding the lock files
+pub const LOCKS_DIR_NAME: &str = ".locks";
+
+/// How long to wait for another process to release a lock
+pub const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Pause between attempts to take a held lock
+const RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A held lock, released when dropped
+#[derive(Debug)]
+pub struct WriteLock {
+    _file: Option<File>,
+}
+
+impl WriteLock {
+    /// A lock that guards nothing, for backends no other process can write to
+    pub fn unguarded() -> Self {
+        Self { _file: None }
+    }
+}
+
+/// Lock the file at `path`, waiting up to `timeout` while another process holds it
+///
+/// `resource` names what the lock guards in the busy error, e.g. "Spec 'x' in
+/// project 'y'".
+pub async fn acquire(path: &Path, resource: &str, timeout: Duration) -> Result<WriteLock> {
+    let file = open(path)?;
+    let started = Instant::now();
+    while !try_lock(&file, path, resource, timeout, started)? {
+        tokio::time::sleep(RETRY_INTERVAL).await;
+    }
+    Ok(WriteLock { _file: Some(file) })
+}
+
+/// [`acquire`] for synchronous code, blocking the thread while it waits
+pub fn acquire_blocking(path: &Path, resource: &str, timeout: Duration) -> Result<WriteLock> {
+    let file = open(path)?;
+    let started = Instant::now();
+    while !try_lock(&file, path, resource, timeout, started)? {
+        std::thread::sleep(RETRY_INTERVAL);
+    }
+    Ok(WriteLock { _file: Some(file) })
+}
+
+fn open(path: &Path) -> Result<File> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create lock directory {:?}", parent))?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+        .with_context(|| format!("Failed to open lock file {:?}", path))
+}
+
+/// Take the lock on `file` if it is free; `false` while it is held and
+/// `timeout` since `started` has not run out
+fn try_lock(
+    file: &File,
+    path: &Path,
+    resource: &str,
+    timeout: Duration,
+    started: Instant,
+) -> Result<bool> {
+    match file.try_lock() {
+        Ok(()) => Ok(true),
+        Err(TryLockError::WouldBlock) if started.elapsed() < timeout => Ok(false),
+        Err(TryLockError::WouldBlock) => Err(ErrorCode::ResourceBusy.error(format!(
+            "{} is being updated by another foundry process (waited {:.1}s). Retry the call in a few seconds; if it keeps failing, check for a stuck foundry process",
+            resource,
+            timeout.as_secs_f64()
+        ))),
+        Err(TryLockError::Error(e)) => {
+            Err(e).with_context(|| format!("Failed to lock {:?}", path))
+        }
+    }
+}
+
+/// Lock file guarding one spec below `root`
+pub fn spec_lock_path(root: &Path, project_name: &str, spec_name: &str) -> Result<PathBuf> {
+    if !paths::is_safe_component(project_name) || !paths::is_safe_component(spec_name) {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Invalid project or spec name '{}/{}'",
+            project_name, spec_name
+        )));
+    }
+    Ok(root
+        .join(LOCKS_DIR_NAME)
+        .join(project_name)
+        .join(format!("{}.lock", spec_name)))
+}
+
+/// Lock file guarding a project's own files below `root`
+pub fn project_lock_path(root: &Path, project_name: &str) -> Result<PathBuf> {
+    if !paths::is_safe_component(project_name) {
+        return Err(
+            ErrorCode::InvalidParams.error(format!("Invalid project name '{}'", project_name))
+        );
+    }
+    Ok(root
+        .join(LOCKS_DIR_NAME)
+        .join(format!("{}.lock", project_name)))
+}
+
+/// Remove the lock files of a deleted project below `root`
+pub fn remove_project_locks(root: &Path, project_name: &str) -> Result<()> {
+    let project_lock = project_lock_path(root, project_name)?;
+    if project_lock.is_file() {
+        std::fs::remove_file(&project_lock)
+            .with_context(|| format!("Failed to remove lock file {:?}", project_lock))?;
+    }
+    let spec_locks = root.join(LOCKS_DIR_NAME).join(project_name);
+    if spec_locks.is_dir() {
+        std::fs::remove_dir_all(&spec_locks)
+            .with_context(|| format!("Failed to remove lock directory {:?}", spec_locks))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_held_lock_reports_busy_until_released() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = spec_lock_path(dir.path(), "demo", "20250101_120000_auth").unwrap();
+        assert_eq!(
+            path,
+            dir.path().join(".locks/demo/20250101_120000_auth.lock")
+        );
+
+        let held = acquire(&path, "Spec 'auth'", LOCK_TIMEOUT).await.unwrap();
+        let busy = acquire(&path, "Spec 'auth'", Duration::from_millis(120))
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&busy), ErrorCode::ResourceBusy);
+        assert!(busy.to_string().contains("Retry"));
+
+        drop(held);
+        acquire(&path, "Spec 'auth'", Duration::ZERO).await.unwrap();
+        assert!(spec_lock_path(dir.path(), "..", "spec").is_err());
+    }
+}
@@ -1,15 +1,39 @@
 //! Core business logic modules
 
+pub mod activity;
+pub mod archive;
+pub mod audit;
 pub mod backends;
+pub mod backup;
+pub mod config;
+pub mod context_budget;
+pub mod decisions;
+pub mod dependencies;
+pub mod diagram;
+pub mod doctor;
 pub mod edit_engine;
+pub mod encryption;
 pub mod filesystem;
 pub mod foundry;
+pub mod history;
 pub mod installation;
+pub mod journal;
+pub mod links;
+pub mod lock;
 pub mod ops;
 pub mod project;
+pub mod repo_scan;
+pub mod sampling;
 pub mod spec;
+pub mod spec_diff;
+pub mod spec_name;
+pub mod tags;
+pub mod tasks;
 pub mod templates;
+pub mod timing;
 pub mod validation;
+pub mod versioning;
+pub mod workspace;
 
 // Selective reexports from filesystem module
 pub use filesystem::{
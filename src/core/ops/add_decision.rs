@@ -0,0 +1,110 @@
+//! Core op for recording a decision in a project's decision log (tool-agnostic)
+
+use anyhow::{Context, Result};
+
+use crate::core::decisions::{self, NewDecision};
+use crate::core::foundry;
+use crate::core::ops::list_decisions::{
+    decision_info, decision_workflow_hints, validate_project_exists,
+};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{AddDecisionResponse, FoundryResponse};
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub title: String,
+    /// Why a decision was needed
+    pub context: String,
+    /// What was decided
+    pub decision: String,
+    pub consequences: Option<String>,
+    /// Defaults to accepted
+    pub status: Option<String>,
+    /// Spec the decision was made for
+    pub spec_name: Option<String>,
+}
+
+#[tracing::instrument(name = "op.add_decision", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<AddDecisionResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    for (name, value) in [
+        ("title", &input.title),
+        ("context", &input.context),
+        ("decision", &input.decision),
+    ] {
+        if value.trim().is_empty() {
+            return Err(ErrorCode::InvalidParams.error(format!("'{}' cannot be empty", name)));
+        }
+    }
+    if input.title.contains('\n') {
+        return Err(ErrorCode::InvalidParams.error("'title' must be a single line"));
+    }
+    let status = input
+        .status
+        .as_deref()
+        .map_or_else(|| decisions::DEFAULT_STATUS.to_string(), str::to_lowercase);
+    decisions::validate_status(&status)?;
+    validate_project_exists(&foundry, &input.project_name).await?;
+
+    if let Some(spec_name) = &input.spec_name {
+        let specs = foundry.list_specs(&input.project_name).await?;
+        if !specs.iter().any(|spec| &spec.name == spec_name) {
+            return Err(ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'. Use 'mcp_foundry_list_specs {}' to see available specs.",
+                spec_name, input.project_name, input.project_name
+            )));
+        }
+    }
+
+    let _lock = foundry.lock_project(&input.project_name).await?;
+    let project = foundry.load_project(&input.project_name).await?;
+    let (content, id) = decisions::append(
+        project.decisions.as_deref().unwrap_or_default(),
+        &NewDecision {
+            title: input.title,
+            context: input.context,
+            decision: input.decision,
+            consequences: input.consequences,
+            status,
+            spec_name: input.spec_name,
+            date: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+        },
+    );
+    foundry
+        .update_decisions(&input.project_name, &content)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to write the decision log of project '{}'",
+                input.project_name
+            )
+        })?;
+
+    let all = decisions::parse(&content);
+    let total_decisions = all.len();
+    let decision = all
+        .into_iter()
+        .find(|decision| decision.id.as_deref() == Some(id.as_str()))
+        .map(decision_info)
+        .context("Recorded decision could not be read back")?;
+
+    let next_steps = vec![
+        format!("Recorded {}: {}", id, decision.title),
+        format!(
+            "You can review the log: {{\"name\": \"list_decisions\", \"arguments\": {{\"project_name\": \"{}\"}}}}",
+            input.project_name
+        ),
+    ];
+    Ok(build_success_response(
+        AddDecisionResponse {
+            project_name: input.project_name,
+            decision,
+            total_decisions,
+        },
+        next_steps,
+        decision_workflow_hints(),
+    ))
+}
@@ -0,0 +1,146 @@
+//! Core op for tagging a project or spec (tool-agnostic)
+
+use anyhow::Result;
+
+use crate::core::{foundry, tags};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, TagsResponse};
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    /// Spec to tag; the project itself when not set
+    pub spec_name: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// Whether tags are being added or removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TagChange {
+    Add,
+    Remove,
+}
+
+#[tracing::instrument(name = "op.add_tag", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<TagsResponse>> {
+    let response_data = apply(&input, TagChange::Add).await?;
+
+    let next_steps = vec![
+        if response_data.changed.is_empty() {
+            format!("{} already had these tags", subject(&response_data))
+        } else {
+            format!(
+                "Tagged {} with {}",
+                subject(&response_data),
+                response_data.changed.join(", ")
+            )
+        },
+        list_next_step(&response_data),
+    ];
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        tag_workflow_hints(),
+    ))
+}
+
+/// Add or remove `input.tags` on the project or spec, returning the result
+pub(crate) async fn apply(input: &Input, change: TagChange) -> Result<TagsResponse> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if input.tags.is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Provide at least one tag"));
+    }
+    if !foundry.project_exists(&input.project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            input.project_name
+        )));
+    }
+
+    let _lock = match &input.spec_name {
+        Some(spec_name) => foundry.lock_spec(&input.project_name, spec_name).await?,
+        None => foundry.lock_project(&input.project_name).await?,
+    };
+    let current = match &input.spec_name {
+        Some(spec_name) => foundry
+            .list_specs(&input.project_name)
+            .await?
+            .into_iter()
+            .find(|spec| &spec.name == spec_name)
+            .ok_or_else(|| {
+                ErrorCode::SpecNotFound.error(format!(
+                    "Spec '{}' not found in project '{}'. Use 'mcp_foundry_list_specs {}' to see available specs.",
+                    spec_name, input.project_name, input.project_name
+                ))
+            })?
+            .tags,
+        None => foundry
+            .list_projects()
+            .await?
+            .into_iter()
+            .find(|project| project.name == input.project_name)
+            .map(|project| project.tags)
+            .unwrap_or_default(),
+    };
+
+    let (updated, changed) = match change {
+        TagChange::Add => tags::add(&current, &input.tags)?,
+        TagChange::Remove => tags::remove(&current, &input.tags),
+    };
+    if !changed.is_empty() {
+        match &input.spec_name {
+            Some(spec_name) => {
+                foundry
+                    .set_spec_tags(&input.project_name, spec_name, &updated)
+                    .await?
+            }
+            None => {
+                foundry
+                    .set_project_tags(&input.project_name, &updated)
+                    .await?
+            }
+        }
+    }
+
+    Ok(TagsResponse {
+        project_name: input.project_name.clone(),
+        spec_name: input.spec_name.clone(),
+        tags: updated,
+        changed,
+    })
+}
+
+/// "spec 'x'" or "project 'y'"
+pub(crate) fn subject(response: &TagsResponse) -> String {
+    response.spec_name.as_ref().map_or_else(
+        || format!("project '{}'", response.project_name),
+        |spec_name| format!("spec '{}'", spec_name),
+    )
+}
+
+/// How to list everything carrying the first of the response's tags
+pub(crate) fn list_next_step(response: &TagsResponse) -> String {
+    let Some(tag) = response.tags.first() else {
+        return format!("{} has no tags left", subject(response));
+    };
+    if response.spec_name.is_some() {
+        format!(
+            "List specs with a tag: {{\"name\": \"list_specs\", \"arguments\": {{\"project_name\": \"{}\", \"tags\": [\"{}\"]}}}}",
+            response.project_name, tag
+        )
+    } else {
+        format!(
+            "List projects with a tag: {{\"name\": \"list_projects\", \"arguments\": {{\"tags\": [\"{}\"]}}}}",
+            tag
+        )
+    }
+}
+
+pub(crate) fn tag_workflow_hints() -> Vec<String> {
+    vec![
+        "Tags are free-form labels such as 'area:backend' or 'quarter:Q3'; they compare case-insensitively".to_string(),
+    ]
+}
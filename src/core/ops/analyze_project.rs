@@ -2,10 +2,13 @@
 
 use anyhow::{Context, Result};
 
-use crate::core::foundry;
-use crate::core::validation::{ContentType, validate_content};
+use crate::core::validation::ContentType;
+use crate::core::{config, foundry, repo_scan};
+use crate::types::errors::ErrorCode;
 use crate::types::project::ProjectConfig;
-use crate::types::responses::{AnalyzeProjectResponse, FoundryResponse, ValidationStatus};
+use crate::types::responses::{
+    AnalyzeProjectResponse, FoundryResponse, RepoScan, ValidationStatus,
+};
 
 #[derive(Debug, Clone)]
 pub struct Input {
@@ -13,13 +16,29 @@ pub struct Input {
     pub vision: String,
     pub tech_stack: String,
     pub summary: String,
+    /// Local checkout to scan; with empty content only the scan is returned
+    pub repo_path: Option<String>,
 }
 
+#[tracing::instrument(name = "op.analyze_project", skip_all, fields(project = %input.project_name))]
 pub async fn run(input: Input) -> Result<FoundryResponse<AnalyzeProjectResponse>> {
     let foundry = foundry::get_default_foundry()?;
 
     validate_project_name(&input.project_name).with_context(|| "Project name validation failed")?;
 
+    let scan = input
+        .repo_path
+        .as_deref()
+        .map(|path| repo_scan::scan(&crate::utils::paths::expand_home(path)))
+        .transpose()?;
+    if let Some(scan) = scan.as_ref().filter(|_| {
+        [&input.vision, &input.tech_stack, &input.summary]
+            .iter()
+            .all(|content| content.trim().is_empty())
+    }) {
+        return Ok(scan_only_response(&input.project_name, scan.clone()));
+    }
+
     validate_content_sizes(&input.vision, &input.tech_stack, &input.summary)
         .with_context(|| "Content size validation failed")?;
 
@@ -28,15 +47,16 @@ pub async fn run(input: Input) -> Result<FoundryResponse<AnalyzeProjectResponse>
         .await
         .with_context(|| format!("Failed to check if project '{}' exists", input.project_name))?
     {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::AlreadyExists.error(format!(
             "Project '{}' already exists. Use MCP to discover existing projects: {{\"name\": \"list_projects\", \"arguments\": {{}}}} or choose a different name.",
             input.project_name
-        ));
+        )));
     }
 
-    let vision_validation = validate_content(ContentType::Vision, &input.vision);
-    let tech_stack_validation = validate_content(ContentType::TechStack, &input.tech_stack);
-    let summary_validation = validate_content(ContentType::Summary, &input.summary);
+    let config = config::for_project(&input.project_name)?;
+    let vision_validation = config.validate(ContentType::Vision, &input.vision);
+    let tech_stack_validation = config.validate(ContentType::TechStack, &input.tech_stack);
+    let summary_validation = config.validate(ContentType::Summary, &input.summary);
 
     let mut validation_errors = Vec::new();
     if !vision_validation.is_valid {
@@ -51,11 +71,11 @@ pub async fn run(input: Input) -> Result<FoundryResponse<AnalyzeProjectResponse>
 
     if !validation_errors.is_empty() {
         let error_count = validation_errors.len();
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ValidationFailed.error(format!(
             "Content validation failed with {} error(s):\n{}",
             error_count,
             validation_errors.join("\n")
-        ));
+        )));
     }
 
     // Create project using the foundry façade
@@ -81,6 +101,7 @@ pub async fn run(input: Input) -> Result<FoundryResponse<AnalyzeProjectResponse>
     let response_data = AnalyzeProjectResponse {
         project_name: input.project_name.clone(),
         files_created,
+        repo_scan: scan,
     };
 
     let next_steps = vec![
@@ -112,35 +133,66 @@ pub async fn run(input: Input) -> Result<FoundryResponse<AnalyzeProjectResponse>
         next_steps,
         validation_status: ValidationStatus::Complete,
         workflow_hints,
+        diagnostics: None,
     })
 }
 
+/// Response for a scan without content: nothing is created yet
+fn scan_only_response(
+    project_name: &str,
+    scan: RepoScan,
+) -> FoundryResponse<AnalyzeProjectResponse> {
+    let next_steps = vec![
+        format!("Scanned {} ({} files); project '{}' was not created yet", scan.root, scan.files_scanned, project_name),
+        "Draft tech_stack from repo_scan.tech_stack_skeleton, adding rationale and versions from the files it lists".to_string(),
+        format!(
+            "Create the project: {{\"name\": \"analyze_project\", \"arguments\": {{\"project_name\": \"{}\", \"vision\": \"...\", \"tech_stack\": \"...\", \"summary\": \"...\"}}}}",
+            project_name
+        ),
+    ];
+    let workflow_hints = vec![
+        "The skeleton lists only what is on disk; read entry points and READMEs for the vision and summary".to_string(),
+        "Manifests list dependency names only; check lockfiles for exact versions".to_string(),
+    ];
+
+    FoundryResponse {
+        data: AnalyzeProjectResponse {
+            project_name: project_name.to_string(),
+            files_created: Vec::new(),
+            repo_scan: Some(scan),
+        },
+        next_steps,
+        validation_status: ValidationStatus::Incomplete,
+        workflow_hints,
+        diagnostics: None,
+    }
+}
+
 fn validate_project_name(name: &str) -> Result<()> {
     if name.trim().is_empty() {
-        return Err(anyhow::anyhow!(
-            "Project name cannot be empty. Please provide a descriptive project name."
-        ));
+        return Err(ErrorCode::InvalidParams
+            .error("Project name cannot be empty. Please provide a descriptive project name."));
     }
     if name.len() > 100 {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ValidationFailed.error(format!(
             "Project name too long ({} characters). Please keep it under 100 characters for filesystem compatibility.",
             name.len()
-        ));
+        )));
     }
     let invalid_chars = ['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
     if let Some(invalid_char) = name.chars().find(|c| invalid_chars.contains(c)) {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ValidationFailed.error(format!(
             "Project name contains invalid character '{}'. Please use only letters, numbers, hyphens, and underscores.",
             invalid_char
-        ));
+        )));
     }
     let reserved_names = ["CON", "PRN", "AUX", "NUL"];
     let upper_name = name.to_uppercase();
     if reserved_names.contains(&upper_name.as_str()) {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ValidationFailed.error(format!(
             "Project name '{}' is reserved by the operating system. Please choose a different name.",
             name
-        ));
+        )));
     }
     Ok(())
 }
@@ -148,25 +200,25 @@ fn validate_project_name(name: &str) -> Result<()> {
 fn validate_content_sizes(vision: &str, tech_stack: &str, summary: &str) -> Result<()> {
     const MAX_CONTENT_SIZE: usize = 50_000;
     if vision.len() > MAX_CONTENT_SIZE {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ValidationFailed.error(format!(
             "Vision content too large ({} characters). Please keep it under {} characters for optimal performance.",
             vision.len(),
             MAX_CONTENT_SIZE
-        ));
+        )));
     }
     if tech_stack.len() > MAX_CONTENT_SIZE {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ValidationFailed.error(format!(
             "Tech stack content too large ({} characters). Please keep it under {} characters for optimal performance.",
             tech_stack.len(),
             MAX_CONTENT_SIZE
-        ));
+        )));
     }
     if summary.len() > MAX_CONTENT_SIZE {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ValidationFailed.error(format!(
             "Summary content too large ({} characters). Please keep it under {} characters for optimal performance.",
             summary.len(),
             MAX_CONTENT_SIZE
-        ));
+        )));
     }
     Ok(())
 }
@@ -0,0 +1,37 @@
+//! Core op for archiving a spec (tool-agnostic)
+
+use anyhow::Result;
+
+use crate::core::ops::set_spec_status::{apply, archived_next_step, status_workflow_hints};
+use crate::types::responses::{FoundryResponse, SpecStatusResponse};
+use crate::types::spec::SpecStatus;
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub spec_name: String,
+}
+
+#[tracing::instrument(name = "op.archive_spec", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<SpecStatusResponse>> {
+    let response_data = apply(&input.project_name, &input.spec_name, SpecStatus::Archived).await?;
+
+    let next_steps = vec![
+        if response_data.previous_status == SpecStatus::Archived {
+            format!("Spec '{}' was already archived", response_data.spec_name)
+        } else {
+            format!(
+                "Archived spec '{}' (was {}); its files are kept",
+                response_data.spec_name, response_data.previous_status
+            )
+        },
+        archived_next_step(&response_data),
+    ];
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        status_workflow_hints(),
+    ))
+}
@@ -0,0 +1,152 @@
+//! Core op for cloning a project under a new name (tool-agnostic)
+//!
+//! The vision, tech stack, summary, decision log and tags are copied. Specs are
+//! copied too unless skipped; each gets a fresh id in the new project and keeps
+//! its status, tags and summary, with `depends_on` and `[[wiki-links]]` by spec
+//! name pointed at the copies.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+
+use crate::core::foundry;
+use crate::core::ops::{copy_spec, create_project};
+use crate::types::errors::ErrorCode;
+use crate::types::project::ProjectConfig;
+use crate::types::responses::{CloneProjectResponse, CopiedSpec, FoundryResponse};
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub new_project_name: String,
+    /// Copy only the project documents, not its specs
+    pub skip_specs: bool,
+}
+
+#[tracing::instrument(name = "op.clone_project", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<CloneProjectResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if !foundry.project_exists(&input.project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            input.project_name
+        )));
+    }
+    create_project::validate_project_preconditions(&foundry, &input.new_project_name).await?;
+
+    let project = foundry.load_project(&input.project_name).await?;
+    foundry
+        .create_project(ProjectConfig {
+            name: input.new_project_name.clone(),
+            vision: project.vision.unwrap_or_default(),
+            tech_stack: project.tech_stack.unwrap_or_default(),
+            summary: project.summary.unwrap_or_default(),
+        })
+        .await
+        .context("Failed to create project structure")?;
+
+    let decisions_copied = match project.decisions.filter(|d| !d.trim().is_empty()) {
+        Some(decisions) => {
+            foundry
+                .update_decisions(&input.new_project_name, &decisions)
+                .await?;
+            true
+        }
+        None => false,
+    };
+    let tags = foundry
+        .list_projects()
+        .await?
+        .into_iter()
+        .find(|metadata| metadata.name == input.project_name)
+        .map(|metadata| metadata.tags)
+        .unwrap_or_default();
+    if !tags.is_empty() {
+        foundry
+            .set_project_tags(&input.new_project_name, &tags)
+            .await?;
+    }
+
+    let mut specs = Vec::new();
+    let mut links_updated = 0;
+    if !input.skip_specs {
+        let mut sources = foundry.list_specs(&input.project_name).await?;
+        sources.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+        let mut names = HashMap::new();
+        for source in &sources {
+            let spec_name = copy_spec::copy(
+                &foundry,
+                source,
+                &input.new_project_name,
+                &source.feature_name,
+                true,
+            )
+            .await?;
+            names.insert(source.name.clone(), spec_name.clone());
+            specs.push(CopiedSpec {
+                source_spec_name: source.name.clone(),
+                spec_name,
+            });
+        }
+
+        for source in sources.iter().filter(|spec| !spec.depends_on.is_empty()) {
+            let depends_on: Vec<String> = source
+                .depends_on
+                .iter()
+                .filter_map(|name| names.get(name).cloned())
+                .collect();
+            foundry
+                .set_spec_dependencies(&input.new_project_name, &names[&source.name], &depends_on)
+                .await?;
+        }
+        for copied in specs
+            .iter()
+            .filter(|copied| copied.source_spec_name != copied.spec_name)
+        {
+            links_updated += foundry
+                .rewrite_wiki_links(
+                    &input.new_project_name,
+                    &[&copied.source_spec_name],
+                    &copied.spec_name,
+                )
+                .await?;
+        }
+    }
+
+    let next_steps = vec![
+        format!(
+            "Project '{}' cloned to '{}' with {} spec(s)",
+            input.project_name,
+            input.new_project_name,
+            specs.len()
+        ),
+        format!(
+            "Load the new project: {{\"name\": \"load_project\", \"arguments\": {{\"project_name\": \"{}\"}}}}",
+            input.new_project_name
+        ),
+        format!(
+            "Adapt its documents with update_project: {{\"name\": \"update_project\", \"arguments\": {{\"project_name\": \"{}\", \"vision\": \"...\"}}}}",
+            input.new_project_name
+        ),
+    ];
+    let workflow_hints = vec![
+        "Copied specs have new ids and keep their status, tags, summary and task state"
+            .to_string(),
+        "Use copy_spec to bring over single specs later, or delete_spec to drop ones the new project does not need".to_string(),
+    ];
+
+    Ok(build_success_response(
+        CloneProjectResponse {
+            project_name: input.new_project_name,
+            source_project_name: input.project_name,
+            specs,
+            decisions_copied,
+            links_updated,
+        },
+        next_steps,
+        workflow_hints,
+    ))
+}
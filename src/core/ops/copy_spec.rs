@@ -0,0 +1,187 @@
+//! Core op for copying or moving a spec to another project (tool-agnostic)
+//!
+//! The copy is created like a new spec, so it gets a fresh id from the target
+//! project's naming strategy and a new created_at. Its files, tags and summary
+//! come along; `depends_on` is dropped because it names specs of the source
+//! project. Copies start as drafts, while a move keeps the status and deletes
+//! the source after backing it up.
+
+use anyhow::{Context, Result};
+
+use crate::core::backends::SharedBackend;
+use crate::core::{backup, foundry};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{CopySpecResponse, FoundryResponse};
+use crate::types::spec::{SpecConfig, SpecMetadata, SpecStatus};
+use crate::utils::paths;
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub spec_name: String,
+    /// Project to copy the spec into; may be the source project
+    pub target_project_name: String,
+    /// Feature name for the copy; the source's when omitted
+    pub feature_name: Option<String>,
+    /// Delete the source spec once it is copied
+    pub delete_source: bool,
+}
+
+#[tracing::instrument(name = "op.copy_spec", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<CopySpecResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if input.spec_name.trim().is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Spec name cannot be empty"));
+    }
+    if let Some(feature_name) = &input.feature_name {
+        paths::validate_feature_name(feature_name).map_err(|e| {
+            ErrorCode::ValidationFailed.error_with_rule("feature_name_format", e.to_string())
+        })?;
+    }
+    if input.delete_source && input.project_name == input.target_project_name {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Cannot move spec '{}' into its own project; use rename_spec to change its feature name",
+            input.spec_name
+        )));
+    }
+    for project_name in [&input.project_name, &input.target_project_name] {
+        if !foundry.project_exists(project_name).await? {
+            return Err(ErrorCode::ProjectNotFound.error(format!(
+                "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+                project_name
+            )));
+        }
+    }
+
+    let specs = foundry
+        .list_specs(&input.project_name)
+        .await
+        .with_context(|| format!("Failed to list specs for project '{}'", input.project_name))?;
+    let Some(source) = specs.iter().find(|spec| spec.name == input.spec_name) else {
+        return Err(ErrorCode::SpecNotFound.error(format!(
+            "Spec '{}' not found in project '{}'. Use 'mcp_foundry_list_specs {}' to see available specs.",
+            input.spec_name, input.project_name, input.project_name
+        )));
+    };
+
+    let feature_name = input
+        .feature_name
+        .as_deref()
+        .unwrap_or(&source.feature_name);
+    let spec_name = copy(
+        &foundry,
+        source,
+        &input.target_project_name,
+        feature_name,
+        input.delete_source,
+    )
+    .await?;
+
+    let mut dependents = Vec::new();
+    if input.delete_source {
+        backup::snapshot_before(&foundry, &input.project_name, &input.spec_name, "copy_spec")
+            .await?;
+        foundry
+            .delete_spec(&input.project_name, &input.spec_name)
+            .await
+            .with_context(|| format!("Failed to delete source spec '{}'", input.spec_name))?;
+        dependents = specs
+            .iter()
+            .filter(|spec| spec.depends_on.contains(&input.spec_name))
+            .map(|spec| spec.name.clone())
+            .collect();
+    }
+
+    let verb = if input.delete_source {
+        "moved"
+    } else {
+        "copied"
+    };
+    let next_steps = vec![
+        format!(
+            "Spec '{}' {} to '{}' in project '{}'",
+            input.spec_name, verb, spec_name, input.target_project_name
+        ),
+        format!(
+            "Load the new spec: {{\"name\": \"load_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            input.target_project_name, spec_name
+        ),
+    ];
+    let mut workflow_hints = vec![if input.delete_source {
+        "Status, tags, summary and task state were kept; the source was backed up before deletion"
+            .to_string()
+    } else {
+        "The copy starts as a draft with the source's tags, summary and task state".to_string()
+    }];
+    if !source.depends_on.is_empty() {
+        workflow_hints.push(format!(
+            "Dependencies were not copied ({}); set them again in the target project",
+            source.depends_on.join(", ")
+        ));
+    }
+    if !dependents.is_empty() {
+        workflow_hints.push(format!(
+            "These specs in '{}' still depend on the moved spec: {}",
+            input.project_name,
+            dependents.join(", ")
+        ));
+    }
+
+    Ok(build_success_response(
+        CopySpecResponse {
+            project_name: input.target_project_name,
+            spec_name,
+            source_project_name: input.project_name,
+            source_spec_name: input.spec_name,
+            source_deleted: input.delete_source,
+            dependencies_dropped: source.depends_on.clone(),
+            dependents,
+        },
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+/// Create a copy of `source` in `target_project_name` and return its name
+///
+/// Tags and summary are copied; dependencies are left to the caller. The
+/// copy is a draft unless `keep_status` is set.
+pub(crate) async fn copy(
+    foundry: &foundry::Foundry<SharedBackend>,
+    source: &SpecMetadata,
+    target_project_name: &str,
+    feature_name: &str,
+    keep_status: bool,
+) -> Result<String> {
+    let spec = foundry
+        .load_spec(&source.project_name, &source.name)
+        .await
+        .with_context(|| format!("Failed to load spec '{}'", source.name))?;
+    let created = foundry
+        .create_spec(SpecConfig {
+            project_name: target_project_name.to_string(),
+            feature_name: feature_name.to_string(),
+            content: spec.content,
+        })
+        .await
+        .with_context(|| format!("Failed to copy spec '{}'", source.name))?;
+
+    if keep_status && source.status != SpecStatus::Draft {
+        foundry
+            .set_spec_status(target_project_name, &created.name, source.status)
+            .await?;
+    }
+    if !source.tags.is_empty() {
+        foundry
+            .set_spec_tags(target_project_name, &created.name, &source.tags)
+            .await?;
+    }
+    if let Some(summary) = &source.summary {
+        foundry
+            .set_spec_summary(target_project_name, &created.name, summary)
+            .await?;
+    }
+    Ok(created.name)
+}
@@ -0,0 +1,277 @@
+//! Core op for assembling a spec handoff packet (tool-agnostic)
+
+use anyhow::{Context, Result};
+
+use crate::core::context_budget::{MIN_TRUNCATED_TOKENS, truncate_to_tokens};
+use crate::core::foundry;
+use crate::core::tasks::{TaskEntry, parse_task_list};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{CreateHandoffResponse, FoundryResponse};
+use crate::utils::formatting::{estimate_tokens, format_count};
+use crate::utils::response::build_success_response;
+
+/// Token budget when not specified
+const DEFAULT_MAX_TOKENS: usize = 2000;
+
+/// Smallest budget that still fits the header and a few open tasks
+const MIN_MAX_TOKENS: usize = 200;
+
+/// Decisions from notes.md carried into the packet, most recent last
+const MAX_DECISIONS: usize = 5;
+
+const TRUNCATION_MARKER: &str = "… (truncated for the handoff budget)";
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub spec_name: String,
+    pub max_tokens: Option<usize>,
+}
+
+/// A titled block of the packet competing for the token budget
+struct Part {
+    title: String,
+    body: String,
+}
+
+#[tracing::instrument(name = "op.create_handoff", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<CreateHandoffResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    validate_project_exists(&foundry, &input.project_name).await?;
+
+    let max_tokens = input.max_tokens.unwrap_or(DEFAULT_MAX_TOKENS);
+    if max_tokens < MIN_MAX_TOKENS {
+        return Err(ErrorCode::InvalidParams
+            .error(format!("'max_tokens' must be at least {}", MIN_MAX_TOKENS)));
+    }
+
+    let spec_name = foundry
+        .find_spec_match(&input.project_name, &input.spec_name)
+        .await?
+        .into_spec_name(&input.project_name, &input.spec_name)?;
+    let spec = foundry
+        .load_spec(&input.project_name, &spec_name)
+        .await
+        .with_context(|| format!("Failed to load spec '{}'", spec_name))?;
+    let project = foundry
+        .load_project(&input.project_name)
+        .await
+        .with_context(|| format!("Failed to load project '{}'", input.project_name))?;
+
+    let tasks = parse_task_list(&spec.content.tasks);
+    let response_data = assemble(
+        &input.project_name,
+        &spec.name,
+        project.summary.as_deref().unwrap_or_default(),
+        &spec.content.spec,
+        &spec.content.notes,
+        &tasks,
+        max_tokens,
+    );
+
+    let mut next_steps = vec![format!(
+        "Handoff packet for '{}' is ready in 'handoff_markdown' (~{} of {} tokens)",
+        response_data.spec_name, response_data.estimated_tokens, max_tokens
+    )];
+    if !response_data.omitted_sections.is_empty() {
+        next_steps.push(format!(
+            "{} did not fit; raise 'max_tokens' or have the recipient run load_spec for the full spec",
+            format_count(
+                response_data.omitted_sections.len(),
+                "section",
+                "sections"
+            )
+        ));
+    }
+    let workflow_hints = vec![
+        "Open tasks and recent decisions are kept first; spec sections fill the remaining budget in document order".to_string(),
+        "Decisions come from notes.md lines mentioning a decision, or a 'Decisions' section".to_string(),
+        "Token counts are estimates (about four characters per token)".to_string(),
+    ];
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+/// Build the packet, filling `max_tokens` by priority: open tasks, decisions,
+/// project summary, then spec sections in order
+pub fn assemble(
+    project_name: &str,
+    spec_name: &str,
+    project_summary: &str,
+    spec_content: &str,
+    notes: &str,
+    tasks: &[TaskEntry],
+    max_tokens: usize,
+) -> CreateHandoffResponse {
+    let completed_tasks = tasks.iter().filter(|task| task.done).count();
+    let open_tasks: Vec<&TaskEntry> = tasks.iter().filter(|task| !task.done).collect();
+    let decisions = recent_decisions(notes);
+
+    let header = format!(
+        "# Handoff: {}\n\nProject: {}\nProgress: {}/{} tasks complete, {} open",
+        spec_name,
+        project_name,
+        completed_tasks,
+        tasks.len(),
+        open_tasks.len()
+    );
+
+    let mut parts = Vec::new();
+    if !open_tasks.is_empty() {
+        parts.push(Part {
+            title: "Open tasks".to_string(),
+            body: format_open_tasks(&open_tasks),
+        });
+    }
+    if !decisions.is_empty() {
+        parts.push(Part {
+            title: "Recent decisions".to_string(),
+            body: decisions
+                .iter()
+                .map(|decision| format!("- {}", decision))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        });
+    }
+    if !project_summary.trim().is_empty() {
+        parts.push(Part {
+            title: "Project summary".to_string(),
+            body: project_summary.trim().to_string(),
+        });
+    }
+    parts.extend(
+        markdown_sections(spec_content)
+            .into_iter()
+            .map(|(title, body)| Part {
+                title: format!("Spec: {}", title),
+                body,
+            }),
+    );
+
+    let mut remaining = max_tokens.saturating_sub(estimate_tokens(&header));
+    let mut included = Vec::new();
+    let mut omitted_sections = Vec::new();
+    let mut truncated = false;
+    for part in parts {
+        let rendered = format!("## {}\n\n{}", part.title, part.body);
+        // Blank line between blocks
+        let cost = estimate_tokens(&rendered) + 1;
+        if cost <= remaining {
+            remaining -= cost;
+            included.push((part.title, rendered));
+        } else if remaining >= MIN_TRUNCATED_TOKENS {
+            let cut = truncate_to_tokens(&rendered, remaining - 1, TRUNCATION_MARKER);
+            remaining = remaining.saturating_sub(estimate_tokens(&cut) + 1);
+            included.push((part.title, cut));
+            truncated = true;
+        } else {
+            omitted_sections.push(part.title);
+        }
+    }
+
+    let handoff_markdown = std::iter::once(header)
+        .chain(included.iter().map(|(_, rendered)| rendered.clone()))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    CreateHandoffResponse {
+        project_name: project_name.to_string(),
+        spec_name: spec_name.to_string(),
+        max_tokens,
+        estimated_tokens: estimate_tokens(&handoff_markdown),
+        open_tasks: open_tasks.len(),
+        completed_tasks,
+        decisions: decisions.len(),
+        included_sections: included.into_iter().map(|(title, _)| title).collect(),
+        omitted_sections,
+        truncated,
+        handoff_markdown,
+    }
+}
+
+/// Open tasks grouped under their task-list section
+fn format_open_tasks(open_tasks: &[&TaskEntry]) -> String {
+    let mut lines = Vec::new();
+    let mut current_section: Option<&str> = None;
+    for task in open_tasks {
+        if current_section != Some(task.section.as_str()) {
+            if !task.section.is_empty() {
+                lines.push(format!("**{}**", task.section));
+            }
+            current_section = Some(task.section.as_str());
+        }
+        lines.push(format!("- [ ] {}", task.text));
+    }
+    lines.join("\n")
+}
+
+/// The last few decisions recorded in notes.md
+///
+/// Every item of a section whose header mentions decisions counts, as does
+/// any other line that mentions deciding or choosing.
+fn recent_decisions(notes: &str) -> Vec<String> {
+    let mut decisions = Vec::new();
+    let mut in_decision_section = false;
+    for line in notes.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            in_decision_section = trimmed.to_lowercase().contains("decision");
+            continue;
+        }
+        let item = trimmed
+            .trim_start_matches(['-', '*'])
+            .trim_start()
+            .to_string();
+        if item.is_empty() {
+            continue;
+        }
+        let lower = item.to_lowercase();
+        let mentions_decision = ["decision", "decided", "decide ", "chose ", "agreed"]
+            .iter()
+            .any(|keyword| lower.contains(keyword));
+        if in_decision_section || mentions_decision {
+            decisions.push(item);
+        }
+    }
+    let skip = decisions.len().saturating_sub(MAX_DECISIONS);
+    decisions.split_off(skip)
+}
+
+/// `(header, body)` of each markdown section; text before the first header is "Overview"
+fn markdown_sections(content: &str) -> Vec<(String, String)> {
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('#') {
+            let title = trimmed.trim_start_matches('#').trim().to_string();
+            sections.push((title, Vec::new()));
+        } else if let Some((_, body)) = sections.last_mut() {
+            body.push(line);
+        } else if !line.trim().is_empty() {
+            sections.push(("Overview".to_string(), vec![line]));
+        }
+    }
+    sections
+        .into_iter()
+        .map(|(title, body)| (title, body.join("\n").trim().to_string()))
+        .filter(|(_, body)| !body.is_empty())
+        .collect()
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
@@ -2,7 +2,9 @@
 
 use anyhow::{Context, Result};
 
+use crate::core::config::{self, FoundryConfig};
 use crate::core::{foundry, validation};
+use crate::types::errors::ErrorCode;
 use crate::types::project::ProjectConfig;
 use crate::types::responses::{CreateProjectResponse, FoundryResponse};
 use crate::utils::response::{build_incomplete_response, build_success_response};
@@ -15,12 +17,12 @@ pub struct Input {
     pub summary: String,
 }
 
+#[tracing::instrument(name = "op.create_project", skip_all, fields(project = %input.project_name))]
 pub async fn run(input: Input) -> Result<FoundryResponse<CreateProjectResponse>> {
     let foundry = foundry::get_default_foundry()?;
 
     validate_project_preconditions(&foundry, &input.project_name).await?;
-
-    let suggestions = process_content_validation(&input)?;
+    let suggestions = validate_project_content(&input)?;
 
     let project_config = build_project_config(input);
     let created_project = foundry
@@ -31,22 +33,34 @@ pub async fn run(input: Input) -> Result<FoundryResponse<CreateProjectResponse>>
     Ok(build_response(created_project, suggestions))
 }
 
-async fn validate_project_preconditions(
-    foundry: &foundry::Foundry<crate::core::backends::filesystem::FilesystemBackend>,
+pub(crate) async fn validate_project_preconditions(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
     project_name: &str,
 ) -> Result<()> {
     validate_project_name(project_name)?;
 
     if foundry.project_exists(project_name).await? {
-        return Err(anyhow::anyhow!("Project '{}' already exists", project_name));
+        return Err(
+            ErrorCode::AlreadyExists.error(format!("Project '{}' already exists", project_name))
+        );
     }
 
     Ok(())
 }
 
-fn process_content_validation(input: &Input) -> Result<Vec<String>> {
-    let validation_results = validate_content(input)?;
+/// Validate a new project's vision, tech stack and summary against its
+/// config, returning the suggestions (and warnings under a lenient profile)
+pub(crate) fn validate_project_content(input: &Input) -> Result<Vec<String>> {
+    let config = config::for_project(&input.project_name)?;
+    process_content_validation(validate_content(input, &config)?, &config)
+}
 
+/// Fail on validation errors (or report them as warnings under a lenient
+/// profile) and return the suggestions for each document
+pub(crate) fn process_content_validation(
+    validation_results: Vec<(&'static str, validation::ValidationResult)>,
+    config: &FoundryConfig,
+) -> Result<Vec<String>> {
     let (validation_errors, suggestions): (Vec<String>, Vec<String>) =
         validation_results.into_iter().fold(
             (Vec::new(), Vec::new()),
@@ -70,10 +84,28 @@ fn process_content_validation(input: &Input) -> Result<Vec<String>> {
         );
 
     if !validation_errors.is_empty() {
-        return Err(anyhow::anyhow!(
-            "Content validation failed:\n{}",
-            validation_errors.join("\n")
-        ));
+        if config.profile().rejects_projects() {
+            return Err(ErrorCode::ValidationFailed.error_with_rule(
+                "content_validation",
+                format!(
+                    "Content validation failed:\n{}",
+                    validation_errors.join("\n")
+                ),
+            ));
+        }
+        // Lenient profile: report failures without rejecting the project
+        let mut warnings: Vec<String> = validation_errors
+            .into_iter()
+            .map(|e| {
+                format!(
+                    "Validation warning ({} profile): {}",
+                    config.profile().name(),
+                    e
+                )
+            })
+            .collect();
+        warnings.extend(suggestions);
+        return Ok(warnings);
     }
 
     Ok(suggestions)
@@ -146,48 +178,51 @@ fn build_response(
     }
 }
 
-fn validate_project_name(name: &str) -> Result<()> {
+pub(crate) fn validate_project_name(name: &str) -> Result<()> {
     if name.is_empty() {
-        return Err(anyhow::anyhow!("Project name cannot be empty"));
+        return Err(ErrorCode::InvalidParams.error("Project name cannot be empty"));
     }
 
     if !name
         .chars()
         .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
     {
-        return Err(anyhow::anyhow!(
-            "Project name must be in kebab-case format (lowercase letters, numbers, and hyphens only)"
-        ));
+        return Err(ErrorCode::ValidationFailed.error_with_rule("project_name_format", "Project name must be in kebab-case format (lowercase letters, numbers, and hyphens only)"));
     }
 
     if name.starts_with('-') || name.ends_with('-') {
-        return Err(anyhow::anyhow!(
-            "Project name cannot start or end with a hyphen"
+        return Err(ErrorCode::ValidationFailed.error_with_rule(
+            "project_name_format",
+            "Project name cannot start or end with a hyphen",
         ));
     }
 
     if name.contains("--") {
-        return Err(anyhow::anyhow!(
-            "Project name cannot contain consecutive hyphens"
+        return Err(ErrorCode::ValidationFailed.error_with_rule(
+            "project_name_format",
+            "Project name cannot contain consecutive hyphens",
         ));
     }
 
     Ok(())
 }
 
-fn validate_content(input: &Input) -> Result<Vec<(&'static str, validation::ValidationResult)>> {
+fn validate_content(
+    input: &Input,
+    config: &FoundryConfig,
+) -> Result<Vec<(&'static str, validation::ValidationResult)>> {
     let validations = vec![
         (
             "Vision",
-            validation::validate_content(validation::ContentType::Vision, &input.vision),
+            config.validate(validation::ContentType::Vision, &input.vision),
         ),
         (
             "Tech Stack",
-            validation::validate_content(validation::ContentType::TechStack, &input.tech_stack),
+            config.validate(validation::ContentType::TechStack, &input.tech_stack),
         ),
         (
             "Summary",
-            validation::validate_content(validation::ContentType::Summary, &input.summary),
+            config.validate(validation::ContentType::Summary, &input.summary),
         ),
     ];
 
@@ -2,9 +2,16 @@
 
 use anyhow::{Context, Result};
 
-use crate::core::{foundry, validation};
-use crate::types::responses::{CreateSpecResponse, FoundryResponse, ValidationStatus};
+use crate::core::config::{self, FoundryConfig};
+use crate::core::ops::load_spec;
+use crate::core::templates::spec_templates::{SpecTemplateInput, SpecTemplateRegistry};
+use crate::core::{context_budget, foundry, links, validation};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{
+    CreateSpecResponse, FoundryResponse, SpecCandidate, ValidationStatus,
+};
 use crate::types::spec::{SpecConfig, SpecContentData};
+use crate::utils::formatting::format_count;
 use crate::utils::paths;
 
 /// Input for create_spec operation (decoupled from interface-specific args)
@@ -15,10 +22,22 @@ pub struct Input {
     pub spec: String,
     pub notes: String,
     pub tasks: String,
+    /// Spec template to render spec.md from, with `spec` as its content
+    pub template: Option<String>,
+    /// Create the spec even when it looks like a duplicate of an existing one
+    pub allow_duplicate: bool,
 }
 
+/// Similarity from which an existing spec counts as a possible duplicate
+const DUPLICATE_THRESHOLD: f32 = 0.9;
+
+/// Words both Overviews need before their text is compared; shorter ones say
+/// too little to tell two specs apart
+const MIN_OVERVIEW_WORDS: usize = 12;
+
 /// Execute the create_spec operation and return a structured response
-pub async fn run(input: Input) -> Result<FoundryResponse<CreateSpecResponse>> {
+#[tracing::instrument(name = "op.create_spec", skip_all, fields(project = %input.project_name))]
+pub async fn run(mut input: Input) -> Result<FoundryResponse<CreateSpecResponse>> {
     let foundry = foundry::get_default_foundry()?;
 
     // Validate project exists
@@ -27,13 +46,55 @@ pub async fn run(input: Input) -> Result<FoundryResponse<CreateSpecResponse>> {
     // Validate feature name
     validate_feature_name(&input.feature_name)?;
 
+    let config = config::for_project(&input.project_name)?;
+
+    // Duplicates are judged by what the user wrote, not by template boilerplate
+    let written_overview = overview(&input.spec);
+
+    // Render the spec template, so validation sees the content that gets written
+    if let Some(template) = input
+        .template
+        .take()
+        .or_else(|| config.templates.spec.clone())
+    {
+        input.spec = render_template(&foundry, &input, &template).await?;
+        input.template = Some(template);
+    }
+
     // Validate content
-    let content_validation = validate_content(&input)?;
+    let content_validation = validate_content(&input, &config)?;
     let has_validation_warnings = content_validation
         .iter()
         .any(|(_, result)| !result.is_valid);
+    if has_validation_warnings && config.profile().rejects_specs() {
+        let errors: Vec<String> = content_validation
+            .iter()
+            .flat_map(|(content_type, result)| {
+                result
+                    .errors
+                    .iter()
+                    .map(move |e| format!("{}: {}", content_type, e))
+            })
+            .collect();
+        return Err(ErrorCode::ValidationFailed.error_with_rule(
+            "content_validation",
+            format!(
+                "Content validation failed ({} profile):\n{}",
+                config.profile().name(),
+                errors.join("\n")
+            ),
+        ));
+    }
+
+    if !input.allow_duplicate {
+        let duplicates = find_duplicates(&foundry, &input, written_overview.as_deref()).await?;
+        if !duplicates.is_empty() {
+            return Err(duplicate_error(&input, duplicates));
+        }
+    }
 
     // Create the spec
+    let template = input.template.clone();
     let spec_config = build_spec_config(input);
     let created_spec = foundry
         .create_spec(spec_config)
@@ -60,49 +121,184 @@ pub async fn run(input: Input) -> Result<FoundryResponse<CreateSpecResponse>> {
     };
 
     let next_steps = generate_next_steps(&created_spec.project_name, &created_spec.name);
-    let workflow_hints = generate_workflow_hints(&content_validation);
+    let mut workflow_hints = generate_workflow_hints(&content_validation);
+    if let Some(template) = &template {
+        workflow_hints.push(format!(
+            "spec.md was rendered from the '{}' template; the validation above applies to the rendered content",
+            template
+        ));
+    }
+
+    // Validate [[wiki-links]] against the project's specs (including the new one)
+    let project_specs = foundry.list_specs(&created_spec.project_name).await?;
+    let broken_links = links::resolve_references(&created_spec.content, &project_specs);
+    if !broken_links.is_empty() {
+        workflow_hints.push(format!(
+            "Unresolved [[links]]: {} - link to a spec name, feature name, or vision/tech-stack/summary",
+            broken_links.join(", ")
+        ));
+    }
 
     Ok(FoundryResponse {
         data: response_data,
         next_steps,
         validation_status,
         workflow_hints,
+        diagnostics: None,
     })
 }
 
+/// Existing specs whose feature name or overview is close to the new spec's,
+/// most similar first
+///
+/// Feature names are compared like fuzzy `load_spec` lookups; `overview`, the
+/// `Overview` the user wrote for the new spec, by the Sørensen-Dice similarity
+/// of its text to each existing spec's when both are long enough to judge.
+async fn find_duplicates(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    input: &Input,
+    overview: Option<&str>,
+) -> Result<Vec<SpecCandidate>> {
+    let feature_name = load_spec::normalize_name(&input.feature_name);
+    let mut duplicates = Vec::new();
+    for meta in foundry.list_specs(&input.project_name).await? {
+        let existing_feature_name = load_spec::normalize_name(&meta.feature_name);
+        let feature = strsim::normalized_levenshtein(&feature_name, &existing_feature_name) as f32;
+        let (match_type, confidence) = if existing_feature_name == feature_name {
+            ("feature_exact", 1.0)
+        } else if feature >= DUPLICATE_THRESHOLD {
+            ("feature_fuzzy", feature)
+        } else {
+            let Some(overview) = overview else {
+                continue;
+            };
+            let existing = foundry.load_spec(&input.project_name, &meta.name).await?;
+            let Some(existing_overview) = self::overview(&existing.content.spec) else {
+                continue;
+            };
+            (
+                "overview_similar",
+                strsim::sorensen_dice(overview, &existing_overview) as f32,
+            )
+        };
+        if confidence >= DUPLICATE_THRESHOLD {
+            duplicates.push(SpecCandidate {
+                spec_name: meta.name,
+                feature_name: meta.feature_name,
+                created_at: meta.created_at,
+                status: meta.status,
+                match_type: match_type.to_string(),
+                confidence: (confidence * 100.0).round() / 100.0,
+            });
+        }
+    }
+    duplicates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    Ok(duplicates)
+}
+
+/// Text of the `Overview` section of spec.md without its heading, lowercased;
+/// `None` when it has fewer than [`MIN_OVERVIEW_WORDS`] words
+fn overview(spec: &str) -> Option<String> {
+    context_budget::split_sections(spec)
+        .into_iter()
+        .find(|(title, _)| title.eq_ignore_ascii_case("overview"))
+        .map(|(_, text)| {
+            text.lines()
+                .filter(|line| !line.trim_start().starts_with('#'))
+                .collect::<Vec<_>>()
+                .join("\n")
+                .trim()
+                .to_lowercase()
+        })
+        .filter(|text| text.split_whitespace().count() >= MIN_OVERVIEW_WORDS)
+}
+
+/// ALREADY_EXISTS error for a spec that was not created because it may
+/// duplicate `duplicates`, which are listed in the error details
+fn duplicate_error(input: &Input, duplicates: Vec<SpecCandidate>) -> anyhow::Error {
+    let mut lines = vec![format!(
+        "Nothing was created: '{}' looks like {}; ask the user whether to extend one of them instead",
+        input.feature_name,
+        format_count(duplicates.len(), "existing spec", "existing specs")
+    )];
+    for duplicate in &duplicates {
+        lines.push(format!(
+            "  - {} ({}, {:.0}% similar): {{\"name\": \"load_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            duplicate.spec_name,
+            duplicate.match_type,
+            duplicate.confidence * 100.0,
+            input.project_name,
+            duplicate.spec_name
+        ));
+    }
+    lines.push(
+        "If it really is a separate feature, call create_spec again with allow_duplicate: true"
+            .to_string(),
+    );
+    ErrorCode::AlreadyExists.error_with_details(
+        lines.join("\n"),
+        serde_json::json!({ "possible_duplicates": duplicates }),
+    )
+}
+
 /// Validate that project exists
 async fn validate_project_exists(
-    foundry: &foundry::Foundry<crate::core::backends::filesystem::FilesystemBackend>,
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
     project_name: &str,
 ) -> Result<()> {
     if !foundry.project_exists(project_name).await? {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ProjectNotFound.error(format!(
             "Project '{}' not found. Use list_projects via MCP to see available projects: {{\"name\": \"list_projects\", \"arguments\": {{}}}}",
             project_name
-        ));
+        )));
     }
     Ok(())
 }
 
+/// Render spec.md from the named template with the project's context
+async fn render_template(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    input: &Input,
+    template: &str,
+) -> Result<String> {
+    let project = foundry
+        .load_project(&input.project_name)
+        .await
+        .context("Failed to load project for spec template")?;
+    let registry = SpecTemplateRegistry::load_default()?;
+    registry.render(
+        template,
+        &SpecTemplateInput {
+            feature_name: &input.feature_name,
+            project_name: &input.project_name,
+            project_summary: project.summary.as_deref().unwrap_or_default(),
+            spec: &input.spec,
+        },
+    )
+}
+
 /// Validate feature name format
 fn validate_feature_name(feature_name: &str) -> Result<()> {
     paths::validate_feature_name(feature_name).context("Feature name validation failed")
 }
 
 /// Validate content according to schema requirements
-fn validate_content(input: &Input) -> Result<Vec<(&'static str, validation::ValidationResult)>> {
+fn validate_content(
+    input: &Input,
+    config: &FoundryConfig,
+) -> Result<Vec<(&'static str, validation::ValidationResult)>> {
     let validations = vec![
         (
             "Spec Content",
-            validation::validate_content(validation::ContentType::Spec, &input.spec),
+            config.validate(validation::ContentType::Spec, &input.spec),
         ),
         (
             "Implementation Notes",
-            validation::validate_content(validation::ContentType::Notes, &input.notes),
+            config.validate(validation::ContentType::Notes, &input.notes),
         ),
         (
             "Task List",
-            validation::validate_content(validation::ContentType::Tasks, &input.tasks),
+            config.validate(validation::ContentType::Tasks, &input.tasks),
         ),
     ];
 
@@ -161,6 +357,12 @@ fn generate_workflow_hints(
             "You might consider reviewing content quality for: {}",
             invalid_content.join(", ")
         ));
+        hints.extend(validation_results.iter().flat_map(|(name, result)| {
+            result
+                .errors
+                .iter()
+                .map(move |e| format!("Validation warning: {}: {}", name, e))
+        }));
     }
 
     hints.push("Tool selection guidance: {\"name\": \"get_foundry_help\", \"arguments\": {\"topic\": \"decision-points\"}}".to_string());
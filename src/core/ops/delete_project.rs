@@ -0,0 +1,88 @@
+//! Core op for deleting a project and its specs (tool-agnostic)
+
+use anyhow::{Context, Result};
+
+use crate::core::backup;
+use crate::core::foundry;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{DeleteProjectResponse, FoundryResponse, ValidationStatus};
+use crate::utils::formatting::format_count;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub confirm: String,
+    /// Also delete the project's specs; without it only empty projects are deleted
+    pub force: bool,
+}
+
+#[tracing::instrument(name = "op.delete_project", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<DeleteProjectResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if input.project_name.trim().is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Project name cannot be empty"));
+    }
+    if !foundry.project_exists(&input.project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            input.project_name
+        )));
+    }
+    if input.confirm.to_lowercase() != "true" {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Deletion not confirmed. Set --confirm true to proceed with deleting project '{}' and all its files. Got: '{}'",
+            input.project_name, input.confirm
+        )));
+    }
+
+    let specs = foundry.list_specs(&input.project_name).await?;
+    if !specs.is_empty() && !input.force {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Project '{}' still has {}. Set --force true to delete them with the project, or delete them first with mcp_foundry_delete_spec",
+            input.project_name,
+            format_count(specs.len(), "spec", "specs")
+        )));
+    }
+
+    for spec in &specs {
+        backup::snapshot_before(&foundry, &input.project_name, &spec.name, "delete_project")
+            .await?;
+    }
+    foundry
+        .delete_project(&input.project_name)
+        .await
+        .with_context(|| format!("Failed to delete project '{}'", input.project_name))?;
+
+    let specs_deleted: Vec<String> = specs.into_iter().map(|spec| spec.name).collect();
+    let next_steps = vec![
+        format!(
+            "Deleted project '{}' with {}",
+            input.project_name,
+            format_count(specs_deleted.len(), "spec", "specs")
+        ),
+        "You can see the remaining projects: mcp_foundry_list_projects".to_string(),
+    ];
+    let mut workflow_hints = vec![
+        "The project's vision, tech stack, summary and decision log cannot be recovered"
+            .to_string(),
+    ];
+    if !specs_deleted.is_empty() {
+        workflow_hints.push(
+            "Deleted specs were backed up first; recreate the project and use 'foundry backup restore <id>' to bring one back"
+                .to_string(),
+        );
+    }
+
+    Ok(FoundryResponse {
+        data: DeleteProjectResponse {
+            project_path: format!("~/.foundry/{}", input.project_name),
+            project_name: input.project_name,
+            specs_deleted,
+        },
+        next_steps,
+        validation_status: ValidationStatus::Complete,
+        workflow_hints,
+        diagnostics: None,
+    })
+}
@@ -2,7 +2,9 @@
 
 use anyhow::{Context, Result};
 
+use crate::core::backup;
 use crate::core::foundry;
+use crate::types::errors::ErrorCode;
 use crate::types::responses::{DeleteSpecResponse, FoundryResponse, ValidationStatus};
 
 #[derive(Debug, Clone)]
@@ -12,6 +14,7 @@ pub struct Input {
     pub confirm: String,
 }
 
+#[tracing::instrument(name = "op.delete_spec", skip_all, fields(project = %input.project_name))]
 pub async fn run(input: Input) -> Result<FoundryResponse<DeleteSpecResponse>> {
     let foundry = foundry::get_default_foundry()?;
 
@@ -31,13 +34,20 @@ pub async fn run(input: Input) -> Result<FoundryResponse<DeleteSpecResponse>> {
             ];
 
             if input.confirm.to_lowercase() != "true" {
-                return Err(anyhow::anyhow!(
+                return Err(ErrorCode::InvalidParams.error(format!(
                     "Deletion not confirmed. Set --confirm true to proceed with deleting spec '{}' and all its files. Got: '{}'",
                     input.spec_name,
                     input.confirm
-                ));
+                )));
             }
 
+            backup::snapshot_before(
+                &foundry,
+                &input.project_name,
+                &input.spec_name,
+                "delete_spec",
+            )
+            .await?;
             foundry
                 .delete_spec(&input.project_name, &input.spec_name)
                 .await
@@ -54,12 +64,12 @@ pub async fn run(input: Input) -> Result<FoundryResponse<DeleteSpecResponse>> {
             }
         }
         Err(_) => {
-            return Err(anyhow::anyhow!(
+            return Err(ErrorCode::SpecNotFound.error(format!(
                 "Spec '{}' not found in project '{}'. Use 'mcp_foundry_load_project {}' to see available specs.",
                 input.spec_name,
                 input.project_name,
                 input.project_name
-            ));
+            )));
         }
     };
 
@@ -68,34 +78,37 @@ pub async fn run(input: Input) -> Result<FoundryResponse<DeleteSpecResponse>> {
         next_steps: generate_next_steps(&input),
         validation_status: ValidationStatus::Complete,
         workflow_hints: generate_workflow_hints(&input),
+        diagnostics: None,
     })
 }
 
 fn validate_args(input: &Input) -> Result<()> {
     if input.project_name.trim().is_empty() {
-        return Err(anyhow::anyhow!("Project name cannot be empty"));
+        return Err(ErrorCode::InvalidParams.error("Project name cannot be empty"));
     }
     if input.spec_name.trim().is_empty() {
-        return Err(anyhow::anyhow!("Spec name cannot be empty"));
+        return Err(ErrorCode::InvalidParams.error("Spec name cannot be empty"));
     }
     if !input.spec_name.contains('_') {
-        return Err(anyhow::anyhow!(
-            "Invalid spec name format '{}'. Expected format: YYYYMMDD_HHMMSS_feature_name",
+        return Err(ErrorCode::ValidationFailed.error_with_rule(
+            "spec_name_format",
+            format!(
+            "Invalid spec name format '{}'. Expected format: <id>_feature_name, e.g. YYYYMMDD_HHMMSS_feature_name",
             input.spec_name
-        ));
+        )));
     }
     Ok(())
 }
 
 async fn validate_project_exists(
-    foundry: &foundry::Foundry<crate::core::backends::filesystem::FilesystemBackend>,
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
     project_name: &str,
 ) -> Result<()> {
     if !foundry.project_exists(project_name).await? {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ProjectNotFound.error(format!(
             "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
             project_name
-        ));
+        )));
     }
     Ok(())
 }
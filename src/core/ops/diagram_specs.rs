@@ -0,0 +1,151 @@
+//! Core op for rendering spec dependencies and task phases as Mermaid diagrams (tool-agnostic)
+
+use anyhow::{Context, Result};
+
+use crate::core::diagram::{self, DiagramNode};
+use crate::core::tasks::parse_task_list;
+use crate::core::{foundry, links};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{DiagramSpecsResponse, FoundryResponse, PhaseDiagram};
+use crate::utils::formatting::format_count;
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    /// Limit the graph to this spec and the specs it links to or is linked from
+    pub spec_name: Option<String>,
+    pub include_phases: bool,
+}
+
+#[tracing::instrument(name = "op.diagram_specs", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<DiagramSpecsResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    validate_project_exists(&foundry, &input.project_name).await?;
+
+    let all_specs = foundry
+        .list_specs(&input.project_name)
+        .await
+        .with_context(|| format!("Failed to list specs for project '{}'", input.project_name))?;
+
+    let focus = match &input.spec_name {
+        Some(spec_name) => Some(
+            links::resolve_link(spec_name, &all_specs)
+                .filter(|name| all_specs.iter().any(|s| &s.name == name))
+                .ok_or_else(|| {
+                    ErrorCode::SpecNotFound.error(format!(
+                        "No spec found matching '{}' in project '{}'",
+                        spec_name, input.project_name
+                    ))
+                })?,
+        ),
+        None => None,
+    };
+
+    // Oldest first so the graph reads in creation order
+    let mut edges: Vec<(String, String)> = Vec::new();
+    let mut loaded = Vec::new();
+    for meta in all_specs.iter().rev() {
+        let spec = foundry
+            .load_spec(&input.project_name, &meta.name)
+            .await
+            .with_context(|| format!("Failed to load spec '{}'", meta.name))?;
+
+        for link in links::collect_spec_links(&spec.content, &all_specs) {
+            if let Some(target) = link.resolved_to
+                && target != spec.name
+                && all_specs.iter().any(|s| s.name == target)
+                && !edges.iter().any(|(f, t)| f == &spec.name && t == &target)
+            {
+                edges.push((spec.name.clone(), target));
+            }
+        }
+        loaded.push((meta.feature_name.clone(), spec));
+    }
+
+    if let Some(focus) = &focus {
+        edges.retain(|(from, to)| from == focus || to == focus);
+        loaded.retain(|(_, spec)| {
+            &spec.name == focus
+                || edges
+                    .iter()
+                    .any(|(f, t)| f == &spec.name || t == &spec.name)
+        });
+    }
+
+    let nodes: Vec<DiagramNode> = loaded
+        .iter()
+        .map(|(feature_name, spec)| DiagramNode {
+            spec_name: spec.name.clone(),
+            label: feature_name.clone(),
+        })
+        .collect();
+    let dependency_diagram = diagram::render_dependency_graph(&nodes, &edges);
+
+    let phase_diagrams = if input.include_phases {
+        loaded
+            .iter()
+            .filter(|(_, spec)| focus.as_ref().is_none_or(|f| f == &spec.name))
+            .map(|(_, spec)| PhaseDiagram {
+                spec_name: spec.name.clone(),
+                diagram: diagram::render_phase_flow(&parse_task_list(&spec.content.tasks)),
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let response_data = DiagramSpecsResponse {
+        project_name: input.project_name.clone(),
+        spec_name: focus,
+        spec_count: nodes.len(),
+        edge_count: edges.len(),
+        dependency_diagram,
+        phase_diagrams,
+    };
+
+    let next_steps = vec![
+        format!(
+            "Rendered {} and {}",
+            format_count(response_data.spec_count, "spec", "specs"),
+            format_count(response_data.edge_count, "dependency", "dependencies")
+        ),
+        "Embed a diagram in notes.md inside a ```mermaid code block using 'update_spec'"
+            .to_string(),
+    ];
+    let mut workflow_hints = vec![
+        "Dependencies come from [[wiki-links]] between specs: an arrow points from the linking spec to the linked spec".to_string(),
+    ];
+    if response_data.edge_count == 0 {
+        workflow_hints.push(
+            "No spec links found - reference related specs with [[feature_name]] to draw dependencies"
+                .to_string(),
+        );
+    }
+    if input.include_phases {
+        workflow_hints.push(
+            "Phase diagrams follow task-list.md sections in order with done/total counts"
+                .to_string(),
+        );
+    }
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,183 @@
+//! Core op for diffing a spec against another spec or an earlier version (tool-agnostic)
+//!
+//! The spec is always the `b/` side. The `a/` side is another spec, a backup
+//! snapshot (see `core::backup`) or the spec as it was before its last
+//! journaled updates (see `core::history`).
+
+use anyhow::{Context, Result};
+
+use crate::core::backup;
+use crate::core::foundry;
+use crate::core::history;
+use crate::core::spec_diff;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{DiffSpecResponse, FoundryResponse};
+use crate::types::spec::{SpecContentData, SpecFileType};
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub spec_name: String,
+    /// Compare with this spec
+    pub other_spec_name: Option<String>,
+    /// Project of `other_spec_name`; defaults to `project_name`
+    pub other_project_name: Option<String>,
+    /// Compare with this backup
+    pub backup_id: Option<String>,
+    /// Compare with the spec before its last this many journaled updates
+    pub history_steps: Option<usize>,
+}
+
+#[tracing::instrument(name = "op.diff_spec", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<DiffSpecResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    let given = [
+        input.other_spec_name.is_some(),
+        input.backup_id.is_some(),
+        input.history_steps.is_some(),
+    ];
+    if given.iter().filter(|given| **given).count() != 1 {
+        return Err(ErrorCode::InvalidParams.error(
+            "Give exactly one of 'other_spec_name', 'backup_id' or 'history_steps' to compare the spec with",
+        ));
+    }
+    if input.other_project_name.is_some() && input.other_spec_name.is_none() {
+        return Err(
+            ErrorCode::InvalidParams.error("'other_project_name' requires 'other_spec_name'")
+        );
+    }
+
+    let spec_name = resolve_spec(&foundry, &input.project_name, &input.spec_name).await?;
+    let spec = foundry
+        .load_spec(&input.project_name, &spec_name)
+        .await
+        .with_context(|| format!("Failed to load spec '{}'", spec_name))?;
+
+    let mut next_steps = Vec::new();
+    let (compared_with, before) = if let Some(other) = &input.other_spec_name {
+        let other_project = input
+            .other_project_name
+            .as_deref()
+            .unwrap_or(&input.project_name);
+        let other_name = resolve_spec(&foundry, other_project, other).await?;
+        let other_spec = foundry
+            .load_spec(other_project, &other_name)
+            .await
+            .with_context(|| format!("Failed to load spec '{}'", other_name))?;
+        let label = if other_project == input.project_name {
+            format!("spec {}", other_name)
+        } else {
+            format!("spec {}/{}", other_project, other_name)
+        };
+        (label, other_spec.content)
+    } else if let Some(id) = &input.backup_id {
+        let dir = backup::default_dir()?;
+        let snapshot = backup::read(&dir, id)?;
+        next_steps.push(format!(
+            "Run 'foundry backup restore {}' to put the backed-up content back",
+            id
+        ));
+        (format!("backup {}", id), snapshot.content)
+    } else {
+        let steps = input.history_steps.unwrap_or_default();
+        let before = content_before_updates(&input.project_name, &spec_name, &spec.content, steps)?;
+        next_steps.push(format!(
+            "Call undo_last_update {} time(s) to return to this version",
+            steps
+        ));
+        (format!("spec before its last {} update(s)", steps), before)
+    };
+
+    let files = spec_diff::diff_content(&before, &spec.content);
+    let unified_diff = [
+        spec_diff::unified_diff("spec.md", &before.spec, &spec.content.spec),
+        spec_diff::unified_diff("notes.md", &before.notes, &spec.content.notes),
+        spec_diff::unified_diff("task-list.md", &before.tasks, &spec.content.tasks),
+    ]
+    .concat();
+    let response_data = DiffSpecResponse {
+        project_name: input.project_name.clone(),
+        spec_name: spec_name.clone(),
+        compared_with,
+        changed: files.iter().any(|file| file.changed),
+        summary: spec_diff::summary(&files),
+        files,
+        unified_diff,
+    };
+
+    next_steps.insert(
+        0,
+        format!(
+            "{} vs {}: {}",
+            response_data.spec_name, response_data.compared_with, response_data.summary
+        ),
+    );
+    let workflow_hints = vec![
+        "Sections are matched by header title; a renamed section shows as one removed and one added".to_string(),
+        "'unified_diff' marks lines only in the spec with '+' and lines only in the compared version with '-'".to_string(),
+    ];
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+async fn resolve_spec(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+    spec_name: &str,
+) -> Result<String> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    foundry
+        .find_spec_match(project_name, spec_name)
+        .await?
+        .into_spec_name(project_name, spec_name)
+}
+
+/// `current` with the files of the newest `steps` journaled updates put back
+fn content_before_updates(
+    project_name: &str,
+    spec_name: &str,
+    current: &SpecContentData,
+    steps: usize,
+) -> Result<SpecContentData> {
+    let entries = history::entries(project_name, spec_name)?;
+    if entries.is_empty() {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Spec '{}' has no journaled updates to compare with",
+            spec_name
+        )));
+    }
+    if steps == 0 || steps > entries.len() {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "'history_steps' must be between 1 and {}, the number of journaled updates of spec '{}'",
+            entries.len(),
+            spec_name
+        )));
+    }
+
+    let mut content = current.clone();
+    for change in entries
+        .iter()
+        .rev()
+        .take(steps)
+        .flat_map(|entry| &entry.files)
+    {
+        let file = match change.file_type {
+            SpecFileType::Spec => &mut content.spec,
+            SpecFileType::Notes => &mut content.notes,
+            SpecFileType::TaskList => &mut content.tasks,
+        };
+        file.clone_from(&change.before);
+    }
+    Ok(content)
+}
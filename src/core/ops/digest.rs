@@ -0,0 +1,279 @@
+//! Core op for summarizing activity across projects over a date range (tool-agnostic)
+//!
+//! Specs created come from spec metadata; tasks completed and notes added
+//! come from the activity journal (see `core::activity`), so only updates
+//! made through Foundry since the journal existed are counted.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use crate::core::activity;
+use crate::core::foundry;
+use crate::core::ops::project_analytics::parse_date;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{
+    DigestNote, DigestResponse, DigestTask, FoundryResponse, ProjectDigest,
+};
+use crate::utils::formatting::format_count;
+use crate::utils::response::build_success_response;
+use crate::utils::timestamp::parse_window;
+
+/// Range covered when `since` is not given
+pub const DEFAULT_SINCE: &str = "7d";
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    /// Start of the range: a window back from now (`7d`, `24h`), a date or an
+    /// RFC 3339 timestamp; defaults to [`DEFAULT_SINCE`]
+    pub since: Option<String>,
+    /// End of the range: a date (inclusive) or an RFC 3339 timestamp; defaults to now
+    pub until: Option<String>,
+    /// Project to summarize; every project when not set
+    pub project_name: Option<String>,
+}
+
+#[tracing::instrument(name = "op.digest", skip_all, fields(project = ?input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<DigestResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+    let now = Utc::now();
+    let since = parse_since(input.since.as_deref().unwrap_or(DEFAULT_SINCE), now)?;
+    let until = input
+        .until
+        .as_deref()
+        .map(parse_until)
+        .transpose()?
+        .unwrap_or(now);
+    if since >= until {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "The digest range is empty: 'since' ({}) is not before 'until' ({})",
+            since.to_rfc3339(),
+            until.to_rfc3339()
+        )));
+    }
+
+    let project_names = match &input.project_name {
+        Some(project_name) => {
+            if !foundry.project_exists(project_name).await? {
+                return Err(ErrorCode::ProjectNotFound
+                    .error(format!("Project '{}' not found", project_name)));
+            }
+            vec![project_name.clone()]
+        }
+        None => foundry
+            .list_projects()
+            .await
+            .context("Failed to list projects")?
+            .into_iter()
+            .map(|project| project.name)
+            .collect(),
+    };
+
+    let mut projects = Vec::new();
+    for project_name in &project_names {
+        let mut specs = foundry
+            .list_specs(project_name)
+            .await
+            .with_context(|| format!("Failed to list specs of '{}'", project_name))?;
+        specs.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        let specs_created: Vec<String> = specs
+            .into_iter()
+            .filter(|spec| timestamp(&spec.created_at).is_some_and(|at| at >= since && at < until))
+            .map(|spec| spec.name)
+            .collect();
+
+        let mut tasks_completed = Vec::new();
+        let mut notes_added = Vec::new();
+        for event in activity::events(project_name, since, until)? {
+            tasks_completed.extend(event.tasks_completed.iter().map(|task| DigestTask {
+                spec_name: event.spec_name.clone(),
+                task: task.clone(),
+                completed_at: event.timestamp.clone(),
+            }));
+            if event.notes_lines_added > 0 {
+                notes_added.push(DigestNote {
+                    spec_name: event.spec_name.clone(),
+                    lines_added: event.notes_lines_added,
+                    updated_at: event.timestamp.clone(),
+                });
+            }
+        }
+
+        if !specs_created.is_empty() || !tasks_completed.is_empty() || !notes_added.is_empty() {
+            projects.push(ProjectDigest {
+                project_name: project_name.clone(),
+                specs_created,
+                tasks_completed,
+                notes_added,
+            });
+        }
+    }
+    projects.sort_by(|a, b| a.project_name.cmp(&b.project_name));
+
+    let mut response_data = DigestResponse {
+        since: since.to_rfc3339(),
+        until: until.to_rfc3339(),
+        projects_checked: project_names.len(),
+        specs_created: projects.iter().map(|p| p.specs_created.len()).sum(),
+        tasks_completed: projects.iter().map(|p| p.tasks_completed.len()).sum(),
+        notes_added: projects.iter().map(|p| p.notes_added.len()).sum(),
+        projects,
+        markdown: String::new(),
+    };
+    response_data.markdown = render_markdown(&response_data);
+
+    let next_steps = if response_data.projects.is_empty() {
+        vec!["No activity in this range; try a longer 'since' window".to_string()]
+    } else {
+        vec![format!(
+            "{} created, {} completed and {} in {}",
+            format_count(response_data.specs_created, "spec", "specs"),
+            format_count(response_data.tasks_completed, "task", "tasks"),
+            format_count(response_data.notes_added, "notes update", "notes updates"),
+            format_count(response_data.projects.len(), "project", "projects"),
+        )]
+    };
+    let workflow_hints = vec![
+        "Use 'markdown' as-is for a weekly report; run `foundry digest --since 7d` from cron to schedule one".to_string(),
+        "Use needs_attention to find specs with open tasks and no recent activity".to_string(),
+    ];
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+/// Start of the range: a window back from `now`, a date or an RFC 3339 timestamp
+pub fn parse_since(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    if let Ok(window) = parse_window(value) {
+        return Ok(now - window);
+    }
+    timestamp(value).ok_or_else(|| {
+        ErrorCode::InvalidParams.error(format!(
+            "Invalid 'since' value '{}'. Use a window (24h, 7d), a date (YYYY-MM-DD) or an RFC 3339 timestamp",
+            value
+        ))
+    })
+}
+
+/// End of the range: the end of a date or an RFC 3339 timestamp
+fn parse_until(value: &str) -> Result<DateTime<Utc>> {
+    let end = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_or_else(
+        |_| {
+            DateTime::parse_from_rfc3339(value)
+                .ok()
+                .map(|at| at.with_timezone(&Utc))
+        },
+        |date| {
+            date.and_hms_opt(0, 0, 0)
+                .map(|start| start.and_utc() + Duration::days(1))
+        },
+    );
+    end.ok_or_else(|| {
+        ErrorCode::InvalidParams.error(format!(
+            "Invalid 'until' value '{}'. Use a date (YYYY-MM-DD) or an RFC 3339 timestamp",
+            value
+        ))
+    })
+}
+
+/// An RFC 3339 timestamp, or the start of a `YYYY-MM-DD` date
+fn timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|at| at.with_timezone(&Utc))
+        .or_else(|| {
+            parse_date(value)
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|start| start.and_utc())
+        })
+}
+
+/// The digest as a markdown report, one section per project
+pub fn render_markdown(digest: &DigestResponse) -> String {
+    let day = |value: &str| value.get(..10).unwrap_or(value).to_string();
+    let mut lines = vec![
+        format!(
+            "# Foundry digest: {} to {}",
+            day(&digest.since),
+            day(&digest.until)
+        ),
+        String::new(),
+        format!(
+            "{} created, {} completed, {} across {}.",
+            format_count(digest.specs_created, "spec", "specs"),
+            format_count(digest.tasks_completed, "task", "tasks"),
+            format_count(digest.notes_added, "notes update", "notes updates"),
+            format_count(digest.projects.len(), "active project", "active projects"),
+        ),
+    ];
+    for project in &digest.projects {
+        lines.push(String::new());
+        lines.push(format!("## {}", project.project_name));
+        if !project.specs_created.is_empty() {
+            lines.push(String::new());
+            lines.push("### Specs created".to_string());
+            lines.push(String::new());
+            lines.extend(
+                project
+                    .specs_created
+                    .iter()
+                    .map(|spec| format!("- {}", spec)),
+            );
+        }
+        if !project.tasks_completed.is_empty() {
+            lines.push(String::new());
+            lines.push("### Tasks completed".to_string());
+            lines.push(String::new());
+            lines.extend(project.tasks_completed.iter().map(|task| {
+                format!(
+                    "- [x] {} ({}, {})",
+                    task.task,
+                    task.spec_name,
+                    day(&task.completed_at)
+                )
+            }));
+        }
+        if !project.notes_added.is_empty() {
+            lines.push(String::new());
+            lines.push("### Notes added".to_string());
+            lines.push(String::new());
+            lines.extend(project.notes_added.iter().map(|note| {
+                format!(
+                    "- {}: {} ({})",
+                    note.spec_name,
+                    format_count(note.lines_added, "line", "lines"),
+                    day(&note.updated_at)
+                )
+            }));
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_bounds_parse_windows_dates_and_timestamps() {
+        let now = DateTime::parse_from_rfc3339("2025-06-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            parse_since("7d", now).unwrap().to_rfc3339(),
+            "2025-06-03T12:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2025-06-01", now).unwrap().to_rfc3339(),
+            "2025-06-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_until("2025-06-07").unwrap().to_rfc3339(),
+            "2025-06-08T00:00:00+00:00"
+        );
+        assert!(parse_since("last week", now).is_err());
+        assert!(parse_until("7d").is_err());
+    }
+}
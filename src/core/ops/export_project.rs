@@ -0,0 +1,92 @@
+//! Core op for exporting a project to a portable archive (tool-agnostic)
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::core::archive::{self, ARCHIVE_EXTENSION};
+use crate::core::foundry;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{ExportProjectResponse, FoundryResponse};
+use crate::utils::formatting::format_count;
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    /// Archive path; `<project>.foundry.tar.gz` in the working directory when not set
+    pub output_path: Option<String>,
+    pub overwrite: bool,
+}
+
+#[tracing::instrument(name = "op.export_project", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<ExportProjectResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    validate_project_exists(&foundry, &input.project_name).await?;
+
+    let archive_path = input.output_path.map_or_else(
+        || PathBuf::from(format!("{}.{}", input.project_name, ARCHIVE_EXTENSION)),
+        PathBuf::from,
+    );
+    if archive_path.exists() && !input.overwrite {
+        return Err(ErrorCode::AlreadyExists.error(format!(
+            "'{}' already exists. Choose another 'output_path' or set 'overwrite' to replace it.",
+            archive_path.display()
+        )));
+    }
+
+    let project_archive = archive::collect(&foundry, &input.project_name).await?;
+    let bytes = archive::write_tar_gz(&project_archive, Vec::new())?;
+    if let Some(parent) = archive_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+    }
+    fs::write(&archive_path, &bytes)
+        .with_context(|| format!("Failed to write archive: {:?}", archive_path))?;
+
+    let response_data = ExportProjectResponse {
+        project_name: input.project_name.clone(),
+        archive_path: archive_path.to_string_lossy().to_string(),
+        spec_count: project_archive.specs.len(),
+        file_count: project_archive.file_count(),
+        bytes: bytes.len(),
+    };
+
+    let next_steps = vec![
+        format!(
+            "Exported '{}' with {} to {}",
+            response_data.project_name,
+            format_count(response_data.spec_count, "spec", "specs"),
+            response_data.archive_path
+        ),
+        format!(
+            "Restore it on another machine with 'foundry import {}' or the import_archive tool",
+            response_data.archive_path
+        ),
+    ];
+    let workflow_hints = vec![
+        "The archive mirrors the ~/.foundry layout plus a manifest.json, so it can be reviewed or checked into a repository".to_string(),
+        "Importing creates specs anew, so spec names get fresh timestamps and links between specs are updated".to_string(),
+    ];
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,239 @@
+//! Core op for exporting a project's tasks in spreadsheet-friendly formats (tool-agnostic)
+
+use anyhow::{Context, Result};
+
+use crate::core::foundry;
+use crate::core::spec_name;
+use crate::core::tasks::{TaskEntry, parse_task_list};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{ExportTasksResponse, FoundryResponse};
+use crate::types::spec::Spec;
+use crate::utils::csv;
+use crate::utils::formatting::format_count;
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub format: Option<String>,
+    pub spec_name: Option<String>,
+}
+
+/// Supported export formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    /// One row per task: spec, section, task, status, completed_at
+    Csv,
+    /// Jira CSV import: one Epic per spec with its tasks as children
+    Jira,
+}
+
+impl ExportFormat {
+    fn parse(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "jira" => Ok(Self::Jira),
+            other => Err(ErrorCode::InvalidParams.error(format!(
+                "Unsupported export format '{}'. Supported formats: csv, jira",
+                other
+            ))),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Jira => "jira",
+        }
+    }
+}
+
+const CSV_HEADER: [&str; 5] = ["spec", "section", "task", "status", "completed_at"];
+const JIRA_HEADER: [&str; 6] = [
+    "Issue Id",
+    "Issue Type",
+    "Summary",
+    "Description",
+    "Parent Id",
+    "Status",
+];
+
+#[tracing::instrument(name = "op.export_tasks", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<ExportTasksResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    validate_project_exists(&foundry, &input.project_name).await?;
+
+    let format = ExportFormat::parse(input.format.as_deref().unwrap_or("csv"))?;
+
+    let spec_names = match &input.spec_name {
+        Some(spec_name) => vec![spec_name.clone()],
+        None => {
+            let mut specs = foundry
+                .list_specs(&input.project_name)
+                .await
+                .with_context(|| {
+                    format!("Failed to list specs for project '{}'", input.project_name)
+                })?;
+            // Oldest first reads naturally in a spreadsheet
+            specs.reverse();
+            specs.into_iter().map(|s| s.name).collect()
+        }
+    };
+
+    let mut specs = Vec::new();
+    for spec_name in &spec_names {
+        let spec = foundry
+            .load_spec(&input.project_name, spec_name)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to load spec '{}' in project '{}'",
+                    spec_name, input.project_name
+                )
+            })?;
+        let tasks = parse_task_list(&spec.content.tasks);
+        specs.push((spec, tasks));
+    }
+
+    let task_count = specs.iter().map(|(_, tasks)| tasks.len()).sum();
+    let done_count = specs
+        .iter()
+        .flat_map(|(_, tasks)| tasks.iter())
+        .filter(|t| t.done)
+        .count();
+    let content = match format {
+        ExportFormat::Csv => csv::format_document(&CSV_HEADER, &csv_rows(&specs)),
+        ExportFormat::Jira => csv::format_document(&JIRA_HEADER, &jira_rows(&specs)),
+    };
+
+    let response_data = ExportTasksResponse {
+        project_name: input.project_name.clone(),
+        format: format.as_str().to_string(),
+        spec_count: specs.len(),
+        task_count,
+        done_count,
+        content,
+    };
+
+    let next_steps = vec![
+        format!(
+            "Exported {} across {}",
+            format_count(response_data.task_count, "task", "tasks"),
+            format_count(response_data.spec_count, "spec", "specs")
+        ),
+        match format {
+            ExportFormat::Csv => {
+                "You can save 'content' to a .csv file and open it in any spreadsheet tool"
+                    .to_string()
+            }
+            ExportFormat::Jira => "You can save 'content' to a .csv file and load it with Jira's CSV importer, mapping Issue Id and Parent Id to keep tasks under their epic".to_string(),
+        },
+    ];
+    let workflow_hints = match format {
+        ExportFormat::Csv => vec![
+            "Columns: spec, section, task, status (done|todo), completed_at".to_string(),
+            "completed_at is filled from the 'completed:YYYY-MM-DD' token that update_spec stamps when a task is checked off"
+                .to_string(),
+        ],
+        ExportFormat::Jira => vec![
+            "Each spec becomes an Epic; each task becomes a Task whose Parent Id is the epic's Issue Id".to_string(),
+            "Status is 'Done' for checked tasks and 'To Do' otherwise".to_string(),
+        ],
+    };
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+fn csv_rows(specs: &[(Spec, Vec<TaskEntry>)]) -> Vec<Vec<String>> {
+    specs
+        .iter()
+        .flat_map(|(spec, tasks)| {
+            tasks.iter().map(|task| {
+                vec![
+                    spec.name.clone(),
+                    task.section.clone(),
+                    task.text.clone(),
+                    task.status_label().to_string(),
+                    task.completed_at.clone().unwrap_or_default(),
+                ]
+            })
+        })
+        .collect()
+}
+
+fn jira_rows(specs: &[(Spec, Vec<TaskEntry>)]) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut next_id = 1;
+
+    for (spec, tasks) in specs {
+        let epic_id = next_id;
+        next_id += 1;
+        let feature_name = spec_name::parse(&spec.name)
+            .map_or_else(|| spec.name.clone(), |parsed| parsed.feature_name);
+        rows.push(vec![
+            epic_id.to_string(),
+            "Epic".to_string(),
+            humanize(&feature_name),
+            format!("Exported from Foundry spec {}", spec.name),
+            String::new(),
+            epic_status(tasks).to_string(),
+        ]);
+
+        for task in tasks {
+            let description = if task.section.is_empty() {
+                format!("Spec: {}", spec.name)
+            } else {
+                format!("Spec: {}\nSection: {}", spec.name, task.section)
+            };
+            rows.push(vec![
+                next_id.to_string(),
+                "Task".to_string(),
+                task.text.clone(),
+                description,
+                epic_id.to_string(),
+                if task.done { "Done" } else { "To Do" }.to_string(),
+            ]);
+            next_id += 1;
+        }
+    }
+
+    rows
+}
+
+fn epic_status(tasks: &[TaskEntry]) -> &'static str {
+    if !tasks.is_empty() && tasks.iter().all(|t| t.done) {
+        "Done"
+    } else if tasks.iter().any(|t| t.done) {
+        "In Progress"
+    } else {
+        "To Do"
+    }
+}
+
+/// Turn a snake_case feature name into a readable title ("user_auth" -> "User auth")
+fn humanize(feature_name: &str) -> String {
+    let spaced = feature_name.replace('_', " ");
+    let mut chars = spaced.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
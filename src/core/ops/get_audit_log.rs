@@ -0,0 +1,102 @@
+//! Core op for reading a project's audit log (tool-agnostic)
+//!
+//! Entries come from `core::audit`. The log outlives the project, so the
+//! changes that led up to a `delete_project` can still be read.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::core::ops::digest::parse_since;
+use crate::core::{audit, foundry};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{AuditLogResponse, FoundryResponse};
+use crate::utils::formatting::format_count;
+use crate::utils::response::build_success_response;
+
+/// Entries returned when `limit` is not given
+pub const DEFAULT_LIMIT: usize = 50;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    /// Only changes to this spec
+    pub spec_name: Option<String>,
+    /// Only this tool or command, e.g. "update_spec"
+    pub operation: Option<String>,
+    /// Only entries from a window back from now (`7d`, `24h`), a date or an
+    /// RFC 3339 timestamp on
+    pub since: Option<String>,
+    /// Newest entries to return; [`DEFAULT_LIMIT`] when not set
+    pub limit: Option<usize>,
+}
+
+#[tracing::instrument(name = "op.get_audit_log", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<AuditLogResponse>> {
+    let since = input
+        .since
+        .as_deref()
+        .map(|since| parse_since(since, Utc::now()))
+        .transpose()?;
+    let limit = input.limit.unwrap_or(DEFAULT_LIMIT);
+
+    let entries = audit::entries(&input.project_name)?;
+    if entries.is_empty()
+        && !foundry::get_default_foundry()?
+            .project_exists(&input.project_name)
+            .await?
+    {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found and has no audit log",
+            input.project_name
+        )));
+    }
+
+    let mut matching: Vec<_> = entries
+        .into_iter()
+        .filter(|entry| {
+            input
+                .spec_name
+                .as_ref()
+                .is_none_or(|spec_name| entry.spec_name.as_ref() == Some(spec_name))
+        })
+        .filter(|entry| {
+            input
+                .operation
+                .as_ref()
+                .is_none_or(|operation| &entry.operation == operation)
+        })
+        .filter(|entry| {
+            since.is_none_or(|since| {
+                DateTime::parse_from_rfc3339(&entry.timestamp).is_ok_and(|at| at >= since)
+            })
+        })
+        .collect();
+    let total_matching = matching.len();
+    matching.reverse();
+    matching.truncate(limit);
+
+    let next_steps = if total_matching > matching.len() {
+        vec![format!(
+            "Showing the newest {} of {}; raise limit or narrow with spec_name, operation or since",
+            matching.len(),
+            format_count(total_matching, "entry", "entries")
+        )]
+    } else {
+        vec![format!(
+            "{} recorded",
+            format_count(total_matching, "change", "changes")
+        )]
+    };
+
+    Ok(build_success_response(
+        AuditLogResponse {
+            project_name: input.project_name,
+            entries: matching,
+            total_matching,
+        },
+        next_steps,
+        vec![
+            "Every call that changes a project is recorded, failed ones included, with the interface (cli, mcp or rest) it came through".to_string(),
+        ],
+    ))
+}
@@ -11,6 +11,7 @@ pub struct Input {
     pub topic: Option<String>,
 }
 
+#[tracing::instrument(name = "op.get_foundry_help", skip_all)]
 pub async fn run(input: Input) -> Result<FoundryResponse<GetFoundryHelpResponse>> {
     let topic = input.topic.as_deref().unwrap_or("overview");
     let content = match topic {
@@ -47,6 +48,7 @@ pub async fn run(input: Input) -> Result<FoundryResponse<GetFoundryHelpResponse>
         next_steps,
         validation_status: ValidationStatus::Complete,
         workflow_hints,
+        diagnostics: None,
     })
 }
 
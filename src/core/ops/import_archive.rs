@@ -0,0 +1,81 @@
+//! Core op for restoring a project from a portable archive (tool-agnostic)
+
+use std::fs::File;
+use std::io::BufReader;
+
+use anyhow::{Context, Result};
+
+use crate::core::archive;
+use crate::core::foundry;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, ImportArchiveResponse, ImportedSpec};
+use crate::utils::formatting::format_count;
+use crate::utils::paths;
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub archive_path: String,
+    /// Project to create; the archived project's name when not set
+    pub project_name: Option<String>,
+}
+
+#[tracing::instrument(name = "op.import_archive", skip_all, fields(archive = %input.archive_path))]
+pub async fn run(input: Input) -> Result<FoundryResponse<ImportArchiveResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    let file = File::open(&input.archive_path).map_err(|e| {
+        ErrorCode::InvalidParams.error(format!(
+            "Cannot open archive '{}': {}",
+            input.archive_path, e
+        ))
+    })?;
+    let project_archive = archive::read_tar_gz(BufReader::new(file))
+        .with_context(|| format!("Failed to read archive '{}'", input.archive_path))?;
+
+    let source_project_name = project_archive.manifest.project_name.clone();
+    let project_name = input
+        .project_name
+        .unwrap_or_else(|| source_project_name.clone());
+    paths::validate_project_name(&project_name)?;
+
+    let (restored, links_rewritten) =
+        archive::restore(&foundry, &project_archive, &project_name).await?;
+
+    let response_data = ImportArchiveResponse {
+        project_name: project_name.clone(),
+        source_project_name,
+        archive_path: input.archive_path.clone(),
+        exported_at: project_archive.manifest.exported_at.clone(),
+        specs: restored
+            .into_iter()
+            .map(|spec| ImportedSpec {
+                original_name: spec.original_name,
+                spec_name: spec.spec_name,
+            })
+            .collect(),
+        links_rewritten,
+    };
+
+    let next_steps = vec![
+        format!(
+            "Imported project '{}' with {}",
+            project_name,
+            format_count(response_data.specs.len(), "spec", "specs")
+        ),
+        format!(
+            "Load the project context: {{\"name\": \"load_project\", \"arguments\": {{\"project_name\": \"{}\"}}}}",
+            project_name
+        ),
+    ];
+    let workflow_hints = vec![
+        "Specs were created anew; 'specs' maps each archived spec name to its new name".to_string(),
+        "Links between specs were rewritten to the new names".to_string(),
+    ];
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
@@ -0,0 +1,527 @@
+//! Core op for importing planning docs from other markdown-based tools (tool-agnostic)
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::foundry;
+use crate::core::ops::create_project;
+use crate::types::errors::ErrorCode;
+use crate::types::project::ProjectConfig;
+use crate::types::responses::{
+    FoundryResponse, ImportFileMapping, ImportProjectResponse, ImportSkip, ImportSpecPlan,
+};
+use crate::types::spec::{SpecConfig, SpecContentData};
+use crate::utils::paths;
+use crate::utils::response::{build_incomplete_response, build_success_response};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub source_path: String,
+    pub preset: Option<String>,
+    pub mapping: Option<ImportMapping>,
+    pub dry_run: bool,
+}
+
+/// Describes where project and spec content lives inside the source directory
+///
+/// Project-level entries are lists of candidate paths relative to the source
+/// root; the first existing file wins. Each directory under `specs_dir` becomes
+/// a spec using the `spec_file`/`notes_file`/`tasks_file` candidates, and each
+/// markdown or text file directly under it becomes a single-file spec.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportMapping {
+    pub vision: Vec<String>,
+    pub tech_stack: Vec<String>,
+    pub summary: Vec<String>,
+    pub specs_dir: Option<String>,
+    pub spec_file: Vec<String>,
+    pub notes_file: Vec<String>,
+    pub tasks_file: Vec<String>,
+}
+
+impl ImportMapping {
+    /// Built-in mapping for a preset name ("docs" or "taskmaster")
+    pub fn preset(name: &str) -> Result<Self> {
+        match name {
+            "docs" => Ok(Self {
+                vision: candidates(&["vision.md", "VISION.md", "README.md"]),
+                tech_stack: candidates(&[
+                    "tech-stack.md",
+                    "TECH_STACK.md",
+                    "architecture.md",
+                    "ARCHITECTURE.md",
+                ]),
+                summary: candidates(&["summary.md", "SUMMARY.md"]),
+                specs_dir: Some("specs".to_string()),
+                spec_file: candidates(&["spec.md", "README.md", "index.md"]),
+                notes_file: candidates(&["notes.md", "NOTES.md"]),
+                tasks_file: candidates(&["task-list.md", "tasks.md", "TODO.md"]),
+            }),
+            "taskmaster" => Ok(Self {
+                vision: candidates(&[
+                    ".taskmaster/docs/prd.txt",
+                    ".taskmaster/docs/prd.md",
+                    "scripts/prd.txt",
+                ]),
+                tech_stack: candidates(&[
+                    ".taskmaster/docs/architecture.md",
+                    ".taskmaster/docs/tech-stack.md",
+                ]),
+                summary: candidates(&[".taskmaster/docs/summary.md", "README.md"]),
+                specs_dir: Some(".taskmaster/tasks".to_string()),
+                spec_file: candidates(&["spec.md"]),
+                notes_file: candidates(&["notes.md"]),
+                tasks_file: candidates(&["tasks.md"]),
+            }),
+            other => Err(ErrorCode::InvalidParams.error(format!(
+                "Unknown import preset '{}'. Supported presets: docs, taskmaster",
+                other
+            ))),
+        }
+    }
+
+    /// Overlay non-empty entries from `other` on top of this mapping
+    fn merged_with(mut self, other: ImportMapping) -> Self {
+        fn take(base: &mut Vec<String>, over: Vec<String>) {
+            if !over.is_empty() {
+                *base = over;
+            }
+        }
+        take(&mut self.vision, other.vision);
+        take(&mut self.tech_stack, other.tech_stack);
+        take(&mut self.summary, other.summary);
+        take(&mut self.spec_file, other.spec_file);
+        take(&mut self.notes_file, other.notes_file);
+        take(&mut self.tasks_file, other.tasks_file);
+        if other.specs_dir.is_some() {
+            self.specs_dir = other.specs_dir;
+        }
+        self
+    }
+}
+
+fn candidates(paths: &[&str]) -> Vec<String> {
+    paths.iter().map(|p| p.to_string()).collect()
+}
+
+/// A spec discovered in the source directory, ready to be written
+struct PlannedSpec {
+    plan: ImportSpecPlan,
+    content: SpecContentData,
+}
+
+#[tracing::instrument(name = "op.import_project", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<ImportProjectResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    // Same name rules (and error codes) as create_project
+    create_project::validate_project_name(&input.project_name)?;
+    paths::validate_project_name(&input.project_name)?;
+    let source_root = resolve_source(&input.source_path)?;
+
+    let preset = input.preset.clone().unwrap_or_else(|| "docs".to_string());
+    let mapping = match input.mapping.clone() {
+        Some(overrides) => ImportMapping::preset(&preset)?.merged_with(overrides),
+        None => ImportMapping::preset(&preset)?,
+    };
+
+    let project_exists = foundry.project_exists(&input.project_name).await?;
+    let mut issues = Vec::new();
+    let mut skipped = Vec::new();
+
+    let mut project_files = Vec::new();
+    let mut project_content = Vec::new();
+    for (target, sources) in [
+        ("vision.md", &mapping.vision),
+        ("tech-stack.md", &mapping.tech_stack),
+        ("summary.md", &mapping.summary),
+    ] {
+        match first_existing(&source_root, sources) {
+            Some(source) if project_exists => skipped.push(ImportSkip {
+                source: display_relative(&source_root, &source),
+                reason: format!(
+                    "Project '{}' already exists; {} is left unchanged",
+                    input.project_name, target
+                ),
+            }),
+            Some(source) => {
+                project_content.push(read_source(&source)?);
+                project_files.push(ImportFileMapping {
+                    source: display_relative(&source_root, &source),
+                    target: target.to_string(),
+                });
+            }
+            None if project_exists => {}
+            None => issues.push(format!(
+                "No source found for {} (looked for: {})",
+                target,
+                sources.join(", ")
+            )),
+        }
+    }
+
+    let existing_features: Vec<String> = if project_exists {
+        foundry
+            .list_specs(&input.project_name)
+            .await?
+            .into_iter()
+            .map(|s| s.feature_name)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut planned_specs = Vec::new();
+    if let Some(specs_dir) = &mapping.specs_dir {
+        let specs_root = source_root.join(specs_dir);
+        if specs_root.is_dir() {
+            for entry in sorted_entries(&specs_root)? {
+                match plan_spec(&source_root, &entry, &mapping)? {
+                    Ok(planned) => {
+                        let feature = &planned.plan.feature_name;
+                        let duplicate = existing_features.contains(feature)
+                            || planned_specs
+                                .iter()
+                                .any(|p: &PlannedSpec| &p.plan.feature_name == feature);
+                        if duplicate {
+                            skipped.push(ImportSkip {
+                                source: planned.plan.source,
+                                reason: format!(
+                                    "A spec for feature '{}' already exists or was already planned",
+                                    feature
+                                ),
+                            });
+                        } else {
+                            planned_specs.push(planned);
+                        }
+                    }
+                    Err(skip) => skipped.push(skip),
+                }
+            }
+        } else {
+            skipped.push(ImportSkip {
+                source: specs_dir.clone(),
+                reason: "Specs directory not found in source".to_string(),
+            });
+        }
+    }
+
+    // A new project gets the same content checks as create_project
+    let mut suggestions = Vec::new();
+    if !project_exists && issues.is_empty() {
+        let mut content = project_content.iter().cloned();
+        let project = create_project::Input {
+            project_name: input.project_name.clone(),
+            vision: content.next().unwrap_or_default(),
+            tech_stack: content.next().unwrap_or_default(),
+            summary: content.next().unwrap_or_default(),
+        };
+        match create_project::validate_project_content(&project) {
+            Ok(found) => suggestions = found,
+            Err(e) => issues.push(e.to_string()),
+        }
+    }
+
+    if !input.dry_run && !issues.is_empty() {
+        return Err(ErrorCode::ValidationFailed.error(format!(
+            "Import of '{}' blocked by {} issue(s):\n{}\nRe-run with dry_run to review the mapping, or provide a mapping that points at the missing files.",
+            source_root.display(),
+            issues.len(),
+            issues.join("\n")
+        )));
+    }
+
+    if !input.dry_run {
+        if !project_exists {
+            let mut content = project_content.into_iter();
+            foundry
+                .create_project(ProjectConfig {
+                    name: input.project_name.clone(),
+                    vision: content.next().unwrap_or_default(),
+                    tech_stack: content.next().unwrap_or_default(),
+                    summary: content.next().unwrap_or_default(),
+                })
+                .await
+                .context("Failed to create project from imported files")?;
+        }
+
+        for planned in planned_specs.iter_mut() {
+            let created = foundry
+                .create_spec(SpecConfig {
+                    project_name: input.project_name.clone(),
+                    feature_name: planned.plan.feature_name.clone(),
+                    content: planned.content.clone(),
+                })
+                .await
+                .with_context(|| format!("Failed to create spec from '{}'", planned.plan.source))?;
+            planned.plan.spec_name = Some(created.name);
+        }
+    }
+
+    let response_data = ImportProjectResponse {
+        project_name: input.project_name.clone(),
+        source_path: source_root.to_string_lossy().to_string(),
+        preset,
+        dry_run: input.dry_run,
+        project_action: if project_exists { "existing" } else { "create" }.to_string(),
+        project_files,
+        specs: planned_specs.into_iter().map(|p| p.plan).collect(),
+        skipped,
+        issues,
+    };
+
+    Ok(build_response(response_data, suggestions))
+}
+
+fn resolve_source(source_path: &str) -> Result<PathBuf> {
+    if source_path.trim().is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Source path cannot be empty"));
+    }
+    let path = PathBuf::from(source_path);
+    if !path.is_dir() {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Source path '{}' does not exist or is not a directory",
+            source_path
+        )));
+    }
+    path.canonicalize()
+        .with_context(|| format!("Failed to resolve source path '{}'", source_path))
+}
+
+fn first_existing(root: &Path, candidates: &[String]) -> Option<PathBuf> {
+    candidates
+        .iter()
+        .map(|c| root.join(c))
+        .find(|p| p.is_file())
+}
+
+pub(crate) fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory: {}", dir.display()))?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            !p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with('.'))
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+    Ok(entries)
+}
+
+fn read_source(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read file: {}", path.display()))
+}
+
+fn display_relative(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Build a spec plan from a directory or single file, or explain why it was skipped
+fn plan_spec(
+    root: &Path,
+    entry: &Path,
+    mapping: &ImportMapping,
+) -> Result<std::result::Result<PlannedSpec, ImportSkip>> {
+    let source = display_relative(root, entry);
+    let stem = entry
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default()
+        .to_string();
+
+    if entry.is_dir() {
+        let Some(spec_path) = first_existing(entry, &mapping.spec_file) else {
+            return Ok(Err(ImportSkip {
+                source,
+                reason: format!(
+                    "No spec file found (looked for: {})",
+                    mapping.spec_file.join(", ")
+                ),
+            }));
+        };
+        let spec = read_source(&spec_path)?;
+        let notes = first_existing(entry, &mapping.notes_file)
+            .map(|p| read_source(&p))
+            .transpose()?
+            .unwrap_or_default();
+        let tasks = first_existing(entry, &mapping.tasks_file)
+            .map(|p| read_source(&p))
+            .transpose()?
+            .unwrap_or_else(|| extract_checklist(&spec));
+
+        return Ok(finish_plan(source, &stem, spec, notes, tasks));
+    }
+
+    if !is_text_file(entry) {
+        return Ok(Err(ImportSkip {
+            source,
+            reason: "Unsupported file type (expected .md, .markdown, or .txt)".to_string(),
+        }));
+    }
+
+    let spec = read_source(entry)?;
+    let title = taskmaster_field(&spec, "Title");
+    // Taskmaster task files carry a single task with a status header
+    let tasks = title.as_deref().map_or_else(
+        || extract_checklist(&spec),
+        |title| {
+            let done =
+                taskmaster_field(&spec, "Status").is_some_and(|s| s.eq_ignore_ascii_case("done"));
+            format!("- [{}] {}", if done { "x" } else { " " }, title)
+        },
+    );
+    let feature_source = title.unwrap_or(stem);
+
+    Ok(finish_plan(
+        source,
+        &feature_source,
+        spec,
+        String::new(),
+        tasks,
+    ))
+}
+
+/// Whether `path` is a markdown or text file that can be imported
+pub(crate) fn is_text_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| matches!(e, "md" | "markdown" | "txt"))
+}
+
+fn finish_plan(
+    source: String,
+    feature_source: &str,
+    spec: String,
+    notes: String,
+    tasks: String,
+) -> std::result::Result<PlannedSpec, ImportSkip> {
+    let feature_name = paths::normalize_feature_name(feature_source);
+    if let Err(e) = paths::validate_feature_name(&feature_name) {
+        return Err(ImportSkip {
+            source,
+            reason: format!("Could not derive a feature name: {}", e),
+        });
+    }
+    if spec.trim().is_empty() {
+        return Err(ImportSkip {
+            source,
+            reason: "Spec content is empty".to_string(),
+        });
+    }
+
+    let notes = if notes.trim().is_empty() {
+        format!("Imported from {}", source)
+    } else {
+        notes
+    };
+    let task_count = tasks
+        .lines()
+        .filter(|l| {
+            let t = l.trim_start();
+            t.starts_with("- [ ]") || t.starts_with("- [x]") || t.starts_with("- [X]")
+        })
+        .count();
+
+    Ok(PlannedSpec {
+        plan: ImportSpecPlan {
+            source,
+            feature_name,
+            task_count,
+            spec_name: None,
+        },
+        content: SpecContentData { spec, notes, tasks },
+    })
+}
+
+/// Read a `# Field: value` header line as written by Taskmaster task files
+fn taskmaster_field(content: &str, field: &str) -> Option<String> {
+    let prefix = format!("# {}:", field);
+    content
+        .lines()
+        .find_map(|l| l.strip_prefix(&prefix))
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Collect checklist lines from a document to seed task-list.md
+pub(crate) fn extract_checklist(content: &str) -> String {
+    content
+        .lines()
+        .filter(|l| {
+            let t = l.trim_start();
+            t.starts_with("- [ ]") || t.starts_with("- [x]") || t.starts_with("- [X]")
+        })
+        .map(|l| l.trim_start())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn build_response(
+    data: ImportProjectResponse,
+    suggestions: Vec<String>,
+) -> FoundryResponse<ImportProjectResponse> {
+    let spec_count = data.specs.len();
+    let mut next_steps = Vec::new();
+    let mut workflow_hints = vec![
+        "Imported content is copied verbatim - review it with load_spec and refine with update_spec".to_string(),
+        format!(
+            "Mapping preset '{}' was used; pass a 'mapping' object to point at different files",
+            data.preset
+        ),
+    ];
+
+    if data.dry_run {
+        next_steps.push(format!(
+            "Dry run: would {} project '{}' and import {} spec(s); nothing was written",
+            if data.project_action == "create" {
+                "create"
+            } else {
+                "update existing"
+            },
+            data.project_name,
+            spec_count
+        ));
+        next_steps.push(format!(
+            "Apply the import: {{\"name\": \"import_project\", \"arguments\": {{\"project_name\": \"{}\", \"source_path\": \"{}\", \"dry_run\": false}}}}",
+            data.project_name, data.source_path
+        ));
+    } else {
+        next_steps.push(format!(
+            "Imported {} spec(s) into project '{}'",
+            spec_count, data.project_name
+        ));
+        next_steps.push(format!(
+            "Load project: {{\"name\": \"load_project\", \"arguments\": {{\"project_name\": \"{}\"}}}}",
+            data.project_name
+        ));
+    }
+
+    if !data.skipped.is_empty() {
+        workflow_hints.push(format!(
+            "{} source item(s) were skipped - see 'skipped' for reasons",
+            data.skipped.len()
+        ));
+    }
+    workflow_hints.extend(suggestions);
+
+    if data.issues.is_empty() {
+        build_success_response(data, next_steps, workflow_hints)
+    } else {
+        next_steps.insert(
+            0,
+            format!(
+                "Resolve {} issue(s) before importing without dry_run",
+                data.issues.len()
+            ),
+        );
+        build_incomplete_response(data, next_steps, workflow_hints)
+    }
+}
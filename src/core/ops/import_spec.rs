@@ -0,0 +1,395 @@
+//! Core op for importing existing markdown as a spec (tool-agnostic)
+//!
+//! The source is one markdown file or a directory of them (e.g. a Notion or
+//! Confluence export). Each file is split at its `##` headings and every
+//! section is sorted into spec.md, notes.md or task-list.md by its heading:
+//! "Tasks", "TODO", "Checklist" and the like go to the task list, "Notes",
+//! "Open Questions", "Decisions" and the like to the notes, and everything
+//! else, including the text before the first heading, to the spec. Files named
+//! like notes or tasks files (`notes.md`, `tasks.md`, `TODO.md`, ...) go to
+//! that file whole.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::core::config;
+use crate::core::foundry;
+use crate::core::ops::import_project::{extract_checklist, is_text_file, sorted_entries};
+use crate::core::validation::ContentType;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{
+    FoundryResponse, ImportFileMapping, ImportSpecResponse, ValidationStatus,
+};
+use crate::types::spec::{SpecConfig, SpecContentData};
+use crate::utils::paths;
+
+/// Headings (lowercase) of sections imported into task-list.md
+const TASK_HEADINGS: &[&str] = &[
+    "task",
+    "todo",
+    "to do",
+    "to-do",
+    "checklist",
+    "action item",
+    "implementation plan",
+    "next step",
+    "work item",
+];
+
+/// Headings (lowercase) of sections imported into notes.md
+const NOTES_HEADINGS: &[&str] = &[
+    "note",
+    "open question",
+    "question",
+    "decision",
+    "discussion",
+    "reference",
+    "research",
+    "meeting",
+    "risk",
+];
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    /// Markdown file, or directory of markdown files, to import
+    pub source_path: String,
+    /// Feature name for the spec; derived from the first title or the file name when omitted
+    pub feature_name: Option<String>,
+    /// Report how the content would be split without creating the spec
+    pub dry_run: bool,
+}
+
+/// Content split out of the source files, with where each part went
+#[derive(Debug, Default)]
+struct SplitContent {
+    spec: Vec<String>,
+    notes: Vec<String>,
+    tasks: Vec<String>,
+    sections: Vec<ImportFileMapping>,
+}
+
+#[tracing::instrument(name = "op.import_spec", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<ImportSpecResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if !foundry.project_exists(&input.project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use list_projects via MCP to see available projects: {{\"name\": \"list_projects\", \"arguments\": {{}}}}",
+            input.project_name
+        )));
+    }
+    let (source, source_root, files) = resolve_files(&input.source_path)?;
+
+    let mut split = SplitContent::default();
+    let mut title = None;
+    for file in &files {
+        let content = std::fs::read_to_string(file)
+            .with_context(|| format!("Failed to read file: {}", file.display()))?;
+        let source = file
+            .strip_prefix(&source_root)
+            .unwrap_or(file)
+            .to_string_lossy()
+            .to_string();
+        title = title.or_else(|| first_title(&content));
+        split_file(&source, &content, &mut split);
+    }
+
+    let feature_name = input
+        .feature_name
+        .clone()
+        .unwrap_or_else(|| paths::normalize_feature_name(&title.unwrap_or_else(|| stem(&source))));
+    paths::validate_feature_name(&feature_name).map_err(|e| {
+        ErrorCode::ValidationFailed.error(format!(
+            "Could not use '{}' as the feature name: {}. Pass feature_name explicitly",
+            feature_name, e
+        ))
+    })?;
+
+    let spec = split.spec.join("\n\n");
+    if spec.trim().is_empty() {
+        return Err(ErrorCode::ValidationFailed.error(format!(
+            "No spec content found in '{}'; every section was sorted into notes or tasks",
+            input.source_path
+        )));
+    }
+    let tasks = if split.tasks.is_empty() {
+        extract_checklist(&spec)
+    } else {
+        split.tasks.join("\n\n")
+    };
+    let notes = if split.notes.is_empty() {
+        format!("Imported from {}", source.display())
+    } else {
+        split.notes.join("\n\n")
+    };
+    let content = SpecContentData { spec, notes, tasks };
+
+    let config = config::for_project(&input.project_name)?;
+    let validation_errors: Vec<String> = [
+        ("spec.md", ContentType::Spec, &content.spec),
+        ("notes.md", ContentType::Notes, &content.notes),
+        ("task-list.md", ContentType::Tasks, &content.tasks),
+    ]
+    .into_iter()
+    .flat_map(|(file_name, content_type, content)| {
+        config
+            .validate(content_type, content)
+            .errors
+            .into_iter()
+            .map(move |e| format!("{}: {}", file_name, e))
+    })
+    .collect();
+    if !input.dry_run && !validation_errors.is_empty() && config.profile().rejects_specs() {
+        return Err(ErrorCode::ValidationFailed.error(format!(
+            "Content validation failed ({} profile), nothing was imported:\n{}",
+            config.profile().name(),
+            validation_errors.join("\n")
+        )));
+    }
+
+    let spec_name = if input.dry_run {
+        None
+    } else {
+        let created = foundry
+            .create_spec(SpecConfig {
+                project_name: input.project_name.clone(),
+                feature_name: feature_name.clone(),
+                content: content.clone(),
+            })
+            .await
+            .with_context(|| format!("Failed to create spec from '{}'", input.source_path))?;
+        Some(created.name)
+    };
+
+    let response_data = ImportSpecResponse {
+        project_name: input.project_name.clone(),
+        source_path: source.to_string_lossy().to_string(),
+        dry_run: input.dry_run,
+        feature_name,
+        spec_name,
+        files_read: files.len(),
+        sections: split.sections,
+        task_count: count_tasks(&content.tasks),
+        validation_errors,
+    };
+    Ok(build_response(response_data))
+}
+
+/// The resolved source, the directory its files are named relative to, and
+/// the markdown files to read, in name order
+fn resolve_files(source_path: &str) -> Result<(PathBuf, PathBuf, Vec<PathBuf>)> {
+    if source_path.trim().is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Source path cannot be empty"));
+    }
+    let path = PathBuf::from(source_path).canonicalize().map_err(|_| {
+        ErrorCode::InvalidParams.error(format!("Source path '{}' does not exist", source_path))
+    })?;
+
+    if path.is_file() {
+        if !is_text_file(&path) {
+            return Err(ErrorCode::InvalidParams.error(format!(
+                "Unsupported file type '{}' (expected .md, .markdown, or .txt)",
+                source_path
+            )));
+        }
+        let root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        return Ok((path.clone(), root, vec![path]));
+    }
+
+    let files: Vec<PathBuf> = sorted_entries(&path)?
+        .into_iter()
+        .filter(|entry| entry.is_file() && is_text_file(entry))
+        .collect();
+    if files.is_empty() {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "No .md, .markdown, or .txt files found in '{}'",
+            source_path
+        )));
+    }
+    Ok((path.clone(), path, files))
+}
+
+/// Sort the sections of one file into `split`
+fn split_file(source: &str, content: &str, split: &mut SplitContent) {
+    let file_stem = stem(Path::new(source)).to_lowercase();
+    let whole_file_target = if matches!(file_stem.as_str(), "tasks" | "task-list" | "todo") {
+        Some(Target::Tasks)
+    } else if matches!(file_stem.as_str(), "notes" | "decisions") {
+        Some(Target::Notes)
+    } else {
+        None
+    };
+    if let Some(target) = whole_file_target {
+        push_section(split, target, source, None, content.trim());
+        return;
+    }
+
+    for (heading, body) in sections(content) {
+        let target = heading.as_deref().map_or(Target::Spec, classify);
+        push_section(split, target, source, heading.as_deref(), &body);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Spec,
+    Notes,
+    Tasks,
+}
+
+fn classify(heading: &str) -> Target {
+    let heading = heading.to_lowercase();
+    if TASK_HEADINGS.iter().any(|word| heading.contains(word)) {
+        Target::Tasks
+    } else if NOTES_HEADINGS.iter().any(|word| heading.contains(word)) {
+        Target::Notes
+    } else {
+        Target::Spec
+    }
+}
+
+fn push_section(
+    split: &mut SplitContent,
+    target: Target,
+    source: &str,
+    heading: Option<&str>,
+    body: &str,
+) {
+    if body.trim().is_empty() {
+        return;
+    }
+    let (parts, file_name, body) = match target {
+        Target::Spec => (&mut split.spec, "spec.md", body.to_string()),
+        Target::Notes => (&mut split.notes, "notes.md", body.to_string()),
+        Target::Tasks => (&mut split.tasks, "task-list.md", as_checklist(body)),
+    };
+    parts.push(body);
+    split.sections.push(ImportFileMapping {
+        source: heading.map_or_else(
+            || source.to_string(),
+            |heading| format!("{}#{}", source, heading),
+        ),
+        target: file_name.to_string(),
+    });
+}
+
+/// `content` split at its `##` headings outside code fences; the text before
+/// the first heading comes first, without a heading
+fn sections(content: &str) -> Vec<(Option<String>, String)> {
+    let mut sections = vec![(None, String::new())];
+    let mut in_fence = false;
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        if !in_fence && let Some(heading) = line.strip_prefix("## ") {
+            sections.push((Some(heading.trim().to_string()), String::new()));
+        }
+        let (_, body) = sections.last_mut().expect("sections is never empty");
+        body.push_str(line);
+        body.push('\n');
+    }
+    sections
+        .into_iter()
+        .map(|(heading, body)| (heading, body.trim().to_string()))
+        .collect()
+}
+
+/// A task section with plain list items turned into open checklist items
+fn as_checklist(body: &str) -> String {
+    body.lines()
+        .map(|line| {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            let item = line.trim_start();
+            if item.starts_with("- [") {
+                return line.to_string();
+            }
+            let text = item
+                .strip_prefix("- ")
+                .or_else(|| item.strip_prefix("* "))
+                .or_else(|| {
+                    let (number, rest) = item.split_once(". ")?;
+                    number.bytes().all(|b| b.is_ascii_digit()).then_some(rest)
+                });
+            text.map_or_else(
+                || line.to_string(),
+                |text| format!("{}- [ ] {}", indent, text),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Text of the first `# ` title
+fn first_title(content: &str) -> Option<String> {
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+}
+
+fn count_tasks(tasks: &str) -> usize {
+    tasks
+        .lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            line.starts_with("- [ ]") || line.starts_with("- [x]") || line.starts_with("- [X]")
+        })
+        .count()
+}
+
+fn stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn build_response(data: ImportSpecResponse) -> FoundryResponse<ImportSpecResponse> {
+    let mut next_steps = Vec::new();
+    if let Some(spec_name) = &data.spec_name {
+        next_steps.push(format!(
+            "Imported {} section(s) into spec '{}'",
+            data.sections.len(),
+            spec_name
+        ));
+        next_steps.push(format!(
+            "Review the split: {{\"name\": \"load_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            data.project_name, spec_name
+        ));
+    } else {
+        next_steps.push(format!(
+            "Dry run: would create spec '{}' from {} section(s); nothing was written. See 'sections' for where each went",
+            data.feature_name,
+            data.sections.len()
+        ));
+        next_steps.push(format!(
+            "Apply the import: {{\"name\": \"import_spec\", \"arguments\": {{\"project_name\": \"{}\", \"source_path\": \"{}\", \"feature_name\": \"{}\"}}}}",
+            data.project_name, data.source_path, data.feature_name
+        ));
+    }
+
+    let mut workflow_hints = vec![
+        "Sections are sorted by heading and copied verbatim - move misplaced ones with update_spec"
+            .to_string(),
+    ];
+    workflow_hints.extend(
+        data.validation_errors
+            .iter()
+            .map(|error| format!("Validation warning: {}", error)),
+    );
+    let validation_status = if data.validation_errors.is_empty() {
+        ValidationStatus::Complete
+    } else {
+        ValidationStatus::Incomplete
+    };
+
+    FoundryResponse {
+        data,
+        next_steps,
+        validation_status,
+        workflow_hints,
+        diagnostics: None,
+    }
+}
@@ -0,0 +1,455 @@
+//! Core op for linting a spec's structural conventions (tool-agnostic)
+//!
+//! Unlike review_spec, which scores how ready a spec is, linting only checks
+//! the shape of its files and pairs each finding with the update_spec
+//! commands that fix it, so an agent can correct a malformed spec itself.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+
+use crate::core::config;
+use crate::core::foundry;
+use crate::core::ops::review_spec::CRITERIA_HEADERS;
+use crate::core::tasks;
+use crate::core::validation::ContentType;
+use crate::types::edit_commands::{EditCommand, EditCommandName, EditCommandTarget, EditSelector};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, LintFinding, LintSeverity, LintSpecResponse};
+use crate::utils::formatting::format_count;
+use crate::utils::response::{build_incomplete_response, build_success_response};
+
+/// Sections spec.md must have unless `[validation.rules.spec]` sets its own
+pub const DEFAULT_REQUIRED_SECTIONS: &[&str] = &["Overview", "Requirements"];
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub spec_name: String,
+}
+
+#[tracing::instrument(name = "op.lint_spec", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<LintSpecResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+    if !foundry.project_exists(&input.project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            input.project_name
+        )));
+    }
+
+    let spec_name = foundry
+        .find_spec_match(&input.project_name, &input.spec_name)
+        .await?
+        .into_spec_name(&input.project_name, &input.spec_name)?;
+    let spec = foundry
+        .load_spec(&input.project_name, &spec_name)
+        .await
+        .with_context(|| format!("Failed to load spec '{}'", spec_name))?;
+
+    let required_sections = config::for_project(&input.project_name)?
+        .validation
+        .rules
+        .get(ContentType::Spec.key())
+        .map(|rules| rules.required_sections.clone())
+        .filter(|sections| !sections.is_empty())
+        .unwrap_or_else(|| {
+            DEFAULT_REQUIRED_SECTIONS
+                .iter()
+                .map(|section| section.to_string())
+                .collect()
+        });
+    let findings = lint(
+        &required_sections,
+        &spec.content.spec,
+        &spec.content.notes,
+        &spec.content.tasks,
+    );
+
+    let count = |severity| {
+        findings
+            .iter()
+            .filter(|finding| finding.severity == severity)
+            .count()
+    };
+    let response_data = LintSpecResponse {
+        project_name: input.project_name,
+        spec_name: spec.name,
+        errors: count(LintSeverity::Error),
+        warnings: count(LintSeverity::Warning),
+        findings,
+    };
+
+    let mut next_steps = vec![if response_data.findings.is_empty() {
+        format!(
+            "Spec '{}' follows every convention",
+            response_data.spec_name
+        )
+    } else {
+        format!(
+            "Spec '{}' has {} and {}",
+            response_data.spec_name,
+            format_count(response_data.errors, "error", "errors"),
+            format_count(response_data.warnings, "warning", "warnings")
+        )
+    }];
+    if response_data
+        .findings
+        .iter()
+        .any(|finding| !finding.fix.is_empty())
+    {
+        next_steps.push(format!(
+            "Apply the 'fix' commands with update_spec for '{}', then re-run lint_spec",
+            response_data.spec_name
+        ));
+    }
+    let workflow_hints = vec![
+        "Fix commands containing <...> placeholders need them replaced with real content first"
+            .to_string(),
+        "Findings without fix commands need a hand edit, e.g. with replace_section_content"
+            .to_string(),
+    ];
+
+    Ok(if response_data.errors == 0 {
+        build_success_response(response_data, next_steps, workflow_hints)
+    } else {
+        build_incomplete_response(response_data, next_steps, workflow_hints)
+    })
+}
+
+/// Findings for spec, notes and task list content, in file order
+///
+/// `required_sections` are heading texts spec.md must have, matched like
+/// `[validation.rules.spec]` (without `#`, ignoring case).
+pub fn lint(
+    required_sections: &[String],
+    spec: &str,
+    notes: &str,
+    tasks_content: &str,
+) -> Vec<LintFinding> {
+    let mut findings = check_required_sections(required_sections, spec);
+    findings.extend(check_acceptance_criteria(spec));
+    findings.extend(check_heading_hierarchy("spec.md", spec));
+    findings.extend(check_heading_hierarchy("notes.md", notes));
+    findings.extend(check_heading_hierarchy("task-list.md", tasks_content));
+    findings.extend(check_checkbox_tasks(tasks_content));
+    findings.extend(check_duplicate_tasks(tasks_content));
+    findings
+}
+
+fn check_required_sections(required_sections: &[String], spec: &str) -> Vec<LintFinding> {
+    let present: Vec<String> = headings(spec)
+        .iter()
+        .map(|heading| heading.text.to_lowercase())
+        .collect();
+    required_sections
+        .iter()
+        .map(|section| section.trim().trim_start_matches('#').trim())
+        .filter(|section| !present.contains(&section.to_lowercase()))
+        .map(|section| LintFinding {
+            rule: "missing_section".to_string(),
+            severity: LintSeverity::Error,
+            file: "spec.md".to_string(),
+            line: None,
+            message: format!("spec.md has no '## {}' section", section),
+            fix: append_section(
+                spec,
+                &format!(
+                    "## {}\n\n<{} of the feature>",
+                    section,
+                    section.to_lowercase()
+                ),
+            ),
+        })
+        .collect()
+}
+
+fn check_acceptance_criteria(spec: &str) -> Vec<LintFinding> {
+    let headings = headings(spec);
+    let criteria: Vec<&Heading> = headings
+        .iter()
+        .filter(|heading| {
+            let text = heading.text.to_lowercase();
+            CRITERIA_HEADERS
+                .iter()
+                .any(|keyword| text.contains(keyword))
+        })
+        .collect();
+    if criteria.is_empty() {
+        return vec![LintFinding {
+            rule: "missing_acceptance_criteria".to_string(),
+            severity: LintSeverity::Warning,
+            file: "spec.md".to_string(),
+            line: None,
+            message: "spec.md has no acceptance criteria section".to_string(),
+            fix: append_section(spec, "## Acceptance Criteria\n\n- <verifiable outcome>"),
+        }];
+    }
+
+    let lines: Vec<&str> = spec.lines().collect();
+    criteria
+        .into_iter()
+        .filter(|heading| {
+            // The section runs to the next heading at the same level or above
+            let end = headings
+                .iter()
+                .find(|next| next.index > heading.index && next.level <= heading.level)
+                .map_or(lines.len(), |next| next.index);
+            lines[heading.index + 1..end]
+                .iter()
+                .all(|line| line.trim().is_empty() || tasks::heading(line).is_some())
+        })
+        .map(|heading| LintFinding {
+            rule: "empty_acceptance_criteria".to_string(),
+            severity: LintSeverity::Error,
+            file: "spec.md".to_string(),
+            line: Some(heading.index + 1),
+            message: format!("'{}' lists no criteria", heading.line),
+            fix: unique_heading(&headings, heading).map_or_else(Vec::new, |header| {
+                vec![command(
+                    EditCommandTarget::Spec,
+                    EditCommandName::AppendToSection,
+                    EditSelector::Section { value: header },
+                    "- <verifiable outcome>",
+                )]
+            }),
+        })
+        .collect()
+}
+
+fn check_heading_hierarchy(file: &str, content: &str) -> Vec<LintFinding> {
+    let finding = |heading: &Heading, message: String| LintFinding {
+        rule: "heading_hierarchy".to_string(),
+        severity: LintSeverity::Warning,
+        file: file.to_string(),
+        line: Some(heading.index + 1),
+        message,
+        fix: Vec::new(),
+    };
+    let mut findings = Vec::new();
+    let mut previous: Option<usize> = None;
+    let mut seen_title = false;
+    for heading in headings(content) {
+        if heading.text.is_empty() {
+            findings.push(finding(
+                &heading,
+                format!("Heading '{}' has no text", heading.line),
+            ));
+        }
+        if heading.level == 1 {
+            if seen_title {
+                findings.push(finding(
+                    &heading,
+                    format!(
+                        "'{}' is a second '#' title; use '##' for sections",
+                        heading.line
+                    ),
+                ));
+            }
+            seen_title = true;
+        }
+        if let Some(previous) = previous
+            && heading.level > previous + 1
+        {
+            findings.push(finding(
+                &heading,
+                format!(
+                    "'{}' skips from level {} to level {}",
+                    heading.line, previous, heading.level
+                ),
+            ));
+        }
+        previous = Some(heading.level);
+    }
+    findings
+}
+
+fn check_checkbox_tasks(tasks_content: &str) -> Vec<LintFinding> {
+    outside_code_blocks(tasks_content)
+        .filter(|(_, line)| !line.starts_with([' ', '\t']))
+        .filter_map(|(index, line)| {
+            let (marker, text) = list_item(line)?;
+            if text.starts_with("[ ]") || text.to_lowercase().starts_with("[x]") {
+                return None;
+            }
+            // Bullets keep their marker; numbered items become a new task at the end
+            let fix = if marker == "- " || marker == "* " {
+                vec![command(
+                    EditCommandTarget::Tasks,
+                    EditCommandName::ReplaceListItem,
+                    task_selector(text),
+                    &format!("[ ] {}", text),
+                )]
+            } else {
+                vec![
+                    command(
+                        EditCommandTarget::Tasks,
+                        EditCommandName::RemoveListItem,
+                        task_selector(text),
+                        "",
+                    ),
+                    command(
+                        EditCommandTarget::Tasks,
+                        EditCommandName::UpsertTask,
+                        task_selector(text),
+                        &format!("- [ ] {}", text),
+                    ),
+                ]
+            };
+            Some(LintFinding {
+                rule: "non_checkbox_task".to_string(),
+                severity: LintSeverity::Error,
+                file: "task-list.md".to_string(),
+                line: Some(index + 1),
+                message: format!(
+                    "'{}' is not a '- [ ]' checkbox, so task tools cannot track it",
+                    line.trim()
+                ),
+                fix,
+            })
+        })
+        .collect()
+}
+
+fn check_duplicate_tasks(tasks_content: &str) -> Vec<LintFinding> {
+    let checkboxes: Vec<(usize, String)> = outside_code_blocks(tasks_content)
+        .filter_map(|(index, line)| {
+            let (_, text) = list_item(line.trim_start())?;
+            let text = text
+                .strip_prefix("[ ]")
+                .or_else(|| text.strip_prefix("[x]"))
+                .or_else(|| text.strip_prefix("[X]"))?;
+            Some((index, text.trim().to_string()))
+        })
+        .collect();
+    let mut texts: HashMap<&str, usize> = HashMap::new();
+    for (_, text) in &checkboxes {
+        *texts.entry(text.as_str()).or_default() += 1;
+    }
+
+    let mut first_lines: HashMap<String, usize> = HashMap::new();
+    let mut findings = Vec::new();
+    for (index, text) in &checkboxes {
+        let key = tasks::strip_metadata(text).to_lowercase();
+        let Some(first) = first_lines.get(&key) else {
+            first_lines.insert(key, *index);
+            continue;
+        };
+        // remove_list_item needs the exact text to match one item
+        let fix = if texts[text.as_str()] == 1 {
+            vec![command(
+                EditCommandTarget::Tasks,
+                EditCommandName::RemoveListItem,
+                task_selector(text),
+                "",
+            )]
+        } else {
+            Vec::new()
+        };
+        findings.push(LintFinding {
+            rule: "duplicate_task".to_string(),
+            severity: LintSeverity::Warning,
+            file: "task-list.md".to_string(),
+            line: Some(index + 1),
+            message: format!("Task '{}' duplicates line {}", text, first + 1),
+            fix,
+        });
+    }
+    findings
+}
+
+/// A markdown heading outside code blocks
+struct Heading {
+    /// 0-based line index
+    index: usize,
+    level: usize,
+    text: String,
+    /// The trimmed heading line, as section selectors expect it
+    line: String,
+}
+
+fn headings(content: &str) -> Vec<Heading> {
+    outside_code_blocks(content)
+        .filter_map(|(index, line)| {
+            let (level, text) = tasks::heading(line)?;
+            Some(Heading {
+                index,
+                level,
+                text: text.to_string(),
+                line: line.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Lines with their 0-based index, skipping fenced code blocks
+fn outside_code_blocks(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut in_fence = false;
+    content.lines().enumerate().filter(move |(_, line)| {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            return false;
+        }
+        !in_fence
+    })
+}
+
+/// Split a list item line into its marker (`- `, `* ` or `1. `) and text
+fn list_item(line: &str) -> Option<(&str, &str)> {
+    if let Some(text) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some((&line[..2], text.trim()));
+    }
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let text = line[digits..].strip_prefix(". ").filter(|_| digits > 0)?;
+    Some((&line[..digits + 2], text.trim()))
+}
+
+/// The heading's line when no other heading has the same text, so a section
+/// selector finds exactly it
+fn unique_heading(headings: &[Heading], heading: &Heading) -> Option<String> {
+    let same = headings
+        .iter()
+        .filter(|other| other.line.eq_ignore_ascii_case(&heading.line))
+        .count();
+    (same == 1).then(|| heading.line.clone())
+}
+
+/// A command appending `block` after the last section, when spec.md has one
+/// that a selector can name
+fn append_section(spec: &str, block: &str) -> Vec<EditCommand> {
+    let headings = headings(spec);
+    headings
+        .last()
+        .and_then(|last| unique_heading(&headings, last))
+        .map_or_else(Vec::new, |header| {
+            vec![command(
+                EditCommandTarget::Spec,
+                EditCommandName::AppendToSection,
+                EditSelector::Section { value: header },
+                block,
+            )]
+        })
+}
+
+fn task_selector(text: &str) -> EditSelector {
+    EditSelector::TaskText {
+        value: text.to_string(),
+        section_context: None,
+    }
+}
+
+fn command(
+    target: EditCommandTarget,
+    name: EditCommandName,
+    selector: EditSelector,
+    content: &str,
+) -> EditCommand {
+    EditCommand {
+        target,
+        command: name,
+        selector,
+        status: None,
+        content: (!content.is_empty()).then(|| content.to_string()),
+    }
+}
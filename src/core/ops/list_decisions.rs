@@ -0,0 +1,106 @@
+//! Core op for listing a project's decision log (tool-agnostic)
+
+use anyhow::Result;
+
+use crate::core::decisions::{self, Decision};
+use crate::core::foundry;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{DecisionInfo, FoundryResponse, ListDecisionsResponse};
+use crate::utils::formatting::format_count;
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    /// Only decisions with this status
+    pub status: Option<String>,
+    /// Only decisions made for this spec
+    pub spec_name: Option<String>,
+}
+
+#[tracing::instrument(name = "op.list_decisions", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<ListDecisionsResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    let status = input.status.as_deref().map(str::to_lowercase);
+    if let Some(status) = &status {
+        decisions::validate_status(status)?;
+    }
+    validate_project_exists(&foundry, &input.project_name).await?;
+
+    let project = foundry.load_project(&input.project_name).await?;
+    let all = decisions::parse(project.decisions.as_deref().unwrap_or_default());
+    let total_decisions = all.len();
+    let matching: Vec<DecisionInfo> = all
+        .into_iter()
+        .filter(|decision| {
+            status
+                .as_ref()
+                .is_none_or(|status| decision.status.as_ref() == Some(status))
+        })
+        .filter(|decision| {
+            input
+                .spec_name
+                .as_ref()
+                .is_none_or(|spec_name| decision.spec_name.as_ref() == Some(spec_name))
+        })
+        .map(decision_info)
+        .collect();
+
+    let next_steps = if total_decisions == 0 {
+        vec![format!(
+            "No decisions recorded yet; record one with mcp_foundry_add_decision {} <title>",
+            input.project_name
+        )]
+    } else {
+        vec![format!(
+            "{} of {} listed",
+            format_count(matching.len(), "decision", "decisions"),
+            total_decisions
+        )]
+    };
+
+    Ok(build_success_response(
+        ListDecisionsResponse {
+            project_name: input.project_name,
+            decisions: matching,
+            total_decisions,
+        },
+        next_steps,
+        decision_workflow_hints(),
+    ))
+}
+
+/// A parsed decision as reported by the decision tools
+pub(crate) fn decision_info(decision: Decision) -> DecisionInfo {
+    DecisionInfo {
+        id: decision.id,
+        title: decision.title,
+        date: decision.date,
+        status: decision.status,
+        spec_name: decision.spec_name,
+        context: decision.context,
+        decision: decision.decision,
+        consequences: decision.consequences,
+    }
+}
+
+pub(crate) fn decision_workflow_hints() -> Vec<String> {
+    vec![
+        "The decision log (decisions.md) is loaded with the project by load_project".to_string(),
+        "Supersede a decision by recording a new one and setting the old one's status with update_decisions".to_string(),
+    ]
+}
+
+pub(crate) async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
@@ -2,15 +2,24 @@
 
 use anyhow::{Context, Result};
 
-use crate::core::foundry;
+use crate::core::{foundry, tags};
 use crate::types::responses::{FoundryResponse, ListProjectsResponse, ProjectInfo};
 use crate::utils::formatting::format_count;
+use crate::utils::pagination;
 use crate::utils::response::build_success_response;
 
-#[derive(Debug, Clone)]
-pub struct Input;
+#[derive(Debug, Clone, Default)]
+pub struct Input {
+    /// Only list projects with every one of these tags
+    pub tags: Vec<String>,
+    /// `next_cursor` of the previous page
+    pub cursor: Option<String>,
+    /// Most projects to return; every project when not set
+    pub limit: Option<usize>,
+}
 
-pub async fn run(_input: Input) -> Result<FoundryResponse<ListProjectsResponse>> {
+#[tracing::instrument(name = "op.list_projects", skip_all)]
+pub async fn run(input: Input) -> Result<FoundryResponse<ListProjectsResponse>> {
     let foundry = foundry::get_default_foundry()?;
 
     let project_metadata_list = foundry
@@ -20,6 +29,7 @@ pub async fn run(_input: Input) -> Result<FoundryResponse<ListProjectsResponse>>
 
     let projects: Vec<ProjectInfo> = project_metadata_list
         .into_iter()
+        .filter(|metadata| tags::matches_all(&metadata.tags, &input.tags))
         .map(|metadata| {
             // Use the location_hint if available, otherwise use "Unknown"
             let project_path = format!("~/.foundry/{}", metadata.name);
@@ -29,11 +39,18 @@ pub async fn run(_input: Input) -> Result<FoundryResponse<ListProjectsResponse>>
                 created_at: metadata.created_at,
                 spec_count: metadata.spec_count,
                 path: project_path,
+                tags: metadata.tags,
             }
         })
         .collect();
 
-    let response_data = ListProjectsResponse { projects };
+    let total_count = projects.len();
+    let page = pagination::paginate(projects, input.cursor.as_deref(), input.limit)?;
+    let response_data = ListProjectsResponse {
+        projects: page.items,
+        total_count,
+        next_cursor: page.next_cursor,
+    };
 
     let (next_steps, workflow_hints) = if response_data.projects.is_empty() {
         (
@@ -48,13 +65,21 @@ pub async fn run(_input: Input) -> Result<FoundryResponse<ListProjectsResponse>>
             ],
         )
     } else {
-        let project_count = response_data.projects.len();
+        let mut next_steps = vec![
+            format_count(response_data.total_count, "project", "projects"),
+            "You can use 'mcp_foundry_create_spec <project_name> <feature_name>' to add specifications".to_string(),
+            "You can use 'mcp_foundry_load_spec <project_name>' to view existing specifications".to_string(),
+        ];
+        if let Some(cursor) = &response_data.next_cursor {
+            next_steps.push(format!(
+                "Showing {} of {}; pass cursor \"{}\" to list the next page",
+                response_data.projects.len(),
+                response_data.total_count,
+                cursor
+            ));
+        }
         (
-            vec![
-                format_count(project_count, "project", "projects"),
-                "You can use 'mcp_foundry_create_spec <project_name> <feature_name>' to add specifications".to_string(),
-                "You can use 'mcp_foundry_load_spec <project_name>' to view existing specifications".to_string(),
-            ],
+            next_steps,
             vec![
                 "Each project can contain multiple timestamped specifications for organized development".to_string(),
                 "You can use 'mcp_foundry_analyze_project' to add project analysis to existing codebases".to_string(),
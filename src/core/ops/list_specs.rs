@@ -1,42 +1,178 @@
 //! Core op for listing specs in a project (tool-agnostic)
 
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
+use chrono::Utc;
 
-use crate::core::foundry;
+use crate::core::ops::needs_attention;
+use crate::core::ops::project_analytics::{load_spec_tasks, stalled_spec};
+use crate::core::{dependencies, foundry, tags};
+use crate::types::errors::ErrorCode;
 use crate::types::responses::{FoundryResponse, ListSpecsResponse, SpecInfo};
+use crate::types::spec::{SpecMetadata, SpecStatus};
+use crate::utils::formatting::format_count;
+use crate::utils::pagination;
 use crate::utils::response::{build_incomplete_response, build_success_response};
 
 #[derive(Debug, Clone)]
 pub struct Input {
     pub project_name: String,
+    /// Status to list, or "all"; everything but archived specs when not set
+    pub status: Option<String>,
+    /// Only list specs that depend on this spec (name or close match)
+    pub blocked_by: Option<String>,
+    /// Only list specs with every one of these tags
+    pub tags: Vec<String>,
+    /// `next_cursor` of the previous page
+    pub cursor: Option<String>,
+    /// Most specs to return; every matching spec when not set
+    pub limit: Option<usize>,
+}
+
+/// Which specs `list_specs` returns
+enum StatusFilter {
+    Unarchived,
+    All,
+    Only(SpecStatus),
 }
 
+impl StatusFilter {
+    fn parse(status: Option<&str>) -> Result<Self> {
+        match status.map(str::trim) {
+            None | Some("") => Ok(Self::Unarchived),
+            Some(value) if value.eq_ignore_ascii_case("all") => Ok(Self::All),
+            Some(value) => Ok(Self::Only(value.parse()?)),
+        }
+    }
+
+    fn matches(&self, spec: &SpecMetadata) -> bool {
+        match self {
+            Self::Unarchived => spec.status != SpecStatus::Archived,
+            Self::All => true,
+            Self::Only(status) => spec.status == *status,
+        }
+    }
+}
+
+/// `spec_meta` as a list entry, its dependencies checked against `all_specs`
+pub(crate) fn spec_info(
+    spec_meta: &SpecMetadata,
+    all_specs: &[SpecMetadata],
+    days_idle: Option<u64>,
+) -> SpecInfo {
+    SpecInfo {
+        name: spec_meta.name.clone(),
+        feature_name: spec_meta.feature_name.clone(),
+        created_at: spec_meta.created_at.clone(),
+        status: spec_meta.status,
+        days_idle,
+        depends_on: spec_meta.depends_on.clone(),
+        blocked_by: dependencies::open_dependencies(spec_meta, all_specs),
+        tags: spec_meta.tags.clone(),
+        task_counts: spec_meta.task_counts,
+        updated_at: spec_meta.updated_at.clone(),
+        summary: spec_meta
+            .summary
+            .as_ref()
+            .map(|summary| summary.text.clone()),
+    }
+}
+
+#[tracing::instrument(name = "op.list_specs", skip_all, fields(project = %input.project_name))]
 pub async fn run(input: Input) -> Result<FoundryResponse<ListSpecsResponse>> {
     let foundry = foundry::get_default_foundry()?;
 
     validate_project_exists(&foundry, &input.project_name).await?;
+    let filter = StatusFilter::parse(input.status.as_deref())?;
 
-    let specs = foundry
+    let all_specs = foundry
         .list_specs(&input.project_name)
         .await
         .with_context(|| format!("Failed to list specs for project '{}'", input.project_name))?;
+    let archived_hidden = match filter {
+        StatusFilter::Unarchived => all_specs
+            .iter()
+            .filter(|spec| spec.status == SpecStatus::Archived)
+            .count(),
+        _ => 0,
+    };
+    let blocked_by = match input.blocked_by.as_deref() {
+        Some(query) => Some(
+            foundry
+                .find_spec_match(&input.project_name, query)
+                .await?
+                .into_spec_name(&input.project_name, query)?,
+        ),
+        None => None,
+    };
+    let specs: Vec<&SpecMetadata> = all_specs
+        .iter()
+        .filter(|spec| filter.matches(spec))
+        .filter(|spec| {
+            blocked_by
+                .as_ref()
+                .is_none_or(|blocker| spec.depends_on.contains(blocker))
+        })
+        .filter(|spec| tags::matches_all(&spec.tags, &input.tags))
+        .collect();
+
+    let today = Utc::now().date_naive();
+    let stale_days = needs_attention::stale_days(&input.project_name)?;
+    let days_idle: HashMap<String, u64> = load_spec_tasks(&foundry, &input.project_name)
+        .await?
+        .iter()
+        .filter_map(|spec| stalled_spec(spec, today, stale_days))
+        .map(|stalled| (stalled.spec_name, stalled.days_idle))
+        .collect();
 
     let spec_infos: Vec<SpecInfo> = specs
         .into_iter()
-        .map(|spec_meta| SpecInfo {
-            name: spec_meta.name,
-            feature_name: spec_meta.feature_name,
-            created_at: spec_meta.created_at,
+        .map(|spec_meta| {
+            spec_info(
+                spec_meta,
+                &all_specs,
+                days_idle.get(&spec_meta.name).copied(),
+            )
         })
         .collect();
 
+    let total_count = spec_infos.len();
+    let page = pagination::paginate(spec_infos, input.cursor.as_deref(), input.limit)?;
     let response_data = ListSpecsResponse {
         project_name: input.project_name.clone(),
-        specs: spec_infos.clone(),
-        total_count: spec_infos.len(),
+        specs: page.items,
+        total_count,
+        next_cursor: page.next_cursor,
     };
 
-    if response_data.specs.is_empty() {
+    if let Some(blocker) = &blocked_by
+        && response_data.specs.is_empty()
+    {
+        let next_steps = vec![format!("No specifications depend on '{}'", blocker)];
+
+        Ok(build_success_response(response_data, next_steps, vec![]))
+    } else if response_data.specs.is_empty() && !input.tags.is_empty() {
+        let next_steps = vec![format!(
+            "No specifications tagged {}",
+            input.tags.join(", ")
+        )];
+
+        Ok(build_success_response(response_data, next_steps, vec![]))
+    } else if response_data.specs.is_empty() && input.status.is_some() {
+        let next_steps = vec![
+            format!(
+                "No specifications with status '{}' in this project",
+                input.status.as_deref().unwrap_or_default()
+            ),
+            format!(
+                "You can list every spec with: {{\"name\": \"list_specs\", \"arguments\": {{\"project_name\": \"{}\", \"status\": \"all\"}}}}",
+                input.project_name
+            ),
+        ];
+
+        Ok(build_success_response(response_data, next_steps, vec![]))
+    } else if response_data.specs.is_empty() && archived_hidden == 0 {
         let next_steps = vec![
             "No specifications found for this project - ready for specification creation"
                 .to_string(),
@@ -59,7 +195,7 @@ pub async fn run(input: Input) -> Result<FoundryResponse<ListSpecsResponse>> {
             workflow_hints,
         ))
     } else {
-        let spec_count = response_data.specs.len();
+        let spec_count = response_data.total_count;
         let mut next_steps = vec![
             format!("Found {} specification(s) in project", spec_count),
             format!(
@@ -68,10 +204,21 @@ pub async fn run(input: Input) -> Result<FoundryResponse<ListSpecsResponse>> {
             ),
         ];
 
-        if spec_count <= 5 {
+        if let Some(cursor) = &response_data.next_cursor {
+            next_steps.push(format!(
+                "Showing {} of {}; pass cursor \"{}\" to list the next page",
+                response_data.specs.len(),
+                spec_count,
+                cursor
+            ));
+        }
+        if response_data.specs.len() <= 5 {
             next_steps.push("Available specs:".to_string());
             for spec in &response_data.specs {
-                next_steps.push(format!("  - {} ({})", spec.name, spec.feature_name));
+                next_steps.push(format!(
+                    "  - {} ({}, {})",
+                    spec.name, spec.feature_name, spec.status
+                ));
             }
         }
 
@@ -79,11 +226,32 @@ pub async fn run(input: Input) -> Result<FoundryResponse<ListSpecsResponse>> {
             "You can create a new spec: mcp_foundry_create_spec {} <feature_name>",
             input.project_name
         ));
+        if archived_hidden > 0 {
+            next_steps.push(format!(
+                "{} hidden; pass status \"archived\" or \"all\" to see them",
+                format_count(archived_hidden, "archived spec", "archived specs")
+            ));
+        }
+        if let Some(blocker) = &blocked_by {
+            next_steps.push(format!(
+                "Listing only specs that depend on '{}'; see the whole graph with mcp_foundry_spec_graph",
+                blocker
+            ));
+        }
+        if !days_idle.is_empty() {
+            next_steps.push(format!(
+                "{} open tasks and no activity for {}+ days (see 'days_idle'); review them with mcp_foundry_needs_attention",
+                format_count(days_idle.len(), "spec has", "specs have"),
+                stale_days
+            ));
+        }
 
         let workflow_hints = vec![
             "Specifications are timestamped and organized by feature for easy navigation"
                 .to_string(),
             format!("Total specs: {}", spec_count),
+            "Specs move through draft → active → completed → archived; change it with mcp_foundry_set_spec_status or mcp_foundry_archive_spec".to_string(),
+            "'blocked_by' lists dependencies that are not completed yet; specs without it are ready to start".to_string(),
             "You can load individual specs to see detailed implementation plans".to_string(),
             "Specs include specification content, notes, and task lists for complete context"
                 .to_string(),
@@ -98,14 +266,14 @@ pub async fn run(input: Input) -> Result<FoundryResponse<ListSpecsResponse>> {
 }
 
 async fn validate_project_exists(
-    foundry: &foundry::Foundry<crate::core::backends::filesystem::FilesystemBackend>,
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
     project_name: &str,
 ) -> Result<()> {
     if !foundry.project_exists(project_name).await? {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ProjectNotFound.error(format!(
             "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
             project_name
-        ));
+        )));
     }
     Ok(())
 }
@@ -2,31 +2,121 @@
 
 use anyhow::Result;
 
+use crate::core::context_budget::{self, BudgetSection};
 use crate::core::foundry;
+use crate::core::ops::list_specs;
+use crate::types::errors::ErrorCode;
 use crate::types::responses::{
-    FoundryResponse, LoadProjectResponse, ProjectContext, ValidationStatus,
+    ContextBudget, FoundryResponse, LoadProjectResponse, ProjectContext, SpecInfo, ValidationStatus,
 };
+use crate::types::spec::SpecMetadata;
+use crate::utils::formatting::format_count;
 
 #[derive(Debug, Clone)]
 pub struct Input {
     pub project_name: String,
+    /// Trim the summary, vision, tech stack and decision log to about this many tokens
+    pub max_tokens: Option<usize>,
+    /// Parts of the project to return (each one of [`INCLUDES`]); every
+    /// document plus the active specs when omitted
+    pub include: Option<Vec<String>>,
+    /// Most active specs returned (default [`DEFAULT_ACTIVE_LIMIT`])
+    pub active_limit: Option<usize>,
 }
 
+/// Parts of a project that can be requested with `include`
+pub const INCLUDES: &[&str] = &[
+    "summary",
+    "vision",
+    "tech_stack",
+    "decisions",
+    "active_specs",
+    "all_specs",
+];
+
+/// Parts returned when `include` is omitted
+const DEFAULT_INCLUDES: &[&str] = &[
+    "summary",
+    "vision",
+    "tech_stack",
+    "decisions",
+    "active_specs",
+];
+
+/// Active specs returned when `active_limit` is omitted
+pub const DEFAULT_ACTIVE_LIMIT: usize = 5;
+
+/// Documents competing for a `max_tokens` budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Document {
+    Summary,
+    Vision,
+    TechStack,
+    Decisions,
+}
+
+#[tracing::instrument(name = "op.load_project", skip_all, fields(project = %input.project_name))]
 pub async fn run(input: Input) -> Result<FoundryResponse<LoadProjectResponse>> {
     let foundry = foundry::get_default_foundry()?;
 
+    context_budget::validate_max_tokens(input.max_tokens)?;
+    let include = resolve_include(&input)?;
     validate_project_exists(&foundry, &input.project_name).await?;
 
     let project = foundry.load_project(&input.project_name).await?;
     let specs = foundry.list_specs(&input.project_name).await?;
 
-    let project_context = build_project_context(project, specs);
+    let active_limit = input.active_limit.unwrap_or(DEFAULT_ACTIVE_LIMIT);
+    let active_specs = if include.contains(&"active_specs") {
+        active_specs(&specs, active_limit)
+    } else {
+        Vec::new()
+    };
+    let all_specs = if include.contains(&"all_specs") {
+        specs
+            .iter()
+            .map(|spec_meta| list_specs::spec_info(spec_meta, &specs, None))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut project_context = build_project_context(project, specs, &include);
+    let budget = input
+        .max_tokens
+        .map(|max_tokens| fit_project_context(&mut project_context, max_tokens));
     let specs_available = project_context.specs_available.clone();
 
     let response_data = LoadProjectResponse {
         project: project_context,
+        budget,
+        include: include.iter().map(|part| part.to_string()).collect(),
+        active_specs,
+        specs: all_specs,
     };
 
+    let mut next_steps = generate_next_steps(&input.project_name, &specs_available);
+    let shown = response_data
+        .active_specs
+        .len()
+        .max(response_data.specs.len());
+    if shown < specs_available.len() {
+        next_steps.push(format!(
+            "{} not shown; pass include=[\"all_specs\"] or use mcp_foundry_list_specs to see every spec",
+            format_count(specs_available.len() - shown, "other spec is", "other specs are")
+        ));
+    }
+    if let Some(budget) = &response_data.budget
+        && (!budget.omitted_sections.is_empty() || budget.truncated)
+    {
+        next_steps.push(format!(
+            "Context was trimmed to ~{} of {} tokens ({} left out); load without 'max_tokens' for the full documents",
+            budget.estimated_tokens,
+            budget.max_tokens,
+            format_count(budget.omitted_sections.len(), "section", "sections")
+        ));
+    }
+
     let validation_status = if specs_available.is_empty() {
         ValidationStatus::Incomplete
     } else {
@@ -35,41 +125,138 @@ pub async fn run(input: Input) -> Result<FoundryResponse<LoadProjectResponse>> {
 
     Ok(FoundryResponse {
         data: response_data,
-        next_steps: generate_next_steps(&input.project_name, &specs_available),
+        next_steps,
         validation_status,
         workflow_hints: generate_workflow_hints(&specs_available),
+        diagnostics: None,
     })
 }
 
 async fn validate_project_exists(
-    foundry: &foundry::Foundry<crate::core::backends::filesystem::FilesystemBackend>,
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
     project_name: &str,
 ) -> Result<()> {
     if !foundry.project_exists(project_name).await? {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ProjectNotFound.error(format!(
             "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
             project_name
-        ));
+        )));
     }
     Ok(())
 }
 
+/// The requested parts in [`INCLUDES`] order, or the defaults
+fn resolve_include(input: &Input) -> Result<Vec<&'static str>> {
+    if input.active_limit == Some(0) {
+        return Err(ErrorCode::InvalidParams.error("active_limit must be at least 1"));
+    }
+    let Some(requested) = &input.include else {
+        return Ok(DEFAULT_INCLUDES.to_vec());
+    };
+    if let Some(unknown) = requested
+        .iter()
+        .find(|part| !INCLUDES.contains(&part.as_str()))
+    {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Unknown include '{}'; use any of: {}",
+            unknown,
+            INCLUDES.join(", ")
+        )));
+    }
+    Ok(INCLUDES
+        .iter()
+        .copied()
+        .filter(|part| requested.iter().any(|requested| requested == part))
+        .collect())
+}
+
+/// The `limit` most recently modified specs that still have work left
+fn active_specs(specs: &[SpecMetadata], limit: usize) -> Vec<SpecInfo> {
+    let mut active: Vec<&SpecMetadata> = specs
+        .iter()
+        .filter(|spec| {
+            let all_done = spec.task_counts.total > 0 && spec.task_counts.remaining == 0;
+            !(spec.status.is_closed() || all_done)
+        })
+        .collect();
+    active.sort_by(|a, b| last_modified(b).cmp(last_modified(a)));
+    active
+        .into_iter()
+        .take(limit)
+        .map(|spec_meta| list_specs::spec_info(spec_meta, specs, None))
+        .collect()
+}
+
+fn last_modified(spec: &SpecMetadata) -> &str {
+    spec.updated_at.as_deref().unwrap_or(&spec.created_at)
+}
+
+/// Project context with documents outside `include` left empty
 fn build_project_context(
     project: crate::types::project::Project,
-    specs: Vec<crate::types::spec::SpecMetadata>,
+    specs: Vec<SpecMetadata>,
+    include: &[&str],
 ) -> ProjectContext {
     let specs_available = specs.into_iter().map(|s| s.name).collect();
+    let document = |part: &str, text: Option<String>| {
+        if include.contains(&part) {
+            text.unwrap_or_default()
+        } else {
+            String::new()
+        }
+    };
 
     ProjectContext {
+        vision: document("vision", project.vision),
+        tech_stack: document("tech_stack", project.tech_stack),
+        summary: document("summary", project.summary),
+        decisions: document("decisions", project.decisions),
         name: project.name,
-        vision: project.vision.unwrap_or_default(),
-        tech_stack: project.tech_stack.unwrap_or_default(),
-        summary: project.summary.unwrap_or_default(),
         specs_available,
         created_at: project.created_at,
     }
 }
 
+/// Trim the summary, vision, tech stack and decision log in place to `max_tokens`
+///
+/// The summary comes first, then vision sections, tech stack sections and
+/// decisions, each in document order.
+fn fit_project_context(context: &mut ProjectContext, max_tokens: usize) -> ContextBudget {
+    let mut sections = Vec::new();
+    if !context.summary.trim().is_empty() {
+        sections.push(BudgetSection {
+            document: Document::Summary,
+            position: 0,
+            title: "Project summary".to_string(),
+            text: std::mem::take(&mut context.summary),
+        });
+    }
+    for (document, label, text) in [
+        (Document::Vision, "Vision", &context.vision),
+        (Document::TechStack, "Tech stack", &context.tech_stack),
+        (Document::Decisions, "Decisions", &context.decisions),
+    ] {
+        sections.extend(
+            context_budget::split_sections(text)
+                .into_iter()
+                .enumerate()
+                .map(|(position, (title, text))| BudgetSection {
+                    document,
+                    position,
+                    title: format!("{}: {}", label, title),
+                    text,
+                }),
+        );
+    }
+
+    let fitted = context_budget::fit(sections, max_tokens);
+    context.summary = fitted.document(&Document::Summary);
+    context.vision = fitted.document(&Document::Vision);
+    context.tech_stack = fitted.document(&Document::TechStack);
+    context.decisions = fitted.document(&Document::Decisions);
+    fitted.report()
+}
+
 fn generate_next_steps(project_name: &str, specs_available: &[String]) -> Vec<String> {
     if specs_available.is_empty() {
         vec![
@@ -124,6 +311,10 @@ fn generate_workflow_hints(specs_available: &[String]) -> Vec<String> {
         hints.push("You can update existing specs with progress as work continues".to_string());
     }
 
+    hints.push(
+        "Record architectural decisions with mcp_foundry_add_decision; the decision log is loaded with the project"
+            .to_string(),
+    );
     hints.push(
         "You can use mcp_foundry_get_foundry_help decision-points to understand tool selection"
             .to_string(),
@@ -2,20 +2,85 @@
 
 use anyhow::{Context, Result};
 
-use crate::core::{foundry, spec};
+use crate::core::context_budget::{self, BudgetSection};
+use crate::core::ops::list_specs;
+use crate::core::tasks::{parse_task_list, phase_stats};
+use crate::core::{foundry, links, spec_name, versioning};
+use crate::types::errors::ErrorCode;
 use crate::types::responses::{
-    FoundryResponse, LoadSpecResponse, SpecContent, SpecInfo, ValidationStatus,
+    ContextBudget, FoundryResponse, LoadSpecResponse, MatchInfo, PhaseProgress, SpecCandidate,
+    SpecContent, SpecFileVersions, SpecInfo, SpecPart, TaskInfo, ValidationStatus, WikiLinkInfo,
 };
+use crate::types::spec::{SpecContentData, SpecFileType, SpecMetadata};
+use crate::utils::formatting::{estimate_tokens, format_count};
+use crate::utils::timestamp;
 
 #[derive(Debug, Clone)]
 pub struct Input {
     pub project_name: String,
     pub spec_name: Option<String>,
+    /// Trim the summary and spec files to about this many tokens
+    pub max_tokens: Option<usize>,
+    /// Return only this part of the spec (one of [`PARTS`])
+    pub part: Option<String>,
+    /// Return only these spec files (any of [`FILES`]), without the project summary
+    pub files: Option<Vec<String>>,
 }
 
+/// Parts of a spec that can be loaded one at a time with `part`
+pub const PARTS: &[&str] = &["summary", "spec", "tasks", "notes"];
+
+/// Spec files that can be loaded in any combination with `files`
+pub const FILES: &[&str] = &["spec", "tasks", "notes"];
+
+/// Estimated size above which loading the spec in parts is suggested
+const LARGE_SPEC_TOKENS: usize = 8_000;
+
+/// Levenshtein similarity a fuzzy match needs to be considered
+const FUZZY_THRESHOLD: f32 = 0.8;
+
+/// Lead the best fuzzy match needs over the runner-up to be loaded directly
+const CONFIDENT_MARGIN: f32 = 0.1;
+
+/// Similarity a spec needs to be suggested when nothing matched
+const SUGGESTION_THRESHOLD: f32 = 0.5;
+
+/// Most candidates listed for disambiguation
+const MAX_CANDIDATES: usize = 10;
+
+/// How a requested `spec_name` resolved against the project's specs
+#[derive(Debug)]
+enum Resolution {
+    /// One spec matched confidently
+    Match(SpecCandidate),
+    /// Several specs match about equally well, best first
+    Ambiguous(Vec<SpecCandidate>),
+    /// Nothing matched; the closest specs, if any, best first
+    NotFound(Vec<SpecCandidate>),
+}
+
+/// Match type, its confidence and whether a spec satisfies it
+type MatchRule<'a> = (&'static str, f32, Box<dyn Fn(&SpecMetadata) -> bool + 'a>);
+
+/// Documents competing for a `max_tokens` budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Document {
+    Summary,
+    Spec,
+    Notes,
+    Tasks,
+}
+
+#[tracing::instrument(name = "op.load_spec", skip_all, fields(project = %input.project_name))]
 pub async fn run(input: Input) -> Result<FoundryResponse<LoadSpecResponse>> {
     let foundry = foundry::get_default_foundry()?;
 
+    context_budget::validate_max_tokens(input.max_tokens)?;
+    validate_part(&input)?;
+    let files = resolve_files(&input)?;
+    let file_types: Option<Vec<SpecFileType>> = files
+        .as_ref()
+        .map(|files| files.iter().map(|file| file_type(file)).collect());
     validate_project_exists(&foundry, &input.project_name).await?;
 
     let project_summary = load_project_summary(&foundry, &input.project_name).await?;
@@ -24,14 +89,12 @@ pub async fn run(input: Input) -> Result<FoundryResponse<LoadSpecResponse>> {
         None => {
             let specs = foundry.list_specs(&input.project_name).await?;
             let available_specs: Vec<SpecInfo> = specs
-                .into_iter()
-                .map(|spec_meta| SpecInfo {
-                    name: spec_meta.name,
-                    feature_name: spec_meta.feature_name,
-                    created_at: spec_meta.created_at,
-                })
+                .iter()
+                .map(|spec_meta| list_specs::spec_info(spec_meta, &specs, None))
                 .collect();
 
+            let (project_summary, budget) = fit_summary(project_summary, input.max_tokens);
+
             let response_data = LoadSpecResponse {
                 project_name: input.project_name.clone(),
                 project_summary,
@@ -40,6 +103,15 @@ pub async fn run(input: Input) -> Result<FoundryResponse<LoadSpecResponse>> {
                 spec_content: None,
                 available_specs: available_specs.clone(),
                 match_info: None,
+                links: Vec::new(),
+                backlinks: Vec::new(),
+                budget,
+                candidates: Vec::new(),
+                tasks: Vec::new(),
+                phases: Vec::new(),
+                part: None,
+                files: Vec::new(),
+                parts: Vec::new(),
             };
 
             Ok(FoundryResponse {
@@ -51,82 +123,82 @@ pub async fn run(input: Input) -> Result<FoundryResponse<LoadSpecResponse>> {
                     ValidationStatus::Complete
                 },
                 workflow_hints: generate_listing_workflow_hints(&available_specs),
+                diagnostics: None,
             })
         }
         Some(spec_name) => {
-            let match_strategy = foundry
-                .find_spec_match(&input.project_name, spec_name)
-                .await?;
-
-            let (spec_data, match_strategy) = match match_strategy {
-                spec::SpecMatchStrategy::None => {
-                    return Err(anyhow::anyhow!(
-                        "No spec found matching '{}' in project '{}'",
+            let all_specs = foundry.list_specs(&input.project_name).await?;
+            let matched = match resolve_spec(spec_name, &all_specs) {
+                Resolution::Match(matched) => matched,
+                Resolution::Ambiguous(candidates) => {
+                    return Ok(build_disambiguation_response(
+                        &input,
                         spec_name,
-                        input.project_name
+                        project_summary,
+                        candidates,
                     ));
                 }
-                spec::SpecMatchStrategy::Multiple(candidates) => {
-                    return Err(anyhow::anyhow!(
-                        "Multiple specs match '{}': {}. Please be more specific.",
-                        spec_name,
-                        candidates.join(", ")
-                    ));
-                }
-                spec::SpecMatchStrategy::Exact(actual_name) => {
-                    let spec_data = foundry
-                        .load_spec(&input.project_name, &actual_name)
-                        .await
-                        .with_context(|| format!("Failed to load spec '{}'", actual_name))?;
-                    (spec_data, spec::SpecMatchStrategy::Exact(actual_name))
-                }
-                spec::SpecMatchStrategy::FeatureExact(actual_name) => {
-                    let spec_data = foundry
-                        .load_spec(&input.project_name, &actual_name)
-                        .await
-                        .with_context(|| format!("Failed to load spec '{}'", actual_name))?;
-                    (
-                        spec_data,
-                        spec::SpecMatchStrategy::FeatureExact(actual_name),
-                    )
-                }
-                spec::SpecMatchStrategy::FeatureFuzzy(actual_name) => {
-                    let spec_data = foundry
-                        .load_spec(&input.project_name, &actual_name)
-                        .await
-                        .with_context(|| format!("Failed to load spec '{}'", actual_name))?;
-                    (
-                        spec_data,
-                        spec::SpecMatchStrategy::FeatureFuzzy(actual_name),
-                    )
-                }
-                spec::SpecMatchStrategy::NameFuzzy(actual_name) => {
-                    let spec_data = foundry
-                        .load_spec(&input.project_name, &actual_name)
-                        .await
-                        .with_context(|| format!("Failed to load spec '{}'", actual_name))?;
-                    (spec_data, spec::SpecMatchStrategy::NameFuzzy(actual_name))
+                Resolution::NotFound(suggestions) => {
+                    let mut message = format!(
+                        "No spec found matching '{}' in project '{}'",
+                        spec_name, input.project_name
+                    );
+                    if !suggestions.is_empty() {
+                        let names: Vec<&str> = suggestions
+                            .iter()
+                            .map(|candidate| candidate.spec_name.as_str())
+                            .collect();
+                        message.push_str(&format!(". Closest specs: {}", names.join(", ")));
+                    }
+                    return Err(ErrorCode::SpecNotFound.error(message));
                 }
             };
+            let spec_data = foundry
+                .load_spec_files(
+                    &input.project_name,
+                    &matched.spec_name,
+                    file_types.as_deref().unwrap_or(SpecFileType::ALL),
+                )
+                .await
+                .with_context(|| format!("Failed to load spec '{}'", matched.spec_name))?;
 
-            let spec_content = SpecContent {
-                content: spec_data.content,
+            // Versions always describe the stored files, even when the content is trimmed
+            let versions = loaded_versions(&spec_data.content, file_types.as_deref());
+            let mut parts = spec_parts(&project_summary, &spec_data.content);
+            if let Some(files) = &files {
+                parts.retain(|part| files.contains(&part.name.as_str()));
+            }
+            let (project_summary, content) = select_part(
+                input.part.as_deref(),
+                project_summary,
+                spec_data.content.clone(),
+            );
+            let project_summary = if files.is_some() {
+                String::new()
+            } else {
+                project_summary
             };
-
-            let match_info = match match_strategy {
-                spec::SpecMatchStrategy::Exact(_) => None,
-                _ => Some(crate::types::responses::MatchInfo {
-                    requested_spec: spec_name.clone(),
-                    matched_spec: spec_data.name.clone(),
-                    match_type: match match_strategy {
-                        spec::SpecMatchStrategy::FeatureExact(_) => "feature_exact".to_string(),
-                        spec::SpecMatchStrategy::FeatureFuzzy(_) => "feature_fuzzy".to_string(),
-                        spec::SpecMatchStrategy::NameFuzzy(_) => "name_fuzzy".to_string(),
-                        _ => "exact".to_string(),
-                    },
-                    confidence: 1.0,
-                }),
+            let spec_links = links::collect_spec_links(&content, &all_specs);
+            let backlinks = foundry
+                .backlinks(&input.project_name, &spec_data.name, &all_specs)
+                .await?;
+            let (project_summary, content, budget) = match input.max_tokens {
+                Some(max_tokens) => {
+                    let (project_summary, content, budget) =
+                        fit_spec_context(project_summary, &content, max_tokens);
+                    (project_summary, content, Some(budget))
+                }
+                None => (project_summary, content, None),
             };
+            let (tasks, phases) = task_infos(&content.tasks);
+            let spec_content = SpecContent { versions, content };
+
+            let match_info = (matched.match_type != "exact").then(|| MatchInfo {
+                requested_spec: spec_name.clone(),
+                matched_spec: spec_data.name.clone(),
+                match_type: matched.match_type,
+                confidence: matched.confidence,
+            });
 
             let response_data = LoadSpecResponse {
                 project_name: input.project_name.clone(),
@@ -136,33 +208,287 @@ pub async fn run(input: Input) -> Result<FoundryResponse<LoadSpecResponse>> {
                 spec_content: Some(spec_content),
                 available_specs: Vec::new(),
                 match_info,
+                links: spec_links,
+                backlinks,
+                budget,
+                candidates: Vec::new(),
+                tasks,
+                phases,
+                part: input.part.clone(),
+                files: files
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+                parts,
             };
 
+            let mut next_steps = generate_spec_next_steps(&input.project_name, &spec_data.name);
+            next_steps.extend(generate_budget_next_steps(response_data.budget.as_ref()));
+            next_steps.extend(generate_part_next_steps(&response_data));
+            let mut workflow_hints = generate_spec_workflow_hints(&spec_data.name);
+            workflow_hints.extend(generate_link_hints(&response_data.links));
+
             Ok(FoundryResponse {
                 data: response_data,
-                next_steps: generate_spec_next_steps(&input.project_name, &spec_data.name),
+                next_steps,
                 validation_status: ValidationStatus::Complete,
-                workflow_hints: generate_spec_workflow_hints(&spec_data.name),
+                workflow_hints,
+                diagnostics: None,
             })
         }
     }
 }
 
+/// Resolve `query` to a spec, trying in order: the exact spec name, the feature
+/// name, a date prefix of the spec name (`20250917`, `2025-09-17`,
+/// `20250917_1430`), a feature name substring and finally Levenshtein
+/// similarity to the feature or spec name
+///
+/// The first rule that matches anything decides: one spec is a match, several
+/// are returned as candidates for the user to choose from.
+fn resolve_spec(query: &str, specs: &[SpecMetadata]) -> Resolution {
+    let query = query.trim();
+    let normalized = normalize_name(query);
+    let date = date_prefix(query);
+    let id = id_query(query);
+
+    if let Some(spec) = specs.iter().find(|spec| spec.name == query) {
+        return Resolution::Match(candidate(spec, "exact", 1.0));
+    }
+
+    let rules: [MatchRule; 4] = [
+        (
+            "feature_exact",
+            1.0,
+            Box::new(|spec| normalize_name(&spec.feature_name) == normalized),
+        ),
+        (
+            "id_exact",
+            1.0,
+            Box::new(|spec| {
+                id.as_ref().is_some_and(|(prefix, number)| {
+                    spec_name::parse(&spec.name).is_some_and(|parsed| {
+                        parsed.number() == Some(*number)
+                            && parsed.prefix().map(str::to_ascii_uppercase) == *prefix
+                    })
+                })
+            }),
+        ),
+        (
+            "date_prefix",
+            0.95,
+            Box::new(|spec| {
+                date.as_ref().is_some_and(|prefix| {
+                    spec.name.starts_with(prefix.as_str())
+                        || timestamp::iso_to_spec_timestamp(&spec.created_at)
+                            .is_ok_and(|stamp| stamp.starts_with(prefix.as_str()))
+                })
+            }),
+        ),
+        (
+            "feature_substring",
+            0.9,
+            Box::new(|spec| {
+                !normalized.is_empty() && normalize_name(&spec.feature_name).contains(&normalized)
+            }),
+        ),
+    ];
+    for (match_type, confidence, matches) in rules {
+        let matched: Vec<SpecCandidate> = specs
+            .iter()
+            .filter(|spec| matches(spec))
+            .map(|spec| candidate(spec, match_type, confidence))
+            .collect();
+        match matched.len() {
+            0 => continue,
+            1 => return Resolution::Match(matched.into_iter().next().unwrap()),
+            _ => return Resolution::Ambiguous(newest_first(matched)),
+        }
+    }
+
+    let mut scored: Vec<SpecCandidate> = specs
+        .iter()
+        .map(|spec| {
+            let feature =
+                strsim::normalized_levenshtein(&normalized, &normalize_name(&spec.feature_name))
+                    as f32;
+            let name = strsim::normalized_levenshtein(query, &spec.name) as f32;
+            if feature >= name {
+                candidate(spec, "feature_fuzzy", feature)
+            } else {
+                candidate(spec, "name_fuzzy", name)
+            }
+        })
+        .collect();
+    scored.sort_by(|a, b| {
+        b.confidence
+            .total_cmp(&a.confidence)
+            .then_with(|| b.spec_name.cmp(&a.spec_name))
+    });
+
+    let close: Vec<SpecCandidate> = scored
+        .iter()
+        .filter(|candidate| candidate.confidence > FUZZY_THRESHOLD)
+        .cloned()
+        .collect();
+    match close.as_slice() {
+        [] => Resolution::NotFound(
+            scored
+                .into_iter()
+                .filter(|candidate| candidate.confidence >= SUGGESTION_THRESHOLD)
+                .take(3)
+                .map(rounded)
+                .collect(),
+        ),
+        [best] => Resolution::Match(rounded(best.clone())),
+        [best, runner_up, ..] if best.confidence - runner_up.confidence >= CONFIDENT_MARGIN => {
+            Resolution::Match(rounded(best.clone()))
+        }
+        _ => Resolution::Ambiguous(
+            close
+                .into_iter()
+                .take(MAX_CANDIDATES)
+                .map(rounded)
+                .collect(),
+        ),
+    }
+}
+
+fn candidate(spec: &SpecMetadata, match_type: &str, confidence: f32) -> SpecCandidate {
+    SpecCandidate {
+        spec_name: spec.name.clone(),
+        feature_name: spec.feature_name.clone(),
+        created_at: spec.created_at.clone(),
+        status: spec.status,
+        match_type: match_type.to_string(),
+        confidence,
+    }
+}
+
+/// Round the confidence to two decimals for display
+fn rounded(mut candidate: SpecCandidate) -> SpecCandidate {
+    candidate.confidence = (candidate.confidence * 100.0).round() / 100.0;
+    candidate
+}
+
+/// Equally good candidates, most recent spec first
+fn newest_first(mut candidates: Vec<SpecCandidate>) -> Vec<SpecCandidate> {
+    candidates.sort_by(|a, b| b.spec_name.cmp(&a.spec_name));
+    candidates.truncate(MAX_CANDIDATES);
+    candidates
+}
+
+/// Lowercase with spaces and dashes as underscores, so "User Auth" and
+/// "user-auth" both compare equal to the feature name "user_auth"
+pub(crate) fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+/// The spec name prefix a date query stands for, or `None` when `query` is not
+/// a date
+///
+/// Accepts a full date with optional time (`20250917`, `2025-09-17`,
+/// `20250917_1430`, `2025-09-17 14:30`).
+fn date_prefix(query: &str) -> Option<String> {
+    let prefix: String = query
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, '-' | ':'))
+        .map(|c| if c == ' ' || c == 'T' { '_' } else { c })
+        .collect();
+    let (date, time) = prefix.split_at_checked(8)?;
+    let valid_time =
+        time.is_empty() || (time.starts_with('_') && time[1..].chars().all(|c| c.is_ascii_digit()));
+    (date.chars().all(|c| c.is_ascii_digit()) && valid_time).then_some(prefix)
+}
+
+/// Number, and upper-cased prefix, of a query naming a numbered spec id
+/// (`3`, `0003`, `rfc-12`)
+fn id_query(query: &str) -> Option<(Option<String>, u64)> {
+    let (prefix, number) = query
+        .rsplit_once('-')
+        .map_or((None, query), |(prefix, number)| (Some(prefix), number));
+    if prefix.is_some_and(|prefix| !spec_name::valid_prefix(prefix))
+        || number.is_empty()
+        || !number.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((prefix.map(str::to_ascii_uppercase), number.parse().ok()?))
+}
+
+/// Response listing the specs `spec_name` could refer to, without loading any
+fn build_disambiguation_response(
+    input: &Input,
+    spec_name: &str,
+    project_summary: String,
+    candidates: Vec<SpecCandidate>,
+) -> FoundryResponse<LoadSpecResponse> {
+    let (project_summary, budget) = fit_summary(project_summary, input.max_tokens);
+
+    let mut next_steps = vec![format!(
+        "'{}' matches {}; ask the user which one they meant before loading",
+        spec_name,
+        format_count(candidates.len(), "spec", "specs")
+    )];
+    for candidate in &candidates {
+        next_steps.push(format!(
+            "  - {} ({}, created {}): {{\"name\": \"load_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            candidate.spec_name,
+            candidate.feature_name,
+            candidate.created_at,
+            input.project_name,
+            candidate.spec_name
+        ));
+    }
+    let workflow_hints = vec![
+        "Candidates are ordered by match confidence; load the chosen one by its exact spec_name"
+            .to_string(),
+        "A date (e.g. 20250917) or the full feature name narrows the match".to_string(),
+    ];
+
+    FoundryResponse {
+        data: LoadSpecResponse {
+            project_name: input.project_name.clone(),
+            project_summary,
+            spec_name: None,
+            created_at: None,
+            spec_content: None,
+            available_specs: Vec::new(),
+            match_info: None,
+            links: Vec::new(),
+            backlinks: Vec::new(),
+            budget,
+            candidates,
+            tasks: Vec::new(),
+            phases: Vec::new(),
+            part: None,
+            files: Vec::new(),
+            parts: Vec::new(),
+        },
+        next_steps,
+        validation_status: ValidationStatus::Incomplete,
+        workflow_hints,
+        diagnostics: None,
+    }
+}
+
 async fn validate_project_exists(
-    foundry: &foundry::Foundry<crate::core::backends::filesystem::FilesystemBackend>,
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
     project_name: &str,
 ) -> Result<()> {
     if !foundry.project_exists(project_name).await? {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ProjectNotFound.error(format!(
             "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
             project_name
-        ));
+        )));
     }
     Ok(())
 }
 
 async fn load_project_summary(
-    foundry: &foundry::Foundry<crate::core::backends::filesystem::FilesystemBackend>,
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
     project_name: &str,
 ) -> Result<String> {
     let project = foundry.load_project(project_name).await?;
@@ -172,6 +498,304 @@ async fn load_project_summary(
     }))
 }
 
+/// Structured tasks, with metadata, and per-phase completion from task-list content
+fn task_infos(tasks_content: &str) -> (Vec<TaskInfo>, Vec<PhaseProgress>) {
+    let tasks = parse_task_list(tasks_content);
+    let phases = phase_stats(tasks_content, &tasks)
+        .into_iter()
+        .map(|phase| PhaseProgress {
+            name: phase.name,
+            total_tasks: phase.total,
+            done_tasks: phase.done,
+        })
+        .collect();
+    let tasks = tasks
+        .into_iter()
+        .map(|task| TaskInfo {
+            title: task.title(),
+            section: task.section,
+            phase: task.phase,
+            done: task.done,
+            assignees: task.metadata.assignees,
+            estimate: task.metadata.estimate,
+            due: task.metadata.due,
+            completed_at: task.completed_at,
+        })
+        .collect();
+    (tasks, phases)
+}
+
+/// The project summary alone, fitted to `max_tokens` when given
+/// Fail on an unknown `part`, or one given without a spec to load
+fn validate_part(input: &Input) -> Result<()> {
+    let Some(part) = &input.part else {
+        return Ok(());
+    };
+    if !PARTS.contains(&part.as_str()) {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Unknown part '{}'; use one of: {}",
+            part,
+            PARTS.join(", ")
+        )));
+    }
+    if input.spec_name.is_none() {
+        return Err(ErrorCode::InvalidParams.error("'part' requires 'spec_name'"));
+    }
+    Ok(())
+}
+
+/// The requested `files`, deduplicated in [`FILES`] order
+///
+/// Fails on an unknown or empty list, on one given without a spec to load,
+/// or together with `part`.
+fn resolve_files(input: &Input) -> Result<Option<Vec<&'static str>>> {
+    let Some(files) = &input.files else {
+        return Ok(None);
+    };
+    if let Some(unknown) = files.iter().find(|file| !FILES.contains(&file.as_str())) {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Unknown file '{}'; use any of: {}",
+            unknown,
+            FILES.join(", ")
+        )));
+    }
+    if files.is_empty() {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "'files' needs at least one of: {}",
+            FILES.join(", ")
+        )));
+    }
+    if input.spec_name.is_none() {
+        return Err(ErrorCode::InvalidParams.error("'files' requires 'spec_name'"));
+    }
+    if input.part.is_some() {
+        return Err(ErrorCode::InvalidParams.error("Use either 'part' or 'files', not both"));
+    }
+    Ok(Some(
+        FILES
+            .iter()
+            .copied()
+            .filter(|file| files.iter().any(|requested| requested == file))
+            .collect(),
+    ))
+}
+
+/// The spec file a [`FILES`] entry names
+fn file_type(file: &str) -> SpecFileType {
+    match file {
+        "spec" => SpecFileType::Spec,
+        "tasks" => SpecFileType::TaskList,
+        _ => SpecFileType::Notes,
+    }
+}
+
+/// Versions of the loaded files, left empty for files not in `files`
+fn loaded_versions(content: &SpecContentData, files: Option<&[SpecFileType]>) -> SpecFileVersions {
+    let versions = versioning::spec_versions(content);
+    let Some(files) = files else {
+        return versions;
+    };
+    let loaded = |file_type: SpecFileType, version: String| {
+        if files.contains(&file_type) {
+            version
+        } else {
+            String::new()
+        }
+    };
+    SpecFileVersions {
+        spec: loaded(SpecFileType::Spec, versions.spec),
+        notes: loaded(SpecFileType::Notes, versions.notes),
+        tasks: loaded(SpecFileType::TaskList, versions.tasks),
+    }
+}
+
+fn spec_parts(project_summary: &str, content: &SpecContentData) -> Vec<SpecPart> {
+    [
+        ("summary", project_summary),
+        ("spec", content.spec.as_str()),
+        ("tasks", content.tasks.as_str()),
+        ("notes", content.notes.as_str()),
+    ]
+    .into_iter()
+    .map(|(name, text)| SpecPart {
+        name: name.to_string(),
+        estimated_tokens: estimate_tokens(text),
+    })
+    .collect()
+}
+
+/// The summary and spec files with everything but `part` left empty
+fn select_part(
+    part: Option<&str>,
+    project_summary: String,
+    content: SpecContentData,
+) -> (String, SpecContentData) {
+    match part {
+        None => (project_summary, content),
+        Some("summary") => (project_summary, SpecContentData::default()),
+        Some("spec") => (
+            String::new(),
+            SpecContentData {
+                spec: content.spec,
+                ..SpecContentData::default()
+            },
+        ),
+        Some("tasks") => (
+            String::new(),
+            SpecContentData {
+                tasks: content.tasks,
+                ..SpecContentData::default()
+            },
+        ),
+        Some(_) => (
+            String::new(),
+            SpecContentData {
+                notes: content.notes,
+                ..SpecContentData::default()
+            },
+        ),
+    }
+}
+
+fn generate_part_next_steps(response: &LoadSpecResponse) -> Vec<String> {
+    let spec_name = response.spec_name.as_deref().unwrap_or_default();
+    if !response.files.is_empty() {
+        let others: Vec<&str> = FILES
+            .iter()
+            .copied()
+            .filter(|file| !response.files.iter().any(|loaded| loaded == file))
+            .collect();
+        if others.is_empty() {
+            return Vec::new();
+        }
+        return vec![format!(
+            "Loaded only {} of {}; fetch the rest with files: {}",
+            response.files.join(", "),
+            spec_name,
+            others.join(", ")
+        )];
+    }
+    if let Some(part) = &response.part {
+        let others: Vec<&str> = response
+            .parts
+            .iter()
+            .map(|other| other.name.as_str())
+            .filter(|other| other != part)
+            .collect();
+        return vec![format!(
+            "Loaded only the '{}' part of {}; fetch the rest with part: {}",
+            part,
+            spec_name,
+            others.join(", ")
+        )];
+    }
+    let total: usize = response
+        .parts
+        .iter()
+        .map(|part| part.estimated_tokens)
+        .sum();
+    if total > LARGE_SPEC_TOKENS && response.budget.is_none() {
+        vec![format!(
+            "{} is large (~{} tokens); pass 'part' ({}) to load it one part at a time",
+            spec_name,
+            total,
+            PARTS.join(", ")
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+fn fit_summary(
+    project_summary: String,
+    max_tokens: Option<usize>,
+) -> (String, Option<ContextBudget>) {
+    match max_tokens {
+        Some(max_tokens) => {
+            let fitted = context_budget::fit(vec![summary_section(project_summary)], max_tokens);
+            (fitted.document(&Document::Summary), Some(fitted.report()))
+        }
+        None => (project_summary, None),
+    }
+}
+
+fn summary_section(project_summary: String) -> BudgetSection<Document> {
+    BudgetSection {
+        document: Document::Summary,
+        position: 0,
+        title: "Project summary".to_string(),
+        text: project_summary,
+    }
+}
+
+/// Trim the summary and spec files to `max_tokens`
+///
+/// Priority runs: project summary, task sections with open tasks, notes
+/// sections from the most recent (last) back, spec sections in order, and
+/// finally task sections whose tasks are all done.
+fn fit_spec_context(
+    project_summary: String,
+    content: &SpecContentData,
+    max_tokens: usize,
+) -> (String, SpecContentData, ContextBudget) {
+    let sections_of = |document: Document, label: &str, text: &str| {
+        context_budget::split_sections(text)
+            .into_iter()
+            .enumerate()
+            .map(|(position, (title, text))| BudgetSection {
+                document,
+                position,
+                title: format!("{}: {}", label, title),
+                text,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let (completed_tasks, open_tasks): (Vec<_>, Vec<_>) =
+        sections_of(Document::Tasks, "Tasks", &content.tasks)
+            .into_iter()
+            .partition(|section| {
+                let tasks = parse_task_list(&section.text);
+                !tasks.is_empty() && tasks.iter().all(|task| task.done)
+            });
+
+    let mut sections = vec![summary_section(project_summary)];
+    sections.extend(open_tasks);
+    sections.extend(
+        sections_of(Document::Notes, "Notes", &content.notes)
+            .into_iter()
+            .rev(),
+    );
+    sections.extend(sections_of(Document::Spec, "Spec", &content.spec));
+    sections.extend(completed_tasks);
+
+    let fitted = context_budget::fit(sections, max_tokens);
+    let trimmed = SpecContentData {
+        spec: fitted.document(&Document::Spec),
+        notes: fitted.document(&Document::Notes),
+        tasks: fitted.document(&Document::Tasks),
+    };
+    (
+        fitted.document(&Document::Summary),
+        trimmed,
+        fitted.report(),
+    )
+}
+
+fn generate_budget_next_steps(budget: Option<&ContextBudget>) -> Vec<String> {
+    match budget {
+        Some(budget) if !budget.omitted_sections.is_empty() || budget.truncated => {
+            vec![format!(
+                "Content was trimmed to ~{} of {} tokens ({} left out); load without 'max_tokens' before editing with replace commands",
+                budget.estimated_tokens,
+                budget.max_tokens,
+                format_count(budget.omitted_sections.len(), "section", "sections")
+            )]
+        }
+        _ => Vec::new(),
+    }
+}
+
 fn generate_listing_next_steps(project_name: &str, available_specs: &[SpecInfo]) -> Vec<String> {
     if available_specs.is_empty() {
         vec![
@@ -264,3 +888,20 @@ fn generate_spec_workflow_hints(spec_name: &str) -> Vec<String> {
         "You can use the project summary for broader context during implementation".to_string(),
     ]
 }
+
+fn generate_link_hints(spec_links: &[WikiLinkInfo]) -> Vec<String> {
+    let broken: Vec<&str> = spec_links
+        .iter()
+        .filter(|l| l.resolved_to.is_none())
+        .map(|l| l.target.as_str())
+        .collect();
+
+    if broken.is_empty() {
+        Vec::new()
+    } else {
+        vec![format!(
+            "Unresolved [[links]]: {} - link to a spec name, feature name, or vision/tech-stack/summary",
+            broken.join(", ")
+        )]
+    }
+}
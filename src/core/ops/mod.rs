@@ -1,14 +1,46 @@
 //! Operation layer for tool-agnostic business actions
 
+pub mod add_decision;
+pub mod add_tag;
 pub mod analyze_project;
+pub mod archive_spec;
+pub mod clone_project;
+pub mod copy_spec;
+pub mod create_handoff;
 pub mod create_project;
 pub mod create_spec;
+pub mod delete_project;
 pub mod delete_spec;
+pub mod diagram_specs;
+pub mod diff_spec;
+pub mod digest;
+pub mod export_project;
+pub mod export_tasks;
+pub mod get_audit_log;
 pub mod get_foundry_help;
+pub mod import_archive;
+pub mod import_project;
+pub mod import_spec;
+pub mod lint_spec;
+pub mod list_decisions;
 pub mod list_projects;
 pub mod list_specs;
 pub mod load_project;
 pub mod load_spec;
+pub mod needs_attention;
+pub mod project_analytics;
+pub mod project_report;
+pub mod remove_tag;
+pub mod rename_spec;
+pub mod review_spec;
+pub mod search_specs;
+pub mod set_spec_status;
+pub mod spec_graph;
+pub mod summarize_spec;
+pub mod undo_last_update;
+pub mod update_decisions;
+pub mod update_project;
 pub mod update_spec;
+pub mod update_specs_batch;
 pub mod validate_content;
 // Additional ops will be added incrementally and wired in
@@ -0,0 +1,116 @@
+//! Core op for finding stale specs that need attention (tool-agnostic)
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+
+use crate::core::ops::project_analytics::{load_spec_tasks, stalled_spec};
+use crate::core::{config, foundry};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, NeedsAttentionResponse, SpecNeedingAttention};
+use crate::utils::formatting::format_count;
+use crate::utils::response::build_success_response;
+
+/// Days without activity after which a spec with open tasks is stale
+pub const DEFAULT_STALE_DAYS: u32 = 14;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    /// Project to check; every project when not set
+    pub project_name: Option<String>,
+    /// Threshold for every project; each project's `[stale] days` when not set
+    pub stale_days: Option<u32>,
+}
+
+/// Stale threshold configured for `project_name`, or [`DEFAULT_STALE_DAYS`]
+pub fn stale_days(project_name: &str) -> Result<u32> {
+    Ok(config::for_project(project_name)?
+        .stale
+        .days
+        .unwrap_or(DEFAULT_STALE_DAYS))
+}
+
+#[tracing::instrument(name = "op.needs_attention", skip_all, fields(project = ?input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<NeedsAttentionResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+    let stale_days = input.stale_days.unwrap_or(DEFAULT_STALE_DAYS);
+
+    let project_names = match &input.project_name {
+        Some(project_name) => {
+            validate_project_exists(&foundry, project_name).await?;
+            vec![project_name.clone()]
+        }
+        None => foundry
+            .list_projects()
+            .await
+            .context("Failed to list projects")?
+            .into_iter()
+            .map(|project| project.name)
+            .collect(),
+    };
+
+    let today = Utc::now().date_naive();
+    let mut specs = Vec::new();
+    for project_name in &project_names {
+        let project_stale_days = match input.stale_days {
+            Some(days) => days,
+            None => self::stale_days(project_name)?,
+        };
+        for spec in load_spec_tasks(&foundry, project_name).await? {
+            if let Some(stalled) = stalled_spec(&spec, today, project_stale_days) {
+                specs.push(SpecNeedingAttention {
+                    project_name: project_name.clone(),
+                    spec_name: stalled.spec_name,
+                    open_tasks: stalled.open_tasks,
+                    last_activity: stalled.last_activity,
+                    days_idle: stalled.days_idle,
+                    stale_days: project_stale_days,
+                });
+            }
+        }
+    }
+    specs.sort_by_key(|spec| std::cmp::Reverse(spec.days_idle));
+
+    let response_data = NeedsAttentionResponse {
+        stale_days,
+        projects_checked: project_names.len(),
+        total_count: specs.len(),
+        specs,
+    };
+
+    let mut next_steps = vec![format!(
+        "{} with open tasks and no activity for {}+ days across {}",
+        format_count(response_data.total_count, "spec needs", "specs need"),
+        stale_days,
+        format_count(response_data.projects_checked, "project", "projects")
+    )];
+    if let Some(stalest) = response_data.specs.first() {
+        next_steps.push(format!(
+            "Pick up or close out '{}': {{\"name\": \"load_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            stalest.spec_name, stalest.project_name, stalest.spec_name
+        ));
+    }
+    let workflow_hints = vec![
+        "Activity is the latest change foundry made to the spec's files, task completed with a 'completed:YYYY-MM-DD' token, or the spec's creation date".to_string(),
+        "Set '[stale] days' in a project's .foundry.toml to give it its own threshold".to_string(),
+        "Finish, re-plan or delete stale specs so open work does not silently rot".to_string(),
+    ];
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
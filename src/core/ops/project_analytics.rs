@@ -0,0 +1,317 @@
+//! Core op for project velocity and completion trends (tool-agnostic)
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+
+use crate::core::foundry;
+use crate::core::ops::needs_attention;
+use crate::core::tasks::{TaskEntry, parse_task_list};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{
+    FoundryResponse, ProjectAnalyticsResponse, StalledSpec, WeeklyCompletions,
+};
+use crate::types::spec::SpecStatus;
+use crate::utils::formatting::format_count;
+use crate::utils::response::build_success_response;
+
+/// Weeks of completion history reported when not specified
+const DEFAULT_WEEKS: u32 = 8;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub weeks: Option<u32>,
+    pub stall_days: Option<u32>,
+}
+
+/// Tasks of one spec with the date it was created
+pub struct SpecTasks {
+    pub name: String,
+    pub created: NaiveDate,
+    pub status: SpecStatus,
+    pub tasks: Vec<TaskEntry>,
+    /// Latest change foundry recorded to one of its files
+    pub modified: Option<NaiveDate>,
+}
+
+#[tracing::instrument(name = "op.project_analytics", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<ProjectAnalyticsResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    validate_project_exists(&foundry, &input.project_name).await?;
+
+    let weeks = input.weeks.unwrap_or(DEFAULT_WEEKS);
+    if weeks == 0 {
+        return Err(ErrorCode::InvalidParams.error("'weeks' must be at least 1"));
+    }
+    let stall_days = match input.stall_days {
+        Some(days) => days,
+        None => needs_attention::stale_days(&input.project_name)?,
+    };
+
+    let specs = load_spec_tasks(&foundry, &input.project_name).await?;
+
+    let response_data = analyze(
+        &input.project_name,
+        &specs,
+        Utc::now().date_naive(),
+        weeks,
+        stall_days,
+    );
+
+    let mut next_steps = vec![format!(
+        "{} completed in the last {}; {} stalled",
+        format_count(
+            response_data
+                .weekly_completions
+                .iter()
+                .map(|w| w.completed)
+                .sum(),
+            "task",
+            "tasks"
+        ),
+        format_count(weeks as usize, "week", "weeks"),
+        format_count(response_data.stalled_specs.len(), "spec", "specs")
+    )];
+    if let Some(stalled) = response_data.stalled_specs.first() {
+        next_steps.push(format!(
+            "Review stalled spec '{}': {{\"name\": \"load_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            stalled.spec_name, input.project_name, stalled.spec_name
+        ));
+    }
+    let mut workflow_hints = vec![
+        "Velocity counts tasks with a 'completed:YYYY-MM-DD' token, which update_spec stamps when a task is checked off; tasks ticked in an editor need one added by hand".to_string(),
+        "'summary_markdown' is ready to paste into notes.md or a status update".to_string(),
+    ];
+    if response_data.undated_completed_tasks > 0 {
+        workflow_hints.push(format!(
+            "{} without a completion date are not counted in weekly velocity or cycle time",
+            format_count(
+                response_data.undated_completed_tasks,
+                "completed task",
+                "completed tasks"
+            )
+        ));
+    }
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+/// Tasks of every spec in `project_name`
+pub async fn load_spec_tasks(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<Vec<SpecTasks>> {
+    let metadata = foundry
+        .list_specs(project_name)
+        .await
+        .with_context(|| format!("Failed to list specs for project '{}'", project_name))?;
+
+    let mut specs = Vec::new();
+    for spec_meta in metadata {
+        let spec = foundry
+            .load_spec(project_name, &spec_meta.name)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to load spec '{}' in project '{}'",
+                    spec_meta.name, project_name
+                )
+            })?;
+        specs.push(SpecTasks {
+            created: parse_date(&spec.created_at).unwrap_or_else(|| Utc::now().date_naive()),
+            status: spec_meta.status,
+            tasks: parse_task_list(&spec.content.tasks),
+            modified: spec_meta
+                .modified_at
+                .values()
+                .filter_map(|modified| parse_date(modified))
+                .max(),
+            name: spec.name,
+        });
+    }
+    Ok(specs)
+}
+
+/// Compute velocity, cycle time and stalled specs as of `today`
+pub fn analyze(
+    project_name: &str,
+    specs: &[SpecTasks],
+    today: NaiveDate,
+    weeks: u32,
+    stall_days: u32,
+) -> ProjectAnalyticsResponse {
+    let this_week = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let mut weekly_completions: Vec<WeeklyCompletions> = (0..weeks as i64)
+        .rev()
+        .map(|offset| WeeklyCompletions {
+            week_start: (this_week - Duration::weeks(offset)).to_string(),
+            completed: 0,
+        })
+        .collect();
+    let first_week = this_week - Duration::weeks(weeks as i64 - 1);
+
+    let mut total_tasks = 0;
+    let mut completed_tasks = 0;
+    let mut undated_completed_tasks = 0;
+    let mut cycle_times = Vec::new();
+    let mut stalled_specs = Vec::new();
+
+    for spec in specs {
+        let completion_dates: Vec<NaiveDate> = spec
+            .tasks
+            .iter()
+            .filter(|task| task.done)
+            .filter_map(|task| task.completed_at.as_deref().and_then(parse_date))
+            .collect();
+        let done = spec.tasks.iter().filter(|task| task.done).count();
+        total_tasks += spec.tasks.len();
+        completed_tasks += done;
+        undated_completed_tasks += done - completion_dates.len();
+
+        for date in &completion_dates {
+            if *date >= first_week && *date <= today {
+                let week = ((*date - first_week).num_days() / 7) as usize;
+                weekly_completions[week].completed += 1;
+            }
+        }
+
+        let last_completion = completion_dates.iter().max().copied();
+        if done == spec.tasks.len() {
+            if let Some(finished) = last_completion
+                && !spec.tasks.is_empty()
+            {
+                cycle_times.push((finished - spec.created).num_days().max(0));
+            }
+        } else if let Some(stalled) = stalled_spec(spec, today, stall_days) {
+            stalled_specs.push(stalled);
+        }
+    }
+    stalled_specs.sort_by_key(|s| std::cmp::Reverse(s.days_idle));
+
+    let average_cycle_time_days = (!cycle_times.is_empty())
+        .then(|| cycle_times.iter().sum::<i64>() as f64 / cycle_times.len() as f64);
+
+    let mut response = ProjectAnalyticsResponse {
+        project_name: project_name.to_string(),
+        as_of: today.to_string(),
+        total_tasks,
+        completed_tasks,
+        undated_completed_tasks,
+        weekly_completions,
+        completed_spec_count: cycle_times.len(),
+        average_cycle_time_days,
+        stall_days,
+        stalled_specs,
+        summary_markdown: String::new(),
+    };
+    response.summary_markdown = summary_markdown(&response);
+    response
+}
+
+/// The spec as stalled when it has open tasks and no activity for `stall_days` as of `today`
+///
+/// Activity is the latest dated task completion, or the spec's creation.
+/// Completed and archived specs never stall.
+pub fn stalled_spec(spec: &SpecTasks, today: NaiveDate, stall_days: u32) -> Option<StalledSpec> {
+    let open_tasks = spec.tasks.iter().filter(|task| !task.done).count();
+    if open_tasks == 0 || spec.status.is_closed() {
+        return None;
+    }
+    let last_activity = spec
+        .tasks
+        .iter()
+        .filter(|task| task.done)
+        .filter_map(|task| task.completed_at.as_deref().and_then(parse_date))
+        .chain(spec.modified)
+        .max()
+        .map_or(spec.created, |d| d.max(spec.created));
+    let days_idle = (today - last_activity).num_days();
+    (days_idle >= stall_days as i64).then(|| StalledSpec {
+        spec_name: spec.name.clone(),
+        open_tasks,
+        last_activity: last_activity.to_string(),
+        days_idle: days_idle as u64,
+    })
+}
+
+fn summary_markdown(report: &ProjectAnalyticsResponse) -> String {
+    let mut lines = vec![
+        format!("## Project analytics: {}", report.project_name),
+        String::new(),
+        format!(
+            "As of {}: {}/{} tasks complete.",
+            report.as_of, report.completed_tasks, report.total_tasks
+        ),
+        String::new(),
+        "### Tasks completed per week".to_string(),
+        String::new(),
+        "| Week of | Completed |".to_string(),
+        "|---|---|".to_string(),
+    ];
+    lines.extend(
+        report
+            .weekly_completions
+            .iter()
+            .map(|week| format!("| {} | {} |", week.week_start, week.completed)),
+    );
+    lines.push(String::new());
+    lines.push("### Cycle time".to_string());
+    lines.push(String::new());
+    lines.push(report.average_cycle_time_days.map_or_else(
+        || "No completed specs with dated tasks yet.".to_string(),
+        |days| {
+            format!(
+                "Average {:.1} days from spec creation to last task completed ({}).",
+                days,
+                format_count(
+                    report.completed_spec_count,
+                    "completed spec",
+                    "completed specs"
+                )
+            )
+        },
+    ));
+    lines.push(String::new());
+    lines.push(format!(
+        "### Stalled specs (no activity for {}+ days)",
+        report.stall_days
+    ));
+    lines.push(String::new());
+    if report.stalled_specs.is_empty() {
+        lines.push("None.".to_string());
+    } else {
+        lines.extend(report.stalled_specs.iter().map(|spec| {
+            format!(
+                "- {}: {} open, last activity {} ({} days ago)",
+                spec.spec_name, spec.open_tasks, spec.last_activity, spec.days_idle
+            )
+        }));
+    }
+    lines.join("\n")
+}
+
+/// Date part of an RFC 3339 timestamp or a plain `YYYY-MM-DD` date
+pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.date_naive())
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,226 @@
+//! Core op for a project progress report (tool-agnostic)
+//!
+//! Rolls every spec up into one status: tasks done against total, completion
+//! per spec, tasks finished recently and stale specs, with a markdown
+//! rendering for weekly updates. Archived specs are left out.
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDate, Utc};
+
+use crate::core::foundry;
+use crate::core::ops::needs_attention;
+use crate::core::ops::project_analytics::{SpecTasks, load_spec_tasks, parse_date, stalled_spec};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{
+    FoundryResponse, ProjectReportResponse, RecentlyCompletedTask, SpecProgress,
+};
+use crate::types::spec::SpecStatus;
+use crate::utils::formatting::format_count;
+use crate::utils::response::build_success_response;
+
+/// Days of completed work reported when not specified
+const DEFAULT_RECENT_DAYS: u32 = 7;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub recent_days: Option<u32>,
+    pub stale_days: Option<u32>,
+}
+
+#[tracing::instrument(name = "op.project_report", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<ProjectReportResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if !foundry.project_exists(&input.project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            input.project_name
+        )));
+    }
+    let recent_days = input.recent_days.unwrap_or(DEFAULT_RECENT_DAYS);
+    if recent_days == 0 {
+        return Err(ErrorCode::InvalidParams.error("'recent_days' must be at least 1"));
+    }
+    let stale_days = match input.stale_days {
+        Some(days) => days,
+        None => needs_attention::stale_days(&input.project_name)?,
+    };
+
+    let specs = load_spec_tasks(&foundry, &input.project_name).await?;
+    let response_data = report(
+        &input.project_name,
+        &specs,
+        Utc::now().date_naive(),
+        recent_days,
+        stale_days,
+    );
+
+    let mut next_steps = vec![format!(
+        "{}/{} tasks complete ({}%); {} finished in the last {}; {} stale",
+        response_data.completed_tasks,
+        response_data.total_tasks,
+        response_data.completion_percent,
+        format_count(response_data.recently_completed.len(), "task", "tasks"),
+        format_count(recent_days as usize, "day", "days"),
+        format_count(response_data.stale_specs.len(), "spec", "specs")
+    )];
+    if let Some(stale) = response_data.stale_specs.first() {
+        next_steps.push(format!(
+            "Follow up on stale spec '{}': {{\"name\": \"load_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            stale.spec_name, input.project_name, stale.spec_name
+        ));
+    }
+    let workflow_hints = vec![
+        "'summary_markdown' is ready to share as a weekly status update".to_string(),
+        "Recently completed work counts tasks with a 'completed:YYYY-MM-DD' token, which update_spec stamps when a task is checked off".to_string(),
+        "For weekly velocity and cycle time use project_analytics".to_string(),
+    ];
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+/// Build the report for `specs` as of `today`
+pub fn report(
+    project_name: &str,
+    specs: &[SpecTasks],
+    today: NaiveDate,
+    recent_days: u32,
+    stale_days: u32,
+) -> ProjectReportResponse {
+    let since = today - Duration::days(recent_days as i64 - 1);
+    let mut progress = Vec::new();
+    let mut recently_completed = Vec::new();
+    let mut stale_specs = Vec::new();
+
+    for spec in specs
+        .iter()
+        .filter(|spec| spec.status != SpecStatus::Archived)
+    {
+        let completed_tasks = spec.tasks.iter().filter(|task| task.done).count();
+        progress.push(SpecProgress {
+            spec_name: spec.name.clone(),
+            status: spec.status,
+            total_tasks: spec.tasks.len(),
+            completed_tasks,
+            completion_percent: percent(completed_tasks, spec.tasks.len()),
+        });
+
+        recently_completed.extend(
+            spec.tasks
+                .iter()
+                .filter(|task| task.done)
+                .filter_map(|task| {
+                    let completed_at = task.completed_at.as_deref().and_then(parse_date)?;
+                    (completed_at >= since && completed_at <= today).then(|| {
+                        RecentlyCompletedTask {
+                            spec_name: spec.name.clone(),
+                            task: task.title(),
+                            completed_at: completed_at.to_string(),
+                        }
+                    })
+                }),
+        );
+
+        stale_specs.extend(stalled_spec(spec, today, stale_days));
+    }
+    recently_completed.sort_by(|a, b| b.completed_at.cmp(&a.completed_at));
+    stale_specs.sort_by_key(|s| std::cmp::Reverse(s.days_idle));
+
+    let total_tasks = progress.iter().map(|spec| spec.total_tasks).sum();
+    let completed_tasks = progress.iter().map(|spec| spec.completed_tasks).sum();
+    let mut response = ProjectReportResponse {
+        project_name: project_name.to_string(),
+        as_of: today.to_string(),
+        total_tasks,
+        completed_tasks,
+        completion_percent: percent(completed_tasks, total_tasks),
+        specs: progress,
+        recent_days,
+        recently_completed,
+        stale_days,
+        stale_specs,
+        summary_markdown: String::new(),
+    };
+    response.summary_markdown = summary_markdown(&response);
+    response
+}
+
+/// Whole percent of `done` out of `total`, rounded down; 0 when there is nothing
+fn percent(done: usize, total: usize) -> u32 {
+    (done * 100).checked_div(total).unwrap_or(0) as u32
+}
+
+fn summary_markdown(report: &ProjectReportResponse) -> String {
+    let mut lines = vec![
+        format!("## Status report: {}", report.project_name),
+        String::new(),
+        format!(
+            "As of {}: {}/{} tasks complete ({}%) across {}.",
+            report.as_of,
+            report.completed_tasks,
+            report.total_tasks,
+            report.completion_percent,
+            format_count(report.specs.len(), "spec", "specs")
+        ),
+        String::new(),
+        "### Specs".to_string(),
+        String::new(),
+    ];
+    if report.specs.is_empty() {
+        lines.push("No specs yet.".to_string());
+    } else {
+        lines.push("| Spec | Status | Tasks | Complete |".to_string());
+        lines.push("|---|---|---|---|".to_string());
+        lines.extend(report.specs.iter().map(|spec| {
+            let complete = if spec.total_tasks == 0 {
+                "-".to_string()
+            } else {
+                format!("{}%", spec.completion_percent)
+            };
+            format!(
+                "| {} | {} | {}/{} | {} |",
+                spec.spec_name, spec.status, spec.completed_tasks, spec.total_tasks, complete
+            )
+        }));
+    }
+
+    lines.push(String::new());
+    lines.push(format!(
+        "### Completed in the last {}",
+        format_count(report.recent_days as usize, "day", "days")
+    ));
+    lines.push(String::new());
+    if report.recently_completed.is_empty() {
+        lines.push("Nothing with a completion date.".to_string());
+    } else {
+        lines.extend(report.recently_completed.iter().map(|task| {
+            format!(
+                "- {} ({}, {})",
+                task.task, task.spec_name, task.completed_at
+            )
+        }));
+    }
+
+    lines.push(String::new());
+    lines.push(format!(
+        "### Stale specs (no activity for {}+ days)",
+        report.stale_days
+    ));
+    lines.push(String::new());
+    if report.stale_specs.is_empty() {
+        lines.push("None.".to_string());
+    } else {
+        lines.extend(report.stale_specs.iter().map(|spec| {
+            format!(
+                "- {}: {} open, last activity {} ({} days ago)",
+                spec.spec_name, spec.open_tasks, spec.last_activity, spec.days_idle
+            )
+        }));
+    }
+    lines.join("\n")
+}
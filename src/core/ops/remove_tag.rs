@@ -0,0 +1,33 @@
+//! Core op for removing tags from a project or spec (tool-agnostic)
+
+use anyhow::Result;
+
+use crate::core::ops::add_tag::{self, TagChange, list_next_step, subject, tag_workflow_hints};
+use crate::types::responses::{FoundryResponse, TagsResponse};
+use crate::utils::response::build_success_response;
+
+pub use crate::core::ops::add_tag::Input;
+
+#[tracing::instrument(name = "op.remove_tag", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<TagsResponse>> {
+    let response_data = add_tag::apply(&input, TagChange::Remove).await?;
+
+    let next_steps = vec![
+        if response_data.changed.is_empty() {
+            format!("{} had none of these tags", subject(&response_data))
+        } else {
+            format!(
+                "Removed {} from {}",
+                response_data.changed.join(", "),
+                subject(&response_data)
+            )
+        },
+        list_next_step(&response_data),
+    ];
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        tag_workflow_hints(),
+    ))
+}
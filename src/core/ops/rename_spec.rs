@@ -0,0 +1,157 @@
+//! Core op for renaming a spec's feature (tool-agnostic)
+//!
+//! The id prefix (timestamp, number or ULID) is kept, so the spec keeps its place in listings and
+//! its created_at; files, status and dependencies move with it. Wiki links and
+//! `depends_on` entries in other specs are rewritten to the new name.
+
+use anyhow::{Context, Result};
+
+use crate::core::foundry;
+use crate::core::spec_name;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, RenameSpecResponse};
+use crate::utils::paths;
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub spec_name: String,
+    /// New feature name in snake_case
+    pub new_feature_name: String,
+}
+
+#[tracing::instrument(name = "op.rename_spec", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<RenameSpecResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if input.spec_name.trim().is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Spec name cannot be empty"));
+    }
+    paths::validate_feature_name(&input.new_feature_name).map_err(|e| {
+        ErrorCode::ValidationFailed.error_with_rule("feature_name_format", e.to_string())
+    })?;
+    if !foundry.project_exists(&input.project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            input.project_name
+        )));
+    }
+
+    let _lock = foundry
+        .lock_spec(&input.project_name, &input.spec_name)
+        .await?;
+    let specs = foundry
+        .list_specs(&input.project_name)
+        .await
+        .with_context(|| format!("Failed to list specs for project '{}'", input.project_name))?;
+    let Some(spec) = specs.iter().find(|spec| spec.name == input.spec_name) else {
+        return Err(ErrorCode::SpecNotFound.error(format!(
+            "Spec '{}' not found in project '{}'. Use 'mcp_foundry_list_specs {}' to see available specs.",
+            input.spec_name, input.project_name, input.project_name
+        )));
+    };
+    let previous_feature_name = spec.feature_name.clone();
+    if previous_feature_name == input.new_feature_name {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Spec '{}' already has feature name '{}'",
+            input.spec_name, input.new_feature_name
+        )));
+    }
+
+    let parsed = spec_name::parse(&input.spec_name).ok_or_else(|| {
+        ErrorCode::ValidationFailed.error(format!(
+            "Invalid spec name format '{}'. Expected format: <id>_feature_name, e.g. YYYYMMDD_HHMMSS_feature_name",
+            input.spec_name
+        ))
+    })?;
+    let new_spec_name = format!("{}_{}", parsed.id, input.new_feature_name);
+
+    foundry
+        .rename_spec(&input.project_name, &input.spec_name, &new_spec_name)
+        .await
+        .with_context(|| format!("Failed to rename spec '{}'", input.spec_name))?;
+
+    // A link by feature name resolves to the newest spec with that feature, so
+    // it only pointed here when no other spec shares the old feature name
+    let mut links_updated = foundry
+        .rewrite_wiki_links(&input.project_name, &[&input.spec_name], &new_spec_name)
+        .await?;
+    if !specs
+        .iter()
+        .any(|other| other.name != input.spec_name && other.feature_name == previous_feature_name)
+    {
+        links_updated += foundry
+            .rewrite_wiki_links(
+                &input.project_name,
+                &[&previous_feature_name],
+                &input.new_feature_name,
+            )
+            .await?;
+    }
+
+    let mut dependents_updated = Vec::new();
+    for dependent in specs
+        .iter()
+        .filter(|other| other.depends_on.contains(&input.spec_name))
+    {
+        let _dependent_lock = foundry
+            .lock_spec(&input.project_name, &dependent.name)
+            .await?;
+        // Re-read under the lock so a dependency change made meanwhile is kept
+        let Some(dependent) = foundry
+            .list_specs(&input.project_name)
+            .await?
+            .into_iter()
+            .find(|spec| spec.name == dependent.name)
+        else {
+            continue;
+        };
+        let depends_on: Vec<String> = dependent
+            .depends_on
+            .iter()
+            .map(|name| {
+                if name == &input.spec_name {
+                    new_spec_name.clone()
+                } else {
+                    name.clone()
+                }
+            })
+            .collect();
+        foundry
+            .set_spec_dependencies(&input.project_name, &dependent.name, &depends_on)
+            .await
+            .with_context(|| format!("Failed to update dependencies of '{}'", dependent.name))?;
+        dependents_updated.push(dependent.name);
+    }
+
+    let next_steps = vec![
+        format!("Spec '{}' renamed to '{}'", input.spec_name, new_spec_name),
+        format!(
+            "Use the new name from now on: {{\"name\": \"load_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            input.project_name, new_spec_name
+        ),
+    ];
+    let workflow_hints = vec![
+        "The id prefix, status, dependencies and task state were kept".to_string(),
+        format!(
+            "Rewrote {} wiki link(s) and the depends_on of {} other spec(s)",
+            links_updated,
+            dependents_updated.len()
+        ),
+        "Backups taken under the old name stay listed under it".to_string(),
+    ];
+
+    Ok(build_success_response(
+        RenameSpecResponse {
+            project_name: input.project_name,
+            spec_name: new_spec_name,
+            previous_spec_name: input.spec_name,
+            feature_name: input.new_feature_name,
+            links_updated,
+            dependents_updated,
+        },
+        next_steps,
+        workflow_hints,
+    ))
+}
@@ -0,0 +1,438 @@
+//! Core op for reviewing a spec against a deterministic quality gate (tool-agnostic)
+
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+
+use crate::core::config::{self, FoundryConfig};
+use crate::core::foundry;
+use crate::core::tasks::{TaskEntry, parse_task_list};
+use crate::core::validation::ContentType;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, ReviewCheck, ReviewSpecResponse};
+use crate::utils::response::{build_incomplete_response, build_success_response};
+
+/// Score a spec needs to pass when no threshold is given
+const DEFAULT_PASS_THRESHOLD: u32 = 70;
+
+/// Words that make a requirement hard to verify
+const VAGUE_TERMS: &[&str] = &[
+    "fast",
+    "quick",
+    "easy",
+    "simple",
+    "intuitive",
+    "user-friendly",
+    "robust",
+    "seamless",
+    "flexible",
+    "scalable",
+    "efficient",
+    "appropriate",
+    "as needed",
+    "as appropriate",
+    "etc",
+    "and so on",
+    "various",
+    "maybe",
+    "possibly",
+    "tbd",
+    "todo",
+];
+
+/// Words ignored when matching acceptance criteria to tasks
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "with", "that", "this", "from", "into", "when", "then", "given", "should",
+    "must", "will", "can", "are", "is", "be", "a", "an", "of", "to", "in", "on", "user", "users",
+];
+
+/// Headers whose list items are treated as acceptance criteria
+pub(crate) const CRITERIA_HEADERS: &[&str] =
+    &["acceptance", "success criteria", "definition of done"];
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub spec_name: String,
+    pub pass_threshold: Option<u32>,
+}
+
+#[tracing::instrument(name = "op.review_spec", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<ReviewSpecResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    validate_project_exists(&foundry, &input.project_name).await?;
+
+    let pass_threshold = input.pass_threshold.unwrap_or(DEFAULT_PASS_THRESHOLD);
+    if pass_threshold > 100 {
+        return Err(ErrorCode::InvalidParams.error("'pass_threshold' must be between 0 and 100"));
+    }
+
+    let spec_name = foundry
+        .find_spec_match(&input.project_name, &input.spec_name)
+        .await?
+        .into_spec_name(&input.project_name, &input.spec_name)?;
+    let spec = foundry
+        .load_spec(&input.project_name, &spec_name)
+        .await
+        .with_context(|| format!("Failed to load spec '{}'", spec_name))?;
+
+    let config = config::for_project(&input.project_name)?;
+    let response_data = review(
+        &config,
+        &input.project_name,
+        &spec.name,
+        &spec.content.spec,
+        &spec.content.notes,
+        &spec.content.tasks,
+        pass_threshold,
+    );
+
+    let failed: Vec<&ReviewCheck> = response_data
+        .checks
+        .iter()
+        .filter(|check| !check.passed)
+        .collect();
+    let mut next_steps = vec![format!(
+        "Spec scored {}/{} (pass threshold {}): {}",
+        response_data.score,
+        response_data.max_score,
+        pass_threshold,
+        if response_data.passed {
+            "ready for implementation"
+        } else {
+            "address the failed checks before implementing"
+        }
+    )];
+    next_steps.extend(failed.iter().filter_map(|check| {
+        check
+            .details
+            .first()
+            .map(|detail| format!("{}: {}", check.title, detail))
+    }));
+    if !failed.is_empty() {
+        next_steps.push(format!(
+            "Fix with update_spec, then re-run review_spec for '{}'",
+            response_data.spec_name
+        ));
+    }
+    let workflow_hints = vec![
+        "Checks are deterministic heuristics; the same spec always gets the same score".to_string(),
+        "Acceptance criteria are list items under an 'Acceptance Criteria', 'Success Criteria' or 'Definition of Done' header".to_string(),
+        "'checklist_markdown' can be pasted into notes.md to track review follow-ups".to_string(),
+    ];
+
+    Ok(if response_data.passed {
+        build_success_response(response_data, next_steps, workflow_hints)
+    } else {
+        build_incomplete_response(response_data, next_steps, workflow_hints)
+    })
+}
+
+/// Score spec, notes and task list content against the review checklist
+pub fn review(
+    config: &FoundryConfig,
+    project_name: &str,
+    spec_name: &str,
+    spec: &str,
+    notes: &str,
+    tasks_content: &str,
+    pass_threshold: u32,
+) -> ReviewSpecResponse {
+    let tasks = parse_task_list(tasks_content);
+    let criteria = acceptance_criteria(spec);
+
+    let checks = vec![
+        check_structure(config, spec, notes, tasks_content),
+        check_criteria_present(&criteria),
+        check_criteria_coverage(&criteria, &tasks),
+        check_vague_requirements(spec),
+        check_tasks_actionable(&tasks),
+        check_testing(spec, &tasks),
+    ];
+
+    let score = checks.iter().map(|check| check.score).sum();
+    let max_score = checks.iter().map(|check| check.max_score).sum();
+    // Structural errors fail the gate regardless of the score
+    let structure_ok = checks[0].passed;
+    let mut response = ReviewSpecResponse {
+        project_name: project_name.to_string(),
+        spec_name: spec_name.to_string(),
+        score,
+        max_score,
+        pass_threshold,
+        passed: structure_ok && score >= pass_threshold,
+        checks,
+        checklist_markdown: String::new(),
+    };
+    response.checklist_markdown = checklist_markdown(&response);
+    response
+}
+
+fn check_structure(config: &FoundryConfig, spec: &str, notes: &str, tasks: &str) -> ReviewCheck {
+    let mut details = Vec::new();
+    let mut score = 0;
+    for (label, content_type, content, points) in [
+        ("spec.md", ContentType::Spec, spec, 10),
+        ("notes.md", ContentType::Notes, notes, 5),
+        ("task-list.md", ContentType::Tasks, tasks, 5),
+    ] {
+        let result = config.validate(content_type, content);
+        if result.is_valid {
+            score += points;
+        }
+        details.extend(
+            result
+                .errors
+                .iter()
+                .map(|error| format!("{}: {}", label, error)),
+        );
+    }
+    ReviewCheck {
+        id: "structure".to_string(),
+        title: "Structural validation".to_string(),
+        passed: details.is_empty(),
+        score,
+        max_score: 20,
+        details,
+    }
+}
+
+fn check_criteria_present(criteria: &[String]) -> ReviewCheck {
+    let passed = !criteria.is_empty();
+    ReviewCheck {
+        id: "acceptance_criteria".to_string(),
+        title: "Acceptance criteria defined".to_string(),
+        passed,
+        score: if passed { 15 } else { 0 },
+        max_score: 15,
+        details: if passed {
+            Vec::new()
+        } else {
+            vec![
+                "Add an '## Acceptance Criteria' section with one verifiable item per line"
+                    .to_string(),
+            ]
+        },
+    }
+}
+
+fn check_criteria_coverage(criteria: &[String], tasks: &[TaskEntry]) -> ReviewCheck {
+    let max_score = 25;
+    let uncovered: Vec<&String> = criteria
+        .iter()
+        .filter(|criterion| !tasks.iter().any(|task| covers(&task.text, criterion)))
+        .collect();
+    let score = if criteria.is_empty() {
+        0
+    } else {
+        let covered = criteria.len() - uncovered.len();
+        (max_score as usize * covered / criteria.len()) as u32
+    };
+    ReviewCheck {
+        id: "criteria_coverage".to_string(),
+        title: "Acceptance criteria covered by tasks".to_string(),
+        passed: !criteria.is_empty() && uncovered.is_empty(),
+        score,
+        max_score,
+        details: if criteria.is_empty() {
+            vec!["No acceptance criteria to match against tasks".to_string()]
+        } else {
+            uncovered
+                .iter()
+                .map(|criterion| format!("No task covers '{}'", criterion))
+                .collect()
+        },
+    }
+}
+
+fn check_vague_requirements(spec: &str) -> ReviewCheck {
+    let max_score: u32 = 20;
+    let flagged: Vec<String> = spec
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let words = words(line);
+            let terms: Vec<&str> = VAGUE_TERMS
+                .iter()
+                .copied()
+                .filter(|term| {
+                    if term.contains(' ') {
+                        words.join(" ").contains(term)
+                    } else {
+                        words.iter().any(|word| word == term)
+                    }
+                })
+                .collect();
+            (!terms.is_empty()).then(|| format!("'{}' ({})", line, terms.join(", ")))
+        })
+        .collect();
+    ReviewCheck {
+        id: "vague_requirements".to_string(),
+        title: "Requirements are specific".to_string(),
+        passed: flagged.is_empty(),
+        score: max_score.saturating_sub(4 * flagged.len() as u32),
+        max_score,
+        details: flagged
+            .into_iter()
+            .map(|line| format!("Vague wording in {}", line))
+            .collect(),
+    }
+}
+
+fn check_tasks_actionable(tasks: &[TaskEntry]) -> ReviewCheck {
+    let max_score: u32 = 10;
+    let short: Vec<&TaskEntry> = tasks
+        .iter()
+        .filter(|task| words(&task.text).len() < 3)
+        .collect();
+    let (score, details) = if tasks.is_empty() {
+        (
+            0,
+            vec!["Task list has no '- [ ]' checklist items".to_string()],
+        )
+    } else {
+        let actionable = tasks.len() - short.len();
+        (
+            (max_score as usize * actionable / tasks.len()) as u32,
+            short
+                .iter()
+                .map(|task| format!("Task '{}' is too terse to act on", task.text))
+                .collect(),
+        )
+    };
+    ReviewCheck {
+        id: "tasks_actionable".to_string(),
+        title: "Tasks are actionable".to_string(),
+        passed: details.is_empty(),
+        score,
+        max_score,
+        details,
+    }
+}
+
+fn check_testing(spec: &str, tasks: &[TaskEntry]) -> ReviewCheck {
+    let mentions_tests = |text: &str| {
+        words(text)
+            .iter()
+            .any(|word| word.starts_with("test") || word == "verify")
+    };
+    let spec_covers = mentions_tests(spec);
+    let tasks_cover = tasks.iter().any(|task| mentions_tests(&task.text));
+    let mut details = Vec::new();
+    if !spec_covers {
+        details.push("Describe how the feature will be tested in spec.md".to_string());
+    }
+    if !tasks_cover {
+        details.push("Add a task for writing or running tests".to_string());
+    }
+    ReviewCheck {
+        id: "testing".to_string(),
+        title: "Testing is planned".to_string(),
+        passed: details.is_empty(),
+        score: 5 * u32::from(spec_covers) + 5 * u32::from(tasks_cover),
+        max_score: 10,
+        details,
+    }
+}
+
+/// List items under acceptance-criteria headers in the spec
+fn acceptance_criteria(spec: &str) -> Vec<String> {
+    let mut criteria = Vec::new();
+    let mut in_criteria = false;
+    for line in spec.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            let header = trimmed.to_lowercase();
+            in_criteria = CRITERIA_HEADERS
+                .iter()
+                .any(|keyword| header.contains(keyword));
+            continue;
+        }
+        if !in_criteria {
+            continue;
+        }
+        let item = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+            .map(|item| {
+                item.trim_start_matches("[ ]")
+                    .trim_start_matches("[x]")
+                    .trim()
+            });
+        if let Some(item) = item
+            && !item.is_empty()
+        {
+            criteria.push(item.to_string());
+        }
+    }
+    criteria
+}
+
+/// Whether a task shares enough significant words with a criterion to cover it
+fn covers(task: &str, criterion: &str) -> bool {
+    let significant = |text: &str| -> HashSet<String> {
+        words(text)
+            .into_iter()
+            .filter(|word| word.len() >= 3 && !STOP_WORDS.contains(&word.as_str()))
+            .map(|word| word.trim_end_matches('s').to_string())
+            .collect()
+    };
+    let criterion_words = significant(criterion);
+    if criterion_words.is_empty() {
+        return false;
+    }
+    let shared = criterion_words.intersection(&significant(task)).count();
+    // Half the criterion's words, and at least two unless it only has one
+    shared * 2 >= criterion_words.len() && shared >= criterion_words.len().min(2)
+}
+
+/// Lowercase words of `text`, keeping inner hyphens
+fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '-')
+        .map(|word| word.trim_matches('-'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn checklist_markdown(review: &ReviewSpecResponse) -> String {
+    let mut lines = vec![
+        format!("## Spec review: {}", review.spec_name),
+        String::new(),
+        format!(
+            "Score {}/{} (pass threshold {}): {}",
+            review.score,
+            review.max_score,
+            review.pass_threshold,
+            if review.passed { "PASS" } else { "FAIL" }
+        ),
+        String::new(),
+    ];
+    for check in &review.checks {
+        lines.push(format!(
+            "- [{}] {} ({}/{})",
+            if check.passed { "x" } else { " " },
+            check.title,
+            check.score,
+            check.max_score
+        ));
+        lines.extend(check.details.iter().map(|detail| format!("  - {}", detail)));
+    }
+    lines.join("\n")
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,241 @@
+//! Core op for full-text search across specs in every project (tool-agnostic)
+
+use anyhow::{Context, Result};
+
+use crate::core::foundry;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, SearchSpecsResponse, SpecSearchMatch};
+use crate::types::spec::Spec;
+use crate::utils::formatting::format_count;
+use crate::utils::response::build_success_response;
+
+/// Matches returned when no limit is given
+pub const DEFAULT_LIMIT: usize = 20;
+
+/// Largest number of matches returned
+const MAX_LIMIT: usize = 100;
+
+/// Longest snippet returned for a matching line, in characters
+const MAX_SNIPPET_CHARS: usize = 160;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub query: String,
+    /// Project to search; every project when not set
+    pub project_name: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[tracing::instrument(name = "op.search_specs", skip_all, fields(project = ?input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<SearchSpecsResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    let terms = query_terms(&input.query);
+    if terms.is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Search query must not be empty"));
+    }
+    let limit = input.limit.unwrap_or(DEFAULT_LIMIT);
+    if limit == 0 || limit > MAX_LIMIT {
+        return Err(ErrorCode::InvalidParams
+            .error(format!("Search limit must be between 1 and {}", MAX_LIMIT)));
+    }
+
+    let project_names = match &input.project_name {
+        Some(project_name) => {
+            validate_project_exists(&foundry, project_name).await?;
+            vec![project_name.clone()]
+        }
+        None => foundry
+            .list_projects()
+            .await
+            .context("Failed to list projects")?
+            .into_iter()
+            .map(|project| project.name)
+            .collect(),
+    };
+
+    let phrase = terms.join(" ");
+    let mut matches = Vec::new();
+    let mut specs_searched = 0;
+    for project_name in &project_names {
+        let specs = foundry
+            .list_specs(project_name)
+            .await
+            .with_context(|| format!("Failed to list specs for project '{}'", project_name))?;
+        for metadata in specs {
+            let spec = foundry
+                .load_spec(project_name, &metadata.name)
+                .await
+                .with_context(|| format!("Failed to load spec '{}'", metadata.name))?;
+            specs_searched += 1;
+            matches.extend(search_spec(&spec, &metadata.feature_name, &terms, &phrase));
+        }
+    }
+
+    // Best matches first; ties go by project, newest spec, then file and line
+    matches.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.project_name.cmp(&b.project_name))
+            .then_with(|| b.spec_name.cmp(&a.spec_name))
+            .then_with(|| a.file.cmp(&b.file))
+            .then_with(|| a.line_number.cmp(&b.line_number))
+    });
+    let total_matches = matches.len();
+    matches.truncate(limit);
+
+    let response_data = SearchSpecsResponse {
+        query: input.query.clone(),
+        projects_searched: project_names.len(),
+        specs_searched,
+        total_matches,
+        truncated: total_matches > limit,
+        matches,
+    };
+
+    let mut next_steps = vec![format!(
+        "Found {} in {} across {}",
+        format_count(total_matches, "match", "matches"),
+        format_count(specs_searched, "spec", "specs"),
+        format_count(response_data.projects_searched, "project", "projects")
+    )];
+    if let Some(best) = response_data.matches.first() {
+        next_steps.push(format!(
+            "Open the best match: {{\"name\": \"load_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            best.project_name, best.spec_name
+        ));
+    }
+    let mut workflow_hints = vec![
+        "Matches are ranked by how many query words a line contains, with exact phrases and feature-name hits ranked higher".to_string(),
+    ];
+    if response_data.truncated {
+        workflow_hints.push(format!(
+            "Showing the top {} of {} matches; narrow the query, set 'project_name', or raise 'limit' (up to {})",
+            limit, total_matches, MAX_LIMIT
+        ));
+    }
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+/// Lowercased query words; quotes and surrounding punctuation are ignored
+fn query_terms(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Every line of `spec` that contains a query word, scored
+fn search_spec(
+    spec: &Spec,
+    feature_name: &str,
+    terms: &[String],
+    phrase: &str,
+) -> Vec<SpecSearchMatch> {
+    let feature_name = feature_name.to_lowercase();
+    let feature_bonus = if terms
+        .iter()
+        .any(|term| feature_name.contains(term.as_str()))
+    {
+        5
+    } else {
+        0
+    };
+
+    let mut matches = Vec::new();
+    for (file, content) in [
+        ("spec.md", &spec.content.spec),
+        ("notes.md", &spec.content.notes),
+        ("task-list.md", &spec.content.tasks),
+    ] {
+        for (index, line) in content.lines().enumerate() {
+            let lower = line.to_lowercase();
+            let matched_terms = terms
+                .iter()
+                .filter(|term| lower.contains(term.as_str()))
+                .count();
+            if matched_terms == 0 {
+                continue;
+            }
+            let occurrences: usize = terms
+                .iter()
+                .map(|term| lower.matches(term.as_str()).count())
+                .sum();
+            let phrase_bonus = if terms.len() > 1 && lower.contains(phrase) {
+                15
+            } else {
+                0
+            };
+            let heading_bonus = if line.trim_start().starts_with('#') {
+                3
+            } else {
+                0
+            };
+            matches.push(SpecSearchMatch {
+                project_name: spec.project_name.clone(),
+                spec_name: spec.name.clone(),
+                file: file.to_string(),
+                line_number: index + 1,
+                snippet: snippet(line, &lower, terms),
+                score: matched_terms * 10
+                    + occurrences.min(5)
+                    + phrase_bonus
+                    + heading_bonus
+                    + feature_bonus,
+            });
+        }
+    }
+    matches
+}
+
+/// `line` trimmed to `MAX_SNIPPET_CHARS` around its first match
+fn snippet(line: &str, lower: &str, terms: &[String]) -> String {
+    let line = line.trim();
+    let chars: Vec<char> = line.chars().collect();
+    if chars.len() <= MAX_SNIPPET_CHARS {
+        return line.to_string();
+    }
+
+    // Lowercasing can change byte lengths, so locate the match by character
+    let lower = lower.trim();
+    let first_match = terms
+        .iter()
+        .filter_map(|term| lower.find(term.as_str()))
+        .min()
+        .map_or(0, |byte| lower[..byte].chars().count());
+    let start = first_match
+        .saturating_sub(MAX_SNIPPET_CHARS / 4)
+        .min(chars.len() - MAX_SNIPPET_CHARS);
+    let end = start + MAX_SNIPPET_CHARS;
+
+    let mut snippet: String = chars[start..end].iter().collect();
+    if start > 0 {
+        snippet.insert(0, '…');
+    }
+    if end < chars.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,118 @@
+//! Core op for moving a spec through its lifecycle (tool-agnostic)
+
+use anyhow::{Context, Result};
+
+use crate::core::foundry;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, SpecStatusResponse};
+use crate::types::spec::SpecStatus;
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub spec_name: String,
+    /// One of draft, active, completed, archived
+    pub status: String,
+}
+
+#[tracing::instrument(name = "op.set_spec_status", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<SpecStatusResponse>> {
+    let status: SpecStatus = input.status.parse()?;
+    let response_data = apply(&input.project_name, &input.spec_name, status).await?;
+
+    let mut next_steps = vec![format!(
+        "Spec '{}' moved from {} to {}",
+        response_data.spec_name, response_data.previous_status, response_data.status
+    )];
+    next_steps.push(match status {
+        SpecStatus::Draft | SpecStatus::Active => format!(
+            "You can continue with the spec: {{\"name\": \"load_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            response_data.project_name, response_data.spec_name
+        ),
+        SpecStatus::Completed => format!(
+            "When the spec no longer needs to be listed, archive it: {{\"name\": \"archive_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            response_data.project_name, response_data.spec_name
+        ),
+        SpecStatus::Archived => archived_next_step(&response_data),
+    });
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        status_workflow_hints(),
+    ))
+}
+
+/// Set the status of `spec_name`, returning the change
+pub(crate) async fn apply(
+    project_name: &str,
+    spec_name: &str,
+    status: SpecStatus,
+) -> Result<SpecStatusResponse> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if spec_name.trim().is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Spec name cannot be empty"));
+    }
+    validate_project_exists(&foundry, project_name).await?;
+
+    let _lock = foundry.lock_spec(project_name, spec_name).await?;
+    let listed = foundry
+        .list_specs(project_name)
+        .await
+        .with_context(|| format!("Failed to list specs for project '{}'", project_name))?
+        .into_iter()
+        .find(|spec| spec.name == spec_name);
+    let Some(listed) = listed else {
+        // Listings skip specs with unreadable metadata; loading reports why
+        foundry.load_spec(project_name, spec_name).await?;
+        return Err(ErrorCode::SpecNotFound.error(format!(
+            "Spec '{}' not found in project '{}'. Use 'mcp_foundry_list_specs {}' with status 'all' to see every spec.",
+            spec_name, project_name, project_name
+        )));
+    };
+    let previous_status = listed.status;
+
+    if previous_status != status {
+        foundry
+            .set_spec_status(project_name, spec_name, status)
+            .await
+            .with_context(|| format!("Failed to set status of spec '{}'", spec_name))?;
+    }
+
+    Ok(SpecStatusResponse {
+        project_name: project_name.to_string(),
+        spec_name: spec_name.to_string(),
+        previous_status,
+        status,
+    })
+}
+
+/// How to find or restore a spec once archived
+pub(crate) fn archived_next_step(response: &SpecStatusResponse) -> String {
+    format!(
+        "Archived specs are hidden from list_specs; see them with status \"archived\", or reopen this one: {{\"name\": \"set_spec_status\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\", \"status\": \"active\"}}}}",
+        response.project_name, response.spec_name
+    )
+}
+
+pub(crate) fn status_workflow_hints() -> Vec<String> {
+    vec![
+        "Specs move through draft → active → completed → archived; any status can be set again later".to_string(),
+        "Completed and archived specs are not reported as stale by needs_attention".to_string(),
+    ]
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
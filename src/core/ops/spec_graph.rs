@@ -0,0 +1,120 @@
+//! Core op for reporting a project's spec dependency graph (tool-agnostic)
+
+use anyhow::{Context, Result};
+
+use crate::core::{dependencies, foundry};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, SpecGraphNode, SpecGraphResponse};
+use crate::utils::formatting::format_count;
+use crate::utils::response::{build_incomplete_response, build_success_response};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+}
+
+#[tracing::instrument(name = "op.spec_graph", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<SpecGraphResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    validate_project_exists(&foundry, &input.project_name).await?;
+
+    let mut specs = foundry
+        .list_specs(&input.project_name)
+        .await
+        .with_context(|| format!("Failed to list specs for project '{}'", input.project_name))?;
+    // Oldest first, so ties and cyclic graphs read in creation order
+    specs.reverse();
+
+    let cycles = dependencies::find_cycles(&specs);
+    let build_order = dependencies::build_order(&specs).unwrap_or_default();
+    if !build_order.is_empty() {
+        specs.sort_by_key(|spec| build_order.iter().position(|name| name == &spec.name));
+    }
+
+    let nodes: Vec<SpecGraphNode> = specs
+        .iter()
+        .map(|spec| SpecGraphNode {
+            spec_name: spec.name.clone(),
+            feature_name: spec.feature_name.clone(),
+            status: spec.status,
+            depends_on: spec.depends_on.clone(),
+            dependents: dependencies::dependents(&spec.name, &specs),
+            blocked_by: dependencies::open_dependencies(spec, &specs),
+            missing_dependencies: dependencies::missing_dependencies(spec, &specs),
+        })
+        .collect();
+    let ready_specs: Vec<String> = nodes
+        .iter()
+        .filter(|node| !node.status.is_closed() && node.blocked_by.is_empty())
+        .map(|node| node.spec_name.clone())
+        .collect();
+    let edge_count = nodes
+        .iter()
+        .map(|node| node.depends_on.len() - node.missing_dependencies.len())
+        .sum();
+
+    let response_data = SpecGraphResponse {
+        project_name: input.project_name.clone(),
+        spec_count: nodes.len(),
+        edge_count,
+        has_cycles: !cycles.is_empty(),
+        cycles,
+        build_order,
+        ready_specs,
+        nodes,
+    };
+
+    let mut next_steps = vec![format!(
+        "{} with {}; {} ready to start",
+        format_count(response_data.spec_count, "spec", "specs"),
+        format_count(response_data.edge_count, "dependency", "dependencies"),
+        format_count(response_data.ready_specs.len(), "spec is", "specs are")
+    )];
+    if let Some(next) = response_data.ready_specs.first() {
+        next_steps.push(format!(
+            "You can pick up '{}' next: mcp_foundry_load_spec {} {}",
+            next, input.project_name, next
+        ));
+    }
+    let workflow_hints = vec![
+        "A spec is ready when it is not completed or archived and every dependency is".to_string(),
+        "Add or remove dependencies with update_spec: {\"target\": \"spec\", \"command\": \"add_dependency\", \"selector\": {\"type\": \"spec_name\", \"value\": \"<spec_name>\"}}".to_string(),
+        "'build_order' lists every spec after the specs it depends on".to_string(),
+    ];
+
+    if response_data.has_cycles {
+        next_steps.push(format!(
+            "{} found (see 'cycles'); remove a dependency with update_spec 'remove_dependency' to break each one",
+            format_count(
+                response_data.cycles.len(),
+                "dependency cycle",
+                "dependency cycles"
+            )
+        ));
+        Ok(build_incomplete_response(
+            response_data,
+            next_steps,
+            workflow_hints,
+        ))
+    } else {
+        Ok(build_success_response(
+            response_data,
+            next_steps,
+            workflow_hints,
+        ))
+    }
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            project_name
+        )));
+    }
+    Ok(())
+}
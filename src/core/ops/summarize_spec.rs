@@ -0,0 +1,144 @@
+//! Core op for drafting a spec's summary with the client's model (tool-agnostic)
+//!
+//! The summary is drafted through MCP sampling (see `core::sampling`) and
+//! stored in spec metadata, where `list_specs` reports it. A summary records
+//! the version of spec.md it describes, so asking again for an unchanged spec
+//! returns the stored summary without sampling.
+
+use anyhow::{Context, Result};
+use similar::TextDiff;
+
+use crate::core::sampling::{self, SamplingRequest};
+use crate::core::{foundry, versioning};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, SummarizeSpecResponse};
+use crate::types::spec::SpecSummary;
+use crate::utils::response::build_success_response;
+use crate::utils::timestamp;
+
+/// Longest summary asked for when `max_words` is not given
+pub const DEFAULT_MAX_WORDS: usize = 60;
+
+/// Share of spec.md lines an edit must change before a new summary is suggested
+const SUBSTANTIAL_CHANGE_RATIO: f32 = 0.2;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub spec_name: String,
+    /// Draft a new summary even if spec.md is unchanged since the last one
+    pub force: bool,
+    /// Longest summary to ask for, in words; [`DEFAULT_MAX_WORDS`] when not set
+    pub max_words: Option<usize>,
+}
+
+#[tracing::instrument(name = "op.summarize_spec", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<SummarizeSpecResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+    let max_words = input.max_words.unwrap_or(DEFAULT_MAX_WORDS);
+    if max_words == 0 {
+        return Err(ErrorCode::InvalidParams.error("max_words must be at least 1"));
+    }
+
+    let metadata = foundry
+        .list_specs(&input.project_name)
+        .await
+        .with_context(|| format!("Failed to list specs for project '{}'", input.project_name))?
+        .into_iter()
+        .find(|spec| spec.name == input.spec_name)
+        .ok_or_else(|| {
+            ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'. Use 'mcp_foundry_list_specs {}' to see available specs.",
+                input.spec_name, input.project_name, input.project_name
+            ))
+        })?;
+    let spec = foundry
+        .load_spec(&input.project_name, &input.spec_name)
+        .await?;
+    let spec_version = versioning::content_version(&spec.content.spec);
+
+    if let Some(summary) = metadata.summary
+        && summary.spec_version == spec_version
+        && !input.force
+    {
+        return Ok(build_success_response(
+            SummarizeSpecResponse {
+                project_name: input.project_name,
+                spec_name: input.spec_name,
+                summary,
+                regenerated: false,
+            },
+            vec![
+                "spec.md is unchanged since this summary; pass force to draft a new one"
+                    .to_string(),
+            ],
+            vec![],
+        ));
+    }
+
+    let sampled = sampling::sample(SamplingRequest {
+        system_prompt: "You summarize software feature specifications for a project index. \
+            Reply with the summary only: plain prose, no headings, lists or preamble."
+            .to_string(),
+        prompt: format!(
+            "Summarize the feature spec '{}' in at most {} words: what it builds and why.\n\n{}",
+            metadata.feature_name, max_words, spec.content.spec
+        ),
+        max_tokens: (max_words * 3).max(64) as u32,
+    })
+    .await?;
+    let text = sampled.text.trim();
+    if text.is_empty() {
+        return Err(ErrorCode::InternalError.error("The client's model returned an empty summary"));
+    }
+
+    let summary = SpecSummary {
+        text: text.to_string(),
+        generated_at: timestamp::iso_timestamp(),
+        model: sampled.model,
+        spec_version,
+    };
+    foundry
+        .set_spec_summary(&input.project_name, &input.spec_name, &summary)
+        .await
+        .with_context(|| format!("Failed to store summary of spec '{}'", input.spec_name))?;
+
+    let next_steps = vec![format!(
+        "Stored the summary of '{}'; list_specs now returns it",
+        input.spec_name
+    )];
+    let workflow_hints = vec![
+        "Summaries go stale as spec.md changes; update_spec suggests summarize_spec after substantial edits".to_string(),
+    ];
+
+    Ok(build_success_response(
+        SummarizeSpecResponse {
+            project_name: input.project_name,
+            spec_name: input.spec_name,
+            summary,
+            regenerated: true,
+        },
+        next_steps,
+        workflow_hints,
+    ))
+}
+
+/// Whether an edit from `before` to `after` changed enough of spec.md to redraft its summary
+pub fn changed_substantially(before: &str, after: &str) -> bool {
+    before != after && TextDiff::from_lines(before, after).ratio() < 1.0 - SUBSTANTIAL_CHANGE_RATIO
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_large_edits_count_as_substantial() {
+        let spec: String = (1..=20).map(|n| format!("Requirement {}\n", n)).collect();
+        let small = spec.replace("Requirement 3\n", "Requirement 3, revised\n");
+        let large: String = (1..=20).map(|n| format!("Goal {}\n", n)).collect();
+        assert!(!changed_substantially(&spec, &spec));
+        assert!(!changed_substantially(&spec, &small));
+        assert!(changed_substantially(&spec, &large));
+    }
+}
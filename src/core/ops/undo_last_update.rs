@@ -0,0 +1,144 @@
+//! Core op for undoing the last update of a spec (tool-agnostic)
+//!
+//! Pops the newest entry of the spec's update journal (see `core::history`)
+//! and writes back the content the update replaced. A file changed again
+//! since that update is only overwritten with `force`.
+
+use anyhow::{Context, Result};
+
+use crate::core::foundry;
+use crate::core::history;
+use crate::core::versioning;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, UndoLastUpdateResponse};
+use crate::types::spec::SpecFileType;
+use crate::utils::response::build_success_response;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    pub spec_name: String,
+    /// Undo even if a file changed again after the update
+    pub force: bool,
+}
+
+#[tracing::instrument(name = "op.undo_last_update", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<UndoLastUpdateResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if input.spec_name.trim().is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Spec name cannot be empty"));
+    }
+    if !foundry.project_exists(&input.project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            input.project_name
+        )));
+    }
+    let _lock = foundry
+        .lock_spec(&input.project_name, &input.spec_name)
+        .await?;
+    let current = foundry
+        .load_spec(&input.project_name, &input.spec_name)
+        .await
+        .map_err(|_| {
+            ErrorCode::SpecNotFound.error(format!(
+                "Spec '{}' not found in project '{}'",
+                input.spec_name, input.project_name
+            ))
+        })?;
+
+    let mut entries = history::entries(&input.project_name, &input.spec_name)?;
+    let Some(entry) = entries.pop() else {
+        return Err(ErrorCode::InvalidParams
+            .error(format!("Spec '{}' has no updates to undo", input.spec_name)));
+    };
+
+    if !input.force {
+        let changed: Vec<&str> = entry
+            .files
+            .iter()
+            .filter(|change| {
+                versioning::content_version(current_content(&current.content, &change.file_type))
+                    != change.after_version
+            })
+            .map(|change| change.file_type.file_name())
+            .collect();
+        if !changed.is_empty() {
+            return Err(ErrorCode::BackendConflict.error(format!(
+                "{} in spec '{}' changed after the {} being undone; undoing would discard those changes. Pass force: true to undo anyway",
+                changed.join(", "),
+                input.spec_name,
+                entry.operation
+            )));
+        }
+    }
+
+    for change in &entry.files {
+        foundry
+            .update_spec_content(
+                &input.project_name,
+                &input.spec_name,
+                change.file_type.clone(),
+                &change.before,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to restore {} of spec '{}'",
+                    change.file_type.file_name(),
+                    input.spec_name
+                )
+            })?;
+    }
+    history::remove_last(&input.project_name, &input.spec_name).await?;
+
+    let restored = foundry
+        .load_spec(&input.project_name, &input.spec_name)
+        .await?;
+    let response_data = UndoLastUpdateResponse {
+        project_name: input.project_name.clone(),
+        spec_name: input.spec_name.clone(),
+        operation: entry.operation,
+        updated_at: entry.updated_at,
+        files_restored: entry
+            .files
+            .iter()
+            .map(|change| change.file_type.file_name().to_string())
+            .collect(),
+        remaining_undos: entries.len(),
+        versions: versioning::spec_versions(&restored.content),
+    };
+
+    let mut next_steps = vec![format!(
+        "Restored {} of spec '{}'",
+        response_data.files_restored.join(", "),
+        response_data.spec_name
+    )];
+    if response_data.remaining_undos > 0 {
+        next_steps.push(format!(
+            "{} earlier update(s) can still be undone by calling undo_last_update again",
+            response_data.remaining_undos
+        ));
+    }
+
+    Ok(build_success_response(
+        response_data,
+        next_steps,
+        vec![
+            "The undo itself is not journaled; redo it by reapplying the edit with update_spec"
+                .to_string(),
+        ],
+    ))
+}
+
+fn current_content<'a>(
+    content: &'a crate::types::spec::SpecContentData,
+    file_type: &SpecFileType,
+) -> &'a str {
+    match file_type {
+        SpecFileType::Spec => &content.spec,
+        SpecFileType::Notes => &content.notes,
+        SpecFileType::TaskList => &content.tasks,
+    }
+}
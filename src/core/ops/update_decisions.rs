@@ -0,0 +1,64 @@
+//! Core op for applying edit commands to a project's decision log (tool-agnostic)
+
+use anyhow::{Context, Result};
+
+use crate::core::decisions;
+use crate::core::edit_engine::EditEngine;
+use crate::core::foundry;
+use crate::core::ops::list_decisions::validate_project_exists;
+use crate::types::edit_commands::EditCommand;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{FoundryResponse, UpdateDecisionsResponse, ValidationStatus};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    /// JSON array of edit commands with target `decisions`
+    pub commands_json: String,
+}
+
+#[tracing::instrument(name = "op.update_decisions", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<UpdateDecisionsResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if input.commands_json.trim().is_empty() {
+        return Err(ErrorCode::InvalidParams.error("'commands' parameter is required"));
+    }
+    validate_project_exists(&foundry, &input.project_name).await?;
+    let commands: Vec<EditCommand> = serde_json::from_str(&input.commands_json)
+        .map_err(|e| ErrorCode::InvalidParams.error(format!("Invalid commands JSON: {}", e)))?;
+
+    let _lock = foundry.lock_project(&input.project_name).await?;
+    let project = foundry.load_project(&input.project_name).await?;
+    let current = project.decisions.unwrap_or_default();
+    let (content, result) = EditEngine::apply_to_decisions(&current, &commands)?;
+    if content != current {
+        foundry
+            .update_decisions(&input.project_name, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write the decision log of project '{}'",
+                    input.project_name
+                )
+            })?;
+    }
+
+    Ok(FoundryResponse {
+        data: UpdateDecisionsResponse {
+            project_name: input.project_name,
+            applied_count: result.applied_count,
+            skipped_idempotent_count: result.skipped_idempotent_count,
+            errors: if result.errors.is_empty() {
+                None
+            } else {
+                Some(result.errors)
+            },
+            total_decisions: decisions::parse(&content).len(),
+        },
+        next_steps: result.next_steps,
+        validation_status: ValidationStatus::Complete,
+        workflow_hints: result.workflow_hints,
+        diagnostics: None,
+    })
+}
@@ -0,0 +1,123 @@
+//! Core op for replacing a project's vision, tech stack or summary (tool-agnostic)
+
+use anyhow::{Context, Result};
+
+use crate::core::config;
+use crate::core::foundry;
+use crate::core::ops::create_project::process_content_validation;
+use crate::core::validation::ContentType;
+use crate::types::errors::ErrorCode;
+use crate::types::project::ProjectFileType;
+use crate::types::responses::{FoundryResponse, UpdateProjectResponse};
+use crate::utils::response::{build_incomplete_response, build_success_response};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    /// New vision.md content; unchanged when `None`
+    pub vision: Option<String>,
+    /// New tech-stack.md content; unchanged when `None`
+    pub tech_stack: Option<String>,
+    /// New summary.md content; unchanged when `None`
+    pub summary: Option<String>,
+}
+
+#[tracing::instrument(name = "op.update_project", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<UpdateProjectResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    let updates: Vec<(&'static str, ContentType, ProjectFileType, String)> = [
+        (
+            "Vision",
+            ContentType::Vision,
+            ProjectFileType::Vision,
+            input.vision,
+        ),
+        (
+            "Tech Stack",
+            ContentType::TechStack,
+            ProjectFileType::TechStack,
+            input.tech_stack,
+        ),
+        (
+            "Summary",
+            ContentType::Summary,
+            ProjectFileType::Summary,
+            input.summary,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(label, content_type, file_type, content)| {
+        content.map(|content| (label, content_type, file_type, content))
+    })
+    .collect();
+    if updates.is_empty() {
+        return Err(ErrorCode::InvalidParams
+            .error("Nothing to update; provide at least one of vision, tech_stack or summary"));
+    }
+    if !foundry.project_exists(&input.project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use 'mcp_foundry_list_projects' to see available projects.",
+            input.project_name
+        )));
+    }
+
+    let config = config::for_project(&input.project_name)?;
+    let suggestions = process_content_validation(
+        updates
+            .iter()
+            .map(|(label, content_type, _, content)| {
+                (*label, config.validate(*content_type, content))
+            })
+            .collect(),
+        &config,
+    )?;
+
+    let mut files_updated = Vec::new();
+    for (_, _, file_type, content) in &updates {
+        foundry
+            .update_project_content(&input.project_name, *file_type, content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to update {} of project '{}'",
+                    file_type.file_name(),
+                    input.project_name
+                )
+            })?;
+        files_updated.push(file_type.file_name().to_string());
+    }
+
+    let next_steps = vec![
+        format!(
+            "Updated {} in project '{}'",
+            files_updated.join(", "),
+            input.project_name
+        ),
+        format!(
+            "You can review the project context: mcp_foundry_load_project {}",
+            input.project_name
+        ),
+    ];
+    let response_data = UpdateProjectResponse {
+        project_name: input.project_name,
+        files_updated,
+    };
+
+    if suggestions.is_empty() {
+        Ok(build_success_response(
+            response_data,
+            next_steps,
+            vec![
+                "Documents are replaced whole; load the project first to keep content you still need"
+                    .to_string(),
+            ],
+        ))
+    } else {
+        Ok(build_incomplete_response(
+            response_data,
+            next_steps,
+            suggestions,
+        ))
+    }
+}
@@ -2,40 +2,227 @@
 
 use anyhow::Result;
 
+use crate::core::activity;
+use crate::core::backup;
+use crate::core::config::{self, FoundryConfig};
+use crate::core::edit_engine::EditCommandsResult;
 use crate::core::foundry;
-use crate::types::edit_commands::EditCommand;
+use crate::core::history;
+use crate::core::links;
+use crate::core::lock::WriteLock;
+use crate::core::ops::summarize_spec;
+use crate::core::timing::{self, Phase};
+use crate::core::validation::ContentType;
+use crate::core::versioning;
+use crate::types::edit_commands::{EditCommand, EditCommandTarget};
+use crate::types::errors::ErrorCode;
 use crate::types::responses::{EditCommandsResponsePayload, FoundryResponse, ValidationStatus};
+use crate::types::spec::{Spec, SpecContentData, SpecFileType};
 
 #[derive(Debug, Clone)]
 pub struct Input {
     pub project_name: String,
     pub spec_name: String,
     pub commands_json: String,
+    /// Version of the targeted file the caller last read; the update fails if it changed
+    pub expected_version: Option<String>,
+    /// Return the diff the commands would make without writing anything
+    pub dry_run: bool,
 }
 
+#[tracing::instrument(name = "op.update_spec", skip_all, fields(project = %input.project_name))]
 pub async fn run(input: Input) -> Result<FoundryResponse<EditCommandsResponsePayload>> {
     let foundry = foundry::get_default_foundry()?;
 
     validate_args(&input)?;
     validate_project_exists(&foundry, &input.project_name).await?;
 
+    // Hold the spec's lock from reading it until the edit is written back, so
+    // another foundry process cannot slip its own update in between
+    let _lock = if input.dry_run {
+        WriteLock::unguarded()
+    } else {
+        foundry
+            .lock_spec(&input.project_name, &input.spec_name)
+            .await?
+    };
+
     // Check if spec exists by trying to load it
-    foundry.load_spec(&input.project_name, &input.spec_name).await.map_err(|_| {
-        anyhow::anyhow!(
+    let current = foundry.load_spec(&input.project_name, &input.spec_name).await.map_err(|_| {
+        ErrorCode::SpecNotFound.error(format!(
             "Spec '{}' not found in project '{}'. Use load_project tool to see available specs: {{\"name\": \"load_project\", \"arguments\": {{\"project_name\": \"{}\"}}}}",
             input.spec_name,
             input.project_name,
             input.project_name
-        )
+        ))
     })?;
 
-    let commands: Vec<EditCommand> = serde_json::from_str(&input.commands_json)
-        .map_err(|e| anyhow::anyhow!("Invalid commands JSON: {}", e))?;
+    let commands: Vec<EditCommand> = {
+        let _parsing = timing::start(Phase::Parsing);
+        serde_json::from_str(&input.commands_json)
+            .map_err(|e| ErrorCode::InvalidParams.error(format!("Invalid commands JSON: {}", e)))?
+    };
+
+    if let Some(expected_version) = &input.expected_version {
+        check_expected_version(&current, &commands, expected_version)?;
+    }
+
+    let config = config::for_project(&input.project_name)?;
+    let edited = foundry
+        .edited_content(&input.project_name, &input.spec_name, &commands)
+        .await?;
+    let validation_warnings = validate_changes(&config, &current.content, &edited);
+
+    if input.dry_run {
+        let result = foundry
+            .preview_edit_commands(&input.project_name, &input.spec_name, &commands)
+            .await?;
+        return Ok(with_validation_warnings(
+            build_response(result, &current, true),
+            validation_warnings,
+        ));
+    }
+
+    if !validation_warnings.is_empty() && config.profile().rejects_specs() {
+        return Err(ErrorCode::ValidationFailed.error_with_rule(
+            "content_validation",
+            format!(
+                "Content validation failed ({} profile), nothing was written:\n{}",
+                config.profile().name(),
+                validation_warnings.join("\n")
+            ),
+        ));
+    }
+
+    if commands
+        .iter()
+        .any(|command| command.command.discards_content())
+    {
+        backup::snapshot_before(
+            &foundry,
+            &input.project_name,
+            &input.spec_name,
+            "update_spec",
+        )
+        .await?;
+    }
 
     let result = foundry
         .apply_edit_commands(&input.project_name, &input.spec_name, &commands)
         .await?;
 
+    let updated = foundry
+        .load_spec(&input.project_name, &input.spec_name)
+        .await?;
+    // The edit is already written, so a journal failure only costs the undo
+    if let Err(e) = history::record(
+        &input.project_name,
+        &input.spec_name,
+        "update_spec",
+        &current.content,
+        &updated.content,
+    )
+    .await
+    {
+        tracing::warn!("Could not journal update of '{}': {:#}", input.spec_name, e);
+    }
+    if let Err(e) = activity::record_update(
+        &input.project_name,
+        &input.spec_name,
+        "update_spec",
+        &current.content,
+        &updated.content,
+    )
+    .await
+    {
+        tracing::warn!(
+            "Could not record activity of '{}': {:#}",
+            input.spec_name,
+            e
+        );
+    }
+
+    let mut response =
+        with_validation_warnings(build_response(result, &updated, false), validation_warnings);
+    let specs = foundry.list_specs(&input.project_name).await?;
+    let broken_before = links::resolve_references(&current.content, &specs);
+    let broken_links: Vec<String> = links::resolve_references(&updated.content, &specs)
+        .into_iter()
+        .filter(|target| !broken_before.contains(target))
+        .collect();
+    if !broken_links.is_empty() {
+        response.workflow_hints.push(format!(
+            "Unresolved [[links]] added: {} - link to a spec name, feature name, or vision/tech-stack/summary",
+            broken_links.join(", ")
+        ));
+    }
+    if summarize_spec::changed_substantially(&current.content.spec, &updated.content.spec) {
+        response.next_steps.push(format!(
+            "spec.md changed substantially; if the client supports sampling, refresh its summary: {{\"name\": \"summarize_spec\", \"arguments\": {{\"project_name\": \"{}\", \"spec_name\": \"{}\"}}}}",
+            input.project_name, input.spec_name
+        ));
+    }
+    Ok(response)
+}
+
+/// Validation errors of every file the edit changes, prefixed with the file name
+fn validate_changes(
+    config: &FoundryConfig,
+    before: &SpecContentData,
+    after: &SpecContentData,
+) -> Vec<String> {
+    [
+        ("spec.md", ContentType::Spec, &before.spec, &after.spec),
+        ("notes.md", ContentType::Notes, &before.notes, &after.notes),
+        (
+            "task-list.md",
+            ContentType::Tasks,
+            &before.tasks,
+            &after.tasks,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, _, before, after)| before != after)
+    .flat_map(|(file_name, content_type, _, after)| {
+        config
+            .validate(content_type, after)
+            .errors
+            .into_iter()
+            .map(move |e| format!("{}: {}", file_name, e))
+    })
+    .collect()
+}
+
+/// `response` with `warnings` reported in its hints and status
+fn with_validation_warnings(
+    mut response: FoundryResponse<EditCommandsResponsePayload>,
+    warnings: Vec<String>,
+) -> FoundryResponse<EditCommandsResponsePayload> {
+    if !warnings.is_empty() {
+        response.validation_status = ValidationStatus::Incomplete;
+        response.workflow_hints.extend(
+            warnings
+                .into_iter()
+                .map(|warning| format!("Validation warning: {}", warning)),
+        );
+    }
+    response
+}
+
+/// Response for applied commands, or for a dry run of them against `spec`
+fn build_response(
+    result: EditCommandsResult,
+    spec: &Spec,
+    dry_run: bool,
+) -> FoundryResponse<EditCommandsResponsePayload> {
+    let next_steps = if dry_run {
+        vec![
+            "Dry run: nothing was written. Show preview_diff to the user, then re-run without dry_run to apply the commands".to_string(),
+        ]
+    } else {
+        result.next_steps
+    };
+
     let response_data = EditCommandsResponsePayload {
         applied_count: result.applied_count,
         skipped_idempotent_count: result.skipped_idempotent_count,
@@ -46,38 +233,95 @@ pub async fn run(input: Input) -> Result<FoundryResponse<EditCommandsResponsePay
             Some(result.errors)
         },
         preview_diff: result.preview_diff,
+        command_diffs: dry_run.then_some(result.command_diffs),
+        dry_run,
+        versions: versioning::spec_versions(&spec.content),
     };
 
-    Ok(FoundryResponse {
+    FoundryResponse {
         data: response_data,
-        next_steps: result.next_steps,
+        next_steps,
         validation_status: ValidationStatus::Complete,
         workflow_hints: result.workflow_hints,
-    })
+        diagnostics: None,
+    }
+}
+
+/// Fail with a conflict when the file the commands target no longer has `expected_version`
+fn check_expected_version(
+    current: &Spec,
+    commands: &[EditCommand],
+    expected_version: &str,
+) -> Result<()> {
+    let mut targets: Vec<SpecFileType> = Vec::new();
+    for command in commands {
+        let file_type = match command.target {
+            EditCommandTarget::Spec => SpecFileType::Spec,
+            EditCommandTarget::Tasks => SpecFileType::TaskList,
+            EditCommandTarget::Notes => SpecFileType::Notes,
+            EditCommandTarget::Decisions => {
+                return Err(ErrorCode::InvalidParams.error(
+                    "decisions.md belongs to the project and has no spec version; edit it with update_decisions",
+                ));
+            }
+        };
+        if !targets.contains(&file_type) {
+            targets.push(file_type);
+        }
+    }
+    let [file_type] = targets.as_slice() else {
+        return Err(ErrorCode::InvalidParams.error(
+            "'expected_version' guards a single file, but the commands target several; send one update_spec call per target",
+        ));
+    };
+
+    let (file_name, content) = match file_type {
+        SpecFileType::Spec => ("spec.md", &current.content.spec),
+        SpecFileType::Notes => ("notes.md", &current.content.notes),
+        SpecFileType::TaskList => ("task-list.md", &current.content.tasks),
+    };
+    let current_version = versioning::content_version(content);
+    if current_version == expected_version {
+        return Ok(());
+    }
+
+    let changes = versioning::recall(expected_version).map_or_else(
+        || "The content for the expected version is not available to diff.".to_string(),
+        |expected| {
+            format!(
+                "Changes since that version:\n{}",
+                versioning::line_diff(&expected, content)
+            )
+        },
+    );
+    Err(ErrorCode::BackendConflict.error(format!(
+        "{} in spec '{}' changed since version {} (now {}); reload it with load_spec and reapply your edits. {}",
+        file_name, current.name, expected_version, current_version, changes
+    )))
 }
 
 fn validate_args(input: &Input) -> Result<()> {
     if input.project_name.trim().is_empty() {
-        return Err(anyhow::anyhow!("Project name cannot be empty"));
+        return Err(ErrorCode::InvalidParams.error("Project name cannot be empty"));
     }
     if input.spec_name.trim().is_empty() {
-        return Err(anyhow::anyhow!("Spec name cannot be empty"));
+        return Err(ErrorCode::InvalidParams.error("Spec name cannot be empty"));
     }
     if input.commands_json.trim().is_empty() {
-        return Err(anyhow::anyhow!("'commands' parameter is required"));
+        return Err(ErrorCode::InvalidParams.error("'commands' parameter is required"));
     }
     Ok(())
 }
 
 async fn validate_project_exists(
-    foundry: &foundry::Foundry<crate::core::backends::filesystem::FilesystemBackend>,
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
     project_name: &str,
 ) -> Result<()> {
     if !foundry.project_exists(project_name).await? {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ProjectNotFound.error(format!(
             "Project '{}' not found. Use list_projects tool to see available projects: {{\"name\": \"list_projects\", \"arguments\": {{}}}}",
             project_name
-        ));
+        )));
     }
     Ok(())
 }
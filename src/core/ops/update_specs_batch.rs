@@ -0,0 +1,241 @@
+//! Core op for applying edit commands to several specs in one call (tool-agnostic)
+
+use anyhow::Result;
+use serde::Deserialize;
+
+use crate::core::activity;
+use crate::core::backup;
+use crate::core::foundry;
+use crate::core::history;
+use crate::core::versioning;
+use crate::types::edit_commands::EditCommand;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{BatchSpecResult, FoundryResponse, UpdateSpecsBatchResponse};
+use crate::utils::formatting::format_count;
+use crate::utils::response::{build_incomplete_response, build_success_response};
+
+/// Most specs one batch may update
+const MAX_BATCH_SPECS: usize = 50;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_name: String,
+    /// JSON array of `{"spec_name": ..., "commands": [...]}` entries
+    pub updates_json: String,
+}
+
+/// One spec's edits within a batch
+#[derive(Debug, Deserialize)]
+struct BatchEntry {
+    spec_name: String,
+    commands: Vec<EditCommand>,
+}
+
+#[tracing::instrument(name = "op.update_specs_batch", skip_all, fields(project = %input.project_name))]
+pub async fn run(input: Input) -> Result<FoundryResponse<UpdateSpecsBatchResponse>> {
+    let foundry = foundry::get_default_foundry()?;
+
+    if input.project_name.trim().is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Project name cannot be empty"));
+    }
+    let entries = parse_entries(&input.updates_json)?;
+    validate_project_exists(&foundry, &input.project_name).await?;
+
+    // Specs are independent: a failing spec keeps its files and the rest still apply
+    let mut results = Vec::with_capacity(entries.len());
+    for entry in entries {
+        results.push(apply_entry(&foundry, &input.project_name, entry).await);
+    }
+
+    let succeeded = results.iter().filter(|result| result.success).count();
+    let response_data = UpdateSpecsBatchResponse {
+        project_name: input.project_name.clone(),
+        total_specs: results.len(),
+        succeeded,
+        failed: results.len() - succeeded,
+        results,
+    };
+
+    let mut next_steps = vec![format!(
+        "Updated {} of {}",
+        format_count(succeeded, "spec", "specs"),
+        response_data.total_specs
+    )];
+    let workflow_hints = vec![
+        "Each spec's commands apply together or not at all; a failed spec is left unchanged"
+            .to_string(),
+        "Use the returned 'versions' as 'expected_version' for follow-up update_spec calls"
+            .to_string(),
+    ];
+
+    if response_data.failed == 0 {
+        Ok(build_success_response(
+            response_data,
+            next_steps,
+            workflow_hints,
+        ))
+    } else {
+        next_steps.push(format!(
+            "{} not changed; fix the reported errors and resend only those entries",
+            format_count(response_data.failed, "spec was", "specs were")
+        ));
+        Ok(build_incomplete_response(
+            response_data,
+            next_steps,
+            workflow_hints,
+        ))
+    }
+}
+
+fn parse_entries(updates_json: &str) -> Result<Vec<BatchEntry>> {
+    let entries: Vec<BatchEntry> = serde_json::from_str(updates_json)
+        .map_err(|e| ErrorCode::InvalidParams.error(format!("Invalid updates JSON: {}", e)))?;
+
+    if entries.is_empty() {
+        return Err(ErrorCode::InvalidParams.error("'updates' must contain at least one entry"));
+    }
+    if entries.len() > MAX_BATCH_SPECS {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "'updates' has {} entries; a batch may update at most {} specs",
+            entries.len(),
+            MAX_BATCH_SPECS
+        )));
+    }
+    for (index, entry) in entries.iter().enumerate() {
+        if entries[..index]
+            .iter()
+            .any(|earlier| earlier.spec_name == entry.spec_name)
+        {
+            return Err(ErrorCode::InvalidParams.error(format!(
+                "Spec '{}' appears more than once in 'updates'; combine its commands into one entry",
+                entry.spec_name
+            )));
+        }
+    }
+    Ok(entries)
+}
+
+/// Apply one entry's commands, all or nothing, reporting failures in the result
+async fn apply_entry(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+    entry: BatchEntry,
+) -> BatchSpecResult {
+    let mut result = BatchSpecResult {
+        spec_name: entry.spec_name.clone(),
+        success: false,
+        applied_count: 0,
+        skipped_idempotent_count: 0,
+        error_code: None,
+        error: None,
+        errors: None,
+        versions: None,
+    };
+
+    if entry.commands.is_empty() {
+        result.error_code = Some(ErrorCode::InvalidParams);
+        result.error = Some("'commands' must be a non-empty array".to_string());
+        return result;
+    }
+    // Held until this spec's edits and journal entry are written
+    let _lock = match foundry.lock_spec(project_name, &entry.spec_name).await {
+        Ok(lock) => lock,
+        Err(e) => {
+            result.error_code = Some(ErrorCode::of(&e));
+            result.error = Some(format!("{:#}", e));
+            return result;
+        }
+    };
+    let Ok(current) = foundry.load_spec(project_name, &entry.spec_name).await else {
+        result.error_code = Some(ErrorCode::SpecNotFound);
+        result.error = Some(format!(
+            "Spec '{}' not found in project '{}'",
+            entry.spec_name, project_name
+        ));
+        return result;
+    };
+
+    if entry
+        .commands
+        .iter()
+        .any(|command| command.command.discards_content())
+        && let Err(e) = backup::snapshot_before(
+            foundry,
+            project_name,
+            &entry.spec_name,
+            "update_specs_batch",
+        )
+        .await
+    {
+        result.error_code = Some(ErrorCode::InternalError);
+        result.error = Some(format!("{:#}", e));
+        return result;
+    }
+
+    let applied = foundry
+        .apply_edit_commands_atomic(project_name, &entry.spec_name, &entry.commands)
+        .await;
+    match applied {
+        Ok(applied) if applied.errors.is_empty() => {
+            result.success = true;
+            result.applied_count = applied.applied_count;
+            result.skipped_idempotent_count = applied.skipped_idempotent_count;
+            let updated = foundry.load_spec(project_name, &entry.spec_name).await.ok();
+            if let Some(updated) = &updated
+                && let Err(e) = history::record(
+                    project_name,
+                    &entry.spec_name,
+                    "update_specs_batch",
+                    &current.content,
+                    &updated.content,
+                )
+                .await
+            {
+                tracing::warn!("Could not journal update of '{}': {:#}", entry.spec_name, e);
+            }
+            if let Some(updated) = &updated
+                && let Err(e) = activity::record_update(
+                    project_name,
+                    &entry.spec_name,
+                    "update_specs_batch",
+                    &current.content,
+                    &updated.content,
+                )
+                .await
+            {
+                tracing::warn!(
+                    "Could not record activity of '{}': {:#}",
+                    entry.spec_name,
+                    e
+                );
+            }
+            result.versions = updated.map(|spec| versioning::spec_versions(&spec.content));
+        }
+        Ok(applied) => {
+            result.error_code = applied.errors.first().map(|error| error.code);
+            result.error = Some(format!(
+                "{} failed, so none of this spec's commands were applied",
+                format_count(applied.errors.len(), "command", "commands")
+            ));
+            result.errors = Some(applied.errors);
+        }
+        Err(e) => {
+            result.error_code = Some(ErrorCode::of(&e));
+            result.error = Some(format!("{:#}", e));
+        }
+    }
+    result
+}
+
+async fn validate_project_exists(
+    foundry: &foundry::Foundry<crate::core::backends::SharedBackend>,
+    project_name: &str,
+) -> Result<()> {
+    if !foundry.project_exists(project_name).await? {
+        return Err(ErrorCode::ProjectNotFound.error(format!(
+            "Project '{}' not found. Use list_projects tool to see available projects: {{\"name\": \"list_projects\", \"arguments\": {{}}}}",
+            project_name
+        )));
+    }
+    Ok(())
+}
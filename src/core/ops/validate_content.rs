@@ -2,15 +2,20 @@
 
 use anyhow::{Context, Result};
 
-use crate::core::validation::{parse_content_type, validate_content};
+use crate::core::config;
+use crate::core::validation::parse_content_type;
+use crate::types::errors::ErrorCode;
 use crate::types::responses::{FoundryResponse, ValidateContentResponse, ValidationStatus};
 
 #[derive(Debug, Clone)]
 pub struct Input {
     pub content_type: String,
     pub content: String,
+    /// Apply this project's `.foundry.toml` overrides on top of the global config
+    pub project_name: Option<String>,
 }
 
+#[tracing::instrument(name = "op.validate_content", skip_all)]
 pub async fn run(input: Input) -> Result<FoundryResponse<ValidateContentResponse>> {
     validate_input_args(&input.content_type, &input.content)
         .with_context(|| "Input validation failed")?;
@@ -22,13 +27,19 @@ pub async fn run(input: Input) -> Result<FoundryResponse<ValidateContentResponse
         )
     })?;
 
-    let validation_result = validate_content(content_type, &input.content);
+    let config = match &input.project_name {
+        Some(project_name) => config::for_project(project_name)?,
+        None => config::global()?,
+    };
+    let validation_result = config.validate(content_type, &input.content);
 
     let response_data = ValidateContentResponse {
         content_type: input.content_type.clone(),
         is_valid: validation_result.is_valid,
         validation_errors: validation_result.errors.clone(),
         suggestions: validation_result.suggestions.clone(),
+        profile: config.profile().name().to_string(),
+        failed_rules: validation_result.failed_rules.clone(),
     };
 
     let validation_status = if validation_result.is_valid {
@@ -68,6 +79,10 @@ pub async fn run(input: Input) -> Result<FoundryResponse<ValidateContentResponse
     let mut workflow_hints = vec![
         "Use this command to pre-validate content before project operations".to_string(),
         "Validation helps ensure content meets Foundry's structural requirements".to_string(),
+        format!(
+            "Validated with the '{}' profile; 'failed_rules' names the rule behind each error (profiles and custom rules are set under [validation] in config.toml)",
+            config.profile().name()
+        ),
     ];
 
     match input.content_type.as_str() {
@@ -98,26 +113,28 @@ pub async fn run(input: Input) -> Result<FoundryResponse<ValidateContentResponse
         next_steps,
         validation_status,
         workflow_hints,
+        diagnostics: None,
     })
 }
 
 fn validate_input_args(content_type: &str, content: &str) -> Result<()> {
     if content_type.trim().is_empty() {
-        return Err(anyhow::anyhow!(
-            "Content type cannot be empty. Supported types: vision, tech-stack, summary, spec, notes, tasks"
-        ));
+        return Err(ErrorCode::InvalidParams.error("Content type cannot be empty. Supported types: vision, tech-stack, summary, spec, notes, tasks"));
     }
     const MAX_VALIDATION_SIZE: usize = 100_000;
     if content.len() > MAX_VALIDATION_SIZE {
-        return Err(anyhow::anyhow!(
+        return Err(ErrorCode::ValidationFailed.error_with_rule(
+            "content_size",
+            format!(
             "Content too large for validation ({} characters). Maximum size for validation is {} characters.",
             content.len(),
             MAX_VALIDATION_SIZE
-        ));
+        )));
     }
     if content.contains('\0') {
-        return Err(anyhow::anyhow!(
-            "Content appears to contain binary data. Only text content can be validated."
+        return Err(ErrorCode::ValidationFailed.error_with_rule(
+            "binary_content",
+            "Content appears to contain binary data. Only text content can be validated.",
         ));
     }
     Ok(())
@@ -90,6 +90,22 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_list_projects_skips_dot_directories() {
+        let env = TestEnvironment::new().unwrap();
+
+        env.with_env_async(|| async {
+            env.create_test_project("visible-project").await.unwrap();
+            let foundry_dir = crate::core::filesystem::foundry_dir().unwrap();
+            std::fs::create_dir_all(foundry_dir.join(".logs")).unwrap();
+
+            let foundry = get_default_foundry().unwrap();
+            let projects = foundry.list_projects().await.unwrap();
+            let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+            assert_eq!(names, vec!["visible-project"]);
+        });
+    }
+
     #[test]
     fn test_load_project_rfc3339_timestamps() {
         let env = TestEnvironment::new().unwrap();
@@ -0,0 +1,489 @@
+//! Repository scanning for analyze_project
+//!
+//! Walks a local checkout and reports what is actually there: languages by
+//! file extension, dependencies from manifest files (Cargo.toml, package.json,
+//! pyproject.toml, requirements.txt, go.mod, Gemfile), CI configuration and
+//! container files. The result seeds the tech-stack draft, so the model starts
+//! from the repository instead of guessing.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{LanguageStat, ManifestInfo, RepoScan};
+
+/// Files visited before the walk stops; large monorepos still get a skeleton
+pub const MAX_FILES: usize = 20_000;
+
+/// Dependencies listed per manifest in the skeleton
+const MAX_LISTED_DEPENDENCIES: usize = 15;
+
+/// Directories holding build output, vendored code or environments
+const SKIPPED_DIRS: &[&str] = &[
+    "target",
+    "node_modules",
+    "vendor",
+    "dist",
+    "build",
+    "out",
+    "__pycache__",
+    "venv",
+    "env",
+];
+
+/// Hidden directories that hold CI configuration
+const HIDDEN_CI_DIRS: &[&str] = &[".github", ".circleci", ".gitlab"];
+
+/// Scan the repository at `root`
+pub fn scan(root: &Path) -> Result<RepoScan> {
+    if !root.is_dir() {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Repository path {:?} does not exist or is not a directory",
+            root
+        )));
+    }
+
+    let mut files = Vec::new();
+    let truncated = walk(root, root, &mut files)?;
+
+    let mut languages: BTreeMap<&'static str, usize> = BTreeMap::new();
+    let mut manifests = Vec::new();
+    let mut ci = Vec::new();
+    let mut containers = Vec::new();
+
+    for relative in &files {
+        let display = relative.to_string_lossy().replace('\\', "/");
+        let file_name = relative
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        if let Some(language) = relative
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(language_for_extension)
+        {
+            *languages.entry(language).or_default() += 1;
+        }
+        if let Some(system) = ci_system(&display) {
+            ci.push(format!("{} ({})", system, display));
+        }
+        if is_container_file(&file_name) {
+            containers.push(display.clone());
+        }
+        if let Some(kind) = manifest_kind(&file_name) {
+            let content = std::fs::read_to_string(root.join(relative)).unwrap_or_default();
+            manifests.push(parse_manifest(kind, display, &content));
+        }
+    }
+
+    let mut languages: Vec<LanguageStat> = languages
+        .into_iter()
+        .map(|(language, files)| LanguageStat {
+            language: language.to_string(),
+            files,
+        })
+        .collect();
+    languages.sort_by(|a, b| b.files.cmp(&a.files).then(a.language.cmp(&b.language)));
+
+    let mut scan = RepoScan {
+        root: root.display().to_string(),
+        files_scanned: files.len(),
+        truncated,
+        languages,
+        manifests,
+        ci,
+        containers,
+        tech_stack_skeleton: String::new(),
+    };
+    scan.tech_stack_skeleton = skeleton(&scan);
+    Ok(scan)
+}
+
+/// Collect file paths relative to `root`; true when `MAX_FILES` was hit
+fn walk(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> Result<bool> {
+    let mut entries: Vec<_> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory {:?}", dir))?
+        .filter_map(|entry| entry.ok())
+        .collect();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            let hidden = name.starts_with('.') && !HIDDEN_CI_DIRS.contains(&name.as_str());
+            if hidden || SKIPPED_DIRS.contains(&name.as_str()) {
+                continue;
+            }
+            if walk(root, &entry.path(), files)? {
+                return Ok(true);
+            }
+        } else if file_type.is_file() {
+            if files.len() >= MAX_FILES {
+                return Ok(true);
+            }
+            if let Ok(relative) = entry.path().strip_prefix(root) {
+                files.push(relative.to_path_buf());
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn language_for_extension(extension: &str) -> Option<&'static str> {
+    Some(match extension.to_ascii_lowercase().as_str() {
+        "rs" => "Rust",
+        "ts" | "tsx" | "mts" | "cts" => "TypeScript",
+        "js" | "jsx" | "mjs" | "cjs" => "JavaScript",
+        "py" => "Python",
+        "go" => "Go",
+        "rb" => "Ruby",
+        "java" => "Java",
+        "kt" | "kts" => "Kotlin",
+        "swift" => "Swift",
+        "c" | "h" => "C",
+        "cc" | "cpp" | "cxx" | "hpp" | "hh" => "C++",
+        "cs" => "C#",
+        "php" => "PHP",
+        "scala" => "Scala",
+        "ex" | "exs" => "Elixir",
+        "erl" => "Erlang",
+        "hs" => "Haskell",
+        "dart" => "Dart",
+        "lua" => "Lua",
+        "sh" | "bash" | "zsh" => "Shell",
+        "sql" => "SQL",
+        "vue" => "Vue",
+        "svelte" => "Svelte",
+        "zig" => "Zig",
+        _ => return None,
+    })
+}
+
+fn ci_system(path: &str) -> Option<&'static str> {
+    let is_yaml = path.ends_with(".yml") || path.ends_with(".yaml");
+    if path.starts_with(".github/workflows/") && is_yaml {
+        Some("GitHub Actions")
+    } else if path == ".gitlab-ci.yml" {
+        Some("GitLab CI")
+    } else if path == ".circleci/config.yml" {
+        Some("CircleCI")
+    } else if path == "Jenkinsfile" {
+        Some("Jenkins")
+    } else if path == "azure-pipelines.yml" {
+        Some("Azure Pipelines")
+    } else if path == "bitbucket-pipelines.yml" {
+        Some("Bitbucket Pipelines")
+    } else {
+        None
+    }
+}
+
+fn is_container_file(file_name: &str) -> bool {
+    file_name == "Dockerfile"
+        || file_name.starts_with("Dockerfile.")
+        || file_name.ends_with(".Dockerfile")
+        || matches!(
+            file_name,
+            "docker-compose.yml" | "docker-compose.yaml" | "compose.yml" | "compose.yaml"
+        )
+}
+
+fn manifest_kind(file_name: &str) -> Option<&'static str> {
+    Some(match file_name {
+        "Cargo.toml" => "cargo",
+        "package.json" => "npm",
+        "pyproject.toml" => "python",
+        "requirements.txt" => "pip",
+        "go.mod" => "go",
+        "Gemfile" => "bundler",
+        "pom.xml" => "maven",
+        "build.gradle" | "build.gradle.kts" => "gradle",
+        _ => return None,
+    })
+}
+
+/// Name and dependency names from a manifest; unparseable files list none
+fn parse_manifest(kind: &str, path: String, content: &str) -> ManifestInfo {
+    let (name, mut dependencies) = match kind {
+        "cargo" => parse_cargo(content),
+        "npm" => parse_package_json(content),
+        "python" => parse_pyproject(content),
+        "pip" => (None, parse_requirements(content)),
+        "go" => parse_go_mod(content),
+        "bundler" => (None, parse_gemfile(content)),
+        _ => (None, Vec::new()),
+    };
+    dependencies.sort();
+    dependencies.dedup();
+    ManifestInfo {
+        path,
+        kind: kind.to_string(),
+        name,
+        dependencies,
+    }
+}
+
+fn parse_cargo(content: &str) -> (Option<String>, Vec<String>) {
+    let Ok(manifest) = content.parse::<toml::Table>() else {
+        return (None, Vec::new());
+    };
+    let name = manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        .map(str::to_string);
+    let workspace = manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("dependencies"));
+    let dependencies = [manifest.get("dependencies"), workspace]
+        .into_iter()
+        .flatten()
+        .filter_map(|table| table.as_table())
+        .flat_map(|table| table.keys().cloned())
+        .collect();
+    (name, dependencies)
+}
+
+fn parse_package_json(content: &str) -> (Option<String>, Vec<String>) {
+    let Ok(manifest) = serde_json::from_str::<serde_json::Value>(content) else {
+        return (None, Vec::new());
+    };
+    let name = manifest["name"].as_str().map(str::to_string);
+    let dependencies = ["dependencies", "devDependencies"]
+        .iter()
+        .filter_map(|section| manifest[*section].as_object())
+        .flat_map(|deps| deps.keys().cloned())
+        .collect();
+    (name, dependencies)
+}
+
+fn parse_pyproject(content: &str) -> (Option<String>, Vec<String>) {
+    let Ok(manifest) = content.parse::<toml::Table>() else {
+        return (None, Vec::new());
+    };
+    let project = manifest.get("project");
+    let name = project
+        .and_then(|project| project.get("name"))
+        .and_then(|name| name.as_str())
+        .map(str::to_string);
+    let mut dependencies: Vec<String> = project
+        .and_then(|project| project.get("dependencies"))
+        .and_then(|deps| deps.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|dep| dep.as_str())
+        .filter_map(requirement_name)
+        .collect();
+    // Poetry keeps dependencies in a table keyed by name
+    if let Some(poetry) = manifest
+        .get("tool")
+        .and_then(|tool| tool.get("poetry"))
+        .and_then(|poetry| poetry.get("dependencies"))
+        .and_then(|deps| deps.as_table())
+    {
+        dependencies.extend(poetry.keys().filter(|key| *key != "python").cloned());
+    }
+    (name, dependencies)
+}
+
+fn parse_requirements(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('-'))
+        .filter_map(requirement_name)
+        .collect()
+}
+
+/// Package name from a PEP 508 requirement such as `requests>=2.0; python_version > "3"`
+fn requirement_name(requirement: &str) -> Option<String> {
+    let name: String = requirement
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+fn parse_go_mod(content: &str) -> (Option<String>, Vec<String>) {
+    let mut name = None;
+    let mut dependencies = Vec::new();
+    let mut in_require_block = false;
+    for line in content.lines().map(str::trim) {
+        if let Some(module) = line.strip_prefix("module ") {
+            name = Some(module.trim().to_string());
+        } else if line == "require (" {
+            in_require_block = true;
+        } else if in_require_block && line == ")" {
+            in_require_block = false;
+        } else if let Some(require) = line.strip_prefix("require ") {
+            dependencies.extend(require.split_whitespace().next().map(str::to_string));
+        } else if in_require_block && !line.is_empty() && !line.starts_with("//") {
+            dependencies.extend(line.split_whitespace().next().map(str::to_string));
+        }
+    }
+    (name, dependencies)
+}
+
+fn parse_gemfile(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("gem "))
+        .filter_map(|rest| {
+            rest.trim()
+                .trim_start_matches(['"', '\''])
+                .split(['"', '\''])
+                .next()
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+/// Markdown tech-stack outline built from what the scan found
+fn skeleton(scan: &RepoScan) -> String {
+    let mut out = String::from("## Languages\n");
+    if scan.languages.is_empty() {
+        out.push_str("- None detected\n");
+    }
+    for language in &scan.languages {
+        out.push_str(&format!(
+            "- {} ({} file{})\n",
+            language.language,
+            language.files,
+            if language.files == 1 { "" } else { "s" }
+        ));
+    }
+
+    if !scan.manifests.is_empty() {
+        out.push_str("\n## Dependencies\n");
+        for manifest in &scan.manifests {
+            let title = manifest.name.as_ref().map_or_else(
+                || format!("\n### {} ({})\n", manifest.path, manifest.kind),
+                |name| format!("\n### {} — {} ({})\n", manifest.path, name, manifest.kind),
+            );
+            out.push_str(&title);
+            if manifest.dependencies.is_empty() {
+                out.push_str("- No dependencies listed\n");
+                continue;
+            }
+            let listed =
+                &manifest.dependencies[..manifest.dependencies.len().min(MAX_LISTED_DEPENDENCIES)];
+            out.push_str(&format!("- {}", listed.join(", ")));
+            let rest = manifest.dependencies.len() - listed.len();
+            if rest > 0 {
+                out.push_str(&format!(" and {} more", rest));
+            }
+            out.push('\n');
+        }
+    }
+
+    if !scan.ci.is_empty() {
+        out.push_str("\n## CI\n");
+        for ci in &scan.ci {
+            out.push_str(&format!("- {}\n", ci));
+        }
+    }
+
+    if !scan.containers.is_empty() {
+        out.push_str("\n## Deployment\n");
+        for container in &scan.containers {
+            out.push_str(&format!("- {}\n", container));
+        }
+    }
+
+    if scan.truncated {
+        out.push_str(&format!(
+            "\n_Scan stopped after {} files; counts are partial._\n",
+            MAX_FILES
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_scan_detects_languages_manifests_ci_and_containers() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "Cargo.toml",
+            "[package]\nname = \"demo\"\n\n[dependencies]\ntokio = \"1\"\nserde = { version = \"1\" }\n",
+        );
+        write(root, "src/main.rs", "fn main() {}");
+        write(root, "src/lib.rs", "");
+        write(
+            root,
+            "web/package.json",
+            r#"{"name": "demo-web", "dependencies": {"react": "^18"}, "devDependencies": {"vite": "^5"}}"#,
+        );
+        write(root, "web/app.tsx", "");
+        write(root, "web/node_modules/react/index.js", "");
+        write(root, "target/debug/build.rs", "");
+        write(root, ".git/config", "");
+        write(root, ".github/workflows/ci.yml", "on: push");
+        write(root, "Dockerfile", "FROM rust");
+        write(
+            root,
+            "requirements.txt",
+            "# tools\nrequests>=2.0\n-r dev.txt\nclick\n",
+        );
+
+        let scan = scan(root).unwrap();
+
+        assert_eq!(scan.languages[0].language, "Rust");
+        assert_eq!(scan.languages[0].files, 2);
+        assert!(
+            scan.languages
+                .iter()
+                .all(|language| language.language != "JavaScript")
+        );
+        let cargo = &scan.manifests[0];
+        assert_eq!(cargo.path, "Cargo.toml");
+        assert_eq!(cargo.name.as_deref(), Some("demo"));
+        assert_eq!(cargo.dependencies, vec!["serde", "tokio"]);
+        let pip = scan.manifests.iter().find(|m| m.kind == "pip").unwrap();
+        assert_eq!(pip.dependencies, vec!["click", "requests"]);
+        let npm = scan.manifests.iter().find(|m| m.kind == "npm").unwrap();
+        assert_eq!(npm.dependencies, vec!["react", "vite"]);
+        assert_eq!(scan.ci, vec!["GitHub Actions (.github/workflows/ci.yml)"]);
+        assert_eq!(scan.containers, vec!["Dockerfile"]);
+        assert!(!scan.truncated);
+        assert!(scan.tech_stack_skeleton.contains("- Rust (2 files)"));
+        assert!(scan.tech_stack_skeleton.contains("## CI"));
+    }
+
+    #[test]
+    fn test_parse_go_mod_and_gemfile() {
+        let (name, deps) = parse_go_mod(
+            "module example.com/app\n\ngo 1.22\n\nrequire (\n\tgithub.com/gin-gonic/gin v1.9.1\n\t// indirect\n)\nrequire golang.org/x/sync v0.6.0\n",
+        );
+        assert_eq!(name.as_deref(), Some("example.com/app"));
+        assert_eq!(deps, vec!["github.com/gin-gonic/gin", "golang.org/x/sync"]);
+
+        let gems =
+            parse_gemfile("source 'https://rubygems.org'\ngem 'rails', '~> 7.1'\ngem \"pg\"\n");
+        assert_eq!(gems, vec!["rails", "pg"]);
+    }
+
+    #[test]
+    fn test_scan_rejects_missing_directory() {
+        assert!(scan(Path::new("/nonexistent/repo")).is_err());
+    }
+}
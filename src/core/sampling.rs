@@ -0,0 +1,61 @@
+//! LLM sampling through the connected client
+//!
+//! Some ops ask the client's model to draft text (MCP sampling: the server
+//! sends `sampling/createMessage` and the client answers with a completion).
+//! Ops stay tool-agnostic: they ask the [`Sampler`] of the current call, which
+//! the MCP server provides while a call from a client that supports sampling
+//! runs. CLI and REST calls have none.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::types::errors::ErrorCode;
+
+/// A completion request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SamplingRequest {
+    pub system_prompt: String,
+    pub prompt: String,
+    pub max_tokens: u32,
+}
+
+/// The client's answer to a [`SamplingRequest`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SampledText {
+    pub text: String,
+    /// Model the client used, as reported by the client
+    pub model: Option<String>,
+}
+
+/// Something that can complete a prompt
+#[async_trait]
+pub trait Sampler: Send + Sync {
+    async fn sample(&self, request: SamplingRequest) -> Result<SampledText>;
+}
+
+tokio::task_local! {
+    static SAMPLER: Arc<dyn Sampler>;
+}
+
+/// Run `future` with `sampler` answering sampling requests
+pub async fn with_sampler<F: Future>(sampler: Arc<dyn Sampler>, future: F) -> F::Output {
+    SAMPLER.scope(sampler, future).await
+}
+
+/// The sampler of the current call, if the caller can sample
+pub fn current() -> Option<Arc<dyn Sampler>> {
+    SAMPLER.try_with(Arc::clone).ok()
+}
+
+/// Complete `request` with the current sampler
+pub async fn sample(request: SamplingRequest) -> Result<SampledText> {
+    let sampler = current().ok_or_else(|| {
+        ErrorCode::SamplingUnavailable.error(
+            "This tool drafts text with the client's model and needs an MCP client that supports sampling",
+        )
+    })?;
+    sampler.sample(request).await
+}
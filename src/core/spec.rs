@@ -7,6 +7,7 @@ use anyhow::{Context, Result};
 use std::path::PathBuf;
 
 use crate::core::foundry::get_default_foundry;
+use crate::types::errors::ErrorCode;
 use crate::types::spec::{
     ContentValidationStatus, Spec, SpecConfig, SpecFileType, SpecFilter, SpecMetadata,
     SpecValidationResult,
@@ -29,9 +30,21 @@ where
     rt.block_on(f)
 }
 
-/// Generate timestamped spec name
-pub fn generate_spec_name(feature_name: &str) -> String {
-    crate::core::foundry::Foundry::<crate::core::backends::filesystem::FilesystemBackend>::generate_spec_name(feature_name)
+/// Name for a new spec of `feature_name` in `project_name`, using the
+/// project's `[spec_names]` strategy and avoiding the `existing` spec names
+pub fn generate_spec_name(
+    project_name: &str,
+    feature_name: &str,
+    existing: &[String],
+) -> Result<String> {
+    let config = crate::core::config::for_project(project_name)?.spec_names;
+    crate::core::spec_name::generate(
+        config.strategy.unwrap_or_default(),
+        config.prefix.as_deref(),
+        feature_name,
+        existing,
+        chrono::Utc::now(),
+    )
 }
 
 /// Create a new spec
@@ -253,6 +266,27 @@ pub enum SpecMatchStrategy {
     None,
 }
 
+impl SpecMatchStrategy {
+    /// The matched spec name, or a coded error when `query` matched none or several
+    pub fn into_spec_name(self, project_name: &str, query: &str) -> Result<String> {
+        match self {
+            Self::Exact(name)
+            | Self::FeatureExact(name)
+            | Self::FeatureFuzzy(name)
+            | Self::NameFuzzy(name) => Ok(name),
+            Self::Multiple(candidates) => Err(ErrorCode::SelectorAmbiguous.error(format!(
+                "Multiple specs match '{}': {}. Please be more specific.",
+                query,
+                candidates.join(", ")
+            ))),
+            Self::None => Err(ErrorCode::SpecNotFound.error(format!(
+                "No spec found matching '{}' in project '{}'",
+                query, project_name
+            ))),
+        }
+    }
+}
+
 /// Find the best matching spec using fuzzy matching
 pub fn find_spec_match(project_name: &str, query: &str) -> Result<SpecMatchStrategy> {
     let foundry = get_default_foundry()?;
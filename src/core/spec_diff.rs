@@ -0,0 +1,192 @@
+//! Section-aware diffs between two versions of a spec
+//!
+//! Each file is split into markdown sections (see
+//! [`context_budget::split_sections`]) and sections are matched by title, so a
+//! diff can say "Requirements changed; task-list.md unchanged" instead of only
+//! listing lines. Sections sharing a title are matched in order.
+
+use std::collections::HashMap;
+
+use similar::{ChangeTag, TextDiff};
+
+use crate::core::context_budget;
+use crate::types::responses::{SectionChange, SectionDiff, SpecFileDiff};
+use crate::types::spec::SpecContentData;
+
+/// Unified diff of one file with `a/` and `b/` headers; empty when unchanged
+pub fn unified_diff(label: &str, before: &str, after: &str) -> String {
+    if before == after {
+        return String::new();
+    }
+    TextDiff::from_lines(before, after)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{}", label), &format!("b/{}", label))
+        .to_string()
+}
+
+/// Diff of spec.md, notes.md and task-list.md, in that order
+pub fn diff_content(before: &SpecContentData, after: &SpecContentData) -> Vec<SpecFileDiff> {
+    vec![
+        diff_file("spec.md", &before.spec, &after.spec),
+        diff_file("notes.md", &before.notes, &after.notes),
+        diff_file("task-list.md", &before.tasks, &after.tasks),
+    ]
+}
+
+/// Line counts and changed sections of one file
+pub fn diff_file(file: &str, before: &str, after: &str) -> SpecFileDiff {
+    let (mut lines_added, mut lines_removed) = (0, 0);
+    for change in TextDiff::from_lines(before, after).iter_all_changes() {
+        match change.tag() {
+            ChangeTag::Insert => lines_added += 1,
+            ChangeTag::Delete => lines_removed += 1,
+            ChangeTag::Equal => {}
+        }
+    }
+
+    let before_sections = keyed_sections(before);
+    let after_sections = keyed_sections(after);
+    let before_by_key: HashMap<&(String, usize), &String> = before_sections
+        .iter()
+        .map(|(key, text)| (key, text))
+        .collect();
+    let after_by_key: HashMap<&(String, usize), &String> = after_sections
+        .iter()
+        .map(|(key, text)| (key, text))
+        .collect();
+
+    let mut sections = Vec::new();
+    let mut unchanged_sections = Vec::new();
+    for (key, text) in &after_sections {
+        let change = match before_by_key.get(key) {
+            None => Some(SectionChange::Added),
+            Some(old) if *old != text => Some(SectionChange::Modified),
+            Some(_) => None,
+        };
+        match change {
+            Some(change) => sections.push(SectionDiff {
+                title: key.0.clone(),
+                change,
+            }),
+            None => unchanged_sections.push(key.0.clone()),
+        }
+    }
+    sections.extend(
+        before_sections
+            .iter()
+            .filter(|(key, _)| !after_by_key.contains_key(key))
+            .map(|(key, _)| SectionDiff {
+                title: key.0.clone(),
+                change: SectionChange::Removed,
+            }),
+    );
+
+    SpecFileDiff {
+        file: file.to_string(),
+        changed: before != after,
+        lines_added,
+        lines_removed,
+        sections,
+        unchanged_sections,
+    }
+}
+
+/// One line describing `files`, e.g. "spec.md: Requirements changed; notes.md
+/// and task-list.md unchanged"
+pub fn summary(files: &[SpecFileDiff]) -> String {
+    let mut parts: Vec<String> = files
+        .iter()
+        .filter(|file| file.changed)
+        .map(|file| {
+            if file.sections.is_empty() {
+                return format!("{}: whitespace or section order changed", file.file);
+            }
+            let sections: Vec<String> = file
+                .sections
+                .iter()
+                .map(|section| {
+                    let verb = match section.change {
+                        SectionChange::Added => "added",
+                        SectionChange::Removed => "removed",
+                        SectionChange::Modified => "changed",
+                    };
+                    format!("{} {}", section.title, verb)
+                })
+                .collect();
+            format!("{}: {}", file.file, sections.join(", "))
+        })
+        .collect();
+    if parts.is_empty() {
+        return "No changes".to_string();
+    }
+
+    let unchanged: Vec<&str> = files
+        .iter()
+        .filter(|file| !file.changed)
+        .map(|file| file.file.as_str())
+        .collect();
+    match unchanged.as_slice() {
+        [] => {}
+        [only] => parts.push(format!("{} unchanged", only)),
+        [rest @ .., last] => parts.push(format!("{} and {} unchanged", rest.join(", "), last)),
+    }
+    parts.join("; ")
+}
+
+/// Sections keyed by title and how many earlier sections share that title
+fn keyed_sections(content: &str) -> Vec<((String, usize), String)> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    context_budget::split_sections(content)
+        .into_iter()
+        .map(|(title, text)| {
+            let count = seen.entry(title.clone()).or_default();
+            let key = (title, *count);
+            *count += 1;
+            (key, text)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_reports_sections_by_title() {
+        let before = SpecContentData {
+            spec: "# Auth\n\n## Requirements\n\n- Login\n\n## Goals\n\n- Fast".to_string(),
+            notes: "## Context\n\nLegacy flow".to_string(),
+            tasks: "## Phase 1\n\n- [ ] Build".to_string(),
+        };
+        let after = SpecContentData {
+            spec: "# Auth\n\n## Requirements\n\n- Login\n- Logout\n\n## Risks\n\n- None"
+                .to_string(),
+            ..before.clone()
+        };
+
+        let files = diff_content(&before, &after);
+        let spec = &files[0];
+        assert!(spec.changed);
+        assert_eq!((spec.lines_added, spec.lines_removed), (3, 2));
+        let changes: Vec<(&str, SectionChange)> = spec
+            .sections
+            .iter()
+            .map(|section| (section.title.as_str(), section.change))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("Requirements", SectionChange::Modified),
+                ("Risks", SectionChange::Added),
+                ("Goals", SectionChange::Removed),
+            ]
+        );
+        assert_eq!(spec.unchanged_sections, ["Auth"]);
+        assert_eq!(
+            summary(&files),
+            "spec.md: Requirements changed, Risks added, Goals removed; notes.md and task-list.md unchanged"
+        );
+        assert_eq!(summary(&diff_content(&before, &before)), "No changes");
+    }
+}
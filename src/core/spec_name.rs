@@ -0,0 +1,314 @@
+//! Spec names: `<id>_<feature_name>` with a configurable id
+//!
+//! `[spec_names] strategy` in `config.toml` or a project's `.foundry.toml`
+//! picks how new spec ids are made:
+//!
+//! | strategy    | example                              |
+//! |-------------|--------------------------------------|
+//! | `timestamp` | `20250601_143000_user_auth` (default) |
+//! | `date`      | `20250601_user_auth`                 |
+//! | `sequential`| `0007_user_auth`                     |
+//! | `ulid`      | `01JX2ZB5C8Q7R0V3M9K4T6W1YD_user_auth` |
+//! | `prefix`    | `RFC-0007_user_auth` (`prefix = "RFC"`) |
+//!
+//! Parsing does not depend on the configured strategy, so specs named under
+//! an earlier strategy keep listing, loading and matching after a change.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Deserialize;
+
+use crate::types::errors::ErrorCode;
+use crate::utils::timestamp;
+
+/// Digits of sequential and prefixed ids, zero-padded
+const SEQUENCE_WIDTH: usize = 4;
+
+/// Crockford base32, the ULID alphabet
+const ULID_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+const ULID_LENGTH: usize = 26;
+
+/// How the id of a new spec name is made
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpecNameStrategy {
+    /// `YYYYMMDD_HHMMSS`, the creation time in UTC
+    #[default]
+    Timestamp,
+    /// `YYYYMMDD`, the creation date in UTC
+    Date,
+    /// `0001`, one more than the highest number in the project
+    Sequential,
+    /// A ULID: sortable by creation time and unique without coordination
+    Ulid,
+    /// `<prefix>-0001`, numbered like `sequential` per prefix
+    Prefix,
+}
+
+/// Kind of id a spec name starts with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecIdKind {
+    Timestamp,
+    Date,
+    Sequential,
+    Ulid,
+    Prefixed,
+}
+
+/// A spec name split into its id and feature name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedSpecName {
+    pub id: String,
+    pub kind: SpecIdKind,
+    pub feature_name: String,
+}
+
+impl ParsedSpecName {
+    /// Creation time the id records (RFC 3339); `None` for numbered ids
+    pub fn created_at(&self) -> Option<String> {
+        match self.kind {
+            SpecIdKind::Timestamp => timestamp::spec_timestamp_to_iso(&self.id).ok(),
+            SpecIdKind::Date => NaiveDate::parse_from_str(&self.id, "%Y%m%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|start| start.and_utc().to_rfc3339()),
+            SpecIdKind::Ulid => ulid_time(&self.id).map(|at| at.to_rfc3339()),
+            SpecIdKind::Sequential | SpecIdKind::Prefixed => None,
+        }
+    }
+
+    /// Number of a sequential or prefixed id
+    pub fn number(&self) -> Option<u64> {
+        match self.kind {
+            SpecIdKind::Sequential => self.id.parse().ok(),
+            SpecIdKind::Prefixed => self.id.rsplit_once('-')?.1.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Prefix of a prefixed id
+    pub fn prefix(&self) -> Option<&str> {
+        match self.kind {
+            SpecIdKind::Prefixed => self.id.rsplit_once('-').map(|(prefix, _)| prefix),
+            _ => None,
+        }
+    }
+}
+
+/// Split `spec_name` into id and feature name, whatever strategy named it
+pub fn parse(spec_name: &str) -> Option<ParsedSpecName> {
+    let parsed = |id: &str, kind: SpecIdKind, feature_name: &str| {
+        (!feature_name.is_empty()).then(|| ParsedSpecName {
+            id: id.to_string(),
+            kind,
+            feature_name: feature_name.to_string(),
+        })
+    };
+
+    if let Some(stamp) = timestamp::parse_spec_timestamp(spec_name) {
+        return parsed(&stamp, SpecIdKind::Timestamp, &spec_name[stamp.len() + 1..]);
+    }
+    let (id, feature_name) = spec_name.split_once('_')?;
+    if id.len() == 8 && NaiveDate::parse_from_str(id, "%Y%m%d").is_ok() {
+        return parsed(id, SpecIdKind::Date, feature_name);
+    }
+    if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) {
+        return parsed(id, SpecIdKind::Sequential, feature_name);
+    }
+    if is_ulid(id) {
+        return parsed(id, SpecIdKind::Ulid, feature_name);
+    }
+    let (prefix, number) = id.rsplit_once('-')?;
+    if valid_prefix(prefix) && !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+        return parsed(id, SpecIdKind::Prefixed, feature_name);
+    }
+    None
+}
+
+/// Whether `prefix` can start a prefixed id: a letter, then letters and digits
+pub fn valid_prefix(prefix: &str) -> bool {
+    prefix.starts_with(|c: char| c.is_ascii_alphabetic())
+        && prefix.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Name for a new spec of `feature_name` that none of `existing` uses
+///
+/// Timestamps move forward a second and ULIDs are redrawn until the name is
+/// free; a date name already taken is an error, since only the feature name
+/// could tell them apart.
+pub fn generate(
+    strategy: SpecNameStrategy,
+    prefix: Option<&str>,
+    feature_name: &str,
+    existing: &[String],
+    now: DateTime<Utc>,
+) -> Result<String> {
+    let taken = |name: &String| existing.contains(name);
+    let name = match strategy {
+        SpecNameStrategy::Timestamp => {
+            let mut at = now;
+            loop {
+                let name = format!("{}_{}", at.format("%Y%m%d_%H%M%S"), feature_name);
+                if !taken(&name) {
+                    break name;
+                }
+                at += Duration::seconds(1);
+            }
+        }
+        SpecNameStrategy::Date => {
+            let name = format!("{}_{}", now.format("%Y%m%d"), feature_name);
+            if taken(&name) {
+                return Err(ErrorCode::AlreadyExists.error(format!(
+                    "Spec '{}' already exists; spec names are dated by day, so use another feature name",
+                    name
+                )));
+            }
+            name
+        }
+        SpecNameStrategy::Sequential => {
+            let next = next_number(existing, |parsed| parsed.kind == SpecIdKind::Sequential);
+            format!("{:0width$}_{}", next, feature_name, width = SEQUENCE_WIDTH)
+        }
+        SpecNameStrategy::Ulid => loop {
+            let name = format!("{}_{}", ulid(now)?, feature_name);
+            if !taken(&name) {
+                break name;
+            }
+        },
+        SpecNameStrategy::Prefix => {
+            let prefix = prefix.filter(|prefix| valid_prefix(prefix)).ok_or_else(|| {
+                ErrorCode::InvalidParams.error(
+                    "The prefix spec name strategy needs [spec_names] prefix, a letter followed by letters or digits",
+                )
+            })?;
+            let next = next_number(existing, |parsed| parsed.prefix() == Some(prefix));
+            format!(
+                "{}-{:0width$}_{}",
+                prefix,
+                next,
+                feature_name,
+                width = SEQUENCE_WIDTH
+            )
+        }
+    };
+    Ok(name)
+}
+
+/// One more than the highest number among `existing` names `counts` accepts
+fn next_number(existing: &[String], counts: impl Fn(&ParsedSpecName) -> bool) -> u64 {
+    existing
+        .iter()
+        .filter_map(|name| parse(name))
+        .filter(|parsed| counts(parsed))
+        .filter_map(|parsed| parsed.number())
+        .max()
+        .unwrap_or(0)
+        + 1
+}
+
+/// A ULID for `now`: 48 bits of milliseconds, then 80 random bits
+fn ulid(now: DateTime<Utc>) -> Result<String> {
+    let mut random = [0u8; 10];
+    ring::rand::SecureRandom::fill(&ring::rand::SystemRandom::new(), &mut random)
+        .map_err(|_| anyhow::anyhow!("Failed to generate random bits for a ULID"))?;
+    let mut value = u128::from(now.timestamp_millis().max(0) as u64) << 80;
+    for (index, byte) in random.iter().enumerate() {
+        value |= u128::from(*byte) << (8 * (9 - index));
+    }
+    Ok((0..ULID_LENGTH)
+        .rev()
+        .map(|position| ULID_ALPHABET[((value >> (5 * position)) & 0x1f) as usize] as char)
+        .collect())
+}
+
+fn is_ulid(id: &str) -> bool {
+    id.len() == ULID_LENGTH
+        && id.starts_with(|c: char| ('0'..='7').contains(&c))
+        && id.bytes().all(|b| ULID_ALPHABET.contains(&b))
+}
+
+/// Creation time of a ULID, from its first ten characters
+fn ulid_time(id: &str) -> Option<DateTime<Utc>> {
+    let millis = id[..10].bytes().try_fold(0i64, |value, byte| {
+        let digit = ULID_ALPHABET.iter().position(|c| *c == byte)?;
+        Some(value * 32 + digit as i64)
+    })?;
+    DateTime::from_timestamp_millis(millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_every_strategy_round_trips_through_parse() {
+        let now = at("2025-06-01T14:30:00Z");
+        let existing = vec![
+            "20250601_143000_auth".to_string(),
+            "0002_billing".to_string(),
+            "RFC-0009_search".to_string(),
+            "ADR-0040_storage".to_string(),
+        ];
+        let generate = |strategy, prefix| generate(strategy, prefix, "auth", &existing, now);
+
+        let name = generate(SpecNameStrategy::Timestamp, None).unwrap();
+        assert_eq!(name, "20250601_143001_auth");
+        assert_eq!(
+            parse(&name).unwrap().created_at().unwrap(),
+            "2025-06-01T14:30:01+00:00"
+        );
+
+        let name = generate(SpecNameStrategy::Date, None).unwrap();
+        assert_eq!(name, "20250601_auth");
+        assert_eq!(parse(&name).unwrap().kind, SpecIdKind::Date);
+
+        assert_eq!(
+            generate(SpecNameStrategy::Sequential, None).unwrap(),
+            "0003_auth"
+        );
+        assert_eq!(
+            generate(SpecNameStrategy::Prefix, Some("RFC")).unwrap(),
+            "RFC-0010_auth"
+        );
+        assert_eq!(
+            generate(SpecNameStrategy::Prefix, Some("OPS")).unwrap(),
+            "OPS-0001_auth"
+        );
+        assert!(generate(SpecNameStrategy::Prefix, None).is_err());
+
+        let name = generate(SpecNameStrategy::Ulid, None).unwrap();
+        let parsed = parse(&name).unwrap();
+        assert_eq!(parsed.kind, SpecIdKind::Ulid);
+        assert_eq!(parsed.feature_name, "auth");
+        assert_eq!(parsed.created_at().unwrap(), "2025-06-01T14:30:00+00:00");
+    }
+
+    #[test]
+    fn test_date_collisions_and_malformed_names() {
+        let now = at("2025-06-01T14:30:00Z");
+        let existing = vec!["20250601_auth".to_string()];
+        let error = generate(SpecNameStrategy::Date, None, "auth", &existing, now).unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::AlreadyExists);
+
+        let parsed = parse("RFC-0012_user_auth").unwrap();
+        assert_eq!(
+            (
+                parsed.prefix(),
+                parsed.number(),
+                parsed.feature_name.as_str()
+            ),
+            (Some("RFC"), Some(12), "user_auth")
+        );
+        assert_eq!(parse("0001_").map(|p| p.kind), None);
+        assert_eq!(parse("auth"), None);
+        assert_eq!(parse("my_auth"), None);
+        assert_eq!(parse("-0001_auth"), None);
+    }
+}
@@ -0,0 +1,106 @@
+//! Tags on projects and specs
+//!
+//! Tags are free-form labels such as `area:backend` or `quarter:Q3`, stored in
+//! project and spec metadata. They keep the case they were added with but
+//! compare case-insensitively, so `Area:Backend` and `area:backend` are the
+//! same tag.
+
+use anyhow::Result;
+
+use crate::types::errors::ErrorCode;
+
+/// Longest tag accepted
+pub const MAX_TAG_LENGTH: usize = 50;
+
+/// Most tags one project or spec may carry
+pub const MAX_TAGS: usize = 20;
+
+/// `tag` trimmed, or an error when it cannot be stored
+pub fn normalize(tag: &str) -> Result<String> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err(ErrorCode::InvalidParams.error("Tags cannot be empty"));
+    }
+    if tag.chars().count() > MAX_TAG_LENGTH {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Tag '{}' is longer than {} characters",
+            tag, MAX_TAG_LENGTH
+        )));
+    }
+    if tag.chars().any(|c| c.is_whitespace() || c == ',') {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Tag '{}' cannot contain spaces or commas; use e.g. 'area:backend'",
+            tag
+        )));
+    }
+    Ok(tag.to_string())
+}
+
+/// Whether `tags` holds `tag`, ignoring case
+pub fn contains(tags: &[String], tag: &str) -> bool {
+    tags.iter()
+        .any(|existing| existing.eq_ignore_ascii_case(tag))
+}
+
+/// Whether `tags` holds every tag in `wanted`
+pub fn matches_all(tags: &[String], wanted: &[String]) -> bool {
+    wanted.iter().all(|tag| contains(tags, tag.trim()))
+}
+
+/// `current` with `added` appended, and the tags that were new
+pub fn add(current: &[String], added: &[String]) -> Result<(Vec<String>, Vec<String>)> {
+    let mut tags = current.to_vec();
+    let mut changed = Vec::new();
+    for tag in added {
+        let tag = normalize(tag)?;
+        if !contains(&tags, &tag) {
+            tags.push(tag.clone());
+            changed.push(tag);
+        }
+    }
+    if tags.len() > MAX_TAGS {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "At most {} tags are allowed; remove some with remove_tag first",
+            MAX_TAGS
+        )));
+    }
+    Ok((tags, changed))
+}
+
+/// `current` without `removed`, and the tags that were there
+pub fn remove(current: &[String], removed: &[String]) -> (Vec<String>, Vec<String>) {
+    let (changed, tags) = current
+        .iter()
+        .cloned()
+        .partition(|tag| contains(removed, tag));
+    (tags, changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_add_and_remove_ignore_case_and_duplicates() {
+        let (current, added) = add(
+            &tags(&["area:backend"]),
+            &tags(&[" quarter:Q3 ", "Area:Backend"]),
+        )
+        .unwrap();
+        assert_eq!(current, tags(&["area:backend", "quarter:Q3"]));
+        assert_eq!(added, tags(&["quarter:Q3"]));
+        assert!(matches_all(&current, &tags(&["QUARTER:q3"])));
+        assert!(!matches_all(&current, &tags(&["quarter:Q3", "team:web"])));
+
+        let (current, removed) = remove(&current, &tags(&["AREA:backend", "missing"]));
+        assert_eq!(current, tags(&["quarter:Q3"]));
+        assert_eq!(removed, tags(&["area:backend"]));
+
+        assert!(add(&current, &tags(&["two words"])).is_err());
+        assert!(add(&current, &tags(&[""])).is_err());
+    }
+}
@@ -0,0 +1,429 @@
+//! Task list parsing for task-list.md content
+//!
+//! Reads markdown checklists into structured entries so ops can report on
+//! tasks without re-implementing checkbox parsing.
+//!
+//! Task lines may carry inline planning metadata, kept in the line text:
+//! `@owner` (assignee, repeatable), `~3d` (estimate: a number plus `m`, `h`,
+//! `d` or `w`), `due:2025-07-01` and `completed:2025-06-01`. Edit commands
+//! that check a task off stamp `completed:` with the current date, and drop it
+//! again when the task is reopened.
+//!
+//! ```markdown
+//! - [ ] Add login endpoint @alice ~2d due:2025-07-01
+//! ```
+//!
+//! Large task lists can be split into phases: a heading whose text starts
+//! with "Phase" (`## Phase 1`, `## Phase 2: Rollout`) opens a phase that runs
+//! until the next heading of the same or a higher level, so deeper headings
+//! group tasks within it.
+
+use crate::core::timing::{self, Phase};
+use crate::types::spec::TaskCounts;
+
+/// A single checklist item from a task list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskEntry {
+    /// Nearest preceding markdown header (without leading '#'), empty if none
+    pub section: String,
+    /// Heading text of the phase the task is in, if any
+    pub phase: Option<String>,
+    /// Task text after the checkbox, with metadata tokens left intact
+    pub text: String,
+    pub done: bool,
+    /// Date from an inline `completed:YYYY-MM-DD` token, when present
+    pub completed_at: Option<String>,
+    /// Assignees, estimate and due date from inline tokens
+    pub metadata: TaskMetadata,
+}
+
+impl TaskEntry {
+    /// Status label used in reports ("done" or "todo")
+    pub fn status_label(&self) -> &'static str {
+        if self.done { "done" } else { "todo" }
+    }
+
+    /// Task text without metadata tokens
+    pub fn title(&self) -> String {
+        strip_metadata(&self.text)
+    }
+}
+
+/// Completion of one phase of a task list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseStats {
+    /// Heading text, e.g. "Phase 2: Rollout"
+    pub name: String,
+    pub total: usize,
+    pub done: usize,
+}
+
+/// Planning metadata from inline tokens on a task line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskMetadata {
+    /// Names from `@owner` tokens, in order
+    pub assignees: Vec<String>,
+    /// Estimate from a `~3d` token, as written
+    pub estimate: Option<String>,
+    /// Date from a `due:YYYY-MM-DD` token
+    pub due: Option<String>,
+}
+
+impl TaskMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.assignees.is_empty() && self.estimate.is_none() && self.due.is_none()
+    }
+
+    /// The metadata as tokens, in `@owner ~3d due:YYYY-MM-DD` order
+    pub fn tokens(&self) -> Vec<String> {
+        let mut tokens: Vec<String> = self
+            .assignees
+            .iter()
+            .map(|assignee| format!("@{}", assignee))
+            .collect();
+        tokens.extend(
+            self.estimate
+                .iter()
+                .map(|estimate| format!("~{}", estimate)),
+        );
+        tokens.extend(self.due.iter().map(|due| format!("due:{}", due)));
+        tokens
+    }
+}
+
+/// One recognized inline token
+enum MetadataToken<'a> {
+    Assignee(&'a str),
+    Estimate(&'a str),
+    Due(&'a str),
+    Completed,
+}
+
+fn metadata_token(word: &str) -> Option<MetadataToken<'_>> {
+    if let Some(name) = word.strip_prefix('@') {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        return valid.then_some(MetadataToken::Assignee(name));
+    }
+    if let Some(estimate) = word.strip_prefix('~') {
+        let number = estimate.strip_suffix(['m', 'h', 'd', 'w'])?;
+        return number
+            .parse::<f64>()
+            .is_ok_and(|value| value > 0.0)
+            .then_some(MetadataToken::Estimate(estimate));
+    }
+    if let Some(date) = word.strip_prefix("due:") {
+        return is_date(date).then_some(MetadataToken::Due(date));
+    }
+    word.strip_prefix("completed:")
+        .filter(|date| is_date(date))
+        .map(|_| MetadataToken::Completed)
+}
+
+fn is_date(value: &str) -> bool {
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+}
+
+/// Metadata from the `@owner`, `~3d` and `due:` tokens in `text`
+///
+/// The first estimate and due date win; malformed tokens are plain text.
+pub fn parse_metadata(text: &str) -> TaskMetadata {
+    let mut metadata = TaskMetadata::default();
+    for word in text.split_whitespace() {
+        match metadata_token(word) {
+            Some(MetadataToken::Assignee(name)) => {
+                if !metadata.assignees.iter().any(|known| known == name) {
+                    metadata.assignees.push(name.to_string());
+                }
+            }
+            Some(MetadataToken::Estimate(estimate)) => {
+                metadata
+                    .estimate
+                    .get_or_insert_with(|| estimate.to_string());
+            }
+            Some(MetadataToken::Due(date)) => {
+                metadata.due.get_or_insert_with(|| date.to_string());
+            }
+            Some(MetadataToken::Completed) | None => {}
+        }
+    }
+    metadata
+}
+
+/// `text` with every metadata token (including `completed:`) removed
+pub fn strip_metadata(text: &str) -> String {
+    text.split_whitespace()
+        .filter(|word| metadata_token(word).is_none())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The `completed:YYYY-MM-DD` token in `text`, if any
+pub fn completed_token(text: &str) -> Option<&str> {
+    text.split_whitespace()
+        .find(|word| matches!(metadata_token(word), Some(MetadataToken::Completed)))
+}
+
+/// Task `text` (after the checkbox) with its `completed:` token matching
+/// `done`: stamped with `today` when done and missing, removed when open
+pub fn with_completion(text: &str, done: bool, today: chrono::NaiveDate) -> String {
+    match (done, completed_token(text)) {
+        (true, None) => format!("{} completed:{}", text.trim_end(), today.format("%Y-%m-%d")),
+        (false, Some(token)) => text
+            .split_whitespace()
+            .filter(|word| *word != token)
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => text.to_string(),
+    }
+}
+
+/// Level and text of a markdown heading line
+pub fn heading(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    let level = trimmed.len() - trimmed.trim_start_matches('#').len();
+    (level > 0).then(|| (level, trimmed[level..].trim()))
+}
+
+/// Whether heading text names a phase ("Phase 1", "Phase 2: Rollout")
+pub fn is_phase_heading(text: &str) -> bool {
+    text.get(..5)
+        .is_some_and(|word| word.eq_ignore_ascii_case("phase"))
+        && text[5..]
+            .chars()
+            .next()
+            .is_none_or(|c| c.is_whitespace() || c == ':')
+}
+
+/// Whether `wanted` refers to the phase headed `phase`: its full text or the
+/// label before a colon ("Phase 2" for "Phase 2: Rollout"), ignoring case and
+/// leading '#'
+pub fn phase_matches(phase: &str, wanted: &str) -> bool {
+    let wanted = wanted.trim().trim_start_matches('#').trim();
+    let label = phase.split_once(':').map_or(phase, |(label, _)| label);
+    phase.eq_ignore_ascii_case(wanted) || label.trim().eq_ignore_ascii_case(wanted)
+}
+
+/// Phase headings in `content`, in order
+pub fn phases(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(heading)
+        .filter(|(_, text)| is_phase_heading(text))
+        .map(|(_, text)| text.to_string())
+        .collect()
+}
+
+/// Task counts per phase, in the order the phases appear; empty when the
+/// task list has no phases
+pub fn phase_stats(content: &str, tasks: &[TaskEntry]) -> Vec<PhaseStats> {
+    phases(content)
+        .into_iter()
+        .map(|name| {
+            let in_phase = || {
+                tasks
+                    .iter()
+                    .filter(|task| task.phase.as_ref() == Some(&name))
+            };
+            PhaseStats {
+                total: in_phase().count(),
+                done: in_phase().filter(|task| task.done).count(),
+                name,
+            }
+        })
+        .collect()
+}
+
+/// Total, completed and remaining checklist items of task list content
+pub fn count_tasks(content: &str) -> TaskCounts {
+    let tasks = parse_task_list(content);
+    let completed = tasks.iter().filter(|task| task.done).count();
+    TaskCounts {
+        total: tasks.len(),
+        completed,
+        remaining: tasks.len() - completed,
+    }
+}
+
+/// Parse all checklist items (`- [ ]`, `- [x]`, `* [ ]`) from task list content
+pub fn parse_task_list(content: &str) -> Vec<TaskEntry> {
+    let _parsing = timing::start(Phase::Parsing);
+    let mut section = String::new();
+    let mut phase: Option<(usize, String)> = None;
+    let mut tasks = Vec::new();
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if let Some((level, text)) = heading(trimmed) {
+            section = text.to_string();
+            if is_phase_heading(text) {
+                phase = Some((level, text.to_string()));
+            } else if phase
+                .as_ref()
+                .is_some_and(|(phase_level, _)| level <= *phase_level)
+            {
+                phase = None;
+            }
+            continue;
+        }
+        if let Some((done, text)) = parse_checkbox(trimmed) {
+            tasks.push(TaskEntry {
+                section: section.clone(),
+                phase: phase.as_ref().map(|(_, name)| name.clone()),
+                text: text.to_string(),
+                done,
+                completed_at: token_value(text, "completed:"),
+                metadata: parse_metadata(text),
+            });
+        }
+    }
+
+    tasks
+}
+
+/// Whether `line` is a checklist item, at any indentation
+pub fn is_task_line(line: &str) -> bool {
+    parse_checkbox(line.trim_start()).is_some()
+}
+
+/// Split a checklist line into (done, text)
+fn parse_checkbox(line: &str) -> Option<(bool, &str)> {
+    let rest = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))?;
+    let (done, text) = if let Some(text) = rest.strip_prefix("[ ]") {
+        (false, text)
+    } else if let Some(text) = rest
+        .strip_prefix("[x]")
+        .or_else(|| rest.strip_prefix("[X]"))
+    {
+        (true, text)
+    } else {
+        return None;
+    };
+    Some((done, text.trim()))
+}
+
+/// Find a whitespace-delimited `prefix<value>` token and return its value
+fn token_value(text: &str, prefix: &str) -> Option<String> {
+    text.split_whitespace()
+        .find_map(|word| word.strip_prefix(prefix))
+        .filter(|v| !v.is_empty())
+        .map(|v| v.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_task_list_tracks_sections_and_status() {
+        let content = "# Tasks\n\n## Setup\n- [ ] Create repo\n- [x] Pick name completed:2025-06-01\n\n## Build\n* [X] Write code\n- not a task\n";
+        let tasks = parse_task_list(content);
+
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].section, "Setup");
+        assert_eq!(tasks[0].text, "Create repo");
+        assert!(!tasks[0].done);
+        assert!(tasks[1].done);
+        assert_eq!(tasks[1].completed_at.as_deref(), Some("2025-06-01"));
+        assert_eq!(tasks[2].section, "Build");
+        assert_eq!(tasks[2].status_label(), "done");
+        assert_eq!(
+            count_tasks(content),
+            TaskCounts {
+                total: 3,
+                completed: 2,
+                remaining: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_task_list_reads_metadata_tokens() {
+        let tasks = parse_task_list(
+            "- [ ] Add login @alice @bob.smith ~2.5d due:2025-07-01\n- [ ] Email me@host ~soon due:tomorrow",
+        );
+
+        assert_eq!(
+            tasks[0].metadata,
+            TaskMetadata {
+                assignees: vec!["alice".to_string(), "bob.smith".to_string()],
+                estimate: Some("2.5d".to_string()),
+                due: Some("2025-07-01".to_string()),
+            }
+        );
+        assert_eq!(tasks[0].title(), "Add login");
+        assert_eq!(
+            tasks[0].metadata.tokens().join(" "),
+            "@alice @bob.smith ~2.5d due:2025-07-01"
+        );
+        assert!(tasks[1].metadata.is_empty());
+        assert_eq!(tasks[1].title(), "Email me@host ~soon due:tomorrow");
+    }
+
+    #[test]
+    fn test_parse_task_list_tracks_phases() {
+        let content = "# Tasks\n\n## Phase 1: Foundations\n- [x] Schema\n### Backend\n- [ ] API\n\n## Phase 2\n- [ ] Rollout\n\n## Follow-ups\n- [ ] Docs\n";
+        let tasks = parse_task_list(content);
+
+        assert_eq!(tasks[0].phase.as_deref(), Some("Phase 1: Foundations"));
+        assert_eq!(tasks[1].section, "Backend");
+        assert_eq!(tasks[1].phase.as_deref(), Some("Phase 1: Foundations"));
+        assert_eq!(tasks[2].phase.as_deref(), Some("Phase 2"));
+        assert_eq!(tasks[3].phase, None);
+        assert_eq!(
+            phase_stats(content, &tasks),
+            vec![
+                PhaseStats {
+                    name: "Phase 1: Foundations".to_string(),
+                    total: 2,
+                    done: 1
+                },
+                PhaseStats {
+                    name: "Phase 2".to_string(),
+                    total: 1,
+                    done: 0
+                },
+            ]
+        );
+
+        assert!(phase_matches("Phase 1: Foundations", "## phase 1"));
+        assert!(phase_matches(
+            "Phase 1: Foundations",
+            "Phase 1: Foundations"
+        ));
+        assert!(!phase_matches("Phase 10", "Phase 1"));
+        assert!(!is_phase_heading("Setup Phase"));
+        assert!(!is_phase_heading("Phases overview"));
+    }
+
+    #[test]
+    fn test_parse_task_list_without_headers() {
+        let tasks = parse_task_list("- [ ] Only task");
+        assert_eq!(tasks.len(), 1);
+        assert!(tasks[0].section.is_empty());
+        assert!(tasks[0].completed_at.is_none());
+    }
+
+    #[test]
+    fn test_with_completion_stamps_and_clears_date() {
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 6, 2).unwrap();
+        assert_eq!(
+            with_completion("Add login @alice", true, today),
+            "Add login @alice completed:2025-06-02"
+        );
+        // An existing date is kept
+        assert_eq!(
+            with_completion("Add login completed:2025-05-01", true, today),
+            "Add login completed:2025-05-01"
+        );
+        assert_eq!(
+            with_completion("Add login completed:2025-05-01 ~2d", false, today),
+            "Add login ~2d"
+        );
+        assert_eq!(with_completion("Add login", false, today), "Add login");
+    }
+}
@@ -1,11 +1,16 @@
-//! Installable command templates for Claude and Cursor
+//! Installable command templates for Claude, Cursor and Windsurf
+//!
+//! Windsurf installs the Cursor commands as workflows, run as `/foundry_create_spec`
+//! and so on; its templates are looked up under `windsurf/commands/` first.
 
 use anyhow::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use super::render::render_installed;
+
 /// We place commands directly under the client's commands directory
-/// Common set of command files we install for Claude (frontmatter) (filename -> content)
+/// Common set of command files we install for Claude (frontmatter) (filename -> template)
 fn claude_command_files() -> Vec<(&'static str, &'static str)> {
     vec![
         ("foundry_analyze_project.md", CLAUDE_ANALYZE_PROJECT_CMD),
@@ -17,7 +22,7 @@ fn claude_command_files() -> Vec<(&'static str, &'static str)> {
     ]
 }
 
-/// Common set of command files we install for Cursor (no frontmatter) (filename -> content)
+/// Common set of command files we install for Cursor (no frontmatter) (filename -> template)
 fn cursor_command_files() -> Vec<(&'static str, &'static str)> {
     vec![
         ("foundry_analyze_project.md", CURSOR_ANALYZE_PROJECT_CMD),
@@ -29,6 +34,20 @@ fn cursor_command_files() -> Vec<(&'static str, &'static str)> {
     ]
 }
 
+/// Render `<client>/commands/<file>` templates, preferring the message catalog's version
+fn rendered(
+    client: &str,
+    files: Vec<(&'static str, &'static str)>,
+) -> Result<Vec<(&'static str, String)>> {
+    files
+        .into_iter()
+        .map(|(filename, template)| {
+            let key = format!("{}/commands/{}", client, filename);
+            Ok((filename, render_installed(&key, template, client)?))
+        })
+        .collect()
+}
+
 /// Resolve Claude commands directory: ~/.claude/commands/foundry
 pub fn claude_commands_dir(config_dir: &Path) -> PathBuf {
     config_dir.join("commands")
@@ -39,6 +58,11 @@ pub fn cursor_commands_dir(config_dir: &Path) -> PathBuf {
     config_dir.join("commands")
 }
 
+/// Resolve Windsurf workflows directory: <project>/.windsurf/workflows
+pub fn windsurf_workflows_dir(workspace_dir: &Path) -> PathBuf {
+    workspace_dir.join("workflows")
+}
+
 /// Install commands into the given directory, creating parent dirs as needed
 /// Install both sets by default (legacy behavior)
 pub fn install_commands(commands_dir: &Path) -> Result<String> {
@@ -50,9 +74,9 @@ pub fn install_commands(commands_dir: &Path) -> Result<String> {
         .with_context(|| format!("Failed to create commands dir: {:?}", commands_dir))?;
 
     let mut created = 0usize;
-    for (filename, content) in claude_command_files()
+    for (filename, content) in rendered("claude", claude_command_files())?
         .into_iter()
-        .chain(cursor_command_files())
+        .chain(rendered("cursor", cursor_command_files())?)
     {
         let path = commands_dir.join(filename);
         fs::write(&path, content)
@@ -77,7 +101,7 @@ pub fn install_claude_commands(commands_dir: &Path) -> Result<String> {
         .with_context(|| format!("Failed to create commands dir: {:?}", commands_dir))?;
 
     let mut created = 0usize;
-    for (filename, content) in claude_command_files() {
+    for (filename, content) in rendered("claude", claude_command_files())? {
         let path = commands_dir.join(filename);
         fs::write(&path, content)
             .with_context(|| format!("Failed to write command file: {:?}", path))?;
@@ -101,7 +125,7 @@ pub fn install_cursor_commands(commands_dir: &Path) -> Result<String> {
         .with_context(|| format!("Failed to create commands dir: {:?}", commands_dir))?;
 
     let mut created = 0usize;
-    for (filename, content) in cursor_command_files() {
+    for (filename, content) in rendered("cursor", cursor_command_files())? {
         let path = commands_dir.join(filename);
         fs::write(&path, content)
             .with_context(|| format!("Failed to write command file: {:?}", path))?;
@@ -115,6 +139,26 @@ pub fn install_cursor_commands(commands_dir: &Path) -> Result<String> {
     ))
 }
 
+/// Install the Cursor commands as Windsurf workflows
+pub fn install_windsurf_workflows(workflows_dir: &Path) -> Result<String> {
+    fs::create_dir_all(workflows_dir)
+        .with_context(|| format!("Failed to create workflows dir: {:?}", workflows_dir))?;
+
+    let mut created = 0usize;
+    for (filename, content) in rendered("windsurf", cursor_command_files())? {
+        let path = workflows_dir.join(filename);
+        fs::write(&path, content)
+            .with_context(|| format!("Failed to write workflow file: {:?}", path))?;
+        created += 1;
+    }
+
+    Ok(format!(
+        "Created Foundry workflows: {} (in {})",
+        created,
+        workflows_dir.to_string_lossy()
+    ))
+}
+
 /// Remove commands directory (non-fatal if missing)
 pub fn remove_commands(commands_dir: &Path) -> Result<Option<String>> {
     if !commands_dir.exists() {
@@ -149,7 +193,7 @@ pub fn remove_commands(commands_dir: &Path) -> Result<Option<String>> {
 // ---- Command contents ----
 
 const CLAUDE_ANALYZE_PROJECT_CMD: &str = r###"---
-allowed-tools: mcp__foundry__analyze_project, mcp__foundry__list_projects
+allowed-tools: mcp__{{server_name}}__analyze_project, mcp__{{server_name}}__list_projects
 description: Analyze an existing codebase and create Foundry project docs
 argument-hint: [project-name]
 ---
@@ -166,6 +210,7 @@ Goal: Analyze the current codebase, draft complete artifacts (vision, tech_stack
 ## Analysis Workflow
 
 **Step 1: Repository Discovery**
+- Start from Foundry's scan: `{"name":"analyze_project","arguments":{"project_name":"$1","repo_path":"<repo_root>"}}` returns `repo_scan.tech_stack_skeleton` without creating anything
 - Languages and frameworks (check package managers, import patterns)
 - Build tools and deployment infrastructure
 - Service architecture and component relationships
@@ -211,10 +256,12 @@ Call analyze_project with finalized content:
   - Creates ~/.foundry/PROJECT with vision.md, tech-stack.md, summary.md from LLM-provided content
   - Content minimums: vision ≥200 chars, tech_stack ≥150, summary ≥100
   - Returns next_steps and workflow_hints for AI development workflow guidance
+
+{{> conventions}}
 "###;
 
 const CLAUDE_CREATE_PROJECT_CMD: &str = r###"---
-allowed-tools: mcp__foundry__create_project, mcp__foundry__list_projects
+allowed-tools: mcp__{{server_name}}__create_project, mcp__{{server_name}}__list_projects
 description: Create a new Foundry project with complete context documents
 argument-hint: [project-name]
 ---
@@ -310,10 +357,12 @@ Call create_project with finalized content:
   - Content minimums: vision ≥200 chars, tech_stack ≥150, summary ≥100
   - No auto-summarization performed - all content must be LLM-provided
   - Returns next_steps and workflow_hints for continued development planning
+
+{{> conventions}}
 "###;
 
 const CLAUDE_LIST_SPECS_CMD: &str = r###"---
-allowed-tools: mcp__foundry__list_specs, mcp__foundry__list_projects, mcp__foundry__load_project
+allowed-tools: mcp__{{server_name}}__list_specs, mcp__{{server_name}}__list_projects, mcp__{{server_name}}__load_project
 description: List specs for a project with lightweight discovery
 argument-hint: [project-name]
 ---
@@ -387,10 +436,12 @@ Call list_specs for the target project:
   - Returns lightweight metadata for specs without loading full content
   - Includes: spec names, feature names, creation dates, and status indicators
   - Enables quick discovery without performance impact of loading complete specifications
+
+{{> conventions}}
 "###;
 
 const CLAUDE_LOAD_SPEC_CMD: &str = r###"---
-allowed-tools: mcp__foundry__load_spec, mcp__foundry__list_projects, mcp__foundry__list_specs
+allowed-tools: mcp__{{server_name}}__load_spec, mcp__{{server_name}}__list_projects, mcp__{{server_name}}__list_specs
 description: Load a spec by fuzzy name with discovery fallback
 argument-hint: [project-name] [spec-query]
 ---
@@ -419,7 +470,7 @@ Call load_spec with intelligent fuzzy matching:
 
 **Step 3: Ambiguity Resolution**
 - **High confidence match:** Load directly and present content summary
-- **Multiple matches:** Display candidates with feature names and dates, ask user to choose
+- **Multiple matches:** load_spec returns `candidates` (feature names, dates, confidence) instead of content; display them and ask user to choose
 - **No matches:** Suggest alternatives or offer to create new spec with provided name
 
 ## Error Recovery Patterns
@@ -479,10 +530,12 @@ Call load_spec with intelligent fuzzy matching:
   - Supports fuzzy spec_name matching for user convenience
   - Returns full context with summary, task analysis, and workflow guidance
   - Enables immediate transition to development or planning activities
+
+{{> conventions}}
 "###;
 
 const CLAUDE_CREATE_SPEC_CMD: &str = r###"---
-allowed-tools: mcp__foundry__create_spec, mcp__foundry__load_project, mcp__foundry__list_specs
+allowed-tools: mcp__{{server_name}}__create_spec, mcp__{{server_name}}__load_project, mcp__{{server_name}}__list_specs
 description: Create a new spec with tasks and notes for a project
 argument-hint: [project-name] [feature-name]
 ---
@@ -625,10 +678,12 @@ Create specification with complete content:
   - Files: spec.md (requirements), task-list.md (checklist), notes.md (context)
   - Enforces one feature per spec for focused development workflow
   - Content must be comprehensive and LLM-provided (no auto-generation)
+
+{{> conventions}}
 "###;
 
 const CLAUDE_UPDATE_SPEC_CMD: &str = r###"---
-allowed-tools: mcp__foundry__update_spec, mcp__foundry__load_spec
+allowed-tools: mcp__{{server_name}}__update_spec, mcp__{{server_name}}__load_spec
 description: Update a spec using Foundry edit_commands safely and idempotently
 argument-hint: [project-name] [spec-query] [update-description]
 ---
@@ -790,6 +845,8 @@ Note: `commands` is required and must be a JSON array when using MCP tools. When
   - **Idempotent Design:** Safe to re-run commands without duplication
   - **Error Recovery:** If selector fails, load current spec content and retry with exact text
   - **Batch Operations:** Single call can execute multiple related commands atomically
+
+{{> conventions}}
 "###;
 
 // Addendum: The following quick reference clarifies required args and common pitfalls for update_spec.
@@ -812,6 +869,8 @@ Analyze an existing codebase and create comprehensive Foundry project documents
 ## Detailed Analysis Workflow
 
 ### Step 1: Repository Discovery
+**Foundry Scan:** `{"name":"analyze_project","arguments":{"project_name":"$1","repo_path":"<repo_root>"}}` returns languages, manifest dependencies, CI and Docker configs as `repo_scan` without creating anything; draft tech_stack from its `tech_stack_skeleton`.
+
 **Technical Infrastructure:**
 - Languages and frameworks (check package managers, import patterns, build configs)
 - Build tools and deployment infrastructure (CI/CD, containerization, cloud configs)
@@ -896,6 +955,8 @@ Analyze an existing codebase and create comprehensive Foundry project documents
 **Primary Workflow:** Systematically gather repository context through file analysis and codebase examination, draft comprehensive vision/tech-stack/summary documents collaboratively with the user, then call the Foundry MCP "analyze_project" tool with finalized content that meets all quality and length requirements.
 
 **Success Criteria:** Created project documents should enable any future developer or AI assistant to understand the project's purpose, technical architecture, and development context without additional research.
+
+{{> conventions}}
 "###;
 
 const CURSOR_CREATE_PROJECT_CMD: &str = r###"# Create Foundry Project
@@ -1032,6 +1093,8 @@ Create a new Foundry project with comprehensive context documents through collab
 **Primary Mission:** Conduct comprehensive project interview to gather complete context, collaboratively draft vision/tech-stack/summary documents that meet all quality requirements, then execute project creation through Foundry MCP tools.
 
 **Success Standard:** Created project should provide sufficient context for any development team or AI assistant to understand the project's purpose, technical approach, and success criteria without requiring additional discovery work.
+
+{{> conventions}}
 "###;
 
 const CURSOR_LIST_SPECS_CMD: &str = r###"# List Specs With Foundry
@@ -1138,6 +1201,8 @@ Discover and analyze existing specifications for a project to guide development
 **Value Delivery:** Transform simple spec listing into comprehensive development planning assistance that helps users understand their project's current state and optimal next actions.
 
 **Success Metrics:** User gains clear understanding of project development status, knows which specs need attention, and receives actionable guidance for continuing their development work.
+
+{{> conventions}}
 "###;
 
 const CURSOR_LOAD_SPEC_CMD: &str = r###"# Load Spec With Foundry
@@ -1173,7 +1238,7 @@ Load a complete specification using intelligent fuzzy matching to enable focused
 
 **Match Quality Assessment:**
 - **High Confidence Single Match:** Load directly and proceed to content analysis
-- **Multiple Matches:** Present candidates with feature names, creation dates, and similarity scores
+- **Multiple Matches:** Present the returned `candidates` with feature names, creation dates, and confidence scores
 - **Low Confidence:** Show alternatives and ask for clarification or more specific search terms
 - **No Matches:** Suggest creating new spec or provide spelling/naming guidance
 
@@ -1264,6 +1329,8 @@ Load a complete specification using intelligent fuzzy matching to enable focused
 **Content Delivery:** Present spec information in digestible format with clear development priorities, highlight immediate next steps, and provide context for decision-making.
 
 **Success Standard:** User gains complete understanding of feature requirements, knows exactly what work remains, and has clear guidance for continuing development efficiently.
+
+{{> conventions}}
 "###;
 
 const CURSOR_CREATE_SPEC_CMD: &str = r###"# Create Spec With Foundry
@@ -1459,6 +1526,8 @@ Create a comprehensive feature specification with detailed implementation guidan
 **Success Criteria:** Created specification should provide sufficient guidance for any development team to implement the feature successfully, with clear requirements, comprehensive task breakdown, and complete technical context.
 
 **Quality Standard:** All documents should work together as cohesive implementation guide that reduces ambiguity, prevents scope creep, and enables efficient development workflow.
+
+{{> conventions}}
 "###;
 
 const CURSOR_UPDATE_SPEC_CMD: &str = r###"# Update Spec With Foundry
@@ -1666,4 +1735,6 @@ Note: `commands` is required and must be a JSON array when using MCP tools. When
 **Execution Standards:** All edit commands must target exact existing text, use appropriate command types for content areas, and maintain specification quality and consistency throughout the update process.
 
 **Success Criteria:** Updates accurately reflect user intent, maintain specification integrity, and provide clear development guidance while supporting continued iterative improvement of the feature specification.
+
+{{> conventions}}
 "###;
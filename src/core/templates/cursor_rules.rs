@@ -86,7 +86,7 @@ Each spec contains:
   - Best for: Documentation, structured management of legacy projects
   - MCP Tool Call: `{"name": "analyze_project", "arguments": {"project_name": "...", "vision": "...", "tech_stack": "...", "summary": "..."}}`
 
-- **`mcp_foundry_load_project`**: For project-wide analysis and context
+- **`mcp_{{server_name}}_load_project`**: For project-wide analysis and context
   - When: Starting comprehensive work sessions, understanding full project scope
   - Returns: Project vision, tech-stack, summary, available specs
   - Use for: Project-wide analysis, architectural decisions, creating multiple specs
@@ -106,7 +106,7 @@ Each spec contains:
 
   - When: Continuing work on existing features, checking task status
   - Returns: Complete spec content with project context
-  - Supports: Fuzzy matching on feature names (e.g., "auth" matches "user_authentication")
+  - Supports: Fuzzy matching on feature names and dates (e.g., "auth" matches "user_authentication", "2025-09-17" matches specs from that day); ambiguous names return `candidates` to choose from
   - Includes: Project summary automatically for context
   - MCP Tool Call: `{"name": "load_spec", "arguments": {"project_name": "...", "spec_name": "..."}}`
 
@@ -169,7 +169,7 @@ Each spec contains:
 
 #### Discovery & Validation
 
-- **`mcp_foundry_list_specs`**: Lightweight spec discovery for focused work
+- **`mcp_{{server_name}}_list_specs`**: Lightweight spec discovery for focused work
 
   - When: Finding available specs without loading full project context
   - Returns: Spec metadata (name, feature, date) without project details
@@ -177,7 +177,7 @@ Each spec contains:
   - Performance: ~90% reduction in data transfer vs load_project
   - MCP Tool Call: `{"name": "list_specs", "arguments": {"project_name": "..."}}`
 
-- **`mcp_foundry_list_projects`**: Discovering available projects
+- **`mcp_{{server_name}}_list_projects`**: Discovering available projects
 
   - When: Finding project names, checking what exists
   - Returns: Project names, creation dates, spec counts
@@ -359,21 +359,21 @@ Each spec contains:
 #### For Focused Feature Work:
 ```
 # ✅ Optimal: Direct spec loading with fuzzy matching
-mcp_foundry_load_spec project-name "auth"  # Fuzzy matches "user_authentication"
+mcp_{{server_name}}_load_spec project-name "auth"  # Fuzzy matches "user_authentication"
 
 # ✅ Alternative: Lightweight discovery then load
-mcp_foundry_list_specs project-name
-mcp_foundry_load_spec project-name specific-spec-name
+mcp_{{server_name}}_list_specs project-name
+mcp_{{server_name}}_load_spec project-name specific-spec-name
 
 # ✅ For new features: Load project context first
-mcp_foundry_load_project project-name
-mcp_foundry_create_spec project-name new-feature
+mcp_{{server_name}}_load_project project-name
+mcp_{{server_name}}_create_spec project-name new-feature
 ```
 
 #### For Project-Wide Analysis:
 ```
 # ✅ Use load_project for comprehensive context
-mcp_foundry_load_project project-name  # Full vision + tech-stack + all specs
+mcp_{{server_name}}_load_project project-name  # Full vision + tech-stack + all specs
 ```
 
 ```json
@@ -442,21 +442,21 @@ mcp_foundry_load_project project-name  # Full vision + tech-stack + all specs
 ### Feature Development Cycle
 
 #### For Existing Features:
-1. **Load Spec**: `mcp_foundry_load_spec my-app "payment"` (fuzzy matching)
-2. **Update Progress**: Use `mcp_foundry_update_spec` with `upsert_task` to add new tasks
+1. **Load Spec**: `mcp_{{server_name}}_load_spec my-app "payment"` (fuzzy matching)
+2. **Update Progress**: Use `mcp_{{server_name}}_update_spec` with `upsert_task` to add new tasks
 3. **Add Notes**: Document implementation decisions using `append_to_section` for notes
 4. **Review Status**: Load spec again to check progress and get workflow guidance
 
 #### For New Features:
-1. **Load Project**: `mcp_foundry_load_project my-app` (get full context)
+1. **Load Project**: `mcp_{{server_name}}_load_project my-app` (get full context)
    ```json
    {"name": "load_project", "arguments": {"project_name": "my-app"}}
    ```
-2. **Create Spec**: `mcp_foundry_create_spec my-app payment-integration`
+2. **Create Spec**: `mcp_{{server_name}}_create_spec my-app payment-integration`
    ```json
    {"name": "create_spec", "arguments": {"project_name": "my-app", "feature_name": "payment-integration", "spec": "...", "tasks": "...", "notes": "..."}}
    ```
-3. **Update Progress**: Use `mcp_foundry_update_spec` with edit commands to add new tasks
+3. **Update Progress**: Use `mcp_{{server_name}}_update_spec` with edit commands to add new tasks
 4. **Add Notes**: Document implementation decisions using `append_to_section` for notes
 
 ### Existing Codebase Analysis
@@ -511,14 +511,14 @@ mcp_foundry_load_project project-name  # Full vision + tech-stack + all specs
 
 ```
 # Focused work on existing feature (PREFERRED for efficiency)
-mcp_foundry_load_spec my-project "auth"  # Fuzzy matching
+mcp_{{server_name}}_load_spec my-project "auth"  # Fuzzy matching
 
 # Lightweight spec discovery
-mcp_foundry_list_specs my-project
+mcp_{{server_name}}_list_specs my-project
 
 # Create new feature spec (load project first for context)
-mcp_foundry_load_project my-project
-mcp_foundry_create_spec my-project user-auth
+mcp_{{server_name}}_load_project my-project
+mcp_{{server_name}}_create_spec my-project user-auth
 ```
 
 ```json
@@ -549,7 +549,9 @@ mcp_foundry_create_spec my-project user-auth
 - **Task Lists**: Specific, actionable implementation steps
 - **Notes**: Design decisions, context, implementation details
 
-Remember: Foundry manages structure, you provide content. Focus on comprehensive, well-structured content that helps future development."###
+Remember: Foundry manages structure, you provide content. Focus on comprehensive, well-structured content that helps future development.
+
+{{> conventions}}"###
     }
 
     fn file_path(config_dir: &Path) -> Result<PathBuf> {
@@ -13,6 +13,9 @@ use std::path::{Path, PathBuf};
 pub trait ClientTemplate {
     /// Get the embedded template content as a static string
     ///
+    /// The content is rendered with `render::render_installed` before it is
+    /// written, so it may use `{{name}}` variables and `{{> name}}` partials.
+    ///
     /// This content is compiled into the binary and should contain all
     /// necessary configuration and guidance for the specific client.
     fn content() -> &'static str;
@@ -40,3 +43,6 @@ pub trait ClientTemplate {
 pub mod claude_subagent;
 pub mod commands;
 pub mod cursor_rules;
+pub mod render;
+pub mod spec_templates;
+pub mod windsurf_rules;
@@ -0,0 +1,558 @@
+//! Install-time rendering of guidance templates
+//!
+//! Command, rules and subagent templates are rendered before they are written,
+//! so installed guidance can follow a team's conventions. Templates may use
+//! `{{name}}` variables and `{{> name}}` partials, read from
+//! `~/.foundry/.templates` (or `foundry install --templates-dir`):
+//!
+//! ```text
+//! ~/.foundry/.templates/
+//!   variables.json        {"server_name": "foundry-work", "team": "Payments"}
+//!   partials/
+//!     conventions.md      appended to every built-in template
+//!   commands/
+//!     foundry_create_spec.md   replaces that command for every client
+//!   claude/commands/
+//!     foundry_create_spec.md   replaces it for Claude only
+//! ```
+//!
+//! Built-in variables are `server_name` (`foundry`), used in MCP tool names
+//! like `mcp__foundry__create_spec`, and `client` (`claude`, `cursor` or
+//! `windsurf`). `foundry install` adds `binary_path`, `default_spec_template`
+//! and `validation_profile`, and `[templates.guidance]` blocks from
+//! `config.toml` as partials. `variables.json` and `foundry install --var
+//! name=value` add variables or override them, in that order. An unknown
+//! variable is an error; a missing partial renders as nothing, so partials
+//! double as optional extension points. A partial tag alone on its line takes
+//! up no line when empty.
+//!
+//! An override command keeps the frontmatter of the command it replaces:
+//! fields it sets itself replace the built-in ones, and the rest are kept.
+//! Clients whose commands have no frontmatter get the override without its
+//! own, so one file in `commands/` serves every client.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+
+use crate::utils::messages;
+
+/// Server name the installers register, used in MCP tool names
+pub const DEFAULT_SERVER_NAME: &str = "foundry";
+
+const TEMPLATES_DIR_NAME: &str = ".templates";
+const VARIABLES_FILE_NAME: &str = "variables.json";
+const PARTIALS_DIR_NAME: &str = "partials";
+const COMMANDS_DIR_NAME: &str = "commands";
+
+/// Clients with their own override directory for commands
+const CLIENTS: &[&str] = &["claude", "cursor", "windsurf"];
+
+/// Partials may include partials this deep, which also stops include cycles
+const MAX_PARTIAL_DEPTH: usize = 8;
+
+static CONTEXT: OnceLock<TemplateContext> = OnceLock::new();
+
+/// Default template directory, `~/.foundry/.templates`
+pub fn default_dir() -> Result<PathBuf> {
+    Ok(crate::core::filesystem::foundry_dir()?.join(TEMPLATES_DIR_NAME))
+}
+
+/// Variables and partials available to templates
+#[derive(Debug, Clone)]
+pub struct TemplateContext {
+    variables: HashMap<String, String>,
+    partials: HashMap<String, String>,
+    /// Override commands by install key, e.g. `commands/foundry_create_spec.md`
+    /// or `claude/commands/foundry_create_spec.md`
+    overrides: HashMap<String, String>,
+}
+
+impl Default for TemplateContext {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl TemplateContext {
+    /// Built-in variables only, which render the templates as shipped
+    pub fn builtin() -> Self {
+        Self {
+            variables: HashMap::from([(
+                "server_name".to_string(),
+                DEFAULT_SERVER_NAME.to_string(),
+            )]),
+            partials: HashMap::new(),
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Built-ins plus `variables.json`, `partials/*.md` and override commands
+    /// from `dir`; any of them may be missing
+    pub fn load(dir: &Path) -> Result<Self> {
+        let mut context = Self::builtin();
+
+        let variables_path = dir.join(VARIABLES_FILE_NAME);
+        if variables_path.exists() {
+            let content = fs::read_to_string(&variables_path).with_context(|| {
+                format!("Failed to read template variables: {:?}", variables_path)
+            })?;
+            let variables: HashMap<String, String> = serde_json::from_str(&content)
+                .with_context(|| format!("Invalid template variables: {:?}", variables_path))?;
+            context.variables.extend(variables);
+        }
+
+        for (name, content) in markdown_files(&dir.join(PARTIALS_DIR_NAME))? {
+            context.partials.insert(name, content);
+        }
+
+        for prefix in std::iter::once(COMMANDS_DIR_NAME.to_string()).chain(
+            CLIENTS
+                .iter()
+                .map(|client| format!("{}/{}", client, COMMANDS_DIR_NAME)),
+        ) {
+            for (name, content) in markdown_files(&dir.join(&prefix))? {
+                context
+                    .overrides
+                    .insert(format!("{}/{}.md", prefix, name), content);
+            }
+        }
+
+        Ok(context)
+    }
+
+    /// Add variables not set yet, so `variables.json` and `--var` still win
+    pub fn with_defaults<'a>(
+        mut self,
+        defaults: impl IntoIterator<Item = (&'a str, String)>,
+    ) -> Self {
+        for (name, value) in defaults {
+            self.variables.entry(name.to_string()).or_insert(value);
+        }
+        self
+    }
+
+    /// Add partials not set yet, so `partials/*.md` files still win
+    pub fn with_default_partials<'a>(
+        mut self,
+        partials: impl IntoIterator<Item = (&'a String, &'a String)>,
+    ) -> Self {
+        for (name, content) in partials {
+            self.partials
+                .entry(name.clone())
+                .or_insert_with(|| content.clone());
+        }
+        self
+    }
+
+    /// Add or override variables from `name=value` pairs
+    pub fn with_assignments(mut self, assignments: &[String]) -> Result<Self> {
+        for assignment in assignments {
+            let (name, value) = assignment.split_once('=').with_context(|| {
+                format!(
+                    "Invalid template variable '{}': expected name=value",
+                    assignment
+                )
+            })?;
+            self.variables
+                .insert(name.trim().to_string(), value.to_string());
+        }
+        Ok(self)
+    }
+
+    pub fn with_partial(mut self, name: &str, content: &str) -> Self {
+        self.partials.insert(name.to_string(), content.to_string());
+        self
+    }
+
+    pub fn with_override(mut self, key: &str, content: &str) -> Self {
+        self.overrides.insert(key.to_string(), content.to_string());
+        self
+    }
+
+    /// The override for the template installed as `key`: the client's own,
+    /// else the one in `commands/` for every client
+    fn override_for(&self, key: &str) -> Option<&str> {
+        self.overrides
+            .get(key)
+            .or_else(|| {
+                let (_, file) = key.split_once(&format!("/{}/", COMMANDS_DIR_NAME))?;
+                self.overrides
+                    .get(&format!("{}/{}", COMMANDS_DIR_NAME, file))
+            })
+            .map(String::as_str)
+    }
+
+    /// Render the template installed as `key`: an override when there is one,
+    /// else `template`
+    pub fn render_installed(&self, key: &str, template: &str, client: &str) -> Result<String> {
+        let template = self.override_for(key).map_or_else(
+            || template.to_string(),
+            |custom| with_frontmatter_of(template, custom),
+        );
+        self.render(&template, client)
+    }
+
+    /// Render `template` for `client`, filling variables and partials
+    pub fn render(&self, template: &str, client: &str) -> Result<String> {
+        self.render_with_variables(template, [("client", client.to_string())])
+    }
+
+    /// Render `template` with `extra` variables on top of the context's own
+    pub fn render_with_variables<'a>(
+        &self,
+        template: &str,
+        extra: impl IntoIterator<Item = (&'a str, String)>,
+    ) -> Result<String> {
+        let mut variables = self.variables.clone();
+        variables.extend(
+            extra
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value)),
+        );
+
+        let rendered = self.render_with(template, &variables, 0)?;
+        // Keep the template's own ending so empty trailing partials leave no blank lines
+        let body = rendered.trim_end_matches('\n');
+        Ok(if template.ends_with('\n') {
+            format!("{}\n", body)
+        } else {
+            body.to_string()
+        })
+    }
+
+    fn render_with(
+        &self,
+        template: &str,
+        variables: &HashMap<String, String>,
+        depth: usize,
+    ) -> Result<String> {
+        if depth > MAX_PARTIAL_DEPTH {
+            anyhow::bail!(
+                "Template partials nest more than {} levels; check for a partial that includes itself",
+                MAX_PARTIAL_DEPTH
+            );
+        }
+
+        let mut output = String::with_capacity(template.len());
+        for line in template.split_inclusive('\n') {
+            if let Some(Tag::Partial(name)) = standalone_tag(line) {
+                let rendered = self.render_partial(name, variables, depth)?;
+                if !rendered.is_empty() {
+                    output.push_str(&rendered);
+                    if !rendered.ends_with('\n') && line.ends_with('\n') {
+                        output.push('\n');
+                    }
+                }
+                continue;
+            }
+
+            let mut rest = line;
+            while let Some(start) = rest.find("{{") {
+                output.push_str(&rest[..start]);
+                let after = &rest[start + 2..];
+                let end = after.find("}}").with_context(|| {
+                    format!("Unclosed '{{{{' in template line: {}", line.trim())
+                })?;
+                match parse_tag(&after[..end]) {
+                    Tag::Variable(name) => {
+                        let value = variables.get(name).with_context(|| {
+                            format!(
+                                "Unknown template variable '{}'; set it in {} or with --var {}=<value>",
+                                name, VARIABLES_FILE_NAME, name
+                            )
+                        })?;
+                        output.push_str(value);
+                    }
+                    Tag::Partial(name) => {
+                        output.push_str(&self.render_partial(name, variables, depth)?);
+                    }
+                }
+                rest = &after[end + 2..];
+            }
+            output.push_str(rest);
+        }
+        Ok(output)
+    }
+
+    fn render_partial(
+        &self,
+        name: &str,
+        variables: &HashMap<String, String>,
+        depth: usize,
+    ) -> Result<String> {
+        self.partials.get(name).map_or_else(
+            || Ok(String::new()),
+            |partial| {
+                self.render_with(partial, variables, depth + 1)
+                    .with_context(|| format!("Failed to render partial '{}'", name))
+            },
+        )
+    }
+}
+
+enum Tag<'a> {
+    Variable(&'a str),
+    Partial(&'a str),
+}
+
+fn parse_tag(inner: &str) -> Tag<'_> {
+    let inner = inner.trim();
+    inner
+        .strip_prefix('>')
+        .map_or(Tag::Variable(inner), |name| Tag::Partial(name.trim()))
+}
+
+/// The tag when `line` holds nothing else
+fn standalone_tag(line: &str) -> Option<Tag<'_>> {
+    let inner = line.trim().strip_prefix("{{")?.strip_suffix("}}")?;
+    (!inner.contains("{{")).then(|| parse_tag(inner))
+}
+
+/// `.md` files in `dir` by file stem; none when `dir` does not exist
+fn markdown_files(dir: &Path) -> Result<Vec<(String, String)>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {:?}", dir))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "md")
+            && let Some(name) = path.file_stem().and_then(|stem| stem.to_str())
+        {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read template: {:?}", path))?;
+            files.push((name.to_string(), content));
+        }
+    }
+    Ok(files)
+}
+
+/// `(frontmatter, body)` of a template opening with a `---` block
+fn split_frontmatter(template: &str) -> Option<(&str, &str)> {
+    let rest = template.strip_prefix("---\n")?;
+    if let Some(body) = rest.strip_prefix("---\n") {
+        return Some(("", body));
+    }
+    let end = rest.find("\n---\n")?;
+    Some((&rest[..=end], &rest[end + 5..]))
+}
+
+/// Frontmatter fields in order, each with its continuation lines
+fn frontmatter_fields(frontmatter: &str) -> Vec<(&str, String)> {
+    let mut fields: Vec<(&str, String)> = Vec::new();
+    for line in frontmatter.lines() {
+        let starts_field = !line.starts_with([' ', '\t', '-']) && line.contains(':');
+        match fields.last_mut() {
+            Some((_, text)) if !starts_field => {
+                text.push_str(line);
+                text.push('\n');
+            }
+            _ => {
+                let key = line.split(':').next().unwrap_or_default().trim();
+                fields.push((key, format!("{}\n", line)));
+            }
+        }
+    }
+    fields
+}
+
+/// `custom` with the frontmatter of `builtin`, updated by `custom`'s own
+/// fields; without frontmatter when `builtin` has none
+fn with_frontmatter_of(builtin: &str, custom: &str) -> String {
+    let (custom_fields, body) = split_frontmatter(custom)
+        .map_or((Vec::new(), custom), |(frontmatter, body)| {
+            (frontmatter_fields(frontmatter), body)
+        });
+    let Some((builtin_frontmatter, _)) = split_frontmatter(builtin) else {
+        return body.to_string();
+    };
+
+    let mut fields = frontmatter_fields(builtin_frontmatter);
+    for (key, text) in custom_fields {
+        match fields.iter_mut().find(|(existing, _)| *existing == key) {
+            Some(field) => field.1 = text,
+            None => fields.push((key, text)),
+        }
+    }
+    let frontmatter: String = fields.into_iter().map(|(_, text)| text).collect();
+    format!("---\n{}---\n{}", frontmatter, body)
+}
+
+/// Install the process-wide context; later calls keep the first context
+pub fn init(context: TemplateContext) {
+    if CONTEXT.set(context).is_err() {
+        tracing::debug!("Template context already initialized");
+    }
+}
+
+/// The process-wide context, built-in variables only until `init`
+pub fn context() -> &'static TemplateContext {
+    CONTEXT.get_or_init(TemplateContext::builtin)
+}
+
+/// The template to install under `key`: an override command when there is
+/// one, else the locale catalog's version or `default`, rendered with the
+/// process-wide context
+pub fn render_installed(key: &str, default: &str, client: &str) -> Result<String> {
+    let template = messages::catalog().template(key, default);
+    context()
+        .render_installed(key, template, client)
+        .with_context(|| format!("Failed to render template '{}'", key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_fills_variables_and_partials() {
+        let context = TemplateContext::builtin()
+            .with_assignments(&["team=Payments".to_string()])
+            .unwrap()
+            .with_partial(
+                "conventions",
+                "## {{team}} conventions\n- Ticket in every spec\n",
+            );
+
+        let rendered = context
+            .render(
+                "allowed-tools: mcp__{{ server_name }}__create_spec ({{client}})\n\n{{> conventions}}\n",
+                "claude",
+            )
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "allowed-tools: mcp__foundry__create_spec (claude)\n\n## Payments conventions\n- Ticket in every spec\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_partial_leaves_template_unchanged() {
+        let context = TemplateContext::builtin();
+        assert_eq!(
+            context
+                .render("Last line\n\n{{> conventions}}\n", "cursor")
+                .unwrap(),
+            "Last line\n"
+        );
+        assert_eq!(
+            context
+                .render("Last line\n\n{{> conventions}}", "cursor")
+                .unwrap(),
+            "Last line"
+        );
+    }
+
+    #[test]
+    fn test_render_rejects_unknown_variables_and_cycles() {
+        let context = TemplateContext::builtin();
+        let error = context.render("Hello {{team}}", "claude").unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Unknown template variable 'team'")
+        );
+        assert!(context.render("Hello {{team", "claude").is_err());
+        assert!(
+            TemplateContext::builtin()
+                .with_assignments(&["no-equals".to_string()])
+                .is_err()
+        );
+
+        let cyclic = TemplateContext::builtin().with_partial("loop", "{{> loop}}");
+        assert!(cyclic.render("{{> loop}}", "claude").is_err());
+    }
+
+    #[test]
+    fn test_load_reads_variables_and_partials() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        assert!(TemplateContext::load(temp_dir.path()).is_ok());
+
+        fs::write(
+            temp_dir.path().join(VARIABLES_FILE_NAME),
+            r#"{"server_name": "foundry-work"}"#,
+        )
+        .unwrap();
+        fs::create_dir(temp_dir.path().join(PARTIALS_DIR_NAME)).unwrap();
+        fs::write(
+            temp_dir
+                .path()
+                .join(PARTIALS_DIR_NAME)
+                .join("conventions.md"),
+            "Use {{server_name}}",
+        )
+        .unwrap();
+
+        let context = TemplateContext::load(temp_dir.path()).unwrap();
+        assert_eq!(
+            context.render("{{> conventions}}", "claude").unwrap(),
+            "Use foundry-work"
+        );
+    }
+
+    #[test]
+    fn test_override_commands_keep_builtin_frontmatter() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let commands = temp_dir.path().join(COMMANDS_DIR_NAME);
+        fs::create_dir(&commands).unwrap();
+        fs::write(
+            commands.join("foundry_load_spec.md"),
+            "---\ndescription: Load a spec the {{team}} way\n---\nRun {{binary_path}} first\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join(VARIABLES_FILE_NAME),
+            r#"{"team": "Payments"}"#,
+        )
+        .unwrap();
+        let context = TemplateContext::load(temp_dir.path())
+            .unwrap()
+            .with_defaults([
+                ("binary_path", "/opt/foundry".to_string()),
+                ("team", "ignored".to_string()),
+            ])
+            .with_override("cursor/commands/foundry_list_specs.md", "Cursor only\n");
+
+        let builtin = "---\nallowed-tools: mcp__{{server_name}}__load_spec\ndescription: Load a spec\n---\n\nBuilt-in body\n";
+        assert_eq!(
+            context
+                .render_installed("claude/commands/foundry_load_spec.md", builtin, "claude")
+                .unwrap(),
+            "---\nallowed-tools: mcp__foundry__load_spec\ndescription: Load a spec the Payments way\n---\nRun /opt/foundry first\n"
+        );
+        assert_eq!(
+            context
+                .render_installed(
+                    "cursor/commands/foundry_load_spec.md",
+                    "Built-in\n",
+                    "cursor"
+                )
+                .unwrap(),
+            "Run /opt/foundry first\n"
+        );
+        assert_eq!(
+            context
+                .render_installed(
+                    "cursor/commands/foundry_list_specs.md",
+                    "Built-in\n",
+                    "cursor"
+                )
+                .unwrap(),
+            "Cursor only\n"
+        );
+        assert_eq!(
+            context
+                .render_installed(
+                    "claude/commands/foundry_list_specs.md",
+                    "Built-in\n",
+                    "claude"
+                )
+                .unwrap(),
+            "Built-in\n"
+        );
+    }
+}
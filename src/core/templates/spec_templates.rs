@@ -0,0 +1,229 @@
+//! User-defined spec scaffolds
+//!
+//! `create_spec` can render spec.md from a named template instead of using the
+//! caller's content as-is. Templates live next to the install templates:
+//!
+//! ```text
+//! ~/.foundry/.templates/
+//!   variables.json        shared with install templates
+//!   partials/             shared with install templates
+//!   specs/
+//!     security.md         used with create_spec template="security"
+//! ```
+//!
+//! A spec template is rendered with the shared variables and partials plus:
+//!
+//! - `feature_name` - the snake_case feature name
+//! - `feature_title` - the feature name in title case (`User Authentication`)
+//! - `date` - today's date, `YYYY-MM-DD` (UTC, like spec names)
+//! - `project_name` and `project_summary`
+//! - `spec` - the content passed to `create_spec`
+//!
+//! A template that never uses `{{spec}}` gets the content appended after the
+//! rendered scaffold, so the caller's content is never dropped.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::core::templates::render::{self, TemplateContext};
+use crate::types::errors::ErrorCode;
+
+const SPEC_TEMPLATES_DIR_NAME: &str = "specs";
+
+/// Values filled into a spec template
+#[derive(Debug, Clone)]
+pub struct SpecTemplateInput<'a> {
+    pub feature_name: &'a str,
+    pub project_name: &'a str,
+    pub project_summary: &'a str,
+    pub spec: &'a str,
+}
+
+/// Spec templates available to `create_spec`, by name
+#[derive(Debug, Clone, Default)]
+pub struct SpecTemplateRegistry {
+    context: TemplateContext,
+    templates: BTreeMap<String, String>,
+}
+
+/// Default spec template directory, `~/.foundry/.templates/specs`
+pub fn default_dir() -> Result<PathBuf> {
+    Ok(render::default_dir()?.join(SPEC_TEMPLATES_DIR_NAME))
+}
+
+impl SpecTemplateRegistry {
+    /// Templates from the default directory, with its variables and partials
+    pub fn load_default() -> Result<Self> {
+        Self::load(&render::default_dir()?)
+    }
+
+    /// Templates from `dir/specs/*.md`, with the variables and partials in `dir`
+    pub fn load(dir: &Path) -> Result<Self> {
+        let mut registry = Self {
+            context: TemplateContext::load(dir)?,
+            templates: BTreeMap::new(),
+        };
+
+        let specs_dir = dir.join(SPEC_TEMPLATES_DIR_NAME);
+        if specs_dir.is_dir() {
+            for entry in fs::read_dir(&specs_dir)
+                .with_context(|| format!("Failed to read spec templates: {:?}", specs_dir))?
+            {
+                let path = entry?.path();
+                if path.extension().is_some_and(|ext| ext == "md")
+                    && let Some(name) = path.file_stem().and_then(|stem| stem.to_str())
+                {
+                    let content = fs::read_to_string(&path)
+                        .with_context(|| format!("Failed to read spec template: {:?}", path))?;
+                    registry.templates.insert(name.to_string(), content);
+                }
+            }
+        }
+
+        Ok(registry)
+    }
+
+    pub fn with_template(mut self, name: &str, content: &str) -> Self {
+        self.templates.insert(name.to_string(), content.to_string());
+        self
+    }
+
+    /// Template names in sorted order
+    pub fn names(&self) -> Vec<&str> {
+        self.templates.keys().map(String::as_str).collect()
+    }
+
+    /// Render template `name` into spec.md content
+    pub fn render(&self, name: &str, input: &SpecTemplateInput) -> Result<String> {
+        let template = self.templates.get(name).ok_or_else(|| {
+            let available = if self.templates.is_empty() {
+                format!("none; add Markdown files to {}", display_default_dir())
+            } else {
+                self.names().join(", ")
+            };
+            ErrorCode::InvalidParams.error(format!(
+                "Spec template '{}' not found. Available templates: {}",
+                name, available
+            ))
+        })?;
+
+        let rendered = self
+            .context
+            .render_with_variables(
+                template,
+                [
+                    ("feature_name", input.feature_name.to_string()),
+                    ("feature_title", title_case(input.feature_name)),
+                    ("date", chrono::Utc::now().format("%Y-%m-%d").to_string()),
+                    ("project_name", input.project_name.to_string()),
+                    ("project_summary", input.project_summary.trim().to_string()),
+                    ("spec", input.spec.trim().to_string()),
+                ],
+            )
+            .map_err(|e| {
+                ErrorCode::InvalidParams.error(format!(
+                    "Failed to render spec template '{}': {:#}",
+                    name, e
+                ))
+            })?;
+
+        if uses_spec_variable(template) {
+            Ok(rendered)
+        } else {
+            Ok(format!("{}\n\n{}", rendered.trim_end(), input.spec.trim()))
+        }
+    }
+}
+
+fn display_default_dir() -> String {
+    default_dir().map_or_else(
+        |_| "~/.foundry/.templates/specs".to_string(),
+        |dir| dir.display().to_string(),
+    )
+}
+
+fn uses_spec_variable(template: &str) -> bool {
+    template
+        .split("{{")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("}}"))
+        .any(|(tag, _)| tag.trim() == "spec")
+}
+
+/// `user_authentication` -> `User Authentication`
+fn title_case(feature_name: &str) -> String {
+    feature_name
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input<'a>(spec: &'a str) -> SpecTemplateInput<'a> {
+        SpecTemplateInput {
+            feature_name: "user_authentication",
+            project_name: "demo",
+            project_summary: "A demo project\n",
+            spec,
+        }
+    }
+
+    #[test]
+    fn test_render_fills_spec_variables() {
+        let registry = SpecTemplateRegistry::default().with_template(
+            "standard",
+            "# {{feature_title}}\n\n_{{project_name}}: {{project_summary}}_\n\n## Overview\n\n{{ spec }}\n",
+        );
+
+        let rendered = registry
+            .render("standard", &input("Users sign in.\n"))
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "# User Authentication\n\n_demo: A demo project_\n\n## Overview\n\nUsers sign in.\n"
+        );
+    }
+
+    #[test]
+    fn test_render_appends_content_when_template_omits_it() {
+        let registry = SpecTemplateRegistry::default()
+            .with_template("checklist", "# {{feature_title}} ({{date}})\n");
+
+        let rendered = registry.render("checklist", &input("Body")).unwrap();
+        assert!(rendered.starts_with("# User Authentication ("));
+        assert!(rendered.ends_with(")\n\nBody"));
+    }
+
+    #[test]
+    fn test_render_reports_unknown_templates_and_variables() {
+        let registry =
+            SpecTemplateRegistry::default().with_template("broken", "# {{ticket}}\n{{spec}}");
+
+        let error = registry.render("missing", &input("Body")).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Spec template 'missing' not found. Available templates: broken")
+        );
+
+        let error = registry.render("broken", &input("Body")).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Unknown template variable 'ticket'")
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Windsurf rules template for Foundry MCP usage guidance
+
+use super::ClientTemplate;
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Windsurf rules template implementation
+///
+/// Provides the embedded foundry.md workspace rule. Windsurf caps rule files
+/// at 12,000 characters, so this is a condensed version of the Cursor rules;
+/// the installed workflows carry the step-by-step guidance.
+pub struct WindsurfRulesTemplate;
+
+impl ClientTemplate for WindsurfRulesTemplate {
+    fn content() -> &'static str {
+        r###"---
+trigger: always_on
+---
+
+# Foundry MCP Usage Guide
+
+Foundry keeps project context and feature specifications in `~/.foundry/` and serves them through the `{{server_name}}` MCP server. Foundry manages structure; you provide ALL content as tool arguments.
+
+## Documents Are Complete Context
+
+Future assistants load these documents as their primary reference. Write every document for someone who has never seen the project: include WHY decisions were made, prerequisites, dependencies and edge cases, not just WHAT to build.
+
+## Project Structure
+
+- `vision.md`: problem, target users, value proposition, roadmap (200+ characters)
+- `tech-stack.md`: languages, frameworks, infrastructure and the reasons for them (150+ characters)
+- `summary.md`: concise context for quick loading (100+ characters)
+- `specs/YYYYMMDD_HHMMSS_feature_name/`: `spec.md` (requirements), `notes.md` (design decisions), `task-list.md` (markdown checklist)
+
+## Which Tool to Use
+
+- Start of a conversation: `list_projects`, then `load_project` for the whole project or `load_spec` for one feature
+- Focused feature work: `load_spec` with a feature name or part of one (fuzzy matching); `list_specs` for lightweight discovery
+- New project: `create_project`; for an existing codebase, `analyze_project`
+- New feature: `create_spec` with spec, notes and tasks content (feature names are snake_case)
+- Progress and changes: `update_spec` with edit commands instead of rewriting whole files
+- Before creating content: `validate_content` to check it against the requirements
+- Unsure what to do next: `get_foundry_help`
+
+## Editing Specs With update_spec
+
+- `set_task_status` with the exact task text to check or uncheck a task
+- `upsert_task` to add a task without duplicating it
+- `append_to_section` with a section header such as `## Requirements`
+- `replace_section_content` / `remove_section` for larger rewrites
+- Copy task text and headers exactly from the loaded file; if a selector does not match, the error lists candidates to retry with
+
+## Working Habits
+
+- Follow the `next_steps` and `workflow_hints` in every response
+- Keep specs focused: one feature per spec, tasks small enough to finish in one sitting
+- Check tasks off as you complete them and record decisions in `notes.md` as you make them
+- Load context before editing; never invent the contents of a file you have not loaded
+
+## Workflows
+
+Run `/foundry_create_project`, `/foundry_analyze_project`, `/foundry_list_specs`, `/foundry_load_spec`, `/foundry_create_spec` or `/foundry_update_spec` for step-by-step guidance on each task.
+
+{{> conventions}}"###
+    }
+
+    fn file_path(config_dir: &Path) -> Result<PathBuf> {
+        Ok(config_dir.join("rules").join("foundry.md"))
+    }
+}
@@ -0,0 +1,213 @@
+//! Per-call phase timing used for slow-operation diagnostics
+//!
+//! The MCP handler runs each tool call inside [`collect`]; code along the way
+//! marks the phases it belongs to with a [`start`] guard. Outside a
+//! collecting scope (CLI commands, tests) the guards do nothing.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::types::responses::{OperationTiming, PhaseTiming, ResponseDiagnostics};
+
+/// Broad category of work within an operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    /// Storage calls through the `Foundry` façade
+    Backend,
+    /// Parsing task lists, wiki-links and command payloads
+    Parsing,
+    /// Content validation
+    Validation,
+    /// Everything not covered by another phase
+    Other,
+}
+
+/// Time spent per phase during one call
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PhaseTimings {
+    pub backend: Duration,
+    pub parsing: Duration,
+    pub validation: Duration,
+}
+
+impl PhaseTimings {
+    fn add(&mut self, phase: Phase, elapsed: Duration) {
+        match phase {
+            Phase::Backend => self.backend += elapsed,
+            Phase::Parsing => self.parsing += elapsed,
+            Phase::Validation => self.validation += elapsed,
+            Phase::Other => {}
+        }
+    }
+
+    /// Each phase with its duration for a call that took `total`, `Other` being the remainder
+    pub fn breakdown(&self, total: Duration) -> Vec<(Phase, Duration)> {
+        let measured = self.backend + self.parsing + self.validation;
+        vec![
+            (Phase::Backend, self.backend),
+            (Phase::Parsing, self.parsing),
+            (Phase::Validation, self.validation),
+            (Phase::Other, total.saturating_sub(measured)),
+        ]
+    }
+
+    /// The phase that took the most time out of `total`
+    pub fn dominant_phase(&self, total: Duration) -> Phase {
+        self.breakdown(total)
+            .into_iter()
+            .max_by_key(|(_, elapsed)| *elapsed)
+            .map_or(Phase::Other, |(phase, _)| phase)
+    }
+
+    /// Slow-operation warning and breakdown for `tool` taking `total` against `threshold`
+    pub fn slow_operation_diagnostics(
+        &self,
+        tool: &str,
+        total: Duration,
+        threshold: Duration,
+    ) -> ResponseDiagnostics {
+        let total_ms = total.as_millis() as u64;
+        let threshold_ms = threshold.as_millis() as u64;
+        let dominant_phase = self.dominant_phase(total);
+        ResponseDiagnostics {
+            warnings: vec![format!(
+                "Slow operation: {} took {} ms (threshold {} ms); most time was spent in {}",
+                tool,
+                total_ms,
+                threshold_ms,
+                dominant_phase.label()
+            )],
+            timing: Some(OperationTiming {
+                total_ms,
+                threshold_ms,
+                dominant_phase,
+                phases: self
+                    .breakdown(total)
+                    .into_iter()
+                    .map(|(phase, elapsed)| PhaseTiming {
+                        phase,
+                        ms: elapsed.as_millis() as u64,
+                    })
+                    .collect(),
+            }),
+        }
+    }
+}
+
+impl Phase {
+    /// Description used in warnings
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Backend => "backend calls",
+            Self::Parsing => "parsing",
+            Self::Validation => "validation",
+            Self::Other => "other work",
+        }
+    }
+}
+
+tokio::task_local! {
+    static TIMINGS: RefCell<PhaseTimings>;
+}
+
+/// Run `future` while collecting the phase timings recorded inside it
+pub async fn collect<F: Future>(future: F) -> (F::Output, PhaseTimings) {
+    TIMINGS
+        .scope(RefCell::new(PhaseTimings::default()), async move {
+            let output = future.await;
+            let timings = TIMINGS.with(|timings| timings.borrow().clone());
+            (output, timings)
+        })
+        .await
+}
+
+/// Attribute the time until the returned guard is dropped to `phase`
+pub fn start(phase: Phase) -> PhaseGuard {
+    PhaseGuard {
+        phase,
+        started: Instant::now(),
+    }
+}
+
+/// Records its lifetime against a phase when dropped
+#[derive(Debug)]
+#[must_use = "the phase is timed until the guard is dropped"]
+pub struct PhaseGuard {
+    phase: Phase,
+    started: Instant,
+}
+
+impl Drop for PhaseGuard {
+    fn drop(&mut self) {
+        let elapsed = self.started.elapsed();
+        let _ = TIMINGS.try_with(|timings| timings.borrow_mut().add(self.phase, elapsed));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_collect_records_guarded_phases() {
+        let ((), timings) = collect(async {
+            let _backend = start(Phase::Backend);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        })
+        .await;
+
+        assert!(timings.backend >= Duration::from_millis(20));
+        assert_eq!(timings.parsing, Duration::ZERO);
+        assert_eq!(
+            timings.dominant_phase(timings.backend + Duration::from_millis(1)),
+            Phase::Backend
+        );
+    }
+
+    #[test]
+    fn test_guard_outside_collect_is_ignored() {
+        drop(start(Phase::Parsing));
+    }
+
+    #[test]
+    fn test_slow_operation_diagnostics_names_dominant_phase() {
+        let timings = PhaseTimings {
+            backend: Duration::from_millis(1800),
+            validation: Duration::from_millis(300),
+            ..Default::default()
+        };
+        let diagnostics = timings.slow_operation_diagnostics(
+            "load_spec",
+            Duration::from_millis(2500),
+            Duration::from_secs(2),
+        );
+
+        assert_eq!(
+            diagnostics.warnings,
+            vec![
+                "Slow operation: load_spec took 2500 ms (threshold 2000 ms); most time was spent in backend calls"
+            ]
+        );
+        let timing = diagnostics.timing.unwrap();
+        assert_eq!(timing.dominant_phase, Phase::Backend);
+        let other = timing.phases.iter().find(|p| p.phase == Phase::Other);
+        assert_eq!(other.unwrap().ms, 400);
+    }
+
+    #[test]
+    fn test_unmeasured_time_is_other() {
+        let timings = PhaseTimings {
+            backend: Duration::from_millis(5),
+            ..Default::default()
+        };
+        assert_eq!(
+            timings.dominant_phase(Duration::from_millis(100)),
+            Phase::Other
+        );
+    }
+}
@@ -1,5 +1,13 @@
 //! Content validation logic
+//!
+//! Every content type has a minimum length set by the validation profile
+//! (`strict`, `standard` or `lenient`), and may carry custom rules from config:
+//! required sections, banned phrases and a maximum length.
 
+use serde::Deserialize;
+
+use crate::core::timing::{self, Phase};
+use crate::types::responses::RuleFailure;
 use crate::utils::validation::{
     conditional_error, conditional_suggestion, conditional_suggestions,
 };
@@ -15,30 +23,220 @@ pub enum ContentType {
     Tasks,
 }
 
+impl ContentType {
+    /// Every content type
+    pub const ALL: [ContentType; 6] = [
+        ContentType::Vision,
+        ContentType::TechStack,
+        ContentType::Summary,
+        ContentType::Spec,
+        ContentType::Notes,
+        ContentType::Tasks,
+    ];
+
+    /// Name used in config files, e.g. `tech_stack`
+    pub fn key(self) -> &'static str {
+        match self {
+            ContentType::Vision => "vision",
+            ContentType::TechStack => "tech_stack",
+            ContentType::Summary => "summary",
+            ContentType::Spec => "spec",
+            ContentType::Notes => "notes",
+            ContentType::Tasks => "tasks",
+        }
+    }
+
+    /// Minimum length in characters unless configured otherwise
+    pub fn default_min_length(self) -> usize {
+        match self {
+            ContentType::Vision => 200,
+            ContentType::TechStack => 150,
+            ContentType::Summary | ContentType::Spec => 100,
+            ContentType::Notes => 50,
+            ContentType::Tasks => 30,
+        }
+    }
+}
+
+/// How demanding validation is, set with `[validation] profile` in config
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationProfile {
+    /// Half again the standard minimum lengths, no placeholder text, and
+    /// specs with failing content are rejected
+    Strict,
+    /// Built-in minimum lengths; only projects with failing content are rejected
+    #[default]
+    Standard,
+    /// Content only has to be non-empty, and failures never reject anything
+    Lenient,
+}
+
+impl ValidationProfile {
+    /// Name used in config files and responses
+    pub fn name(self) -> &'static str {
+        match self {
+            ValidationProfile::Strict => "strict",
+            ValidationProfile::Standard => "standard",
+            ValidationProfile::Lenient => "lenient",
+        }
+    }
+
+    /// Minimum length of `content_type` unless configured otherwise
+    pub fn min_length(self, content_type: ContentType) -> usize {
+        let standard = content_type.default_min_length();
+        match self {
+            ValidationProfile::Strict => standard * 3 / 2,
+            ValidationProfile::Standard => standard,
+            ValidationProfile::Lenient => 1,
+        }
+    }
+
+    /// Rules applied to every content type on top of configured ones
+    pub fn rules(self) -> ContentRules {
+        match self {
+            ValidationProfile::Strict => ContentRules {
+                banned_phrases: vec!["lorem ipsum".to_string(), "TBD".to_string()],
+                ..ContentRules::default()
+            },
+            ValidationProfile::Standard | ValidationProfile::Lenient => ContentRules::default(),
+        }
+    }
+
+    /// Whether create_project rejects content that fails validation
+    pub fn rejects_projects(self) -> bool {
+        self != ValidationProfile::Lenient
+    }
+
+    /// Whether create_spec and update_spec reject content that fails validation,
+    /// rather than reporting the failures as warnings
+    pub fn rejects_specs(self) -> bool {
+        self == ValidationProfile::Strict
+    }
+}
+
+/// Custom rules for one content type, from `[validation.rules.<content_type>]`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ContentRules {
+    /// Markdown headings the content must have, matched without `#` and case
+    pub required_sections: Vec<String>,
+    /// Phrases the content must not contain, matched ignoring case
+    pub banned_phrases: Vec<String>,
+    /// Maximum length in characters
+    pub max_length: Option<usize>,
+}
+
+impl ContentRules {
+    /// The rules `content` breaks
+    pub fn check(&self, content: &str) -> Vec<RuleFailure> {
+        let mut failures = Vec::new();
+        if let Some(max_length) = self.max_length
+            && content.len() > max_length
+        {
+            failures.push(RuleFailure {
+                rule: "max_length".to_string(),
+                message: format!(
+                    "Content must be at most {} characters (has {})",
+                    max_length,
+                    content.len()
+                ),
+            });
+        }
+
+        let headings: Vec<String> = content
+            .lines()
+            .filter(|line| line.trim_start().starts_with('#'))
+            .map(heading_text)
+            .collect();
+        failures.extend(
+            self.required_sections
+                .iter()
+                .filter(|section| !headings.contains(&heading_text(section)))
+                .map(|section| RuleFailure {
+                    rule: "required_section".to_string(),
+                    message: format!("Missing required section '{}'", section),
+                }),
+        );
+
+        let lower_content = content.to_lowercase();
+        failures.extend(
+            self.banned_phrases
+                .iter()
+                .filter(|phrase| lower_content.contains(&phrase.to_lowercase()))
+                .map(|phrase| RuleFailure {
+                    rule: "banned_phrase".to_string(),
+                    message: format!("Contains banned phrase '{}'", phrase),
+                }),
+        );
+        failures
+    }
+}
+
+/// Heading text without leading `#`s, trimmed and lowercased
+fn heading_text(line: &str) -> String {
+    line.trim().trim_start_matches('#').trim().to_lowercase()
+}
+
 /// Validation result
 pub struct ValidationResult {
     pub is_valid: bool,
     pub errors: Vec<String>,
     pub suggestions: Vec<String>,
+    /// Rule behind each error, in the same order
+    pub failed_rules: Vec<RuleFailure>,
+}
+
+impl ValidationResult {
+    /// Record `failures` as errors
+    pub fn add_failures(&mut self, failures: Vec<RuleFailure>) {
+        if failures.is_empty() {
+            return;
+        }
+        self.is_valid = false;
+        self.errors
+            .extend(failures.iter().map(|failure| failure.message.clone()));
+        self.failed_rules.extend(failures);
+    }
+}
+
+fn min_length_failures(errors: &[String]) -> Vec<RuleFailure> {
+    errors
+        .iter()
+        .map(|message| RuleFailure {
+            rule: "min_length".to_string(),
+            message: message.clone(),
+        })
+        .collect()
 }
 
 /// Validate content based on type
 pub fn validate_content(content_type: ContentType, content: &str) -> ValidationResult {
+    validate_content_with_min_length(content_type, content, content_type.default_min_length())
+}
+
+/// Validate content based on type, requiring at least `min_length` characters
+pub fn validate_content_with_min_length(
+    content_type: ContentType,
+    content: &str,
+    min_length: usize,
+) -> ValidationResult {
+    let _validation = timing::start(Phase::Validation);
     match content_type {
-        ContentType::Vision => validate_vision_content(content),
-        ContentType::TechStack => validate_tech_stack_content(content),
-        ContentType::Summary => validate_summary_content(content),
-        ContentType::Spec => validate_spec_content(content),
-        ContentType::Notes => validate_notes_content(content),
-        ContentType::Tasks => validate_tasks_content(content),
+        ContentType::Vision => validate_vision_content(content, min_length),
+        ContentType::TechStack => validate_tech_stack_content(content, min_length),
+        ContentType::Summary => validate_summary_content(content, min_length),
+        ContentType::Spec => validate_spec_content(content, min_length),
+        ContentType::Notes => validate_notes_content(content, min_length),
+        ContentType::Tasks => validate_tasks_content(content, min_length),
     }
 }
 
 /// Validate vision content (2-4 paragraphs, 200+ characters)
-fn validate_vision_content(content: &str) -> ValidationResult {
+fn validate_vision_content(content: &str, min_length: usize) -> ValidationResult {
     let errors = conditional_error(
-        content.len() < 200,
-        "Vision content must be at least 200 characters",
+        content.len() < min_length,
+        format!("Vision content must be at least {} characters", min_length),
     );
 
     let paragraphs_count = content
@@ -86,16 +284,20 @@ fn validate_vision_content(content: &str) -> ValidationResult {
 
     ValidationResult {
         is_valid: errors.is_empty(),
+        failed_rules: min_length_failures(&errors),
         errors,
         suggestions,
     }
 }
 
 /// Validate tech stack content (150+ characters)
-fn validate_tech_stack_content(content: &str) -> ValidationResult {
+fn validate_tech_stack_content(content: &str, min_length: usize) -> ValidationResult {
     let errors = conditional_error(
-        content.len() < 150,
-        "Tech stack content must be at least 150 characters",
+        content.len() < min_length,
+        format!(
+            "Tech stack content must be at least {} characters",
+            min_length
+        ),
     );
 
     let lower_content = content.to_lowercase();
@@ -117,16 +319,17 @@ fn validate_tech_stack_content(content: &str) -> ValidationResult {
 
     ValidationResult {
         is_valid: errors.is_empty(),
+        failed_rules: min_length_failures(&errors),
         errors,
         suggestions,
     }
 }
 
 /// Validate summary content (100+ characters, concise)
-fn validate_summary_content(content: &str) -> ValidationResult {
+fn validate_summary_content(content: &str, min_length: usize) -> ValidationResult {
     let errors = conditional_error(
-        content.len() < 100,
-        "Summary content must be at least 100 characters",
+        content.len() < min_length,
+        format!("Summary content must be at least {} characters", min_length),
     );
 
     let suggestions = conditional_suggestion(
@@ -136,16 +339,17 @@ fn validate_summary_content(content: &str) -> ValidationResult {
 
     ValidationResult {
         is_valid: errors.is_empty(),
+        failed_rules: min_length_failures(&errors),
         errors,
         suggestions,
     }
 }
 
 /// Validate spec content
-fn validate_spec_content(content: &str) -> ValidationResult {
+fn validate_spec_content(content: &str, min_length: usize) -> ValidationResult {
     let errors = conditional_error(
-        content.len() < 100,
-        "Spec content must be at least 100 characters",
+        content.len() < min_length,
+        format!("Spec content must be at least {} characters", min_length),
     );
 
     let lower_content = content.to_lowercase();
@@ -184,16 +388,17 @@ fn validate_spec_content(content: &str) -> ValidationResult {
 
     ValidationResult {
         is_valid: errors.is_empty(),
+        failed_rules: min_length_failures(&errors),
         errors,
         suggestions,
     }
 }
 
 /// Validate notes content
-fn validate_notes_content(content: &str) -> ValidationResult {
+fn validate_notes_content(content: &str, min_length: usize) -> ValidationResult {
     let errors = conditional_error(
-        content.len() < 50,
-        "Notes content must be at least 50 characters",
+        content.len() < min_length,
+        format!("Notes content must be at least {} characters", min_length),
     );
 
     let lower_content = content.to_lowercase();
@@ -225,16 +430,17 @@ fn validate_notes_content(content: &str) -> ValidationResult {
 
     ValidationResult {
         is_valid: errors.is_empty(),
+        failed_rules: min_length_failures(&errors),
         errors,
         suggestions,
     }
 }
 
 /// Validate tasks content
-fn validate_tasks_content(content: &str) -> ValidationResult {
+fn validate_tasks_content(content: &str, min_length: usize) -> ValidationResult {
     let errors = conditional_error(
-        content.len() < 30,
-        "Tasks content must be at least 30 characters",
+        content.len() < min_length,
+        format!("Tasks content must be at least {} characters", min_length),
     );
 
     let lower_content = content.to_lowercase();
@@ -250,6 +456,7 @@ fn validate_tasks_content(content: &str) -> ValidationResult {
 
     ValidationResult {
         is_valid: errors.is_empty(),
+        failed_rules: min_length_failures(&errors),
         errors,
         suggestions,
     }
@@ -275,7 +482,7 @@ mod tests {
     #[test]
     fn test_validate_vision_content_too_short() {
         let content = "Too short";
-        let result = validate_vision_content(content);
+        let result = validate_vision_content(content, ContentType::Vision.default_min_length());
 
         assert!(!result.is_valid);
         assert_eq!(result.errors.len(), 1);
@@ -285,7 +492,8 @@ mod tests {
     #[test]
     fn test_validate_tech_stack_content_too_short() {
         let content = "Too short";
-        let result = validate_tech_stack_content(content);
+        let result =
+            validate_tech_stack_content(content, ContentType::TechStack.default_min_length());
 
         assert!(!result.is_valid);
         assert_eq!(result.errors.len(), 1);
@@ -295,7 +503,7 @@ mod tests {
     #[test]
     fn test_validate_summary_content_too_short() {
         let content = "Too short";
-        let result = validate_summary_content(content);
+        let result = validate_summary_content(content, ContentType::Summary.default_min_length());
 
         assert!(!result.is_valid);
         assert_eq!(result.errors.len(), 1);
@@ -305,7 +513,7 @@ mod tests {
     #[test]
     fn test_validate_spec_content_too_short() {
         let content = "Too short";
-        let result = validate_spec_content(content);
+        let result = validate_spec_content(content, ContentType::Spec.default_min_length());
 
         assert!(!result.is_valid);
         assert_eq!(result.errors.len(), 1);
@@ -315,13 +523,51 @@ mod tests {
     #[test]
     fn test_validate_notes_content_too_short() {
         let content = "Too short";
-        let result = validate_notes_content(content);
+        let result = validate_notes_content(content, ContentType::Notes.default_min_length());
 
         assert!(!result.is_valid);
         assert_eq!(result.errors.len(), 1);
         assert!(result.errors[0].contains("50 characters"));
     }
 
+    #[test]
+    fn test_content_rules_report_each_failure() {
+        let rules = ContentRules {
+            required_sections: vec!["Requirements".to_string(), "## Rollout".to_string()],
+            banned_phrases: vec!["tbd".to_string()],
+            max_length: Some(40),
+        };
+        let content = "# Spec\n\n## requirements\n\nOwner: TBD, decided later";
+        let rules_failed: Vec<String> = rules
+            .check(content)
+            .into_iter()
+            .map(|failure| failure.rule)
+            .collect();
+        assert_eq!(
+            rules_failed,
+            vec!["max_length", "required_section", "banned_phrase"]
+        );
+        assert!(ContentRules::default().check(content).is_empty());
+    }
+
+    #[test]
+    fn test_profiles_scale_min_length() {
+        assert_eq!(
+            ValidationProfile::Strict.min_length(ContentType::Vision),
+            300
+        );
+        assert_eq!(
+            ValidationProfile::Standard.min_length(ContentType::Vision),
+            200
+        );
+        assert_eq!(
+            ValidationProfile::Lenient.min_length(ContentType::Vision),
+            1
+        );
+        assert!(!ValidationProfile::Lenient.rejects_projects());
+        assert!(ValidationProfile::Strict.rejects_specs());
+    }
+
     #[test]
     fn test_parse_content_type_valid() {
         assert!(matches!(
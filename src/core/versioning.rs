@@ -0,0 +1,189 @@
+//! Content versions for optimistic concurrency
+//!
+//! Every spec file has a version: a short SHA-256 of its content. `load_spec`
+//! returns the versions it read, and `update_spec` can take the version a
+//! caller last saw as `expected_version`; if the file has changed since, the
+//! update fails with a `BACKEND_CONFLICT` error instead of overwriting the
+//! other session's edit.
+//!
+//! Versions are derived from content rather than stored, so they work with any
+//! backend and need no migration. Content this process has returned is kept in
+//! a small cache so a conflict can show what changed since the caller's read.
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+use ring::digest;
+
+use crate::types::responses::SpecFileVersions;
+use crate::types::spec::{SpecContentData, SpecFileType};
+
+/// Hex digits of the SHA-256 kept in a version
+const VERSION_HEX_LEN: usize = 16;
+
+/// Versions of recently seen content kept for conflict diffs
+const MAX_REMEMBERED: usize = 64;
+
+/// Lines of unchanged context around each change in a conflict diff
+const DIFF_CONTEXT_LINES: usize = 2;
+
+/// Larger files are not diffed line by line
+const MAX_DIFF_LINES: usize = 2000;
+
+static REMEMBERED: OnceLock<Mutex<VecDeque<(String, String)>>> = OnceLock::new();
+
+/// Version of `content`
+pub fn content_version(content: &str) -> String {
+    let hash = digest::digest(&digest::SHA256, content.as_bytes());
+    hash.as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>()[..VERSION_HEX_LEN]
+        .to_string()
+}
+
+/// Versions of all three spec files, remembering their content
+pub fn spec_versions(content: &SpecContentData) -> SpecFileVersions {
+    SpecFileVersions {
+        spec: remember(&content.spec),
+        notes: remember(&content.notes),
+        tasks: remember(&content.tasks),
+    }
+}
+
+/// The version of `file_type` within `versions`
+pub fn version_of(versions: &SpecFileVersions, file_type: &SpecFileType) -> String {
+    match file_type {
+        SpecFileType::Spec => versions.spec.clone(),
+        SpecFileType::Notes => versions.notes.clone(),
+        SpecFileType::TaskList => versions.tasks.clone(),
+    }
+}
+
+/// Version of `content`, kept so a later conflict can be diffed against it
+pub fn remember(content: &str) -> String {
+    let version = content_version(content);
+    let mut remembered = cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if !remembered.iter().any(|(known, _)| *known == version) {
+        if remembered.len() == MAX_REMEMBERED {
+            remembered.pop_front();
+        }
+        remembered.push_back((version.clone(), content.to_string()));
+    }
+    version
+}
+
+/// Content this process returned under `version`, if still cached
+pub fn recall(version: &str) -> Option<String> {
+    cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .find(|(known, _)| known == version)
+        .map(|(_, content)| content.clone())
+}
+
+fn cache() -> &'static Mutex<VecDeque<(String, String)>> {
+    REMEMBERED.get_or_init(|| Mutex::new(VecDeque::with_capacity(MAX_REMEMBERED)))
+}
+
+/// Line diff from `old` to `new` with a little context, `-`/`+`/` ` prefixed
+///
+/// Runs of unchanged lines between changes are collapsed to `...`.
+pub fn line_diff(old: &str, new: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    if old_lines.len() > MAX_DIFF_LINES || new_lines.len() > MAX_DIFF_LINES {
+        return format!(
+            "(files too large to diff: {} -> {} lines)",
+            old_lines.len(),
+            new_lines.len()
+        );
+    }
+
+    // Longest common subsequence table, filled from the end
+    let mut lcs = vec![vec![0usize; new_lines.len() + 1]; old_lines.len() + 1];
+    for i in (0..old_lines.len()).rev() {
+        for j in (0..new_lines.len()).rev() {
+            lcs[i][j] = if old_lines[i] == new_lines[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops: Vec<(char, &str)> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old_lines.len() || j < new_lines.len() {
+        if i < old_lines.len() && j < new_lines.len() && old_lines[i] == new_lines[j] {
+            ops.push((' ', old_lines[i]));
+            i += 1;
+            j += 1;
+        } else if i < old_lines.len() && (j == new_lines.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push(('-', old_lines[i]));
+            i += 1;
+        } else {
+            ops.push(('+', new_lines[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (kind, _))| *kind != ' ')
+        .map(|(index, _)| index)
+        .collect();
+    let near_change = |index: usize| {
+        changed
+            .iter()
+            .any(|&change| index.abs_diff(change) <= DIFF_CONTEXT_LINES)
+    };
+
+    let mut output = Vec::new();
+    let mut skipped = false;
+    for (index, (kind, line)) in ops.iter().enumerate() {
+        if near_change(index) {
+            output.push(format!("{}{}", kind, line));
+            skipped = false;
+        } else if !skipped {
+            output.push("...".to_string());
+            skipped = true;
+        }
+    }
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_version_is_stable_and_short() {
+        let version = content_version("- [ ] Task");
+        assert_eq!(version.len(), VERSION_HEX_LEN);
+        assert_eq!(version, content_version("- [ ] Task"));
+        assert_ne!(version, content_version("- [x] Task"));
+    }
+
+    #[test]
+    fn test_remember_and_recall() {
+        let version = remember("remembered content for recall");
+        assert_eq!(
+            recall(&version).as_deref(),
+            Some("remembered content for recall")
+        );
+        assert_eq!(recall("0000000000000000"), None);
+    }
+
+    #[test]
+    fn test_line_diff_shows_changes_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh";
+        let new = "a\nb\nc\nd\nE\nf\ng\nh";
+        assert_eq!(line_diff(old, new), "...\n c\n d\n-e\n+E\n f\n g\n...");
+        assert_eq!(line_diff("one", "one\ntwo"), " one\n+two");
+    }
+}
@@ -0,0 +1,426 @@
+//! Multiple foundry roots ("workspaces")
+//!
+//! `~/.foundry/workspaces.toml` names extra foundry directories, each with its
+//! own projects, config and backups:
+//!
+//! ```toml
+//! [workspaces]
+//! work = "~/work/.foundry"
+//! personal = "~/personal/.foundry"
+//! ```
+//!
+//! `default` is the home foundry directory (see [`home_foundry_dir`]). The CLI
+//! picks a workspace with `--workspace` (or `FOUNDRY_WORKSPACE`) and MCP calls
+//! with a `workspace` parameter; everything under
+//! [`crate::core::filesystem::foundry_dir`] follows the workspace in effect.
+//! The registry itself is only read from the home foundry directory.
+//!
+//! The home foundry directory is `$FOUNDRY_HOME` when set. Otherwise it is
+//! `$XDG_DATA_HOME/foundry` (`~/.local/share/foundry` when `XDG_DATA_HOME` is
+//! unset) if that exists or `XDG_DATA_HOME` is set and there is no
+//! `~/.foundry`, else `~/.foundry`. [`migrate_home`] moves an existing
+//! `~/.foundry` to the XDG location and leaves a symlink behind.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::core::backends::{self, SharedBackend};
+use crate::core::config::BackendConfig;
+use crate::core::foundry;
+use crate::types::errors::ErrorCode;
+use crate::utils::paths;
+
+/// Environment variable overriding the home foundry directory
+pub const FOUNDRY_HOME_ENV: &str = "FOUNDRY_HOME";
+
+/// Workspace registry file in the home foundry directory
+pub const REGISTRY_FILE: &str = "workspaces.toml";
+
+/// Name of the workspace at the home foundry directory
+pub const DEFAULT_WORKSPACE: &str = "default";
+
+/// Outcome of [`migrate_home`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HomeMigration {
+    /// Directory the data was moved from, `~/.foundry`
+    pub from: PathBuf,
+    /// Directory the data now lives in
+    pub to: PathBuf,
+    /// Whether `from` was replaced by a symlink to `to`
+    pub symlinked: bool,
+}
+
+/// Contents of `workspaces.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Registry {
+    /// Workspace name to foundry directory
+    pub workspaces: BTreeMap<String, PathBuf>,
+}
+
+tokio::task_local! {
+    static WORKSPACE_DIR: PathBuf;
+}
+
+static DEFAULT_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Backends opened for workspaces named in MCP calls, by directory
+static BACKENDS: OnceLock<Mutex<HashMap<PathBuf, SharedBackend>>> = OnceLock::new();
+
+/// Run `future` with `foundry_dir()` inside it resolving to `dir`
+pub async fn with_workspace_dir<F: Future>(dir: PathBuf, future: F) -> F::Output {
+    WORKSPACE_DIR.scope(dir, future).await
+}
+
+/// Make `dir` the foundry directory of the whole process, e.g. the one chosen
+/// with `--workspace`; later calls keep the first directory
+pub fn set_default_dir(dir: PathBuf) {
+    if DEFAULT_DIR.set(dir).is_err() {
+        tracing::debug!("Default workspace already set");
+    }
+}
+
+/// The default workspace and home of the registry: `$FOUNDRY_HOME`, the
+/// XDG data directory or `~/.foundry` (see the module docs)
+pub fn home_foundry_dir() -> Result<PathBuf> {
+    if let Some(dir) = std::env::var_os(FOUNDRY_HOME_ENV).filter(|dir| !dir.is_empty()) {
+        return Ok(paths::expand_home(PathBuf::from(dir)));
+    }
+    let xdg_dir = xdg_foundry_dir()?;
+    let legacy_dir = legacy_foundry_dir()?;
+    let xdg_requested = std::env::var_os("XDG_DATA_HOME").is_some_and(|dir| !dir.is_empty());
+    if xdg_dir.is_dir() || (xdg_requested && !legacy_dir.exists()) {
+        return Ok(xdg_dir);
+    }
+    Ok(legacy_dir)
+}
+
+/// `~/.foundry`, where foundry data lived before XDG support
+pub fn legacy_foundry_dir() -> Result<PathBuf> {
+    Ok(dirs::home_dir()
+        .context("Could not determine home directory")?
+        .join(".foundry"))
+}
+
+/// `$XDG_DATA_HOME/foundry`, or `~/.local/share/foundry` without `XDG_DATA_HOME`
+pub fn xdg_foundry_dir() -> Result<PathBuf> {
+    if let Some(data_home) = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+    {
+        return Ok(data_home.join("foundry"));
+    }
+    Ok(dirs::home_dir()
+        .context("Could not determine home directory")?
+        .join(".local")
+        .join("share")
+        .join("foundry"))
+}
+
+/// Move `~/.foundry` to `to` (the XDG data directory by default) and leave a
+/// symlink at `~/.foundry` so older installs and scripts keep working
+///
+/// Fails if `~/.foundry` is missing or already a symlink, or if `to` holds
+/// anything. A failed symlink is reported, not an error: the data has moved
+/// and is found at the XDG location either way.
+pub fn migrate_home(to: Option<&Path>) -> Result<HomeMigration> {
+    let from = legacy_foundry_dir()?;
+    let to = to.map_or_else(xdg_foundry_dir, |dir| Ok(paths::expand_home(dir)))?;
+    if !to.is_absolute() {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Migration target must be an absolute path, got {:?}",
+            to
+        )));
+    }
+    let metadata = std::fs::symlink_metadata(&from).map_err(|_| {
+        ErrorCode::InvalidParams.error(format!("Nothing to migrate: {:?} does not exist", from))
+    })?;
+    if metadata.file_type().is_symlink() {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "{:?} is already a symlink to {:?}; nothing to migrate",
+            from,
+            std::fs::read_link(&from).unwrap_or_default()
+        )));
+    }
+    if to.starts_with(&from) {
+        return Err(ErrorCode::InvalidParams
+            .error(format!("Cannot migrate {:?} into itself ({:?})", from, to)));
+    }
+    if to.exists() {
+        let empty = std::fs::read_dir(&to)
+            .map(|mut entries| entries.next().is_none())
+            .unwrap_or(false);
+        if !empty {
+            return Err(ErrorCode::AlreadyExists.error(format!(
+                "{:?} already exists and is not empty; move or remove it before migrating",
+                to
+            )));
+        }
+        std::fs::remove_dir(&to).with_context(|| format!("Failed to remove empty {:?}", to))?;
+    }
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {:?}", parent))?;
+    }
+
+    if std::fs::rename(&from, &to).is_err() {
+        // Different filesystems: copy, then remove the original
+        copy_dir(&from, &to).with_context(|| format!("Failed to copy {:?} to {:?}", from, to))?;
+        std::fs::remove_dir_all(&from)
+            .with_context(|| format!("Copied to {:?} but failed to remove {:?}", to, from))?;
+    }
+
+    let symlinked = match symlink_dir(&to, &from) {
+        Ok(()) => true,
+        Err(e) => {
+            tracing::warn!("Failed to link {:?} to {:?}: {}", from, to, e);
+            false
+        }
+    };
+    Ok(HomeMigration {
+        from,
+        to,
+        symlinked,
+    })
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if file_type.is_symlink() {
+            symlink_dir(&std::fs::read_link(entry.path())?, &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn symlink_dir(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(windows)]
+fn symlink_dir(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::windows::fs::symlink_dir(target, link)
+}
+
+/// Foundry directory in effect: the one from [`with_workspace_dir`], then
+/// [`set_default_dir`], then [`home_foundry_dir`]
+pub fn current_dir() -> Result<PathBuf> {
+    WORKSPACE_DIR
+        .try_with(Clone::clone)
+        .ok()
+        .or_else(|| DEFAULT_DIR.get().cloned())
+        .map_or_else(home_foundry_dir, Ok)
+}
+
+/// `workspaces.toml` in the home foundry directory
+pub fn registry_path() -> Result<PathBuf> {
+    Ok(home_foundry_dir()?.join(REGISTRY_FILE))
+}
+
+/// Read the registry; a missing file is an empty registry
+pub fn read_registry() -> Result<Registry> {
+    let path = registry_path()?;
+    if !path.exists() {
+        return Ok(Registry::default());
+    }
+    let content =
+        std::fs::read_to_string(&path).with_context(|| format!("Failed to read {:?}", path))?;
+    let mut registry: Registry = toml::from_str(&content).map_err(|e| {
+        ErrorCode::ValidationFailed.error(format!("Invalid workspace registry {:?}: {}", path, e))
+    })?;
+    for dir in registry.workspaces.values_mut() {
+        *dir = paths::expand_home(&*dir);
+    }
+    Ok(registry)
+}
+
+fn write_registry(registry: &Registry) -> Result<()> {
+    let content = toml::to_string(registry).context("Failed to serialize workspace registry")?;
+    crate::core::filesystem::write_file_atomic(registry_path()?, &content)
+}
+
+/// Every workspace, `default` first, with its directory
+pub fn list() -> Result<Vec<(String, PathBuf)>> {
+    let mut workspaces = vec![(DEFAULT_WORKSPACE.to_string(), home_foundry_dir()?)];
+    workspaces.extend(read_registry()?.workspaces);
+    Ok(workspaces)
+}
+
+/// Directory of the workspace called `name`
+pub fn resolve(name: &str) -> Result<PathBuf> {
+    if name == DEFAULT_WORKSPACE {
+        return home_foundry_dir();
+    }
+    read_registry()?
+        .workspaces
+        .remove(name)
+        .ok_or_else(|| {
+            let names = list()
+                .map(|workspaces| {
+                    workspaces
+                        .into_iter()
+                        .map(|(name, _)| name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+            ErrorCode::InvalidParams.error(format!(
+                "Unknown workspace '{}'. Available workspaces: {}. Add one with 'foundry workspace add <name> <path>'.",
+                name, names
+            ))
+        })
+}
+
+/// Register `dir` as workspace `name`, replacing an existing entry
+pub fn add(name: &str, dir: &Path) -> Result<PathBuf> {
+    validate_name(name)?;
+    if name == DEFAULT_WORKSPACE {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "'{}' is always the home foundry directory and cannot be registered",
+            DEFAULT_WORKSPACE
+        )));
+    }
+    let dir = paths::expand_home(dir);
+    if !dir.is_absolute() {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Workspace directory must be an absolute path, got {:?}",
+            dir
+        )));
+    }
+    let mut registry = read_registry()?;
+    registry.workspaces.insert(name.to_string(), dir.clone());
+    write_registry(&registry)?;
+    Ok(dir)
+}
+
+/// Remove workspace `name` from the registry; its directory is left alone
+pub fn remove(name: &str) -> Result<PathBuf> {
+    let mut registry = read_registry()?;
+    let dir = registry.workspaces.remove(name).ok_or_else(|| {
+        ErrorCode::InvalidParams.error(format!("Workspace '{}' is not registered", name))
+    })?;
+    write_registry(&registry)?;
+    Ok(dir)
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(ErrorCode::InvalidParams.error(format!(
+            "Invalid workspace name '{}'. Use letters, digits, '-' and '_'",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Run `future` against workspace `name`: `foundry_dir()` resolves to its
+/// directory and `get_default_foundry()` to the backend its config selects
+///
+/// Backends are opened once per workspace and reused by later calls.
+pub async fn scoped<F: Future>(name: &str, future: F) -> Result<F::Output> {
+    let dir = resolve(name)?;
+    let backend = backend_for(&dir)?;
+    Ok(with_workspace_dir(dir, foundry::with_backend(backend, future)).await)
+}
+
+fn backend_for(dir: &Path) -> Result<SharedBackend> {
+    let cache = BACKENDS.get_or_init(Default::default);
+    if let Some(backend) = cache.lock().unwrap().get(dir) {
+        return Ok(Arc::clone(backend));
+    }
+    let backend = WORKSPACE_DIR.sync_scope(dir.to_path_buf(), || {
+        backends::resolve_backend_config(BackendConfig::default())
+            .and_then(|config| backends::open_configured_backend(&config))
+    })?;
+    Ok(Arc::clone(
+        cache
+            .lock()
+            .unwrap()
+            .entry(dir.to_path_buf())
+            .or_insert(backend),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_environment::TestEnvironment;
+
+    #[test]
+    fn test_workspace_names_are_validated() {
+        assert!(validate_name("client-a_2").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name("../up").is_err());
+        assert!(validate_name("has space").is_err());
+    }
+
+    fn with_home<T>(home: &Path, xdg_data_home: Option<&Path>, f: impl FnOnce() -> T) -> T {
+        temp_env::with_vars(
+            [
+                ("HOME", Some(home.as_os_str())),
+                ("FOUNDRY_HOME", None),
+                ("XDG_DATA_HOME", xdg_data_home.map(Path::as_os_str)),
+            ],
+            f,
+        )
+    }
+
+    #[test]
+    fn test_home_dir_prefers_foundry_home_then_existing_dirs() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path();
+        let xdg = home.join("data");
+
+        with_home(home, None, || {
+            assert_eq!(home_foundry_dir().unwrap(), home.join(".foundry"));
+            temp_env::with_var("FOUNDRY_HOME", Some("/srv/foundry"), || {
+                assert_eq!(home_foundry_dir().unwrap(), PathBuf::from("/srv/foundry"));
+            });
+        });
+        // A fresh install follows XDG_DATA_HOME
+        with_home(home, Some(&xdg), || {
+            assert_eq!(home_foundry_dir().unwrap(), xdg.join("foundry"));
+        });
+        // Existing ~/.foundry data wins until it is migrated
+        std::fs::create_dir_all(home.join(".foundry/demo")).unwrap();
+        with_home(home, Some(&xdg), || {
+            assert_eq!(home_foundry_dir().unwrap(), home.join(".foundry"));
+
+            let migration = migrate_home(None).unwrap();
+            assert_eq!(migration.to, xdg.join("foundry"));
+            assert!(migration.symlinked);
+            assert_eq!(home_foundry_dir().unwrap(), xdg.join("foundry"));
+            assert!(xdg.join("foundry/demo").is_dir());
+            assert!(home.join(".foundry/demo").is_dir());
+            assert!(migrate_home(None).is_err());
+        });
+    }
+
+    #[test]
+    fn test_scope_overrides_current_dir() {
+        let env = TestEnvironment::new().unwrap();
+        env.with_env_async(|| async {
+            let dir = env.temp_dir.path().join("workspace");
+            let inside = with_workspace_dir(dir.clone(), async { current_dir().unwrap() }).await;
+            assert_eq!(inside, dir);
+            assert_eq!(current_dir().unwrap(), env.foundry_dir());
+        });
+    }
+}
@@ -1,8 +1,11 @@
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{Args as _, CommandFactory, FromArgMatches, Parser, Subcommand};
 use std::env;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::time::Duration;
 
-use foundry_mcp::{cli, mcp};
+use foundry_mcp::{cli, mcp, utils};
 
 #[derive(Parser)]
 #[command(name = "foundry")]
@@ -17,14 +20,64 @@ Examples:
   foundry serve                                   # Start MCP server
   foundry install claude-code                     # Install MCP server for Claude Code
   foundry install cursor                          # Install MCP server for Cursor IDE
+  foundry install vscode                          # Install MCP server for VS Code
+  foundry install windsurf                        # Install MCP server for Windsurf
+  foundry install zed                             # Install MCP server for Zed
   foundry status                                  # Check installation status
+  foundry doctor --fix                            # Check ~/.foundry and installs, repair what is safe
   foundry uninstall claude-code                   # Remove MCP server from Claude Code
+  foundry schema --format openapi                 # Dump MCP tool schemas
+  foundry stats --since 7d                        # Tool usage from the wire log
+  foundry replay ~/.foundry/.logs/wire.jsonl      # Re-run recorded calls in memory
+  foundry export my-app                           # Bundle a project into my-app.foundry.tar.gz
+  foundry import my-app.foundry.tar.gz            # Restore a project from an archive
+  foundry backup list --project my-app            # Backups taken before destructive edits
+  foundry audit show my-app --since 7d            # Changes made by agents and people
+  foundry encryption keygen ~/.config/foundry/key # Key for encrypting specs at rest
+  foundry workspace add work ~/work/.foundry      # Register another foundry directory
+  foundry workspace migrate                       # Move ~/.foundry to ~/.local/share/foundry
+  foundry tui                                     # Browse projects and specs in the terminal
+  foundry completions zsh > ~/.zfunc/_foundry     # Shell completions, project and spec names included
+  foundry --workspace work serve                  # Serve the work workspace
 
 For project/spec operations, use Foundry MCP tools from your IDE/agent."
 )]
 struct Args {
     #[command(subcommand)]
     command: Commands,
+
+    /// Locale for guidance text and installed templates (default: $FOUNDRY_LOCALE, else en)
+    ///
+    /// Translations are read from ~/.foundry/.locales/<locale>.json
+    #[arg(long, global = true)]
+    locale: Option<String>,
+
+    /// Storage for projects and specs: filesystem (~/.foundry), git or notion
+    ///
+    /// Defaults to [backend] in ~/.foundry/config.toml, else filesystem; a
+    /// project's .foundry.toml can pick its own backend
+    #[arg(long, global = true)]
+    backend: Option<String>,
+
+    /// Working tree for --backend git (default: ~/.foundry/.git-backend)
+    #[arg(long, global = true)]
+    git_dir: Option<PathBuf>,
+
+    /// Remote for --backend git; cloned into --git-dir and pushed after every commit
+    #[arg(long, global = true)]
+    git_remote: Option<String>,
+
+    /// Root page (id or URL) for --backend notion; the token is read from $NOTION_TOKEN
+    #[arg(long, global = true)]
+    notion_page: Option<String>,
+
+    /// Print machine-readable JSON instead of text, errors included (default: $FOUNDRY_OUTPUT=json)
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Workspace to use, as registered with `foundry workspace add` (default: $FOUNDRY_WORKSPACE, else ~/.foundry)
+    #[arg(long, global = true)]
+    workspace: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -37,13 +90,13 @@ enum Commands {
 
     /// Install Foundry MCP server for AI development environments
     ///
-    /// Supports installation for claude-code and cursor environments
+    /// Supports installation for claude-code, cursor, vscode, windsurf and zed environments
     /// Creates necessary configuration files and registers the MCP server
     Install(cli::args::InstallArgs),
 
     /// Uninstall Foundry MCP server from AI development environments
     ///
-    /// Removes MCP server configuration from claude-code and cursor environments
+    /// Removes MCP server configuration from claude-code, cursor, vscode, windsurf and zed environments
     /// Optionally cleans up configuration files
     Uninstall(cli::args::UninstallArgs),
 
@@ -52,42 +105,302 @@ enum Commands {
     /// Displays installation status, binary paths, and configuration details
     /// for all supported AI development environments
     Status(cli::args::StatusArgs),
+
+    /// Print the MCP tool surface as a JSON document
+    ///
+    /// Emits every tool's input/output schema as JSON Schema (default) or
+    /// OpenAPI 3.1, for external validation, client codegen, and docs
+    Schema(cli::args::SchemaArgs),
+
+    /// Summarize recorded tool usage from the local wire log
+    ///
+    /// Reports calls, error rates and latencies per tool from logs written by
+    /// `foundry serve --wire-log`; nothing leaves the machine
+    Stats(cli::args::StatsArgs),
+
+    /// Re-run recorded tool calls against an in-memory backend
+    ///
+    /// Replays a wire log from `foundry serve --wire-log` in order, without
+    /// touching ~/.foundry, and reports where outcomes differ from the recording
+    Replay(cli::args::ReplayArgs),
+
+    /// List specs with open tasks and no recent activity
+    ///
+    /// Flags specs whose last task completion (or creation) is older than
+    /// --days, across every project or just --project
+    Stale(cli::args::StaleArgs),
+
+    /// Summarize specs created, tasks completed and notes added
+    ///
+    /// Covers --since (default 7d) up to --until across every project or just
+    /// --project; run it from cron for a scheduled weekly summary
+    Digest(cli::args::DigestArgs),
+
+    /// Search spec, notes and task-list text across projects
+    ///
+    /// Prints matching lines ranked by relevance with their project, spec,
+    /// file and line number
+    Search(cli::args::SearchArgs),
+
+    /// Export a project to a portable .tar.gz archive
+    ///
+    /// Bundles vision, tech stack, summary and every spec so the project can
+    /// be shared between machines or checked into a repository
+    Export(cli::args::ExportArgs),
+
+    /// Restore a project from an archive written by `foundry export`
+    ///
+    /// Creates the project and its specs; specs get new timestamped names
+    /// and links between them are updated
+    Import(cli::args::ImportArgs),
+
+    /// List, restore and prune spec backups
+    ///
+    /// Specs are backed up to ~/.foundry/.backups before delete_spec and
+    /// edits that replace or remove whole sections
+    Backup(cli::args::BackupArgs),
+
+    /// Show the audit log of changes made to a project
+    ///
+    /// Every change through MCP tools, the REST API or the CLI is appended to
+    /// ~/.foundry/.audit/<project>.jsonl with its interface and outcome
+    Audit(cli::args::AuditArgs),
+
+    /// Check ~/.foundry data, config and MCP installs and suggest fixes
+    ///
+    /// Finds orphaned specs, malformed metadata, invalid spec timestamps,
+    /// leftover temporary files, invalid config, missing Linear credentials
+    /// and broken installs; --fix repairs the problems it safely can
+    Doctor(cli::args::DoctorArgs),
+
+    /// Manage encryption of project and spec files at rest
+    ///
+    /// Turn encryption on with [encryption] key_file or passphrase_env in
+    /// ~/.foundry/config.toml, then run `foundry encryption encrypt` to
+    /// convert existing files
+    Encryption(cli::args::EncryptionArgs),
+
+    /// Browse projects and specs in an interactive terminal UI
+    ///
+    /// Open specs, read their files, check off tasks and see recently
+    /// completed work without going through an AI assistant
+    Tui(cli::args::TuiArgs),
+
+    /// List, add and remove workspaces
+    ///
+    /// A workspace is a foundry directory of its own, registered in
+    /// ~/.foundry/workspaces.toml and picked with --workspace
+    Workspace(cli::args::WorkspaceArgs),
+
+    /// Print a shell completion script
+    ///
+    /// Supports bash, zsh, fish, powershell and elvish; all but elvish also
+    /// complete project and spec names from ~/.foundry
+    Completions(cli::args::CompletionsArgs),
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize tracing
-    tracing_subscriber::fmt::init();
+    if env::args().nth(1).as_deref() == Some("__complete") {
+        return complete().await;
+    }
 
     // Parse CLI arguments and run in CLI mode
     let args = Args::parse();
+    let json = cli::output::json_requested(args.json);
+
+    let result = run(args, json).await;
+    mcp::logging::shutdown();
+    match result {
+        Ok(()) => Ok(()),
+        Err(e) if json => {
+            println!("{}", cli::output::error_json(&e));
+            std::process::exit(1);
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// The hidden `__complete` hook called by completion scripts
+///
+/// Kept out of `Args` so the generated scripts don't offer it, and answered
+/// without opening the configured backend so completion stays quick.
+async fn complete() -> Result<()> {
+    let command = cli::args::CompleteArgs::augment_args(clap::Command::new("__complete"));
+    let args =
+        cli::args::CompleteArgs::from_arg_matches(&command.get_matches_from(env::args().skip(1)))?;
+    if let Ok(name) = env::var("FOUNDRY_WORKSPACE")
+        && !name.is_empty()
+    {
+        foundry_mcp::core::workspace::set_default_dir(foundry_mcp::core::workspace::resolve(
+            &name,
+        )?);
+    }
+    let names = cli::commands::completions::complete(args, Args::command()).await?;
+    if !names.is_empty() {
+        println!("{}", names);
+    }
+    Ok(())
+}
+
+async fn run(args: Args, json: bool) -> Result<()> {
+    // Initialize tracing; serve picks its own format and level
+    match &args.command {
+        Commands::Serve(serve_args) => {
+            let format = mcp::logging::LogFormat::parse(&serve_args.log_format)?;
+            mcp::logging::init(format, serve_args.verbose);
+        }
+        _ => mcp::logging::init(mcp::logging::LogFormat::Text, false),
+    }
+
+    let workspace = args.workspace.clone().or_else(|| {
+        env::var("FOUNDRY_WORKSPACE")
+            .ok()
+            .filter(|name| !name.is_empty())
+    });
+    if let Some(name) = &workspace {
+        foundry_mcp::core::workspace::set_default_dir(foundry_mcp::core::workspace::resolve(name)?);
+    }
+
+    let locale = utils::messages::resolve_locale(args.locale.as_deref());
+    if locale != utils::messages::DEFAULT_LOCALE {
+        let catalog = foundry_mcp::core::filesystem::foundry_dir()
+            .and_then(|dir| utils::messages::MessageCatalog::load(&locale, &dir.join(".locales")));
+        match catalog {
+            Ok(catalog) => utils::messages::init(catalog),
+            Err(e) => tracing::warn!("Using English guidance: {:#}", e),
+        }
+    }
+
+    let backend_config = foundry_mcp::core::backends::resolve_backend_config(
+        foundry_mcp::core::config::BackendConfig {
+            kind: args.backend,
+            git_dir: args.git_dir,
+            git_remote: args.git_remote,
+            notion_page: args.notion_page,
+        },
+    )?;
+    foundry_mcp::core::foundry::set_default_backend(
+        foundry_mcp::core::backends::open_configured_backend(&backend_config)?,
+    );
 
-    match args.command {
+    let output = match args.command {
         Commands::Serve(args) => {
             if args.verbose {
                 tracing::info!("Starting MCP server in verbose mode");
             } else {
                 tracing::info!("Starting MCP server");
             }
-            return mcp::FoundryMcpServer::start().await.map_err(|e| {
+            let options = mcp::ServerOptions {
+                wire_log: args.wire_log,
+                slow_op_threshold: Duration::from_millis(args.slow_op_threshold_ms),
+                crash_reports: args.crash_reports,
+                guidance: utils::response::GuidanceLevel::parse(&args.guidance)?,
+                hint_rules: args.hint_rules.map(PathBuf::from),
+                stale_check_days: args.stale_check_days,
+                webhooks: args.webhooks.map(PathBuf::from),
+                rest_addr: args
+                    .rest_port
+                    .map(|port| listen_addr(&args.rest_bind, port, "--rest-bind"))
+                    .transpose()?,
+                metrics: args.metrics,
+                tool_filter: foundry_mcp::mcp::ToolFilter::new(args.read_only, &args.allow_tools)?,
+                metrics_addr: args
+                    .metrics_port
+                    .map(|port| listen_addr(&args.metrics_bind, port, "--metrics-bind"))
+                    .transpose()?,
+                watch_dir: args
+                    .watch
+                    .then(|| {
+                        foundry_mcp::core::backends::storage_dir(
+                            backend_config.kind(),
+                            backend_config.git_dir.as_deref(),
+                        )
+                    })
+                    .transpose()?,
+            };
+            return mcp::FoundryMcpServer::start(options).await.map_err(|e| {
                 eprintln!("MCP server error: {}", e);
                 std::process::exit(1);
             });
         }
-        Commands::Install(args) => {
-            let output = cli::commands::install::execute(args).await?;
-            println!("{}", output);
-            return Ok(());
+        Commands::Install(mut args) => {
+            args.json = json;
+            cli::commands::install::execute(args).await?
         }
-        Commands::Uninstall(args) => {
-            let output = cli::commands::uninstall::execute(args).await?;
-            println!("{}", output);
-            return Ok(());
+        Commands::Uninstall(mut args) => {
+            args.json = json;
+            cli::commands::uninstall::execute(args).await?
+        }
+        Commands::Status(mut args) => {
+            args.json = json;
+            cli::commands::status::execute(args).await?
+        }
+        Commands::Schema(args) => cli::commands::schema::execute(args).await?,
+        Commands::Stats(mut args) => {
+            args.json = json;
+            cli::commands::stats::execute(args).await?
+        }
+        Commands::Replay(mut args) => {
+            args.json = json;
+            cli::commands::replay::execute(args).await?
+        }
+        Commands::Stale(mut args) => {
+            args.json = json;
+            cli::commands::stale::execute(args).await?
+        }
+        Commands::Digest(mut args) => {
+            args.json = json;
+            cli::commands::digest::execute(args).await?
+        }
+        Commands::Search(mut args) => {
+            args.json = json;
+            cli::commands::search::execute(args).await?
+        }
+        Commands::Export(mut args) => {
+            args.json = json;
+            cli::commands::export::execute(args).await?
         }
-        Commands::Status(args) => {
-            let output = cli::commands::status::execute(args).await?;
-            println!("{}", output);
+        Commands::Import(mut args) => {
+            args.json = json;
+            cli::commands::import::execute(args).await?
+        }
+        Commands::Backup(mut args) => {
+            args.json = json;
+            cli::commands::backup::execute(args).await?
+        }
+        Commands::Audit(mut args) => {
+            args.json = json;
+            cli::commands::audit::execute(args).await?
+        }
+        Commands::Doctor(mut args) => {
+            args.json = json;
+            cli::commands::doctor::execute(args).await?
+        }
+        Commands::Encryption(mut args) => {
+            args.json = json;
+            cli::commands::encryption::execute(args).await?
+        }
+        Commands::Tui(args) => {
+            cli::commands::tui::execute(args).await?;
             return Ok(());
         }
-    }
+        Commands::Workspace(mut args) => {
+            args.json = json;
+            cli::commands::workspace::execute(args).await?
+        }
+        Commands::Completions(args) => cli::commands::completions::execute(args, Args::command())?,
+    };
+    println!("{}", output);
+    Ok(())
+}
+
+/// Socket address for a `--*-bind` IP address (IPv4 or IPv6, e.g. `::1`) and port
+fn listen_addr(host: &str, port: u16, flag: &str) -> Result<SocketAddr> {
+    let ip: IpAddr = host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid {} address '{}': {}", flag, host, e))?;
+    Ok(SocketAddr::new(ip, port))
 }
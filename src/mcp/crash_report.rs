@@ -0,0 +1,214 @@
+//! # Crash Reports
+//!
+//! Opt-in panic hook for `foundry serve`. When the server panics it writes a
+//! JSON report (panic message and location, backtrace, versions and the most
+//! recent tool calls) to `~/.foundry/.crash/` and prints where it went, so a
+//! bug report can carry the context that led up to the crash.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::mcp::{logging, wire_log};
+use crate::utils::timestamp;
+
+/// Tool calls kept for the "recent operations" section of a report
+const RECENT_CALL_CAPACITY: usize = 20;
+
+/// One tool call as remembered for crash reports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentCall {
+    pub timestamp: String,
+    pub request_id: String,
+    pub tool: String,
+    /// Parameter summary with secrets redacted and spec content elided
+    pub params: String,
+    /// `ok`, `error`, or `started` for a call that had not returned yet
+    pub outcome: String,
+    pub duration_ms: Option<u64>,
+}
+
+/// Bounded history of the latest tool calls, shared with the panic hook
+#[derive(Debug, Default)]
+pub struct RecentCalls {
+    calls: Mutex<VecDeque<RecentCall>>,
+}
+
+impl RecentCalls {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Remember that a call started, so a panic inside it still shows up
+    pub fn started(&self, request_id: &str, tool: &str, params: &Value) {
+        self.push(RecentCall {
+            timestamp: timestamp::iso_timestamp(),
+            request_id: request_id.to_string(),
+            tool: tool.to_string(),
+            params: logging::summarize_params(&wire_log::sanitize(params)),
+            outcome: "started".to_string(),
+            duration_ms: None,
+        });
+    }
+
+    /// Update a started call with its outcome
+    pub fn finished(&self, request_id: &str, outcome: &str, duration_ms: u64) {
+        let mut calls = self.calls.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(call) = calls
+            .iter_mut()
+            .rev()
+            .find(|call| call.request_id == request_id)
+        {
+            call.outcome = outcome.to_string();
+            call.duration_ms = Some(duration_ms);
+        }
+    }
+
+    /// Calls currently remembered, oldest first
+    pub fn snapshot(&self) -> Vec<RecentCall> {
+        let calls = self.calls.lock().unwrap_or_else(|e| e.into_inner());
+        calls.iter().cloned().collect()
+    }
+
+    fn push(&self, call: RecentCall) {
+        let mut calls = self.calls.lock().unwrap_or_else(|e| e.into_inner());
+        if calls.len() == RECENT_CALL_CAPACITY {
+            calls.pop_front();
+        }
+        calls.push_back(call);
+    }
+}
+
+/// Contents of a crash report file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub timestamp: String,
+    pub foundry_version: String,
+    pub os: String,
+    pub arch: String,
+    pub thread: String,
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: String,
+    pub recent_calls: Vec<RecentCall>,
+}
+
+/// Default crash report directory, `~/.foundry/.crash`
+pub fn default_dir() -> Result<PathBuf> {
+    Ok(crate::core::filesystem::foundry_dir()?.join(".crash"))
+}
+
+/// Install a panic hook writing reports to `dir`, then running the previous hook
+pub fn install(dir: PathBuf, recent_calls: Arc<RecentCalls>) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = build_report(info, recent_calls.snapshot());
+        match write_report(&dir, &report) {
+            Ok(path) => eprintln!(
+                "Foundry crashed. A crash report was written to {}\nPlease attach it to an issue at {}/issues",
+                path.display(),
+                env!("CARGO_PKG_REPOSITORY")
+            ),
+            Err(e) => eprintln!("Foundry crashed and the crash report could not be written: {e:#}"),
+        }
+        previous(info);
+    }));
+}
+
+fn build_report(info: &PanicHookInfo<'_>, recent_calls: Vec<RecentCall>) -> CrashReport {
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "<non-string panic payload>".to_string());
+
+    CrashReport {
+        timestamp: timestamp::iso_timestamp(),
+        foundry_version: env!("CARGO_PKG_VERSION").to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        thread: std::thread::current()
+            .name()
+            .unwrap_or("<unnamed>")
+            .to_string(),
+        message,
+        location: info
+            .location()
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+        backtrace: std::backtrace::Backtrace::force_capture().to_string(),
+        recent_calls,
+    }
+}
+
+/// Write `report` to a new file in `dir` and return its path
+pub fn write_report(dir: &Path, report: &CrashReport) -> Result<PathBuf> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create crash report directory: {:?}", dir))?;
+    let path = dir.join(format!(
+        "crash-{}-{}.json",
+        timestamp::spec_timestamp(),
+        std::process::id()
+    ));
+    let content = serde_json::to_string_pretty(report)?;
+    fs::write(&path, content)
+        .with_context(|| format!("Failed to write crash report: {:?}", path))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_recent_calls_are_bounded_and_redacted() {
+        let recent = RecentCalls::new();
+        for index in 0..RECENT_CALL_CAPACITY + 3 {
+            recent.started(
+                &format!("req-{}", index),
+                "load_spec",
+                &json!({"project_name": "demo", "api_key": "secret-value"}),
+            );
+        }
+        recent.finished("req-22", "ok", 12);
+
+        let calls = recent.snapshot();
+        assert_eq!(calls.len(), RECENT_CALL_CAPACITY);
+        assert_eq!(calls[0].request_id, "req-3");
+        let last = calls.last().unwrap();
+        assert_eq!(last.outcome, "ok");
+        assert_eq!(last.duration_ms, Some(12));
+        assert!(last.params.contains("project_name=\"demo\""));
+        assert!(!last.params.contains("secret-value"));
+    }
+
+    #[test]
+    fn test_write_report_creates_json_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join(".crash");
+        let report = CrashReport {
+            timestamp: timestamp::iso_timestamp(),
+            foundry_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            thread: "main".to_string(),
+            message: "boom".to_string(),
+            location: Some("src/lib.rs:1:1".to_string()),
+            backtrace: String::new(),
+            recent_calls: Vec::new(),
+        };
+
+        let path = write_report(&dir, &report).unwrap();
+        let written: CrashReport =
+            serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(written.message, "boom");
+    }
+}
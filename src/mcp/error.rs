@@ -6,6 +6,8 @@
 use rust_mcp_sdk::schema::schema_utils::CallToolError;
 use serde_json;
 
+use crate::types::errors::{ErrorCode, ErrorPayload};
+
 /// Comprehensive error type for Foundry MCP server operations
 #[derive(Debug, thiserror::Error)]
 pub enum FoundryMcpError {
@@ -13,6 +15,20 @@ pub enum FoundryMcpError {
     #[error("Parameter validation failed: {message}")]
     InvalidParams { message: String },
 
+    /// Tool name that is not registered
+    #[error("Unknown tool: {name}")]
+    UnknownTool { name: String },
+
+    /// Registered tool that this server does not expose
+    #[error(
+        "Tool '{name}' is not enabled on this server (started with --read-only or --allow-tools)"
+    )]
+    ToolNotAllowed { name: String },
+
+    /// Call refused because the server is shutting down
+    #[error("Server is shutting down")]
+    ShuttingDown,
+
     /// CLI command execution errors
     #[error("CLI command execution failed: {source}")]
     CliCommand {
@@ -73,6 +89,15 @@ impl From<FoundryMcpError> for CallToolError {
             FoundryMcpError::InvalidParams { message } => {
                 CallToolError::new(InvalidParamsError(message))
             }
+            FoundryMcpError::UnknownTool { name } => {
+                CallToolError::new(InvalidParamsError(format!("Unknown tool: {}", name)))
+            }
+            FoundryMcpError::ToolNotAllowed { .. } => {
+                CallToolError::new(InvalidParamsError(err.to_string()))
+            }
+            FoundryMcpError::ShuttingDown => {
+                CallToolError::new(InternalMcpError("Server is shutting down".to_string()))
+            }
             FoundryMcpError::CliCommand { source } => {
                 CallToolError::new(InternalMcpError(format!("CLI command failed: {}", source)))
             }
@@ -98,6 +123,10 @@ impl FoundryMcpError {
         }
     }
 
+    pub fn unknown_tool<S: Into<String>>(name: S) -> Self {
+        FoundryMcpError::UnknownTool { name: name.into() }
+    }
+
     pub fn transport_error<S: Into<String>>(message: S) -> Self {
         FoundryMcpError::Transport {
             message: message.into(),
@@ -110,3 +139,73 @@ impl FoundryMcpError {
         }
     }
 }
+
+impl FoundryMcpError {
+    /// Stable code for this error; op errors carry theirs from `ErrorCode::error`
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            FoundryMcpError::InvalidParams { .. } => ErrorCode::InvalidParams,
+            FoundryMcpError::UnknownTool { .. } => ErrorCode::UnknownTool,
+            FoundryMcpError::ToolNotAllowed { .. } => ErrorCode::ToolNotAllowed,
+            FoundryMcpError::ShuttingDown => ErrorCode::ShuttingDown,
+            FoundryMcpError::CliCommand { source } => ErrorCode::of(source),
+            FoundryMcpError::Serialization { .. }
+            | FoundryMcpError::Filesystem { .. }
+            | FoundryMcpError::Transport { .. }
+            | FoundryMcpError::Internal { .. } => ErrorCode::InternalError,
+        }
+    }
+
+    /// Code and message as returned to MCP clients in a failed tool result
+    pub fn payload(&self) -> ErrorPayload {
+        let message = match self {
+            FoundryMcpError::InvalidParams { message } => message.clone(),
+            FoundryMcpError::CliCommand { source } => source.to_string(),
+            other => other.to_string(),
+        };
+        let (rule, details) = match self {
+            FoundryMcpError::CliCommand { source } => (
+                ErrorCode::rule_of(source).map(str::to_string),
+                ErrorCode::details_of(source).cloned(),
+            ),
+            _ => (None, None),
+        };
+        ErrorPayload {
+            code: self.code(),
+            message,
+            rule,
+            details,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_reports_code_from_op_error() {
+        let error: FoundryMcpError = ErrorCode::SpecNotFound
+            .error("Spec 'x' not found in project 'demo'")
+            .into();
+        let payload = error.payload();
+        assert_eq!(payload.code, ErrorCode::SpecNotFound);
+        assert_eq!(payload.message, "Spec 'x' not found in project 'demo'");
+        assert_eq!(payload.rule, None);
+
+        let error: FoundryMcpError = ErrorCode::ValidationFailed
+            .error_with_rule(
+                "project_name_format",
+                "Project name cannot contain consecutive hyphens",
+            )
+            .into();
+        assert_eq!(error.payload().rule.as_deref(), Some("project_name_format"));
+        let body = serde_json::to_value(error.payload()).unwrap();
+        assert_eq!(body["code"], "VALIDATION_FAILED");
+        assert_eq!(body["rule"], "project_name_format");
+
+        let error = FoundryMcpError::invalid_params("Missing project_name parameter");
+        assert_eq!(error.payload().code, ErrorCode::InvalidParams);
+        assert_eq!(error.payload().message, "Missing project_name parameter");
+    }
+}
@@ -3,7 +3,6 @@
 //! This module implements the ServerHandler trait to route MCP tool requests
 //! to core operations, maintaining identical functionality and response formats.
 
-use anyhow::Result;
 use async_trait::async_trait;
 use rust_mcp_sdk::{
     McpServer,
@@ -14,17 +13,116 @@ use rust_mcp_sdk::{
     },
 };
 use serde_json::Value;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::Instrument;
 
 use crate::cli;
-use crate::mcp::{error::FoundryMcpError, tools::FoundryTools, traits::McpToolDefinition};
+use crate::core::{audit, timing};
+use crate::mcp::{
+    crash_report::RecentCalls,
+    error::FoundryMcpError,
+    hints::HintRules,
+    logging,
+    metrics::{self, ServerMetrics},
+    sampling,
+    shutdown::InFlightCalls,
+    tools::{self, FoundryTools, ToolFilter},
+    traits::McpToolHandler,
+    webhooks::WebhookConfig,
+    wire_log::WireLog,
+};
+use crate::types::responses::{AuditInterface, ResponseDiagnostics};
+use crate::utils::messages;
+use crate::utils::response::GuidanceLevel;
+
+/// Calls taking at least this long get a timing warning unless configured otherwise
+pub const DEFAULT_SLOW_OP_THRESHOLD: Duration = Duration::from_secs(2);
 
 /// Main server handler that routes MCP requests to core operations
-pub struct FoundryServerHandler;
+///
+/// Clones share the wire log, in-flight counter and recent calls, so the REST
+/// API can serve calls through the same pipeline as MCP.
+#[derive(Clone)]
+pub struct FoundryServerHandler {
+    wire_log: Option<Arc<WireLog>>,
+    in_flight: Arc<InFlightCalls>,
+    slow_op_threshold: Duration,
+    recent_calls: Option<Arc<RecentCalls>>,
+    metrics: Option<Arc<ServerMetrics>>,
+    tool_filter: ToolFilter,
+    guidance: GuidanceLevel,
+    hint_rules: HintRules,
+    webhooks: Option<Arc<WebhookConfig>>,
+}
 
 impl FoundryServerHandler {
     /// Create a new server handler
     pub fn new() -> Self {
-        Self
+        Self {
+            wire_log: None,
+            in_flight: InFlightCalls::new(),
+            slow_op_threshold: DEFAULT_SLOW_OP_THRESHOLD,
+            recent_calls: None,
+            metrics: None,
+            tool_filter: ToolFilter::default(),
+            guidance: GuidanceLevel::Full,
+            hint_rules: HintRules::default(),
+            webhooks: None,
+        }
+    }
+
+    /// Tool calls currently running, shared with the server for graceful shutdown
+    pub fn in_flight(&self) -> Arc<InFlightCalls> {
+        Arc::clone(&self.in_flight)
+    }
+
+    /// Record every tool call and response to `wire_log`
+    pub fn with_wire_log(mut self, wire_log: WireLog) -> Self {
+        self.wire_log = Some(Arc::new(wire_log));
+        self
+    }
+
+    /// Attach timing diagnostics to responses of calls taking at least `threshold`
+    pub fn with_slow_op_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_op_threshold = threshold;
+        self
+    }
+
+    /// Add the hints of matching `hint_rules` to every response
+    pub fn with_hint_rules(mut self, hint_rules: HintRules) -> Self {
+        self.hint_rules = hint_rules;
+        self
+    }
+
+    /// Trim `next_steps`/`workflow_hints` in every response to `guidance`
+    pub fn with_guidance(mut self, guidance: GuidanceLevel) -> Self {
+        self.guidance = guidance;
+        self
+    }
+
+    /// Notify `webhooks` of every project and spec change
+    pub fn with_webhooks(mut self, webhooks: WebhookConfig) -> Self {
+        self.webhooks = Some(Arc::new(webhooks));
+        self
+    }
+
+    /// Remember recent tool calls in `recent_calls` for crash reports
+    pub fn with_recent_calls(mut self, recent_calls: Arc<RecentCalls>) -> Self {
+        self.recent_calls = Some(recent_calls);
+        self
+    }
+
+    /// Count every tool call and its outcome in `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<ServerMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Only list and serve the tools `tool_filter` allows
+    pub fn with_tool_filter(mut self, tool_filter: ToolFilter) -> Self {
+        self.tool_filter = tool_filter;
+        self
     }
 
     /// Convert MCP parameters to typed inputs and execute operation
@@ -33,207 +131,796 @@ impl FoundryServerHandler {
         tool_name: &str,
         params: &Value,
     ) -> Result<Value, FoundryMcpError> {
-        match tool_name {
-            "create_project" => {
-                let args =
-                    <cli::args::CreateProjectArgs as McpToolDefinition>::from_mcp_params(params)
-                        .map_err(|e| {
-                            FoundryMcpError::invalid_params(format!(
-                                "Invalid parameters for create_project: {}",
-                                e
-                            ))
-                        })?;
-
-                let result = crate::core::ops::create_project::run(
-                    crate::core::ops::create_project::Input {
-                        project_name: args.project_name,
-                        vision: args.vision,
-                        tech_stack: args.tech_stack,
-                        summary: args.summary,
-                    },
-                )
-                .await?;
-
-                Ok(serde_json::to_value(result)?)
-            }
-            "analyze_project" => {
-                let args = cli::args::AnalyzeProjectArgs::from_mcp_params(params).map_err(|e| {
-                    FoundryMcpError::invalid_params(format!(
-                        "Invalid parameters for analyze_project: {}",
-                        e
-                    ))
-                })?;
-
-                let result = crate::core::ops::analyze_project::run(
-                    crate::core::ops::analyze_project::Input {
-                        project_name: args.project_name,
-                        vision: args.vision,
-                        tech_stack: args.tech_stack,
-                        summary: args.summary,
-                    },
-                )
-                .await?;
-
-                Ok(serde_json::to_value(result)?)
-            }
-            "load_project" => {
-                let args = cli::args::LoadProjectArgs::from_mcp_params(params).map_err(|e| {
-                    FoundryMcpError::invalid_params(format!(
-                        "Invalid parameters for load_project: {}",
-                        e
-                    ))
-                })?;
-
-                let result =
-                    crate::core::ops::load_project::run(crate::core::ops::load_project::Input {
-                        project_name: args.project_name,
-                    })
-                    .await?;
-
-                Ok(serde_json::to_value(result)?)
-            }
-            "create_spec" => {
-                let args = cli::args::CreateSpecArgs::from_mcp_params(params).map_err(|e| {
-                    FoundryMcpError::invalid_params(format!(
-                        "Invalid parameters for create_spec: {}",
-                        e
-                    ))
-                })?;
-
-                let result =
-                    crate::core::ops::create_spec::run(crate::core::ops::create_spec::Input {
-                        project_name: args.project_name,
-                        feature_name: args.feature_name,
-                        spec: args.spec,
-                        notes: args.notes,
-                        tasks: args.tasks,
-                    })
-                    .await?;
-
-                Ok(serde_json::to_value(result)?)
-            }
-            "load_spec" => {
-                let args = cli::args::LoadSpecArgs::from_mcp_params(params).map_err(|e| {
-                    FoundryMcpError::invalid_params(format!(
-                        "Invalid parameters for load_spec: {}",
-                        e
-                    ))
-                })?;
-
-                let result = crate::core::ops::load_spec::run(crate::core::ops::load_spec::Input {
-                    project_name: args.project_name,
-                    spec_name: args.spec_name,
-                })
-                .await?;
-
-                Ok(serde_json::to_value(result)?)
-            }
-            "list_projects" => {
-                let args = cli::args::ListProjectsArgs::from_mcp_params(params).map_err(|e| {
-                    FoundryMcpError::invalid_params(format!(
-                        "Invalid parameters for list_projects: {}",
-                        e
-                    ))
-                })?;
-
-                let _ = args; // unit struct
-                let result =
-                    crate::core::ops::list_projects::run(crate::core::ops::list_projects::Input)
-                        .await?;
-
-                Ok(serde_json::to_value(result)?)
-            }
-            "list_specs" => {
-                let args = cli::args::ListSpecsArgs::from_mcp_params(params).map_err(|e| {
-                    FoundryMcpError::invalid_params(format!(
-                        "Invalid parameters for list_specs: {}",
-                        e
-                    ))
-                })?;
-
-                let result =
-                    crate::core::ops::list_specs::run(crate::core::ops::list_specs::Input {
-                        project_name: args.project_name,
-                    })
-                    .await?;
-
-                Ok(serde_json::to_value(result)?)
-            }
-            "validate_content" => {
-                let args =
-                    cli::args::ValidateContentArgs::from_mcp_params(params).map_err(|e| {
-                        FoundryMcpError::invalid_params(format!(
-                            "Invalid parameters for validate_content: {}",
-                            e
-                        ))
-                    })?;
-
-                let result = crate::core::ops::validate_content::run(
-                    crate::core::ops::validate_content::Input {
-                        content_type: args.content_type,
-                        content: args.content,
-                    },
-                )
-                .await?;
-
-                Ok(serde_json::to_value(result)?)
-            }
-            "get_foundry_help" => {
-                let args = cli::args::GetFoundryHelpArgs::from_mcp_params(params).map_err(|e| {
-                    FoundryMcpError::invalid_params(format!(
-                        "Invalid parameters for get_foundry_help: {}",
-                        e
-                    ))
-                })?;
-
-                let result = crate::core::ops::get_foundry_help::run(
-                    crate::core::ops::get_foundry_help::Input { topic: args.topic },
-                )
-                .await?;
-
-                Ok(serde_json::to_value(result)?)
-            }
-            "update_spec" => {
-                let args = cli::args::UpdateSpecArgs::from_mcp_params(params).map_err(|e| {
-                    FoundryMcpError::invalid_params(format!(
-                        "Invalid parameters for update_spec: {}",
-                        e
-                    ))
-                })?;
-
-                let result =
-                    crate::core::ops::update_spec::run(crate::core::ops::update_spec::Input {
-                        project_name: args.project_name,
-                        spec_name: args.spec_name,
-                        commands_json: args.commands,
-                    })
-                    .await?;
-
-                Ok(serde_json::to_value(result)?)
-            }
-            "delete_spec" => {
-                let args = cli::args::DeleteSpecArgs::from_mcp_params(params).map_err(|e| {
-                    FoundryMcpError::invalid_params(format!(
-                        "Invalid parameters for delete_spec: {}",
-                        e
-                    ))
-                })?;
-
-                let result =
-                    crate::core::ops::delete_spec::run(crate::core::ops::delete_spec::Input {
-                        project_name: args.project_name,
-                        spec_name: args.spec_name,
-                        confirm: args.confirm,
-                    })
-                    .await?;
-
-                Ok(serde_json::to_value(result)?)
+        if !self.tool_filter.allows(tool_name) && FoundryTools::tool_names().contains(&tool_name) {
+            return Err(FoundryMcpError::ToolNotAllowed {
+                name: tool_name.to_string(),
+            });
+        }
+        FoundryTools::dispatch(tool_name, params).await
+    }
+
+    /// Run a tool call through the full pipeline shared by MCP and REST
+    ///
+    /// Applies hint rules, guidance level, translation and slow-op diagnostics
+    /// to the response, and records the call in logs, recent calls and the
+    /// wire log.
+    pub async fn call_tool(
+        &self,
+        tool_name: &str,
+        params_value: &Value,
+    ) -> Result<Value, FoundryMcpError> {
+        let Some(_in_flight) = self.in_flight.enter() else {
+            tracing::warn!("Refusing call to {} during shutdown", tool_name);
+            return Err(FoundryMcpError::ShuttingDown);
+        };
+
+        let request_id = logging::next_request_id();
+        let span = tracing::info_span!("tool_call", request_id = %request_id, tool = %tool_name);
+        tracing::debug!(parent: &span, "Handling call_tool request for: {}", tool_name);
+
+        if let Some(recent_calls) = &self.recent_calls {
+            recent_calls.started(&request_id, tool_name, params_value);
+        }
+
+        // Route to CLI command and get JSON result; ops and backends log inside the span
+        let started = Instant::now();
+        let (mut outcome, timings) =
+            timing::collect(self.route_to_cli_command(tool_name, params_value))
+                .instrument(span.clone())
+                .await;
+        if let (Some(webhooks), Ok(response)) = (&self.webhooks, &outcome) {
+            webhooks.notify(tool_name, params_value, response);
+        }
+        if let Ok(Value::Object(response)) = &mut outcome {
+            self.hint_rules.apply(tool_name, params_value, response);
+            self.guidance.apply(response);
+            messages::catalog().translate_response(response);
+        }
+        let elapsed = started.elapsed();
+        let duration_ms = elapsed.as_millis() as u64;
+        if elapsed >= self.slow_op_threshold {
+            let diagnostics =
+                timings.slow_operation_diagnostics(tool_name, elapsed, self.slow_op_threshold);
+            tracing::warn!(parent: &span, "{}", diagnostics.warnings.join("; "));
+            if let Ok(Value::Object(response)) = &mut outcome {
+                attach_diagnostics(response, diagnostics);
             }
-            _ => Err(FoundryMcpError::invalid_params(format!(
-                "Unknown tool: {}",
-                tool_name
-            ))),
         }
+        let params_summary = logging::summarize_params(params_value);
+        match &outcome {
+            Ok(_) => tracing::info!(
+                parent: &span,
+                tool = %tool_name,
+                params = %params_summary,
+                duration_ms,
+                outcome = "ok",
+                "Tool call completed"
+            ),
+            Err(e) => tracing::warn!(
+                parent: &span,
+                tool = %tool_name,
+                params = %params_summary,
+                duration_ms,
+                outcome = "error",
+                error = %e,
+                "Tool call failed"
+            ),
+        }
+        if let Some(metrics) = &self.metrics {
+            metrics.record_call(tool_name, &outcome, elapsed);
+        }
+        if let Some(recent_calls) = &self.recent_calls {
+            let outcome = if outcome.is_ok() { "ok" } else { "error" };
+            recent_calls.finished(&request_id, outcome, duration_ms);
+        }
+        if let Some(wire_log) = &self.wire_log
+            && let Err(e) =
+                wire_log.record(&request_id, tool_name, params_value, &outcome, duration_ms)
+        {
+            tracing::warn!(parent: &span, "Failed to write wire log: {}", e);
+        }
+        if let Err(e) = record_audit(&request_id, tool_name, params_value, &outcome) {
+            tracing::warn!(parent: &span, "Failed to write audit log: {:#}", e);
+        }
+        outcome
+    }
+}
+
+/// Record a call that can change data in its project's audit log
+///
+/// The project comes from the parameters, or from the response for tools
+/// that name it themselves (`import_archive`).
+fn record_audit(
+    request_id: &str,
+    tool_name: &str,
+    params: &Value,
+    outcome: &Result<Value, FoundryMcpError>,
+) -> anyhow::Result<()> {
+    if !tools::changes_data(tool_name, params) {
+        return Ok(());
+    }
+    let project_name = params["project_name"].as_str().or_else(|| {
+        outcome
+            .as_ref()
+            .ok()
+            .and_then(|response| response["data"]["project_name"].as_str())
+    });
+    let Some(project_name) = project_name.filter(|name| !name.is_empty()) else {
+        return Ok(());
+    };
+    let error_code = outcome.as_ref().err().map(FoundryMcpError::code);
+    audit::record_call(
+        project_name,
+        tool_name,
+        params,
+        error_code,
+        Some(request_id),
+    )
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::CreateProjectArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::create_project::run(crate::core::ops::create_project::Input {
+                project_name: self.project_name,
+                vision: self.vision,
+                tech_stack: self.tech_stack,
+                summary: self.summary,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::AnalyzeProjectArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::analyze_project::run(crate::core::ops::analyze_project::Input {
+                project_name: self.project_name,
+                vision: self.vision,
+                tech_stack: self.tech_stack,
+                summary: self.summary,
+                repo_path: self.repo_path,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::LoadProjectArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::load_project::run(crate::core::ops::load_project::Input {
+            project_name: self.project_name,
+            max_tokens: self.max_tokens,
+            include: self.include,
+            active_limit: self.active_limit,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::CreateSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::create_spec::run(crate::core::ops::create_spec::Input {
+            project_name: self.project_name,
+            feature_name: self.feature_name,
+            spec: self.spec,
+            notes: self.notes,
+            tasks: self.tasks,
+            template: self.template,
+            allow_duplicate: self.allow_duplicate,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::LoadSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::load_spec::run(crate::core::ops::load_spec::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            max_tokens: self.max_tokens,
+            part: self.part,
+            files: self.files,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ListProjectsArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::list_projects::run(crate::core::ops::list_projects::Input {
+            tags: self.tags.unwrap_or_default(),
+            cursor: self.cursor,
+            limit: self.limit,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ListSpecsArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::list_specs::run(crate::core::ops::list_specs::Input {
+            project_name: self.project_name,
+            status: self.status,
+            blocked_by: self.blocked_by,
+            tags: self.tags.unwrap_or_default(),
+            cursor: self.cursor,
+            limit: self.limit,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ValidateContentArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::validate_content::run(crate::core::ops::validate_content::Input {
+                content_type: self.content_type,
+                content: self.content,
+                project_name: self.project_name,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::GetFoundryHelpArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::get_foundry_help::run(crate::core::ops::get_foundry_help::Input {
+                topic: self.topic,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::UpdateSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::update_spec::run(crate::core::ops::update_spec::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            commands_json: self.commands,
+            expected_version: self.expected_version,
+            dry_run: self.dry_run,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::UpdateSpecsBatchArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::update_specs_batch::run(
+            crate::core::ops::update_specs_batch::Input {
+                project_name: self.project_name,
+                updates_json: self.updates,
+            },
+        )
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::DeleteSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::delete_spec::run(crate::core::ops::delete_spec::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            confirm: self.confirm,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::UpdateProjectArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::update_project::run(crate::core::ops::update_project::Input {
+                project_name: self.project_name,
+                vision: self.vision,
+                tech_stack: self.tech_stack,
+                summary: self.summary,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::DeleteProjectArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::delete_project::run(crate::core::ops::delete_project::Input {
+                project_name: self.project_name,
+                confirm: self.confirm,
+                force: self.force,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ExportTasksArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::export_tasks::run(crate::core::ops::export_tasks::Input {
+            project_name: self.project_name,
+            format: self.format,
+            spec_name: self.spec_name,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::DiagramSpecsArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::diagram_specs::run(crate::core::ops::diagram_specs::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            include_phases: self.include_phases,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ProjectAnalyticsArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::project_analytics::run(crate::core::ops::project_analytics::Input {
+                project_name: self.project_name,
+                weeks: self.weeks,
+                stall_days: self.stall_days,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ProjectReportArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::project_report::run(crate::core::ops::project_report::Input {
+                project_name: self.project_name,
+                recent_days: self.recent_days,
+                stale_days: self.stale_days,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::NeedsAttentionArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::needs_attention::run(crate::core::ops::needs_attention::Input {
+                project_name: self.project_name,
+                stale_days: self.stale_days,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ActivityDigestArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::digest::run(crate::core::ops::digest::Input {
+            since: self.since,
+            until: self.until,
+            project_name: self.project_name,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::CreateHandoffArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::create_handoff::run(crate::core::ops::create_handoff::Input {
+                project_name: self.project_name,
+                spec_name: self.spec_name,
+                max_tokens: self.max_tokens,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ReviewSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::review_spec::run(crate::core::ops::review_spec::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            pass_threshold: self.pass_threshold,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::LintSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::lint_spec::run(crate::core::ops::lint_spec::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::DiffSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::diff_spec::run(crate::core::ops::diff_spec::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            other_spec_name: self.other_spec_name,
+            other_project_name: self.other_project_name,
+            backup_id: self.backup_id,
+            history_steps: self.history_steps,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ExportProjectArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::export_project::run(crate::core::ops::export_project::Input {
+                project_name: self.project_name,
+                output_path: self.output_path,
+                overwrite: self.overwrite,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ImportArchiveArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::import_archive::run(crate::core::ops::import_archive::Input {
+                archive_path: self.archive_path,
+                project_name: self.project_name,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::SearchSpecsArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::search_specs::run(crate::core::ops::search_specs::Input {
+            query: self.query,
+            project_name: self.project_name,
+            limit: self.limit,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::GetAuditLogArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::get_audit_log::run(crate::core::ops::get_audit_log::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            operation: self.operation,
+            since: self.since,
+            limit: self.limit,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::SetSpecStatusArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::set_spec_status::run(crate::core::ops::set_spec_status::Input {
+                project_name: self.project_name,
+                spec_name: self.spec_name,
+                status: self.status,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::CopySpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::copy_spec::run(crate::core::ops::copy_spec::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            target_project_name: self.target_project_name,
+            feature_name: self.feature_name,
+            delete_source: self.delete_source,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::CloneProjectArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::clone_project::run(crate::core::ops::clone_project::Input {
+            project_name: self.project_name,
+            new_project_name: self.new_project_name,
+            skip_specs: self.skip_specs,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::RenameSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::rename_spec::run(crate::core::ops::rename_spec::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            new_feature_name: self.new_feature_name,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::GetServerStatsArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let Some(metrics) = metrics::enabled() else {
+            return Err(FoundryMcpError::invalid_params(
+                "Metrics are not being collected; restart the server with 'foundry serve --metrics' (or --metrics-port) to use get_server_stats",
+            ));
+        };
+        let stats = metrics.snapshot();
+        let next_steps = vec![format!(
+            "{} tool call(s), {} failed, in the {}s since the server started",
+            stats.total_calls, stats.error_count, stats.uptime_seconds
+        )];
+        let result = crate::utils::response::build_success_response(
+            stats,
+            next_steps,
+            vec!["Counts reset when the server restarts; use 'foundry stats' on the wire log for longer history".to_string()],
+        );
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::UndoLastUpdateArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::undo_last_update::run(crate::core::ops::undo_last_update::Input {
+                project_name: self.project_name,
+                spec_name: self.spec_name,
+                force: self.force,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::SummarizeSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::summarize_spec::run(crate::core::ops::summarize_spec::Input {
+                project_name: self.project_name,
+                spec_name: self.spec_name,
+                force: self.force,
+                max_words: self.max_words,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::AddTagArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::add_tag::run(crate::core::ops::add_tag::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            tags: self.tags,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::RemoveTagArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::remove_tag::run(crate::core::ops::remove_tag::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+            tags: self.tags,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ArchiveSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::archive_spec::run(crate::core::ops::archive_spec::Input {
+            project_name: self.project_name,
+            spec_name: self.spec_name,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::SpecGraphArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::spec_graph::run(crate::core::ops::spec_graph::Input {
+            project_name: self.project_name,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ImportProjectArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let mapping = self
+            .mapping
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| {
+                FoundryMcpError::invalid_params(format!(
+                    "Invalid mapping for import_project: {}",
+                    e
+                ))
+            })?;
+
+        let result =
+            crate::core::ops::import_project::run(crate::core::ops::import_project::Input {
+                project_name: self.project_name,
+                source_path: self.source_path,
+                preset: self.preset,
+                mapping,
+                dry_run: self.dry_run.unwrap_or(true),
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ImportSpecArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::import_spec::run(crate::core::ops::import_spec::Input {
+            project_name: self.project_name,
+            source_path: self.source_path,
+            feature_name: self.feature_name,
+            dry_run: self.dry_run,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+/// Merge `diagnostics` into the response's `diagnostics` field, keeping any already there
+fn attach_diagnostics(
+    response: &mut serde_json::Map<String, Value>,
+    diagnostics: ResponseDiagnostics,
+) {
+    let mut merged: ResponseDiagnostics = response
+        .get("diagnostics")
+        .and_then(|existing| serde_json::from_value(existing.clone()).ok())
+        .unwrap_or_default();
+    merged.warnings.extend(diagnostics.warnings);
+    merged.timing = diagnostics.timing;
+    if let Ok(value) = serde_json::to_value(merged) {
+        response.insert("diagnostics".to_string(), value);
+    }
+}
+
+/// `{"error": {"code", "message"}}` body for a failed call, message translated
+pub fn error_body(error: &FoundryMcpError) -> Value {
+    let mut payload = error.payload();
+    payload.message = messages::catalog().translate(&payload.message);
+    serde_json::json!({ "error": payload })
+}
+
+/// Failed tool result carrying `{"error": {"code", "message"}}` so agents can branch on the code
+fn error_result(error: &FoundryMcpError) -> CallToolResult {
+    let body = error_body(error);
+    let text = serde_json::to_string_pretty(&body).unwrap_or_else(|_| error.to_string());
+    CallToolResult {
+        content: vec![TextContent::from(text).into()],
+        is_error: Some(true),
+        meta: None,
+        structured_content: None,
     }
 }
 
@@ -254,7 +941,7 @@ impl ServerHandler for FoundryServerHandler {
         tracing::debug!("Handling list_tools request");
 
         Ok(ListToolsResult {
-            tools: FoundryTools::all_tools(),
+            tools: self.tool_filter.tools(),
             meta: None,
             next_cursor: None,
         })
@@ -268,7 +955,8 @@ impl ServerHandler for FoundryServerHandler {
     ) -> Result<ListResourcesResult, RpcError> {
         tracing::debug!("Handling list_resources request");
 
-        // Return empty resources list since Foundry doesn't provide resources
+        // Foundry serves no resources; watch mode's list_changed notifications
+        // carry the changed projects and specs in `_meta` instead
         Ok(ListResourcesResult {
             resources: vec![],
             meta: None,
@@ -280,24 +968,79 @@ impl ServerHandler for FoundryServerHandler {
     async fn handle_call_tool_request(
         &self,
         request: CallToolRequest,
-        _runtime: &dyn McpServer,
+        runtime: &dyn McpServer,
     ) -> Result<CallToolResult, CallToolError> {
-        let tool_name = request.tool_name();
         let default_map = serde_json::Map::new();
         let params = request.params.arguments.as_ref().unwrap_or(&default_map);
         let params_value = serde_json::Value::Object(params.clone());
 
-        tracing::debug!("Handling call_tool request for: {}", tool_name);
-
-        // Route to CLI command and get JSON result
-        let result = self.route_to_cli_command(tool_name, &params_value).await?;
+        let call = audit::with_interface(
+            AuditInterface::Mcp,
+            self.call_tool(request.tool_name(), &params_value),
+        );
+        let result = match sampling::with_client_sampling(runtime, call).await {
+            Ok(result) => result,
+            Err(e) => return Ok(error_result(&e)),
+        };
 
         // Convert JSON result to MCP tool result
         // The CLI commands return structured JSON, so we return it as-is
         let content_text = serde_json::to_string_pretty(&result).map_err(FoundryMcpError::from)?;
+        let tool_result = CallToolResult::text_content(vec![TextContent::from(content_text)]);
+
+        // Tools publish an output schema, so the same payload goes in structuredContent
+        Ok(match result {
+            serde_json::Value::Object(structured) => {
+                tool_result.with_structured_content(structured)
+            }
+            _ => tool_result,
+        })
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::AddDecisionArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result = crate::core::ops::add_decision::run(crate::core::ops::add_decision::Input {
+            project_name: self.project_name,
+            title: self.title,
+            context: self.context,
+            decision: self.decision,
+            consequences: self.consequences,
+            status: self.status,
+            spec_name: self.spec_name,
+        })
+        .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::ListDecisionsArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::list_decisions::run(crate::core::ops::list_decisions::Input {
+                project_name: self.project_name,
+                status: self.status,
+                spec_name: self.spec_name,
+            })
+            .await?;
+
+        Ok(serde_json::to_value(result)?)
+    }
+}
+
+#[async_trait]
+impl McpToolHandler for cli::args::UpdateDecisionsArgs {
+    async fn handle(self) -> Result<Value, FoundryMcpError> {
+        let result =
+            crate::core::ops::update_decisions::run(crate::core::ops::update_decisions::Input {
+                project_name: self.project_name,
+                commands_json: self.commands,
+            })
+            .await?;
 
-        Ok(CallToolResult::text_content(vec![TextContent::from(
-            content_text,
-        )]))
+        Ok(serde_json::to_value(result)?)
     }
 }
@@ -0,0 +1,273 @@
+//! # Workflow Hint Rules
+//!
+//! Config-driven coaching on top of the hints each tool builds itself. Rules
+//! live in `~/.foundry/.hints.json` and are checked against every successful
+//! tool response:
+//!
+//! ```json
+//! {
+//!   "replace_builtin": false,
+//!   "rules": [
+//!     {
+//!       "tool": "load_project",
+//!       "when": [{ "path": "data.project.specs_available", "empty": true }],
+//!       "hint": "{project_name} has no specs yet; plan the first feature with create_spec"
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! A rule fires when its `tool` matches (or is omitted) and all of its `when`
+//! conditions hold. `path` is a dot-separated path into the response JSON; a
+//! missing field counts as empty. `{param}` in the hint is replaced by the
+//! call's parameter of that name. Hints go to `workflow_hints` unless the rule
+//! sets `"field": "next_steps"`; `replace_builtin` drops the tool's own hints
+//! first.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+const HINTS_FILE_NAME: &str = ".hints.json";
+
+/// Default rules file, `~/.foundry/.hints.json`
+pub fn default_path() -> Result<PathBuf> {
+    Ok(crate::core::filesystem::foundry_dir()?.join(HINTS_FILE_NAME))
+}
+
+/// Workflow hint rules loaded from config
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HintRules {
+    /// Drop the hints tools produce themselves before applying rules
+    #[serde(default)]
+    pub replace_builtin: bool,
+    #[serde(default)]
+    pub rules: Vec<HintRule>,
+}
+
+/// One condition → hint rule
+#[derive(Debug, Clone, Deserialize)]
+pub struct HintRule {
+    /// Tool the rule applies to; every tool when omitted
+    #[serde(default)]
+    pub tool: Option<String>,
+    /// Conditions that must all hold
+    #[serde(default)]
+    pub when: Vec<HintCondition>,
+    pub hint: String,
+    #[serde(default)]
+    pub field: HintField,
+}
+
+/// Response field a rule's hint is added to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HintField {
+    #[default]
+    WorkflowHints,
+    NextSteps,
+}
+
+impl HintField {
+    fn key(self) -> &'static str {
+        match self {
+            Self::WorkflowHints => "workflow_hints",
+            Self::NextSteps => "next_steps",
+        }
+    }
+}
+
+/// Check against one response field; every check given must pass
+#[derive(Debug, Clone, Deserialize)]
+pub struct HintCondition {
+    /// Dot-separated path into the response, e.g. `data.project.specs_available`
+    pub path: String,
+    /// Field is present and not null
+    #[serde(default)]
+    pub exists: Option<bool>,
+    /// Field is missing, null, or an empty string, array or object
+    #[serde(default)]
+    pub empty: Option<bool>,
+    /// Field equals this value
+    #[serde(default)]
+    pub equals: Option<Value>,
+    /// String field contains this text, or array field contains this value
+    #[serde(default)]
+    pub contains: Option<Value>,
+}
+
+impl HintCondition {
+    fn holds(&self, response: &Map<String, Value>) -> bool {
+        let field = lookup(response, &self.path);
+        let exists = field.is_some_and(|value| !value.is_null());
+        let empty = field.is_none_or(|value| match value {
+            Value::Null => true,
+            Value::String(s) => s.is_empty(),
+            Value::Array(items) => items.is_empty(),
+            Value::Object(fields) => fields.is_empty(),
+            _ => false,
+        });
+
+        self.exists.is_none_or(|expected| expected == exists)
+            && self.empty.is_none_or(|expected| expected == empty)
+            && self
+                .equals
+                .as_ref()
+                .is_none_or(|expected| field == Some(expected))
+            && self
+                .contains
+                .as_ref()
+                .is_none_or(|needle| match (field, needle) {
+                    (Some(Value::String(s)), Value::String(text)) => s.contains(text.as_str()),
+                    (Some(Value::Array(items)), needle) => items.contains(needle),
+                    _ => false,
+                })
+    }
+}
+
+impl HintRules {
+    /// Rules from `path`; no rules when the file does not exist
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read hint rules: {:?}", path))?;
+        serde_json::from_str(&content).with_context(|| format!("Invalid hint rules: {:?}", path))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty() && !self.replace_builtin
+    }
+
+    /// Add the hints of every matching rule to a serialized `FoundryResponse`
+    pub fn apply(&self, tool: &str, params: &Value, response: &mut Map<String, Value>) {
+        if self.is_empty() {
+            return;
+        }
+
+        let fired: Vec<(HintField, String)> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.tool.as_deref().is_none_or(|name| name == tool))
+            .filter(|rule| rule.when.iter().all(|condition| condition.holds(response)))
+            .map(|rule| (rule.field, fill_params(&rule.hint, params)))
+            .collect();
+
+        if self.replace_builtin {
+            response.remove(HintField::WorkflowHints.key());
+        }
+        for (field, hint) in fired {
+            let hints = response
+                .entry(field.key())
+                .or_insert_with(|| Value::Array(Vec::new()));
+            if let Value::Array(hints) = hints {
+                hints.push(Value::String(hint));
+            }
+        }
+    }
+}
+
+fn lookup<'a>(response: &'a Map<String, Value>, path: &str) -> Option<&'a Value> {
+    let mut segments = path.split('.');
+    let first = response.get(segments.next()?)?;
+    segments.try_fold(first, |value, segment| match value {
+        Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+        other => other.get(segment),
+    })
+}
+
+/// Replace `{name}` with the call's `name` parameter when it is a string or number
+fn fill_params(hint: &str, params: &Value) -> String {
+    let Some(params) = params.as_object() else {
+        return hint.to_string();
+    };
+    params.iter().fold(hint.to_string(), |hint, (name, value)| {
+        let replacement = match value {
+            Value::String(s) => s.clone(),
+            Value::Number(n) => n.to_string(),
+            _ => return hint,
+        };
+        hint.replace(&format!("{{{}}}", name), &replacement)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rules(config: Value) -> HintRules {
+        serde_json::from_value(config).unwrap()
+    }
+
+    fn response(value: Value) -> Map<String, Value> {
+        let Value::Object(map) = value else {
+            unreachable!()
+        };
+        map
+    }
+
+    #[test]
+    fn test_rule_fires_when_conditions_hold() {
+        let rules = rules(json!({
+            "rules": [{
+                "tool": "load_project",
+                "when": [{"path": "data.project.specs_available", "empty": true}],
+                "hint": "{project_name} has no specs yet; try create_spec",
+            }, {
+                "tool": "load_spec",
+                "hint": "Never for load_project",
+            }]
+        }));
+        let params = json!({"project_name": "demo"});
+
+        let mut without_specs = response(json!({
+            "data": {"project": {"name": "demo"}},
+            "workflow_hints": ["builtin"],
+        }));
+        rules.apply("load_project", &params, &mut without_specs);
+        assert_eq!(
+            without_specs["workflow_hints"],
+            json!(["builtin", "demo has no specs yet; try create_spec"])
+        );
+
+        let mut with_specs = response(json!({
+            "data": {"project": {"specs_available": ["20250101_000000_auth"]}},
+        }));
+        rules.apply("load_project", &params, &mut with_specs);
+        assert!(!with_specs.contains_key("workflow_hints"));
+    }
+
+    #[test]
+    fn test_replace_builtin_and_next_steps_field() {
+        let rules = rules(json!({
+            "replace_builtin": true,
+            "rules": [{
+                "when": [
+                    {"path": "validation_status", "equals": "incomplete"},
+                    {"path": "data.files_created", "contains": "spec.md"},
+                ],
+                "hint": "Fill in the missing sections",
+                "field": "next_steps",
+            }]
+        }));
+        let mut incomplete = response(json!({
+            "data": {"files_created": ["spec.md", "notes.md"]},
+            "validation_status": "incomplete",
+            "next_steps": ["builtin step"],
+            "workflow_hints": ["builtin hint"],
+        }));
+
+        rules.apply("create_spec", &json!({}), &mut incomplete);
+
+        assert!(!incomplete.contains_key("workflow_hints"));
+        assert_eq!(
+            incomplete["next_steps"],
+            json!(["builtin step", "Fill in the missing sections"])
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! # Server Logging
+//!
+//! Log output for the MCP server. Logs always go to stderr because stdout is
+//! the MCP transport. With `--log-format json` every event is one JSON object
+//! per line, and events emitted while a tool call runs list the call's
+//! `tool_call` span (`request_id`, `tool`) in their `spans`, including those
+//! from ops and backends, so a single request can be followed through the log.
+//!
+//! Spans can also be exported over OTLP (HTTP/protobuf). Export is off unless
+//! `OTEL_EXPORTER_OTLP_ENDPOINT` or `OTEL_EXPORTER_OTLP_TRACES_ENDPOINT` is
+//! set; the exporter takes its endpoint, headers and timeout from the standard
+//! `OTEL_EXPORTER_OTLP_*` variables and the service name from
+//! `OTEL_SERVICE_NAME` (default `foundry`).
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::Result;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use serde_json::Value;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+/// Variables that turn on OTLP span export when either is set
+const OTLP_ENDPOINT_VARS: [&str; 2] = [
+    "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+    "OTEL_EXPORTER_OTLP_ENDPOINT",
+];
+
+/// Tracer provider of the OTLP export, kept to flush it on exit
+static TRACER_PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+/// Output format for server logs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines
+    Text,
+    /// One JSON object per event
+    Json,
+}
+
+impl LogFormat {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            other => Err(anyhow::anyhow!(
+                "Unsupported log format: {}. Supported formats: text, json",
+                other
+            )),
+        }
+    }
+}
+
+/// Install the global tracing subscriber, writing to stderr
+///
+/// `RUST_LOG` takes precedence; otherwise the level is `debug` when verbose
+/// and `info` when not. Verbose mode also logs each span as it closes with its
+/// busy/idle time, which times ops (`op.*`), the edit engine and backend calls
+/// (`backend.*`). The same spans go to OTLP when export is configured.
+pub fn init(format: LogFormat, verbose: bool) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(if verbose { "debug" } else { "info" }));
+    let span_events = if verbose {
+        FmtSpan::CLOSE
+    } else {
+        FmtSpan::NONE
+    };
+    let fmt = tracing_subscriber::fmt::layer()
+        .with_span_events(span_events)
+        .with_writer(std::io::stderr);
+    let fmt = match format {
+        LogFormat::Text => fmt.boxed(),
+        LogFormat::Json => fmt
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .boxed(),
+    };
+    let otlp = otlp_layer().unwrap_or_else(|e| {
+        eprintln!("OTLP export is off: {:#}", e);
+        None
+    });
+
+    let result = tracing_subscriber::registry()
+        .with(fmt)
+        .with(otlp)
+        .with(filter)
+        .try_init();
+    if let Err(e) = result {
+        eprintln!("Failed to initialize logging: {}", e);
+    }
+}
+
+/// Whether the environment asks for OTLP span export
+pub fn otlp_enabled() -> bool {
+    OTLP_ENDPOINT_VARS
+        .iter()
+        .any(|var| std::env::var(var).is_ok_and(|value| !value.trim().is_empty()))
+}
+
+/// Layer exporting spans over OTLP; `None` when export is not configured
+fn otlp_layer<S>() -> Result<Option<OpenTelemetryLayer<S, SdkTracer>>>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    if !otlp_enabled() {
+        return Ok(None);
+    }
+    // The blocking HTTP client cannot be created on an async runtime thread
+    let exporter = std::thread::spawn(|| {
+        opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .build()
+    })
+    .join()
+    .map_err(|_| anyhow::anyhow!("OTLP exporter setup panicked"))??;
+    let mut resource = Resource::builder();
+    if std::env::var("OTEL_SERVICE_NAME").is_err() {
+        resource = resource.with_service_name("foundry");
+    }
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource.build())
+        .build();
+    let tracer = provider.tracer("foundry-mcp");
+    let _ = TRACER_PROVIDER.set(provider);
+    Ok(Some(tracing_opentelemetry::layer().with_tracer(tracer)))
+}
+
+/// Send spans still queued for OTLP export; call before the process exits
+pub fn shutdown() {
+    if let Some(provider) = TRACER_PROVIDER.get()
+        && let Err(e) = provider.shutdown()
+    {
+        eprintln!("Failed to flush OTLP spans: {}", e);
+    }
+}
+
+static REQUEST_COUNTER: AtomicU64 = AtomicU64::new(1);
+
+/// Identifier for one tool call, unique within the server process
+///
+/// The process id prefix keeps IDs distinct across servers sharing a log.
+pub fn next_request_id() -> String {
+    format!(
+        "{}-{}",
+        std::process::id(),
+        REQUEST_COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Longest string value logged verbatim in a params summary
+const MAX_INLINE_PARAM_LEN: usize = 64;
+
+/// Summarize tool call parameters for logging without dumping spec content
+///
+/// Short strings, numbers and booleans are kept; long strings, arrays and
+/// objects are replaced by their size.
+pub fn summarize_params(params: &Value) -> String {
+    let Some(params) = params.as_object() else {
+        return summarize_value(params);
+    };
+
+    let fields: Vec<String> = params
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, summarize_value(value)))
+        .collect();
+    fields.join(" ")
+}
+
+fn summarize_value(value: &Value) -> String {
+    match value {
+        Value::String(s) if s.chars().count() <= MAX_INLINE_PARAM_LEN => format!("{:?}", s),
+        Value::String(s) => format!("<{} chars>", s.chars().count()),
+        Value::Array(items) => format!("<{} items>", items.len()),
+        Value::Object(fields) => format!("<{} fields>", fields.len()),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_log_format() {
+        assert_eq!(LogFormat::parse("text").unwrap(), LogFormat::Text);
+        assert_eq!(LogFormat::parse("json").unwrap(), LogFormat::Json);
+        assert!(LogFormat::parse("yaml").is_err());
+    }
+
+    #[test]
+    fn test_summarize_params_hides_long_content() {
+        let params = json!({
+            "project_name": "demo",
+            "spec": "x".repeat(500),
+            "confirm": true,
+            "commands": [{}, {}],
+        });
+        assert_eq!(
+            summarize_params(&params),
+            "commands=<2 items> confirm=true project_name=\"demo\" spec=<500 chars>"
+        );
+    }
+
+    #[test]
+    fn test_otlp_export_is_off_without_endpoint() {
+        temp_env::with_vars_unset(OTLP_ENDPOINT_VARS, || assert!(!otlp_enabled()));
+        temp_env::with_var(
+            "OTEL_EXPORTER_OTLP_ENDPOINT",
+            Some("http://localhost:4318"),
+            || {
+                assert!(otlp_enabled());
+            },
+        );
+    }
+
+    #[test]
+    fn test_next_request_id_is_unique() {
+        assert_ne!(next_request_id(), next_request_id());
+    }
+}
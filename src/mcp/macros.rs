@@ -13,10 +13,60 @@
 //!         content: String {
 //!             description = "Content with minimum length validation",
 //!             min_length = 100
+//!         },
+//!         limit: Option<u32> {
+//!             description = "Optional numeric parameter"
 //!         }
 //!     }
 //! }
 //! ```
+//!
+//! An optional `output = ResponseType,` after the tool description publishes
+//! the tool's MCP `output_schema`, generated from the `JsonSchema` derive on the
+//! response type in `types::responses` so documented outputs track the payload.
+//!
+//! Field options are comma-separated `key = value` pairs in any order:
+//! `description`, `min_length`, `max_length`, `pattern`, `enum_values` and
+//! `default`. `max_length`, `pattern` and `enum_values` are also enforced by
+//! `from_mcp_params`, so clients that skip schema validation are still held to
+//! them. `enum_values` takes the allowed strings (e.g.,
+//! `status: String { enum_values = ["draft", "active"] }`) and becomes a JSON
+//! Schema `enum`, on the array's `items` for `Vec<String>` fields. A `default` is
+//! published in the schema, makes the parameter optional, and is parsed in
+//! place of a missing value (e.g., `format: String { default = "csv" }`).
+//! A field's `///` doc comment is used as its description when no
+//! `description` option is given; a field with neither, or
+//! with an unknown option, fails to compile with an error naming the field.
+//!
+//! When the struct itself is written inside the macro (with its attributes, e.g.
+//! `#[derive(Args, Debug)]`), the macro also emits the struct definition, so the
+//! same doc comments drive both clap help and MCP descriptions:
+//!
+//! ```ignore
+//! impl_mcp_tool! {
+//!     name = "example_tool",
+//!     description = "An example tool for documentation",
+//!     #[derive(Args, Debug)]
+//!     pub struct ExampleArgs {
+//!         /// The name parameter
+//!         pub name: String,
+//!
+//!         /// Optional numeric parameter
+//!         #[arg(long)]
+//!         pub limit: Option<u32>,
+//!     }
+//! }
+//! ```
+//!
+//! Field types map to JSON schema types through
+//! [`McpParameter`](crate::mcp::traits::McpParameter): `String` is `string`,
+//! `bool` is `boolean`, integers are `integer` and floats are `number`.
+//! `Vec<T>` is an `array` whose `items` schema comes from `T`, and
+//! `serde_json::Map<String, Value>` is a free-form `object` (so
+//! `Vec<serde_json::Map<String, Value>>` is an array of objects).
+//! `Option<T>` fields are optional; every other field is required. Structs
+//! declared with `impl_mcp_object!` become nested `object` schemas with their
+//! own `properties` and `required` lists.
 
 /// The macro generates:
 /// - `impl McpToolDefinition for StructName`
@@ -27,38 +77,23 @@ macro_rules! impl_mcp_tool {
     (
         name = $tool_name:expr,
         description = $tool_desc:expr,
+        $(output = $output:ty,)?
         struct $struct_name:ident {
             $(
-                $field:ident: $field_type:ty {
-                    description = $field_desc:expr
-                    $(, min_length = $min_len:expr)?
-                }
+                $(#[$($attr:tt)*])* $field:ident: $field_type:ty { $($options:tt)* }
             ),* $(,)?
         }
     ) => {
         impl $crate::mcp::traits::McpToolDefinition for $struct_name {
-            fn tool_definition() -> rust_mcp_sdk::schema::Tool {
-                let mut properties: std::collections::HashMap<String, serde_json::Map<String, serde_json::Value>> = std::collections::HashMap::new();
-
-                $(
-                    {
-                        let mut property = serde_json::Map::new();
-                        property.insert("type".to_string(), serde_json::json!("string"));
-                        property.insert("description".to_string(), serde_json::json!($field_desc));
-                        $(
-                            property.insert("minLength".to_string(), serde_json::json!($min_len));
-                        )?
-                        properties.insert(stringify!($field).to_string(), property);
-                    }
-                )*
+            const NAME: &'static str = $tool_name;
 
-                // Determine required fields (non-Option types)
-                let required_fields = vec![
-                    $(stringify!($field).to_string()),*
-                ];
+            fn tool_definition() -> rust_mcp_sdk::schema::Tool {
+                let (properties, required_fields) = $crate::__mcp_fields!(@schema
+                    $( $(#[$($attr)*])* $field: $field_type { $($options)* } ),*
+                );
 
                 rust_mcp_sdk::schema::Tool {
-                    name: $tool_name.to_string(),
+                    name: Self::NAME.to_string(),
                     description: Some($tool_desc.to_string()),
                     title: None,
                     input_schema: rust_mcp_sdk::schema::ToolInputSchema::new(
@@ -67,24 +102,434 @@ macro_rules! impl_mcp_tool {
                     ),
                     annotations: None,
                     meta: None,
-                    output_schema: None,
+                    output_schema: $crate::__mcp_fields!(@output $($output)?),
                 }
             }
 
             fn from_mcp_params(params: &serde_json::Value) -> anyhow::Result<Self> {
-                Ok(Self {
+                Ok($crate::__mcp_fields!(@parse params, "";
+                    $( $(#[$($attr)*])* $field: $field_type { $($options)* } ),*
+                ))
+            }
+        }
+    };
+    // Struct-defining form: the attributed struct is emitted as written, so clap
+    // help and MCP descriptions share the same `///` doc comments.
+    (
+        name = $tool_name:expr,
+        description = $tool_desc:expr,
+        $(output = $output:ty,)?
+        $(#[$($struct_attr:tt)*])+
+        $vis:vis struct $struct_name:ident {
+            $(
+                $(#[$($attr:tt)*])*
+                $field_vis:vis $field:ident: $field_type:ty $({ $($options:tt)* })?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$($struct_attr)*])+
+        $vis struct $struct_name {
+            $(
+                $(#[$($attr)*])*
+                $field_vis $field: $field_type,
+            )*
+        }
+
+        $crate::impl_mcp_tool! {
+            name = $tool_name,
+            description = $tool_desc,
+            $(output = $output,)?
+            struct $struct_name {
+                $( $(#[$($attr)*])* $field: $field_type { $($($options)*)? } ),*
+            }
+        }
+    };
+}
+
+/// Implements [`McpParameter`](crate::mcp::traits::McpParameter) for a struct so it
+/// can be used as a nested `object` field in `impl_mcp_tool!`
+///
+/// Uses the same field syntax as `impl_mcp_tool!`. The generated schema carries the
+/// struct's own `properties` and `required` lists, and parse errors name the full
+/// path of the offending field (e.g., `selector.section`).
+///
+/// ```ignore
+/// impl_mcp_object! {
+///     struct Selector {
+///         section: String {
+///             description = "Section header to target"
+///         },
+///         task_text: Option<String> {
+///             description = "Task text to match within the section"
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_mcp_object {
+    (
+        struct $struct_name:ident {
+            $(
+                $(#[$($attr:tt)*])* $field:ident: $field_type:ty { $($options:tt)* }
+            ),* $(,)?
+        }
+    ) => {
+        impl $crate::mcp::traits::McpParameter for $struct_name {
+            fn schema() -> serde_json::Map<String, serde_json::Value> {
+                let (properties, required_fields) = $crate::__mcp_fields!(@schema
+                    $( $(#[$($attr)*])* $field: $field_type { $($options)* } ),*
+                );
+
+                let mut schema = serde_json::Map::new();
+                schema.insert("type".to_string(), serde_json::json!("object"));
+                schema.insert(
+                    "properties".to_string(),
+                    serde_json::Value::Object(
+                        properties
+                            .into_iter()
+                            .map(|(name, property)| (name, serde_json::Value::Object(property)))
+                            .collect(),
+                    ),
+                );
+                schema.insert("required".to_string(), serde_json::json!(required_fields));
+                schema
+            }
+
+            fn from_param(name: &str, value: &serde_json::Value) -> anyhow::Result<Self> {
+                if !value.is_object() {
+                    return Err(if value.is_null() {
+                        anyhow::anyhow!("Missing {} parameter", name)
+                    } else {
+                        anyhow::anyhow!("Parameter {} must be an object, got {}", name, value)
+                    });
+                }
+                Ok($crate::__mcp_fields!(@parse value, name;
+                    $( $(#[$($attr)*])* $field: $field_type { $($options)* } ),*
+                ))
+            }
+        }
+    };
+}
+
+/// Shared field expansion for `impl_mcp_tool!` and `impl_mcp_object!`
+///
+/// `@schema` evaluates to `(properties, required_fields)`; `@parse` evaluates to
+/// `Self { .. }` with each field read from `$params`, prefixing error paths with
+/// `$prefix` when it is non-empty.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mcp_fields {
+    (@schema
+        $(
+            $(#[$($attr:tt)*])* $field:ident: $field_type:ty { $($options:tt)* }
+        ),*
+    ) => {{
+        let mut properties: std::collections::HashMap<String, serde_json::Map<String, serde_json::Value>> = std::collections::HashMap::new();
+
+        $(
+            {
+                let mut property = <$field_type as $crate::mcp::traits::McpParameter>::schema();
+                $crate::__mcp_fields!(@docs property, $field, ($($options)*); []; $(#[$($attr)*])*);
+                properties.insert(stringify!($field).to_string(), property);
+            }
+        )*
+
+        // Determine required fields (non-Option types without a default)
+        let mut required_fields: Vec<String> = Vec::new();
+        $(
+            if <$field_type as $crate::mcp::traits::McpParameter>::REQUIRED
+                && !properties[stringify!($field)].contains_key("default")
+            {
+                required_fields.push(stringify!($field).to_string());
+            }
+        )*
+
+        (properties, required_fields)
+    }};
+    (@parse $params:expr, $prefix:expr;
+        $(
+            $(#[$($attr:tt)*])* $field:ident: $field_type:ty { $($options:tt)* }
+        ),*
+    ) => {{
+        let params: &serde_json::Value = $params;
+        let prefix: &str = $prefix;
+        Self {
+            $(
+                $field: {
+                    let name = if prefix.is_empty() {
+                        stringify!($field).to_string()
+                    } else {
+                        format!("{}.{}", prefix, stringify!($field))
+                    };
+                    let default: Option<serde_json::Value> =
+                        $crate::__mcp_fields!(@default $($options)*);
+                    let value = match (&params[stringify!($field)], &default) {
+                        (serde_json::Value::Null, Some(default)) => default,
+                        (value, _) => value,
+                    };
+                    $crate::__mcp_fields!(@validate name, value; $($options)*);
+                    <$field_type as $crate::mcp::traits::McpParameter>::from_param(&name, value)?
+                }
+            ),*
+        }
+    }};
+    // Doc comments (`#[doc = "..."]`) on a field become its description unless a
+    // `description` option overrides them; other attributes are ignored.
+    (@docs $property:ident, $field:ident, $options:tt; [$($doc:literal)*]; #[doc = $line:literal] $($rest:tt)*) => {
+        $crate::__mcp_fields!(@docs $property, $field, $options; [$($doc)* $line]; $($rest)*);
+    };
+    (@docs $property:ident, $field:ident, $options:tt; [$($doc:literal)*]; #[$($other:tt)*] $($rest:tt)*) => {
+        $crate::__mcp_fields!(@docs $property, $field, $options; [$($doc)*]; $($rest)*);
+    };
+    (@docs $property:ident, $field:ident, ($($options:tt)*); [];) => {
+        $crate::__mcp_fields!(@options $property, $field, (); $($options)*);
+    };
+    (@docs $property:ident, $field:ident, ($($options:tt)*); [$($doc:literal)+];) => {
+        $property.insert(
+            "description".to_string(),
+            serde_json::json!($crate::mcp::macros::doc_description(&[$($doc),+])),
+        );
+        $crate::__mcp_fields!(@options $property, $field, (described); $($options)*);
+    };
+    // Field options are `key = value` pairs in any order. Unknown keys and a
+    // missing description are rejected at compile time.
+    (@options $property:ident, $field:ident, $described:tt; description = $value:expr $(, $($rest:tt)*)?) => {
+        $property.insert("description".to_string(), serde_json::json!($value));
+        $crate::__mcp_fields!(@options $property, $field, (described); $($($rest)*)?);
+    };
+    (@options $property:ident, $field:ident, $described:tt; min_length = $value:expr $(, $($rest:tt)*)?) => {
+        $property.insert("minLength".to_string(), serde_json::json!($value));
+        $crate::__mcp_fields!(@options $property, $field, $described; $($($rest)*)?);
+    };
+    (@options $property:ident, $field:ident, $described:tt; default = $value:expr $(, $($rest:tt)*)?) => {
+        $property.insert("default".to_string(), serde_json::json!($value));
+        $crate::__mcp_fields!(@options $property, $field, $described; $($($rest)*)?);
+    };
+    (@options $property:ident, $field:ident, $described:tt; max_length = $value:expr $(, $($rest:tt)*)?) => {
+        $property.insert("maxLength".to_string(), serde_json::json!($value));
+        $crate::__mcp_fields!(@options $property, $field, $described; $($($rest)*)?);
+    };
+    (@options $property:ident, $field:ident, $described:tt; pattern = $value:expr $(, $($rest:tt)*)?) => {
+        $property.insert("pattern".to_string(), serde_json::json!($value));
+        $crate::__mcp_fields!(@options $property, $field, $described; $($($rest)*)?);
+    };
+    (@options $property:ident, $field:ident, $described:tt; enum_values = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::mcp::macros::insert_enum(&mut $property, &$value);
+        $crate::__mcp_fields!(@options $property, $field, $described; $($($rest)*)?);
+    };
+    (@options $property:ident, $field:ident, (described);) => {};
+    (@options $property:ident, $field:ident, ();) => {
+        compile_error!(concat!(
+            "impl_mcp_tool! field `",
+            stringify!($field),
+            "` needs a doc comment or `description = \"...\"`"
+        ));
+    };
+    (@options $property:ident, $field:ident, $described:tt; $key:ident = $($rest:tt)*) => {
+        compile_error!(concat!(
+            "unknown option `",
+            stringify!($key),
+            "` on impl_mcp_tool! field `",
+            stringify!($field),
+            "`; expected one of: description, min_length, max_length, pattern, enum_values, default"
+        ));
+    };
+    (@options $property:ident, $field:ident, $described:tt; $($rest:tt)+) => {
+        compile_error!(concat!(
+            "expected `key = value` options on impl_mcp_tool! field `",
+            stringify!($field),
+            "`, found `",
+            stringify!($($rest)+),
+            "`"
+        ));
+    };
+    // Enforce `max_length`, `pattern` and `enum_values` on the raw value before conversion
+    (@validate $name:ident, $value:ident;) => {};
+    (@validate $name:ident, $value:ident; max_length = $max:expr $(, $($rest:tt)*)?) => {
+        $crate::mcp::macros::check_max_length(&$name, $value, $max)?;
+        $crate::__mcp_fields!(@validate $name, $value; $($($rest)*)?);
+    };
+    (@validate $name:ident, $value:ident; pattern = $pattern:expr $(, $($rest:tt)*)?) => {
+        $crate::mcp::macros::check_pattern(&$name, $value, $pattern)?;
+        $crate::__mcp_fields!(@validate $name, $value; $($($rest)*)?);
+    };
+    (@validate $name:ident, $value:ident; enum_values = $values:expr $(, $($rest:tt)*)?) => {
+        $crate::mcp::macros::check_enum(&$name, $value, &$values)?;
+        $crate::__mcp_fields!(@validate $name, $value; $($($rest)*)?);
+    };
+    (@validate $name:ident, $value:ident; $key:ident = $other:expr $(, $($rest:tt)*)?) => {
+        $crate::__mcp_fields!(@validate $name, $value; $($($rest)*)?);
+    };
+    // Output schema from the optional tool-level `output = ResponseType`
+    (@output) => {
+        None
+    };
+    (@output $output:ty) => {
+        Some($crate::mcp::traits::output_schema::<$output>())
+    };
+    // Find a field's `default` option as JSON (options are validated by `@options`)
+    (@default) => {
+        None
+    };
+    (@default default = $value:expr $(, $($rest:tt)*)?) => {
+        Some(serde_json::json!($value))
+    };
+    (@default $key:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__mcp_fields!(@default $($($rest)*)?)
+    };
+}
+
+/// Registers the MCP tools served by Foundry
+///
+/// Generates `FoundryTools::all_tools()` and `FoundryTools::dispatch()` from one
+/// list of argument types, so a tool cannot be listed without being routable.
+/// Every tool also takes the optional `workspace` parameter, handled here
+/// rather than by each handler.
+/// Each type must implement `McpToolDefinition` and `McpToolHandler`; a
+/// duplicate tool name surfaces as an unreachable match arm.
+///
+/// ```ignore
+/// foundry_tools! {
+///     CreateProjectArgs,
+///     LoadProjectArgs,
+/// }
+/// ```
+#[macro_export]
+macro_rules! foundry_tools {
+    ($($args:ty),* $(,)?) => {
+        impl FoundryTools {
+            /// Get all available tools, each accepting the optional `workspace` parameter
+            pub fn all_tools() -> Vec<rust_mcp_sdk::schema::Tool> {
+                vec![
+                    $($crate::mcp::tools::with_workspace_param(
+                        <$args as $crate::mcp::traits::McpToolDefinition>::tool_definition()
+                    )),*
+                ]
+            }
+
+            /// Names of all available tools, in registration order
+            pub fn tool_names() -> Vec<&'static str> {
+                vec![$(<$args as $crate::mcp::traits::McpToolDefinition>::NAME),*]
+            }
+
+            /// Parse MCP parameters for `tool_name` and run its operation, in the
+            /// workspace named by a `workspace` parameter if there is one
+            pub async fn dispatch(
+                tool_name: &str,
+                params: &serde_json::Value,
+            ) -> Result<serde_json::Value, $crate::mcp::error::FoundryMcpError> {
+                match $crate::mcp::tools::requested_workspace(params)? {
+                    Some(workspace) => {
+                        $crate::core::workspace::scoped(
+                            workspace,
+                            Self::dispatch_tool(tool_name, params),
+                        )
+                        .await?
+                    }
+                    None => Self::dispatch_tool(tool_name, params).await,
+                }
+            }
+
+            async fn dispatch_tool(
+                tool_name: &str,
+                params: &serde_json::Value,
+            ) -> Result<serde_json::Value, $crate::mcp::error::FoundryMcpError> {
+                match tool_name {
                     $(
-                        $field: params[stringify!($field)]
-                            .as_str()
-                            .ok_or_else(|| anyhow::anyhow!("Missing {} parameter", stringify!($field)))?
-                            .to_string()
-                    ),*
-                })
+                        <$args as $crate::mcp::traits::McpToolDefinition>::NAME => {
+                            let args = <$args as $crate::mcp::traits::McpToolDefinition>::from_mcp_params(params)
+                                .map_err(|e| {
+                                    $crate::mcp::error::FoundryMcpError::invalid_params(format!(
+                                        "Invalid parameters for {}: {}",
+                                        tool_name, e
+                                    ))
+                                })?;
+                            $crate::mcp::traits::McpToolHandler::handle(args).await
+                        }
+                    )*
+                    _ => Err($crate::mcp::error::FoundryMcpError::unknown_tool(tool_name)),
+                }
             }
         }
     };
 }
 
+/// Join `///` doc lines into a description, dropping the leading space rustdoc keeps
+#[doc(hidden)]
+pub fn doc_description(lines: &[&str]) -> String {
+    lines
+        .iter()
+        .map(|line| line.trim())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Reject string values longer than `max` characters (non-strings are left to type checks)
+#[doc(hidden)]
+pub fn check_max_length(name: &str, value: &serde_json::Value, max: usize) -> anyhow::Result<()> {
+    match value.as_str() {
+        Some(text) if text.chars().count() > max => Err(anyhow::anyhow!(
+            "Parameter {} must be at most {} characters, got {}",
+            name,
+            max,
+            text.chars().count()
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Reject string values that do not match `pattern` (JSON Schema semantics: unanchored)
+#[doc(hidden)]
+pub fn check_pattern(name: &str, value: &serde_json::Value, pattern: &str) -> anyhow::Result<()> {
+    let Some(text) = value.as_str() else {
+        return Ok(());
+    };
+    let regex = regex::Regex::new(pattern)
+        .map_err(|e| anyhow::anyhow!("Invalid pattern for parameter {}: {}", name, e))?;
+    if regex.is_match(text) {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "Parameter {} must match pattern {}",
+            name,
+            pattern
+        ))
+    }
+}
+
+/// Add `values` as the property's JSON Schema `enum`, or its `items` enum for arrays
+#[doc(hidden)]
+pub fn insert_enum(property: &mut serde_json::Map<String, serde_json::Value>, values: &[&str]) {
+    let target = match property.get_mut("items") {
+        Some(serde_json::Value::Object(items)) => items,
+        _ => property,
+    };
+    target.insert("enum".to_string(), serde_json::json!(values));
+}
+
+/// Reject strings (or array elements) that are not one of `values`
+#[doc(hidden)]
+pub fn check_enum(name: &str, value: &serde_json::Value, values: &[&str]) -> anyhow::Result<()> {
+    let invalid = match value {
+        serde_json::Value::String(text) => (!values.contains(&text.as_str())).then_some(text),
+        serde_json::Value::Array(items) => items.iter().find_map(|item| match item {
+            serde_json::Value::String(text) if !values.contains(&text.as_str()) => Some(text),
+            _ => None,
+        }),
+        _ => None,
+    };
+    invalid.map_or(Ok(()), |text| {
+        Err(anyhow::anyhow!(
+            "Parameter {} must be one of {}, got '{}'",
+            name,
+            values.join(", "),
+            text
+        ))
+    })
+}
+
 // The macros are automatically available where this module is used
 
 #[cfg(test)]
@@ -203,4 +648,572 @@ mod tests {
         let error_msg = result.unwrap_err().to_string();
         assert!(error_msg.contains("Missing") && error_msg.contains("parameter"));
     }
+
+    #[derive(Debug)]
+    pub struct TestTypedArgs {
+        pub name: String,
+        pub dry_run: bool,
+        pub limit: Option<u32>,
+        pub threshold: f64,
+    }
+
+    impl_mcp_tool! {
+        name = "typed_tool",
+        description = "Tool with non-string parameters",
+        struct TestTypedArgs {
+            name: String {
+                description = "Name"
+            },
+            dry_run: bool {
+                description = "Whether to skip writes"
+            },
+            limit: Option<u32> {
+                description = "Maximum number of results"
+            },
+            threshold: f64 {
+                description = "Similarity threshold"
+            }
+        }
+    }
+
+    #[test]
+    fn test_typed_parameter_schema() {
+        let tool = TestTypedArgs::tool_definition();
+        let properties = tool.input_schema.properties.as_ref().unwrap();
+
+        assert_eq!(properties["dry_run"]["type"], serde_json::json!("boolean"));
+        assert_eq!(properties["limit"]["type"], serde_json::json!("integer"));
+        assert_eq!(properties["limit"]["minimum"], serde_json::json!(0));
+        assert_eq!(properties["threshold"]["type"], serde_json::json!("number"));
+
+        let required = &tool.input_schema.required;
+        assert_eq!(required.len(), 3);
+        assert!(!required.contains(&"limit".to_string()));
+    }
+
+    #[test]
+    fn test_typed_parameter_conversion() {
+        let params = serde_json::json!({
+            "name": "typed",
+            "dry_run": true,
+            "limit": 25,
+            "threshold": 0.75
+        });
+        let result = TestTypedArgs::from_mcp_params(&params).unwrap();
+        assert_eq!(result.name, "typed");
+        assert!(result.dry_run);
+        assert_eq!(result.limit, Some(25));
+        assert_eq!(result.threshold, 0.75);
+
+        let params = serde_json::json!({ "name": "typed", "dry_run": false, "threshold": 1 });
+        let result = TestTypedArgs::from_mcp_params(&params).unwrap();
+        assert_eq!(result.limit, None);
+        assert_eq!(result.threshold, 1.0);
+    }
+
+    #[test]
+    fn test_typed_parameter_rejects_wrong_types() {
+        let params = serde_json::json!({ "name": "typed", "dry_run": "yes", "threshold": 1 });
+        let error = TestTypedArgs::from_mcp_params(&params)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("dry_run must be a boolean"));
+
+        let params =
+            serde_json::json!({ "name": "typed", "dry_run": true, "limit": -1, "threshold": 1 });
+        assert!(TestTypedArgs::from_mcp_params(&params).is_err());
+    }
+
+    #[derive(Debug)]
+    pub struct TestArrayArgs {
+        pub tags: Vec<String>,
+        pub commands: Vec<serde_json::Map<String, serde_json::Value>>,
+        pub weights: Option<Vec<u32>>,
+    }
+
+    impl_mcp_tool! {
+        name = "array_tool",
+        description = "Tool with array parameters",
+        struct TestArrayArgs {
+            tags: Vec<String> {
+                description = "Tags to apply"
+            },
+            commands: Vec<serde_json::Map<String, serde_json::Value>> {
+                description = "Edit commands"
+            },
+            weights: Option<Vec<u32>> {
+                description = "Optional weights"
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_parameter_schema() {
+        let tool = TestArrayArgs::tool_definition();
+        let properties = tool.input_schema.properties.as_ref().unwrap();
+
+        assert_eq!(
+            properties["tags"]["items"],
+            serde_json::json!({ "type": "string" })
+        );
+        assert_eq!(properties["commands"]["type"], serde_json::json!("array"));
+        assert_eq!(
+            properties["commands"]["items"],
+            serde_json::json!({ "type": "object" })
+        );
+        assert_eq!(
+            properties["weights"]["items"]["type"],
+            serde_json::json!("integer")
+        );
+        assert_eq!(tool.input_schema.required.len(), 2);
+    }
+
+    #[test]
+    fn test_array_parameter_conversion() {
+        let params = serde_json::json!({
+            "tags": ["a", "b"],
+            "commands": [{ "command": "upsert_task", "content": "- [ ] Do it" }]
+        });
+        let result = TestArrayArgs::from_mcp_params(&params).unwrap();
+        assert_eq!(result.tags, vec!["a", "b"]);
+        assert_eq!(result.commands[0]["command"], "upsert_task");
+        assert!(result.weights.is_none());
+
+        let params = serde_json::json!({ "tags": ["a", 1], "commands": [] });
+        let error = TestArrayArgs::from_mcp_params(&params)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("tags[1] must be a string"));
+    }
+
+    #[derive(Debug)]
+    pub struct TestSelector {
+        pub section: String,
+        pub task_text: Option<String>,
+    }
+
+    impl_mcp_object! {
+        struct TestSelector {
+            section: String {
+                description = "Section header to target"
+            },
+            task_text: Option<String> {
+                description = "Task text to match"
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct TestNestedArgs {
+        pub selector: TestSelector,
+        pub fallbacks: Option<Vec<TestSelector>>,
+    }
+
+    impl_mcp_tool! {
+        name = "nested_tool",
+        description = "Tool with nested object parameters",
+        struct TestNestedArgs {
+            selector: TestSelector {
+                description = "Where to apply the edit"
+            },
+            fallbacks: Option<Vec<TestSelector>> {
+                description = "Alternative selectors"
+            }
+        }
+    }
+
+    #[test]
+    fn test_nested_object_schema() {
+        let tool = TestNestedArgs::tool_definition();
+        let properties = tool.input_schema.properties.as_ref().unwrap();
+
+        let selector = &properties["selector"];
+        assert_eq!(selector["type"], serde_json::json!("object"));
+        assert_eq!(
+            selector["description"],
+            serde_json::json!("Where to apply the edit")
+        );
+        assert_eq!(selector["required"], serde_json::json!(["section"]));
+        assert_eq!(
+            selector["properties"]["task_text"]["type"],
+            serde_json::json!("string")
+        );
+        assert_eq!(
+            properties["fallbacks"]["items"]["required"],
+            serde_json::json!(["section"])
+        );
+        assert_eq!(tool.input_schema.required, vec!["selector".to_string()]);
+    }
+
+    #[test]
+    fn test_nested_object_conversion() {
+        let params = serde_json::json!({
+            "selector": { "section": "## Setup", "task_text": "Init repo" },
+            "fallbacks": [{ "section": "## Tasks" }]
+        });
+        let result = TestNestedArgs::from_mcp_params(&params).unwrap();
+        assert_eq!(result.selector.section, "## Setup");
+        assert_eq!(result.selector.task_text.as_deref(), Some("Init repo"));
+        assert!(result.fallbacks.unwrap()[0].task_text.is_none());
+
+        let params = serde_json::json!({ "selector": { "task_text": "Init repo" } });
+        let error = TestNestedArgs::from_mcp_params(&params)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("Missing selector.section parameter"));
+
+        let params = serde_json::json!({ "selector": "## Setup" });
+        assert!(TestNestedArgs::from_mcp_params(&params).is_err());
+    }
+
+    #[derive(Debug)]
+    pub struct TestOptionOrderArgs {
+        pub content: String,
+    }
+
+    impl_mcp_tool! {
+        name = "option_order_tool",
+        description = "Tool whose field options are not in the default order",
+        struct TestOptionOrderArgs {
+            content: String {
+                min_length = 10,
+                description = "Content, with commas, \"quotes\" and = signs",
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_options_in_any_order() {
+        let tool = TestOptionOrderArgs::tool_definition();
+        let content = &tool.input_schema.properties.as_ref().unwrap()["content"];
+        assert_eq!(content["minLength"], serde_json::json!(10));
+        assert_eq!(
+            content["description"],
+            serde_json::json!("Content, with commas, \"quotes\" and = signs")
+        );
+
+        let params = serde_json::json!({ "content": "Some content" });
+        let result = TestOptionOrderArgs::from_mcp_params(&params).unwrap();
+        assert_eq!(result.content, "Some content");
+    }
+
+    #[derive(Debug)]
+    pub struct TestDocArgs {
+        pub project_name: String,
+        pub limit: Option<u32>,
+    }
+
+    impl_mcp_tool! {
+        name = "doc_tool",
+        description = "Tool whose field descriptions come from doc comments",
+        struct TestDocArgs {
+            /// Project to inspect
+            ///
+            /// Must already exist
+            project_name: String {},
+            /// Ignored in favour of the explicit description
+            limit: Option<u32> {
+                description = "Explicit description wins"
+            }
+        }
+    }
+
+    #[test]
+    fn test_doc_comment_descriptions() {
+        let tool = TestDocArgs::tool_definition();
+        let properties = tool.input_schema.properties.as_ref().unwrap();
+        assert_eq!(
+            properties["project_name"]["description"],
+            serde_json::json!("Project to inspect\n\nMust already exist")
+        );
+        assert_eq!(
+            properties["limit"]["description"],
+            serde_json::json!("Explicit description wins")
+        );
+
+        let result =
+            TestDocArgs::from_mcp_params(&serde_json::json!({ "project_name": "p" })).unwrap();
+        assert_eq!(result.project_name, "p");
+        assert!(result.limit.is_none());
+    }
+
+    impl_mcp_tool! {
+        name = "defined_tool",
+        description = "Tool whose struct is defined by the macro",
+        /// CLI arguments for defined_tool
+        #[derive(Debug, clap::Args)]
+        pub struct TestDefinedArgs {
+            /// Name shared by CLI help and MCP schema
+            pub name: String,
+
+            /// Content to write
+            #[arg(long)]
+            pub content: String {
+                min_length = 5
+            },
+
+            /// Run without writing
+            #[arg(long)]
+            pub dry_run: Option<bool>,
+        }
+    }
+
+    #[test]
+    fn test_struct_defining_form() {
+        use clap::CommandFactory;
+
+        let tool = TestDefinedArgs::tool_definition();
+        let properties = tool.input_schema.properties.as_ref().unwrap();
+        assert_eq!(
+            properties["name"]["description"],
+            serde_json::json!("Name shared by CLI help and MCP schema")
+        );
+        assert_eq!(
+            properties["dry_run"]["description"],
+            serde_json::json!("Run without writing")
+        );
+        assert_eq!(properties["content"]["minLength"], serde_json::json!(5));
+        assert_eq!(tool.input_schema.required.len(), 2);
+        assert!(!tool.input_schema.required.contains(&"dry_run".to_string()));
+
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: TestDefinedArgs,
+        }
+        let command = Cli::command();
+        let name_arg = command
+            .get_arguments()
+            .find(|a| a.get_id() == "name")
+            .unwrap();
+        assert_eq!(
+            name_arg.get_help().unwrap().to_string(),
+            "Name shared by CLI help and MCP schema"
+        );
+
+        let result = TestDefinedArgs::from_mcp_params(&serde_json::json!({
+            "name": "n",
+            "content": "Hello world",
+            "dry_run": true
+        }))
+        .unwrap();
+        assert_eq!(result.name, "n");
+        assert_eq!(result.content, "Hello world");
+        assert_eq!(result.dry_run, Some(true));
+    }
+
+    #[derive(Debug)]
+    pub struct TestDefaultArgs {
+        pub format: String,
+        pub dry_run: bool,
+        pub limit: Option<u32>,
+    }
+
+    impl_mcp_tool! {
+        name = "default_tool",
+        description = "Tool with default parameter values",
+        struct TestDefaultArgs {
+            format: String {
+                description = "Output format",
+                default = "csv"
+            },
+            dry_run: bool {
+                default = true,
+                description = "Report without writing"
+            },
+            limit: Option<u32> {
+                description = "Maximum results",
+                default = 10
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_values_in_schema_and_parsing() {
+        let tool = TestDefaultArgs::tool_definition();
+        let properties = tool.input_schema.properties.as_ref().unwrap();
+        assert_eq!(properties["format"]["default"], serde_json::json!("csv"));
+        assert_eq!(properties["dry_run"]["default"], serde_json::json!(true));
+        assert_eq!(properties["limit"]["default"], serde_json::json!(10));
+        assert!(tool.input_schema.required.is_empty());
+
+        let result = TestDefaultArgs::from_mcp_params(&serde_json::json!({})).unwrap();
+        assert_eq!(result.format, "csv");
+        assert!(result.dry_run);
+        assert_eq!(result.limit, Some(10));
+
+        let result = TestDefaultArgs::from_mcp_params(&serde_json::json!({
+            "format": "jira",
+            "dry_run": false,
+            "limit": 3
+        }))
+        .unwrap();
+        assert_eq!(result.format, "jira");
+        assert!(!result.dry_run);
+        assert_eq!(result.limit, Some(3));
+    }
+
+    #[derive(Debug)]
+    pub struct TestConstraintArgs {
+        pub project_name: String,
+        pub title: Option<String>,
+    }
+
+    impl_mcp_tool! {
+        name = "constraint_tool",
+        description = "Tool with length and pattern constraints",
+        struct TestConstraintArgs {
+            project_name: String {
+                description = "Kebab-case project name",
+                pattern = "^[a-z0-9]+(-[a-z0-9]+)*$",
+                max_length = 20
+            },
+            title: Option<String> {
+                description = "Short title",
+                max_length = 5
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_length_and_pattern_schema() {
+        let tool = TestConstraintArgs::tool_definition();
+        let properties = tool.input_schema.properties.as_ref().unwrap();
+        assert_eq!(
+            properties["project_name"]["pattern"],
+            serde_json::json!("^[a-z0-9]+(-[a-z0-9]+)*$")
+        );
+        assert_eq!(
+            properties["project_name"]["maxLength"],
+            serde_json::json!(20)
+        );
+        assert_eq!(properties["title"]["maxLength"], serde_json::json!(5));
+    }
+
+    #[test]
+    fn test_max_length_and_pattern_enforced() {
+        let ok = TestConstraintArgs::from_mcp_params(&serde_json::json!({
+            "project_name": "my-app",
+            "title": "Héllo"
+        }))
+        .unwrap();
+        assert_eq!(ok.title.as_deref(), Some("Héllo"));
+        assert_eq!(ok.project_name, "my-app");
+
+        let error = TestConstraintArgs::from_mcp_params(&serde_json::json!({
+            "project_name": "My_App"
+        }))
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("project_name must match pattern"));
+
+        let error = TestConstraintArgs::from_mcp_params(&serde_json::json!({
+            "project_name": "my-app",
+            "title": "Too long"
+        }))
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("title must be at most 5 characters"));
+    }
+
+    #[derive(Debug)]
+    pub struct TestEnumArgs {
+        pub status: String,
+        pub tags: Option<Vec<String>>,
+    }
+
+    impl_mcp_tool! {
+        name = "enum_tool",
+        description = "Tool with enumerated parameters",
+        struct TestEnumArgs {
+            status: String {
+                description = "Lifecycle status",
+                enum_values = ["draft", "active"]
+            },
+            tags: Option<Vec<String>> {
+                description = "Labels to apply",
+                enum_values = ["bug", "feature"]
+            }
+        }
+    }
+
+    #[test]
+    fn test_enum_values_in_schema_and_enforced() {
+        let tool = TestEnumArgs::tool_definition();
+        let properties = tool.input_schema.properties.as_ref().unwrap();
+        assert_eq!(
+            properties["status"]["enum"],
+            serde_json::json!(["draft", "active"])
+        );
+        assert_eq!(properties["status"]["type"], serde_json::json!("string"));
+        assert_eq!(
+            properties["tags"]["items"]["enum"],
+            serde_json::json!(["bug", "feature"])
+        );
+        assert!(!properties["tags"].contains_key("enum"));
+
+        let ok = TestEnumArgs::from_mcp_params(&serde_json::json!({
+            "status": "active",
+            "tags": ["bug"]
+        }))
+        .unwrap();
+        assert_eq!(ok.status, "active");
+        assert_eq!(ok.tags, Some(vec!["bug".to_string()]));
+
+        let error = TestEnumArgs::from_mcp_params(&serde_json::json!({ "status": "done" }))
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("status must be one of draft, active, got 'done'"));
+        let error = TestEnumArgs::from_mcp_params(&serde_json::json!({
+            "status": "draft",
+            "tags": ["bug", "chore"]
+        }))
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("got 'chore'"));
+    }
+
+    #[derive(Debug)]
+    pub struct TestOutputArgs {
+        pub project_name: String,
+    }
+
+    impl_mcp_tool! {
+        name = "output_tool",
+        description = "Tool that documents its response",
+        output = crate::types::responses::DiagramSpecsResponse,
+        struct TestOutputArgs {
+            project_name: String {
+                description = "Project name"
+            }
+        }
+    }
+
+    #[test]
+    fn test_output_schema_from_response_type() {
+        let tool = TestOutputArgs::tool_definition();
+        let output = tool.output_schema.unwrap();
+        assert!(output.required.contains(&"data".to_string()));
+
+        let properties = output.properties.unwrap();
+        let data = &properties["data"];
+        assert_eq!(
+            data["properties"]["dependency_diagram"]["type"],
+            serde_json::json!("string")
+        );
+        // Nested response types are inlined rather than referenced
+        assert_eq!(
+            data["properties"]["phase_diagrams"]["items"]["properties"]["spec_name"]["type"],
+            serde_json::json!("string")
+        );
+        assert!(properties.contains_key("validation_status"));
+
+        assert!(TestTypedArgs::tool_definition().output_schema.is_none());
+        assert_eq!(
+            TestOutputArgs::from_mcp_params(&serde_json::json!({ "project_name": "p" }))
+                .unwrap()
+                .project_name,
+            "p"
+        );
+    }
 }
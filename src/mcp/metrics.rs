@@ -0,0 +1,575 @@
+//! # Server Metrics
+//!
+//! Opt-in, in-memory metrics for `foundry serve --metrics` (or
+//! `--metrics-port`): calls and latencies per tool, outcomes of edit commands,
+//! and the confidence of fuzzy `spec_name` matches. Counters start at zero
+//! when the server starts and are read through the `get_server_stats` tool or,
+//! with `--metrics-port`, a Prometheus text endpoint at `GET /metrics`:
+//!
+//! | Metric | Type | Labels |
+//! |---|---|---|
+//! | `foundry_tool_calls_total` | counter | `tool`, `outcome` |
+//! | `foundry_tool_errors_total` | counter | `tool`, `code` |
+//! | `foundry_tool_call_duration_seconds` | histogram | `tool` |
+//! | `foundry_edit_commands_total` | counter | `result` |
+//! | `foundry_edit_command_failures_total` | counter | `code` |
+//! | `foundry_spec_match_confidence` | histogram | |
+//! | `foundry_uptime_seconds` | gauge | |
+//!
+//! Metrics are read from tool responses, so ops need no instrumentation.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+use crate::mcp::error::FoundryMcpError;
+use crate::mcp::rest;
+use crate::types::errors::ErrorCode;
+use crate::types::responses::{
+    ConfidenceBucket, EditCommandStats, ServerStatsResponse, ToolUsageStats,
+};
+
+/// Upper bounds of the call latency buckets, in seconds
+const LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Upper bounds of the match confidence buckets
+const CONFIDENCE_BUCKETS: &[f64] = &[0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
+
+/// Tool label for calls naming a tool that does not exist, so the label set stays bounded
+const UNKNOWN_TOOL_LABEL: &str = "unknown";
+
+static METRICS: OnceLock<Arc<ServerMetrics>> = OnceLock::new();
+
+/// Turn on metrics for this process and return the shared collector
+pub fn enable() -> Arc<ServerMetrics> {
+    Arc::clone(METRICS.get_or_init(|| Arc::new(ServerMetrics::new())))
+}
+
+/// The shared collector, if metrics were turned on
+pub fn enabled() -> Option<Arc<ServerMetrics>> {
+    METRICS.get().cloned()
+}
+
+/// Counts and latencies collected since the server started
+#[derive(Debug)]
+pub struct ServerMetrics {
+    started: Instant,
+    state: Mutex<MetricsState>,
+}
+
+#[derive(Debug)]
+struct MetricsState {
+    tools: BTreeMap<String, ToolMetrics>,
+    edit_commands_applied: u64,
+    edit_commands_skipped: u64,
+    /// Failed edit commands by error code
+    edit_command_failures: BTreeMap<&'static str, u64>,
+    match_confidence: Histogram,
+}
+
+#[derive(Debug)]
+struct ToolMetrics {
+    calls: u64,
+    /// Failed calls by error code
+    errors: BTreeMap<&'static str, u64>,
+    latency: Histogram,
+    max_duration: Duration,
+}
+
+/// Observations counted into buckets by upper bound, with their sum
+#[derive(Debug, Clone)]
+struct Histogram {
+    bounds: &'static [f64],
+    /// Observations per bucket, not cumulative; the last entry is above every bound
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            counts: vec![0; bounds.len() + 1],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        let bucket = self
+            .bounds
+            .iter()
+            .position(|bound| value <= *bound)
+            .unwrap_or(self.bounds.len());
+        self.counts[bucket] += 1;
+        self.sum += value;
+        self.count += 1;
+    }
+
+    /// `_bucket`, `_sum` and `_count` lines for `name` with `labels` (`key="value"` pairs)
+    fn render(&self, out: &mut String, name: &str, labels: &str) {
+        let separator = if labels.is_empty() { "" } else { "," };
+        let mut cumulative = 0;
+        for (bound, count) in self.bounds.iter().zip(&self.counts) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{}_bucket{{{}{}le=\"{}\"}} {}",
+                name, labels, separator, bound, cumulative
+            );
+        }
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{}{}le=\"+Inf\"}} {}",
+            name, labels, separator, self.count
+        );
+        let braces = if labels.is_empty() {
+            String::new()
+        } else {
+            format!("{{{}}}", labels)
+        };
+        let _ = writeln!(out, "{}_sum{} {}", name, braces, self.sum);
+        let _ = writeln!(out, "{}_count{} {}", name, braces, self.count);
+    }
+}
+
+impl Default for ServerMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ServerMetrics {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            state: Mutex::new(MetricsState {
+                tools: BTreeMap::new(),
+                edit_commands_applied: 0,
+                edit_commands_skipped: 0,
+                edit_command_failures: BTreeMap::new(),
+                match_confidence: Histogram::new(CONFIDENCE_BUCKETS),
+            }),
+        }
+    }
+
+    /// Count a finished tool call and what its response reports
+    pub fn record_call(
+        &self,
+        tool_name: &str,
+        outcome: &Result<Value, FoundryMcpError>,
+        elapsed: Duration,
+    ) {
+        let code = outcome.as_ref().err().map(FoundryMcpError::code);
+        let tool = if code == Some(ErrorCode::UnknownTool) {
+            UNKNOWN_TOOL_LABEL
+        } else {
+            tool_name
+        };
+
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let tool_metrics = state
+            .tools
+            .entry(tool.to_string())
+            .or_insert_with(|| ToolMetrics {
+                calls: 0,
+                errors: BTreeMap::new(),
+                latency: Histogram::new(LATENCY_BUCKETS),
+                max_duration: Duration::ZERO,
+            });
+        tool_metrics.calls += 1;
+        tool_metrics.latency.observe(elapsed.as_secs_f64());
+        tool_metrics.max_duration = tool_metrics.max_duration.max(elapsed);
+        if let Some(code) = code {
+            *tool_metrics.errors.entry(code.as_str()).or_default() += 1;
+        }
+
+        if let Ok(response) = outcome {
+            let data = &response["data"];
+            match tool_name {
+                "update_spec" if data["dry_run"] != Value::Bool(true) => {
+                    state.record_edit_commands(data);
+                }
+                "update_specs_batch" => {
+                    for result in data["results"].as_array().into_iter().flatten() {
+                        state.record_edit_commands(result);
+                    }
+                }
+                "load_spec" => {
+                    // A resolved fuzzy match, or the best guess when several specs matched
+                    let confidence = data["match_info"]["confidence"]
+                        .as_f64()
+                        .or_else(|| data["candidates"][0]["confidence"].as_f64());
+                    if let Some(confidence) = confidence {
+                        state.match_confidence.observe(confidence);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Totals since startup, as returned by `get_server_stats`
+    pub fn snapshot(&self) -> ServerStatsResponse {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut tools: Vec<ToolUsageStats> = state
+            .tools
+            .iter()
+            .map(|(tool, metrics)| {
+                let errors = metrics.errors.values().sum::<u64>() as usize;
+                ToolUsageStats {
+                    tool: tool.clone(),
+                    calls: metrics.calls as usize,
+                    errors,
+                    error_rate: errors as f64 / metrics.calls as f64,
+                    avg_duration_ms: metrics.latency.sum * 1000.0 / metrics.calls as f64,
+                    max_duration_ms: metrics.max_duration.as_millis() as u64,
+                }
+            })
+            .collect();
+        // Stable sort keeps ties in name order
+        tools.sort_by_key(|t| std::cmp::Reverse(t.calls));
+
+        let failed = state.edit_command_failures.values().sum::<u64>();
+        let succeeded = state.edit_commands_applied + state.edit_commands_skipped;
+        let edit_commands = EditCommandStats {
+            applied: state.edit_commands_applied,
+            skipped_idempotent: state.edit_commands_skipped,
+            failed,
+            success_rate: (succeeded + failed > 0)
+                .then(|| succeeded as f64 / (succeeded + failed) as f64),
+            failures_by_code: state
+                .edit_command_failures
+                .iter()
+                .map(|(code, count)| (code.to_string(), *count))
+                .collect(),
+        };
+
+        let histogram = &state.match_confidence;
+        let match_confidence = histogram
+            .bounds
+            .iter()
+            .zip(&histogram.counts)
+            .scan(0.0, |from, (to, count)| {
+                let bucket = ConfidenceBucket {
+                    from: *from,
+                    to: *to,
+                    count: *count,
+                };
+                *from = *to;
+                Some(bucket)
+            })
+            .collect();
+
+        ServerStatsResponse {
+            uptime_seconds: self.started.elapsed().as_secs(),
+            total_calls: tools.iter().map(|t| t.calls).sum(),
+            error_count: tools.iter().map(|t| t.errors).sum(),
+            tools,
+            edit_commands,
+            match_confidence,
+        }
+    }
+
+    /// Metrics in the Prometheus text exposition format
+    pub fn render_prometheus(&self) -> String {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let mut out = String::new();
+
+        out.push_str("# HELP foundry_tool_calls_total Tool calls handled, by tool and outcome\n");
+        out.push_str("# TYPE foundry_tool_calls_total counter\n");
+        for (tool, metrics) in &state.tools {
+            let errors = metrics.errors.values().sum::<u64>();
+            for (outcome, count) in [("ok", metrics.calls - errors), ("error", errors)] {
+                let _ = writeln!(
+                    out,
+                    "foundry_tool_calls_total{{tool=\"{}\",outcome=\"{}\"}} {}",
+                    escape_label(tool),
+                    outcome,
+                    count
+                );
+            }
+        }
+
+        out.push_str(
+            "# HELP foundry_tool_errors_total Failed tool calls, by tool and error code\n",
+        );
+        out.push_str("# TYPE foundry_tool_errors_total counter\n");
+        for (tool, metrics) in &state.tools {
+            for (code, count) in &metrics.errors {
+                let _ = writeln!(
+                    out,
+                    "foundry_tool_errors_total{{tool=\"{}\",code=\"{}\"}} {}",
+                    escape_label(tool),
+                    code,
+                    count
+                );
+            }
+        }
+
+        out.push_str("# HELP foundry_tool_call_duration_seconds Tool call latency\n");
+        out.push_str("# TYPE foundry_tool_call_duration_seconds histogram\n");
+        for (tool, metrics) in &state.tools {
+            metrics.latency.render(
+                &mut out,
+                "foundry_tool_call_duration_seconds",
+                &format!("tool=\"{}\"", escape_label(tool)),
+            );
+        }
+
+        out.push_str(
+            "# HELP foundry_edit_commands_total Edit commands sent to update_spec and update_specs_batch, by result\n",
+        );
+        out.push_str("# TYPE foundry_edit_commands_total counter\n");
+        for (result, count) in [
+            ("applied", state.edit_commands_applied),
+            ("skipped_idempotent", state.edit_commands_skipped),
+            ("failed", state.edit_command_failures.values().sum()),
+        ] {
+            let _ = writeln!(
+                out,
+                "foundry_edit_commands_total{{result=\"{}\"}} {}",
+                result, count
+            );
+        }
+
+        out.push_str(
+            "# HELP foundry_edit_command_failures_total Failed edit commands, by error code\n",
+        );
+        out.push_str("# TYPE foundry_edit_command_failures_total counter\n");
+        for (code, count) in &state.edit_command_failures {
+            let _ = writeln!(
+                out,
+                "foundry_edit_command_failures_total{{code=\"{}\"}} {}",
+                code, count
+            );
+        }
+
+        out.push_str(
+            "# HELP foundry_spec_match_confidence Confidence of fuzzy spec_name matches in load_spec\n",
+        );
+        out.push_str("# TYPE foundry_spec_match_confidence histogram\n");
+        state
+            .match_confidence
+            .render(&mut out, "foundry_spec_match_confidence", "");
+
+        out.push_str("# HELP foundry_uptime_seconds Seconds since metrics collection started\n");
+        out.push_str("# TYPE foundry_uptime_seconds gauge\n");
+        let _ = writeln!(
+            out,
+            "foundry_uptime_seconds {}",
+            self.started.elapsed().as_secs()
+        );
+        out
+    }
+}
+
+impl MetricsState {
+    /// Count the commands reported in an `update_spec` payload or batch result
+    fn record_edit_commands(&mut self, result: &Value) {
+        self.edit_commands_applied += result["applied_count"].as_u64().unwrap_or(0);
+        self.edit_commands_skipped += result["skipped_idempotent_count"].as_u64().unwrap_or(0);
+        for error in result["errors"].as_array().into_iter().flatten() {
+            let code =
+                serde_json::from_value(error["code"].clone()).unwrap_or(ErrorCode::InternalError);
+            *self
+                .edit_command_failures
+                .entry(ErrorCode::as_str(code))
+                .or_default() += 1;
+        }
+    }
+}
+
+/// `value` escaped for use inside a quoted Prometheus label
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Listener for the metrics endpoint on `addr`
+pub async fn bind(addr: SocketAddr) -> Result<TcpListener> {
+    TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind metrics endpoint to {}", addr))
+}
+
+/// Serve `GET /metrics` on `listener` until the process exits
+pub async fn serve(listener: TcpListener, metrics: Arc<ServerMetrics>) {
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                tracing::warn!("Failed to accept metrics connection: {}", e);
+                continue;
+            }
+        };
+        let metrics = Arc::clone(&metrics);
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &metrics).await {
+                tracing::debug!("Metrics connection from {} failed: {:#}", peer, e);
+            }
+        });
+    }
+}
+
+async fn handle_connection<S>(stream: S, metrics: &ServerMetrics) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut reader = BufReader::new(stream);
+    let (status, reason, body) = match rest::read_request(&mut reader, rest::READ_TIMEOUT).await {
+        Ok(request) if request.method == "GET" && request.path == "/metrics" => {
+            (200, "OK", metrics.render_prometheus())
+        }
+        Ok(request) => (
+            404,
+            "Not Found",
+            format!(
+                "No route for {} {}; metrics are served at GET /metrics\n",
+                request.method, request.path
+            ),
+        ),
+        Err(e) => (
+            400,
+            "Bad Request",
+            format!("Malformed HTTP request: {:#}\n", e),
+        ),
+    };
+
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason,
+        body.len()
+    );
+    let stream = reader.get_mut();
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body.as_bytes()).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tokio::io::AsyncReadExt;
+
+    fn record_sample_calls(metrics: &ServerMetrics) {
+        metrics.record_call(
+            "update_spec",
+            &Ok(json!({"data": {
+                "applied_count": 2,
+                "skipped_idempotent_count": 1,
+                "errors": [{"code": "SELECTOR_NOT_FOUND"}],
+                "dry_run": false
+            }})),
+            Duration::from_millis(30),
+        );
+        // Dry runs preview commands without applying them
+        metrics.record_call(
+            "update_spec",
+            &Ok(json!({"data": {"applied_count": 5, "dry_run": true}})),
+            Duration::from_millis(10),
+        );
+        metrics.record_call(
+            "load_spec",
+            &Ok(json!({"data": {"match_info": {"confidence": 0.85}}})),
+            Duration::from_millis(5),
+        );
+        metrics.record_call(
+            "load_spec",
+            &Err(FoundryMcpError::CliCommand {
+                source: ErrorCode::SpecNotFound.error("Spec 'x' not found"),
+            }),
+            Duration::from_millis(3),
+        );
+        metrics.record_call(
+            "no_such_tool",
+            &Err(FoundryMcpError::unknown_tool("no_such_tool")),
+            Duration::ZERO,
+        );
+    }
+
+    #[test]
+    fn test_snapshot_counts_calls_commands_and_matches() {
+        let metrics = ServerMetrics::new();
+        record_sample_calls(&metrics);
+        let stats = metrics.snapshot();
+
+        assert_eq!(stats.total_calls, 5);
+        assert_eq!(stats.error_count, 2);
+        assert_eq!(stats.tools[0].tool, "load_spec");
+        assert_eq!(stats.tools[0].errors, 1);
+        assert!(stats.tools.iter().any(|t| t.tool == UNKNOWN_TOOL_LABEL));
+
+        assert_eq!(stats.edit_commands.applied, 2);
+        assert_eq!(stats.edit_commands.skipped_idempotent, 1);
+        assert_eq!(stats.edit_commands.failed, 1);
+        assert_eq!(stats.edit_commands.success_rate, Some(0.75));
+        assert_eq!(
+            stats.edit_commands.failures_by_code["SELECTOR_NOT_FOUND"],
+            1
+        );
+
+        let matched: Vec<(f64, u64)> = stats
+            .match_confidence
+            .iter()
+            .filter(|bucket| bucket.count > 0)
+            .map(|bucket| (bucket.from, bucket.count))
+            .collect();
+        assert_eq!(matched, vec![(0.8, 1)]);
+    }
+
+    #[test]
+    fn test_prometheus_output_has_cumulative_buckets() {
+        let metrics = ServerMetrics::new();
+        record_sample_calls(&metrics);
+        let text = metrics.render_prometheus();
+
+        assert!(text.contains("foundry_tool_calls_total{tool=\"update_spec\",outcome=\"ok\"} 2"));
+        assert!(
+            text.contains(
+                "foundry_tool_errors_total{tool=\"load_spec\",code=\"SPEC_NOT_FOUND\"} 1"
+            )
+        );
+        assert!(text.contains(
+            "foundry_tool_call_duration_seconds_bucket{tool=\"update_spec\",le=\"0.025\"} 1"
+        ));
+        assert!(text.contains(
+            "foundry_tool_call_duration_seconds_bucket{tool=\"update_spec\",le=\"+Inf\"} 2"
+        ));
+        assert!(text.contains("foundry_edit_commands_total{result=\"failed\"} 1"));
+        assert!(text.contains("foundry_spec_match_confidence_bucket{le=\"0.9\"} 1"));
+        assert!(text.contains("foundry_spec_match_confidence_count 1"));
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_serves_metrics_path_only() {
+        let metrics = ServerMetrics::new();
+        for (path, expected) in [("/metrics", "200 OK"), ("/other", "404 Not Found")] {
+            let (mut client, server) = tokio::io::duplex(64 * 1024);
+            client
+                .write_all(format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).as_bytes())
+                .await
+                .unwrap();
+            handle_connection(server, &metrics).await.unwrap();
+            let mut response = String::new();
+            client.read_to_string(&mut response).await.unwrap();
+            assert!(response.starts_with(&format!("HTTP/1.1 {}", expected)));
+        }
+    }
+}
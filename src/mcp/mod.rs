@@ -7,12 +7,22 @@
 //! requirement that "MCP tools map directly to CLI commands" with identical
 //! JSON response formats.
 
+pub mod crash_report;
 pub mod error;
 pub mod handlers;
+pub mod hints;
+pub mod logging;
 pub mod macros;
+pub mod metrics;
+pub mod rest;
+pub mod sampling;
 pub mod server;
+pub mod shutdown;
 pub mod tools;
 pub mod traits;
+pub mod watch;
+pub mod webhooks;
+pub mod wire_log;
 
 pub use handlers::*;
 pub use server::*;
@@ -0,0 +1,504 @@
+//! # REST API
+//!
+//! Plain HTTP/JSON access to the core ops for tools that cannot speak MCP.
+//! Every route maps to an MCP tool and runs through the same
+//! [`FoundryServerHandler::call_tool`] pipeline, so responses are the same
+//! `FoundryResponse` JSON the tools return:
+//!
+//! | Route | Tool |
+//! |---|---|
+//! | `GET /projects` | `list_projects` |
+//! | `POST /projects` | `create_project` |
+//! | `GET /projects/{project}` | `load_project` |
+//! | `GET /projects/{project}/specs` | `list_specs` |
+//! | `POST /projects/{project}/specs` | `create_spec` |
+//! | `GET /projects/{project}/specs/{spec}` | `load_spec` |
+//! | `PATCH /projects/{project}/specs/{spec}` | `update_spec` |
+//!
+//! `GET /health` answers `{"status": "ok"}` without calling a tool, or 503
+//! with `{"status": "shutting_down"}` once a shutdown signal has arrived.
+//!
+//! Request bodies are the tool's parameters as a JSON object; names in the
+//! path fill in `project_name` and `spec_name`. Failures return
+//! `{"error": {"code", "message"}}` with a status derived from the code.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde_json::{Map, Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+use crate::core::audit;
+use crate::mcp::error::FoundryMcpError;
+use crate::mcp::handlers::{FoundryServerHandler, error_body};
+use crate::types::errors::ErrorCode;
+use crate::types::responses::AuditInterface;
+
+/// Largest request body accepted
+const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+/// Largest request line or header accepted
+const MAX_HEADER_LINE_BYTES: usize = 8 * 1024;
+
+/// Most headers accepted in one request
+const MAX_HEADERS: usize = 100;
+
+/// Time a client gets to send its whole request
+pub(crate) const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Listener for the REST API on `addr`
+pub async fn bind(addr: SocketAddr) -> Result<TcpListener> {
+    TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind REST API to {}", addr))
+}
+
+/// Serve REST requests on `listener` until the process exits
+pub async fn serve(listener: TcpListener, handler: FoundryServerHandler) {
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                tracing::warn!("Failed to accept REST connection: {}", e);
+                continue;
+            }
+        };
+        let handler = handler.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &handler).await {
+                tracing::debug!("REST connection from {} failed: {:#}", peer, e);
+            }
+        });
+    }
+}
+
+/// Read one request from `stream`, run it and write the response
+async fn handle_connection<S>(stream: S, handler: &FoundryServerHandler) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut reader = BufReader::new(stream);
+    let (status, body) = match read_request(&mut reader, READ_TIMEOUT).await {
+        Ok(request) => respond(&request, handler).await,
+        Err(e) => (
+            400,
+            error_body(&FoundryMcpError::invalid_params(format!(
+                "Malformed HTTP request: {:#}",
+                e
+            ))),
+        ),
+    };
+
+    let body = serde_json::to_string_pretty(&body)?;
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason_phrase(status),
+        body.len()
+    );
+    let stream = reader.get_mut();
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body.as_bytes()).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+/// Method, path and body of an HTTP request
+#[derive(Debug)]
+pub(crate) struct Request {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) body: Vec<u8>,
+}
+
+/// Read the request line, headers and body of one HTTP request, failing if
+/// the client has not sent all of it within `timeout`
+pub(crate) async fn read_request<R: AsyncRead + Unpin>(
+    reader: &mut BufReader<R>,
+    timeout: Duration,
+) -> Result<Request> {
+    tokio::time::timeout(timeout, read_request_parts(reader))
+        .await
+        .map_err(|_| anyhow::anyhow!("request not received within {:?}", timeout))?
+}
+
+async fn read_request_parts<R: AsyncRead + Unpin>(reader: &mut BufReader<R>) -> Result<Request> {
+    let request_line = read_line(reader).await?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        anyhow::bail!("invalid request line '{}'", request_line);
+    };
+    // The query string is not used by any route
+    let path = target.split('?').next().unwrap_or_default().to_string();
+    let method = method.to_string();
+
+    let mut content_length = 0;
+    let mut headers = 0;
+    loop {
+        let line = read_line(reader).await?;
+        if line.is_empty() {
+            break;
+        }
+        headers += 1;
+        if headers > MAX_HEADERS {
+            anyhow::bail!("more than {} headers", MAX_HEADERS);
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = value
+                .trim()
+                .parse()
+                .with_context(|| format!("invalid Content-Length '{}'", value.trim()))?;
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        anyhow::bail!("body of {} bytes exceeds the limit", content_length);
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).await?;
+    Ok(Request { method, path, body })
+}
+
+/// One header line without its line ending
+async fn read_line<R: AsyncRead + Unpin>(reader: &mut BufReader<R>) -> Result<String> {
+    let mut line = Vec::new();
+    let read = (&mut *reader)
+        .take(MAX_HEADER_LINE_BYTES as u64)
+        .read_until(b'\n', &mut line)
+        .await?;
+    if read == 0 || !line.ends_with(b"\n") {
+        anyhow::bail!("connection closed or header line too long");
+    }
+    Ok(String::from_utf8(line)
+        .context("header is not UTF-8")?
+        .trim_end_matches(['\r', '\n'])
+        .to_string())
+}
+
+/// Status and JSON body for `request`
+async fn respond(request: &Request, handler: &FoundryServerHandler) -> (u16, Value) {
+    if request.method == "GET" && request.path == "/health" {
+        let in_flight = handler.in_flight();
+        return if in_flight.is_shutting_down() {
+            (503, json!({"status": "shutting_down"}))
+        } else {
+            (200, json!({"status": "ok", "in_flight": in_flight.count()}))
+        };
+    }
+    let segments: Vec<String> = request
+        .path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(percent_decode)
+        .collect();
+    let Some((tool, path_params)) = route(&request.method, &segments) else {
+        return (
+            404,
+            error_body(&FoundryMcpError::invalid_params(format!(
+                "No REST route for {} {}",
+                request.method, request.path
+            ))),
+        );
+    };
+
+    let mut params = match parse_body(&request.body) {
+        Ok(params) => params,
+        Err(e) => return (400, error_body(&e)),
+    };
+    params.extend(path_params);
+
+    let params = Value::Object(params);
+    let call = handler.call_tool(tool, &params);
+    match audit::with_interface(AuditInterface::Rest, call).await {
+        Ok(response) => (if request.method == "POST" { 201 } else { 200 }, response),
+        Err(e) => (status_for(e.code()), error_body(&e)),
+    }
+}
+
+/// Tool for a method and path, with the parameters taken from the path
+fn route(method: &str, segments: &[String]) -> Option<(&'static str, Map<String, Value>)> {
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+    let tool = match (method, segments.as_slice()) {
+        ("GET", ["projects"]) => "list_projects",
+        ("POST", ["projects"]) => "create_project",
+        ("GET", ["projects", _]) => "load_project",
+        ("GET", ["projects", _, "specs"]) => "list_specs",
+        ("POST", ["projects", _, "specs"]) => "create_spec",
+        ("GET", ["projects", _, "specs", _]) => "load_spec",
+        ("PATCH", ["projects", _, "specs", _]) => "update_spec",
+        _ => return None,
+    };
+    // Names follow their collection: /projects/{project_name}/specs/{spec_name}
+    let path_params = segments
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .zip(["project_name", "spec_name"])
+        .map(|(value, name)| (name.to_string(), Value::String(value.to_string())))
+        .collect();
+    Some((tool, path_params))
+}
+
+/// Request body as tool parameters; an empty body means no parameters
+fn parse_body(body: &[u8]) -> Result<Map<String, Value>, FoundryMcpError> {
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(Map::new());
+    }
+    match serde_json::from_slice(body) {
+        Ok(Value::Object(params)) => Ok(params),
+        Ok(_) => Err(FoundryMcpError::invalid_params(
+            "Request body must be a JSON object of tool parameters",
+        )),
+        Err(e) => Err(FoundryMcpError::invalid_params(format!(
+            "Request body is not valid JSON: {}",
+            e
+        ))),
+    }
+}
+
+/// HTTP status for a failed call's error code
+fn status_for(code: ErrorCode) -> u16 {
+    match code {
+        ErrorCode::ProjectNotFound | ErrorCode::SpecNotFound | ErrorCode::UnknownTool => 404,
+        ErrorCode::AlreadyExists | ErrorCode::BackendConflict => 409,
+        ErrorCode::SelectorAmbiguous
+        | ErrorCode::SelectorNotFound
+        | ErrorCode::ValidationFailed => 422,
+        ErrorCode::InvalidParams => 400,
+        ErrorCode::ToolNotAllowed => 403,
+        ErrorCode::SamplingUnavailable | ErrorCode::CapabilityUnsupported => 501,
+        ErrorCode::ShuttingDown | ErrorCode::ResourceBusy | ErrorCode::BackendUnavailable => 503,
+        ErrorCode::InternalError => 500,
+    }
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        409 => "Conflict",
+        422 => "Unprocessable Content",
+        501 => "Not Implemented",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+/// Decode `%XX` escapes in a path segment; invalid escapes are kept as-is
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| segment.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::backends::memory::InMemoryBackend;
+    use crate::core::{foundry, workspace};
+    use crate::mcp::tools::ToolFilter;
+    use std::sync::Arc;
+
+    /// Send a raw request through `handle_connection` and parse the response
+    async fn send(handler: &FoundryServerHandler, request: String) -> (u16, Value) {
+        let (mut client, server) = tokio::io::duplex(64 * 1024);
+        client.write_all(request.as_bytes()).await.unwrap();
+        handle_connection(server, handler).await.unwrap();
+
+        let mut response = String::new();
+        client.read_to_string(&mut response).await.unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    fn request(method: &str, path: &str, body: &Value) -> String {
+        let body = body.to_string();
+        format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+    }
+
+    #[tokio::test]
+    async fn test_rest_routes_share_tool_responses() {
+        let handler = FoundryServerHandler::new();
+        let workspace = tempfile::TempDir::new().unwrap();
+        let calls = foundry::with_backend(Arc::new(InMemoryBackend::new()), async {
+            let project = json!({
+                "project_name": "rest-demo",
+                "vision": "REST demo vision describing the problem, users and goals. ".repeat(4),
+                "tech_stack": "Rust with tokio, serde and clap for the command line. ".repeat(3),
+                "summary": "REST demo project used to test the REST API routes. ".repeat(2),
+            });
+            let (status, _) = send(&handler, request("POST", "/projects", &project)).await;
+            assert_eq!(status, 201);
+
+            let spec = json!({
+                "feature_name": "login",
+                "spec": "Login spec",
+                "notes": "Notes",
+                "tasks": "- [ ] Build login",
+            });
+            let (status, created) = send(
+                &handler,
+                request("POST", "/projects/rest-demo/specs", &spec),
+            )
+            .await;
+            assert_eq!(status, 201);
+            let spec_name = created["data"]["spec_name"].as_str().unwrap().to_string();
+
+            let (status, listed) = send(
+                &handler,
+                request("GET", "/projects/rest-demo/specs", &json!({})),
+            )
+            .await;
+            assert_eq!(status, 200);
+            assert_eq!(listed["data"]["specs"][0]["name"], spec_name);
+
+            let (status, loaded) = send(
+                &handler,
+                request(
+                    "GET",
+                    &format!("/projects/rest-demo/specs/{}", spec_name),
+                    &json!({}),
+                ),
+            )
+            .await;
+            assert_eq!(status, 200);
+            assert_eq!(loaded["data"]["spec_name"], spec_name);
+
+            let (status, missing) =
+                send(&handler, request("GET", "/projects/missing", &json!({}))).await;
+            assert_eq!(status, 404);
+            assert_eq!(missing["error"]["code"], "PROJECT_NOT_FOUND");
+
+            // Changes are audited as REST calls; reads are not
+            let entries = audit::entries("rest-demo").unwrap();
+            let operations: Vec<&str> = entries.iter().map(|e| e.operation.as_str()).collect();
+            assert_eq!(operations, ["create_project", "create_spec"]);
+            assert!(
+                entries
+                    .iter()
+                    .all(|entry| entry.interface == AuditInterface::Rest)
+            );
+        });
+        workspace::with_workspace_dir(workspace.path().to_path_buf(), calls).await;
+    }
+
+    #[tokio::test]
+    async fn test_rest_rejects_unknown_routes_and_bad_bodies() {
+        let handler = FoundryServerHandler::new();
+
+        let (status, body) = send(&handler, request("DELETE", "/projects", &json!({}))).await;
+        assert_eq!(status, 404);
+        assert_eq!(body["error"]["code"], "INVALID_PARAMS");
+
+        let (status, body) = send(
+            &handler,
+            "POST /projects HTTP/1.1\r\nContent-Length: 3\r\n\r\n[1]".to_string(),
+        )
+        .await;
+        assert_eq!(status, 400);
+        assert!(
+            body["error"]["message"]
+                .as_str()
+                .unwrap()
+                .contains("JSON object")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_shutdown() {
+        let handler = FoundryServerHandler::new();
+        let (status, body) = send(&handler, request("GET", "/health", &json!({}))).await;
+        assert_eq!(status, 200);
+        assert_eq!(body, json!({"status": "ok", "in_flight": 0}));
+
+        handler.in_flight().begin_shutdown();
+        let (status, body) = send(&handler, request("GET", "/health", &json!({}))).await;
+        assert_eq!(status, 503);
+        assert_eq!(body["status"], "shutting_down");
+    }
+
+    #[tokio::test]
+    async fn test_read_request_limits_headers_and_time() {
+        let headers: String = (0..=MAX_HEADERS)
+            .map(|index| format!("X-Header-{}: value\r\n", index))
+            .collect();
+        let (mut client, server) = tokio::io::duplex(64 * 1024);
+        client
+            .write_all(format!("GET /projects HTTP/1.1\r\n{}\r\n", headers).as_bytes())
+            .await
+            .unwrap();
+        let error = read_request(&mut BufReader::new(server), READ_TIMEOUT)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("headers"), "{}", error);
+
+        // A client that stops sending is dropped once the timeout passes
+        let (mut client, server) = tokio::io::duplex(64 * 1024);
+        client
+            .write_all(b"GET /projects HTTP/1.1\r\nHost: localhost\r\n")
+            .await
+            .unwrap();
+        let error = read_request(&mut BufReader::new(server), Duration::from_millis(50))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("not received"), "{}", error);
+        drop(client);
+    }
+
+    #[tokio::test]
+    async fn test_rest_refuses_tools_outside_the_filter() {
+        let handler =
+            FoundryServerHandler::new().with_tool_filter(ToolFilter::new(true, &[]).unwrap());
+        foundry::with_backend(Arc::new(InMemoryBackend::new()), async {
+            let (status, body) = send(&handler, request("POST", "/projects", &json!({}))).await;
+            assert_eq!(status, 403);
+            assert_eq!(body["error"]["code"], "TOOL_NOT_ALLOWED");
+
+            let (status, _) = send(&handler, request("GET", "/projects", &json!({}))).await;
+            assert_eq!(status, 200);
+        })
+        .await;
+    }
+
+    #[test]
+    fn test_route_fills_names_from_path() {
+        let segments: Vec<String> = ["projects", "demo", "specs", "20250101_000000_auth"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let (tool, params) = route("PATCH", &segments).unwrap();
+        assert_eq!(tool, "update_spec");
+        assert_eq!(params["project_name"], "demo");
+        assert_eq!(params["spec_name"], "20250101_000000_auth");
+        assert!(route("PUT", &segments).is_none());
+        assert_eq!(percent_decode("user%20auth%zz"), "user auth%zz");
+    }
+}
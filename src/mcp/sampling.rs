@@ -0,0 +1,89 @@
+//! # Client Sampling
+//!
+//! Answers `core::sampling` requests by sending `sampling/createMessage` to the
+//! connected client. The runtime is only borrowed while a tool call runs, so
+//! the call gets a channel-backed [`Sampler`] and the handler forwards what it
+//! receives until the call finishes.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use rust_mcp_sdk::{
+    McpServer,
+    schema::{
+        CreateMessageRequestParams, CreateMessageResultContent, Role, SamplingMessage, TextContent,
+    },
+};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::core::sampling::{self, SampledText, Sampler, SamplingRequest};
+
+type SampleCall = (SamplingRequest, oneshot::Sender<Result<SampledText>>);
+
+/// Sampler handing requests to the handler serving the current call
+struct ClientSampler {
+    requests: mpsc::UnboundedSender<SampleCall>,
+}
+
+#[async_trait]
+impl Sampler for ClientSampler {
+    async fn sample(&self, request: SamplingRequest) -> Result<SampledText> {
+        let (reply, answer) = oneshot::channel();
+        self.requests
+            .send((request, reply))
+            .map_err(|_| anyhow!("The tool call finished before sampling"))?;
+        answer
+            .await
+            .map_err(|_| anyhow!("The sampling request was dropped"))?
+    }
+}
+
+/// Run a tool call, answering its sampling requests through `runtime` when the
+/// client supports sampling
+pub async fn with_client_sampling<F: Future>(runtime: &dyn McpServer, call: F) -> F::Output {
+    if runtime.client_supports_sampling() != Some(true) {
+        return call.await;
+    }
+    let (requests, mut received) = mpsc::unbounded_channel();
+    let call = sampling::with_sampler(Arc::new(ClientSampler { requests }), call);
+    tokio::pin!(call);
+    loop {
+        tokio::select! {
+            output = &mut call => return output,
+            Some((request, reply)) = received.recv() => {
+                let _ = reply.send(create_message(runtime, request).await);
+            }
+        }
+    }
+}
+
+async fn create_message(runtime: &dyn McpServer, request: SamplingRequest) -> Result<SampledText> {
+    let params = CreateMessageRequestParams {
+        include_context: None,
+        max_tokens: i64::from(request.max_tokens),
+        messages: vec![SamplingMessage {
+            content: TextContent::new(request.prompt, None, None).into(),
+            role: Role::User,
+        }],
+        metadata: None,
+        model_preferences: None,
+        stop_sequences: Vec::new(),
+        system_prompt: Some(request.system_prompt),
+        temperature: None,
+    };
+    let result = runtime
+        .create_message(params)
+        .await
+        .map_err(|e| anyhow!("The client did not complete the sampling request: {}", e))?;
+    match result.content {
+        CreateMessageResultContent::TextContent(content) => Ok(SampledText {
+            text: content.text,
+            model: Some(result.model).filter(|model| !model.is_empty()),
+        }),
+        _ => Err(anyhow!(
+            "The client answered the sampling request without text"
+        )),
+    }
+}
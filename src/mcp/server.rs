@@ -13,8 +13,75 @@ use rust_mcp_sdk::{
     },
 };
 use rust_mcp_transport::{StdioTransport, TransportOptions};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::mcp::{error::FoundryMcpError, handlers::FoundryServerHandler};
+use crate::mcp::{
+    crash_report::{self, RecentCalls},
+    error::FoundryMcpError,
+    handlers::{DEFAULT_SLOW_OP_THRESHOLD, FoundryServerHandler},
+    hints::{self, HintRules},
+    metrics, rest,
+    shutdown::{SHUTDOWN_GRACE_PERIOD, shutdown_signal},
+    tools::{FoundryTools, ToolFilter},
+    watch::{self, FoundryWatcher},
+    webhooks::{self, WebhookConfig},
+    wire_log::WireLog,
+};
+use crate::utils::response::GuidanceLevel;
+
+/// Time allowed after the last call finishes for its response to be written
+const RESPONSE_FLUSH_DELAY: Duration = Duration::from_millis(100);
+
+/// Options for a server started with `foundry serve`
+#[derive(Debug, Clone)]
+pub struct ServerOptions {
+    /// Record tool calls and responses to the rotating wire log
+    pub wire_log: bool,
+    /// Calls taking at least this long report a timing breakdown in their diagnostics
+    pub slow_op_threshold: Duration,
+    /// Write a local crash report when the server panics
+    pub crash_reports: bool,
+    /// How much of `next_steps`/`workflow_hints` responses keep
+    pub guidance: GuidanceLevel,
+    /// Workflow hint rules file; `~/.foundry/.hints.json` when not set
+    pub hint_rules: Option<PathBuf>,
+    /// Warn at startup about specs with open tasks idle for this many days
+    pub stale_check_days: Option<u32>,
+    /// Webhooks file; `~/.foundry/.webhooks.json` when not set
+    pub webhooks: Option<PathBuf>,
+    /// Also serve the REST API on this address
+    pub rest_addr: Option<SocketAddr>,
+    /// Notify the client when files under this directory change on disk
+    pub watch_dir: Option<PathBuf>,
+    /// Collect metrics for `get_server_stats`
+    pub metrics: bool,
+    /// Tools the server lists and serves, over MCP and REST
+    pub tool_filter: ToolFilter,
+    /// Also serve the metrics in Prometheus format on this address
+    pub metrics_addr: Option<SocketAddr>,
+}
+
+impl Default for ServerOptions {
+    fn default() -> Self {
+        Self {
+            wire_log: false,
+            slow_op_threshold: DEFAULT_SLOW_OP_THRESHOLD,
+            crash_reports: false,
+            guidance: GuidanceLevel::Full,
+            hint_rules: None,
+            stale_check_days: None,
+            webhooks: None,
+            rest_addr: None,
+            watch_dir: None,
+            metrics: false,
+            tool_filter: ToolFilter::default(),
+            metrics_addr: None,
+        }
+    }
+}
 
 /// MCP Server configuration and startup
 pub struct FoundryMcpServer;
@@ -24,7 +91,7 @@ impl FoundryMcpServer {
     ///
     /// This is the main entry point for MCP server mode, providing all 8 foundry
     /// commands as MCP tools with identical functionality to the CLI.
-    pub async fn start() -> Result<(), FoundryMcpError> {
+    pub async fn start(options: ServerOptions) -> Result<(), FoundryMcpError> {
         tracing::info!("Starting Foundry MCP server");
 
         // Create server details following PRD specifications
@@ -38,7 +105,7 @@ impl FoundryMcpServer {
                 tools: Some(ServerCapabilitiesTools { list_changed: None }),
                 resources: Some(ServerCapabilitiesResources {
                     subscribe: None,
-                    list_changed: None,
+                    list_changed: options.watch_dir.is_some().then_some(true),
                 }),
                 ..Default::default()
             },
@@ -52,8 +119,111 @@ impl FoundryMcpServer {
             protocol_version: LATEST_PROTOCOL_VERSION.to_string(),
         };
 
+        let registered = FoundryTools::tool_names();
+        let exposed: Vec<&str> = registered
+            .iter()
+            .copied()
+            .filter(|tool| options.tool_filter.allows(tool))
+            .collect();
+        if exposed.len() < registered.len() {
+            tracing::info!(
+                "Exposing {} of {} tools: {}",
+                exposed.len(),
+                registered.len(),
+                exposed.join(", ")
+            );
+        }
+
         // Create the server handler
-        let handler = FoundryServerHandler::new();
+        let mut handler = FoundryServerHandler::new()
+            .with_tool_filter(options.tool_filter)
+            .with_slow_op_threshold(options.slow_op_threshold)
+            .with_guidance(options.guidance);
+        let hint_rules_path = match options.hint_rules {
+            Some(path) => path,
+            None => hints::default_path().map_err(|e| {
+                FoundryMcpError::internal_error(format!("Failed to locate hint rules: {}", e))
+            })?,
+        };
+        let hint_rules = HintRules::load(&hint_rules_path)
+            .map_err(|e| FoundryMcpError::internal_error(format!("{:#}", e)))?;
+        if !hint_rules.is_empty() {
+            tracing::info!(
+                "Loaded {} workflow hint rule(s) from {:?}",
+                hint_rules.rules.len(),
+                hint_rules_path
+            );
+        }
+        handler = handler.with_hint_rules(hint_rules);
+        let webhooks_path = match options.webhooks {
+            Some(path) => path,
+            None => webhooks::default_path().map_err(|e| {
+                FoundryMcpError::internal_error(format!("Failed to locate webhooks: {}", e))
+            })?,
+        };
+        let webhooks = WebhookConfig::load(&webhooks_path)
+            .map_err(|e| FoundryMcpError::internal_error(format!("{:#}", e)))?;
+        if !webhooks.is_empty() {
+            tracing::info!(
+                "Sending change events to {} webhook(s) from {:?}",
+                webhooks.webhooks.len(),
+                webhooks_path
+            );
+            handler = handler.with_webhooks(webhooks);
+        }
+        if options.wire_log {
+            let wire_log = WireLog::open_default().map_err(|e| {
+                FoundryMcpError::internal_error(format!("Failed to open wire log: {}", e))
+            })?;
+            tracing::info!("Recording tool calls to {:?}", wire_log.active_path());
+            handler = handler.with_wire_log(wire_log);
+        }
+        if options.crash_reports {
+            let dir = crash_report::default_dir().map_err(|e| {
+                FoundryMcpError::internal_error(format!(
+                    "Failed to locate crash report directory: {}",
+                    e
+                ))
+            })?;
+            tracing::info!("Writing crash reports to {:?}", dir);
+            let recent_calls = RecentCalls::new();
+            crash_report::install(dir, Arc::clone(&recent_calls));
+            handler = handler.with_recent_calls(recent_calls);
+        }
+
+        if options.metrics || options.metrics_addr.is_some() {
+            let metrics = metrics::enable();
+            if let Some(addr) = options.metrics_addr {
+                let listener = metrics::bind(addr)
+                    .await
+                    .map_err(|e| FoundryMcpError::internal_error(format!("{:#}", e)))?;
+                tracing::info!("Metrics endpoint listening on http://{}/metrics", addr);
+                tokio::spawn(metrics::serve(listener, Arc::clone(&metrics)));
+            }
+            handler = handler.with_metrics(metrics);
+        }
+
+        if let Some(stale_days) = options.stale_check_days {
+            warn_stale_specs(stale_days).await;
+        }
+
+        if let Some(addr) = options.rest_addr {
+            let listener = rest::bind(addr)
+                .await
+                .map_err(|e| FoundryMcpError::internal_error(format!("{:#}", e)))?;
+            tracing::info!("REST API listening on http://{}", addr);
+            tokio::spawn(rest::serve(listener, handler.clone()));
+        }
+
+        let watcher = match &options.watch_dir {
+            Some(dir) => {
+                let watcher = FoundryWatcher::start(dir, handler.in_flight())
+                    .map_err(|e| FoundryMcpError::internal_error(format!("{:#}", e)))?;
+                tracing::info!("Watching {:?} for changes", watcher.root());
+                Some(watcher)
+            }
+            None => None,
+        };
 
         // Create stdio transport
         let transport_options = TransportOptions::default();
@@ -63,11 +233,83 @@ impl FoundryMcpServer {
 
         // Create and start the server
         tracing::info!("Foundry MCP server started, listening on stdio");
+        let in_flight = handler.in_flight();
         let server = create_server(server_details, transport, handler);
-        server.start().await.map_err(|e| {
-            FoundryMcpError::internal_error(format!("MCP server runtime error: {}", e))
-        })?;
+        let run = async {
+            let mut start = server.start();
+            match watcher {
+                Some(watcher) => tokio::select! {
+                    result = &mut start => result,
+                    _ = watch::forward_changes(watcher, &server) => start.await,
+                },
+                None => start.await,
+            }
+        };
+        tokio::pin!(run);
+
+        let signal = tokio::select! {
+            result = &mut run => {
+                return result.map_err(|e| {
+                    FoundryMcpError::internal_error(format!("MCP server runtime error: {}", e))
+                });
+            }
+            signal = shutdown_signal() => signal,
+        };
+
+        // Keep serving until in-flight calls finish so no op stops between writes
+        in_flight.begin_shutdown();
+        tracing::info!(
+            "Received {}, waiting for {} in-flight tool call(s)",
+            signal,
+            in_flight.count()
+        );
+        let drained = tokio::time::timeout(SHUTDOWN_GRACE_PERIOD, async {
+            tokio::select! {
+                _ = &mut run => {}
+                _ = in_flight.wait_idle() => {
+                    // Let the transport write the final responses
+                    let _ = tokio::time::timeout(RESPONSE_FLUSH_DELAY, &mut run).await;
+                }
+            }
+        })
+        .await;
+        if drained.is_err() {
+            tracing::warn!(
+                "Shutting down with {} tool call(s) still running after {:?}",
+                in_flight.count(),
+                SHUTDOWN_GRACE_PERIOD
+            );
+        }
+        tracing::info!("Foundry MCP server stopped");
 
         Ok(())
     }
 }
+
+/// Log a warning for every stale spec; a failed check never stops startup
+async fn warn_stale_specs(stale_days: u32) {
+    let input = crate::core::ops::needs_attention::Input {
+        project_name: None,
+        stale_days: Some(stale_days),
+    };
+    match crate::core::ops::needs_attention::run(input).await {
+        Ok(response) => {
+            for spec in &response.data.specs {
+                tracing::warn!(
+                    "Spec '{}' in project '{}' has {} open task(s) and no activity for {} days",
+                    spec.spec_name,
+                    spec.project_name,
+                    spec.open_tasks,
+                    spec.days_idle
+                );
+            }
+            tracing::info!(
+                "Stale spec check: {} spec(s) across {} project(s) idle for {}+ days",
+                response.data.total_count,
+                response.data.projects_checked,
+                stale_days
+            );
+        }
+        Err(e) => tracing::warn!("Stale spec check failed: {:#}", e),
+    }
+}
@@ -0,0 +1,156 @@
+//! # Graceful Shutdown
+//!
+//! Tracks tool calls in flight so `foundry serve` can stop on SIGTERM/SIGINT
+//! without cutting an op off between two writes: once a signal arrives new
+//! calls are refused, and the server keeps running until the calls already
+//! started have finished (or the grace period runs out).
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::Notify;
+
+/// How long to wait for in-flight calls after a shutdown signal
+pub const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+/// Counter of running tool calls plus the shutting-down flag
+#[derive(Debug, Default)]
+pub struct InFlightCalls {
+    count: AtomicUsize,
+    shutting_down: AtomicBool,
+    idle: Notify,
+    last_finished: Mutex<Option<Instant>>,
+}
+
+impl InFlightCalls {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Register a call; `None` once shutdown has begun and the call must be refused
+    pub fn enter(self: &Arc<Self>) -> Option<InFlightGuard> {
+        // Count first, then check: a call that passes the check is already
+        // counted, so shutdown cannot see zero calls while it starts
+        self.count.fetch_add(1, Ordering::SeqCst);
+        if self.shutting_down.load(Ordering::SeqCst) {
+            if self.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+                self.idle.notify_waiters();
+            }
+            return None;
+        }
+        Some(InFlightGuard {
+            calls: Arc::clone(self),
+        })
+    }
+
+    /// Number of calls currently running
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Refuse new calls from now on
+    pub fn begin_shutdown(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    /// Whether a call is running or one finished less than `window` ago
+    pub fn active_within(&self, window: Duration) -> bool {
+        if self.count() > 0 {
+            return true;
+        }
+        self.last_finished
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_some_and(|finished| finished.elapsed() < window)
+    }
+
+    /// Resolve once no calls are running
+    pub async fn wait_idle(&self) {
+        loop {
+            let notified = self.idle.notified();
+            if self.count() == 0 {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// Marks one call as running until dropped
+#[derive(Debug)]
+pub struct InFlightGuard {
+    calls: Arc<InFlightCalls>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        *self
+            .calls
+            .last_finished
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(Instant::now());
+        if self.calls.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.calls.idle.notify_waiters();
+        }
+    }
+}
+
+/// Resolve with the signal name on SIGINT (Ctrl-C) or, on Unix, SIGTERM
+pub async fn shutdown_signal() -> &'static str {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => tokio::select! {
+                _ = tokio::signal::ctrl_c() => "SIGINT",
+                _ = sigterm.recv() => "SIGTERM",
+            },
+            Err(e) => {
+                tracing::warn!("Failed to listen for SIGTERM: {}", e);
+                let _ = tokio::signal::ctrl_c().await;
+                "SIGINT"
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+        "SIGINT"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_wait_idle_resolves_when_last_call_finishes() {
+        let calls = InFlightCalls::new();
+        let first = calls.enter().unwrap();
+        let second = calls.enter().unwrap();
+        assert_eq!(calls.count(), 2);
+
+        calls.begin_shutdown();
+        assert!(calls.enter().is_none());
+        // A refused call is not left counted
+        assert_eq!(calls.count(), 2);
+
+        let waiter = {
+            let calls = Arc::clone(&calls);
+            tokio::spawn(async move { calls.wait_idle().await })
+        };
+        drop(first);
+        drop(second);
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(calls.count(), 0);
+    }
+}
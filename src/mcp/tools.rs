@@ -4,39 +4,274 @@
 //! Each tool provides identical functionality to its corresponding CLI command
 //! with the same parameter validation and JSON response format.
 //!
-//! All tool definitions are now auto-generated using the #[derive(McpTool)] macro
-//! or manual trait implementations for special cases (unit structs).
-
-use rust_mcp_sdk::schema::Tool as McpTool;
+//! The tool list and call routing are generated by `foundry_tools!` from a
+//! single registry, so every listed tool has a handler.
 
 // Import the CLI args that have McpTool implementations
 use crate::cli::args::{
-    AnalyzeProjectArgs, CreateProjectArgs, CreateSpecArgs, DeleteSpecArgs, GetFoundryHelpArgs,
-    ListProjectsArgs, ListSpecsArgs, LoadProjectArgs, LoadSpecArgs, UpdateSpecArgs,
-    ValidateContentArgs,
+    ActivityDigestArgs, AddDecisionArgs, AddTagArgs, AnalyzeProjectArgs, ArchiveSpecArgs,
+    CloneProjectArgs, CopySpecArgs, CreateHandoffArgs, CreateProjectArgs, CreateSpecArgs,
+    DeleteProjectArgs, DeleteSpecArgs, DiagramSpecsArgs, DiffSpecArgs, ExportProjectArgs,
+    ExportTasksArgs, GetAuditLogArgs, GetFoundryHelpArgs, GetServerStatsArgs, ImportArchiveArgs,
+    ImportProjectArgs, ImportSpecArgs, LintSpecArgs, ListDecisionsArgs, ListProjectsArgs,
+    ListSpecsArgs, LoadProjectArgs, LoadSpecArgs, NeedsAttentionArgs, ProjectAnalyticsArgs,
+    ProjectReportArgs, RemoveTagArgs, RenameSpecArgs, ReviewSpecArgs, SearchSpecsArgs,
+    SetSpecStatusArgs, SpecGraphArgs, SummarizeSpecArgs, UndoLastUpdateArgs, UpdateDecisionsArgs,
+    UpdateProjectArgs, UpdateSpecArgs, UpdateSpecsBatchArgs, ValidateContentArgs,
 };
-use crate::mcp::traits::McpToolDefinition;
 
-/// Tool definitions for all 10 foundry commands
+use std::collections::BTreeSet;
+
+use crate::mcp::error::FoundryMcpError;
+
+/// Tool definitions and routing for all foundry commands
 pub struct FoundryTools;
 
-impl FoundryTools {
-    /// Get all available tools
-    pub fn all_tools() -> Vec<McpTool> {
-        vec![
-            CreateProjectArgs::tool_definition(),   // Generated by macro
-            AnalyzeProjectArgs::tool_definition(),  // Generated by macro
-            LoadProjectArgs::tool_definition(),     // Generated by macro
-            CreateSpecArgs::tool_definition(),      // Generated by macro
-            LoadSpecArgs::tool_definition(),        // Generated by macro
-            UpdateSpecArgs::tool_definition(),      // Generated by macro
-            DeleteSpecArgs::tool_definition(),      // Generated by macro
-            ListProjectsArgs::tool_definition(),    // Manual impl for unit struct
-            ListSpecsArgs::tool_definition(),       // Generated by macro
-            ValidateContentArgs::tool_definition(), // Generated by macro
-            GetFoundryHelpArgs::tool_definition(),  // Generated by macro
-        ]
-    }
-
-    // All tool definitions are now auto-generated by McpTool macro or trait implementations
+// Registering a tool here lists it and routes calls to its `McpToolHandler`
+// impl in `handlers.rs`. Tools use `impl_mcp_tool!` or a manual
+// `McpToolDefinition` impl (custom schemas).
+crate::foundry_tools! {
+    CreateProjectArgs,
+    AnalyzeProjectArgs,
+    LoadProjectArgs,
+    CreateSpecArgs,
+    LoadSpecArgs,
+    UpdateSpecArgs,
+    UpdateSpecsBatchArgs,
+    UndoLastUpdateArgs,
+    SummarizeSpecArgs,
+    DiffSpecArgs,
+    DeleteSpecArgs,
+    ListProjectsArgs,
+    ListSpecsArgs,
+    ValidateContentArgs,
+    GetFoundryHelpArgs,
+    GetServerStatsArgs,
+    ExportTasksArgs,
+    ImportProjectArgs,
+    ImportSpecArgs,
+    DiagramSpecsArgs,
+    ProjectAnalyticsArgs,
+    ProjectReportArgs,
+    NeedsAttentionArgs,
+    ActivityDigestArgs,
+    CreateHandoffArgs,
+    ReviewSpecArgs,
+    LintSpecArgs,
+    ExportProjectArgs,
+    ImportArchiveArgs,
+    SearchSpecsArgs,
+    SetSpecStatusArgs,
+    ArchiveSpecArgs,
+    RenameSpecArgs,
+    CopySpecArgs,
+    AddTagArgs,
+    RemoveTagArgs,
+    SpecGraphArgs,
+    AddDecisionArgs,
+    ListDecisionsArgs,
+    UpdateDecisionsArgs,
+    UpdateProjectArgs,
+    CloneProjectArgs,
+    DeleteProjectArgs,
+    GetAuditLogArgs,
+}
+
+/// Tools that never change projects, specs or files; the ones `serve --read-only` exposes
+pub const READ_ONLY_TOOLS: &[&str] = &[
+    "load_project",
+    "load_spec",
+    "list_projects",
+    "list_specs",
+    "validate_content",
+    "get_foundry_help",
+    "get_server_stats",
+    "export_tasks",
+    "diagram_specs",
+    "project_analytics",
+    "project_report",
+    "needs_attention",
+    "activity_digest",
+    "create_handoff",
+    "review_spec",
+    "lint_spec",
+    "search_specs",
+    "spec_graph",
+    "list_decisions",
+    "diff_spec",
+    "get_audit_log",
+];
+
+/// Whether a call to `tool_name` with `params` can change data, and so goes in the audit log
+///
+/// Dry runs write nothing; `import_project` only writes with `dry_run: false`.
+pub fn changes_data(tool_name: &str, params: &serde_json::Value) -> bool {
+    FoundryTools::tool_names().contains(&tool_name)
+        && !READ_ONLY_TOOLS.contains(&tool_name)
+        && !params["dry_run"]
+            .as_bool()
+            .unwrap_or(tool_name == "import_project")
+}
+
+/// The registered tools a server exposes, from `--read-only` and `--allow-tools`
+#[derive(Debug, Clone, Default)]
+pub struct ToolFilter {
+    /// Names of the exposed tools; every tool when not set
+    allowed: Option<BTreeSet<String>>,
+}
+
+impl ToolFilter {
+    /// Expose only [`READ_ONLY_TOOLS`] when `read_only`, and only `allow_tools`
+    /// when it is not empty
+    pub fn new(read_only: bool, allow_tools: &[String]) -> anyhow::Result<Self> {
+        let registered = FoundryTools::tool_names();
+        if let Some(unknown) = allow_tools
+            .iter()
+            .find(|name| !registered.contains(&name.as_str()))
+        {
+            anyhow::bail!(
+                "Unknown tool '{}' in --allow-tools. Available tools: {}",
+                unknown,
+                registered.join(", ")
+            );
+        }
+        if read_only
+            && let Some(mutating) = allow_tools
+                .iter()
+                .find(|name| !READ_ONLY_TOOLS.contains(&name.as_str()))
+        {
+            anyhow::bail!(
+                "--allow-tools includes '{}', which changes data and is not available with --read-only",
+                mutating
+            );
+        }
+
+        let allowed = if !allow_tools.is_empty() {
+            Some(allow_tools.iter().cloned().collect())
+        } else if read_only {
+            Some(
+                READ_ONLY_TOOLS
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect(),
+            )
+        } else {
+            None
+        };
+        Ok(Self { allowed })
+    }
+
+    /// Whether calls to `tool_name` are served
+    pub fn allows(&self, tool_name: &str) -> bool {
+        self.allowed
+            .as_ref()
+            .is_none_or(|allowed| allowed.contains(tool_name))
+    }
+
+    /// Definitions of the exposed tools, for `tools/list`
+    pub fn tools(&self) -> Vec<rust_mcp_sdk::schema::Tool> {
+        FoundryTools::all_tools()
+            .into_iter()
+            .filter(|tool| self.allows(&tool.name))
+            .collect()
+    }
+}
+
+/// Parameter every tool accepts to run against another workspace
+pub const WORKSPACE_PARAM: &str = "workspace";
+
+/// `tool` with the optional `workspace` parameter added to its input schema
+pub fn with_workspace_param(mut tool: rust_mcp_sdk::schema::Tool) -> rust_mcp_sdk::schema::Tool {
+    let mut property = serde_json::Map::new();
+    property.insert("type".to_string(), serde_json::json!("string"));
+    property.insert(
+        "description".to_string(),
+        serde_json::json!(
+            "Workspace to run in, as registered with 'foundry workspace add' (default: the server's workspace)"
+        ),
+    );
+    tool.input_schema
+        .properties
+        .get_or_insert_with(Default::default)
+        .insert(WORKSPACE_PARAM.to_string(), property);
+    tool
+}
+
+/// The `workspace` named in `params`, if any
+pub fn requested_workspace(params: &serde_json::Value) -> Result<Option<&str>, FoundryMcpError> {
+    match params.get(WORKSPACE_PARAM) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(name)) => Ok(Some(name.as_str())),
+        Some(_) => Err(FoundryMcpError::invalid_params(format!(
+            "'{}' must be a string",
+            WORKSPACE_PARAM
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_names_match_tool_definitions() {
+        let names: Vec<String> = FoundryTools::all_tools()
+            .into_iter()
+            .map(|tool| tool.name)
+            .collect();
+        assert_eq!(names, FoundryTools::tool_names());
+        assert!(names.contains(&"diagram_specs".to_string()));
+    }
+
+    #[test]
+    fn test_read_only_tools_are_registered() {
+        let names = FoundryTools::tool_names();
+        for tool in READ_ONLY_TOOLS {
+            assert!(names.contains(tool), "{} is not registered", tool);
+        }
+    }
+
+    #[test]
+    fn test_tool_filter_limits_exposed_tools() {
+        assert!(ToolFilter::default().allows("update_spec"));
+        assert_eq!(
+            ToolFilter::default().tools().len(),
+            FoundryTools::tool_names().len()
+        );
+
+        let read_only = ToolFilter::new(true, &[]).unwrap();
+        assert!(read_only.allows("load_spec"));
+        assert!(!read_only.allows("update_spec"));
+        assert_eq!(read_only.tools().len(), READ_ONLY_TOOLS.len());
+
+        let subset =
+            ToolFilter::new(true, &["list_specs".to_string(), "load_spec".to_string()]).unwrap();
+        assert!(subset.allows("load_spec"));
+        assert!(!subset.allows("load_project"));
+
+        assert!(ToolFilter::new(false, &["no_such_tool".to_string()]).is_err());
+        assert!(ToolFilter::new(true, &["update_spec".to_string()]).is_err());
+        assert!(
+            ToolFilter::new(false, &["update_spec".to_string()])
+                .unwrap()
+                .allows("update_spec")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_rejects_unknown_tool_and_bad_params() {
+        let error = FoundryTools::dispatch("no_such_tool", &serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("Unknown tool: no_such_tool"));
+
+        let error = FoundryTools::dispatch("load_project", &serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Invalid parameters for load_project: Missing project_name parameter")
+        );
+    }
 }
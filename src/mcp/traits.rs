@@ -4,11 +4,20 @@
 //! from CLI argument structs using procedural macros.
 
 use anyhow::Result;
-use rust_mcp_sdk::schema::Tool as McpTool;
-use serde_json::Value;
+use async_trait::async_trait;
+use rust_mcp_sdk::schema::{Tool as McpTool, ToolOutputSchema};
+use schemars::JsonSchema;
+use schemars::generate::SchemaSettings;
+use serde_json::{Map, Value, json};
+
+use crate::mcp::error::FoundryMcpError;
+use crate::types::responses::FoundryResponse;
 
 /// Trait for CLI argument structs that can be automatically converted to MCP tools
 pub trait McpToolDefinition {
+    /// MCP tool name, also used to route tool calls
+    const NAME: &'static str;
+
     /// Generate the MCP tool definition from the struct
     fn tool_definition() -> McpTool;
 
@@ -17,3 +26,208 @@ pub trait McpToolDefinition {
     where
         Self: Sized;
 }
+
+/// Trait for tool arguments that can run their operation once parsed
+///
+/// Implemented next to the request handlers; `foundry_tools!` wires every
+/// registered tool's definition and handler together.
+#[async_trait]
+pub trait McpToolHandler: McpToolDefinition + Sized {
+    /// Execute the tool's operation and return its JSON response
+    async fn handle(self) -> Result<Value, FoundryMcpError>;
+}
+
+/// Build a tool's MCP output schema from its response payload type
+///
+/// Tools return `FoundryResponse<T>`, so the schema covers the envelope
+/// (`data`, `next_steps`, `validation_status`, `workflow_hints`) with `T` as
+/// `data`. Nested types are inlined because the output schema has no `$defs`.
+pub fn output_schema<T: JsonSchema>() -> ToolOutputSchema {
+    let schema = SchemaSettings::draft2020_12()
+        .with(|settings| settings.inline_subschemas = true)
+        .into_generator()
+        .into_root_schema_for::<FoundryResponse<T>>();
+
+    let properties = schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .iter()
+                .filter_map(|(name, property)| {
+                    property
+                        .as_object()
+                        .map(|property| (name.clone(), property.clone()))
+                })
+                .collect()
+        });
+    let required = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| {
+            required
+                .iter()
+                .filter_map(|name| name.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    ToolOutputSchema::new(required, properties)
+}
+
+/// Trait mapping a field type to its JSON schema and MCP parameter extraction
+///
+/// `impl_mcp_tool!` dispatches through this trait so each field's schema type,
+/// required-ness and parsing follow from its Rust type.
+pub trait McpParameter: Sized {
+    /// Whether the parameter must be provided (false for `Option<T>`)
+    const REQUIRED: bool = true;
+
+    /// JSON schema fragment for this type (e.g., `{"type": "string"}`)
+    fn schema() -> Map<String, Value>;
+
+    /// Extract the value for parameter `name`; `value` is `Null` when absent
+    fn from_param(name: &str, value: &Value) -> Result<Self>;
+}
+
+fn type_schema(json_type: &str) -> Map<String, Value> {
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!(json_type));
+    schema
+}
+
+fn invalid_type(name: &str, expected: &str, value: &Value) -> anyhow::Error {
+    if value.is_null() {
+        anyhow::anyhow!("Missing {} parameter", name)
+    } else {
+        anyhow::anyhow!("Parameter {} must be {}, got {}", name, expected, value)
+    }
+}
+
+impl McpParameter for String {
+    fn schema() -> Map<String, Value> {
+        type_schema("string")
+    }
+
+    fn from_param(name: &str, value: &Value) -> Result<Self> {
+        value
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| invalid_type(name, "a string", value))
+    }
+}
+
+impl McpParameter for bool {
+    fn schema() -> Map<String, Value> {
+        type_schema("boolean")
+    }
+
+    fn from_param(name: &str, value: &Value) -> Result<Self> {
+        value
+            .as_bool()
+            .ok_or_else(|| invalid_type(name, "a boolean", value))
+    }
+}
+
+macro_rules! impl_integer_parameter {
+    ($($int:ty),*) => {
+        $(
+            impl McpParameter for $int {
+                fn schema() -> Map<String, Value> {
+                    let mut schema = type_schema("integer");
+                    if <$int>::MIN == 0 {
+                        schema.insert("minimum".to_string(), json!(0));
+                    }
+                    schema
+                }
+
+                fn from_param(name: &str, value: &Value) -> Result<Self> {
+                    let parsed = value
+                        .as_i64()
+                        .and_then(|n| <$int>::try_from(n).ok())
+                        .or_else(|| value.as_u64().and_then(|n| <$int>::try_from(n).ok()));
+                    parsed.ok_or_else(|| {
+                        invalid_type(
+                            name,
+                            concat!("an integer in range for ", stringify!($int)),
+                            value,
+                        )
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_parameter!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl McpParameter for f64 {
+    fn schema() -> Map<String, Value> {
+        type_schema("number")
+    }
+
+    fn from_param(name: &str, value: &Value) -> Result<Self> {
+        value
+            .as_f64()
+            .ok_or_else(|| invalid_type(name, "a number", value))
+    }
+}
+
+impl McpParameter for f32 {
+    fn schema() -> Map<String, Value> {
+        type_schema("number")
+    }
+
+    fn from_param(name: &str, value: &Value) -> Result<Self> {
+        f64::from_param(name, value).map(|n| n as f32)
+    }
+}
+
+impl<T: McpParameter> McpParameter for Option<T> {
+    const REQUIRED: bool = false;
+
+    fn schema() -> Map<String, Value> {
+        T::schema()
+    }
+
+    fn from_param(name: &str, value: &Value) -> Result<Self> {
+        if value.is_null() {
+            Ok(None)
+        } else {
+            T::from_param(name, value).map(Some)
+        }
+    }
+}
+
+impl<T: McpParameter> McpParameter for Vec<T> {
+    fn schema() -> Map<String, Value> {
+        let mut schema = type_schema("array");
+        schema.insert("items".to_string(), Value::Object(T::schema()));
+        schema
+    }
+
+    fn from_param(name: &str, value: &Value) -> Result<Self> {
+        let items = value
+            .as_array()
+            .ok_or_else(|| invalid_type(name, "an array", value))?;
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| T::from_param(&format!("{}[{}]", name, index), item))
+            .collect()
+    }
+}
+
+/// Free-form JSON object; the handler is responsible for interpreting its keys
+impl McpParameter for Map<String, Value> {
+    fn schema() -> Map<String, Value> {
+        type_schema("object")
+    }
+
+    fn from_param(name: &str, value: &Value) -> Result<Self> {
+        value
+            .as_object()
+            .cloned()
+            .ok_or_else(|| invalid_type(name, "an object", value))
+    }
+}
@@ -0,0 +1,264 @@
+//! # Watch Mode
+//!
+//! With `foundry serve --watch` the server watches the project storage
+//! directory and tells the client when project or spec files change on disk
+//! outside its tool calls (a human editing `task-list.md`, a `git pull` into
+//! the git backend), so agents reload instead of working against stale content.
+//!
+//! Changes seen while a tool call (MCP or REST) is running, or within
+//! [`OWN_WRITE_GRACE`] after one finished, are taken to be the server's own
+//! writes and dropped. An outside edit landing in that window is dropped too.
+//!
+//! Foundry serves no MCP resources, so `resources/list` stays empty: the
+//! notification is only a signal to reload with `load_project`/`load_spec`.
+//!
+//! Changes are collected for a short debounce window and sent as one
+//! `notifications/resources/list_changed`, listing the changed projects and
+//! specs under `_meta`:
+//!
+//! ```json
+//! {
+//!   "method": "notifications/resources/list_changed",
+//!   "params": {
+//!     "_meta": {
+//!       "foundry/changes": [
+//!         { "project_name": "my-app", "spec_name": "20250601_120000_auth" },
+//!         { "project_name": "my-app" }
+//!       ]
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! A change without `spec_name` is to project-level files (vision, tech stack,
+//! summary). Hidden entries (`.git`, `.logs`, `.webhooks.json`, ...) are
+//! ignored, so the server's own logs never trigger a notification.
+
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use rust_mcp_sdk::{McpServer, schema::ResourceListChangedNotificationParams};
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+use crate::mcp::shutdown::InFlightCalls;
+
+/// Time to keep collecting changes after the first one before notifying
+pub const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// How long after a tool call finishes its file events are still ignored;
+/// the OS reports them a little after the write
+pub const OWN_WRITE_GRACE: Duration = Duration::from_millis(500);
+
+/// `_meta` key listing the changed projects and specs
+pub const CHANGES_META_KEY: &str = "foundry/changes";
+
+/// A project, or one spec in it, whose files changed on disk
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Change {
+    pub project_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec_name: Option<String>,
+}
+
+/// Recursive watch on the storage directory
+pub struct FoundryWatcher {
+    root: PathBuf,
+    paths: mpsc::UnboundedReceiver<PathBuf>,
+    // Events stop when the watcher is dropped
+    _watcher: RecommendedWatcher,
+}
+
+impl FoundryWatcher {
+    /// Start watching `root` and everything below it, ignoring changes made
+    /// while one of `tool_calls` runs
+    pub fn start(root: &Path, tool_calls: Arc<InFlightCalls>) -> Result<Self> {
+        // Some platforms report canonical paths, so strip against the canonical root
+        let root = root
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve watch directory {:?}", root))?;
+        let (sender, paths) = mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(
+            move |result: notify::Result<notify::Event>| match result {
+                Ok(event)
+                    if !event.kind.is_access() && !tool_calls.active_within(OWN_WRITE_GRACE) =>
+                {
+                    for path in event.paths {
+                        let _ = sender.send(path);
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("File watch error: {}", e),
+            },
+        )
+        .context("Failed to create file watcher")?;
+        watcher
+            .watch(&root, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {:?}", root))?;
+
+        Ok(Self {
+            root,
+            paths,
+            _watcher: watcher,
+        })
+    }
+
+    /// Directory being watched
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Wait for the next batch of changes, debounced; `None` once the watcher stops
+    pub async fn next_changes(&mut self) -> Option<Vec<Change>> {
+        loop {
+            let first = self.paths.recv().await?;
+            let mut changes: BTreeSet<Change> =
+                change_for(&self.root, &first).into_iter().collect();
+
+            let deadline = tokio::time::Instant::now() + DEBOUNCE;
+            while let Ok(Some(path)) = tokio::time::timeout_at(deadline, self.paths.recv()).await {
+                changes.extend(change_for(&self.root, &path));
+            }
+
+            if !changes.is_empty() {
+                return Some(changes.into_iter().collect());
+            }
+        }
+    }
+}
+
+/// Send a `resources/list_changed` notification for every batch of changes
+///
+/// Waits for the client to finish initializing first; returns when the
+/// watcher stops.
+pub async fn forward_changes(mut watcher: FoundryWatcher, runtime: &dyn McpServer) {
+    runtime.wait_for_initialization().await;
+    while let Some(changes) = watcher.next_changes().await {
+        tracing::info!("Files changed on disk: {}", describe(&changes));
+        if let Err(e) = runtime
+            .send_resource_list_changed(Some(notification_params(&changes)))
+            .await
+        {
+            tracing::warn!("Failed to send resource change notification: {}", e);
+        }
+    }
+}
+
+/// Notification parameters carrying `changes` under `_meta`
+pub fn notification_params(changes: &[Change]) -> ResourceListChangedNotificationParams {
+    let mut meta = serde_json::Map::new();
+    meta.insert(
+        CHANGES_META_KEY.to_string(),
+        serde_json::to_value(changes).unwrap_or_default(),
+    );
+    ResourceListChangedNotificationParams {
+        meta: Some(meta),
+        extra: None,
+    }
+}
+
+/// The project or spec a changed `path` under `root` belongs to
+///
+/// `None` for the root itself, paths outside it and hidden entries.
+pub fn change_for(root: &Path, path: &Path) -> Option<Change> {
+    let relative = path.strip_prefix(root).ok()?;
+    let parts: Vec<&str> = relative
+        .components()
+        .map(|component| match component {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    if parts.iter().any(|part| part.starts_with('.')) {
+        return None;
+    }
+
+    let project_name = parts.first()?.to_string();
+    let spec_name = match parts.as_slice() {
+        [_, "specs", spec, ..] => Some(spec.to_string()),
+        _ => None,
+    };
+    Some(Change {
+        project_name,
+        spec_name,
+    })
+}
+
+fn describe(changes: &[Change]) -> String {
+    changes
+        .iter()
+        .map(|change| {
+            change.spec_name.as_ref().map_or_else(
+                || change.project_name.clone(),
+                |spec_name| format!("{}/{}", change.project_name, spec_name),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_for_maps_paths_to_projects_and_specs() {
+        let root = Path::new("/home/user/.foundry");
+        let change = |path: &str| change_for(root, &root.join(path));
+
+        assert_eq!(
+            change("my-app/specs/20250601_120000_auth/task-list.md"),
+            Some(Change {
+                project_name: "my-app".to_string(),
+                spec_name: Some("20250601_120000_auth".to_string()),
+            })
+        );
+        assert_eq!(
+            change("my-app/vision.md"),
+            Some(Change {
+                project_name: "my-app".to_string(),
+                spec_name: None,
+            })
+        );
+        assert_eq!(change("my-app/specs").unwrap().spec_name, None);
+        assert_eq!(change(".logs/wire.jsonl"), None);
+        assert_eq!(change("my-app/.git/index"), None);
+        assert_eq!(change(""), None);
+        assert_eq!(change_for(root, Path::new("/tmp/other.md")), None);
+    }
+
+    #[tokio::test]
+    async fn test_watcher_reports_spec_file_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let spec_dir = dir.path().join("my-app/specs/20250601_120000_auth");
+        std::fs::create_dir_all(&spec_dir).unwrap();
+        std::fs::create_dir_all(dir.path().join(".logs")).unwrap();
+
+        let tool_calls = InFlightCalls::new();
+        let mut watcher = FoundryWatcher::start(dir.path(), Arc::clone(&tool_calls)).unwrap();
+        std::fs::write(dir.path().join(".logs/wire.jsonl"), "{}").unwrap();
+        // Written by a tool call, so not reported
+        {
+            let _call = tool_calls.enter().unwrap();
+            std::fs::write(dir.path().join("my-app/vision.md"), "# Vision").unwrap();
+        }
+        tokio::time::sleep(OWN_WRITE_GRACE * 2).await;
+        std::fs::write(spec_dir.join("task-list.md"), "- [ ] Task").unwrap();
+
+        let changes = tokio::time::timeout(Duration::from_secs(5), watcher.next_changes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            changes,
+            vec![Change {
+                project_name: "my-app".to_string(),
+                spec_name: Some("20250601_120000_auth".to_string()),
+            }]
+        );
+    }
+}
@@ -0,0 +1,371 @@
+//! # Webhooks
+//!
+//! Outgoing notifications for project and spec changes, so Slack channels and
+//! downstream automation can react without polling. Webhooks are configured in
+//! `~/.foundry/.webhooks.json`:
+//!
+//! ```json
+//! {
+//!   "webhooks": [
+//!     {
+//!       "url": "https://hooks.slack.com/services/...",
+//!       "secret": "shared-secret",
+//!       "events": ["spec.created", "spec.deleted"]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Every successful change is POSTed as JSON to each webhook subscribed to its
+//! event (all events when `events` is omitted):
+//!
+//! ```json
+//! {
+//!   "event": "spec.updated",
+//!   "timestamp": "2025-06-01T12:00:00+00:00",
+//!   "tool": "update_spec",
+//!   "project_name": "my-app",
+//!   "spec_name": "20250601_120000_auth",
+//!   "text": "Spec '20250601_120000_auth' updated in project 'my-app'",
+//!   "data": { "...": "the tool response data" }
+//! }
+//! ```
+//!
+//! `text` lets Slack incoming webhooks post the payload as-is. With a
+//! `secret`, the `X-Foundry-Signature` header carries `sha256=<hex>`, the
+//! HMAC-SHA256 of the body. Deliveries run in the background and are not
+//! retried; failures are logged.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use ring::hmac;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const WEBHOOKS_FILE_NAME: &str = ".webhooks.json";
+
+/// Time allowed for a webhook endpoint to respond
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Header carrying the body signature when the webhook has a secret
+pub const SIGNATURE_HEADER: &str = "X-Foundry-Signature";
+
+/// Header carrying the event name
+pub const EVENT_HEADER: &str = "X-Foundry-Event";
+
+/// Default config file, `~/.foundry/.webhooks.json`
+pub fn default_path() -> Result<PathBuf> {
+    Ok(crate::core::filesystem::foundry_dir()?.join(WEBHOOKS_FILE_NAME))
+}
+
+/// Webhooks loaded from config
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WebhookConfig {
+    #[serde(default)]
+    pub webhooks: Vec<Webhook>,
+}
+
+/// One endpoint and the events it receives
+#[derive(Debug, Clone, Deserialize)]
+pub struct Webhook {
+    pub url: String,
+    /// Signs each body with HMAC-SHA256 when set
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// Events to send, e.g. `spec.created`; every event when empty
+    #[serde(default)]
+    pub events: Vec<String>,
+}
+
+impl Webhook {
+    fn wants(&self, event: &str) -> bool {
+        self.events.is_empty() || self.events.iter().any(|e| e == event)
+    }
+}
+
+/// Change notification sent to webhooks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    pub event: String,
+    pub timestamp: String,
+    pub tool: String,
+    pub project_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec_name: Option<String>,
+    /// One-line description of the change
+    pub text: String,
+    /// The tool's response data
+    pub data: Value,
+}
+
+impl WebhookConfig {
+    /// Webhooks from `path`; none when the file does not exist
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read webhooks: {:?}", path))?;
+        let config: Self = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid webhooks: {:?}", path))?;
+        if let Some(webhook) = config.webhooks.iter().find(|webhook| {
+            !webhook.url.starts_with("http://") && !webhook.url.starts_with("https://")
+        }) {
+            anyhow::bail!(
+                "Invalid webhooks: {:?}: URL '{}' must start with http:// or https://",
+                path,
+                webhook.url
+            );
+        }
+        Ok(config)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.webhooks.is_empty()
+    }
+
+    /// Send the change made by a successful tool call to subscribed webhooks
+    ///
+    /// Returns immediately; deliveries run as background tasks.
+    pub fn notify(&self, tool: &str, params: &Value, response: &Value) {
+        let Some(payload) = payload_for(tool, params, response) else {
+            return;
+        };
+        for webhook in self.webhooks.iter().filter(|w| w.wants(&payload.event)) {
+            let webhook = webhook.clone();
+            let payload = payload.clone();
+            tokio::spawn(async move {
+                if let Err(e) = deliver(&webhook, &payload).await {
+                    tracing::warn!(
+                        "Webhook {} for {} failed: {:#}",
+                        webhook.url,
+                        payload.event,
+                        e
+                    );
+                }
+            });
+        }
+    }
+}
+
+/// Event name for a tool call that changes projects or specs
+pub fn event_for(tool: &str, params: &Value) -> Option<&'static str> {
+    match tool {
+        "create_project" | "analyze_project" | "clone_project" => Some("project.created"),
+        // import_project only reports a plan unless dry_run is explicitly false
+        "import_project" if params["dry_run"] == Value::Bool(false) => Some("project.imported"),
+        "import_archive" => Some("project.imported"),
+        "update_project" => Some("project.updated"),
+        "delete_project" => Some("project.deleted"),
+        "create_spec" | "copy_spec" => Some("spec.created"),
+        "update_spec" | "update_specs_batch" => Some("spec.updated"),
+        "delete_spec" => Some("spec.deleted"),
+        "rename_spec" => Some("spec.renamed"),
+        "set_spec_status" | "archive_spec" => Some("spec.status_changed"),
+        _ => None,
+    }
+}
+
+/// Payload describing the change made by `tool`, if it made one
+pub fn payload_for(tool: &str, params: &Value, response: &Value) -> Option<WebhookPayload> {
+    let event = event_for(tool, params)?;
+    let data = response.get("data").cloned().unwrap_or(Value::Null);
+    let project_name = data["project_name"]
+        .as_str()
+        .or_else(|| params["project_name"].as_str())?
+        .to_string();
+    let spec_name = data["spec_name"]
+        .as_str()
+        .or_else(|| params["spec_name"].as_str())
+        .map(str::to_string);
+
+    let action = event
+        .split_once('.')
+        .map_or("changed", |(_, action)| action);
+    let text = spec_name.as_ref().map_or_else(
+        || format!("Project '{}' {}", project_name, action),
+        |spec_name| {
+            format!(
+                "Spec '{}' {} in project '{}'",
+                spec_name, action, project_name
+            )
+        },
+    );
+
+    Some(WebhookPayload {
+        event: event.to_string(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        tool: tool.to_string(),
+        project_name,
+        spec_name,
+        text,
+        data,
+    })
+}
+
+/// `sha256=<hex>` HMAC-SHA256 signature of `body`
+pub fn signature(secret: &str, body: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, body);
+    let hex: String = tag
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    format!("sha256={}", hex)
+}
+
+/// POST `payload` to `webhook`, failing on a non-success status
+pub async fn deliver(webhook: &Webhook, payload: &WebhookPayload) -> Result<()> {
+    let body = serde_json::to_vec(payload)?;
+    let client = reqwest::Client::builder()
+        .timeout(DELIVERY_TIMEOUT)
+        .build()
+        .context("Failed to create HTTP client")?;
+
+    let mut request = client
+        .post(&webhook.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(EVENT_HEADER, &payload.event);
+    if let Some(secret) = &webhook.secret {
+        request = request.header(SIGNATURE_HEADER, signature(secret, &body));
+    }
+
+    let response = request.body(body).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        anyhow::bail!("endpoint responded with {}", status);
+    }
+    tracing::debug!("Webhook {} accepted {}", webhook.url, payload.event);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn test_payload_describes_spec_and_project_changes() {
+        let response = json!({
+            "data": {"project_name": "demo", "spec_name": "20250101_000000_auth", "files_created": ["spec.md"]},
+            "next_steps": [],
+        });
+        let payload =
+            payload_for("create_spec", &json!({"project_name": "demo"}), &response).unwrap();
+        assert_eq!(payload.event, "spec.created");
+        assert_eq!(payload.spec_name.as_deref(), Some("20250101_000000_auth"));
+        assert_eq!(
+            payload.text,
+            "Spec '20250101_000000_auth' created in project 'demo'"
+        );
+        assert_eq!(payload.data["files_created"][0], "spec.md");
+
+        let payload = payload_for(
+            "create_project",
+            &json!({"project_name": "demo"}),
+            &json!({"data": {"project_name": "demo"}}),
+        )
+        .unwrap();
+        assert_eq!(payload.text, "Project 'demo' created");
+
+        assert!(payload_for("load_spec", &json!({}), &response).is_none());
+        assert!(event_for("import_project", &json!({})).is_none());
+        assert_eq!(
+            event_for("import_project", &json!({"dry_run": false})),
+            Some("project.imported")
+        );
+
+        let payload = payload_for(
+            "delete_project",
+            &json!({"project_name": "demo", "confirm": "true"}),
+            &json!({"data": {"project_name": "demo", "specs_deleted": 2}}),
+        )
+        .unwrap();
+        assert_eq!(payload.event, "project.deleted");
+        assert_eq!(payload.text, "Project 'demo' deleted");
+        assert_eq!(
+            event_for("update_project", &json!({"project_name": "demo"})),
+            Some("project.updated")
+        );
+    }
+
+    #[test]
+    fn test_signature_is_hmac_sha256_hex() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            signature("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_load_filters_events_and_rejects_bad_urls() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join(".webhooks.json");
+        assert!(WebhookConfig::load(&path).unwrap().is_empty());
+
+        fs::write(
+            &path,
+            json!({"webhooks": [{"url": "https://example.com/hook", "events": ["spec.deleted"]}]})
+                .to_string(),
+        )
+        .unwrap();
+        let config = WebhookConfig::load(&path).unwrap();
+        assert!(config.webhooks[0].wants("spec.deleted"));
+        assert!(!config.webhooks[0].wants("spec.created"));
+
+        fs::write(
+            &path,
+            json!({"webhooks": [{"url": "ftp://example.com"}]}).to_string(),
+        )
+        .unwrap();
+        assert!(WebhookConfig::load(&path).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_deliver_posts_signed_payload() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 4096];
+            // Read until the JSON body has arrived
+            while !request.ends_with(b"}") {
+                let read = stream.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let webhook = Webhook {
+            url,
+            secret: Some("secret".to_string()),
+            events: Vec::new(),
+        };
+        let payload = payload_for(
+            "delete_spec",
+            &json!({"project_name": "demo", "spec_name": "20250101_000000_auth"}),
+            &json!({"data": {}}),
+        )
+        .unwrap();
+        deliver(&webhook, &payload).await.unwrap();
+
+        let request = server.await.unwrap().to_lowercase();
+        assert!(request.starts_with("post /hook"));
+        assert!(request.contains("x-foundry-event: spec.deleted"));
+        assert!(request.contains("x-foundry-signature: sha256="));
+        assert!(
+            request.contains("\"text\":\"spec '20250101_000000_auth' deleted in project 'demo'\"")
+        );
+    }
+}
@@ -0,0 +1,352 @@
+//! # Wire Log
+//!
+//! Opt-in record of every tool call and its response, written as JSON lines to
+//! `~/.foundry/.logs/wire.jsonl` and rotated by size. Values under secret-like
+//! keys are redacted and long strings are truncated, so the log can be attached
+//! to a bug report when an agent leaves a spec in an unexpected state.
+//!
+//! With encryption at rest configured, each line is encrypted on its own (like
+//! the audit log), so spec content in params and responses is never written in
+//! plain text.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+
+use crate::core::encryption;
+use crate::mcp::error::FoundryMcpError;
+use crate::types::errors::ErrorCode;
+use crate::utils::timestamp;
+
+/// Rotate once the active file reaches this size
+const DEFAULT_MAX_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Rotated files kept besides the active one (`wire.1.jsonl` is the newest)
+const DEFAULT_MAX_ROTATED_FILES: usize = 5;
+
+/// Longest string kept in full; longer strings are cut to this many characters
+const MAX_STRING_CHARS: usize = 2000;
+
+/// Key fragments whose values are never written to the log (besides `*token` keys)
+const SECRET_KEY_FRAGMENTS: [&str; 5] =
+    ["secret", "password", "api_key", "apikey", "authorization"];
+
+const ACTIVE_FILE_NAME: &str = "wire.jsonl";
+
+/// One recorded tool call as read back from the log
+#[derive(Debug, Clone, Deserialize)]
+pub struct WireLogEntry {
+    pub timestamp: String,
+    pub request_id: String,
+    pub tool: String,
+    pub duration_ms: u64,
+    #[serde(default)]
+    pub params: Value,
+    pub outcome: String,
+    #[serde(default)]
+    pub response: Option<Value>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl WireLogEntry {
+    pub fn is_error(&self) -> bool {
+        self.outcome == "error"
+    }
+}
+
+/// Default wire log directory, `~/.foundry/.logs`
+pub fn default_dir() -> Result<PathBuf> {
+    Ok(crate::core::filesystem::foundry_dir()?.join(".logs"))
+}
+
+/// Wire log files in `dir`, oldest first (`wire.N.jsonl` ... `wire.1.jsonl`, `wire.jsonl`)
+pub fn log_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut rotated = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {:?}", dir))? {
+        let name = entry?.file_name().to_string_lossy().to_string();
+        if let Some(index) = name
+            .strip_prefix("wire.")
+            .and_then(|rest| rest.strip_suffix(".jsonl"))
+            .and_then(|index| index.parse::<usize>().ok())
+        {
+            rotated.push(index);
+        }
+    }
+    rotated.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut files: Vec<PathBuf> = rotated
+        .into_iter()
+        .map(|index| dir.join(format!("wire.{}.jsonl", index)))
+        .collect();
+    let active = dir.join(ACTIVE_FILE_NAME);
+    if active.exists() {
+        files.push(active);
+    }
+    Ok(files)
+}
+
+/// Parse a wire log file; lines that are not valid entries are skipped
+pub fn read_entries(path: &Path) -> Result<Vec<WireLogEntry>> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read wire log: {:?}", path))?;
+    let mut key = None;
+    let mut entries = Vec::new();
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        let line = if encryption::is_encrypted(line) {
+            let key = match key {
+                Some(key) => key,
+                None => *key.insert(encryption::configured_key()?.ok_or_else(|| {
+                    ErrorCode::InvalidParams.error(format!(
+                        "{:?} is encrypted but no key is configured. Set [encryption] in config.toml",
+                        path
+                    ))
+                })?),
+            };
+            encryption::decrypt(&key, &sealed_path(path), line)?
+        } else {
+            line.to_string()
+        };
+        if let Ok(entry) = serde_json::from_str(&line) {
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
+
+/// Path wire log lines are encrypted for: the active file, so lines stay
+/// readable after rotation renames their file
+fn sealed_path(path: &Path) -> PathBuf {
+    path.with_file_name(ACTIVE_FILE_NAME)
+}
+
+/// Rotating JSON-lines log of tool calls and responses
+pub struct WireLog {
+    dir: PathBuf,
+    max_file_bytes: u64,
+    max_rotated_files: usize,
+    lock: Mutex<()>,
+}
+
+impl WireLog {
+    /// Wire log in the default location, `~/.foundry/.logs`
+    pub fn open_default() -> Result<Self> {
+        Self::open(
+            default_dir()?,
+            DEFAULT_MAX_FILE_BYTES,
+            DEFAULT_MAX_ROTATED_FILES,
+        )
+    }
+
+    /// Wire log in `dir`, rotating at `max_file_bytes` and keeping `max_rotated_files`
+    pub fn open(dir: PathBuf, max_file_bytes: u64, max_rotated_files: usize) -> Result<Self> {
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create wire log directory: {:?}", dir))?;
+        Ok(Self {
+            dir,
+            max_file_bytes,
+            max_rotated_files,
+            lock: Mutex::new(()),
+        })
+    }
+
+    /// Path of the file currently being written
+    pub fn active_path(&self) -> PathBuf {
+        self.dir.join(ACTIVE_FILE_NAME)
+    }
+
+    /// Append one tool call with its response (or error message)
+    pub fn record(
+        &self,
+        request_id: &str,
+        tool_name: &str,
+        params: &Value,
+        outcome: &std::result::Result<Value, FoundryMcpError>,
+        duration_ms: u64,
+    ) -> Result<()> {
+        let mut entry = json!({
+            "timestamp": timestamp::iso_timestamp(),
+            "request_id": request_id,
+            "tool": tool_name,
+            "duration_ms": duration_ms,
+            "params": sanitize(params),
+        });
+        match outcome {
+            Ok(response) => {
+                entry["outcome"] = json!("ok");
+                entry["response"] = sanitize(response);
+            }
+            Err(error) => {
+                entry["outcome"] = json!("error");
+                entry["error"] = json!(truncate(&error.to_string()));
+            }
+        }
+
+        let mut line = serde_json::to_string(&entry)?;
+        if let Some(key) = encryption::configured_key()? {
+            line = encryption::encrypt(&key, &self.active_path(), &line)?;
+        }
+        line.push('\n');
+
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        self.rotate_if_needed()?;
+        let path = self.active_path();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open wire log: {:?}", path))?;
+        file.write_all(line.as_bytes())
+            .with_context(|| format!("Failed to write wire log: {:?}", path))?;
+        Ok(())
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        self.dir.join(format!("wire.{}.jsonl", index))
+    }
+
+    fn rotate_if_needed(&self) -> Result<()> {
+        let active = self.active_path();
+        let size = fs::metadata(&active).map(|m| m.len()).unwrap_or(0);
+        if size < self.max_file_bytes {
+            return Ok(());
+        }
+
+        if self.max_rotated_files == 0 {
+            return remove_if_exists(&active);
+        }
+
+        remove_if_exists(&self.rotated_path(self.max_rotated_files))?;
+        for index in (1..self.max_rotated_files).rev() {
+            let from = self.rotated_path(index);
+            if from.exists() {
+                fs::rename(&from, self.rotated_path(index + 1))
+                    .with_context(|| format!("Failed to rotate wire log: {:?}", from))?;
+            }
+        }
+        fs::rename(&active, self.rotated_path(1))
+            .with_context(|| format!("Failed to rotate wire log: {:?}", active))?;
+        Ok(())
+    }
+}
+
+fn remove_if_exists(path: &Path) -> Result<()> {
+    if path.exists() {
+        fs::remove_file(path).with_context(|| format!("Failed to remove {:?}", path))?;
+    }
+    Ok(())
+}
+
+/// Copy of `value` with secret-like keys redacted and long strings truncated
+pub(crate) fn sanitize(value: &Value) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| {
+                    let value = if is_secret_key(key) {
+                        json!("[REDACTED]")
+                    } else {
+                        sanitize(value)
+                    };
+                    (key.clone(), value)
+                })
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(sanitize).collect()),
+        Value::String(s) => Value::String(truncate(s)),
+        other => other.clone(),
+    }
+}
+
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    // `access_token` is a secret, `max_tokens` is not
+    key.ends_with("token")
+        || SECRET_KEY_FRAGMENTS
+            .iter()
+            .any(|fragment| key.contains(fragment))
+}
+
+fn truncate(s: &str) -> String {
+    let total = s.chars().count();
+    if total <= MAX_STRING_CHARS {
+        return s.to_string();
+    }
+    let kept: String = s.chars().take(MAX_STRING_CHARS).collect();
+    format!("{}… [truncated {} chars]", kept, total - MAX_STRING_CHARS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_sanitize_redacts_secrets_and_truncates_strings() {
+        let params = json!({
+            "project_name": "demo",
+            "linear_api_key": "lin_123",
+            "access_token": "abc",
+            "max_tokens": 500,
+            "nested": {"Authorization": "Bearer abc", "spec": "x".repeat(2005)},
+        });
+        let sanitized = sanitize(&params);
+
+        assert_eq!(sanitized["project_name"], "demo");
+        assert_eq!(sanitized["linear_api_key"], "[REDACTED]");
+        assert_eq!(sanitized["access_token"], "[REDACTED]");
+        assert_eq!(sanitized["max_tokens"], 500);
+        assert_eq!(sanitized["nested"]["Authorization"], "[REDACTED]");
+        let spec = sanitized["nested"]["spec"].as_str().unwrap();
+        assert!(spec.ends_with("… [truncated 5 chars]"));
+    }
+
+    #[test]
+    fn test_record_writes_json_lines_and_rotates() {
+        let temp_dir = TempDir::new().unwrap();
+        let log = WireLog::open(temp_dir.path().to_path_buf(), 1, 2).unwrap();
+        let params = json!({"project_name": "demo"});
+
+        for index in 0..4 {
+            log.record(
+                &format!("req-{}", index),
+                "load_project",
+                &params,
+                &Err(FoundryMcpError::invalid_params(
+                    "Missing project_name parameter",
+                )),
+                3,
+            )
+            .unwrap();
+        }
+
+        // Every write rotates: active holds req-3, rotated files hold req-2 and req-1
+        let active = fs::read_to_string(log.active_path()).unwrap();
+        let entry: Value = serde_json::from_str(active.trim()).unwrap();
+        assert_eq!(entry["request_id"], "req-3");
+        assert_eq!(entry["outcome"], "error");
+        assert_eq!(entry["params"]["project_name"], "demo");
+        assert!(log.rotated_path(1).exists());
+        assert!(log.rotated_path(2).exists());
+        assert!(!log.rotated_path(3).exists());
+
+        // Reading back walks the files oldest first
+        let request_ids: Vec<String> = log_files(temp_dir.path())
+            .unwrap()
+            .iter()
+            .flat_map(|path| read_entries(path).unwrap())
+            .map(|entry| entry.request_id)
+            .collect();
+        assert_eq!(request_ids, vec!["req-1", "req-2", "req-3"]);
+    }
+}
@@ -71,6 +71,8 @@ mod test_impl {
                     feature_name,
                     spec_content.to_lowercase()
                 ),
+                template: None,
+                allow_duplicate: false,
             };
 
             create_spec::run(input).await.map(|_| ())
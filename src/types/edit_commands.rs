@@ -1,14 +1,18 @@
+use crate::types::errors::ErrorCode;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum EditCommandTarget {
     Spec,
     Tasks,
     Notes,
+    /// The project's decisions.md, edited with update_decisions
+    Decisions,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum EditCommandName {
     SetTaskStatus,
@@ -20,9 +24,22 @@ pub enum EditCommandName {
     ReplaceListItem,
     ReplaceInSection,
     ReplaceSectionContent,
+    AddDependency,
+    RemoveDependency,
+    AddPhase,
+    MoveTaskToPhase,
+    SetTasksStatusMatching,
+    CompletePhase,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl EditCommandName {
+    /// Whether the command can discard whole sections, so the spec is backed up first
+    pub fn discards_content(&self) -> bool {
+        matches!(self, Self::RemoveSection | Self::ReplaceSectionContent)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EditSelector {
     Section {
@@ -37,16 +54,26 @@ pub enum EditSelector {
         section: String,
         text: String,
     },
+    SpecName {
+        value: String,
+    },
+    /// Tasks whose text matches a glob (`*`, `?`) or a `/regex/`, optionally
+    /// only those under a section
+    TaskPattern {
+        value: String,
+        #[serde(default)]
+        section: Option<String>,
+    },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TaskStatus {
     Done,
     Todo,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EditCommand {
     pub target: EditCommandTarget,
     pub command: EditCommandName,
@@ -57,7 +84,7 @@ pub struct EditCommand {
     pub content: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FileUpdateSummary {
     pub target: EditCommandTarget,
     pub applied: usize,
@@ -66,15 +93,25 @@ pub struct FileUpdateSummary {
     pub hints: Option<Vec<String>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// What one edit command would change, from a dry run
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CommandDiff {
+    pub command_index: usize,
+    pub target: EditCommandTarget,
+    /// Unified diff of the command's change; empty when it changes nothing
+    pub diff: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SelectorCandidate {
     pub selector_suggestion: EditSelector,
     pub preview: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EditCommandError {
     pub target: EditCommandTarget,
+    pub code: ErrorCode,
     pub command_index: usize,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,226 @@
+//! Stable error codes for tool failures
+//!
+//! Codes are part of the tool contract: agents branch on them instead of
+//! parsing messages, so existing codes must never be renamed.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Machine-readable category of a failed operation or edit command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// The named project does not exist
+    ProjectNotFound,
+    /// The named spec does not exist in the project
+    SpecNotFound,
+    /// A project or spec with that name already exists
+    AlreadyExists,
+    /// An edit selector matched more than one location
+    SelectorAmbiguous,
+    /// An edit selector matched nothing
+    SelectorNotFound,
+    /// Input was well-formed but breaks a content or naming rule
+    ValidationFailed,
+    /// A tool parameter was missing or had the wrong type or format
+    InvalidParams,
+    /// No tool with the requested name is registered
+    UnknownTool,
+    /// The tool exists but this server was started without it (`--read-only`, `--allow-tools`)
+    ToolNotAllowed,
+    /// The backend state changed underneath the operation
+    BackendConflict,
+    /// Another process holds the lock on the resource; retry shortly
+    ResourceBusy,
+    /// The server is shutting down and no longer accepts calls
+    ShuttingDown,
+    /// The tool drafts text with the client's model, and the client does not support sampling
+    SamplingUnavailable,
+    /// The storage backend lacks a capability the operation needs and has no fallback for it
+    CapabilityUnsupported,
+    /// The storage backend could not be reached (network failure, service error, missing tool)
+    BackendUnavailable,
+    /// Anything without a more specific code
+    InternalError,
+}
+
+impl ErrorCode {
+    /// The serialized code, e.g. `SPEC_NOT_FOUND`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ProjectNotFound => "PROJECT_NOT_FOUND",
+            Self::SpecNotFound => "SPEC_NOT_FOUND",
+            Self::AlreadyExists => "ALREADY_EXISTS",
+            Self::SelectorAmbiguous => "SELECTOR_AMBIGUOUS",
+            Self::SelectorNotFound => "SELECTOR_NOT_FOUND",
+            Self::ValidationFailed => "VALIDATION_FAILED",
+            Self::InvalidParams => "INVALID_PARAMS",
+            Self::UnknownTool => "UNKNOWN_TOOL",
+            Self::ToolNotAllowed => "TOOL_NOT_ALLOWED",
+            Self::BackendConflict => "BACKEND_CONFLICT",
+            Self::ResourceBusy => "RESOURCE_BUSY",
+            Self::ShuttingDown => "SHUTTING_DOWN",
+            Self::SamplingUnavailable => "SAMPLING_UNAVAILABLE",
+            Self::CapabilityUnsupported => "CAPABILITY_UNSUPPORTED",
+            Self::BackendUnavailable => "BACKEND_UNAVAILABLE",
+            Self::InternalError => "INTERNAL_ERROR",
+        }
+    }
+
+    /// An `anyhow::Error` carrying this code, for use in `?`-propagated results
+    pub fn error(self, message: impl Into<String>) -> anyhow::Error {
+        anyhow::Error::new(CodedError {
+            code: self,
+            message: message.into(),
+            rule: None,
+            details: None,
+        })
+    }
+
+    /// Like [`ErrorCode::error`], also naming the broken `rule` (e.g.
+    /// `spec_name_format`) so agents can tell failures with one code apart
+    pub fn error_with_rule(self, rule: &str, message: impl Into<String>) -> anyhow::Error {
+        anyhow::Error::new(CodedError {
+            code: self,
+            message: message.into(),
+            rule: Some(rule.to_string()),
+            details: None,
+        })
+    }
+
+    /// Like [`ErrorCode::error`], with structured `details` for the caller to
+    /// act on (e.g. the specs a refused create_spec may duplicate)
+    pub fn error_with_details(
+        self,
+        message: impl Into<String>,
+        details: serde_json::Value,
+    ) -> anyhow::Error {
+        anyhow::Error::new(CodedError {
+            code: self,
+            message: message.into(),
+            rule: None,
+            details: Some(details),
+        })
+    }
+
+    /// The code attached anywhere in `error`'s context chain, or `InternalError`
+    pub fn of(error: &anyhow::Error) -> Self {
+        error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<CodedError>())
+            .map_or(Self::InternalError, |coded| coded.code)
+    }
+
+    /// The rule attached anywhere in `error`'s context chain
+    pub fn rule_of(error: &anyhow::Error) -> Option<&str> {
+        error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<CodedError>())
+            .and_then(|coded| coded.rule.as_deref())
+    }
+
+    /// The details attached anywhere in `error`'s context chain
+    pub fn details_of(error: &anyhow::Error) -> Option<&serde_json::Value> {
+        error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<CodedError>())
+            .and_then(|coded| coded.details.as_ref())
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error message tagged with an `ErrorCode`
+#[derive(Debug, thiserror::Error)]
+#[error("{message}")]
+pub struct CodedError {
+    pub code: ErrorCode,
+    pub message: String,
+    /// Rule a `ValidationFailed` error broke, when it names one
+    pub rule: Option<String>,
+    /// Structured data about the failure, when there is any
+    pub details: Option<serde_json::Value>,
+}
+
+/// Error payload returned to MCP clients when a tool call fails
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ErrorPayload {
+    pub code: ErrorCode,
+    pub message: String,
+    /// Broken validation rule, e.g. `project_name_format` or `dependency_cycle`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+    /// Structured data about the failure, e.g. `possible_duplicates` for a
+    /// refused create_spec
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_code_survives_added_context() {
+        let error = Err::<(), _>(ErrorCode::SpecNotFound.error("Spec 'x' not found"))
+            .context("Failed to load spec")
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::SpecNotFound);
+        assert_eq!(
+            ErrorCode::of(&anyhow::anyhow!("plain")),
+            ErrorCode::InternalError
+        );
+    }
+
+    #[test]
+    fn test_rule_survives_added_context() {
+        let error = Err::<(), _>(
+            ErrorCode::ValidationFailed.error_with_rule("dependency_cycle", "Cycle: a → b → a"),
+        )
+        .context("Failed to set dependencies")
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+        assert_eq!(ErrorCode::rule_of(&error), Some("dependency_cycle"));
+        assert_eq!(
+            ErrorCode::rule_of(&ErrorCode::SpecNotFound.error("missing")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_details_survive_added_context() {
+        let error = Err::<(), _>(ErrorCode::AlreadyExists.error_with_details(
+            "Looks like an existing spec",
+            serde_json::json!({"possible_duplicates": ["20250101_000000_auth"]}),
+        ))
+        .context("Failed to create spec")
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::AlreadyExists);
+        assert_eq!(
+            ErrorCode::details_of(&error).unwrap()["possible_duplicates"][0],
+            "20250101_000000_auth"
+        );
+        assert!(ErrorCode::details_of(&ErrorCode::SpecNotFound.error("missing")).is_none());
+    }
+
+    #[test]
+    fn test_codes_serialize_as_screaming_snake_case() {
+        for code in [
+            ErrorCode::ProjectNotFound,
+            ErrorCode::SelectorAmbiguous,
+            ErrorCode::BackendConflict,
+            ErrorCode::ResourceBusy,
+            ErrorCode::ShuttingDown,
+            ErrorCode::SamplingUnavailable,
+            ErrorCode::CapabilityUnsupported,
+            ErrorCode::BackendUnavailable,
+        ] {
+            assert_eq!(serde_json::to_value(code).unwrap(), code.as_str());
+        }
+    }
+}
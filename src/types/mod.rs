@@ -1,11 +1,13 @@
 //! Core type definitions for Foundry
 
 pub mod edit_commands;
+pub mod errors;
 pub mod project;
 pub mod responses;
 pub mod spec;
 
 pub use edit_commands::*;
+pub use errors::*;
 pub use project::*;
 pub use responses::*;
 pub use spec::*;
@@ -22,6 +22,9 @@ pub struct Project {
     pub vision: Option<String>,
     pub tech_stack: Option<String>,
     pub summary: Option<String>,
+    /// Decision log (decisions.md); `None` until the first decision is recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decisions: Option<String>,
 }
 
 /// Project creation parameters
@@ -33,6 +36,26 @@ pub struct ProjectConfig {
     pub summary: String,
 }
 
+/// Project documents that can be replaced after creation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectFileType {
+    Vision,
+    TechStack,
+    Summary,
+}
+
+impl ProjectFileType {
+    /// File the document is stored in
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::Vision => "vision.md",
+            Self::TechStack => "tech-stack.md",
+            Self::Summary => "summary.md",
+        }
+    }
+}
+
 /// Project metadata for listing operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectMetadata {
@@ -40,4 +63,7 @@ pub struct ProjectMetadata {
     pub created_at: String,
     pub spec_count: usize,
     pub last_modified: String,
+    /// Labels such as `quarter:Q3`, in the order they were added
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
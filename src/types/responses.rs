@@ -1,10 +1,12 @@
 //! JSON response structures for CLI commands
 
-use super::spec::SpecContentData;
+use super::spec::{SpecContentData, SpecStatus, SpecSummary, TaskCounts};
+use crate::core::timing::Phase;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Generic response wrapper for all CLI commands
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FoundryResponse<T> {
     /// Command-specific data payload
     pub data: T,
@@ -16,10 +18,41 @@ pub struct FoundryResponse<T> {
     /// Optional workflow guidance hints (only included when relevant)
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub workflow_hints: Vec<String>,
+    /// Server-side diagnostics such as slow-operation warnings (only included when relevant)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diagnostics: Option<ResponseDiagnostics>,
+}
+
+/// Diagnostics the server attaches to a response
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ResponseDiagnostics {
+    /// Human-readable warnings about how the call was served
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Timing breakdown, present when the call exceeded the slow-operation threshold
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timing: Option<OperationTiming>,
+}
+
+/// Where the time went for a slow operation
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OperationTiming {
+    pub total_ms: u64,
+    pub threshold_ms: u64,
+    /// Phase that took the largest share of `total_ms`
+    pub dominant_phase: Phase,
+    pub phases: Vec<PhaseTiming>,
+}
+
+/// Time spent in one phase of an operation
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PhaseTiming {
+    pub phase: Phase,
+    pub ms: u64,
 }
 
 /// Validation status for operations
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum ValidationStatus {
     /// Operation completed successfully with all validations passing
@@ -31,7 +64,7 @@ pub enum ValidationStatus {
 }
 
 /// Response for create_project command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CreateProjectResponse {
     pub project_name: String,
     pub created_at: String,
@@ -42,34 +75,71 @@ pub struct CreateProjectResponse {
 }
 
 /// Response for list_projects command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ListProjectsResponse {
     pub projects: Vec<ProjectInfo>,
+    /// Projects in all pages
+    pub total_count: usize,
+    /// Pass as `cursor` to list the next page; absent on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectInfo {
     pub name: String,
     pub created_at: String,
     pub spec_count: usize,
     pub path: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 /// Response for list_specs command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ListSpecsResponse {
     pub project_name: String,
     pub specs: Vec<SpecInfo>,
+    /// Matching specs in all pages
     pub total_count: usize,
+    /// Pass as `cursor` to list the next page; absent on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 /// Response for load_project command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LoadProjectResponse {
     pub project: ProjectContext,
+    /// How the context was trimmed (only when `max_tokens` was given)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<ContextBudget>,
+    /// Parts that were loaded; documents left out are empty
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Most recently modified specs that still have work left (with `active_specs`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub active_specs: Vec<SpecInfo>,
+    /// Every spec in the project (with `all_specs`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub specs: Vec<SpecInfo>,
+}
+
+/// How loaded context was trimmed to fit `max_tokens`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ContextBudget {
+    pub max_tokens: usize,
+    /// Estimated size of the kept sections (about four characters per token)
+    pub estimated_tokens: usize,
+    /// Titles of the kept sections, highest priority first
+    pub included_sections: Vec<String>,
+    /// Titles of the sections left out to stay within `max_tokens`
+    pub omitted_sections: Vec<String>,
+    /// Whether a section was cut short to fit
+    pub truncated: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectContext {
     pub name: String,
     pub vision: String,
@@ -79,10 +149,13 @@ pub struct ProjectContext {
     /// List of available specs (only included if specs exist)
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub specs_available: Vec<String>,
+    /// Decision log (decisions.md), only included once a decision is recorded
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub decisions: String,
 }
 
 /// Response for create_spec command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CreateSpecResponse {
     pub project_name: String,
     pub spec_name: String,
@@ -94,7 +167,7 @@ pub struct CreateSpecResponse {
 }
 
 /// Response for load_spec command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LoadSpecResponse {
     pub project_name: String,
     pub project_summary: String,
@@ -109,43 +182,212 @@ pub struct LoadSpecResponse {
     /// Indicates if fuzzy matching was used
     #[serde(skip_serializing_if = "Option::is_none")]
     pub match_info: Option<MatchInfo>,
+    /// `[[wiki-links]]` found in the spec files and their resolved targets
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<WikiLinkInfo>,
+    /// Specs whose files link to this spec, newest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backlinks: Vec<String>,
+    /// How the content was trimmed (only when `max_tokens` was given)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<ContextBudget>,
+    /// Specs the requested name could refer to, best match first (set when no
+    /// single spec matched confidently and nothing was loaded)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub candidates: Vec<SpecCandidate>,
+    /// Task-list items of the returned content with their inline metadata
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tasks: Vec<TaskInfo>,
+    /// Completion per task-list phase (`## Phase 1`, ...), in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phases: Vec<PhaseProgress>,
+    /// The only part returned, when one was requested with `part`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part: Option<String>,
+    /// The only files returned, when chosen with `files`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
+    /// Size of every part of the loaded spec, for fetching them one at a time
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<SpecPart>,
+}
+
+/// A separately fetchable part of a loaded spec
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpecPart {
+    /// Value to pass as `part`: summary, spec, tasks or notes
+    pub name: String,
+    /// Rough token count of the full part
+    pub estimated_tokens: usize,
+}
+
+/// Completion of one task-list phase
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PhaseProgress {
+    /// Phase heading, e.g. "Phase 2: Rollout"
+    pub name: String,
+    pub total_tasks: usize,
+    pub done_tasks: usize,
+}
+
+/// A task-list item with the planning metadata parsed from its line
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TaskInfo {
+    /// Nearest preceding header, empty if none
+    pub section: String,
+    /// Phase heading the task is under, if the task list has phases
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    /// Task text without metadata tokens
+    pub title: String,
+    pub done: bool,
+    /// From `@owner` tokens
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+    /// From a `~3d` token (number plus m, h, d or w)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<String>,
+    /// From a `due:YYYY-MM-DD` token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    /// From a `completed:YYYY-MM-DD` token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A spec that a fuzzy `spec_name` may refer to
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpecCandidate {
+    pub spec_name: String,
+    pub feature_name: String,
+    pub created_at: String,
+    #[serde(default)]
+    pub status: SpecStatus,
+    /// How the name matched ("exact", "feature_exact", "date_prefix",
+    /// "feature_substring", "feature_fuzzy" or "name_fuzzy"), or for a
+    /// possible duplicate in create_spec, "overview_similar"
+    pub match_type: String,
+    /// 0.0 to 1.0
+    pub confidence: f32,
+}
+
+/// A `[[wiki-link]]` found in spec content
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WikiLinkInfo {
+    pub target: String,
+    /// File the link appears in ("spec", "notes", or "tasks")
+    pub found_in: String,
+    /// Spec name or project document the link resolves to (None when broken)
+    pub resolved_to: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SpecInfo {
     pub name: String,
     pub feature_name: String,
     pub created_at: String,
+    #[serde(default)]
+    pub status: SpecStatus,
+    /// Days since the last activity, set when the spec has open tasks and has gone stale
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub days_idle: Option<u64>,
+    /// Specs this spec depends on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// Dependencies that are not completed or archived yet
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Checkbox counts of task-list.md
+    #[serde(default)]
+    pub task_counts: TaskCounts,
+    /// When one of the spec's files last changed (RFC 3339)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// Summary drafted by summarize_spec
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MatchInfo {
     pub requested_spec: String,
     pub matched_spec: String,
-    pub match_type: String, // "exact", "feature_fuzzy", "name_fuzzy"
+    pub match_type: String, // see SpecCandidate::match_type
     pub confidence: f32,    // 0.0 to 1.0
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SpecContent {
     pub content: SpecContentData,
+    /// Version of each file; pass one to update_spec as `expected_version`.
+    /// Empty for files left out with `files`
+    pub versions: SpecFileVersions,
+}
+
+/// Content versions of a spec's files (short SHA-256 of each file)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SpecFileVersions {
+    pub spec: String,
+    pub notes: String,
+    pub tasks: String,
 }
 
 /// Response for analyze_project command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AnalyzeProjectResponse {
     pub project_name: String,
     pub files_created: Vec<String>,
+    /// What a `repo_path` scan found, when one was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo_scan: Option<RepoScan>,
+}
+
+/// Languages, manifests, CI and container files found in a repository
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RepoScan {
+    pub root: String,
+    pub files_scanned: usize,
+    /// True when the walk stopped at the file limit
+    pub truncated: bool,
+    /// Languages by file count, most files first
+    pub languages: Vec<LanguageStat>,
+    pub manifests: Vec<ManifestInfo>,
+    /// CI systems with the file that configures them
+    pub ci: Vec<String>,
+    /// Dockerfiles and compose files
+    pub containers: Vec<String>,
+    /// Markdown outline to draft tech-stack.md from
+    pub tech_stack_skeleton: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LanguageStat {
+    pub language: String,
+    pub files: usize,
+}
+
+/// A manifest file and the dependency names it declares
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ManifestInfo {
+    /// Path relative to the repository root
+    pub path: String,
+    /// cargo, npm, python, pip, go, bundler, maven or gradle
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub dependencies: Vec<String>,
 }
 
 /// Response for get_foundry_help command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetFoundryHelpResponse {
     pub topic: String,
     pub content: HelpContent,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct HelpContent {
     pub title: String,
     pub description: String,
@@ -154,16 +396,28 @@ pub struct HelpContent {
 }
 
 /// Response for validate_content command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ValidateContentResponse {
     pub content_type: String,
     pub is_valid: bool,
     pub validation_errors: Vec<String>,
     pub suggestions: Vec<String>,
+    /// Validation profile applied: strict, standard or lenient
+    pub profile: String,
+    /// Rule behind each validation error
+    pub failed_rules: Vec<RuleFailure>,
+}
+
+/// A validation rule that content broke
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RuleFailure {
+    /// min_length, max_length, required_section or banned_phrase
+    pub rule: String,
+    pub message: String,
 }
 
 /// Response for update_spec command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UpdateSpecResponse {
     pub project_name: String,
     pub spec_name: String,
@@ -172,7 +426,7 @@ pub struct UpdateSpecResponse {
 }
 
 /// Individual file update result within a multi-file update operation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FileUpdateResult {
     /// Type of file updated ("spec", "tasks", or "notes")
     pub file_type: String,
@@ -198,19 +452,58 @@ pub struct FileUpdateResult {
     pub match_confidence: Option<f32>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, JsonSchema)]
 pub struct EditCommandsResponsePayload {
     pub applied_count: usize,
     pub skipped_idempotent_count: usize,
     pub file_updates: Vec<crate::types::edit_commands::FileUpdateSummary>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub errors: Option<Vec<crate::types::edit_commands::EditCommandError>>,
+    /// Unified diff of the whole update; only set for dry runs
     #[serde(skip_serializing_if = "Option::is_none")]
     pub preview_diff: Option<String>,
+    /// What each command would change, in command order; only set for dry runs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_diffs: Option<Vec<crate::types::edit_commands::CommandDiff>>,
+    /// Whether this was a dry run that wrote nothing
+    pub dry_run: bool,
+    /// Versions of the spec's files after the update (unchanged for dry runs)
+    pub versions: SpecFileVersions,
+}
+
+/// Response for update_specs_batch command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateSpecsBatchResponse {
+    pub project_name: String,
+    pub total_specs: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// One result per entry, in request order
+    pub results: Vec<BatchSpecResult>,
+}
+
+/// Outcome of one spec's edits in update_specs_batch
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BatchSpecResult {
+    pub spec_name: String,
+    /// Whether every command applied; a failed spec is left unchanged
+    pub success: bool,
+    pub applied_count: usize,
+    pub skipped_idempotent_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<crate::types::errors::ErrorCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Per-command failures, when commands were attempted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<crate::types::edit_commands::EditCommandError>>,
+    /// Versions of the spec's files after a successful update
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub versions: Option<SpecFileVersions>,
 }
 
 /// Response for delete_spec command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DeleteSpecResponse {
     pub project_name: String,
     pub spec_name: String,
@@ -220,8 +513,848 @@ pub struct DeleteSpecResponse {
     pub files_deleted: Vec<String>,
 }
 
+/// Response for update_project command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateProjectResponse {
+    pub project_name: String,
+    /// Project documents that were replaced, e.g. "vision.md"
+    pub files_updated: Vec<String>,
+}
+
+/// Response for delete_project command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DeleteProjectResponse {
+    pub project_name: String,
+    pub project_path: String,
+    /// Specs removed with the project
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub specs_deleted: Vec<String>,
+}
+
+/// Response for export_tasks command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportTasksResponse {
+    pub project_name: String,
+    pub format: String,
+    pub spec_count: usize,
+    pub task_count: usize,
+    pub done_count: usize,
+    /// Exported document (e.g., CSV text with a header row)
+    pub content: String,
+}
+
+/// Response for project_analytics command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectAnalyticsResponse {
+    pub project_name: String,
+    /// Date the report was computed for (YYYY-MM-DD)
+    pub as_of: String,
+    pub total_tasks: usize,
+    pub completed_tasks: usize,
+    /// Completed tasks without a `completed:YYYY-MM-DD` token, left out of trends
+    pub undated_completed_tasks: usize,
+    /// Completions per week, oldest first, ending with the current week
+    pub weekly_completions: Vec<WeeklyCompletions>,
+    /// Specs with every task done and at least one dated completion
+    pub completed_spec_count: usize,
+    /// Mean days from spec creation to its last completed task
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_cycle_time_days: Option<f64>,
+    /// Idle days after which a spec with open tasks counts as stalled
+    pub stall_days: u32,
+    /// Stalled specs, longest idle first
+    pub stalled_specs: Vec<StalledSpec>,
+    /// The report as markdown
+    pub summary_markdown: String,
+}
+
+/// Response for project_report command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectReportResponse {
+    pub project_name: String,
+    /// Date the report was computed for (YYYY-MM-DD)
+    pub as_of: String,
+    /// Tasks across every spec that is not archived
+    pub total_tasks: usize,
+    pub completed_tasks: usize,
+    /// Whole percent of tasks complete, rounded down
+    pub completion_percent: u32,
+    /// Progress of each spec, newest first
+    pub specs: Vec<SpecProgress>,
+    /// Days of completed work listed in `recently_completed`
+    pub recent_days: u32,
+    /// Tasks completed within `recent_days`, newest first
+    pub recently_completed: Vec<RecentlyCompletedTask>,
+    /// Idle days after which a spec with open tasks counts as stale
+    pub stale_days: u32,
+    /// Stale specs, longest idle first
+    pub stale_specs: Vec<StalledSpec>,
+    /// The report as markdown
+    pub summary_markdown: String,
+}
+
+/// Task completion of one spec
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpecProgress {
+    pub spec_name: String,
+    pub status: SpecStatus,
+    pub total_tasks: usize,
+    pub completed_tasks: usize,
+    /// Whole percent of tasks complete, rounded down; 0 for a spec without tasks
+    pub completion_percent: u32,
+}
+
+/// A task with a `completed:YYYY-MM-DD` date inside the report window
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RecentlyCompletedTask {
+    pub spec_name: String,
+    /// Task text without metadata tokens
+    pub task: String,
+    pub completed_at: String,
+}
+
+/// Tasks completed in one week
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WeeklyCompletions {
+    /// Monday of the week (YYYY-MM-DD)
+    pub week_start: String,
+    pub completed: usize,
+}
+
+/// Spec with open tasks and no recent activity
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StalledSpec {
+    pub spec_name: String,
+    pub open_tasks: usize,
+    /// Latest recorded file change or task completion, or the spec's creation date (YYYY-MM-DD)
+    pub last_activity: String,
+    pub days_idle: u64,
+}
+
+/// Response for needs_attention command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NeedsAttentionResponse {
+    /// Idle days after which a spec with open tasks counts as stale, for
+    /// projects without their own `[stale] days`
+    pub stale_days: u32,
+    pub projects_checked: usize,
+    /// Stale specs, longest idle first
+    pub specs: Vec<SpecNeedingAttention>,
+    pub total_count: usize,
+}
+
+/// Stale spec found by needs_attention
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpecNeedingAttention {
+    pub project_name: String,
+    pub spec_name: String,
+    pub open_tasks: usize,
+    /// Latest recorded file change or task completion, or the spec's creation date (YYYY-MM-DD)
+    pub last_activity: String,
+    pub days_idle: u64,
+    /// Threshold the spec was checked against
+    pub stale_days: u32,
+}
+
+/// Response for digest command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DigestResponse {
+    /// Start of the range (RFC 3339, inclusive)
+    pub since: String,
+    /// End of the range (RFC 3339, exclusive)
+    pub until: String,
+    pub projects_checked: usize,
+    pub specs_created: usize,
+    pub tasks_completed: usize,
+    /// Spec updates that added to notes.md
+    pub notes_added: usize,
+    /// Projects with activity in the range, by name
+    pub projects: Vec<ProjectDigest>,
+    /// The digest as markdown, e.g. for a weekly report
+    pub markdown: String,
+}
+
+/// Activity of one project in a digest
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectDigest {
+    pub project_name: String,
+    /// Specs created in the range, oldest first
+    pub specs_created: Vec<String>,
+    pub tasks_completed: Vec<DigestTask>,
+    pub notes_added: Vec<DigestNote>,
+}
+
+/// Task checked off in a digest's range
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DigestTask {
+    pub spec_name: String,
+    /// Task text without metadata tokens
+    pub task: String,
+    pub completed_at: String,
+}
+
+/// Spec update that added to notes.md in a digest's range
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DigestNote {
+    pub spec_name: String,
+    pub lines_added: usize,
+    pub updated_at: String,
+}
+
+/// Response for create_handoff command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CreateHandoffResponse {
+    pub project_name: String,
+    /// Resolved spec name
+    pub spec_name: String,
+    pub max_tokens: usize,
+    /// Estimated size of `handoff_markdown` (about four characters per token)
+    pub estimated_tokens: usize,
+    pub open_tasks: usize,
+    pub completed_tasks: usize,
+    /// Decisions from notes.md included in the packet
+    pub decisions: usize,
+    /// Titles of the blocks in the packet, in order
+    pub included_sections: Vec<String>,
+    /// Titles of the blocks left out to stay within `max_tokens`
+    pub omitted_sections: Vec<String>,
+    /// Whether a block was cut short to fit
+    pub truncated: bool,
+    /// The handoff packet as markdown
+    pub handoff_markdown: String,
+}
+
+/// Response for review_spec command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReviewSpecResponse {
+    pub project_name: String,
+    /// Resolved spec name
+    pub spec_name: String,
+    pub score: u32,
+    pub max_score: u32,
+    pub pass_threshold: u32,
+    /// Score reached the threshold and the spec has no structural errors
+    pub passed: bool,
+    pub checks: Vec<ReviewCheck>,
+    /// The checklist as markdown
+    pub checklist_markdown: String,
+}
+
+/// One scored item of a spec review
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReviewCheck {
+    /// Stable identifier, e.g. `criteria_coverage`
+    pub id: String,
+    pub title: String,
+    pub passed: bool,
+    pub score: u32,
+    pub max_score: u32,
+    /// What to fix; empty when the check passed
+    pub details: Vec<String>,
+}
+
+/// Response for lint_spec command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LintSpecResponse {
+    pub project_name: String,
+    /// Resolved spec name
+    pub spec_name: String,
+    pub errors: usize,
+    pub warnings: usize,
+    /// In file order: spec.md, notes.md, then task-list.md
+    pub findings: Vec<LintFinding>,
+}
+
+/// How much a lint finding matters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// Breaks a convention the tools rely on
+    Error,
+    /// Worth fixing, but nothing depends on it
+    Warning,
+}
+
+/// One convention a spec breaks
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LintFinding {
+    /// Stable identifier, e.g. `non_checkbox_task`
+    pub rule: String,
+    pub severity: LintSeverity,
+    /// spec.md, notes.md or task-list.md
+    pub file: String,
+    /// 1-based line the finding is about, when it is about one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub message: String,
+    /// update_spec commands that fix the finding; empty when it needs a
+    /// hand edit. `<...>` placeholders must be filled in before applying
+    pub fix: Vec<crate::types::edit_commands::EditCommand>,
+}
+
+/// Response for diff_spec command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiffSpecResponse {
+    pub project_name: String,
+    /// Resolved spec name
+    pub spec_name: String,
+    /// What the spec was compared with (the `a/` side of the diff), e.g.
+    /// "spec 20250101_120000_auth" or "backup demo/auth/20250101_120000_000000-delete_spec"
+    pub compared_with: String,
+    pub changed: bool,
+    /// e.g. "spec.md: Requirements changed; notes.md and task-list.md unchanged"
+    pub summary: String,
+    /// spec.md, notes.md and task-list.md, in that order
+    pub files: Vec<SpecFileDiff>,
+    /// Unified diff from `compared_with` to the spec, one file after another
+    pub unified_diff: String,
+}
+
+/// Changes to one spec file
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpecFileDiff {
+    /// spec.md, notes.md or task-list.md
+    pub file: String,
+    pub changed: bool,
+    pub lines_added: usize,
+    pub lines_removed: usize,
+    /// Sections added, removed or changed, matched by header title
+    pub sections: Vec<SectionDiff>,
+    /// Titles of the sections present on both sides without changes
+    pub unchanged_sections: Vec<String>,
+}
+
+/// A section that differs between the two sides of a diff
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SectionDiff {
+    /// Header text without the `#`s; "Overview" for text before the first header
+    pub title: String,
+    pub change: SectionChange,
+}
+
+/// How a section differs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SectionChange {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// Response for export_project command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportProjectResponse {
+    pub project_name: String,
+    /// Path of the written .tar.gz archive
+    pub archive_path: String,
+    pub spec_count: usize,
+    /// Files in the archive, including manifest.json
+    pub file_count: usize,
+    /// Archive size in bytes
+    pub bytes: usize,
+}
+
+/// Response for import_archive command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImportArchiveResponse {
+    /// Project created from the archive
+    pub project_name: String,
+    /// Project name recorded in the archive
+    pub source_project_name: String,
+    pub archive_path: String,
+    /// When the archive was exported (RFC 3339)
+    pub exported_at: String,
+    pub specs: Vec<ImportedSpec>,
+    /// Links between specs updated to the new spec names
+    pub links_rewritten: usize,
+}
+
+/// A spec restored from an archive
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImportedSpec {
+    /// Spec name in the archive
+    pub original_name: String,
+    /// Spec name after import
+    pub spec_name: String,
+}
+
+/// Interface a change was made through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditInterface {
+    Cli,
+    Mcp,
+    Rest,
+}
+
+/// Whether an audited call succeeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOutcome {
+    Ok,
+    Error,
+}
+
+/// One call that changed (or tried to change) a project, from its audit log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AuditEntry {
+    /// When the call finished (RFC 3339)
+    pub timestamp: String,
+    pub interface: AuditInterface,
+    /// Tool or command that made the change, e.g. "update_spec"
+    pub operation: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec_name: Option<String>,
+    /// Edit commands the call applied, as given
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<serde_json::Value>,
+    pub outcome: AuditOutcome,
+    /// Error code of a failed call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    /// Id of the MCP/REST call, as in server logs and the wire log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+/// Response for get_audit_log and `foundry audit show`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AuditLogResponse {
+    pub project_name: String,
+    /// Matching entries, newest first
+    pub entries: Vec<AuditEntry>,
+    /// Matching entries before `limit` was applied
+    pub total_matching: usize,
+}
+
+/// A spec backup taken before a destructive operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BackupInfo {
+    /// Pass to `foundry backup restore`
+    pub id: String,
+    pub project_name: String,
+    pub spec_name: String,
+    /// Operation the backup was taken before, e.g. "delete_spec"
+    pub reason: String,
+    /// When the backup was taken (RFC 3339)
+    pub taken_at: String,
+}
+
+/// Response for backup list command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BackupListResponse {
+    pub backup_dir: String,
+    /// Newest first
+    pub backups: Vec<BackupInfo>,
+}
+
+/// Response for backup restore command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BackupRestoreResponse {
+    pub backup: BackupInfo,
+    /// Spec holding the restored content; differs from the backup's spec when it was recreated
+    pub spec_name: String,
+    /// Whether the spec had been deleted and was created anew
+    pub recreated: bool,
+    /// Backup of the content the restore overwrote
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaced_backup: Option<BackupInfo>,
+}
+
+/// Response for backup prune command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BackupPruneResponse {
+    pub backup_dir: String,
+    /// Backups kept per spec
+    pub keep: usize,
+    pub removed: usize,
+}
+
+/// Outcome of one `foundry doctor` check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DoctorStatus {
+    Ok,
+    Warning,
+    Error,
+}
+
+/// One finding of `foundry doctor`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DoctorCheck {
+    /// data, config, installation or linear
+    pub category: String,
+    pub status: DoctorStatus,
+    /// What was checked: a path relative to the foundry directory, an
+    /// environment name or a config file
+    pub subject: String,
+    pub message: String,
+    /// What to do about it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+    /// Whether `--fix` repairs it
+    pub fixable: bool,
+    /// Whether `--fix` repaired it in this run
+    pub fixed: bool,
+}
+
+/// Response for doctor command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DoctorResponse {
+    pub foundry_dir: String,
+    pub checks: Vec<DoctorCheck>,
+    pub errors: usize,
+    pub warnings: usize,
+    pub fixed: usize,
+}
+
+/// Response for encryption encrypt and decrypt commands
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EncryptionResponse {
+    pub foundry_dir: String,
+    /// Files rewritten, relative to the foundry directory
+    pub files: Vec<String>,
+}
+
+/// Response for encryption keygen command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EncryptionKeygenResponse {
+    pub key_file: String,
+    /// Config to add to ~/.foundry/config.toml to use the key
+    pub config: String,
+}
+
+/// A registered workspace
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WorkspaceInfo {
+    pub name: String,
+    pub path: String,
+    /// Whether this is the workspace the command ran in
+    pub current: bool,
+}
+
+/// Response for workspace commands: the registry after the change
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WorkspaceListResponse {
+    pub registry_path: String,
+    pub workspaces: Vec<WorkspaceInfo>,
+}
+
+/// Response for search_specs command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchSpecsResponse {
+    pub query: String,
+    pub projects_searched: usize,
+    pub specs_searched: usize,
+    /// Matching lines found, before `limit` was applied
+    pub total_matches: usize,
+    /// Whether matches were left out to stay within `limit`
+    pub truncated: bool,
+    /// Matching lines, best first
+    pub matches: Vec<SpecSearchMatch>,
+}
+
+/// A line found by search_specs
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpecSearchMatch {
+    pub project_name: String,
+    pub spec_name: String,
+    /// spec.md, notes.md or task-list.md
+    pub file: String,
+    /// 1-based line number within `file`
+    pub line_number: usize,
+    /// The matching line, shortened around the first match when long
+    pub snippet: String,
+    /// Relevance; higher is better
+    pub score: usize,
+}
+
+/// Response for set_spec_status and archive_spec commands
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpecStatusResponse {
+    pub project_name: String,
+    pub spec_name: String,
+    pub previous_status: SpecStatus,
+    pub status: SpecStatus,
+}
+
+/// Response for the summarize_spec command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SummarizeSpecResponse {
+    pub project_name: String,
+    pub spec_name: String,
+    pub summary: SpecSummary,
+    /// Whether a new summary was drafted; false when the stored one still matches spec.md
+    pub regenerated: bool,
+}
+
+/// Response for add_tag and remove_tag commands
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TagsResponse {
+    pub project_name: String,
+    /// Tagged spec; absent when the project itself was tagged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec_name: Option<String>,
+    /// Tags after the change
+    pub tags: Vec<String>,
+    /// Tags that were added or removed; already present or missing ones are left out
+    pub changed: Vec<String>,
+}
+
+/// A decision from a project's decision log
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DecisionInfo {
+    /// `ADR-NNN`; hand-written entries may have none
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Spec the decision was made for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec_name: Option<String>,
+    pub context: String,
+    pub decision: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub consequences: String,
+}
+
+/// Response for add_decision command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AddDecisionResponse {
+    pub project_name: String,
+    pub decision: DecisionInfo,
+    /// Decisions in the log, including this one
+    pub total_decisions: usize,
+}
+
+/// Response for list_decisions command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ListDecisionsResponse {
+    pub project_name: String,
+    /// Matching decisions, oldest first
+    pub decisions: Vec<DecisionInfo>,
+    /// Decisions in the log before filtering
+    pub total_decisions: usize,
+}
+
+/// Response for update_decisions command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateDecisionsResponse {
+    pub project_name: String,
+    pub applied_count: usize,
+    pub skipped_idempotent_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<crate::types::edit_commands::EditCommandError>>,
+    /// Decisions in the log after the edit
+    pub total_decisions: usize,
+}
+
+/// Response for rename_spec command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RenameSpecResponse {
+    pub project_name: String,
+    /// New spec name, with the original id prefix
+    pub spec_name: String,
+    pub previous_spec_name: String,
+    pub feature_name: String,
+    /// Wiki links rewritten across the project's specs
+    pub links_updated: usize,
+    /// Specs whose depends_on named the old spec
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependents_updated: Vec<String>,
+}
+
+/// Response for copy_spec command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CopySpecResponse {
+    /// Project the spec was copied into
+    pub project_name: String,
+    /// Name of the copy, with a new id
+    pub spec_name: String,
+    pub source_project_name: String,
+    pub source_spec_name: String,
+    /// Whether the source spec was deleted (a move)
+    pub source_deleted: bool,
+    /// Dependencies of the source spec, which the copy does not keep
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies_dropped: Vec<String>,
+    /// Specs in the source project that still depend on the moved spec
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependents: Vec<String>,
+}
+
+/// Response for clone_project command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CloneProjectResponse {
+    /// Name of the new project
+    pub project_name: String,
+    pub source_project_name: String,
+    /// Copied specs, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub specs: Vec<CopiedSpec>,
+    /// Whether the decision log was copied
+    pub decisions_copied: bool,
+    /// Wiki links in the copies rewritten to the copied spec names
+    pub links_updated: usize,
+}
+
+/// A spec copied by clone_project
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CopiedSpec {
+    pub source_spec_name: String,
+    /// Name of the copy in the new project
+    pub spec_name: String,
+}
+
+/// Response for undo_last_update command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UndoLastUpdateResponse {
+    pub project_name: String,
+    pub spec_name: String,
+    /// Operation that was undone, e.g. "update_spec"
+    pub operation: String,
+    /// When the undone update was made (RFC 3339)
+    pub updated_at: String,
+    /// Files put back to their content before the update
+    pub files_restored: Vec<String>,
+    /// Earlier updates that can still be undone
+    pub remaining_undos: usize,
+    /// Versions of the spec files after the undo
+    pub versions: SpecFileVersions,
+}
+
+/// Response for spec_graph command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpecGraphResponse {
+    pub project_name: String,
+    pub spec_count: usize,
+    /// Dependencies between existing specs
+    pub edge_count: usize,
+    /// Specs in build order when there is no cycle, oldest first otherwise
+    pub nodes: Vec<SpecGraphNode>,
+    /// Spec names, each after the specs it depends on; empty when there is a cycle
+    pub build_order: Vec<String>,
+    /// Specs not completed or archived whose dependencies all are
+    pub ready_specs: Vec<String>,
+    pub has_cycles: bool,
+    /// Each cycle as spec names, the first repeated at the end
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cycles: Vec<Vec<String>>,
+}
+
+/// A spec in the dependency graph
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpecGraphNode {
+    pub spec_name: String,
+    pub feature_name: String,
+    pub status: SpecStatus,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// Specs that depend on this one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependents: Vec<String>,
+    /// Dependencies that are not completed or archived yet
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<String>,
+    /// Dependencies naming specs that no longer exist
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_dependencies: Vec<String>,
+}
+
+/// Response for diagram_specs command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiagramSpecsResponse {
+    pub project_name: String,
+    /// Spec the graph was focused on, when one was requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec_name: Option<String>,
+    pub spec_count: usize,
+    pub edge_count: usize,
+    /// Mermaid `graph TD` source for spec-to-spec links
+    pub dependency_diagram: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phase_diagrams: Vec<PhaseDiagram>,
+}
+
+/// Mermaid `flowchart LR` source for one spec's task phases
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PhaseDiagram {
+    pub spec_name: String,
+    pub diagram: String,
+}
+
+/// Response for import_project command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImportProjectResponse {
+    pub project_name: String,
+    pub source_path: String,
+    /// Mapping preset used as the base for this import ("docs" or "taskmaster")
+    pub preset: String,
+    /// When true nothing was written; the response is a report of what would happen
+    pub dry_run: bool,
+    /// "create" for a new project, "existing" when specs are added to a project
+    pub project_action: String,
+    /// Project files that will be (or were) populated from the source
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub project_files: Vec<ImportFileMapping>,
+    pub specs: Vec<ImportSpecPlan>,
+    /// Source items that were not imported, with reasons
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<ImportSkip>,
+    /// Problems that block a non-dry-run import
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<String>,
+}
+
+/// Response for import_spec command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImportSpecResponse {
+    pub project_name: String,
+    /// File or directory the content was read from
+    pub source_path: String,
+    /// When true nothing was written; the response shows how the content would be split
+    pub dry_run: bool,
+    pub feature_name: String,
+    /// Name of the created spec (only set once written)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec_name: Option<String>,
+    pub files_read: usize,
+    /// Where each section went: `source` is `file#heading` (or the file, for
+    /// text before its first heading) and `target` the spec file
+    pub sections: Vec<ImportFileMapping>,
+    pub task_count: usize,
+    /// Validation errors in the imported content
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validation_errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImportFileMapping {
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImportSpecPlan {
+    pub source: String,
+    pub feature_name: String,
+    pub task_count: usize,
+    /// Name of the created spec (only set once written)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImportSkip {
+    pub source: String,
+    pub reason: String,
+}
+
 /// Response for install command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct InstallResponse {
     pub target: String,
     pub binary_path: String,
@@ -233,7 +1366,7 @@ pub struct InstallResponse {
 }
 
 /// Response for uninstall command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UninstallResponse {
     pub target: String,
     pub config_path: String,
@@ -245,15 +1378,112 @@ pub struct UninstallResponse {
 }
 
 /// Response for status command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct StatusResponse {
     pub binary_path: String,
     pub binary_found: bool,
     pub environments: Vec<EnvironmentStatus>,
 }
 
+/// Response for stats command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StatsResponse {
+    /// Directory the wire log files were read from
+    pub log_dir: String,
+    /// Time window the stats cover (e.g., "7d"); all recorded calls when absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<String>,
+    pub total_calls: usize,
+    pub error_count: usize,
+    /// Per-tool usage, most called first
+    pub tools: Vec<ToolUsageStats>,
+}
+
+/// Usage of a single tool in the stats window
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ToolUsageStats {
+    pub tool: String,
+    pub calls: usize,
+    pub errors: usize,
+    /// Share of calls that failed, from 0.0 to 1.0
+    pub error_rate: f64,
+    pub avg_duration_ms: f64,
+    pub max_duration_ms: u64,
+}
+
+/// Response for get_server_stats command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ServerStatsResponse {
+    /// Seconds since the server started collecting metrics
+    pub uptime_seconds: u64,
+    pub total_calls: usize,
+    pub error_count: usize,
+    /// Per-tool usage since startup, most called first
+    pub tools: Vec<ToolUsageStats>,
+    pub edit_commands: EditCommandStats,
+    /// Confidence of fuzzy `spec_name` matches made by load_spec, lowest bucket first
+    pub match_confidence: Vec<ConfidenceBucket>,
+}
+
+/// Outcomes of edit commands sent with update_spec and update_specs_batch (not dry runs)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct EditCommandStats {
+    pub applied: u64,
+    /// Commands that found their change already in place
+    pub skipped_idempotent: u64,
+    pub failed: u64,
+    /// Share of commands that applied or were already applied, from 0.0 to 1.0;
+    /// absent before any command was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success_rate: Option<f64>,
+    /// Failed commands by error code, e.g. {"SELECTOR_NOT_FOUND": 3}
+    pub failures_by_code: std::collections::BTreeMap<String, u64>,
+}
+
+/// Fuzzy matches whose confidence fell in `from..to` (`to` included for the last bucket)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ConfidenceBucket {
+    pub from: f64,
+    pub to: f64,
+    pub count: u64,
+}
+
+/// Response for replay command
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayResponse {
+    /// Wire log file or directory the calls were read from
+    pub source: String,
+    pub total_calls: usize,
+    /// Calls whose replayed outcome (ok/error) matched the recording
+    pub matched: usize,
+    pub diverged: usize,
+    /// Whether replay stopped at the first divergence
+    pub stopped_early: bool,
+    pub steps: Vec<ReplayStep>,
+}
+
+/// One recorded tool call and how it replayed
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayStep {
+    /// 1-based position in the replayed sequence
+    pub index: usize,
+    pub request_id: String,
+    pub tool: String,
+    /// `ok` or `error` as recorded
+    pub recorded_outcome: String,
+    /// `ok` or `error` when replayed
+    pub replayed_outcome: String,
+    pub matches: bool,
+    /// Error returned by the replayed call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Why the replay may not be faithful (redacted or truncated params)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
 /// Installation/uninstallation status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum InstallationStatus {
     /// Installation/uninstallation completed successfully
@@ -265,7 +1495,7 @@ pub enum InstallationStatus {
 }
 
 /// Status information for a specific environment
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EnvironmentStatus {
     pub name: String,
     pub installed: bool,
@@ -1,18 +1,35 @@
 //! Spec-related type definitions
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use crate::core::backends::ResourceLocator;
 
 /// Spec content data structure
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct SpecContentData {
     pub spec: String,
     pub notes: String,
     pub tasks: String,
 }
 
+impl SpecContentData {
+    /// Empty every file not in `files`
+    pub fn retain(&mut self, files: &[SpecFileType]) {
+        for file_type in SpecFileType::ALL {
+            if !files.contains(file_type) {
+                match file_type {
+                    SpecFileType::Spec => self.spec.clear(),
+                    SpecFileType::Notes => self.notes.clear(),
+                    SpecFileType::TaskList => self.tasks.clear(),
+                }
+            }
+        }
+    }
+}
+
 /// Core specification structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Spec {
@@ -46,6 +63,109 @@ pub struct SpecMetadata {
     pub created_at: String,
     pub feature_name: String,
     pub project_name: String,
+    #[serde(default)]
+    pub status: SpecStatus,
+    /// Names of the specs this spec depends on
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// Labels such as `area:backend`, in the order they were added
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Checkbox counts of task-list.md
+    #[serde(default)]
+    pub task_counts: TaskCounts,
+    /// When one of the spec's files last changed (RFC 3339), if the backend knows
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// When foundry last wrote each file (file name → RFC 3339), where the
+    /// backend records it; unlike file times it survives copies and checkouts
+    #[serde(default)]
+    pub modified_at: BTreeMap<String, String>,
+    /// Short summary drafted by `summarize_spec`
+    #[serde(default)]
+    pub summary: Option<SpecSummary>,
+}
+
+/// A drafted summary of a spec and where it came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SpecSummary {
+    pub text: String,
+    /// When the summary was drafted (RFC 3339)
+    pub generated_at: String,
+    /// Model the client drafted it with, as reported by the client
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Version of spec.md the summary describes (see `core::versioning`)
+    pub spec_version: String,
+}
+
+/// Checkbox counts of a task list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct TaskCounts {
+    pub total: usize,
+    pub completed: usize,
+    pub remaining: usize,
+}
+
+/// Where a spec is in its lifecycle: draft → active → completed → archived
+///
+/// Specs without a stored status are drafts. Any status can be set from any
+/// other, so completed or archived specs can be reopened.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SpecStatus {
+    #[default]
+    Draft,
+    Active,
+    Completed,
+    Archived,
+}
+
+impl SpecStatus {
+    pub const ALL: [SpecStatus; 4] = [
+        SpecStatus::Draft,
+        SpecStatus::Active,
+        SpecStatus::Completed,
+        SpecStatus::Archived,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpecStatus::Draft => "draft",
+            SpecStatus::Active => "active",
+            SpecStatus::Completed => "completed",
+            SpecStatus::Archived => "archived",
+        }
+    }
+
+    /// Whether work on the spec is over, so it no longer counts as stale
+    pub fn is_closed(&self) -> bool {
+        matches!(self, SpecStatus::Completed | SpecStatus::Archived)
+    }
+}
+
+impl std::fmt::Display for SpecStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for SpecStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        let value = value.trim().to_lowercase();
+        SpecStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str() == value)
+            .ok_or_else(|| {
+                crate::types::errors::ErrorCode::InvalidParams.error(format!(
+                    "Unknown spec status '{}'. Use one of: {}",
+                    value,
+                    SpecStatus::ALL.map(|status| status.as_str()).join(", ")
+                ))
+            })
+    }
 }
 
 /// Spec filtering criteria for advanced queries
@@ -58,7 +178,7 @@ pub struct SpecFilter {
 }
 
 /// Spec file types for content updates
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SpecFileType {
     Spec,
@@ -66,6 +186,20 @@ pub enum SpecFileType {
     TaskList,
 }
 
+impl SpecFileType {
+    /// Every spec file
+    pub const ALL: &'static [Self] = &[Self::Spec, Self::Notes, Self::TaskList];
+
+    /// File the content is stored in
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Spec => "spec.md",
+            Self::Notes => "notes.md",
+            Self::TaskList => "task-list.md",
+        }
+    }
+}
+
 /// Content validation status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContentValidationStatus {
@@ -0,0 +1,49 @@
+//! Minimal CSV writing helpers (RFC 4180 quoting)
+
+/// Quote a field when it contains a delimiter, quote, or line break
+pub fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Join fields into a single CSV record (without trailing newline)
+pub fn format_row<S: AsRef<str>>(fields: &[S]) -> String {
+    fields
+        .iter()
+        .map(|f| escape_field(f.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Build a CSV document from a header and rows, one record per line
+pub fn format_document<S: AsRef<str>>(header: &[&str], rows: &[Vec<S>]) -> String {
+    std::iter::once(format_row(header))
+        .chain(rows.iter().map(|row| format_row(row)))
+        .map(|line| line + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_field() {
+        assert_eq!(escape_field("plain"), "plain");
+        assert_eq!(escape_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn test_format_document() {
+        let rows = vec![vec!["1", "x,y"], vec!["2", ""]];
+        assert_eq!(
+            format_document(&["id", "value"], &rows),
+            "id,value\n1,\"x,y\"\n2,\n"
+        );
+    }
+}
@@ -25,6 +25,11 @@ pub fn format_count(count: usize, singular: &str, plural: &str) -> String {
     }
 }
 
+/// Rough token count for budgeting text sent to a model (about four characters per token)
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
 /// Format a list of items with a separator and optional prefix
 pub fn format_list_with_details(items: &[String], separator: &str, prefix: Option<&str>) -> String {
     if items.is_empty() {
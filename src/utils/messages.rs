@@ -0,0 +1,261 @@
+//! Message catalog for user-facing guidance text
+//!
+//! Guidance is written in English throughout the code. A catalog for another
+//! locale maps those English messages to translations, so next steps,
+//! workflow hints, error messages and installed templates can be shown in the
+//! team's language without touching the code that produces them.
+//!
+//! Catalogs are JSON files named `<locale>.json` in `~/.foundry/.locales`:
+//!
+//! ```json
+//! {
+//!   "messages": {
+//!     "Project '{}' not found": "Proyecto '{}' no encontrado"
+//!   },
+//!   "templates": {
+//!     "claude/commands/foundry_create_spec.md": "..."
+//!   }
+//! }
+//! ```
+//!
+//! `{}` in a message matches any text, which is carried over into the
+//! translation's `{}` in the same order. A message with no matching entry is
+//! left in English.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// Locale whose messages are the ones in the code
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Environment variable selecting the locale when `--locale` is not given
+pub const LOCALE_ENV_VAR: &str = "FOUNDRY_LOCALE";
+
+const PLACEHOLDER: &str = "{}";
+
+static CATALOG: OnceLock<MessageCatalog> = OnceLock::new();
+
+/// Translations of English messages and templates for one locale
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MessageCatalog {
+    #[serde(skip)]
+    locale: String,
+    #[serde(default)]
+    messages: HashMap<String, String>,
+    #[serde(default)]
+    templates: HashMap<String, String>,
+}
+
+impl MessageCatalog {
+    /// The catalog for the default locale, which leaves every message unchanged
+    pub fn english() -> Self {
+        Self {
+            locale: DEFAULT_LOCALE.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Load `<locale>.json` from `dir`; the default locale needs no file
+    pub fn load(locale: &str, dir: &Path) -> Result<Self> {
+        if locale == DEFAULT_LOCALE {
+            return Ok(Self::english());
+        }
+        let path = dir.join(format!("{}.json", locale));
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read message catalog: {:?}", path))?;
+        let mut catalog: Self = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid message catalog: {:?}", path))?;
+        catalog.locale = locale.to_string();
+        Ok(catalog)
+    }
+
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Translate `text`, or return it unchanged when the catalog has no entry for it
+    pub fn translate(&self, text: &str) -> String {
+        if let Some(translated) = self.messages.get(text) {
+            return translated.clone();
+        }
+        // The longest matching pattern is the most specific one
+        self.messages
+            .iter()
+            .filter(|(pattern, _)| pattern.contains(PLACEHOLDER))
+            .filter_map(|(pattern, translation)| {
+                match_placeholders(pattern, text).map(|values| (pattern.len(), translation, values))
+            })
+            .max_by_key(|(len, _, _)| *len)
+            .map_or_else(
+                || text.to_string(),
+                |(_, translation, values)| fill_placeholders(translation, &values),
+            )
+    }
+
+    /// Translate the guidance fields of a serialized `FoundryResponse`
+    pub fn translate_response(&self, response: &mut Map<String, Value>) {
+        if self.messages.is_empty() {
+            return;
+        }
+        for field in ["next_steps", "workflow_hints"] {
+            if let Some(Value::Array(items)) = response.get_mut(field) {
+                self.translate_strings(items);
+            }
+        }
+        if let Some(Value::Array(warnings)) = response
+            .get_mut("diagnostics")
+            .and_then(|diagnostics| diagnostics.get_mut("warnings"))
+        {
+            self.translate_strings(warnings);
+        }
+    }
+
+    /// Localized content for the template installed as `key`, or `default`
+    pub fn template<'a>(&'a self, key: &str, default: &'a str) -> &'a str {
+        self.templates.get(key).map_or(default, String::as_str)
+    }
+
+    fn translate_strings(&self, items: &mut [Value]) {
+        for item in items {
+            if let Value::String(text) = item {
+                *text = self.translate(text);
+            }
+        }
+    }
+}
+
+/// Install the process-wide catalog; later calls keep the first catalog
+pub fn init(catalog: MessageCatalog) {
+    if CATALOG.set(catalog).is_err() {
+        tracing::debug!("Message catalog already initialized");
+    }
+}
+
+/// The process-wide catalog, English until [`init`] installs another
+pub fn catalog() -> &'static MessageCatalog {
+    CATALOG.get_or_init(MessageCatalog::english)
+}
+
+/// Locale from `--locale`, else `FOUNDRY_LOCALE`, else English
+pub fn resolve_locale(flag: Option<&str>) -> String {
+    flag.map(str::to_string)
+        .or_else(|| std::env::var(LOCALE_ENV_VAR).ok())
+        .filter(|locale| !locale.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_LOCALE.to_string())
+}
+
+/// Values matched by each `{}` when `text` fits `pattern`
+fn match_placeholders(pattern: &str, text: &str) -> Option<Vec<String>> {
+    let literals: Vec<&str> = pattern.split(PLACEHOLDER).collect();
+    let (first, rest) = literals.split_first()?;
+    let mut remaining = text.strip_prefix(first)?;
+    let mut values = Vec::new();
+
+    for (index, literal) in rest.iter().enumerate() {
+        let is_last = index == rest.len() - 1;
+        let end = if is_last {
+            // The final literal anchors the end of the text
+            remaining.strip_suffix(*literal)?.len()
+        } else if literal.is_empty() {
+            0
+        } else {
+            remaining.find(literal)?
+        };
+        values.push(remaining[..end].to_string());
+        remaining = &remaining[end + literal.len()..];
+    }
+
+    remaining.is_empty().then_some(values)
+}
+
+fn fill_placeholders(translation: &str, values: &[String]) -> String {
+    let mut parts = translation.split(PLACEHOLDER);
+    let mut filled = parts.next().unwrap_or_default().to_string();
+    for (index, part) in parts.enumerate() {
+        filled.push_str(values.get(index).map_or(PLACEHOLDER, String::as_str));
+        filled.push_str(part);
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn spanish() -> MessageCatalog {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("es.json"),
+            json!({
+                "messages": {
+                    "Project '{}' not found": "Proyecto '{}' no encontrado",
+                    "Load spec {} from project {}": "Cargue la especificación {} del proyecto {}",
+                    "Review the spec": "Revise la especificación",
+                },
+                "templates": {"cursor/rules": "Reglas"},
+            })
+            .to_string(),
+        )
+        .unwrap();
+        MessageCatalog::load("es", temp_dir.path()).unwrap()
+    }
+
+    #[test]
+    fn test_translate_exact_and_placeholder_messages() {
+        let catalog = spanish();
+        assert_eq!(catalog.locale(), "es");
+        assert_eq!(
+            catalog.translate("Review the spec"),
+            "Revise la especificación"
+        );
+        assert_eq!(
+            catalog.translate("Project 'demo' not found"),
+            "Proyecto 'demo' no encontrado"
+        );
+        assert_eq!(
+            catalog.translate("Load spec auth from project demo"),
+            "Cargue la especificación auth del proyecto demo"
+        );
+        assert_eq!(catalog.translate("Something else"), "Something else");
+        assert_eq!(catalog.template("cursor/rules", "Rules"), "Reglas");
+        assert_eq!(catalog.template("claude/subagent", "Agent"), "Agent");
+    }
+
+    #[test]
+    fn test_translate_response_guidance_fields() {
+        let Value::Object(mut response) = json!({
+            "data": {"note": "Review the spec"},
+            "next_steps": ["Review the spec"],
+            "workflow_hints": ["Project 'x' not found"],
+            "diagnostics": {"warnings": ["Review the spec"]},
+        }) else {
+            unreachable!()
+        };
+
+        spanish().translate_response(&mut response);
+
+        assert_eq!(response["next_steps"][0], "Revise la especificación");
+        assert_eq!(response["workflow_hints"][0], "Proyecto 'x' no encontrado");
+        assert_eq!(
+            response["diagnostics"]["warnings"][0],
+            "Revise la especificación"
+        );
+        // Data is never translated
+        assert_eq!(response["data"]["note"], "Review the spec");
+    }
+
+    #[test]
+    fn test_english_needs_no_catalog_file() {
+        let catalog = MessageCatalog::load(DEFAULT_LOCALE, Path::new("/nonexistent")).unwrap();
+        assert_eq!(catalog.translate("Review the spec"), "Review the spec");
+        assert!(MessageCatalog::load("fr", Path::new("/nonexistent")).is_err());
+    }
+}
@@ -1,6 +1,9 @@
 //! Utility functions
 
+pub mod csv;
 pub mod formatting;
+pub mod messages;
+pub mod pagination;
 pub mod paths;
 pub mod response;
 pub mod status_formatter;
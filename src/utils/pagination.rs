@@ -0,0 +1,87 @@
+//! Cursor pagination for list responses
+//!
+//! Lists are returned whole unless a `limit` is given. A page that stops
+//! short of the end carries a `next_cursor`; passing it back as `cursor`
+//! returns the following page. Cursors are opaque to callers and only valid
+//! for the same list and filters.
+
+use anyhow::Result;
+
+use crate::types::errors::ErrorCode;
+
+/// Largest `limit` accepted for one page
+pub const MAX_PAGE_SIZE: usize = 200;
+
+/// One page of a list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Cursor for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// The page of `items` starting at `cursor` and holding at most `limit` items
+pub fn paginate<T>(items: Vec<T>, cursor: Option<&str>, limit: Option<usize>) -> Result<Page<T>> {
+    if let Some(limit) = limit
+        && !(1..=MAX_PAGE_SIZE).contains(&limit)
+    {
+        return Err(ErrorCode::InvalidParams
+            .error(format!("'limit' must be between 1 and {}", MAX_PAGE_SIZE)));
+    }
+    let start = match cursor.map(str::trim).filter(|cursor| !cursor.is_empty()) {
+        Some(cursor) => decode(cursor)?,
+        None => 0,
+    };
+    if start > items.len() {
+        return Err(ErrorCode::InvalidParams
+            .error("'cursor' is past the end of the list; list again without a cursor"));
+    }
+
+    let end = limit.map_or(items.len(), |limit| (start + limit).min(items.len()));
+    let next_cursor = (end < items.len()).then(|| encode(end));
+    Ok(Page {
+        items: items.into_iter().skip(start).take(end - start).collect(),
+        next_cursor,
+    })
+}
+
+fn encode(offset: usize) -> String {
+    format!("o{}", offset)
+}
+
+fn decode(cursor: &str) -> Result<usize> {
+    cursor
+        .strip_prefix('o')
+        .and_then(|offset| offset.parse().ok())
+        .ok_or_else(|| {
+            ErrorCode::InvalidParams.error(format!(
+                "Invalid cursor '{}'; pass the next_cursor of a previous page",
+                cursor
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pages_follow_cursors_to_the_end() {
+        let items: Vec<u32> = (0..5).collect();
+        let first = paginate(items.clone(), None, Some(2)).unwrap();
+        assert_eq!(first.items, vec![0, 1]);
+        let second = paginate(items.clone(), first.next_cursor.as_deref(), Some(2)).unwrap();
+        assert_eq!(second.items, vec![2, 3]);
+        let last = paginate(items.clone(), second.next_cursor.as_deref(), Some(2)).unwrap();
+        assert_eq!(last.items, vec![4]);
+        assert_eq!(last.next_cursor, None);
+
+        let all = paginate(items.clone(), None, None).unwrap();
+        assert_eq!(all.items.len(), 5);
+        assert_eq!(all.next_cursor, None);
+
+        assert!(paginate(items.clone(), None, Some(0)).is_err());
+        assert!(paginate(items.clone(), Some("bogus"), Some(2)).is_err());
+        assert!(paginate(items, Some("o9"), None).is_err());
+    }
+}
@@ -1,7 +1,16 @@
 //! Path manipulation utilities
 
 use anyhow::Result;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// `path` with a leading `~/` replaced by the home directory
+pub fn expand_home(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
 
 /// Normalize project name to kebab-case
 pub fn normalize_project_name(name: &str) -> String {
@@ -21,6 +30,25 @@ pub fn normalize_project_name(name: &str) -> String {
         .join("-")
 }
 
+/// Normalize free-form text (titles, file stems) to a snake_case feature name
+pub fn normalize_feature_name(name: &str) -> String {
+    let normalized = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>()
+        .split('_')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<&str>>()
+        .join("_");
+
+    if normalized.len() > 50 {
+        normalized[..50].trim_end_matches('_').to_string()
+    } else {
+        normalized
+    }
+}
+
 /// Validate project name format
 pub fn validate_project_name(name: &str) -> Result<()> {
     if name.is_empty() {
@@ -119,11 +147,16 @@ pub fn validate_feature_name(name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Whether `part` is safe as one component of a path below a foundry
+/// directory: not empty, not hidden (which also rules out `..`) and free of
+/// path separators
+pub fn is_safe_component(part: &str) -> bool {
+    !part.is_empty() && !part.starts_with('.') && !part.contains(['/', '\\'])
+}
+
 /// Get relative path from foundry directory
 pub fn relative_to_foundry(path: &Path) -> Result<String> {
-    let foundry_dir = dirs::home_dir()
-        .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?
-        .join(".foundry");
+    let foundry_dir = crate::core::workspace::current_dir()?;
 
     let relative_path = path
         .strip_prefix(&foundry_dir)
@@ -134,9 +167,7 @@ pub fn relative_to_foundry(path: &Path) -> Result<String> {
 
 /// Ensure path is safe (doesn't escape the foundry directory)
 pub fn ensure_safe_path(path: &Path) -> Result<()> {
-    let foundry_dir = dirs::home_dir()
-        .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?
-        .join(".foundry");
+    let foundry_dir = crate::core::workspace::current_dir()?;
 
     if !path.starts_with(&foundry_dir) {
         return Err(anyhow::anyhow!("Path is outside of foundry directory"));
@@ -1,7 +1,53 @@
 //! Response building utilities to eliminate code duplication
 
+use anyhow::Result;
+use serde_json::{Map, Value};
+
 use crate::types::responses::{FoundryResponse, ValidationStatus};
 
+/// How much guidance text (`next_steps`, `workflow_hints`) responses carry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GuidanceLevel {
+    /// Every next step and workflow hint
+    #[default]
+    Full,
+    /// Only the first next step, no workflow hints
+    Minimal,
+    /// No next steps or workflow hints
+    None,
+}
+
+impl GuidanceLevel {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "full" => Ok(Self::Full),
+            "minimal" => Ok(Self::Minimal),
+            "none" => Ok(Self::None),
+            other => Err(anyhow::anyhow!(
+                "Unsupported guidance level: {}. Supported levels: full, minimal, none",
+                other
+            )),
+        }
+    }
+
+    /// Trim the guidance fields of a serialized `FoundryResponse` to this level
+    pub fn apply(self, response: &mut Map<String, Value>) {
+        match self {
+            Self::Full => {}
+            Self::Minimal => {
+                response.remove("workflow_hints");
+                if let Some(Value::Array(next_steps)) = response.get_mut("next_steps") {
+                    next_steps.truncate(1);
+                }
+            }
+            Self::None => {
+                response.remove("workflow_hints");
+                response.remove("next_steps");
+            }
+        }
+    }
+}
+
 /// Build a standard success response with common patterns
 pub fn build_success_response<T>(
     data: T,
@@ -13,6 +59,7 @@ pub fn build_success_response<T>(
         next_steps,
         validation_status: ValidationStatus::Complete,
         workflow_hints,
+        diagnostics: None,
     }
 }
 
@@ -27,6 +74,7 @@ pub fn build_incomplete_response<T>(
         next_steps,
         validation_status: ValidationStatus::Incomplete,
         workflow_hints,
+        diagnostics: None,
     }
 }
 
@@ -59,6 +107,34 @@ mod tests {
     use super::*;
     use crate::types::responses::ValidationStatus;
 
+    #[test]
+    fn test_guidance_level_trims_hint_fields() {
+        let response = serde_json::json!({
+            "data": {},
+            "validation_status": "complete",
+            "next_steps": ["first", "second"],
+            "workflow_hints": ["hint"],
+        });
+        let Value::Object(response) = response else {
+            unreachable!()
+        };
+
+        let mut full = response.clone();
+        GuidanceLevel::Full.apply(&mut full);
+        assert_eq!(full, response);
+
+        let mut minimal = response.clone();
+        GuidanceLevel::Minimal.apply(&mut minimal);
+        assert_eq!(minimal["next_steps"], serde_json::json!(["first"]));
+        assert!(!minimal.contains_key("workflow_hints"));
+
+        let mut none = response;
+        GuidanceLevel::parse("none").unwrap().apply(&mut none);
+        assert!(!none.contains_key("next_steps"));
+        assert!(!none.contains_key("workflow_hints"));
+        assert!(GuidanceLevel::parse("verbose").is_err());
+    }
+
     #[test]
     fn test_build_success_response() {
         let data = "test data";
@@ -1,7 +1,7 @@
 //! Timestamp generation and parsing utilities
 
 use anyhow::Result;
-use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc};
 
 /// Generate ISO timestamp string for general use
 pub fn iso_timestamp() -> String {
@@ -129,6 +129,25 @@ pub fn extract_feature_name(spec_name: &str) -> Option<String> {
     None
 }
 
+/// Parse a window like `30m`, `24h` or `7d`
+pub fn parse_window(window: &str) -> Result<Duration> {
+    let invalid = || {
+        anyhow::anyhow!(
+            "Invalid --since window '{}'. Use a number followed by m, h or d (e.g., 24h, 7d)",
+            window
+        )
+    };
+    let split = window.len().checked_sub(1).ok_or_else(invalid)?;
+    let (amount, unit) = window.split_at(split);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    match unit {
+        "m" => Ok(Duration::minutes(amount)),
+        "h" => Ok(Duration::hours(amount)),
+        "d" => Ok(Duration::days(amount)),
+        _ => Err(invalid()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
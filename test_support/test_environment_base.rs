@@ -47,6 +47,10 @@ pub struct TestEnvironment {
     home: PathBuf,
     cursor_config: PathBuf,
     claude_config: PathBuf,
+    vscode_config: PathBuf,
+    windsurf_config: PathBuf,
+    windsurf_workspace: PathBuf,
+    zed_config: PathBuf,
     bin: PathBuf,
 }
 
@@ -59,6 +63,10 @@ impl TestEnvironment {
         let home = temp_dir.path().join("home");
         let cursor_config = temp_dir.path().join(".cursor");
         let claude_config = temp_dir.path().join(".claude");
+        let vscode_config = temp_dir.path().join("vscode").join("User");
+        let windsurf_config = temp_dir.path().join("windsurf");
+        let windsurf_workspace = temp_dir.path().join(".windsurf");
+        let zed_config = temp_dir.path().join("zed");
         let bin = temp_dir.path().join("bin");
 
         // Ensure directories exist
@@ -72,6 +80,10 @@ impl TestEnvironment {
             home,
             cursor_config,
             claude_config,
+            vscode_config,
+            windsurf_config,
+            windsurf_workspace,
+            zed_config,
             bin,
         })
     }
@@ -168,6 +180,30 @@ impl TestEnvironment {
         new_path.push(orig_path);
         vars.push((OsString::from("PATH"), Some(new_path)));
 
+        // VS Code user config dir
+        vars.push((
+            OsString::from("VSCODE_CONFIG_DIR"),
+            Some(self.vscode_config.clone().into_os_string()),
+        ));
+
+        // Windsurf and Zed config dirs
+        vars.push((
+            OsString::from("WINDSURF_CONFIG_DIR"),
+            Some(self.windsurf_config.clone().into_os_string()),
+        ));
+        vars.push((
+            OsString::from("WINDSURF_WORKSPACE_DIR"),
+            Some(self.windsurf_workspace.clone().into_os_string()),
+        ));
+        vars.push((
+            OsString::from("ZED_CONFIG_DIR"),
+            Some(self.zed_config.clone().into_os_string()),
+        ));
+
+        // Keep the foundry directory at $HOME/.foundry whatever the host sets
+        vars.push((OsString::from("FOUNDRY_HOME"), None));
+        vars.push((OsString::from("XDG_DATA_HOME"), None));
+
         vars
     }
 
@@ -270,6 +306,26 @@ impl TestEnvironment {
         self.cursor_config.clone()
     }
 
+    /// Get VS Code MCP config path within test environment
+    pub fn vscode_config_path(&self) -> PathBuf {
+        self.vscode_config.join("mcp.json")
+    }
+
+    /// Get Windsurf MCP config path within test environment
+    pub fn windsurf_config_path(&self) -> PathBuf {
+        self.windsurf_config.join("mcp_config.json")
+    }
+
+    /// Get Windsurf workspace directory (rules and workflows) within test environment
+    pub fn windsurf_workspace_dir(&self) -> PathBuf {
+        self.windsurf_workspace.clone()
+    }
+
+    /// Get Zed settings path within test environment
+    pub fn zed_settings_path(&self) -> PathBuf {
+        self.zed_config.join("settings.json")
+    }
+
     /// Get claude code config path within test environment
     pub fn claude_code_config_path(&self) -> PathBuf {
         self.home.join(".claude.json")
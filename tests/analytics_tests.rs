@@ -0,0 +1,238 @@
+//! Integration tests for project analytics
+
+mod common;
+
+use chrono::NaiveDate;
+use common::TestEnvironment;
+use foundry_mcp::core::ops::project_analytics::{self, SpecTasks};
+use foundry_mcp::core::ops::project_report;
+use foundry_mcp::core::tasks::parse_task_list;
+use foundry_mcp::types::spec::SpecStatus;
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+}
+
+#[test]
+fn test_analyze_velocity_cycle_time_and_stalled_specs() {
+    let specs = vec![
+        SpecTasks {
+            name: "20250601_090000_done_feature".to_string(),
+            created: date("2025-06-01"),
+            status: SpecStatus::Active,
+            tasks: parse_task_list(
+                "- [x] First completed:2025-06-03\n- [x] Second completed:2025-06-11",
+            ),
+            modified: None,
+        },
+        SpecTasks {
+            name: "20250501_090000_stuck_feature".to_string(),
+            created: date("2025-05-01"),
+            status: SpecStatus::Active,
+            tasks: parse_task_list(
+                "- [x] Started completed:2025-05-02\n- [ ] Finish\n- [x] Undated",
+            ),
+            modified: None,
+        },
+        SpecTasks {
+            name: "20250610_090000_active_feature".to_string(),
+            created: date("2025-06-10"),
+            status: SpecStatus::Active,
+            tasks: parse_task_list("- [ ] Build it"),
+            modified: None,
+        },
+    ];
+
+    // 2025-06-12 is a Thursday; the current week starts Monday 2025-06-09
+    let report = project_analytics::analyze("demo", &specs, date("2025-06-12"), 2, 14);
+
+    let weeks: Vec<(&str, usize)> = report
+        .weekly_completions
+        .iter()
+        .map(|w| (w.week_start.as_str(), w.completed))
+        .collect();
+    assert_eq!(weeks, vec![("2025-06-02", 1), ("2025-06-09", 1)]);
+    assert_eq!(report.total_tasks, 6);
+    assert_eq!(report.completed_tasks, 4);
+    assert_eq!(report.undated_completed_tasks, 1);
+    assert_eq!(report.completed_spec_count, 1);
+    assert_eq!(report.average_cycle_time_days, Some(10.0));
+
+    assert_eq!(report.stalled_specs.len(), 1);
+    let stalled = &report.stalled_specs[0];
+    assert_eq!(stalled.spec_name, "20250501_090000_stuck_feature");
+    assert_eq!(stalled.open_tasks, 1);
+    assert_eq!(stalled.last_activity, "2025-05-02");
+    assert_eq!(stalled.days_idle, 41);
+
+    // A file change recorded by foundry counts as activity
+    let edited = SpecTasks {
+        name: "20250501_090000_edited_feature".to_string(),
+        created: date("2025-05-01"),
+        status: SpecStatus::Active,
+        tasks: parse_task_list("- [ ] Finish"),
+        modified: Some(date("2025-06-05")),
+    };
+    assert!(project_analytics::stalled_spec(&edited, date("2025-06-12"), 14).is_none());
+    let stalled = project_analytics::stalled_spec(&edited, date("2025-06-12"), 5).unwrap();
+    assert_eq!(stalled.last_activity, "2025-06-05");
+
+    assert!(report.summary_markdown.contains("| 2025-06-09 | 1 |"));
+    assert!(
+        report
+            .summary_markdown
+            .contains("- 20250501_090000_stuck_feature: 1 open")
+    );
+}
+
+#[test]
+fn test_project_analytics_runs_against_project() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("analytics-app").await.unwrap();
+        env.create_test_spec("analytics-app", "user_auth", "Auth")
+            .await
+            .unwrap();
+
+        let response = project_analytics::run(project_analytics::Input {
+            project_name: "analytics-app".to_string(),
+            weeks: None,
+            stall_days: None,
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(response.data.weekly_completions.len(), 8);
+        assert_eq!(response.data.total_tasks, 7);
+        assert_eq!(response.data.completed_tasks, 0);
+        // A spec created today is not stalled yet
+        assert!(response.data.stalled_specs.is_empty());
+        assert!(response.data.average_cycle_time_days.is_none());
+    });
+}
+
+#[test]
+fn test_project_analytics_missing_project() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let error = project_analytics::run(project_analytics::Input {
+            project_name: "missing".to_string(),
+            weeks: None,
+            stall_days: None,
+        })
+        .await
+        .unwrap_err();
+
+        assert!(error.to_string().contains("not found"));
+    });
+}
+
+#[test]
+fn test_project_report_rolls_up_progress_recent_work_and_stale_specs() {
+    let specs = vec![
+        SpecTasks {
+            name: "20250610_090000_checkout".to_string(),
+            created: date("2025-06-10"),
+            status: SpecStatus::Active,
+            tasks: parse_task_list(
+                "- [x] Cart @ana completed:2025-06-11\n- [x] Payment completed:2025-06-01\n- [ ] Receipts\n- [ ] Refunds",
+            ),
+            modified: None,
+        },
+        SpecTasks {
+            name: "20250501_090000_search".to_string(),
+            created: date("2025-05-01"),
+            status: SpecStatus::Active,
+            tasks: parse_task_list("- [ ] Index\n- [x] Undated"),
+            modified: None,
+        },
+        SpecTasks {
+            name: "20250401_090000_legacy".to_string(),
+            created: date("2025-04-01"),
+            status: SpecStatus::Archived,
+            tasks: parse_task_list("- [ ] Never done"),
+            modified: None,
+        },
+        SpecTasks {
+            name: "20250612_090000_empty".to_string(),
+            created: date("2025-06-12"),
+            status: SpecStatus::Draft,
+            tasks: Vec::new(),
+            modified: None,
+        },
+    ];
+
+    let report = project_report::report("demo", &specs, date("2025-06-12"), 7, 14);
+
+    assert_eq!(report.total_tasks, 6);
+    assert_eq!(report.completed_tasks, 3);
+    assert_eq!(report.completion_percent, 50);
+    let progress: Vec<(&str, usize, u32)> = report
+        .specs
+        .iter()
+        .map(|s| (s.spec_name.as_str(), s.total_tasks, s.completion_percent))
+        .collect();
+    assert_eq!(
+        progress,
+        vec![
+            ("20250610_090000_checkout", 4, 50),
+            ("20250501_090000_search", 2, 50),
+            ("20250612_090000_empty", 0, 0),
+        ]
+    );
+
+    // Only completions dated inside the window, with metadata stripped
+    assert_eq!(report.recently_completed.len(), 1);
+    assert_eq!(report.recently_completed[0].task, "Cart");
+    assert_eq!(report.recently_completed[0].completed_at, "2025-06-11");
+
+    assert_eq!(report.stale_specs.len(), 1);
+    assert_eq!(report.stale_specs[0].spec_name, "20250501_090000_search");
+
+    assert!(
+        report
+            .summary_markdown
+            .contains("As of 2025-06-12: 3/6 tasks complete (50%) across 3 specs.")
+    );
+    assert!(
+        report
+            .summary_markdown
+            .contains("| 20250612_090000_empty | draft | 0/0 | - |")
+    );
+    assert!(
+        report
+            .summary_markdown
+            .contains("- Cart (20250610_090000_checkout, 2025-06-11)")
+    );
+}
+
+#[test]
+fn test_project_report_runs_against_project() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("report-app").await.unwrap();
+        env.create_test_spec("report-app", "user_auth", "Auth")
+            .await
+            .unwrap();
+
+        let response = project_report::run(project_report::Input {
+            project_name: "report-app".to_string(),
+            recent_days: None,
+            stale_days: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.specs.len(), 1);
+        assert_eq!(response.data.recent_days, 7);
+        assert!(response.data.stale_specs.is_empty());
+
+        let error = project_report::run(project_report::Input {
+            project_name: "report-app".to_string(),
+            recent_days: Some(0),
+            stale_days: None,
+        })
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("recent_days"));
+    });
+}
@@ -0,0 +1,138 @@
+//! Integration tests for project export and import archives
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::{export_project, import_archive};
+use foundry_mcp::types::spec::SpecFileType;
+
+#[test]
+fn test_export_then_import_restores_project_and_links() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("archive-app").await.unwrap();
+        env.create_test_spec("archive-app", "auth", "Authentication")
+            .await
+            .unwrap();
+        env.create_test_spec("archive-app", "billing", "Billing")
+            .await
+            .unwrap();
+
+        let foundry = get_default_foundry().unwrap();
+        let specs = foundry.list_specs("archive-app").await.unwrap();
+        let auth = specs.iter().find(|s| s.feature_name == "auth").unwrap();
+        let billing = specs.iter().find(|s| s.feature_name == "billing").unwrap();
+        foundry
+            .update_spec_content(
+                "archive-app",
+                &billing.name,
+                SpecFileType::Notes,
+                &format!("Depends on [[{}|auth]]", auth.name),
+            )
+            .await
+            .unwrap();
+
+        let archive_path = env.temp_dir.path().join("archive-app.foundry.tar.gz");
+        let exported = export_project::run(export_project::Input {
+            project_name: "archive-app".to_string(),
+            output_path: Some(archive_path.to_string_lossy().to_string()),
+            overwrite: false,
+        })
+        .await
+        .unwrap();
+        assert_eq!(exported.data.spec_count, 2);
+        assert_eq!(exported.data.file_count, 10);
+        assert!(archive_path.exists());
+
+        let imported = import_archive::run(import_archive::Input {
+            archive_path: archive_path.to_string_lossy().to_string(),
+            project_name: Some("archive-copy".to_string()),
+        })
+        .await
+        .unwrap();
+        assert_eq!(imported.data.source_project_name, "archive-app");
+        assert_eq!(imported.data.specs.len(), 2);
+        assert_eq!(imported.data.links_rewritten, 1);
+
+        let project = foundry.load_project("archive-copy").await.unwrap();
+        let original = foundry.load_project("archive-app").await.unwrap();
+        assert_eq!(project.vision, original.vision);
+        assert_eq!(project.summary, original.summary);
+
+        let new_auth = imported
+            .data
+            .specs
+            .iter()
+            .find(|s| s.original_name == auth.name)
+            .unwrap();
+        let new_billing = imported
+            .data
+            .specs
+            .iter()
+            .find(|s| s.original_name == billing.name)
+            .unwrap();
+        let restored = foundry
+            .load_spec("archive-copy", &new_billing.spec_name)
+            .await
+            .unwrap();
+        assert_eq!(
+            restored.content.notes,
+            format!("Depends on [[{}|auth]]", new_auth.spec_name)
+        );
+        let original_auth = foundry.load_spec("archive-app", &auth.name).await.unwrap();
+        let restored_auth = foundry
+            .load_spec("archive-copy", &new_auth.spec_name)
+            .await
+            .unwrap();
+        assert_eq!(restored_auth.content.spec, original_auth.content.spec);
+        assert_eq!(restored_auth.content.tasks, original_auth.content.tasks);
+    });
+}
+
+#[test]
+fn test_export_and_import_refuse_to_overwrite() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("archive-app").await.unwrap();
+
+        let archive_path = env.temp_dir.path().join("archive-app.foundry.tar.gz");
+        let export = |overwrite| {
+            export_project::run(export_project::Input {
+                project_name: "archive-app".to_string(),
+                output_path: Some(archive_path.to_string_lossy().to_string()),
+                overwrite,
+            })
+        };
+        export(false).await.unwrap();
+        let error = export(false).await.unwrap_err();
+        assert!(error.to_string().contains("already exists"));
+        export(true).await.unwrap();
+
+        // The archived project still exists under its own name
+        let error = import_archive::run(import_archive::Input {
+            archive_path: archive_path.to_string_lossy().to_string(),
+            project_name: None,
+        })
+        .await
+        .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Project 'archive-app' already exists")
+        );
+
+        let error = import_archive::run(import_archive::Input {
+            archive_path: env
+                .temp_dir
+                .path()
+                .join("missing.tar.gz")
+                .to_string_lossy()
+                .to_string(),
+            project_name: None,
+        })
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("Cannot open archive"));
+    });
+}
@@ -0,0 +1,81 @@
+//! Integration tests for the audit log of changes
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::audit;
+use foundry_mcp::core::ops::get_audit_log;
+use foundry_mcp::types::errors::ErrorCode;
+use foundry_mcp::types::responses::{AuditInterface, AuditOutcome};
+use serde_json::json;
+
+fn input(spec_name: Option<&str>, limit: Option<usize>) -> get_audit_log::Input {
+    get_audit_log::Input {
+        project_name: "audited".to_string(),
+        spec_name: spec_name.map(str::to_string),
+        operation: None,
+        since: Some("1d".to_string()),
+        limit,
+    }
+}
+
+#[test]
+fn test_changes_are_appended_and_read_newest_first() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("audited").await.unwrap();
+        let command = json!({"target": "tasks", "command": "upsert_task", "content": "- [ ] Ship"});
+
+        audit::record_call(
+            "audited",
+            "update_spec",
+            &json!({"spec_name": "20250101_000000_auth", "commands": [command.clone()]}),
+            None,
+            None,
+        )
+        .unwrap();
+        audit::with_interface(AuditInterface::Mcp, async {
+            audit::record_call(
+                "audited",
+                "update_specs_batch",
+                &json!({"updates": json!([
+                    {"spec_name": "20250101_000000_auth", "commands": [command.clone()]},
+                    {"spec_name": "20250102_000000_billing", "commands": [command.clone()]},
+                ]).to_string()}),
+                Some(ErrorCode::SelectorNotFound),
+                Some("req-7"),
+            )
+        })
+        .await
+        .unwrap();
+
+        let log = get_audit_log::run(input(None, None)).await.unwrap().data;
+        assert_eq!(log.total_matching, 3);
+        let newest = &log.entries[0];
+        assert_eq!(newest.spec_name.as_deref(), Some("20250102_000000_billing"));
+        assert_eq!(newest.interface, AuditInterface::Mcp);
+        assert_eq!(newest.outcome, AuditOutcome::Error);
+        assert_eq!(newest.error_code.as_deref(), Some("SELECTOR_NOT_FOUND"));
+        assert_eq!(newest.request_id.as_deref(), Some("req-7"));
+        let oldest = &log.entries[2];
+        assert_eq!(oldest.interface, AuditInterface::Cli);
+        assert_eq!(oldest.outcome, AuditOutcome::Ok);
+        assert_eq!(oldest.commands, [command]);
+
+        let auth = get_audit_log::run(input(Some("20250101_000000_auth"), Some(1)))
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(auth.total_matching, 2);
+        assert_eq!(auth.entries.len(), 1);
+        assert_eq!(auth.entries[0].operation, "update_specs_batch");
+
+        let missing = get_audit_log::run(get_audit_log::Input {
+            project_name: "never-created".to_string(),
+            ..input(None, None)
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&missing), ErrorCode::ProjectNotFound);
+    });
+}
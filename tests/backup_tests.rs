@@ -0,0 +1,188 @@
+//! Integration tests for spec backups and undoing spec updates
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::cli::args::{BackupArgs, BackupCommand};
+use foundry_mcp::cli::commands::backup;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::{delete_spec, undo_last_update, update_spec};
+use foundry_mcp::types::errors::ErrorCode;
+use foundry_mcp::types::responses::{BackupListResponse, BackupRestoreResponse};
+
+async fn backup_json<T: serde::de::DeserializeOwned>(command: BackupCommand) -> T {
+    let output = backup::execute(BackupArgs {
+        command,
+        json: true,
+    })
+    .await
+    .unwrap();
+    serde_json::from_str(&output).unwrap()
+}
+
+#[test]
+fn test_deleted_spec_is_backed_up_and_restored() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("backup-app").await.unwrap();
+        env.create_test_spec("backup-app", "auth", "Authentication")
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        let spec_name = foundry.list_specs("backup-app").await.unwrap()[0]
+            .name
+            .clone();
+        let original = foundry.load_spec("backup-app", &spec_name).await.unwrap();
+
+        delete_spec::run(delete_spec::Input {
+            project_name: "backup-app".to_string(),
+            spec_name: spec_name.clone(),
+            confirm: "true".to_string(),
+        })
+        .await
+        .unwrap();
+        assert!(foundry.list_specs("backup-app").await.unwrap().is_empty());
+
+        let listed: BackupListResponse = backup_json(BackupCommand::List {
+            project: Some("backup-app".to_string()),
+            spec: None,
+        })
+        .await;
+        assert_eq!(listed.backups.len(), 1);
+        assert_eq!(listed.backups[0].spec_name, spec_name);
+        assert_eq!(listed.backups[0].reason, "delete_spec");
+
+        let restored: BackupRestoreResponse = backup_json(BackupCommand::Restore {
+            id: listed.backups[0].id.clone(),
+        })
+        .await;
+        assert!(restored.recreated);
+        assert!(restored.replaced_backup.is_none());
+
+        let spec = foundry
+            .load_spec("backup-app", &restored.spec_name)
+            .await
+            .unwrap();
+        assert_eq!(spec.content.spec, original.content.spec);
+        assert_eq!(spec.content.tasks, original.content.tasks);
+    });
+}
+
+#[test]
+fn test_only_section_replacing_edits_are_backed_up() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("backup-edits").await.unwrap();
+        env.create_test_spec("backup-edits", "auth", "Authentication")
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        let spec_name = foundry.list_specs("backup-edits").await.unwrap()[0]
+            .name
+            .clone();
+
+        let run = |commands: serde_json::Value| {
+            update_spec::run(update_spec::Input {
+                project_name: "backup-edits".to_string(),
+                spec_name: spec_name.clone(),
+                commands_json: commands.to_string(),
+                expected_version: None,
+                dry_run: false,
+            })
+        };
+        run(serde_json::json!([{
+            "target": "tasks",
+            "command": "upsert_task",
+            "selector": {"type": "task_text", "value": "Write docs"},
+            "content": "- [ ] Write docs"
+        }]))
+        .await
+        .unwrap();
+        let list = || BackupCommand::List {
+            project: None,
+            spec: Some(spec_name.clone()),
+        };
+        let listed: BackupListResponse = backup_json(list()).await;
+        assert!(listed.backups.is_empty());
+
+        run(serde_json::json!([{
+            "target": "spec",
+            "command": "replace_section_content",
+            "selector": {"type": "section", "value": "## Overview"},
+            "content": "Rewritten overview"
+        }]))
+        .await
+        .unwrap();
+        let listed: BackupListResponse = backup_json(list()).await;
+        assert_eq!(listed.backups.len(), 1);
+        assert_eq!(listed.backups[0].reason, "update_spec");
+    });
+}
+
+#[test]
+fn test_undo_last_update_steps_back_through_updates() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("undo-app").await.unwrap();
+        env.create_test_spec("undo-app", "auth", "Authentication")
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        let spec_name = foundry.list_specs("undo-app").await.unwrap()[0]
+            .name
+            .clone();
+        let original = foundry.load_spec("undo-app", &spec_name).await.unwrap();
+
+        let edit = |task: &str| {
+            update_spec::run(update_spec::Input {
+                project_name: "undo-app".to_string(),
+                spec_name: spec_name.clone(),
+                commands_json: serde_json::json!([{
+                    "target": "tasks",
+                    "command": "upsert_task",
+                    "selector": {"type": "task_text", "value": task},
+                    "content": format!("- [ ] {}", task)
+                }])
+                .to_string(),
+                expected_version: None,
+                dry_run: false,
+            })
+        };
+        let undo = |force: bool| {
+            undo_last_update::run(undo_last_update::Input {
+                project_name: "undo-app".to_string(),
+                spec_name: spec_name.clone(),
+                force,
+            })
+        };
+        edit("Write docs").await.unwrap();
+        let after_first = foundry.load_spec("undo-app", &spec_name).await.unwrap();
+        edit("Ship it").await.unwrap();
+
+        let undone = undo(false).await.unwrap().data;
+        assert_eq!(undone.operation, "update_spec");
+        assert_eq!(undone.files_restored, ["task-list.md"]);
+        assert_eq!(undone.remaining_undos, 1);
+        let spec = foundry.load_spec("undo-app", &spec_name).await.unwrap();
+        assert_eq!(spec.content.tasks, after_first.content.tasks);
+
+        // A file changed outside the journal is only overwritten with force
+        foundry
+            .update_spec_content(
+                "undo-app",
+                &spec_name,
+                foundry_mcp::types::spec::SpecFileType::TaskList,
+                "- [ ] Edited by hand",
+            )
+            .await
+            .unwrap();
+        let error = undo(false).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::BackendConflict);
+        assert_eq!(undo(true).await.unwrap().data.remaining_undos, 0);
+        let spec = foundry.load_spec("undo-app", &spec_name).await.unwrap();
+        assert_eq!(spec.content.tasks, original.content.tasks);
+
+        let error = undo(false).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+    });
+}
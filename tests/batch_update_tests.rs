@@ -0,0 +1,107 @@
+//! Integration tests for update_specs_batch
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::ops::update_specs_batch;
+use foundry_mcp::types::errors::ErrorCode;
+
+fn mark_done(task: &str) -> serde_json::Value {
+    serde_json::json!({
+        "target": "tasks",
+        "command": "set_task_status",
+        "selector": {"type": "task_text", "value": task},
+        "status": "done"
+    })
+}
+
+#[test]
+fn test_batch_applies_each_spec_atomically() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("batch-app").await.unwrap();
+        let specs_dir = env.foundry_dir().join("batch-app").join("specs");
+        let mut spec_names = Vec::new();
+        for (feature, tasks) in [
+            (
+                "auth",
+                "## Tasks\n- [ ] Rename session module\n- [ ] Update imports\n",
+            ),
+            ("billing", "## Tasks\n- [ ] Rename invoice module\n"),
+        ] {
+            let spec_name = format!("20250101_12000{}_{}", spec_names.len(), feature);
+            let dir = specs_dir.join(&spec_name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("spec.md"), "# Spec").unwrap();
+            std::fs::write(dir.join("notes.md"), "Notes").unwrap();
+            std::fs::write(dir.join("task-list.md"), tasks).unwrap();
+            spec_names.push(spec_name);
+        }
+
+        let updates = serde_json::json!([
+            {"spec_name": spec_names[0], "commands": [
+                mark_done("Rename session module"),
+                mark_done("Update imports")
+            ]},
+            // The second command fails, so the first must not be written either
+            {"spec_name": spec_names[1], "commands": [
+                mark_done("Rename invoice module"),
+                mark_done("Ship the thing")
+            ]},
+            {"spec_name": "20250101_120009_missing", "commands": [mark_done("Anything")]}
+        ]);
+        let response = update_specs_batch::run(update_specs_batch::Input {
+            project_name: "batch-app".to_string(),
+            updates_json: updates.to_string(),
+        })
+        .await
+        .unwrap();
+
+        let data = &response.data;
+        assert_eq!((data.total_specs, data.succeeded, data.failed), (3, 1, 2));
+
+        let auth = &data.results[0];
+        assert!(auth.success);
+        assert_eq!(auth.applied_count, 2);
+        assert!(auth.versions.is_some());
+        let auth_tasks =
+            std::fs::read_to_string(specs_dir.join(&spec_names[0]).join("task-list.md")).unwrap();
+        assert!(auth_tasks.contains("- [x] Rename session module"));
+        assert!(auth_tasks.contains("- [x] Update imports"));
+
+        let billing = &data.results[1];
+        assert!(!billing.success);
+        assert_eq!(billing.error_code, Some(ErrorCode::SelectorNotFound));
+        assert_eq!(billing.errors.as_ref().unwrap()[0].command_index, 1);
+        let billing_tasks =
+            std::fs::read_to_string(specs_dir.join(&spec_names[1]).join("task-list.md")).unwrap();
+        assert!(billing_tasks.contains("- [ ] Rename invoice module"));
+
+        assert_eq!(data.results[2].error_code, Some(ErrorCode::SpecNotFound));
+    });
+}
+
+#[test]
+fn test_batch_rejects_invalid_requests() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("batch-errors").await.unwrap();
+
+        for updates in [
+            serde_json::json!([]),
+            serde_json::json!([
+                {"spec_name": "20250101_120000_auth", "commands": [mark_done("A")]},
+                {"spec_name": "20250101_120000_auth", "commands": [mark_done("B")]}
+            ]),
+            serde_json::json!({"spec_name": "20250101_120000_auth"}),
+        ] {
+            let error = update_specs_batch::run(update_specs_batch::Input {
+                project_name: "batch-errors".to_string(),
+                updates_json: updates.to_string(),
+            })
+            .await
+            .unwrap_err();
+            assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+        }
+    });
+}
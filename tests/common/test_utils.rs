@@ -36,6 +36,8 @@ impl TestEnvironment {
         InstallArgs {
             target: target.to_string(),
             binary_path: None,
+            vars: Vec::new(),
+            templates_dir: None,
             json: true, // Always use JSON mode for testing
         }
     }
@@ -66,6 +68,8 @@ impl TestEnvironment {
         let args = InstallArgs {
             target: target.to_string(),
             binary_path: None,
+            vars: Vec::new(),
+            templates_dir: None,
             json: false, // Text mode for this helper
         };
         install::execute(args).await
@@ -214,11 +218,50 @@ impl TestEnvironment {
                 feature_name,
                 spec_content.to_lowercase()
             ),
+            template: None,
+            allow_duplicate: false,
         };
 
         create_spec::run(input).await.map(|_| ())
     }
 
+    /// Create a spec whose spec.md is exactly `spec`, with one done and one open
+    /// task, and return its full name
+    pub async fn create_bare_spec(
+        &self,
+        project_name: &str,
+        feature_name: &str,
+        spec: &str,
+    ) -> Result<String> {
+        let input = create_spec::Input {
+            project_name: project_name.to_string(),
+            feature_name: feature_name.to_string(),
+            spec: spec.to_string(),
+            notes: "Notes".to_string(),
+            tasks: "- [x] Design\n- [ ] Build it".to_string(),
+            template: None,
+            allow_duplicate: false,
+        };
+
+        Ok(create_spec::run(input).await?.data.spec_name)
+    }
+
+    /// Write a spec directory created on 2020-01-01 with `tasks`, so it is long
+    /// past any stale threshold, and return its name
+    pub fn write_old_spec(&self, project_name: &str, feature_name: &str, tasks: &str) -> String {
+        let spec_name = format!("20200101_090000_{}", feature_name);
+        let spec_dir = self
+            .foundry_dir()
+            .join(project_name)
+            .join("specs")
+            .join(&spec_name);
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(spec_dir.join("spec.md"), "# Old spec").unwrap();
+        fs::write(spec_dir.join("notes.md"), "Notes").unwrap();
+        fs::write(spec_dir.join("task-list.md"), tasks).unwrap();
+        spec_name
+    }
+
     // Legacy helper methods for integration test compatibility
 
     /// Create project args (legacy integration test helper)
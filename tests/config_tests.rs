@@ -0,0 +1,219 @@
+//! Integration tests for ~/.foundry/config.toml and per-project .foundry.toml
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::backends::{open_configured_backend, resolve_backend_config};
+use foundry_mcp::core::config::BackendConfig;
+use foundry_mcp::core::foundry::{Foundry, get_default_foundry};
+use foundry_mcp::core::ops::{create_project, create_spec, update_spec, validate_content};
+use foundry_mcp::types::errors::ErrorCode;
+use foundry_mcp::types::project::ProjectConfig;
+use foundry_mcp::types::responses::ValidationStatus;
+
+#[test]
+fn test_global_and_project_config_drive_validation_and_templates() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let foundry_dir = env.foundry_dir();
+        std::fs::create_dir_all(&foundry_dir).unwrap();
+        let short_project = || create_project::Input {
+            project_name: "short-app".to_string(),
+            vision: "A short vision".to_string(),
+            tech_stack: "Rust".to_string(),
+            summary: "Short".to_string(),
+        };
+
+        let error = create_project::run(short_project()).await.unwrap_err();
+        assert!(error.to_string().contains("at least 200 characters"));
+
+        std::fs::write(
+            foundry_dir.join("config.toml"),
+            "[validation.min_length]\nvision = 10\ntech_stack = 4\nsummary = 5\n",
+        )
+        .unwrap();
+        create_project::run(short_project()).await.unwrap();
+
+        // Project config picks the spec template used when create_spec names none
+        std::fs::create_dir_all(foundry_dir.join(".templates/specs")).unwrap();
+        std::fs::write(
+            foundry_dir.join(".templates/specs/rfc.md"),
+            "# RFC: {{feature_title}}\n\n{{spec}}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            foundry_dir.join("short-app/.foundry.toml"),
+            "[templates]\nspec = \"rfc\"\n",
+        )
+        .unwrap();
+
+        let spec_args = env.create_spec_args("short-app", "login_flow");
+        let response = create_spec::run(create_spec::Input {
+            project_name: spec_args.project_name,
+            feature_name: spec_args.feature_name,
+            spec: spec_args.spec,
+            notes: spec_args.notes,
+            tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
+        })
+        .await
+        .unwrap();
+        let spec = get_default_foundry()
+            .unwrap()
+            .load_spec("short-app", &response.data.spec_name)
+            .await
+            .unwrap();
+        assert!(spec.content.spec.starts_with("# RFC: Login Flow\n"));
+
+        // Invalid config is reported instead of being ignored
+        std::fs::write(
+            foundry_dir.join("short-app/.foundry.toml"),
+            "[templates]\nspek = \"rfc\"\n",
+        )
+        .unwrap();
+        let error = create_spec::run(create_spec::Input {
+            project_name: "short-app".to_string(),
+            feature_name: "other".to_string(),
+            spec: "x".repeat(200),
+            notes: "x".repeat(60),
+            tasks: "- [ ] Task one two three four".to_string(),
+            template: None,
+            allow_duplicate: false,
+        })
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains(".foundry.toml"));
+    });
+}
+
+#[test]
+fn test_project_config_routes_project_to_its_own_backend() {
+    if which::which("git").is_err() {
+        return;
+    }
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let foundry_dir = env.foundry_dir();
+        let repo_dir = env.temp_dir.path().join("team-specs");
+        std::fs::create_dir_all(foundry_dir.join("team-app")).unwrap();
+        std::fs::write(
+            foundry_dir.join("team-app/.foundry.toml"),
+            format!("[backend]\nkind = \"git\"\ngit_dir = {:?}\n", repo_dir),
+        )
+        .unwrap();
+
+        let default = resolve_backend_config(BackendConfig::default()).unwrap();
+        assert_eq!(default.kind(), "filesystem");
+        let foundry = Foundry::new(open_configured_backend(&default).unwrap());
+        for name in ["my-app", "team-app"] {
+            foundry
+                .create_project(ProjectConfig {
+                    name: name.to_string(),
+                    vision: "Vision".to_string(),
+                    tech_stack: "Rust".to_string(),
+                    summary: "Summary".to_string(),
+                })
+                .await
+                .unwrap();
+        }
+
+        assert!(foundry_dir.join("my-app/vision.md").exists());
+        assert!(!foundry_dir.join("team-app/vision.md").exists());
+        assert!(repo_dir.join("team-app/vision.md").exists());
+        let names: Vec<String> = foundry
+            .list_projects()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|project| project.name)
+            .collect();
+        assert_eq!(names, vec!["my-app", "team-app"]);
+    });
+}
+
+#[test]
+fn test_validation_profile_and_rules_apply_to_spec_ops() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("rules-app").await.unwrap();
+        let project_config = env.foundry_dir().join("rules-app/.foundry.toml");
+        let spec_args = env.create_spec_args("rules-app", "login_flow");
+        let create = || {
+            create_spec::run(create_spec::Input {
+                project_name: "rules-app".to_string(),
+                feature_name: "login_flow".to_string(),
+                spec: spec_args.spec.clone(),
+                notes: spec_args.notes.clone(),
+                tasks: spec_args.tasks.clone(),
+                template: None,
+                allow_duplicate: false,
+            })
+        };
+
+        std::fs::write(
+            &project_config,
+            "[validation]\nprofile = \"strict\"\n\n[validation.rules.spec]\nrequired_sections = [\"Acceptance Criteria\"]\n",
+        )
+        .unwrap();
+        let validated = validate_content::run(validate_content::Input {
+            content_type: "spec".to_string(),
+            content: spec_args.spec.clone(),
+            project_name: Some("rules-app".to_string()),
+        })
+        .await
+        .unwrap()
+        .data;
+        assert!(!validated.is_valid);
+        assert_eq!(validated.profile, "strict");
+        assert_eq!(validated.failed_rules.len(), 1);
+        assert_eq!(validated.failed_rules[0].rule, "required_section");
+        let error = create().await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+        assert!(error.to_string().contains("Acceptance Criteria"));
+
+        std::fs::write(
+            &project_config,
+            "[validation]\nprofile = \"strict\"\n\n[validation.rules.spec]\nrequired_sections = [\"Requirements\"]\nbanned_phrases = [\"TBD\"]\n",
+        )
+        .unwrap();
+        let spec_name = create().await.unwrap().data.spec_name;
+        let update = || {
+            update_spec::run(update_spec::Input {
+                project_name: "rules-app".to_string(),
+                spec_name: spec_name.clone(),
+                commands_json: serde_json::json!([{
+                    "target": "spec",
+                    "command": "append_to_section",
+                    "selector": {"type": "section", "value": "## Overview"},
+                    "content": "Rollout owner: TBD"
+                }])
+                .to_string(),
+                expected_version: None,
+                dry_run: false,
+            })
+        };
+        let error = update().await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+        let foundry = get_default_foundry().unwrap();
+        let spec = foundry.load_spec("rules-app", &spec_name).await.unwrap();
+        assert!(!spec.content.spec.contains("TBD"));
+
+        // Lenient keeps custom rules but only warns
+        std::fs::write(
+            &project_config,
+            "[validation]\nprofile = \"lenient\"\n\n[validation.rules.spec]\nbanned_phrases = [\"TBD\"]\n",
+        )
+        .unwrap();
+        let response = update().await.unwrap();
+        assert_eq!(response.validation_status, ValidationStatus::Incomplete);
+        assert!(
+            response
+                .workflow_hints
+                .iter()
+                .any(|hint| hint.contains("spec.md: Contains banned phrase 'TBD'"))
+        );
+        let spec = foundry.load_spec("rules-app", &spec_name).await.unwrap();
+        assert!(spec.content.spec.contains("Rollout owner: TBD"));
+    });
+}
@@ -0,0 +1,137 @@
+//! Integration tests for token-budgeted load_project and load_spec
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::ops::{create_spec, load_project, load_spec};
+use foundry_mcp::types::errors::ErrorCode;
+
+#[test]
+fn test_load_spec_with_max_tokens_keeps_priority_sections() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("budget-app").await.unwrap();
+        let created = create_spec::run(create_spec::Input {
+            project_name: "budget-app".to_string(),
+            feature_name: "checkout".to_string(),
+            spec: format!(
+                "# Checkout\n\n## Overview\n\nPay for a cart.\n\n## Details\n\n{}",
+                "Long implementation detail line.\n".repeat(300)
+            ),
+            notes: "## Early notes\n\nFirst thoughts.\n\n## Latest notes\n\nSwitched to Stripe."
+                .to_string(),
+            tasks: "## Done\n\n- [x] Cart model\n\n## Payments\n\n- [ ] Stripe client\n- [x] Price display"
+                .to_string(),
+            template: None,
+            allow_duplicate: false,
+        })
+        .await
+        .unwrap();
+        let spec_name = created.data.spec_name;
+
+        let response = load_spec::run(load_spec::Input {
+            project_name: "budget-app".to_string(),
+            spec_name: Some(spec_name.clone()),
+            max_tokens: Some(250),
+            part: None,
+            files: None,
+        })
+        .await
+        .unwrap();
+
+        let budget = response.data.budget.unwrap();
+        assert!(budget.estimated_tokens <= 250, "{}", budget.estimated_tokens);
+        assert_eq!(
+            budget.included_sections[..4],
+            [
+                "Project summary",
+                "Tasks: Payments",
+                "Notes: Latest notes",
+                "Notes: Early notes"
+            ]
+            .map(String::from)
+        );
+        assert!(budget.truncated);
+        assert!(budget.omitted_sections.contains(&"Tasks: Done".to_string()));
+
+        let content = response.data.spec_content.unwrap();
+        assert!(content.content.tasks.starts_with("## Payments"));
+        assert!(!content.content.tasks.contains("Cart model"));
+        assert!(
+            content
+                .content
+                .notes
+                .ends_with("## Latest notes\n\nSwitched to Stripe.")
+        );
+        assert!(content.content.spec.contains("## Overview"));
+        assert!(content.content.spec.contains("truncated to fit max_tokens"));
+
+        // Versions describe the stored files, not the trimmed content
+        let full = load_spec::run(load_spec::Input {
+            project_name: "budget-app".to_string(),
+            spec_name: Some(spec_name),
+            max_tokens: None,
+            part: None,
+            files: None,
+        })
+        .await
+        .unwrap();
+        assert!(full.data.budget.is_none());
+        assert_eq!(
+            content.versions.tasks,
+            full.data.spec_content.unwrap().versions.tasks
+        );
+    });
+}
+
+#[test]
+fn test_load_project_with_max_tokens() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("budget-project").await.unwrap();
+
+        let response = load_project::run(load_project::Input {
+            project_name: "budget-project".to_string(),
+            max_tokens: Some(150),
+            include: None,
+            active_limit: None,
+        })
+        .await
+        .unwrap();
+
+        let budget = response.data.budget.unwrap();
+        assert_eq!(budget.included_sections[0], "Project summary");
+        assert!(
+            budget
+                .omitted_sections
+                .iter()
+                .all(|section| section.starts_with("Vision: ")
+                    || section.starts_with("Tech stack: "))
+        );
+        assert!(!budget.omitted_sections.is_empty());
+        assert!(
+            response
+                .data
+                .project
+                .summary
+                .starts_with("Test project budget-project")
+        );
+        assert!(
+            response
+                .next_steps
+                .iter()
+                .any(|step| step.contains("Context was trimmed"))
+        );
+
+        let error = load_project::run(load_project::Input {
+            project_name: "budget-project".to_string(),
+            max_tokens: Some(20),
+            include: None,
+            active_limit: None,
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+        assert!(error.to_string().contains("at least 100"));
+    });
+}
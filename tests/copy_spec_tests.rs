@@ -0,0 +1,201 @@
+//! Integration tests for clone_project and copy_spec
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::{add_tag, clone_project, copy_spec, set_spec_status};
+use foundry_mcp::types::errors::ErrorCode;
+use foundry_mcp::types::spec::{SpecMetadata, SpecStatus};
+
+async fn specs(project: &str) -> Vec<SpecMetadata> {
+    get_default_foundry()
+        .unwrap()
+        .list_specs(project)
+        .await
+        .unwrap()
+}
+
+#[test]
+fn test_clone_project_copies_documents_and_specs() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("billing-api").await.unwrap();
+        let auth = env
+            .create_bare_spec("billing-api", "auth", "# Auth")
+            .await
+            .unwrap();
+        let ui = env
+            .create_bare_spec(
+                "billing-api",
+                "login_ui",
+                &format!("# UI\n\nSee [[{}]].", auth),
+            )
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        foundry
+            .set_spec_dependencies("billing-api", &ui, std::slice::from_ref(&auth))
+            .await
+            .unwrap();
+        set_spec_status::run(set_spec_status::Input {
+            project_name: "billing-api".to_string(),
+            spec_name: auth.clone(),
+            status: "active".to_string(),
+        })
+        .await
+        .unwrap();
+
+        let response = clone_project::run(clone_project::Input {
+            project_name: "billing-api".to_string(),
+            new_project_name: "payments-api".to_string(),
+            skip_specs: false,
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.project_name, "payments-api");
+        assert_eq!(response.data.specs.len(), 2);
+
+        let source = foundry.load_project("billing-api").await.unwrap();
+        let clone = foundry.load_project("payments-api").await.unwrap();
+        assert_eq!(clone.vision, source.vision);
+        assert_eq!(clone.tech_stack, source.tech_stack);
+
+        let copied = specs("payments-api").await;
+        let copy_of = |name: &str| {
+            let copied_name = &response
+                .data
+                .specs
+                .iter()
+                .find(|spec| spec.source_spec_name == name)
+                .unwrap()
+                .spec_name;
+            copied
+                .iter()
+                .find(|spec| &spec.name == copied_name)
+                .unwrap()
+        };
+        assert_eq!(copy_of(&auth).status, SpecStatus::Active);
+        assert_eq!(copy_of(&ui).depends_on, vec![copy_of(&auth).name.clone()]);
+        let ui_copy = foundry
+            .load_spec("payments-api", &copy_of(&ui).name)
+            .await
+            .unwrap();
+        assert!(
+            ui_copy
+                .content
+                .spec
+                .contains(&format!("[[{}]]", copy_of(&auth).name))
+        );
+        assert_eq!(specs("billing-api").await.len(), 2);
+
+        let response = clone_project::run(clone_project::Input {
+            project_name: "billing-api".to_string(),
+            new_project_name: "docs-only".to_string(),
+            skip_specs: true,
+        })
+        .await
+        .unwrap();
+        assert!(response.data.specs.is_empty());
+        assert!(specs("docs-only").await.is_empty());
+
+        let error = clone_project::run(clone_project::Input {
+            project_name: "billing-api".to_string(),
+            new_project_name: "payments-api".to_string(),
+            skip_specs: false,
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::AlreadyExists);
+    });
+}
+
+#[test]
+fn test_copy_spec_copies_or_moves_between_projects() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("source-app").await.unwrap();
+        env.create_test_project("target-app").await.unwrap();
+        let auth = env
+            .create_bare_spec("source-app", "auth", "# Auth")
+            .await
+            .unwrap();
+        let export = env
+            .create_bare_spec("source-app", "export", "# Export")
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        foundry
+            .set_spec_dependencies("source-app", &export, std::slice::from_ref(&auth))
+            .await
+            .unwrap();
+        add_tag::run(add_tag::Input {
+            project_name: "source-app".to_string(),
+            spec_name: Some(export.clone()),
+            tags: vec!["area:backend".to_string()],
+        })
+        .await
+        .unwrap();
+        set_spec_status::run(set_spec_status::Input {
+            project_name: "source-app".to_string(),
+            spec_name: export.clone(),
+            status: "active".to_string(),
+        })
+        .await
+        .unwrap();
+
+        let response = copy_spec::run(copy_spec::Input {
+            project_name: "source-app".to_string(),
+            spec_name: export.clone(),
+            target_project_name: "target-app".to_string(),
+            feature_name: Some("csv_export".to_string()),
+            delete_source: false,
+        })
+        .await
+        .unwrap();
+        assert!(response.data.spec_name.ends_with("_csv_export"));
+        assert_eq!(response.data.dependencies_dropped, vec![auth.clone()]);
+        let copy = specs("target-app").await.remove(0);
+        assert_eq!(copy.status, SpecStatus::Draft);
+        assert_eq!(copy.tags, vec!["area:backend"]);
+        assert!(copy.depends_on.is_empty());
+        assert_eq!(copy.task_counts.completed, 1);
+        assert_eq!(specs("source-app").await.len(), 2);
+
+        let response = copy_spec::run(copy_spec::Input {
+            project_name: "source-app".to_string(),
+            spec_name: auth.clone(),
+            target_project_name: "target-app".to_string(),
+            feature_name: None,
+            delete_source: true,
+        })
+        .await
+        .unwrap();
+        assert!(response.data.source_deleted);
+        assert_eq!(response.data.dependents, vec![export.clone()]);
+        assert_eq!(specs("source-app").await.len(), 1);
+        assert_eq!(specs("target-app").await.len(), 2);
+
+        let error = copy_spec::run(copy_spec::Input {
+            project_name: "source-app".to_string(),
+            spec_name: export.clone(),
+            target_project_name: "source-app".to_string(),
+            feature_name: None,
+            delete_source: true,
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+
+        let error = copy_spec::run(copy_spec::Input {
+            project_name: "source-app".to_string(),
+            spec_name: export,
+            target_project_name: "missing-app".to_string(),
+            feature_name: None,
+            delete_source: false,
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ProjectNotFound);
+    });
+}
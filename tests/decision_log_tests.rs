@@ -0,0 +1,188 @@
+//! Integration tests for the project decision log
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::ops::{
+    add_decision, list_decisions, load_project, update_decisions, update_spec,
+};
+use foundry_mcp::types::errors::ErrorCode;
+
+fn decision(project: &str, title: &str, spec_name: Option<String>) -> add_decision::Input {
+    add_decision::Input {
+        project_name: project.to_string(),
+        title: title.to_string(),
+        context: format!("Context for {}", title),
+        decision: format!("We decided: {}", title),
+        consequences: None,
+        status: None,
+        spec_name,
+    }
+}
+
+#[test]
+fn test_decisions_are_recorded_listed_and_loaded_with_the_project() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("adr-app").await.unwrap();
+        env.create_test_spec("adr-app", "sessions", "Sessions")
+            .await
+            .unwrap();
+        let spec_name = foundry_mcp::core::foundry::get_default_foundry()
+            .unwrap()
+            .list_specs("adr-app")
+            .await
+            .unwrap()[0]
+            .name
+            .clone();
+
+        let first = add_decision::run(decision(
+            "adr-app",
+            "Store sessions in Redis",
+            Some(spec_name.clone()),
+        ))
+        .await
+        .unwrap();
+        assert_eq!(first.data.decision.id.as_deref(), Some("ADR-001"));
+        assert_eq!(first.data.decision.status.as_deref(), Some("accepted"));
+        let mut proposed = decision("adr-app", "Use JWTs", None);
+        proposed.status = Some("Proposed".to_string());
+        let second = add_decision::run(proposed).await.unwrap();
+        assert_eq!(second.data.decision.id.as_deref(), Some("ADR-002"));
+        assert_eq!(second.data.total_decisions, 2);
+
+        let raw = std::fs::read_to_string(env.foundry_dir().join("adr-app/decisions.md")).unwrap();
+        assert!(raw.starts_with("# Decision Log"));
+
+        let listed = list_decisions::run(list_decisions::Input {
+            project_name: "adr-app".to_string(),
+            status: Some("proposed".to_string()),
+            spec_name: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(listed.data.total_decisions, 2);
+        assert_eq!(listed.data.decisions.len(), 1);
+        assert_eq!(listed.data.decisions[0].title, "Use JWTs");
+        let for_spec = list_decisions::run(list_decisions::Input {
+            project_name: "adr-app".to_string(),
+            status: None,
+            spec_name: Some(spec_name.clone()),
+        })
+        .await
+        .unwrap();
+        assert_eq!(for_spec.data.decisions.len(), 1);
+        assert_eq!(
+            for_spec.data.decisions[0].context,
+            "Context for Store sessions in Redis"
+        );
+
+        let loaded = load_project::run(load_project::Input {
+            project_name: "adr-app".to_string(),
+            max_tokens: None,
+            include: None,
+            active_limit: None,
+        })
+        .await
+        .unwrap();
+        assert!(
+            loaded
+                .data
+                .project
+                .decisions
+                .contains("## ADR-002: Use JWTs")
+        );
+
+        // Unknown statuses and specs are rejected before anything is written
+        let mut bad = decision("adr-app", "Bad", None);
+        bad.status = Some("maybe".to_string());
+        let error = add_decision::run(bad).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+        let error = add_decision::run(decision(
+            "adr-app",
+            "Bad",
+            Some("20200101_000000_missing".to_string()),
+        ))
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::SpecNotFound);
+    });
+}
+
+#[test]
+fn test_update_decisions_edits_the_log_with_section_commands() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("adr-edit").await.unwrap();
+        env.create_test_spec("adr-edit", "auth", "Auth")
+            .await
+            .unwrap();
+        add_decision::run(decision("adr-edit", "Use JWTs", None))
+            .await
+            .unwrap();
+
+        let commands = serde_json::json!([{
+            "target": "decisions",
+            "command": "replace_in_section",
+            "selector": {"type": "text_in_section", "section": "## ADR-001: Use JWTs", "text": "- **Status**: accepted"},
+            "content": "- **Status**: superseded"
+        }]);
+        let updated = update_decisions::run(update_decisions::Input {
+            project_name: "adr-edit".to_string(),
+            commands_json: commands.to_string(),
+        })
+        .await
+        .unwrap();
+        assert_eq!(updated.data.applied_count, 1);
+        assert!(updated.data.errors.is_none());
+        let listed = list_decisions::run(list_decisions::Input {
+            project_name: "adr-edit".to_string(),
+            status: Some("superseded".to_string()),
+            spec_name: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(listed.data.decisions.len(), 1);
+
+        // Spec targets go to update_spec, and the decision log is not a spec file
+        let commands = serde_json::json!([{
+            "target": "notes",
+            "command": "append_to_section",
+            "selector": {"type": "section", "value": "## Notes"},
+            "content": "x"
+        }]);
+        let error = update_decisions::run(update_decisions::Input {
+            project_name: "adr-edit".to_string(),
+            commands_json: commands.to_string(),
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+
+        let spec_name = foundry_mcp::core::foundry::get_default_foundry()
+            .unwrap()
+            .list_specs("adr-edit")
+            .await
+            .unwrap()[0]
+            .name
+            .clone();
+        let commands = serde_json::json!([{
+            "target": "decisions",
+            "command": "append_to_section",
+            "selector": {"type": "section", "value": "## ADR-001: Use JWTs"},
+            "content": "x"
+        }]);
+        let response = update_spec::run(update_spec::Input {
+            project_name: "adr-edit".to_string(),
+            spec_name,
+            commands_json: commands.to_string(),
+            expected_version: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+        let errors = response.data.errors.unwrap();
+        assert_eq!(errors[0].code, ErrorCode::ValidationFailed);
+        assert!(errors[0].message.contains("update_decisions"));
+    });
+}
@@ -0,0 +1,101 @@
+//! Integration tests for diffing specs against other specs and their history
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::{diff_spec, update_spec};
+use foundry_mcp::types::errors::ErrorCode;
+use foundry_mcp::types::responses::SectionChange;
+
+fn input(spec_name: &str) -> diff_spec::Input {
+    diff_spec::Input {
+        project_name: "diff-app".to_string(),
+        spec_name: spec_name.to_string(),
+        other_spec_name: None,
+        other_project_name: None,
+        backup_id: None,
+        history_steps: None,
+    }
+}
+
+#[test]
+fn test_diff_spec_against_history_and_other_spec() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("diff-app").await.unwrap();
+        env.create_test_spec("diff-app", "auth", "Authentication")
+            .await
+            .unwrap();
+        env.create_test_spec("diff-app", "billing", "Billing")
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        let specs = foundry.list_specs("diff-app").await.unwrap();
+        let auth = specs
+            .iter()
+            .find(|spec| spec.name.ends_with("_auth"))
+            .unwrap()
+            .name
+            .clone();
+
+        update_spec::run(update_spec::Input {
+            project_name: "diff-app".to_string(),
+            spec_name: auth.clone(),
+            commands_json: serde_json::json!([{
+                "target": "tasks",
+                "command": "upsert_task",
+                "selector": {"type": "task_text", "value": "Write docs"},
+                "content": "- [ ] Write docs"
+            }])
+            .to_string(),
+            expected_version: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+
+        let diff = diff_spec::run(diff_spec::Input {
+            history_steps: Some(1),
+            ..input("auth")
+        })
+        .await
+        .unwrap()
+        .data;
+        assert_eq!(diff.spec_name, auth);
+        assert!(diff.changed);
+        assert!(!diff.files[0].changed);
+        let tasks = &diff.files[2];
+        assert_eq!(tasks.file, "task-list.md");
+        assert_eq!(tasks.lines_added - tasks.lines_removed, 1);
+        assert!(
+            tasks
+                .sections
+                .iter()
+                .all(|section| section.change == SectionChange::Modified)
+        );
+        assert!(diff.summary.ends_with("spec.md and notes.md unchanged"));
+        assert!(diff.unified_diff.contains("+- [ ] Write docs"));
+
+        let diff = diff_spec::run(diff_spec::Input {
+            other_spec_name: Some("billing".to_string()),
+            ..input("auth")
+        })
+        .await
+        .unwrap()
+        .data;
+        assert!(diff.compared_with.ends_with("_billing"));
+        assert!(diff.files[0].changed);
+        assert!(diff.unified_diff.contains("--- a/spec.md"));
+
+        let error = diff_spec::run(diff_spec::Input {
+            history_steps: Some(2),
+            ..input("auth")
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+        let error = diff_spec::run(input("auth")).await.unwrap_err();
+        assert!(error.to_string().contains("exactly one"));
+    });
+}
@@ -0,0 +1,88 @@
+//! Integration tests for activity digests
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::{digest, update_spec};
+use foundry_mcp::types::errors::ErrorCode;
+
+fn input(since: &str) -> digest::Input {
+    digest::Input {
+        since: Some(since.to_string()),
+        until: None,
+        project_name: None,
+    }
+}
+
+#[test]
+fn test_digest_reports_specs_tasks_and_notes() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("digest-app").await.unwrap();
+        env.create_test_project("quiet-app").await.unwrap();
+        env.create_test_spec("digest-app", "auth", "Authentication")
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        let spec_name = foundry.list_specs("digest-app").await.unwrap()[0]
+            .name
+            .clone();
+
+        update_spec::run(update_spec::Input {
+            project_name: "digest-app".to_string(),
+            spec_name: spec_name.clone(),
+            commands_json: serde_json::json!([
+                {
+                    "target": "tasks",
+                    "command": "set_task_status",
+                    "selector": {"type": "task_text", "value": "Initialize configuration"},
+                    "status": "done"
+                },
+                {
+                    "target": "notes",
+                    "command": "append_to_section",
+                    "selector": {"type": "section", "value": "## Design Decisions"},
+                    "content": "- **Tokens**: JWT with short expiry"
+                }
+            ])
+            .to_string(),
+            expected_version: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+
+        let digest = digest::run(input("7d")).await.unwrap().data;
+        assert_eq!(digest.projects_checked, 2);
+        assert_eq!(
+            (
+                digest.specs_created,
+                digest.tasks_completed,
+                digest.notes_added
+            ),
+            (1, 1, 1)
+        );
+        assert_eq!(digest.projects.len(), 1);
+        let project = &digest.projects[0];
+        assert_eq!(project.project_name, "digest-app");
+        assert_eq!(project.specs_created, [spec_name]);
+        assert_eq!(project.tasks_completed[0].task, "Initialize configuration");
+        assert_eq!(project.notes_added[0].lines_added, 1);
+        assert!(digest.markdown.contains("## digest-app"));
+        assert!(digest.markdown.contains("- [x] Initialize configuration"));
+
+        // A range ending before the activity is empty
+        let earlier = digest::run(digest::Input {
+            until: Some("2000-01-01".to_string()),
+            ..input("1999-01-01")
+        })
+        .await
+        .unwrap()
+        .data;
+        assert!(earlier.projects.is_empty());
+
+        let error = digest::run(input("last week")).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+    });
+}
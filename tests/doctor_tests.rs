@@ -0,0 +1,137 @@
+//! Integration tests for `foundry doctor`
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::cli::args::DoctorArgs;
+use foundry_mcp::cli::commands::doctor;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::types::responses::{DoctorResponse, DoctorStatus};
+
+async fn run_doctor(fix: bool) -> DoctorResponse {
+    let output = doctor::execute(DoctorArgs { fix, json: true })
+        .await
+        .unwrap();
+    serde_json::from_str(&output).unwrap()
+}
+
+#[test]
+fn test_doctor_reports_and_repairs_data_problems() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("doc-app").await.unwrap();
+        env.create_test_spec("doc-app", "auth", "Authentication")
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        let spec_name = foundry.list_specs("doc-app").await.unwrap()[0].name.clone();
+        let specs_dir = env.foundry_dir().join("doc-app/specs");
+        let spec_dir = specs_dir.join(&spec_name);
+        std::fs::remove_file(spec_dir.join("notes.md")).unwrap();
+        std::fs::write(spec_dir.join("metadata.json"), "{not json").unwrap();
+        std::fs::write(spec_dir.join("spec.md.tmp"), "partial").unwrap();
+        std::fs::create_dir_all(specs_dir.join("20250101_000000_empty")).unwrap();
+        std::fs::create_dir_all(specs_dir.join("20251399_000000_bad_date")).unwrap();
+        std::fs::write(
+            specs_dir.join("20251399_000000_bad_date/spec.md"),
+            "# Bad date",
+        )
+        .unwrap();
+
+        let report = run_doctor(false).await;
+        let problems: Vec<(&str, DoctorStatus, bool)> = report
+            .checks
+            .iter()
+            .filter(|check| check.category == "data" && check.status != DoctorStatus::Ok)
+            .map(|check| (check.message.as_str(), check.status, check.fixable))
+            .collect();
+        assert!(problems.iter().any(|(message, status, fixable)| {
+            message.contains("no valid <id>_<feature> name")
+                && *status == DoctorStatus::Error
+                && !fixable
+        }));
+        assert!(
+            problems
+                .iter()
+                .any(|(message, _, fixable)| *message == "Empty spec directory" && *fixable)
+        );
+        assert!(
+            problems
+                .iter()
+                .any(|(message, _, fixable)| message.contains("no notes.md") && *fixable)
+        );
+        assert!(
+            problems
+                .iter()
+                .any(|(message, _, fixable)| message.contains("not valid JSON") && *fixable)
+        );
+        assert!(
+            problems
+                .iter()
+                .any(|(message, _, fixable)| message.contains("interrupted write") && *fixable)
+        );
+        assert_eq!(report.fixed, 0);
+        assert!(!spec_dir.join("notes.md").exists());
+
+        let fixed = run_doctor(true).await;
+        let repaired = fixed
+            .checks
+            .iter()
+            .filter(|check| check.category == "data" && check.fixed)
+            .count();
+        assert_eq!(repaired, 4);
+        assert!(spec_dir.join("notes.md").exists());
+        assert!(spec_dir.join("metadata.json.corrupt").exists());
+        assert!(!spec_dir.join("spec.md.tmp").exists());
+        assert!(!specs_dir.join("20250101_000000_empty").exists());
+
+        // Only the misnamed directory is left, and it needs a person
+        let after = run_doctor(false).await;
+        let data_problems: Vec<_> = after
+            .checks
+            .iter()
+            .filter(|check| check.category == "data" && check.status != DoctorStatus::Ok)
+            .collect();
+        assert_eq!(data_problems.len(), 1);
+        assert!(
+            data_problems[0]
+                .subject
+                .ends_with("20251399_000000_bad_date")
+        );
+    });
+}
+
+#[test]
+fn test_doctor_checks_config_and_linear_credentials() {
+    let env = TestEnvironment::new().unwrap();
+    temp_env::with_var("LINEAR_API_KEY", None::<&str>, || {
+        env.with_env_async(|| async {
+            let foundry_dir = env.foundry_dir();
+            std::fs::create_dir_all(&foundry_dir).unwrap();
+            std::fs::write(
+                foundry_dir.join("config.toml"),
+                "[linear]\nteam = \"ENG\"\n",
+            )
+            .unwrap();
+
+            let report = run_doctor(false).await;
+            let linear = report
+                .checks
+                .iter()
+                .find(|check| check.category == "linear")
+                .unwrap();
+            assert_eq!(linear.status, DoctorStatus::Warning);
+            assert!(linear.fix.as_deref().unwrap().contains("LINEAR_API_KEY"));
+
+            std::fs::write(foundry_dir.join("config.toml"), "[linar]\nteam = \"ENG\"\n").unwrap();
+            let report = run_doctor(false).await;
+            let config = report
+                .checks
+                .iter()
+                .find(|check| check.category == "config")
+                .unwrap();
+            assert_eq!(config.status, DoctorStatus::Error);
+            assert!(report.errors >= 1);
+        })
+    });
+}
@@ -4,7 +4,8 @@ mod common;
 
 use common::test_utils::TestEnvironment;
 use foundry_mcp::cli::args::UpdateSpecArgs;
-use foundry_mcp::core::ops::{create_project, create_spec, update_spec};
+use foundry_mcp::core::ops::{create_project, create_spec, load_spec, update_spec};
+use foundry_mcp::types::ErrorCode;
 
 fn commands_json(cmds: serde_json::Value) -> String {
     serde_json::to_string(&cmds).unwrap()
@@ -31,6 +32,8 @@ fn test_set_task_status_done() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -63,12 +66,16 @@ fn test_set_task_status_done() {
             project_name: "ec-project".to_string(),
             spec_name: spec_name.clone(),
             commands: commands_json(cmds),
+            expected_version: None,
+            dry_run: false,
         };
 
         let resp = update_spec::run(update_spec::Input {
             project_name: args.project_name,
             spec_name: args.spec_name,
             commands_json: args.commands,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -104,6 +111,8 @@ fn test_upsert_task_idempotent() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -124,11 +133,15 @@ fn test_upsert_task_idempotent() {
                 project_name: "ec-upsert".to_string(),
                 spec_name: spec_name.clone(),
                 commands: commands_json(cmd.clone()),
+                expected_version: None,
+                dry_run: false,
             };
             let _ = update_spec::run(update_spec::Input {
                 project_name: args.project_name,
                 spec_name: args.spec_name,
                 commands_json: args.commands,
+                expected_version: None,
+                dry_run: false,
             })
             .await
             .unwrap();
@@ -168,6 +181,8 @@ fn test_append_to_spec_section() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -195,11 +210,15 @@ fn test_append_to_spec_section() {
             project_name: "ec-append".to_string(),
             spec_name: spec_name.clone(),
             commands: commands_json(cmds),
+            expected_version: None,
+            dry_run: false,
         };
         let _ = update_spec::run(update_spec::Input {
             project_name: args.project_name,
             spec_name: args.spec_name,
             commands_json: args.commands,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -221,11 +240,15 @@ fn test_append_to_spec_section() {
             project_name: "ec-append".to_string(),
             spec_name: spec_name.clone(),
             commands: commands_json(cmds2),
+            expected_version: None,
+            dry_run: false,
         };
         let _ = update_spec::run(update_spec::Input {
             project_name: args2.project_name,
             spec_name: args2.spec_name,
             commands_json: args2.commands,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -233,3 +256,585 @@ fn test_append_to_spec_section() {
         assert_eq!(updated2.matches("- Item B").count(), 1);
     });
 }
+
+#[test]
+fn test_selector_errors_carry_error_codes() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let project_args = env.create_project_args("ec-codes");
+        create_project::run(create_project::Input {
+            project_name: project_args.project_name,
+            vision: project_args.vision,
+            tech_stack: project_args.tech_stack,
+            summary: project_args.summary,
+        })
+        .await
+        .unwrap();
+        let spec_args = env.create_spec_args("ec-codes", "feature");
+        let spec_name = create_spec::run(create_spec::Input {
+            project_name: spec_args.project_name,
+            feature_name: spec_args.feature_name,
+            spec: spec_args.spec,
+            notes: spec_args.notes,
+            tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
+        })
+        .await
+        .unwrap()
+        .data
+        .spec_name;
+
+        let task_file = env
+            .foundry_dir()
+            .join("ec-codes")
+            .join("specs")
+            .join(&spec_name)
+            .join("task-list.md");
+        std::fs::write(&task_file, "## Tasks\n- [ ] Write docs\n- [ ] Write docs\n").unwrap();
+
+        let cmds = serde_json::json!([
+            {
+                "target": "tasks",
+                "command": "set_task_status",
+                "selector": {"type": "task_text", "value": "Write docs"},
+                "status": "done"
+            },
+            {
+                "target": "tasks",
+                "command": "set_task_status",
+                "selector": {"type": "task_text", "value": "Ship it"},
+                "status": "done"
+            }
+        ]);
+        let resp = update_spec::run(update_spec::Input {
+            project_name: "ec-codes".to_string(),
+            spec_name: spec_name.clone(),
+            commands_json: commands_json(cmds),
+            expected_version: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+
+        let errors = resp.data.errors.unwrap();
+        let codes: Vec<_> = errors.iter().map(|e| e.code).collect();
+        assert_eq!(
+            codes,
+            vec![ErrorCode::SelectorAmbiguous, ErrorCode::SelectorNotFound]
+        );
+
+        let missing = update_spec::run(update_spec::Input {
+            project_name: "ec-codes".to_string(),
+            spec_name: "20240101_000000_missing".to_string(),
+            commands_json: "[]".to_string(),
+            expected_version: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&missing), ErrorCode::SpecNotFound);
+    });
+}
+
+#[test]
+fn test_expected_version_detects_concurrent_edits() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("ec-versions").await.unwrap();
+        env.create_test_spec("ec-versions", "feature", "Versioned feature")
+            .await
+            .unwrap();
+        let spec_name = foundry_mcp::core::foundry::get_default_foundry()
+            .unwrap()
+            .list_specs("ec-versions")
+            .await
+            .unwrap()
+            .remove(0)
+            .name;
+
+        let loaded =
+            foundry_mcp::core::ops::load_spec::run(foundry_mcp::core::ops::load_spec::Input {
+                project_name: "ec-versions".to_string(),
+                spec_name: Some(spec_name.clone()),
+                max_tokens: None,
+                part: None,
+                files: None,
+            })
+            .await
+            .unwrap();
+        let read_version = loaded.data.spec_content.unwrap().versions.tasks;
+
+        let update = |task: &str, expected_version: Option<String>| {
+            update_spec::run(update_spec::Input {
+                project_name: "ec-versions".to_string(),
+                spec_name: spec_name.clone(),
+                commands_json: commands_json(serde_json::json!([{
+                    "target": "tasks",
+                    "command": "upsert_task",
+                    "selector": {"type": "task_text", "value": task},
+                    "content": format!("- [ ] {}", task)
+                }])),
+                expected_version,
+                dry_run: false,
+            })
+        };
+
+        // First session writes against the version it read
+        let first = update("Add rate limiting", Some(read_version.clone()))
+            .await
+            .unwrap();
+        assert_ne!(first.data.versions.tasks, read_version);
+
+        // Second session still holds the old version
+        let error = update("Add audit logging", Some(read_version.clone()))
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::BackendConflict);
+        let message = error.to_string();
+        assert!(message.contains("task-list.md"));
+        assert!(message.contains(&first.data.versions.tasks));
+        assert!(message.contains("+- [ ] Add rate limiting"));
+
+        // Retrying with the current version succeeds
+        update("Add audit logging", Some(first.data.versions.tasks.clone()))
+            .await
+            .unwrap();
+
+        let error = update_spec::run(update_spec::Input {
+            project_name: "ec-versions".to_string(),
+            spec_name: spec_name.clone(),
+            commands_json: commands_json(serde_json::json!([
+                {"target": "tasks", "command": "upsert_task",
+                 "selector": {"type": "task_text", "value": "One"}, "content": "- [ ] One"},
+                {"target": "notes", "command": "append_to_section",
+                 "selector": {"type": "section", "value": "## Design Decisions"}, "content": "More"}
+            ])),
+            expected_version: Some(read_version),
+            dry_run: false,
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+    });
+}
+
+#[test]
+fn test_task_metadata_tokens_in_edit_commands_and_load_spec() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("ec-meta").await.unwrap();
+        let spec_args = env.create_spec_args("ec-meta", "feature");
+        let spec_name = create_spec::run(create_spec::Input {
+            project_name: spec_args.project_name,
+            feature_name: spec_args.feature_name,
+            spec: spec_args.spec,
+            notes: spec_args.notes,
+            tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
+        })
+        .await
+        .unwrap()
+        .data
+        .spec_name;
+        let task_file = env
+            .foundry_dir()
+            .join("ec-meta")
+            .join("specs")
+            .join(&spec_name)
+            .join("task-list.md");
+        std::fs::write(
+            &task_file,
+            "## Tasks\n- [ ] Add login endpoint @alice ~2d\n- [ ] Write docs completed:2025-06-01\n",
+        )
+        .unwrap();
+
+        // Selectors match without the metadata; upsert replaces it
+        let cmds = serde_json::json!([
+            {
+                "target": "tasks",
+                "command": "set_task_status",
+                "selector": {"type": "task_text", "value": "Add login endpoint"},
+                "status": "done"
+            },
+            {
+                "target": "tasks",
+                "command": "upsert_task",
+                "selector": {"type": "task_text", "value": "Add login endpoint"},
+                "content": "- [ ] Add login endpoint @bob ~3d due:2025-07-01"
+            },
+            {
+                "target": "tasks",
+                "command": "upsert_task",
+                "selector": {"type": "task_text", "value": "Write docs"},
+                "content": "- [ ] Write docs @carol"
+            }
+        ]);
+        let resp = update_spec::run(update_spec::Input {
+            project_name: "ec-meta".to_string(),
+            spec_name: spec_name.clone(),
+            commands_json: commands_json(cmds),
+            expected_version: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+        assert_eq!(resp.data.applied_count, 3);
+
+        let updated = std::fs::read_to_string(&task_file).unwrap();
+        assert!(updated.contains("- [x] Add login endpoint @bob ~3d due:2025-07-01"));
+        assert!(updated.contains("- [ ] Write docs @carol completed:2025-06-01"));
+
+        let loaded = load_spec::run(load_spec::Input {
+            project_name: "ec-meta".to_string(),
+            spec_name: Some(spec_name),
+            max_tokens: None,
+            part: None,
+            files: None,
+        })
+        .await
+        .unwrap();
+        let task = &loaded.data.tasks[0];
+        assert_eq!(task.title, "Add login endpoint");
+        assert!(task.done);
+        assert_eq!(task.assignees, vec!["bob".to_string()]);
+        assert_eq!(task.estimate.as_deref(), Some("3d"));
+        assert_eq!(task.due.as_deref(), Some("2025-07-01"));
+        assert_eq!(loaded.data.tasks[1].completed_at.as_deref(), Some("2025-06-01"));
+    });
+}
+
+#[test]
+fn test_dry_run_returns_diffs_without_writing() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let project_args = env.create_project_args("dry-run-project");
+        create_project::run(create_project::Input {
+            project_name: project_args.project_name,
+            vision: project_args.vision,
+            tech_stack: project_args.tech_stack,
+            summary: project_args.summary,
+        })
+        .await
+        .unwrap();
+        let spec_args = env.create_spec_args("dry-run-project", "feature");
+        let spec_name = create_spec::run(create_spec::Input {
+            project_name: spec_args.project_name,
+            feature_name: spec_args.feature_name,
+            spec: spec_args.spec,
+            notes: spec_args.notes,
+            tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
+        })
+        .await
+        .unwrap()
+        .data
+        .spec_name;
+
+        let task_file = env
+            .foundry_dir()
+            .join("dry-run-project/specs")
+            .join(&spec_name)
+            .join("task-list.md");
+        let original = "## Tasks\n- [ ] Implement OAuth2 integration\n";
+        std::fs::write(&task_file, original).unwrap();
+
+        let resp = update_spec::run(update_spec::Input {
+            project_name: "dry-run-project".to_string(),
+            spec_name: spec_name.clone(),
+            commands_json: commands_json(serde_json::json!([
+                {
+                    "target": "tasks",
+                    "command": "set_task_status",
+                    "selector": {"type": "task_text", "value": "Implement OAuth2 integration"},
+                    "status": "done"
+                },
+                {
+                    "target": "tasks",
+                    "command": "upsert_task",
+                    "selector": {"type": "task_text", "value": "Write docs"},
+                    "content": "- [ ] Write docs"
+                }
+            ])),
+            expected_version: None,
+            dry_run: true,
+        })
+        .await
+        .unwrap();
+
+        assert!(resp.data.dry_run);
+        assert_eq!(resp.data.applied_count, 2);
+        let preview = resp.data.preview_diff.unwrap();
+        assert!(preview.contains("--- a/task-list.md"));
+        assert!(preview.contains("-- [ ] Implement OAuth2 integration"));
+        assert!(preview.contains("+- [x] Implement OAuth2 integration"));
+        assert!(preview.contains("+- [ ] Write docs"));
+
+        let command_diffs = resp.data.command_diffs.unwrap();
+        assert_eq!(command_diffs.len(), 2);
+        assert!(command_diffs[0].diff.contains("+- [x] Implement OAuth2"));
+        assert!(!command_diffs[0].diff.contains("Write docs"));
+        assert!(command_diffs[1].diff.contains("+- [ ] Write docs"));
+
+        assert_eq!(std::fs::read_to_string(&task_file).unwrap(), original);
+    });
+}
+
+#[test]
+fn test_phase_commands_and_phase_stats() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("ec-phases").await.unwrap();
+        let spec_args = env.create_spec_args("ec-phases", "feature");
+        let spec_name = create_spec::run(create_spec::Input {
+            project_name: spec_args.project_name,
+            feature_name: spec_args.feature_name,
+            spec: spec_args.spec,
+            notes: spec_args.notes,
+            tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
+        })
+        .await
+        .unwrap()
+        .data
+        .spec_name;
+        let task_file = env
+            .foundry_dir()
+            .join("ec-phases")
+            .join("specs")
+            .join(&spec_name)
+            .join("task-list.md");
+        std::fs::write(
+            &task_file,
+            "# Tasks\n\n## Phase 1: Foundations\n- [x] Design schema\n- [ ] Ship rollout flag\n\n## Notes\n- [ ] Loose task\n",
+        )
+        .unwrap();
+
+        let update = |cmds: serde_json::Value| {
+            update_spec::run(update_spec::Input {
+                project_name: "ec-phases".to_string(),
+                spec_name: spec_name.clone(),
+                commands_json: commands_json(cmds),
+                expected_version: None,
+                dry_run: false,
+            })
+        };
+        let resp = update(serde_json::json!([
+            {
+                "target": "tasks",
+                "command": "add_phase",
+                "selector": {"type": "section", "value": "Phase 2: Rollout"},
+                "content": "- [ ] Enable for beta users"
+            },
+            {
+                "target": "tasks",
+                "command": "move_task_to_phase",
+                "selector": {"type": "task_text", "value": "Ship rollout flag"},
+                "content": "Phase 2"
+            },
+            {
+                "target": "tasks",
+                "command": "move_task_to_phase",
+                "selector": {"type": "task_text", "value": "Loose task"},
+                "content": "## Phase 1: Foundations"
+            }
+        ]))
+        .await
+        .unwrap();
+        assert_eq!(resp.data.applied_count, 3);
+        assert!(resp.data.errors.is_none());
+        assert_eq!(
+            std::fs::read_to_string(&task_file).unwrap(),
+            "# Tasks\n\n## Phase 1: Foundations\n- [x] Design schema\n- [ ] Loose task\n\n## Notes\n\n## Phase 2: Rollout\n- [ ] Enable for beta users\n- [ ] Ship rollout flag"
+        );
+
+        // Re-running is idempotent; unknown phases and bad names are errors
+        let resp = update(serde_json::json!([
+            {
+                "target": "tasks",
+                "command": "add_phase",
+                "selector": {"type": "section", "value": "Phase 2"}
+            },
+            {
+                "target": "tasks",
+                "command": "move_task_to_phase",
+                "selector": {"type": "task_text", "value": "Ship rollout flag"},
+                "content": "Phase 2"
+            }
+        ]))
+        .await
+        .unwrap();
+        assert_eq!(resp.data.skipped_idempotent_count, 2);
+        let errors = update(serde_json::json!([
+            {
+                "target": "tasks",
+                "command": "move_task_to_phase",
+                "selector": {"type": "task_text", "value": "Design schema"},
+                "content": "Phase 3"
+            },
+            {
+                "target": "tasks",
+                "command": "add_phase",
+                "selector": {"type": "section", "value": "Cleanup"}
+            }
+        ]))
+        .await
+        .unwrap()
+        .data
+        .errors
+        .unwrap();
+        let codes: Vec<_> = errors.iter().map(|e| e.code).collect();
+        assert_eq!(
+            codes,
+            vec![ErrorCode::SelectorNotFound, ErrorCode::ValidationFailed]
+        );
+
+        let loaded = load_spec::run(load_spec::Input {
+            project_name: "ec-phases".to_string(),
+            spec_name: Some(spec_name),
+            max_tokens: None,
+            part: None,
+            files: None,
+        })
+        .await
+        .unwrap()
+        .data;
+        let phases: Vec<(&str, usize, usize)> = loaded
+            .phases
+            .iter()
+            .map(|phase| (phase.name.as_str(), phase.done_tasks, phase.total_tasks))
+            .collect();
+        assert_eq!(
+            phases,
+            vec![("Phase 1: Foundations", 1, 2), ("Phase 2: Rollout", 0, 2)]
+        );
+        assert_eq!(loaded.tasks[1].phase.as_deref(), Some("Phase 1: Foundations"));
+    });
+}
+
+#[test]
+fn test_bulk_task_status_by_pattern_and_phase() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("ec-bulk").await.unwrap();
+        let spec_args = env.create_spec_args("ec-bulk", "feature");
+        let spec_name = create_spec::run(create_spec::Input {
+            project_name: spec_args.project_name,
+            feature_name: spec_args.feature_name,
+            spec: spec_args.spec,
+            notes: spec_args.notes,
+            tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
+        })
+        .await
+        .unwrap()
+        .data
+        .spec_name;
+        let task_file = env
+            .foundry_dir()
+            .join("ec-bulk")
+            .join("specs")
+            .join(&spec_name)
+            .join("task-list.md");
+        std::fs::write(
+            &task_file,
+            "# Tasks\n\n## Phase 1: API\n- [ ] Add users endpoint @alice\n  - [ ] Add orders endpoint\n- [ ] Write docs\n\n## Phase 2: Rollout\n- [ ] Add metrics endpoint\n- [ ] Enable flag\n",
+        )
+        .unwrap();
+
+        let update = |cmds: serde_json::Value| {
+            update_spec::run(update_spec::Input {
+                project_name: "ec-bulk".to_string(),
+                spec_name: spec_name.clone(),
+                commands_json: commands_json(cmds),
+                expected_version: None,
+                dry_run: false,
+            })
+        };
+        let resp = update(serde_json::json!([
+            {
+                "target": "tasks",
+                "command": "set_tasks_status_matching",
+                "selector": {"type": "task_pattern", "value": "add * endpoint", "section": "Phase 1"},
+                "status": "done"
+            },
+            {
+                "target": "tasks",
+                "command": "set_tasks_status_matching",
+                "selector": {"type": "task_pattern", "value": "/^Enable/"},
+                "status": "done"
+            }
+        ]))
+        .await
+        .unwrap();
+        assert_eq!(resp.data.applied_count, 3);
+        // Checked-off tasks are stamped with today's date
+        let stamp = format!("completed:{}", chrono::Utc::now().date_naive());
+        assert_eq!(
+            std::fs::read_to_string(&task_file).unwrap(),
+            format!(
+                "# Tasks\n\n## Phase 1: API\n- [x] Add users endpoint @alice {stamp}\n  - [x] Add orders endpoint {stamp}\n- [ ] Write docs\n\n## Phase 2: Rollout\n- [ ] Add metrics endpoint\n- [x] Enable flag {stamp}"
+            )
+        );
+
+        // complete_phase checks off the rest of a phase; re-running is idempotent
+        let resp = update(serde_json::json!([
+            {
+                "target": "tasks",
+                "command": "complete_phase",
+                "selector": {"type": "section", "value": "Phase 1"}
+            }
+        ]))
+        .await
+        .unwrap();
+        assert_eq!(resp.data.applied_count, 1);
+        let resp = update(serde_json::json!([
+            {
+                "target": "tasks",
+                "command": "complete_phase",
+                "selector": {"type": "section", "value": "## Phase 1: API"}
+            }
+        ]))
+        .await
+        .unwrap();
+        assert_eq!(resp.data.skipped_idempotent_count, 1);
+
+        let errors = update(serde_json::json!([
+            {
+                "target": "tasks",
+                "command": "set_tasks_status_matching",
+                "selector": {"type": "task_pattern", "value": "Deploy *"},
+                "status": "done"
+            },
+            {
+                "target": "tasks",
+                "command": "set_tasks_status_matching",
+                "selector": {"type": "task_pattern", "value": "/(unclosed/"},
+                "status": "todo"
+            },
+            {
+                "target": "tasks",
+                "command": "complete_phase",
+                "selector": {"type": "section", "value": "Phase 3"}
+            }
+        ]))
+        .await
+        .unwrap()
+        .data
+        .errors
+        .unwrap();
+        let codes: Vec<_> = errors.iter().map(|e| e.code).collect();
+        assert_eq!(
+            codes,
+            vec![
+                ErrorCode::SelectorNotFound,
+                ErrorCode::ValidationFailed,
+                ErrorCode::SelectorNotFound
+            ]
+        );
+    });
+}
@@ -0,0 +1,274 @@
+//! Integration tests for encryption of project and spec files at rest
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::cli::args::{EncryptionArgs, EncryptionCommand};
+use foundry_mcp::cli::commands::encryption;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::delete_spec;
+use foundry_mcp::core::{activity, audit, history};
+use foundry_mcp::mcp::wire_log::{self, WireLog};
+use foundry_mcp::types::errors::ErrorCode;
+use foundry_mcp::types::responses::{BackupListResponse, EncryptionResponse};
+use foundry_mcp::types::spec::{SpecContentData, SpecSummary};
+use serde_json::json;
+
+async fn encryption_json(command: EncryptionCommand) -> EncryptionResponse {
+    let output = encryption::execute(EncryptionArgs {
+        command,
+        json: true,
+    })
+    .await
+    .unwrap();
+    serde_json::from_str(&output).unwrap()
+}
+
+#[test]
+fn test_content_is_encrypted_on_disk_and_read_back_transparently() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let foundry_dir = env.foundry_dir();
+        std::fs::create_dir_all(&foundry_dir).unwrap();
+        env.create_test_project("plain-app").await.unwrap();
+        // Journals written before the key was configured
+        let before = SpecContentData::default();
+        let after = SpecContentData {
+            notes: "Confidential note\n".to_string(),
+            ..before.clone()
+        };
+        history::record(
+            "plain-app",
+            "20250101_120000_auth",
+            "update_spec",
+            &before,
+            &after,
+        )
+        .await
+        .unwrap();
+        activity::record_update(
+            "plain-app",
+            "20250101_120000_auth",
+            "update_spec",
+            &before,
+            &after,
+        )
+        .await
+        .unwrap();
+        audit::record_call("plain-app", "update_project", &json!({}), None, None).unwrap();
+
+        let key_file = foundry_dir.parent().unwrap().join("foundry.key");
+        foundry_mcp::core::encryption::generate_key_file(&key_file).unwrap();
+        std::fs::write(
+            foundry_dir.join("config.toml"),
+            format!(
+                "[encryption]\nkey_file = {:?}\n",
+                key_file.display().to_string()
+            ),
+        )
+        .unwrap();
+
+        env.create_test_project("secret-app").await.unwrap();
+        env.create_test_spec("secret-app", "launch_plan", "Confidential launch")
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        let spec_name = foundry.list_specs("secret-app").await.unwrap()[0]
+            .name
+            .clone();
+        let spec_dir = foundry_dir.join("secret-app/specs").join(&spec_name);
+        let raw = std::fs::read_to_string(spec_dir.join("spec.md")).unwrap();
+        assert!(raw.starts_with("foundry-encrypted:v2:"));
+        assert!(!raw.contains("Confidential"));
+
+        let spec = foundry.load_spec("secret-app", &spec_name).await.unwrap();
+        assert!(spec.content.spec.contains("Confidential launch"));
+        // Summaries are spec content and stay out of the plaintext metadata
+        let summary = SpecSummary {
+            text: "Confidential summary".to_string(),
+            generated_at: "2026-01-01T00:00:00Z".to_string(),
+            model: None,
+            spec_version: "1".to_string(),
+        };
+        foundry
+            .set_spec_summary("secret-app", &spec_name, &summary)
+            .await
+            .unwrap();
+        let metadata = std::fs::read_to_string(spec_dir.join("metadata.json")).unwrap_or_default();
+        assert!(!metadata.contains("Confidential"));
+        let raw = std::fs::read_to_string(spec_dir.join("summary.json")).unwrap();
+        assert!(raw.starts_with("foundry-encrypted:v2:"));
+        let listed = foundry.list_specs("secret-app").await.unwrap();
+        assert_eq!(
+            listed[0].summary.as_ref().unwrap().text,
+            "Confidential summary"
+        );
+        // Files written before the key was configured stay readable
+        let plain = foundry.load_project("plain-app").await.unwrap();
+        assert!(!plain.vision.unwrap().is_empty());
+
+        let converted = encryption_json(EncryptionCommand::Encrypt).await;
+        // Project files plus the update, activity and audit journals
+        assert_eq!(converted.files.len(), 6);
+        assert!(
+            converted
+                .files
+                .iter()
+                .all(|file| file.contains("plain-app"))
+        );
+        for journal in [
+            ".history/plain-app/20250101_120000_auth.json",
+            ".activity/plain-app.json",
+            ".audit/plain-app.jsonl",
+        ] {
+            let raw = std::fs::read_to_string(foundry_dir.join(journal)).unwrap();
+            assert!(raw.starts_with("foundry-encrypted:v2:"), "{}", journal);
+            assert!(!raw.contains("Confidential"));
+        }
+        assert_eq!(audit::entries("plain-app").unwrap().len(), 1);
+        assert_eq!(
+            history::entries("plain-app", "20250101_120000_auth")
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // Backups are encrypted too and still list
+        delete_spec::run(delete_spec::Input {
+            project_name: "secret-app".to_string(),
+            spec_name: spec_name.clone(),
+            confirm: "true".to_string(),
+        })
+        .await
+        .unwrap();
+        let output =
+            foundry_mcp::cli::commands::backup::execute(foundry_mcp::cli::args::BackupArgs {
+                command: foundry_mcp::cli::args::BackupCommand::List {
+                    project: None,
+                    spec: None,
+                },
+                json: true,
+            })
+            .await
+            .unwrap();
+        let listed: BackupListResponse = serde_json::from_str(&output).unwrap();
+        assert_eq!(listed.backups.len(), 1);
+
+        let decrypted = encryption_json(EncryptionCommand::Decrypt).await;
+        // Both projects, the backup and the journals
+        assert_eq!(decrypted.files.len(), 10);
+        std::fs::remove_file(foundry_dir.join("config.toml")).unwrap();
+        let plain = foundry.load_project("secret-app").await.unwrap();
+        assert!(!plain.vision.unwrap().starts_with("foundry-encrypted"));
+    });
+}
+
+#[test]
+fn test_encrypted_files_without_key_fail_with_hint() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let foundry_dir = env.foundry_dir();
+        std::fs::create_dir_all(&foundry_dir).unwrap();
+        let key_file = foundry_dir.parent().unwrap().join("foundry.key");
+        foundry_mcp::core::encryption::generate_key_file(&key_file).unwrap();
+        std::fs::write(
+            foundry_dir.join("config.toml"),
+            format!(
+                "[encryption]\nkey_file = {:?}\n",
+                key_file.display().to_string()
+            ),
+        )
+        .unwrap();
+        env.create_test_project("locked-app").await.unwrap();
+        env.create_test_spec("locked-app", "auth", "Authentication")
+            .await
+            .unwrap();
+        std::fs::remove_file(foundry_dir.join("config.toml")).unwrap();
+
+        let foundry = get_default_foundry().unwrap();
+        let spec_name = foundry.list_specs("locked-app").await.unwrap()[0]
+            .name
+            .clone();
+        let error = foundry
+            .load_spec("locked-app", &spec_name)
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+        assert!(format!("{:#}", error).contains("no key is configured"));
+    });
+}
+
+#[test]
+fn test_encrypted_content_is_bound_to_its_spec() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let foundry_dir = env.foundry_dir();
+        std::fs::create_dir_all(&foundry_dir).unwrap();
+        let key_file = foundry_dir.parent().unwrap().join("foundry.key");
+        foundry_mcp::core::encryption::generate_key_file(&key_file).unwrap();
+        std::fs::write(
+            foundry_dir.join("config.toml"),
+            format!(
+                "[encryption]\nkey_file = {:?}\n",
+                key_file.display().to_string()
+            ),
+        )
+        .unwrap();
+        env.create_test_project("bound-app").await.unwrap();
+        env.create_test_spec("bound-app", "auth", "Authentication")
+            .await
+            .unwrap();
+        env.create_test_spec("bound-app", "billing", "Billing")
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        let names: Vec<String> = foundry
+            .list_specs("bound-app")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|spec| spec.name)
+            .collect();
+        let (auth, billing) = if names[0].ends_with("_auth") {
+            (&names[0], &names[1])
+        } else {
+            (&names[1], &names[0])
+        };
+
+        // Renamed specs are re-encrypted for their new path
+        let renamed = foundry
+            .rename_spec("bound-app", auth, &auth.replace("_auth", "_login"))
+            .await
+            .unwrap();
+        assert!(renamed.content.spec.contains("Authentication"));
+
+        // Ciphertext copied onto another spec's file does not decrypt
+        let specs_dir = foundry_dir.join("bound-app/specs");
+        std::fs::copy(
+            specs_dir.join(&renamed.name).join("spec.md"),
+            specs_dir.join(billing).join("spec.md"),
+        )
+        .unwrap();
+        let error = foundry.load_spec("bound-app", billing).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+
+        // Wire log lines carry spec content and are encrypted too
+        let log = WireLog::open_default().unwrap();
+        log.record(
+            "req-1",
+            "load_spec",
+            &json!({"project_name": "bound-app"}),
+            &Ok(json!({"spec": "Confidential launch"})),
+            1,
+        )
+        .unwrap();
+        let raw = std::fs::read_to_string(log.active_path()).unwrap();
+        assert!(raw.starts_with("foundry-encrypted:v2:"));
+        assert!(!raw.contains("Confidential"));
+        let entries = wire_log::read_entries(&log.active_path()).unwrap();
+        assert_eq!(
+            entries[0].response.as_ref().unwrap()["spec"],
+            "Confidential launch"
+        );
+    });
+}
@@ -0,0 +1,118 @@
+//! Integration tests for spec handoff packets
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::ops::create_handoff;
+use foundry_mcp::core::tasks::parse_task_list;
+use foundry_mcp::utils::formatting::estimate_tokens;
+
+#[test]
+fn test_assemble_keeps_tasks_and_decisions_within_budget() {
+    let tasks =
+        parse_task_list("## Backend\n- [x] Schema\n- [ ] Endpoints\n## Frontend\n- [ ] Login form");
+    let notes = "## Context\nSome background\n\n## Decisions\n- Use JWT\n- Sessions expire after 15 minutes\n\nWe decided to skip OAuth for now.";
+    let spec = format!(
+        "# Auth\n\n## Overview\n\nLogin for users.\n\n## Details\n\n{}",
+        "Long implementation detail line.\n".repeat(200)
+    );
+
+    let report = create_handoff::assemble(
+        "demo",
+        "20250101_000000_auth",
+        "Demo project summary",
+        &spec,
+        notes,
+        &tasks,
+        300,
+    );
+
+    assert!(
+        report.estimated_tokens <= 300,
+        "{}",
+        report.estimated_tokens
+    );
+    assert_eq!(
+        report.estimated_tokens,
+        estimate_tokens(&report.handoff_markdown)
+    );
+    assert_eq!(report.open_tasks, 2);
+    assert_eq!(report.completed_tasks, 1);
+    assert_eq!(report.decisions, 3);
+    assert_eq!(
+        report.included_sections[..4],
+        [
+            "Open tasks",
+            "Recent decisions",
+            "Project summary",
+            "Spec: Overview"
+        ]
+        .map(String::from)
+    );
+    assert!(report.truncated);
+
+    let markdown = &report.handoff_markdown;
+    assert!(markdown.starts_with("# Handoff: 20250101_000000_auth"));
+    assert!(markdown.contains("Progress: 1/3 tasks complete, 2 open"));
+    assert!(markdown.contains("**Frontend**\n- [ ] Login form"));
+    assert!(!markdown.contains("Schema"));
+    assert!(markdown.contains("- We decided to skip OAuth for now."));
+    assert!(markdown.contains("truncated for the handoff budget"));
+}
+
+#[test]
+fn test_create_handoff_resolves_feature_name() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("handoff-app").await.unwrap();
+        env.create_test_spec("handoff-app", "user_auth", "Authentication")
+            .await
+            .unwrap();
+
+        let response = create_handoff::run(create_handoff::Input {
+            project_name: "handoff-app".to_string(),
+            spec_name: "user_auth".to_string(),
+            max_tokens: None,
+        })
+        .await
+        .unwrap();
+
+        assert!(response.data.spec_name.ends_with("_user_auth"));
+        assert_eq!(response.data.open_tasks, 7);
+        assert_eq!(response.data.decisions, 2);
+        assert!(response.data.omitted_sections.is_empty());
+        assert!(!response.data.truncated);
+        assert!(
+            response
+                .data
+                .handoff_markdown
+                .contains("## Spec: Requirements")
+        );
+    });
+}
+
+#[test]
+fn test_create_handoff_rejects_tiny_budget_and_missing_spec() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("handoff-app").await.unwrap();
+
+        let error = create_handoff::run(create_handoff::Input {
+            project_name: "handoff-app".to_string(),
+            spec_name: "anything".to_string(),
+            max_tokens: Some(50),
+        })
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("at least 200"));
+
+        let error = create_handoff::run(create_handoff::Input {
+            project_name: "handoff-app".to_string(),
+            spec_name: "missing_feature".to_string(),
+            max_tokens: None,
+        })
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("No spec found"));
+    });
+}
@@ -0,0 +1,406 @@
+//! Integration tests for importing and exporting Foundry content
+//!
+//! Import sources are created inside the isolated TestEnvironment so no
+//! real user directories are touched.
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::{export_tasks, import_project, import_spec, list_specs};
+use foundry_mcp::types::errors::ErrorCode;
+use foundry_mcp::types::responses::ValidationStatus;
+
+/// Vision, tech stack and summary long enough to pass create_project validation
+const VISION: &str = "# Vision\n\nImported vision content. The app solves the problem of scattered product notes for small teams: target users are developers and product managers who want one place to plan features and track the tasks that ship them.";
+const TECH_STACK: &str = "## Backend\n\n- Rust with tokio for the server\n- SQLite database for storage\n- React frontend served as static files\n- Deployed with Docker on a single small VM behind nginx";
+const SUMMARY: &str = "Imported summary. A planning app for small teams, built in Rust with a React frontend, that keeps product notes, specs and tasks in one place.";
+
+fn write_docs_source(env: &TestEnvironment) -> std::path::PathBuf {
+    env.write_file("source/README.md", VISION).unwrap();
+    env.write_file("source/ARCHITECTURE.md", TECH_STACK)
+        .unwrap();
+    env.write_file("source/summary.md", SUMMARY).unwrap();
+    env.write_file(
+        "source/specs/User Auth/spec.md",
+        "# User Auth\n\n## Overview\n\nLogin flow.\n\n- [ ] Add login form\n- [x] Pick provider",
+    )
+    .unwrap();
+    env.write_file("source/specs/billing.md", "# Billing\n\n- [ ] Add invoices")
+        .unwrap();
+    env.write_file("source/specs/diagram.png", "not markdown")
+        .unwrap();
+    env.join("source")
+}
+
+#[test]
+fn test_import_project_dry_run_writes_nothing() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let source = write_docs_source(&env);
+
+        let response = import_project::run(import_project::Input {
+            project_name: "imported-app".to_string(),
+            source_path: source.to_string_lossy().to_string(),
+            preset: None,
+            mapping: None,
+            dry_run: true,
+        })
+        .await
+        .unwrap();
+
+        assert!(response.data.dry_run);
+        assert_eq!(response.data.project_action, "create");
+        assert_eq!(response.data.project_files.len(), 3);
+        assert!(response.data.issues.is_empty());
+
+        let features: Vec<&str> = response
+            .data
+            .specs
+            .iter()
+            .map(|s| s.feature_name.as_str())
+            .collect();
+        assert_eq!(features, vec!["user_auth", "billing"]);
+        assert!(response.data.specs.iter().all(|s| s.spec_name.is_none()));
+        assert_eq!(response.data.skipped.len(), 1, "png should be skipped");
+
+        assert!(!env.foundry_dir().join("imported-app").exists());
+    });
+}
+
+#[test]
+fn test_import_project_writes_project_and_specs() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let source = write_docs_source(&env);
+
+        let response = import_project::run(import_project::Input {
+            project_name: "imported-app".to_string(),
+            source_path: source.to_string_lossy().to_string(),
+            preset: Some("docs".to_string()),
+            mapping: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(response.validation_status, ValidationStatus::Complete);
+        assert!(response.data.specs.iter().all(|s| s.spec_name.is_some()));
+
+        let project_dir = env.foundry_dir().join("imported-app");
+        let vision = std::fs::read_to_string(project_dir.join("vision.md")).unwrap();
+        assert!(vision.contains("Imported vision content"));
+
+        let specs = list_specs::run(list_specs::Input {
+            project_name: "imported-app".to_string(),
+            status: None,
+            blocked_by: None,
+            tags: Vec::new(),
+            cursor: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(specs.data.total_count, 2);
+
+        let auth = response
+            .data
+            .specs
+            .iter()
+            .find(|s| s.feature_name == "user_auth")
+            .unwrap();
+        assert_eq!(auth.task_count, 2);
+        let tasks = std::fs::read_to_string(
+            project_dir
+                .join("specs")
+                .join(auth.spec_name.as_ref().unwrap())
+                .join("task-list.md"),
+        )
+        .unwrap();
+        assert!(tasks.contains("- [x] Pick provider"));
+
+        // Re-running skips specs whose feature already exists
+        let rerun = import_project::run(import_project::Input {
+            project_name: "imported-app".to_string(),
+            source_path: source.to_string_lossy().to_string(),
+            preset: None,
+            mapping: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+        assert_eq!(rerun.data.project_action, "existing");
+        assert!(rerun.data.specs.is_empty());
+    });
+}
+
+#[test]
+fn test_import_project_taskmaster_preset_and_mapping_overrides() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.write_file("tm/.taskmaster/docs/prd.txt", VISION).unwrap();
+        env.write_file("tm/docs/stack.md", TECH_STACK).unwrap();
+        env.write_file("tm/README.md", SUMMARY).unwrap();
+        env.write_file(
+            "tm/.taskmaster/tasks/task_001.txt",
+            "# Task ID: 1\n# Title: Setup Project Repository\n# Status: done\n# Details:\nInit repo",
+        )
+        .unwrap();
+
+        let mapping: import_project::ImportMapping =
+            serde_json::from_value(serde_json::json!({ "tech_stack": ["docs/stack.md"] }))
+                .unwrap();
+
+        let response = import_project::run(import_project::Input {
+            project_name: "tm-app".to_string(),
+            source_path: env.join("tm").to_string_lossy().to_string(),
+            preset: Some("taskmaster".to_string()),
+            mapping: Some(mapping),
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(response.data.specs.len(), 1);
+        let spec = &response.data.specs[0];
+        assert_eq!(spec.feature_name, "setup_project_repository");
+
+        let tasks = std::fs::read_to_string(
+            env.foundry_dir()
+                .join("tm-app/specs")
+                .join(spec.spec_name.as_ref().unwrap())
+                .join("task-list.md"),
+        )
+        .unwrap();
+        assert_eq!(tasks.trim(), "- [x] Setup Project Repository");
+    });
+}
+
+#[test]
+fn test_import_project_missing_sources_block_write() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.write_file("sparse/README.md", "Only a readme").unwrap();
+        let source = env.join("sparse").to_string_lossy().to_string();
+
+        let report = import_project::run(import_project::Input {
+            project_name: "sparse-app".to_string(),
+            source_path: source.clone(),
+            preset: None,
+            mapping: None,
+            dry_run: true,
+        })
+        .await
+        .unwrap();
+        assert_eq!(report.validation_status, ValidationStatus::Incomplete);
+        assert_eq!(report.data.issues.len(), 2);
+
+        let result = import_project::run(import_project::Input {
+            project_name: "sparse-app".to_string(),
+            source_path: source,
+            preset: None,
+            mapping: None,
+            dry_run: false,
+        })
+        .await;
+        assert!(result.is_err());
+        assert!(!env.foundry_dir().join("sparse-app").exists());
+    });
+}
+
+#[test]
+fn test_import_project_validates_new_project_like_create_project() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.write_file("short/README.md", "# Vision\n\nToo short.")
+            .unwrap();
+        env.write_file("short/ARCHITECTURE.md", TECH_STACK).unwrap();
+        env.write_file("short/summary.md", SUMMARY).unwrap();
+        let source = env.join("short").to_string_lossy().to_string();
+        let import = |project_name: &str, dry_run: bool| {
+            import_project::run(import_project::Input {
+                project_name: project_name.to_string(),
+                source_path: source.clone(),
+                preset: None,
+                mapping: None,
+                dry_run,
+            })
+        };
+
+        let report = import("short-app", true).await.unwrap();
+        assert_eq!(report.validation_status, ValidationStatus::Incomplete);
+        assert!(report.data.issues[0].contains("Vision content must be at least"));
+
+        let error = import("short-app", false).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+        assert!(!env.foundry_dir().join("short-app").exists());
+
+        // Names create_project refuses are refused here too
+        write_docs_source(&env);
+        let source = env.join("source").to_string_lossy().to_string();
+        let error = import_project::run(import_project::Input {
+            project_name: "double--dash".to_string(),
+            source_path: source,
+            preset: None,
+            mapping: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+        assert!(!env.foundry_dir().join("double--dash").exists());
+    });
+}
+
+#[test]
+fn test_export_tasks_csv_across_project() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("export-app").await.unwrap();
+        env.create_test_spec("export-app", "user_auth", "Auth")
+            .await
+            .unwrap();
+
+        let response = export_tasks::run(export_tasks::Input {
+            project_name: "export-app".to_string(),
+            format: None,
+            spec_name: None,
+        })
+        .await
+        .unwrap();
+
+        let lines: Vec<&str> = response.data.content.lines().collect();
+        assert_eq!(lines[0], "spec,section,task,status,completed_at");
+        assert_eq!(response.data.task_count, 7);
+        assert_eq!(lines.len(), 8);
+        assert!(lines[1].ends_with(",Setup Phase,Create base structure for user_auth,todo,"));
+        assert_eq!(response.data.done_count, 0);
+    });
+}
+
+#[test]
+fn test_export_tasks_rejects_unknown_format() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("export-app").await.unwrap();
+
+        let result = export_tasks::run(export_tasks::Input {
+            project_name: "export-app".to_string(),
+            format: Some("xlsx".to_string()),
+            spec_name: None,
+        })
+        .await;
+        assert!(result.is_err());
+    });
+}
+
+#[test]
+fn test_export_tasks_jira_links_tasks_to_epic() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("export-app").await.unwrap();
+        env.create_test_spec("export-app", "user_auth", "Auth")
+            .await
+            .unwrap();
+        env.create_test_spec("export-app", "billing", "Billing")
+            .await
+            .unwrap();
+        let specs = list_specs::run(list_specs::Input {
+            project_name: "export-app".to_string(),
+            status: None,
+            blocked_by: None,
+            tags: Vec::new(),
+            cursor: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+        let auth = specs
+            .data
+            .specs
+            .iter()
+            .find(|s| s.feature_name == "user_auth")
+            .unwrap();
+
+        let response = export_tasks::run(export_tasks::Input {
+            project_name: "export-app".to_string(),
+            format: Some("jira".to_string()),
+            spec_name: Some(auth.name.clone()),
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(response.data.format, "jira");
+        assert_eq!(response.data.spec_count, 1);
+        let content = &response.data.content;
+        assert!(content.starts_with("Issue Id,Issue Type,Summary,Description,Parent Id,Status\n"));
+        assert!(content.contains("1,Epic,User auth,"));
+        assert!(content.contains("2,Task,Create base structure for user_auth,\"Spec: "));
+        assert!(content.contains("Section: Setup Phase\",1,To Do"));
+        assert!(!content.contains("billing"));
+
+        let missing = export_tasks::run(export_tasks::Input {
+            project_name: "export-app".to_string(),
+            format: Some("jira".to_string()),
+            spec_name: Some("20200101_000000_missing".to_string()),
+        })
+        .await;
+        assert!(missing.is_err());
+    });
+}
+
+#[test]
+fn test_import_spec_sorts_sections_by_heading() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("doc-app").await.unwrap();
+        env.write_file(
+            "export/Payment Retries.md",
+            "# Payment Retries\n\nRetry failed card payments.\n\n## Requirements\n\nRetry three times.\n\n## Open Questions\n\nWhich errors are final?\n\n## Tasks\n\n- Add retry queue\n1. Email the customer\n- [x] Agree on limits\n\n```\n## Not a heading\n```",
+        )
+        .unwrap();
+        let source = env.join("export/Payment Retries.md");
+        let import = |dry_run: bool| {
+            import_spec::run(import_spec::Input {
+                project_name: "doc-app".to_string(),
+                source_path: source.to_string_lossy().to_string(),
+                feature_name: None,
+                dry_run,
+            })
+        };
+
+        let preview = import(true).await.unwrap().data;
+        assert_eq!(preview.feature_name, "payment_retries");
+        assert!(preview.spec_name.is_none());
+        let targets: Vec<(&str, &str)> = preview
+            .sections
+            .iter()
+            .map(|section| (section.source.as_str(), section.target.as_str()))
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                ("Payment Retries.md", "spec.md"),
+                ("Payment Retries.md#Requirements", "spec.md"),
+                ("Payment Retries.md#Open Questions", "notes.md"),
+                ("Payment Retries.md#Tasks", "task-list.md"),
+            ]
+        );
+        assert_eq!(preview.task_count, 3);
+        assert!(get_default_foundry().unwrap().list_specs("doc-app").await.unwrap().is_empty());
+
+        let imported = import(false).await.unwrap().data;
+        let spec = get_default_foundry()
+            .unwrap()
+            .load_spec("doc-app", imported.spec_name.as_ref().unwrap())
+            .await
+            .unwrap();
+        assert!(spec.content.spec.contains("Retry three times."));
+        assert!(!spec.content.spec.contains("Which errors"));
+        assert!(spec.content.notes.contains("Which errors are final?"));
+        assert!(spec.content.tasks.contains("- [ ] Add retry queue"));
+        assert!(spec.content.tasks.contains("- [ ] Email the customer"));
+        assert!(spec.content.tasks.contains("- [x] Agree on limits"));
+        assert!(spec.content.tasks.contains("## Not a heading"));
+    });
+}
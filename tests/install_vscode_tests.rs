@@ -0,0 +1,43 @@
+//! Integration tests for Foundry CLI VS Code installation commands
+
+use anyhow::Result;
+use foundry_mcp::types::responses::InstallationStatus;
+
+mod common;
+use common::TestEnvironment;
+
+/// Test VS Code install, status and uninstall through the CLI commands
+#[test]
+fn test_vscode_install_status_uninstall() -> Result<()> {
+    let env = TestEnvironment::new()?;
+
+    env.with_env_async(|| async {
+        let config_path = env.vscode_config_path();
+        assert!(!config_path.exists(), "Config should not exist initially");
+
+        let response = env.install_and_parse("vscode").await?;
+        assert_eq!(response.target, "vscode");
+        assert_eq!(response.installation_status, InstallationStatus::Success);
+        assert_eq!(response.config_path, config_path.to_string_lossy());
+
+        let config: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&config_path)?)?;
+        assert_eq!(config["servers"]["foundry"]["command"], "foundry");
+
+        let status = env.status_and_parse("vscode").await?;
+        assert_eq!(status.environments.len(), 1);
+        assert_eq!(status.environments[0].name, "vscode");
+        assert!(status.environments[0].installed);
+
+        let response = env.uninstall_and_parse("vscode", false).await?;
+        assert_eq!(response.uninstallation_status, InstallationStatus::Success);
+        let config: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&config_path)?)?;
+        assert!(config["servers"].get("foundry").is_none());
+
+        let status = env.status_and_parse("vscode").await?;
+        assert!(!status.environments[0].installed);
+
+        Ok(())
+    })
+}
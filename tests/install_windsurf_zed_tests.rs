@@ -0,0 +1,73 @@
+//! Integration tests for Foundry CLI Windsurf and Zed installation commands
+
+use anyhow::Result;
+use foundry_mcp::types::responses::InstallationStatus;
+
+mod common;
+use common::TestEnvironment;
+
+/// Test Windsurf install, status and uninstall through the CLI commands
+#[test]
+fn test_windsurf_install_status_uninstall() -> Result<()> {
+    let env = TestEnvironment::new()?;
+
+    env.with_env_async(|| async {
+        let config_path = env.windsurf_config_path();
+        let response = env.install_and_parse("windsurf").await?;
+        assert_eq!(response.target, "windsurf");
+        assert_eq!(response.installation_status, InstallationStatus::Success);
+        assert_eq!(response.config_path, config_path.to_string_lossy());
+
+        let config: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&config_path)?)?;
+        assert_eq!(config["mcpServers"]["foundry"]["command"], "foundry");
+        let workspace = env.windsurf_workspace_dir();
+        assert!(workspace.join("rules/foundry.md").exists());
+        assert!(workspace.join("workflows/foundry_load_spec.md").exists());
+
+        let status = env.status_and_parse("windsurf").await?;
+        assert_eq!(status.environments[0].name, "windsurf");
+        assert!(status.environments[0].installed);
+
+        let response = env.uninstall_and_parse("windsurf", true).await?;
+        assert_eq!(response.uninstallation_status, InstallationStatus::Success);
+        assert!(!config_path.exists());
+        assert!(!workspace.join("rules/foundry.md").exists());
+
+        let status = env.status_and_parse("windsurf").await?;
+        assert!(!status.environments[0].installed);
+
+        Ok(())
+    })
+}
+
+/// Test Zed install, status and uninstall through the CLI commands
+#[test]
+fn test_zed_install_status_uninstall() -> Result<()> {
+    let env = TestEnvironment::new()?;
+
+    env.with_env_async(|| async {
+        let settings_path = env.zed_settings_path();
+        let response = env.install_and_parse("zed").await?;
+        assert_eq!(response.target, "zed");
+        assert_eq!(response.installation_status, InstallationStatus::Success);
+
+        let settings: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&settings_path)?)?;
+        assert_eq!(settings["context_servers"]["foundry"]["command"], "foundry");
+        assert_eq!(
+            settings["context_servers"]["foundry"]["args"],
+            serde_json::json!(["serve"])
+        );
+
+        let status = env.status_and_parse("zed").await?;
+        assert_eq!(status.environments[0].name, "zed");
+        assert!(status.environments[0].installed);
+
+        env.uninstall_and_parse("zed", false).await?;
+        let status = env.status_and_parse("zed").await?;
+        assert!(!status.environments[0].installed);
+
+        Ok(())
+    })
+}
@@ -0,0 +1,123 @@
+//! Integration tests for spec linting and its fix-its
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::ops::{create_spec, lint_spec, update_spec};
+use foundry_mcp::types::responses::{LintFinding, LintSeverity, ValidationStatus};
+
+const SPEC: &str = "# Export\n\n## Overview\n\nExport search results as CSV files that open in spreadsheet tools.\n\n#### Columns\n\nOne column per result field.\n\n## Acceptance Criteria\n\n## Rollout\n\n```text\n# not a heading\n```";
+const TASKS: &str = "## Phase 1: Build\n\n- [ ] Write the CSV encoder\n- Add the export endpoint\n1. Add a download button\n- [ ] Write the CSV encoder @alice\n- [ ] Document the export format\n- [ ] Document the export format";
+
+fn rules(findings: &[LintFinding]) -> Vec<&str> {
+    findings
+        .iter()
+        .map(|finding| finding.rule.as_str())
+        .collect()
+}
+
+fn input() -> lint_spec::Input {
+    lint_spec::Input {
+        project_name: "lint-app".to_string(),
+        spec_name: "export".to_string(),
+    }
+}
+
+#[test]
+fn test_lint_reports_each_convention() {
+    let findings = lint_spec::lint(&["Overview".to_string()], SPEC, "## Notes\n\nNone.", TASKS);
+
+    assert_eq!(
+        rules(&findings),
+        vec![
+            "empty_acceptance_criteria",
+            "heading_hierarchy",
+            "non_checkbox_task",
+            "non_checkbox_task",
+            "duplicate_task",
+            "duplicate_task",
+        ]
+    );
+    assert_eq!(findings[0].line, Some(11));
+    assert_eq!(findings[1].line, Some(7));
+    assert!(findings[1].message.contains("level 2 to level 4"));
+    assert!(findings[1].fix.is_empty());
+    assert_eq!(findings[2].fix.len(), 1);
+    assert_eq!(findings[3].fix.len(), 2, "numbered items are re-added");
+    // Metadata aside the tasks match, and the later one can be removed by its text
+    assert_eq!(findings[4].severity, LintSeverity::Warning);
+    assert_eq!(findings[4].fix.len(), 1);
+    // Identical lines leave no selector that picks only one of them
+    assert!(findings[5].fix.is_empty());
+
+    let findings = lint_spec::lint(
+        &["## Requirements".to_string()],
+        "# Export\n\n## Overview\n\nCSV export.",
+        "",
+        "- [ ] Write the encoder",
+    );
+    assert_eq!(
+        rules(&findings),
+        vec!["missing_section", "missing_acceptance_criteria"]
+    );
+    assert_eq!(findings[0].severity, LintSeverity::Error);
+    assert_eq!(findings[1].severity, LintSeverity::Warning);
+}
+
+#[test]
+fn test_lint_fixes_correct_the_spec() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("lint-app").await.unwrap();
+        create_spec::run(create_spec::Input {
+            project_name: "lint-app".to_string(),
+            feature_name: "export".to_string(),
+            spec: "# Export\n\n## Overview\n\nExport search results as CSV files that open in spreadsheet tools without any cleanup.".to_string(),
+            notes: "## Context\n\nCustomers currently copy results by hand.".to_string(),
+            tasks: "## Phase 1: Build\n\n- [ ] Write the CSV encoder\n- Add the export endpoint\n1. Add a download button\n- [ ] Write the CSV encoder @alice".to_string(),
+            template: None,
+            allow_duplicate: false,
+        })
+        .await
+        .unwrap();
+
+        let response = lint_spec::run(input()).await.unwrap();
+        assert!(matches!(
+            response.validation_status,
+            ValidationStatus::Incomplete
+        ));
+        assert_eq!(response.data.errors, 3);
+        assert_eq!(response.data.warnings, 2);
+
+        let commands: Vec<_> = response
+            .data
+            .findings
+            .iter()
+            .flat_map(|finding| finding.fix.clone())
+            .collect();
+        let commands_json = serde_json::to_string(&commands)
+            .unwrap()
+            .replace("<requirements of the feature>", "- Results export as UTF-8 CSV")
+            .replace("<verifiable outcome>", "Exported files open in spreadsheet tools");
+        update_spec::run(update_spec::Input {
+            project_name: "lint-app".to_string(),
+            spec_name: response.data.spec_name.clone(),
+            commands_json,
+            expected_version: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+
+        let response = lint_spec::run(input()).await.unwrap();
+        assert!(
+            response.data.findings.is_empty(),
+            "{:?}",
+            response.data.findings
+        );
+        assert!(matches!(
+            response.validation_status,
+            ValidationStatus::Complete
+        ));
+    });
+}
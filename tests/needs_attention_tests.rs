@@ -0,0 +1,150 @@
+//! Integration tests for stale spec detection
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::{list_specs, needs_attention};
+use foundry_mcp::types::spec::SpecFileType;
+use std::fs;
+
+#[test]
+fn test_needs_attention_flags_stale_specs_across_projects() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("stale-app").await.unwrap();
+        env.create_test_project("quiet-app").await.unwrap();
+        env.create_test_spec("stale-app", "fresh_feature", "Fresh")
+            .await
+            .unwrap();
+        let stale = env.write_old_spec("stale-app", "forgotten", "- [x] Start\n- [ ] Finish");
+        // Finished specs never need attention, however old
+        env.write_old_spec("quiet-app", "shipped", "- [x] Done completed:2020-01-05");
+
+        let response = needs_attention::run(needs_attention::Input {
+            project_name: None,
+            stale_days: None,
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(response.data.stale_days, 14);
+        assert_eq!(response.data.projects_checked, 2);
+        assert_eq!(response.data.total_count, 1);
+        let spec = &response.data.specs[0];
+        assert_eq!(spec.project_name, "stale-app");
+        assert_eq!(spec.spec_name, stale);
+        assert_eq!(spec.open_tasks, 1);
+        assert_eq!(spec.last_activity, "2020-01-01");
+        assert!(response.next_steps[1].contains(&stale));
+
+        let scoped = needs_attention::run(needs_attention::Input {
+            project_name: Some("quiet-app".to_string()),
+            stale_days: Some(1),
+        })
+        .await
+        .unwrap();
+        assert_eq!(scoped.data.projects_checked, 1);
+        assert!(scoped.data.specs.is_empty());
+    });
+}
+
+#[test]
+fn test_needs_attention_uses_recorded_edits_and_project_threshold() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("edited-app").await.unwrap();
+        env.create_test_project("patient-app").await.unwrap();
+        let edited = env.write_old_spec("edited-app", "revived", "- [ ] Finish");
+        env.write_old_spec("patient-app", "slow_burn", "- [ ] Finish");
+        fs::write(
+            env.foundry_dir().join("patient-app").join(".foundry.toml"),
+            "[stale]\ndays = 100000\n",
+        )
+        .unwrap();
+
+        let stale = || async {
+            needs_attention::run(needs_attention::Input {
+                project_name: None,
+                stale_days: None,
+            })
+            .await
+            .unwrap()
+            .data
+            .specs
+        };
+        let specs = stale().await;
+        assert_eq!(specs.len(), 1, "{:?}", specs);
+        assert_eq!(specs[0].spec_name, edited);
+        assert_eq!(specs[0].stale_days, 14);
+
+        get_default_foundry()
+            .unwrap()
+            .update_spec_content("edited-app", &edited, SpecFileType::Notes, "Picked back up")
+            .await
+            .unwrap();
+        assert!(stale().await.is_empty());
+
+        // An explicit threshold applies to every project
+        let response = needs_attention::run(needs_attention::Input {
+            project_name: Some("patient-app".to_string()),
+            stale_days: Some(14),
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.total_count, 1);
+    });
+}
+
+#[test]
+fn test_list_specs_annotates_stale_specs() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("listed-app").await.unwrap();
+        env.create_test_spec("listed-app", "fresh_feature", "Fresh")
+            .await
+            .unwrap();
+        let stale = env.write_old_spec("listed-app", "forgotten", "- [ ] Finish");
+
+        let response = list_specs::run(list_specs::Input {
+            project_name: "listed-app".to_string(),
+            status: None,
+            blocked_by: None,
+            tags: Vec::new(),
+            cursor: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+
+        for spec in &response.data.specs {
+            assert_eq!(
+                spec.days_idle.is_some(),
+                spec.name == stale,
+                "{}",
+                spec.name
+            );
+        }
+        assert!(
+            response
+                .next_steps
+                .iter()
+                .any(|step| step.contains("mcp_foundry_needs_attention"))
+        );
+    });
+}
+
+#[test]
+fn test_needs_attention_missing_project() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let error = needs_attention::run(needs_attention::Input {
+            project_name: Some("missing".to_string()),
+            stale_days: None,
+        })
+        .await
+        .unwrap_err();
+
+        assert!(error.to_string().contains("not found"));
+    });
+}
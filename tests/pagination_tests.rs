@@ -0,0 +1,188 @@
+//! Integration tests for paged listings and loading specs in parts
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::ops::{list_projects, list_specs, load_spec};
+use foundry_mcp::types::errors::ErrorCode;
+
+async fn specs_page(
+    project: &str,
+    cursor: Option<String>,
+    limit: Option<usize>,
+) -> anyhow::Result<(Vec<String>, usize, Option<String>)> {
+    let response = list_specs::run(list_specs::Input {
+        project_name: project.to_string(),
+        status: None,
+        blocked_by: None,
+        tags: Vec::new(),
+        cursor,
+        limit,
+    })
+    .await?;
+    Ok((
+        response
+            .data
+            .specs
+            .into_iter()
+            .map(|spec| spec.name)
+            .collect(),
+        response.data.total_count,
+        response.data.next_cursor,
+    ))
+}
+
+#[test]
+fn test_list_specs_and_projects_page_with_cursor() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("paged-app").await.unwrap();
+        env.create_test_project("other-app").await.unwrap();
+        for feature in ["alpha", "beta", "gamma"] {
+            env.create_test_spec("paged-app", feature, "# Spec")
+                .await
+                .unwrap();
+        }
+
+        let (all, total, cursor) = specs_page("paged-app", None, None).await.unwrap();
+        assert_eq!((all.len(), total, cursor), (3, 3, None));
+
+        // Pages walk the full list in order without overlap
+        let (first, total, cursor) = specs_page("paged-app", None, Some(2)).await.unwrap();
+        assert_eq!(first, all[..2]);
+        assert_eq!(total, 3);
+        let (rest, _, cursor) = specs_page("paged-app", cursor, Some(2)).await.unwrap();
+        assert_eq!(rest, all[2..]);
+        assert_eq!(cursor, None);
+
+        let error = specs_page("paged-app", Some("bogus".to_string()), None)
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+        let error = specs_page("paged-app", None, Some(0)).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+
+        let first = list_projects::run(list_projects::Input {
+            limit: Some(1),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert_eq!(first.data.projects.len(), 1);
+        assert_eq!(first.data.total_count, 2);
+        let second = list_projects::run(list_projects::Input {
+            cursor: first.data.next_cursor,
+            limit: Some(1),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert_eq!(second.data.projects.len(), 1);
+        assert_ne!(second.data.projects[0].name, first.data.projects[0].name);
+        assert!(second.data.next_cursor.is_none());
+    });
+}
+
+#[test]
+fn test_load_spec_returns_one_part_at_a_time() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("parts-app").await.unwrap();
+        env.create_test_spec("parts-app", "checkout", "# Checkout\n\nPay for things")
+            .await
+            .unwrap();
+
+        let load = |part: Option<&str>| {
+            load_spec::run(load_spec::Input {
+                project_name: "parts-app".to_string(),
+                spec_name: Some("checkout".to_string()),
+                max_tokens: None,
+                part: part.map(str::to_string),
+                files: None,
+            })
+        };
+
+        let full = load(None).await.unwrap().data;
+        let names: Vec<&str> = full.parts.iter().map(|part| part.name.as_str()).collect();
+        assert_eq!(names, ["summary", "spec", "tasks", "notes"]);
+        assert!(full.parts.iter().all(|part| part.estimated_tokens > 0));
+
+        let spec = load(Some("spec")).await.unwrap();
+        assert_eq!(spec.data.part.as_deref(), Some("spec"));
+        assert!(spec.data.project_summary.is_empty());
+        let content = spec.data.spec_content.unwrap();
+        assert!(content.content.spec.contains("Pay for things"));
+        assert!(content.content.tasks.is_empty());
+        assert!(content.content.notes.is_empty());
+        // Versions still describe the stored files
+        assert_eq!(
+            content.versions,
+            full.spec_content.as_ref().unwrap().versions
+        );
+        assert!(spec.next_steps.iter().any(|step| step.contains("tasks")));
+
+        let tasks = load(Some("tasks")).await.unwrap().data;
+        let content = tasks.spec_content.unwrap().content;
+        assert!(content.spec.is_empty());
+        assert_eq!(content.tasks, full.spec_content.unwrap().content.tasks);
+        assert_eq!(tasks.tasks.len(), full.tasks.len());
+
+        let error = load(Some("everything")).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+    });
+}
+
+#[test]
+fn test_load_spec_returns_only_requested_files() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("files-app").await.unwrap();
+        env.create_test_spec("files-app", "checkout", "# Checkout\n\nPay for things")
+            .await
+            .unwrap();
+
+        let load = |files: Option<&[&str]>, part: Option<&str>| {
+            load_spec::run(load_spec::Input {
+                project_name: "files-app".to_string(),
+                spec_name: Some("checkout".to_string()),
+                max_tokens: None,
+                part: part.map(str::to_string),
+                files: files.map(|files| files.iter().map(|file| file.to_string()).collect()),
+            })
+        };
+
+        let full = load(None, None).await.unwrap().data;
+        let full_content = full.spec_content.unwrap();
+
+        let response = load(Some(&["notes", "tasks", "tasks"]), None)
+            .await
+            .unwrap();
+        let data = response.data;
+        assert_eq!(data.files, ["tasks", "notes"]);
+        assert!(data.project_summary.is_empty());
+        let names: Vec<&str> = data.parts.iter().map(|part| part.name.as_str()).collect();
+        assert_eq!(names, ["tasks", "notes"]);
+        let loaded = data.spec_content.unwrap();
+        assert!(loaded.content.spec.is_empty());
+        assert_eq!(loaded.content.tasks, full_content.content.tasks);
+        assert_eq!(loaded.content.notes, full_content.content.notes);
+        assert!(loaded.versions.spec.is_empty());
+        assert_eq!(loaded.versions.tasks, full_content.versions.tasks);
+        assert_eq!(data.tasks.len(), full.tasks.len());
+        assert!(
+            response
+                .next_steps
+                .iter()
+                .any(|step| step.contains("files: spec"))
+        );
+
+        for (files, part) in [
+            (Some(&["everything"][..]), None),
+            (Some(&[][..]), None),
+            (Some(&["tasks"][..]), Some("tasks")),
+        ] {
+            let error = load(files, part).await.unwrap_err();
+            assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+        }
+    });
+}
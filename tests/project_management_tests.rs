@@ -6,7 +6,11 @@
 mod common;
 
 use common::TestEnvironment;
-use foundry_mcp::core::ops::{create_project, create_spec, load_project};
+use foundry_mcp::core::ops::{
+    analyze_project, create_project, create_spec, delete_project, load_project, set_spec_status,
+    update_project,
+};
+use foundry_mcp::types::errors::ErrorCode;
 use foundry_mcp::types::responses::ValidationStatus;
 
 /// Test the complete project creation workflow
@@ -86,6 +90,9 @@ fn test_load_project_empty() {
         let load_args = env.load_project_args("empty-project");
         let response = load_project::run(load_project::Input {
             project_name: load_args.project_name,
+            max_tokens: None,
+            include: None,
+            active_limit: None,
         })
         .await
         .unwrap();
@@ -142,6 +149,8 @@ fn test_load_project_with_specs() {
             spec: spec1_args.spec,
             notes: spec1_args.notes,
             tasks: spec1_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -153,6 +162,8 @@ fn test_load_project_with_specs() {
             spec: spec2_args.spec,
             notes: spec2_args.notes,
             tasks: spec2_args.tasks,
+            template: None,
+            allow_duplicate: true,
         })
         .await
         .unwrap();
@@ -161,6 +172,9 @@ fn test_load_project_with_specs() {
         let load_args = env.load_project_args("project-with-specs");
         let response = load_project::run(load_project::Input {
             project_name: load_args.project_name,
+            max_tokens: None,
+            include: None,
+            active_limit: None,
         })
         .await
         .unwrap();
@@ -213,15 +227,26 @@ fn test_error_missing_project() {
         // Try to load non-existent project
         let load_args = foundry_mcp::cli::args::LoadProjectArgs {
             project_name: "non-existent-project".to_string(),
+            max_tokens: None,
+            include: None,
+            active_limit: None,
         };
 
         let result = load_project::run(load_project::Input {
             project_name: load_args.project_name,
+            max_tokens: None,
+            include: None,
+            active_limit: None,
         })
         .await;
         assert!(result.is_err(), "Should fail for missing project");
 
-        let error_msg = result.unwrap_err().to_string();
+        let error = result.unwrap_err();
+        assert_eq!(
+            foundry_mcp::types::ErrorCode::of(&error),
+            foundry_mcp::types::ErrorCode::ProjectNotFound
+        );
+        let error_msg = error.to_string();
         assert!(
             error_msg.contains("not found"),
             "Error should mention project not found"
@@ -256,6 +281,9 @@ fn test_end_to_end_workflow() {
         let load_args = env.load_project_args(project_name);
         let load_response = load_project::run(load_project::Input {
             project_name: load_args.project_name,
+            max_tokens: None,
+            include: None,
+            active_limit: None,
         })
         .await
         .unwrap();
@@ -273,6 +301,8 @@ fn test_end_to_end_workflow() {
             spec: spec_args.content.spec,
             notes: spec_args.content.notes,
             tasks: spec_args.content.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -282,6 +312,9 @@ fn test_end_to_end_workflow() {
         let load_args2 = env.load_project_args(project_name);
         let load_response2 = load_project::run(load_project::Input {
             project_name: load_args2.project_name,
+            max_tokens: None,
+            include: None,
+            active_limit: None,
         })
         .await
         .unwrap();
@@ -357,3 +390,212 @@ fn test_filesystem_isolation() {
         });
     }
 }
+
+/// analyze_project with only repo_path scans the repository without creating anything
+#[test]
+fn test_analyze_project_repo_scan() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let repo = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(repo.path().join("src")).unwrap();
+        std::fs::write(
+            repo.path().join("Cargo.toml"),
+            "[package]\nname = \"scanned\"\n\n[dependencies]\naxum = \"0.7\"\n",
+        )
+        .unwrap();
+        std::fs::write(repo.path().join("src/main.rs"), "fn main() {}").unwrap();
+        std::fs::write(repo.path().join("Dockerfile"), "FROM rust").unwrap();
+
+        let scan_only = analyze_project::Input {
+            project_name: "scanned-app".to_string(),
+            vision: String::new(),
+            tech_stack: String::new(),
+            summary: String::new(),
+            repo_path: Some(repo.path().to_string_lossy().into_owned()),
+        };
+        let response = analyze_project::run(scan_only.clone()).await.unwrap();
+        assert!(response.data.files_created.is_empty());
+        assert_eq!(response.validation_status, ValidationStatus::Incomplete);
+        let scan = response.data.repo_scan.unwrap();
+        assert_eq!(scan.languages[0].language, "Rust");
+        assert_eq!(scan.manifests[0].dependencies, vec!["axum"]);
+        assert_eq!(scan.containers, vec!["Dockerfile"]);
+        assert!(scan.tech_stack_skeleton.contains("axum"));
+        assert!(!env.foundry_dir().join("scanned-app").exists());
+
+        // With drafted content the project is created and the scan still returned
+        let args = env.create_project_args("scanned-app");
+        let response = analyze_project::run(analyze_project::Input {
+            vision: args.vision,
+            tech_stack: args.tech_stack,
+            summary: args.summary,
+            ..scan_only
+        })
+        .await
+        .unwrap();
+        assert!(response.data.repo_scan.is_some());
+        assert!(env.foundry_dir().join("scanned-app/tech-stack.md").exists());
+
+        let error = analyze_project::run(analyze_project::Input {
+            project_name: "missing-repo".to_string(),
+            vision: String::new(),
+            tech_stack: String::new(),
+            summary: String::new(),
+            repo_path: Some("/nonexistent/repo".to_string()),
+        })
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("not a directory"));
+    });
+}
+
+#[test]
+fn test_load_project_include_and_active_specs() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("focus-app").await.unwrap();
+        for feature in ["search", "export", "billing"] {
+            env.create_test_spec("focus-app", feature, "A spec.")
+                .await
+                .unwrap();
+        }
+        let all = load_project::run(load_project::Input {
+            project_name: "focus-app".to_string(),
+            max_tokens: None,
+            include: None,
+            active_limit: None,
+        })
+        .await
+        .unwrap();
+        assert!(!all.data.project.vision.is_empty());
+        assert_eq!(all.data.active_specs.len(), 3);
+        assert!(all.data.specs.is_empty());
+
+        let completed = all.data.project.specs_available[0].clone();
+        set_spec_status::run(set_spec_status::Input {
+            project_name: "focus-app".to_string(),
+            spec_name: completed.clone(),
+            status: "completed".to_string(),
+        })
+        .await
+        .unwrap();
+
+        let response = load_project::run(load_project::Input {
+            project_name: "focus-app".to_string(),
+            max_tokens: None,
+            include: Some(vec!["active_specs".to_string(), "summary".to_string()]),
+            active_limit: Some(1),
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.include, vec!["summary", "active_specs"]);
+        assert!(response.data.project.vision.is_empty());
+        assert!(response.data.project.tech_stack.is_empty());
+        assert!(!response.data.project.summary.is_empty());
+        assert_eq!(response.data.project.specs_available.len(), 3);
+        assert_eq!(response.data.active_specs.len(), 1);
+        assert_ne!(response.data.active_specs[0].name, completed);
+        assert!(
+            response
+                .next_steps
+                .iter()
+                .any(|step| step.contains("2 other specs are not shown"))
+        );
+
+        let response = load_project::run(load_project::Input {
+            project_name: "focus-app".to_string(),
+            max_tokens: None,
+            include: Some(vec!["all_specs".to_string()]),
+            active_limit: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.specs.len(), 3);
+        assert!(response.data.active_specs.is_empty());
+        assert!(response.data.project.summary.is_empty());
+
+        let error = load_project::run(load_project::Input {
+            project_name: "focus-app".to_string(),
+            max_tokens: None,
+            include: Some(vec!["roadmap".to_string()]),
+            active_limit: None,
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+    });
+}
+
+#[test]
+fn test_update_project_replaces_only_given_documents() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("update-me").await.unwrap();
+        let project_dir = env.foundry_dir().join("update-me");
+        let tech_stack = std::fs::read_to_string(project_dir.join("tech-stack.md")).unwrap();
+
+        let vision = "A refreshed product vision for the update-me project that now also covers mobile users, offline support and a partner API, with a roadmap that starts with sync and conflict resolution before the partner API ships.";
+        let response = update_project::run(update_project::Input {
+            project_name: "update-me".to_string(),
+            vision: Some(vision.to_string()),
+            tech_stack: None,
+            summary: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.files_updated, vec!["vision.md"]);
+
+        let loaded = load_project::run(load_project::Input {
+            project_name: "update-me".to_string(),
+            max_tokens: None,
+            include: None,
+            active_limit: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(loaded.data.project.vision, vision);
+        assert_eq!(loaded.data.project.tech_stack, tech_stack);
+
+        let error = update_project::run(update_project::Input {
+            project_name: "update-me".to_string(),
+            vision: None,
+            tech_stack: None,
+            summary: None,
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+    });
+}
+
+#[test]
+fn test_delete_project_requires_confirmation_and_force_for_specs() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("doomed").await.unwrap();
+        env.create_test_spec("doomed", "auth", "# Auth")
+            .await
+            .unwrap();
+        let delete = |confirm: &str, force: bool| {
+            delete_project::run(delete_project::Input {
+                project_name: "doomed".to_string(),
+                confirm: confirm.to_string(),
+                force,
+            })
+        };
+
+        let error = delete("false", true).await.unwrap_err();
+        assert!(error.to_string().contains("not confirmed"));
+        // Specs are only deleted with force
+        let error = delete("true", false).await.unwrap_err();
+        assert!(error.to_string().contains("--force"));
+        assert!(env.foundry_dir().join("doomed").exists());
+
+        let response = delete("true", true).await.unwrap();
+        assert_eq!(response.data.specs_deleted.len(), 1);
+        assert!(!env.foundry_dir().join("doomed").exists());
+
+        let error = delete("true", true).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ProjectNotFound);
+    });
+}
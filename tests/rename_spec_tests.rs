@@ -0,0 +1,115 @@
+//! Integration tests for rename_spec
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::{list_specs, rename_spec, set_spec_status};
+use foundry_mcp::types::errors::ErrorCode;
+use foundry_mcp::types::spec::SpecStatus;
+
+fn rename_input(spec_name: &str, new_feature_name: &str) -> rename_spec::Input {
+    rename_spec::Input {
+        project_name: "rename-app".to_string(),
+        spec_name: spec_name.to_string(),
+        new_feature_name: new_feature_name.to_string(),
+    }
+}
+
+#[test]
+fn test_rename_keeps_prefix_state_and_fixes_references() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("rename-app").await.unwrap();
+        let auth = env
+            .create_bare_spec("rename-app", "auth", "# Auth")
+            .await
+            .unwrap();
+        let ui = env
+            .create_bare_spec(
+                "rename-app",
+                "login_ui",
+                &format!("# UI\n\nBuilds on [[{}]] and [[auth|the auth spec]].", auth),
+            )
+            .await
+            .unwrap();
+        let foundry = get_default_foundry().unwrap();
+        foundry
+            .set_spec_dependencies("rename-app", &ui, std::slice::from_ref(&auth))
+            .await
+            .unwrap();
+        set_spec_status::run(set_spec_status::Input {
+            project_name: "rename-app".to_string(),
+            spec_name: auth.clone(),
+            status: "active".to_string(),
+        })
+        .await
+        .unwrap();
+
+        let response = rename_spec::run(rename_input(&auth, "authentication"))
+            .await
+            .unwrap();
+        let renamed = response.data.spec_name.clone();
+        assert_eq!(renamed, format!("{}_authentication", &auth[..15]));
+        assert_eq!(response.data.previous_spec_name, auth);
+        assert_eq!(response.data.links_updated, 2);
+        assert_eq!(response.data.dependents_updated, vec![ui.clone()]);
+
+        let specs = list_specs::run(list_specs::Input {
+            project_name: "rename-app".to_string(),
+            status: None,
+            blocked_by: None,
+            tags: Vec::new(),
+            cursor: None,
+            limit: None,
+        })
+        .await
+        .unwrap()
+        .data
+        .specs;
+        let renamed_meta = specs.iter().find(|s| s.name == renamed).unwrap();
+        assert_eq!(renamed_meta.status, SpecStatus::Active);
+        assert_eq!(renamed_meta.feature_name, "authentication");
+        assert!(specs.iter().all(|s| s.name != auth));
+        let ui_meta = specs.iter().find(|s| s.name == ui).unwrap();
+        assert_eq!(ui_meta.depends_on, vec![renamed.clone()]);
+
+        let spec = foundry.load_spec("rename-app", &renamed).await.unwrap();
+        assert_eq!(spec.content.tasks, "- [x] Design\n- [ ] Build it");
+        let ui_spec = foundry.load_spec("rename-app", &ui).await.unwrap();
+        assert_eq!(
+            ui_spec.content.spec,
+            format!(
+                "# UI\n\nBuilds on [[{}]] and [[authentication|the auth spec]].",
+                renamed
+            )
+        );
+    });
+}
+
+#[test]
+fn test_rename_rejects_bad_input() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("rename-app").await.unwrap();
+        let auth = env
+            .create_bare_spec("rename-app", "auth", "# Auth")
+            .await
+            .unwrap();
+
+        let error = rename_spec::run(rename_input(&auth, "Not Snake"))
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+
+        let error = rename_spec::run(rename_input(&auth, "auth"))
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+
+        let error = rename_spec::run(rename_input("20240101_000000_missing", "other"))
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::SpecNotFound);
+    });
+}
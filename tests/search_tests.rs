@@ -0,0 +1,98 @@
+//! Integration tests for full-text search across specs
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::search_specs;
+use foundry_mcp::types::spec::SpecFileType;
+
+#[test]
+fn test_search_ranks_matches_across_projects() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("shop").await.unwrap();
+        env.create_test_project("blog").await.unwrap();
+        env.create_test_spec("shop", "refunds", "Refund handling")
+            .await
+            .unwrap();
+        env.create_test_spec("blog", "comments", "Comment threads")
+            .await
+            .unwrap();
+
+        let foundry = get_default_foundry().unwrap();
+        let comments = foundry.list_specs("blog").await.unwrap().remove(0);
+        foundry
+            .update_spec_content(
+                "blog",
+                &comments.name,
+                SpecFileType::Notes,
+                "## Moderation\n\nRate limit comment posting per user.\nRefund requests are out of scope.",
+            )
+            .await
+            .unwrap();
+
+        let response = search_specs::run(search_specs::Input {
+            query: "rate limit".to_string(),
+            project_name: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.projects_searched, 2);
+        assert_eq!(response.data.specs_searched, 2);
+        let best = &response.data.matches[0];
+        assert_eq!(best.project_name, "blog");
+        assert_eq!(best.spec_name, comments.name);
+        assert_eq!(best.file, "notes.md");
+        assert_eq!(best.line_number, 3);
+        assert_eq!(best.snippet, "Rate limit comment posting per user.");
+
+        // A feature-name hit outranks the same word elsewhere
+        let response = search_specs::run(search_specs::Input {
+            query: "refund".to_string(),
+            project_name: None,
+            limit: Some(1),
+        })
+        .await
+        .unwrap();
+        assert!(response.data.truncated);
+        assert_eq!(response.data.matches.len(), 1);
+        assert_eq!(response.data.matches[0].project_name, "shop");
+
+        let response = search_specs::run(search_specs::Input {
+            query: "refund".to_string(),
+            project_name: Some("blog".to_string()),
+            limit: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.total_matches, 1);
+        assert_eq!(response.data.matches[0].file, "notes.md");
+    });
+}
+
+#[test]
+fn test_search_rejects_bad_input() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let search = |query: &str, project_name: Option<&str>, limit| {
+            search_specs::run(search_specs::Input {
+                query: query.to_string(),
+                project_name: project_name.map(str::to_string),
+                limit,
+            })
+        };
+
+        let error = search(" \"\" ", None, None).await.unwrap_err();
+        assert!(error.to_string().contains("must not be empty"));
+        let error = search("auth", None, Some(0)).await.unwrap_err();
+        assert!(error.to_string().contains("between 1 and 100"));
+        let error = search("auth", Some("missing"), None).await.unwrap_err();
+        assert!(error.to_string().contains("Project 'missing' not found"));
+
+        let response = search("auth", None, None).await.unwrap();
+        assert_eq!(response.data.total_matches, 0);
+        assert!(response.data.matches.is_empty());
+    });
+}
@@ -0,0 +1,167 @@
+//! Integration tests for spec dependencies and the spec graph
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::ops::{list_specs, set_spec_status, spec_graph, update_spec};
+use foundry_mcp::types::errors::ErrorCode;
+
+async fn dependency_command(
+    project: &str,
+    spec: &str,
+    command: &str,
+    dependency: &str,
+) -> anyhow::Result<usize> {
+    let commands = serde_json::json!([{
+        "target": "spec",
+        "command": command,
+        "selector": {"type": "spec_name", "value": dependency}
+    }]);
+    let response = update_spec::run(update_spec::Input {
+        project_name: project.to_string(),
+        spec_name: spec.to_string(),
+        commands_json: commands.to_string(),
+        expected_version: None,
+        dry_run: false,
+    })
+    .await?;
+    Ok(response.data.applied_count)
+}
+
+async fn graph(project: &str) -> foundry_mcp::types::responses::SpecGraphResponse {
+    spec_graph::run(spec_graph::Input {
+        project_name: project.to_string(),
+    })
+    .await
+    .unwrap()
+    .data
+}
+
+#[test]
+fn test_dependencies_drive_graph_and_ready_specs() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("graph-app").await.unwrap();
+        let schema = env
+            .create_bare_spec("graph-app", "schema", "# schema")
+            .await
+            .unwrap();
+        let api = env
+            .create_bare_spec("graph-app", "api", "# api")
+            .await
+            .unwrap();
+        let ui = env
+            .create_bare_spec("graph-app", "ui", "# ui")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            dependency_command("graph-app", &ui, "add_dependency", &api)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            dependency_command("graph-app", &api, "add_dependency", &schema)
+                .await
+                .unwrap(),
+            1
+        );
+        // Adding an existing dependency is idempotent
+        assert_eq!(
+            dependency_command("graph-app", &api, "add_dependency", &schema)
+                .await
+                .unwrap(),
+            0
+        );
+
+        let data = graph("graph-app").await;
+        assert_eq!(
+            data.build_order,
+            vec![schema.clone(), api.clone(), ui.clone()]
+        );
+        assert_eq!(data.ready_specs, vec![schema.clone()]);
+        assert_eq!(data.edge_count, 2);
+        assert!(!data.has_cycles);
+        assert_eq!(data.nodes[0].dependents, vec![api.clone()]);
+        assert_eq!(data.nodes[2].blocked_by, vec![api.clone()]);
+
+        // Finishing the schema unblocks the API
+        set_spec_status::run(set_spec_status::Input {
+            project_name: "graph-app".to_string(),
+            spec_name: schema.clone(),
+            status: "completed".to_string(),
+        })
+        .await
+        .unwrap();
+        assert_eq!(graph("graph-app").await.ready_specs, vec![api.clone()]);
+
+        // list_specs reports open blockers and filters by what a spec unblocks
+        let listed = list_specs::run(list_specs::Input {
+            project_name: "graph-app".to_string(),
+            status: None,
+            blocked_by: Some("api".to_string()),
+            tags: Vec::new(),
+            cursor: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(listed.data.total_count, 1);
+        assert_eq!(listed.data.specs[0].name, ui);
+        assert_eq!(listed.data.specs[0].depends_on, vec![api.clone()]);
+        assert_eq!(listed.data.specs[0].blocked_by, vec![api.clone()]);
+
+        assert_eq!(
+            dependency_command("graph-app", &ui, "remove_dependency", &api)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(graph("graph-app").await.ready_specs, vec![api, ui]);
+    });
+}
+
+#[test]
+fn test_invalid_dependencies_are_rejected() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("graph-errors").await.unwrap();
+        let first = env
+            .create_bare_spec("graph-errors", "first", "# first")
+            .await
+            .unwrap();
+        let second = env
+            .create_bare_spec("graph-errors", "second", "# second")
+            .await
+            .unwrap();
+
+        dependency_command("graph-errors", &second, "add_dependency", &first)
+            .await
+            .unwrap();
+
+        let error = dependency_command("graph-errors", &first, "add_dependency", &second)
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+        assert!(error.to_string().contains("cycle"), "{}", error);
+
+        let error = dependency_command("graph-errors", &first, "add_dependency", &first)
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+
+        let error = dependency_command(
+            "graph-errors",
+            &first,
+            "add_dependency",
+            "20200101_000000_missing",
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::SpecNotFound);
+
+        // Nothing was stored by the rejected commands
+        assert_eq!(graph("graph-errors").await.build_order, vec![first, second]);
+    });
+}
@@ -0,0 +1,150 @@
+//! Integration tests for fuzzy spec name resolution in load_spec
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::load_spec;
+use foundry_mcp::types::errors::ErrorCode;
+use foundry_mcp::types::responses::{FoundryResponse, LoadSpecResponse, ValidationStatus};
+
+async fn load(query: &str) -> anyhow::Result<FoundryResponse<LoadSpecResponse>> {
+    load_spec::run(load_spec::Input {
+        project_name: "match-app".to_string(),
+        spec_name: Some(query.to_string()),
+        max_tokens: None,
+        part: None,
+        files: None,
+    })
+    .await
+}
+
+fn match_type(response: &FoundryResponse<LoadSpecResponse>) -> &str {
+    &response.data.match_info.as_ref().unwrap().match_type
+}
+
+#[test]
+fn test_load_spec_resolves_fuzzy_names() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("match-app").await.unwrap();
+        for feature in ["user_authentication", "user_profile", "payment_processing"] {
+            env.create_test_spec("match-app", feature, "Feature under test")
+                .await
+                .unwrap();
+        }
+
+        let response = load("payment").await.unwrap();
+        assert_eq!(match_type(&response), "feature_substring");
+        assert!(
+            response
+                .data
+                .spec_name
+                .unwrap()
+                .ends_with("_payment_processing")
+        );
+
+        let response = load("User Authentication").await.unwrap();
+        assert_eq!(match_type(&response), "feature_exact");
+
+        let response = load("user_authentcation").await.unwrap();
+        assert_eq!(match_type(&response), "feature_fuzzy");
+        assert!(
+            response
+                .data
+                .spec_name
+                .unwrap()
+                .ends_with("_user_authentication")
+        );
+
+        let error = load("zzzz").await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::SpecNotFound);
+        assert!(error.to_string().contains("No spec found matching"));
+    });
+}
+
+#[test]
+fn test_load_spec_returns_candidates_when_ambiguous() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("match-app").await.unwrap();
+        for feature in ["user_authentication", "user_profile"] {
+            env.create_test_spec("match-app", feature, "Feature under test")
+                .await
+                .unwrap();
+        }
+
+        let response = load("user").await.unwrap();
+        assert_eq!(response.validation_status, ValidationStatus::Incomplete);
+        assert!(response.data.spec_content.is_none());
+        let candidates = &response.data.candidates;
+        assert_eq!(candidates.len(), 2);
+        assert!(
+            candidates
+                .iter()
+                .all(|candidate| candidate.match_type == "feature_substring")
+        );
+        assert!(
+            response
+                .next_steps
+                .iter()
+                .any(|step| step.contains(&candidates[0].spec_name))
+        );
+
+        // A dashed date matches every spec created that day
+        let date = &candidates[0].spec_name[..8];
+        let dashed = format!("{}-{}-{}", &date[..4], &date[4..6], &date[6..]);
+        let response = load(&dashed).await.unwrap();
+        assert_eq!(response.data.candidates.len(), 2);
+        assert_eq!(response.data.candidates[0].match_type, "date_prefix");
+    });
+}
+
+#[test]
+fn test_numbered_spec_names_list_load_and_match() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("match-app").await.unwrap();
+        std::fs::write(
+            env.foundry_dir().join("match-app/.foundry.toml"),
+            "[spec_names]\nstrategy = \"sequential\"\n",
+        )
+        .unwrap();
+        for feature in ["auth", "billing"] {
+            env.create_test_spec("match-app", feature, "Feature under test")
+                .await
+                .unwrap();
+        }
+        std::fs::write(
+            env.foundry_dir().join("match-app/.foundry.toml"),
+            "[spec_names]\nstrategy = \"prefix\"\nprefix = \"RFC\"\n",
+        )
+        .unwrap();
+        env.create_test_spec("match-app", "search", "Feature under test")
+            .await
+            .unwrap();
+
+        let foundry = get_default_foundry().unwrap();
+        let mut names: Vec<String> = foundry
+            .list_specs("match-app")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|spec| spec.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, ["0001_auth", "0002_billing", "RFC-0001_search"]);
+
+        let response = load("2").await.unwrap();
+        assert_eq!(match_type(&response), "id_exact");
+        assert_eq!(response.data.spec_name.unwrap(), "0002_billing");
+
+        let response = load("rfc-1").await.unwrap();
+        assert_eq!(response.data.spec_name.unwrap(), "RFC-0001_search");
+
+        // Numbered names carry no date, so date queries match the creation time
+        let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        let response = load(&today).await.unwrap();
+        assert_eq!(response.data.candidates.len(), 3);
+    });
+}
@@ -0,0 +1,202 @@
+//! Integration tests for spec lifecycle statuses
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::{archive_spec, list_specs, needs_attention, set_spec_status};
+use foundry_mcp::types::errors::ErrorCode;
+use foundry_mcp::types::spec::{SpecFileType, SpecStatus};
+use std::fs;
+
+async fn listed(project: &str, status: Option<&str>) -> Vec<(String, SpecStatus)> {
+    list_specs::run(list_specs::Input {
+        project_name: project.to_string(),
+        status: status.map(str::to_string),
+        blocked_by: None,
+        tags: Vec::new(),
+        cursor: None,
+        limit: None,
+    })
+    .await
+    .unwrap()
+    .data
+    .specs
+    .into_iter()
+    .map(|spec| (spec.name, spec.status))
+    .collect()
+}
+
+#[test]
+fn test_spec_lifecycle_and_list_filtering() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("lifecycle-app").await.unwrap();
+        let old = env.write_old_spec("lifecycle-app", "legacy", "- [ ] Finish");
+        let retired = env.write_old_spec("lifecycle-app", "retired", "- [ ] Finish");
+
+        // Specs start as drafts
+        assert_eq!(
+            listed("lifecycle-app", None).await,
+            vec![
+                (retired.clone(), SpecStatus::Draft),
+                (old.clone(), SpecStatus::Draft)
+            ]
+        );
+
+        let response = set_spec_status::run(set_spec_status::Input {
+            project_name: "lifecycle-app".to_string(),
+            spec_name: old.clone(),
+            status: "Active".to_string(),
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.previous_status, SpecStatus::Draft);
+        assert_eq!(response.data.status, SpecStatus::Active);
+
+        let response = archive_spec::run(archive_spec::Input {
+            project_name: "lifecycle-app".to_string(),
+            spec_name: retired.clone(),
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.status, SpecStatus::Archived);
+        assert!(
+            env.foundry_dir()
+                .join("lifecycle-app/specs")
+                .join(&retired)
+                .join("spec.md")
+                .exists()
+        );
+
+        // Archived specs are hidden unless asked for
+        assert_eq!(
+            listed("lifecycle-app", None).await,
+            vec![(old.clone(), SpecStatus::Active)]
+        );
+        assert_eq!(
+            listed("lifecycle-app", Some("archived")).await,
+            vec![(retired.clone(), SpecStatus::Archived)]
+        );
+        assert_eq!(listed("lifecycle-app", Some("all")).await.len(), 2);
+        assert!(listed("lifecycle-app", Some("completed")).await.is_empty());
+
+        // Only the active spec is still stale
+        let stale = needs_attention::run(needs_attention::Input {
+            project_name: Some("lifecycle-app".to_string()),
+            stale_days: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(stale.data.total_count, 1);
+        assert_eq!(stale.data.specs[0].spec_name, old);
+    });
+}
+
+#[test]
+fn test_spec_status_errors() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("status-errors").await.unwrap();
+        let spec = env.write_old_spec("status-errors", "feature", "- [ ] Finish");
+
+        let error = set_spec_status::run(set_spec_status::Input {
+            project_name: "status-errors".to_string(),
+            spec_name: spec,
+            status: "shipped".to_string(),
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+        assert!(
+            error
+                .to_string()
+                .contains("draft, active, completed, archived")
+        );
+
+        let error = archive_spec::run(archive_spec::Input {
+            project_name: "status-errors".to_string(),
+            spec_name: "20200101_090000_missing".to_string(),
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::SpecNotFound);
+
+        let error = list_specs::run(list_specs::Input {
+            project_name: "status-errors".to_string(),
+            status: Some("done".to_string()),
+            blocked_by: None,
+            tags: Vec::new(),
+            cursor: None,
+            limit: None,
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+    });
+}
+
+#[test]
+fn test_corrupt_metadata_is_not_overwritten() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("corrupt-app").await.unwrap();
+        let spec = env.write_old_spec("corrupt-app", "feature", "- [ ] Finish");
+        let metadata = env
+            .foundry_dir()
+            .join("corrupt-app/specs")
+            .join(&spec)
+            .join("metadata.json");
+        fs::write(&metadata, "{\"status\": \"active\", \"tags\": [").unwrap();
+
+        let error = set_spec_status::run(set_spec_status::Input {
+            project_name: "corrupt-app".to_string(),
+            spec_name: spec.clone(),
+            status: "completed".to_string(),
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+        assert!(format!("{:#}", error).contains("foundry doctor --fix"));
+
+        // Ordinary edits fail too, leaving both the file and the metadata alone
+        let error = get_default_foundry()
+            .unwrap()
+            .update_spec_content("corrupt-app", &spec, SpecFileType::Notes, "Edited")
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::ValidationFailed);
+        assert_eq!(
+            fs::read_to_string(metadata.with_file_name("notes.md")).unwrap(),
+            "Notes"
+        );
+        assert_eq!(
+            fs::read_to_string(&metadata).unwrap(),
+            "{\"status\": \"active\", \"tags\": ["
+        );
+
+        // Listings skip the corrupt entry instead of failing as a whole
+        let healthy = env.write_old_spec("corrupt-app", "healthy", "- [ ] Finish");
+        let names: Vec<String> = listed("corrupt-app", None)
+            .await
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, vec![healthy]);
+        env.create_test_project("other-app").await.unwrap();
+        fs::write(
+            env.foundry_dir().join("corrupt-app/metadata.json"),
+            "not json",
+        )
+        .unwrap();
+        let projects = get_default_foundry()
+            .unwrap()
+            .list_projects()
+            .await
+            .unwrap();
+        assert_eq!(
+            projects.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            vec!["other-app"]
+        );
+    });
+}
@@ -7,8 +7,11 @@ mod common;
 
 use common::{TestEnvironment, UpdateSpecArgs};
 use foundry_mcp::cli::args::LoadSpecArgs;
-use foundry_mcp::core::ops::{create_project, create_spec, delete_spec, load_spec, update_spec};
+use foundry_mcp::core::ops::{
+    create_project, create_spec, delete_spec, diagram_specs, load_spec, update_spec,
+};
 use foundry_mcp::types::edit_commands::EditCommandTarget;
+use foundry_mcp::types::errors::ErrorCode;
 use foundry_mcp::types::responses::ValidationStatus;
 
 /// Test creating a spec for an existing project
@@ -36,6 +39,8 @@ fn test_create_spec_full_workflow() {
             spec: spec_args.content.spec,
             notes: spec_args.content.notes,
             tasks: spec_args.content.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -82,6 +87,8 @@ fn test_error_spec_missing_project() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await;
 
@@ -94,6 +101,65 @@ fn test_error_spec_missing_project() {
     });
 }
 
+/// Test creating a spec from a user-defined spec template
+#[test]
+fn test_create_spec_from_template() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("template-test").await.unwrap();
+        let templates_dir = env.foundry_dir().join(".templates");
+        std::fs::create_dir_all(templates_dir.join("specs")).unwrap();
+        std::fs::write(
+            templates_dir.join("variables.json"),
+            r#"{"team": "Payments"}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            templates_dir.join("specs").join("security.md"),
+            "# {{feature_title}}\n\nOwner: {{team}} ({{project_name}})\n\n{{spec}}\n\n## Security Review\n\n- Threat model reviewed\n",
+        )
+        .unwrap();
+
+        let spec_args = env.create_spec_args("template-test", "payment_refunds");
+        let input = |template: &str| create_spec::Input {
+            project_name: spec_args.project_name.clone(),
+            feature_name: spec_args.feature_name.clone(),
+            spec: spec_args.spec.clone(),
+            notes: spec_args.notes.clone(),
+            tasks: spec_args.tasks.clone(),
+            template: Some(template.to_string()),
+            allow_duplicate: false,
+        };
+
+        let response = create_spec::run(input("security")).await.unwrap();
+        let loaded = load_spec::run(load_spec::Input {
+            project_name: "template-test".to_string(),
+            spec_name: Some(response.data.spec_name.clone()),
+            max_tokens: None,
+            part: None,
+            files: None,
+        })
+        .await
+        .unwrap();
+        let spec = loaded.data.spec_content.unwrap().content.spec;
+        assert!(spec.starts_with("# Payment Refunds\n\nOwner: Payments (template-test)\n\n# Feature Name"));
+        assert!(spec.ends_with("## Security Review\n\n- Threat model reviewed\n"));
+        assert!(
+            response
+                .workflow_hints
+                .iter()
+                .any(|hint| hint.contains("rendered from the 'security' template"))
+        );
+
+        let error = create_spec::run(input("missing")).await.unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Spec template 'missing' not found. Available templates: security")
+        );
+    });
+}
+
 /// Test load_spec listing functionality (no spec_name provided)
 #[test]
 fn test_load_spec_list_empty_project() {
@@ -116,11 +182,17 @@ fn test_load_spec_list_empty_project() {
         let load_args = LoadSpecArgs {
             project_name: project_name.to_string(),
             spec_name: None,
+            max_tokens: None,
+            part: None,
+            files: None,
         };
 
         let response = load_spec::run(load_spec::Input {
             project_name: load_args.project_name,
             spec_name: load_args.spec_name,
+            max_tokens: None,
+            part: None,
+            files: None,
         })
         .await
         .unwrap();
@@ -181,6 +253,8 @@ fn test_load_spec_list_with_specs() {
             spec: spec1_args.spec,
             notes: spec1_args.notes,
             tasks: spec1_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -192,6 +266,8 @@ fn test_load_spec_list_with_specs() {
             spec: spec2_args.spec,
             notes: spec2_args.notes,
             tasks: spec2_args.tasks,
+            template: None,
+            allow_duplicate: true,
         })
         .await
         .unwrap();
@@ -200,11 +276,17 @@ fn test_load_spec_list_with_specs() {
         let load_args = LoadSpecArgs {
             project_name: project_name.to_string(),
             spec_name: None,
+            max_tokens: None,
+            part: None,
+            files: None,
         };
 
         let response = load_spec::run(load_spec::Input {
             project_name: load_args.project_name,
             spec_name: load_args.spec_name,
+            max_tokens: None,
+            part: None,
+            files: None,
         })
         .await
         .unwrap();
@@ -263,16 +345,19 @@ fn test_load_spec_specific_spec() {
 
         // Create a spec
         let spec_args = env.create_spec_args(project_name, "payment_system");
-        let spec_response = create_spec::run(create_spec::Input { project_name: spec_args.project_name, feature_name: spec_args.feature_name, spec: spec_args.spec, notes: spec_args.notes, tasks: spec_args.tasks }).await.unwrap();
+        let spec_response = create_spec::run(create_spec::Input { project_name: spec_args.project_name, feature_name: spec_args.feature_name, spec: spec_args.spec, notes: spec_args.notes, tasks: spec_args.tasks, template: None, allow_duplicate: false }).await.unwrap();
         let spec_name = spec_response.data.spec_name;
 
         // Load the specific spec
         let load_args = LoadSpecArgs {
         project_name: project_name.to_string(),
         spec_name: Some(spec_name.clone()),
+        max_tokens: None,
+        part: None,
+        files: None,
         };
 
-        let response = load_spec::run(load_spec::Input { project_name: load_args.project_name, spec_name: load_args.spec_name }).await.unwrap();
+        let response = load_spec::run(load_spec::Input { project_name: load_args.project_name, spec_name: load_args.spec_name, max_tokens: None, part: None, files: None }).await.unwrap();
 
         // Verify response structure
         assert_eq!(response.data.project_name, project_name);
@@ -336,11 +421,17 @@ fn test_load_spec_missing_project() {
         let load_args = LoadSpecArgs {
             project_name: "non-existent-project".to_string(),
             spec_name: None,
+            max_tokens: None,
+            part: None,
+            files: None,
         };
 
         let result = load_spec::run(load_spec::Input {
             project_name: load_args.project_name,
             spec_name: load_args.spec_name,
+            max_tokens: None,
+            part: None,
+            files: None,
         })
         .await;
         assert!(result.is_err());
@@ -373,11 +464,17 @@ fn test_load_spec_missing_spec() {
         let load_args = LoadSpecArgs {
             project_name: project_name.to_string(),
             spec_name: Some("20240101_120000_nonexistent".to_string()),
+            max_tokens: None,
+            part: None,
+            files: None,
         };
 
         let result = load_spec::run(load_spec::Input {
             project_name: load_args.project_name,
             spec_name: load_args.spec_name,
+            max_tokens: None,
+            part: None,
+            files: None,
         })
         .await;
         assert!(result.is_err());
@@ -409,11 +506,17 @@ fn test_load_spec_invalid_spec_name() {
         let load_args = LoadSpecArgs {
             project_name: project_name.to_string(),
             spec_name: Some("invalid-spec-name".to_string()),
+            max_tokens: None,
+            part: None,
+            files: None,
         };
 
         let result = load_spec::run(load_spec::Input {
             project_name: load_args.project_name,
             spec_name: load_args.spec_name,
+            max_tokens: None,
+            part: None,
+            files: None,
         })
         .await;
         assert!(result.is_err());
@@ -445,10 +548,16 @@ fn test_load_spec_end_to_end_workflow() {
         let list_args = LoadSpecArgs {
             project_name: project_name.to_string(),
             spec_name: None,
+            max_tokens: None,
+            part: None,
+            files: None,
         };
         let list_response = load_spec::run(load_spec::Input {
             project_name: list_args.project_name,
             spec_name: list_args.spec_name,
+            max_tokens: None,
+            part: None,
+            files: None,
         })
         .await
         .unwrap();
@@ -466,6 +575,8 @@ fn test_load_spec_end_to_end_workflow() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -475,10 +586,16 @@ fn test_load_spec_end_to_end_workflow() {
         let list_args2 = LoadSpecArgs {
             project_name: project_name.to_string(),
             spec_name: None,
+            max_tokens: None,
+            part: None,
+            files: None,
         };
         let list_response2 = load_spec::run(load_spec::Input {
             project_name: list_args2.project_name,
             spec_name: list_args2.spec_name,
+            max_tokens: None,
+            part: None,
+            files: None,
         })
         .await
         .unwrap();
@@ -492,10 +609,16 @@ fn test_load_spec_end_to_end_workflow() {
         let load_args = LoadSpecArgs {
             project_name: project_name.to_string(),
             spec_name: Some(spec_name.clone()),
+            max_tokens: None,
+            part: None,
+            files: None,
         };
         let load_response = load_spec::run(load_spec::Input {
             project_name: load_args.project_name,
             spec_name: load_args.spec_name,
+            max_tokens: None,
+            part: None,
+            files: None,
         })
         .await
         .unwrap();
@@ -543,6 +666,8 @@ fn test_update_spec_replace() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -554,6 +679,8 @@ fn test_update_spec_replace() {
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -619,6 +746,8 @@ fn test_update_spec_append() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -630,6 +759,8 @@ fn test_update_spec_append() {
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -681,6 +812,8 @@ fn test_update_spec_task_list() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -708,6 +841,8 @@ fn test_update_spec_task_list() {
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -744,6 +879,8 @@ fn test_update_spec_error_handling() {
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await;
         assert!(result.is_err());
@@ -766,6 +903,8 @@ fn test_update_spec_error_handling() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -778,6 +917,8 @@ fn test_update_spec_error_handling() {
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await;
         assert!(result.is_err());
@@ -807,6 +948,8 @@ fn test_delete_spec_success() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -901,6 +1044,8 @@ fn test_delete_spec_error_handling() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -949,6 +1094,8 @@ fn test_spec_lifecycle_workflow() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -960,6 +1107,8 @@ fn test_spec_lifecycle_workflow() {
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -974,6 +1123,8 @@ fn test_spec_lifecycle_workflow() {
             project_name: append_args.project_name,
             spec_name: append_args.spec_name,
             commands_json: append_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -989,6 +1140,8 @@ fn test_spec_lifecycle_workflow() {
             project_name: task_args.project_name,
             spec_name: task_args.spec_name,
             commands_json: task_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -999,10 +1152,16 @@ fn test_spec_lifecycle_workflow() {
         let load_args = LoadSpecArgs {
             project_name: "lifecycle-project".to_string(),
             spec_name: Some(spec_name.clone()),
+            max_tokens: None,
+            part: None,
+            files: None,
         };
         let load_response = load_spec::run(load_spec::Input {
             project_name: load_args.project_name,
             spec_name: load_args.spec_name,
+            max_tokens: None,
+            part: None,
+            files: None,
         })
         .await
         .unwrap();
@@ -1070,6 +1229,8 @@ fn test_remove_list_item() {
             notes: spec_args.notes,
             tasks: "- [ ] Task to remove\n- [ ] Task to keep\n- [ ] Another task to keep"
                 .to_string(),
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -1092,6 +1253,8 @@ fn test_remove_list_item() {
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -1155,6 +1318,8 @@ This will be implemented using standard patterns.
             spec: spec_content.to_string(),
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -1178,6 +1343,8 @@ This will be implemented using standard patterns.
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -1245,6 +1412,8 @@ Comprehensive test coverage will be implemented.
             spec: spec_args.spec,
             notes: notes_content.to_string(),
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -1267,6 +1436,8 @@ Comprehensive test coverage will be implemented.
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -1347,6 +1518,8 @@ Since this fixes bugs rather than adding features, no migration is needed. Howev
             spec: spec_content.to_string(),
             notes: notes_content.to_string(),
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -1391,6 +1564,8 @@ Since this fixes bugs rather than adding features, no migration is needed. Howev
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -1455,6 +1630,8 @@ fn test_replace_list_item() {
             tasks:
                 "- [ ] Implement basic authentication\n- [ ] Add error handling\n- [ ] Write tests"
                     .to_string(),
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -1478,6 +1655,8 @@ fn test_replace_list_item() {
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -1544,6 +1723,8 @@ This will be implemented using standard patterns with PostgreSQL.
             spec: spec_content.to_string(),
             notes: spec_args.notes,
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -1567,6 +1748,8 @@ This will be implemented using standard patterns with PostgreSQL.
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -1632,6 +1815,8 @@ Basic unit tests will be sufficient for this project.
             spec: spec_args.spec,
             notes: notes_content.to_string(),
             tasks: spec_args.tasks,
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -1662,6 +1847,8 @@ Basic unit tests will be sufficient for this project.
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -1725,6 +1912,8 @@ fn test_replacement_idempotent_behavior() {
             spec: spec_args.spec,
             notes: spec_args.notes,
             tasks: "- [ ] Initial task\n- [ ] Second task".to_string(),
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -1748,6 +1937,8 @@ fn test_replacement_idempotent_behavior() {
             project_name: update_args.project_name.clone(),
             spec_name: update_args.spec_name.clone(),
             commands_json: update_args.commands_json.clone(),
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -1776,6 +1967,8 @@ fn test_replacement_idempotent_behavior() {
             project_name: idempotent_args.project_name,
             spec_name: idempotent_args.spec_name,
             commands_json: idempotent_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -1860,6 +2053,8 @@ MySQL 5.7 has known limitations that will be addressed in this migration.
             spec: spec_content.to_string(),
             notes: notes_content.to_string(),
             tasks: tasks_content.to_string(),
+            template: None,
+            allow_duplicate: false,
         })
         .await
         .unwrap();
@@ -1950,6 +2145,8 @@ MySQL 5.7 has known limitations that will be addressed in this migration.
             project_name: update_args.project_name,
             spec_name: update_args.spec_name,
             commands_json: update_args.commands_json,
+            expected_version: None,
+            dry_run: false,
         })
         .await
         .unwrap();
@@ -2013,3 +2210,232 @@ MySQL 5.7 has known limitations that will be addressed in this migration.
         assert!(!tasks_updated.contains("Create migration scripts for MySQL 5.7 compatibility"));
     });
 }
+
+/// Test that [[wiki-links]] are resolved in load_spec and rewritten across a project
+#[test]
+fn test_wiki_links_resolved_and_rewritten() {
+    let env = TestEnvironment::new().unwrap();
+
+    env.with_env_async(|| async {
+        env.create_test_project("links-project").await.unwrap();
+        env.create_test_spec("links-project", "billing", "Billing")
+            .await
+            .unwrap();
+        env.create_test_spec(
+            "links-project",
+            "checkout",
+            "Depends on [[billing]], the [[vision]], and [[missing_spec]]",
+        )
+        .await
+        .unwrap();
+
+        let response = load_spec::run(load_spec::Input {
+            project_name: "links-project".to_string(),
+            spec_name: Some("checkout".to_string()),
+            max_tokens: None,
+            part: None,
+            files: None,
+        })
+        .await
+        .unwrap();
+
+        let links = &response.data.links;
+        let billing = links.iter().find(|l| l.target == "billing").unwrap();
+        assert!(billing.resolved_to.as_ref().unwrap().ends_with("_billing"));
+        let vision = links.iter().find(|l| l.target == "vision").unwrap();
+        assert_eq!(vision.resolved_to.as_deref(), Some("vision.md"));
+        let missing = links.iter().find(|l| l.target == "missing_spec").unwrap();
+        assert!(missing.resolved_to.is_none());
+        assert!(
+            response
+                .workflow_hints
+                .iter()
+                .any(|h| h.contains("Unresolved [[links]]: missing_spec"))
+        );
+
+        let foundry = foundry_mcp::core::foundry::get_default_foundry().unwrap();
+        let rewritten = foundry
+            .rewrite_wiki_links("links-project", &["billing"], "payments")
+            .await
+            .unwrap();
+        // Linked from both spec.md and notes.md of the checkout spec
+        assert_eq!(rewritten, 2);
+
+        let checkout_name = response.data.spec_name.unwrap();
+        let spec = foundry
+            .load_spec("links-project", &checkout_name)
+            .await
+            .unwrap();
+        assert!(spec.content.spec.contains("[[payments]]"));
+        assert!(!spec.content.spec.contains("[[billing]]"));
+    });
+}
+
+/// Test `[[spec:...]]` references: backlinks in load_spec, hints on update, rewrite on rename
+#[test]
+fn test_spec_references_backlinks_and_validation() {
+    let env = TestEnvironment::new().unwrap();
+
+    env.with_env_async(|| async {
+        env.create_test_project("refs-project").await.unwrap();
+        env.create_test_spec("refs-project", "auth", "Auth")
+            .await
+            .unwrap();
+        env.create_test_spec("refs-project", "session", "Builds on [[spec:auth]]")
+            .await
+            .unwrap();
+        let foundry = foundry_mcp::core::foundry::get_default_foundry().unwrap();
+        let specs = foundry.list_specs("refs-project").await.unwrap();
+        let name_of = |feature: &str| {
+            specs
+                .iter()
+                .find(|spec| spec.feature_name == feature)
+                .unwrap()
+                .name
+                .clone()
+        };
+        let (auth, session) = (name_of("auth"), name_of("session"));
+
+        let response = load_spec::run(load_spec::Input {
+            project_name: "refs-project".to_string(),
+            spec_name: Some(auth.clone()),
+            max_tokens: None,
+            part: None,
+            files: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.data.backlinks, vec![session.clone()]);
+
+        // Spec references never resolve to project documents
+        let response = update_spec::run(update_spec::Input {
+            project_name: "refs-project".to_string(),
+            spec_name: session.clone(),
+            commands_json: r###"[{"target": "spec", "command": "append_to_section", "selector": {"type": "section", "value": "## Overview"}, "content": "See [[spec:vision]] and [[vision]]."}]"###.to_string(),
+            expected_version: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+        assert!(
+            response
+                .workflow_hints
+                .iter()
+                .any(|h| h.contains("Unresolved [[links]] added: spec:vision -")),
+            "{:?}",
+            response.workflow_hints
+        );
+
+        foundry
+            .rewrite_wiki_links("refs-project", &["auth"], "login")
+            .await
+            .unwrap();
+        let spec = foundry.load_spec("refs-project", &session).await.unwrap();
+        assert!(spec.content.spec.contains("Builds on [[spec:login]]"));
+    });
+}
+
+#[test]
+fn test_diagram_specs_renders_links_and_phases() {
+    let env = TestEnvironment::new().unwrap();
+
+    env.with_env_async(|| async {
+        env.create_test_project("diagram-project").await.unwrap();
+        env.create_test_spec("diagram-project", "billing", "Billing")
+            .await
+            .unwrap();
+        env.create_test_spec("diagram-project", "checkout", "Depends on [[billing]]")
+            .await
+            .unwrap();
+        env.create_test_spec("diagram-project", "reporting", "Standalone")
+            .await
+            .unwrap();
+
+        let response = diagram_specs::run(diagram_specs::Input {
+            project_name: "diagram-project".to_string(),
+            spec_name: None,
+            include_phases: false,
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(response.data.spec_count, 3);
+        assert_eq!(response.data.edge_count, 1);
+        let diagram = &response.data.dependency_diagram;
+        assert!(diagram.starts_with("graph TD\n"));
+        assert!(diagram.contains("[\"checkout\"]"));
+        assert!(response.data.phase_diagrams.is_empty());
+
+        let focused = diagram_specs::run(diagram_specs::Input {
+            project_name: "diagram-project".to_string(),
+            spec_name: Some("billing".to_string()),
+            include_phases: true,
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(focused.data.spec_count, 2);
+        assert!(!focused.data.dependency_diagram.contains("reporting"));
+        assert_eq!(focused.data.phase_diagrams.len(), 1);
+        let phases = &focused.data.phase_diagrams[0].diagram;
+        assert!(phases.contains("Setup Phase (0/"));
+        assert!(phases.contains("p0 --> p1"));
+
+        let missing = diagram_specs::run(diagram_specs::Input {
+            project_name: "diagram-project".to_string(),
+            spec_name: Some("nonexistent".to_string()),
+            include_phases: false,
+        })
+        .await;
+        assert!(missing.is_err());
+    });
+}
+
+#[test]
+fn test_create_spec_flags_possible_duplicates() {
+    let env = TestEnvironment::new().unwrap();
+
+    env.with_env_async(|| async {
+        env.create_test_project("dup-project").await.unwrap();
+        let input = |feature_name: &str, overview: &str, allow_duplicate: bool| create_spec::Input {
+            project_name: "dup-project".to_string(),
+            feature_name: feature_name.to_string(),
+            spec: format!("# {}\n\n## Overview\n\n{}\n\n## Requirements\n\n- Sessions expire after 30 minutes of inactivity", feature_name, overview),
+            notes: "## Context\n\nAgents asked for this during the security review.".to_string(),
+            tasks: "## Phase 1\n\n- [ ] Add the session store\n- [ ] Expire idle sessions".to_string(),
+            template: None,
+            allow_duplicate,
+        };
+        let overview = "Users sign in with email and password and stay signed in across browser restarts until they sign out.";
+        create_spec::run(input("user_login", overview, false)).await.unwrap();
+
+        let duplicates = |error: &anyhow::Error| {
+            assert_eq!(ErrorCode::of(error), ErrorCode::AlreadyExists);
+            ErrorCode::details_of(error).unwrap()["possible_duplicates"]
+                .as_array()
+                .unwrap()
+                .clone()
+        };
+        let error = create_spec::run(input("user_logins", "Something else entirely.", false))
+            .await
+            .unwrap_err();
+        let found = duplicates(&error);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0]["match_type"], "feature_fuzzy");
+        assert!(error.to_string().contains("allow_duplicate: true"));
+
+        let similar_overview = overview.replace("browser restarts", "browser restarts and updates");
+        let error = create_spec::run(input("remember_me", &similar_overview, false))
+            .await
+            .unwrap_err();
+        assert_eq!(duplicates(&error)[0]["match_type"], "overview_similar");
+
+        let response = create_spec::run(input("remember_me", &similar_overview, true))
+            .await
+            .unwrap();
+        assert!(response.data.spec_name.ends_with("_remember_me"));
+
+        let foundry = foundry_mcp::core::foundry::get_default_foundry().unwrap();
+        assert_eq!(foundry.list_specs("dup-project").await.unwrap().len(), 2);
+    });
+}
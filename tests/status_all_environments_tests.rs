@@ -1,7 +1,7 @@
 //! Integration tests for Foundry CLI status commands across all environments
 //!
 //! These tests verify the status reporting functionality when querying all
-//! supported environments (cursor and claude-code) simultaneously.
+//! supported environments simultaneously.
 
 use anyhow::Result;
 
@@ -17,11 +17,11 @@ fn test_status_all_environments() -> Result<()> {
         // Test status for all environments (no target specified)
         let status_response = env.get_status_response(None, false).await?;
 
-        // Should return status for both claude-code and cursor
+        // Should return status for claude-code, cursor, vscode, windsurf and zed
         assert_eq!(
             status_response.environments.len(),
-            2,
-            "Should return status for both environments"
+            5,
+            "Should return status for all environments"
         );
 
         let env_names: Vec<&String> = status_response
@@ -37,6 +37,18 @@ fn test_status_all_environments() -> Result<()> {
             env_names.contains(&&"cursor".to_string()),
             "Should include cursor"
         );
+        assert!(
+            env_names.contains(&&"vscode".to_string()),
+            "Should include vscode"
+        );
+        assert!(
+            env_names.contains(&&"windsurf".to_string()),
+            "Should include windsurf"
+        );
+        assert!(
+            env_names.contains(&&"zed".to_string()),
+            "Should include zed"
+        );
 
         // Neither should be installed initially
         for env_status in &status_response.environments {
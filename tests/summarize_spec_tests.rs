@@ -0,0 +1,125 @@
+//! Integration tests for spec summaries drafted through sampling
+
+mod common;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::TestEnvironment;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::ops::{list_specs, summarize_spec, update_spec};
+use foundry_mcp::core::sampling::{self, SampledText, Sampler, SamplingRequest};
+use foundry_mcp::types::errors::ErrorCode;
+
+/// Sampler answering every request with the same text, counting requests
+#[derive(Default)]
+struct FixedSampler {
+    calls: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Sampler for FixedSampler {
+    async fn sample(&self, request: SamplingRequest) -> anyhow::Result<SampledText> {
+        assert!(request.prompt.contains("at most 40 words"));
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(SampledText {
+            text: "  Adds login with short-lived JWTs.\n".to_string(),
+            model: Some("test-model".to_string()),
+        })
+    }
+}
+
+fn input(spec_name: &str) -> summarize_spec::Input {
+    summarize_spec::Input {
+        project_name: "summary-app".to_string(),
+        spec_name: spec_name.to_string(),
+        force: false,
+        max_words: Some(40),
+    }
+}
+
+#[test]
+fn test_summaries_are_sampled_stored_and_listed() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("summary-app").await.unwrap();
+        env.create_test_spec("summary-app", "auth", "Authentication")
+            .await
+            .unwrap();
+        let spec_name = get_default_foundry()
+            .unwrap()
+            .list_specs("summary-app")
+            .await
+            .unwrap()[0]
+            .name
+            .clone();
+
+        // Without a sampling client there is nothing to draft with
+        let error = summarize_spec::run(input(&spec_name)).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::SamplingUnavailable);
+
+        let sampler = Arc::new(FixedSampler::default());
+        let response = sampling::with_sampler(
+            sampler.clone(),
+            summarize_spec::run(input(&spec_name)),
+        )
+        .await
+        .unwrap()
+        .data;
+        assert!(response.regenerated);
+        assert_eq!(response.summary.text, "Adds login with short-lived JWTs.");
+        assert_eq!(response.summary.model.as_deref(), Some("test-model"));
+
+        let listed = list_specs::run(list_specs::Input {
+            project_name: "summary-app".to_string(),
+            status: None,
+            blocked_by: None,
+            tags: Vec::new(),
+            cursor: None,
+            limit: None,
+        })
+        .await
+        .unwrap()
+        .data;
+        assert_eq!(
+            listed.specs[0].summary.as_deref(),
+            Some("Adds login with short-lived JWTs.")
+        );
+
+        // An unchanged spec keeps its summary without sampling again
+        let response = sampling::with_sampler(
+            sampler.clone(),
+            summarize_spec::run(input(&spec_name)),
+        )
+        .await
+        .unwrap()
+        .data;
+        assert!(!response.regenerated);
+        assert_eq!(sampler.calls.load(Ordering::SeqCst), 1);
+
+        // Rewriting spec.md suggests a new summary
+        let response = update_spec::run(update_spec::Input {
+            project_name: "summary-app".to_string(),
+            spec_name: spec_name.clone(),
+            commands_json: serde_json::json!([{
+                "target": "spec",
+                "command": "replace_section_content",
+                "selector": {"type": "section", "value": "## Overview"},
+                "content": "Passwordless login over email magic links.\nLinks expire after ten minutes.\nSessions last a week and refresh silently.\nAdmins can revoke sessions.\nEvery login is audited.\nFailed logins are rate limited."
+            }])
+            .to_string(),
+            expected_version: None,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+        assert!(
+            response
+                .next_steps
+                .iter()
+                .any(|step| step.contains("summarize_spec")),
+            "{:?}",
+            response.next_steps
+        );
+    });
+}
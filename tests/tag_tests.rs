@@ -0,0 +1,142 @@
+//! Integration tests for tagging projects and specs and filtering listings by tag
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::core::ops::{add_tag, list_projects, list_specs, remove_tag};
+use foundry_mcp::types::errors::ErrorCode;
+
+fn tags(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+async fn tagged_specs(project: &str, wanted: &[&str]) -> Vec<String> {
+    list_specs::run(list_specs::Input {
+        project_name: project.to_string(),
+        status: None,
+        blocked_by: None,
+        tags: tags(wanted),
+        cursor: None,
+        limit: None,
+    })
+    .await
+    .unwrap()
+    .data
+    .specs
+    .into_iter()
+    .map(|spec| spec.feature_name)
+    .collect()
+}
+
+#[test]
+fn test_tags_filter_spec_and_project_listings() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("tag-app").await.unwrap();
+        env.create_test_project("other-app").await.unwrap();
+        let auth = env
+            .create_bare_spec("tag-app", "auth", "# auth")
+            .await
+            .unwrap();
+        let billing = env
+            .create_bare_spec("tag-app", "billing", "# billing")
+            .await
+            .unwrap();
+
+        let tag = |spec_name: &str, values: &[&str]| {
+            add_tag::run(add_tag::Input {
+                project_name: "tag-app".to_string(),
+                spec_name: Some(spec_name.to_string()),
+                tags: tags(values),
+            })
+        };
+        let added = tag(&auth, &["area:backend", "quarter:Q3"]).await.unwrap();
+        assert_eq!(added.data.changed, tags(&["area:backend", "quarter:Q3"]));
+        tag(&billing, &["area:backend"]).await.unwrap();
+        // Tags compare case-insensitively, so nothing new is added
+        let again = tag(&billing, &["Area:Backend"]).await.unwrap();
+        assert!(again.data.changed.is_empty());
+
+        assert_eq!(tagged_specs("tag-app", &["area:backend"]).await.len(), 2);
+        assert_eq!(
+            tagged_specs("tag-app", &["area:backend", "QUARTER:q3"]).await,
+            ["auth"]
+        );
+
+        let removed = remove_tag::run(remove_tag::Input {
+            project_name: "tag-app".to_string(),
+            spec_name: Some(auth.clone()),
+            tags: tags(&["quarter:q3", "missing"]),
+        })
+        .await
+        .unwrap();
+        assert_eq!(removed.data.tags, tags(&["area:backend"]));
+        assert_eq!(removed.data.changed, tags(&["quarter:Q3"]));
+        assert!(tagged_specs("tag-app", &["quarter:Q3"]).await.is_empty());
+
+        add_tag::run(add_tag::Input {
+            project_name: "tag-app".to_string(),
+            spec_name: None,
+            tags: tags(&["team:payments"]),
+        })
+        .await
+        .unwrap();
+        let projects = list_projects::run(list_projects::Input {
+            tags: tags(&["team:payments"]),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        let names: Vec<&str> = projects
+            .data
+            .projects
+            .iter()
+            .map(|project| project.name.as_str())
+            .collect();
+        assert_eq!(names, ["tag-app"]);
+
+        let error = tag(&auth, &["two words"]).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::InvalidParams);
+        let error = tag("20240101_000000_missing", &["x"]).await.unwrap_err();
+        assert_eq!(ErrorCode::of(&error), ErrorCode::SpecNotFound);
+    });
+}
+
+#[test]
+fn test_concurrent_tagging_keeps_every_tag() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        env.create_test_project("busy-app").await.unwrap();
+        let spec_name = env
+            .create_bare_spec("busy-app", "auth", "# auth")
+            .await
+            .unwrap();
+
+        // Each call reads the tags, adds one and writes them back; the spec
+        // lock keeps them from overwriting each other
+        std::thread::scope(|scope| {
+            for index in 0..8 {
+                let spec_name = &spec_name;
+                scope.spawn(move || {
+                    tokio::runtime::Builder::new_current_thread()
+                        .enable_all()
+                        .build()
+                        .unwrap()
+                        .block_on(add_tag::run(add_tag::Input {
+                            project_name: "busy-app".to_string(),
+                            spec_name: Some(spec_name.clone()),
+                            tags: tags(&[&format!("tag-{}", index)]),
+                        }))
+                        .unwrap();
+                });
+            }
+        });
+
+        for index in 0..8 {
+            assert_eq!(
+                tagged_specs("busy-app", &[&format!("tag-{}", index)]).await,
+                vec!["auth".to_string()]
+            );
+        }
+    });
+}
@@ -8,10 +8,12 @@ use foundry_mcp::core::installation::install_for_cursor;
 mod common;
 use common::TestEnvironment;
 use std::fs;
+#[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 use tempfile::TempDir;
 
 /// Test template installation in read-only directories
+#[cfg(unix)]
 #[test]
 fn test_template_installation_readonly_directory() -> Result<()> {
     let env = TestEnvironment::new()?;
@@ -92,6 +94,25 @@ fn test_template_overwrite_on_reinstallation() -> Result<()> {
     Ok(())
 }
 
+/// Test installed templates are rendered with the built-in variables
+#[test]
+fn test_template_installation_renders_placeholders() -> Result<()> {
+    let env = TestEnvironment::new()?;
+
+    env.with_env_async(|| async {
+        env.install_and_parse("cursor").await?;
+
+        let content = fs::read_to_string(env.cursor_rules_path())?;
+        assert!(content.contains("`mcp_foundry_load_project`"));
+        assert!(!content.contains("{{"));
+        assert!(content.ends_with("helps future development."));
+
+        Ok::<(), anyhow::Error>(())
+    })?;
+
+    Ok(())
+}
+
 /// Test template installation with existing custom content
 #[test]
 fn test_template_installation_with_existing_custom_content() -> Result<()> {
@@ -0,0 +1,116 @@
+//! Integration tests for workspaces
+
+mod common;
+
+use common::TestEnvironment;
+use foundry_mcp::cli::args::{WorkspaceArgs, WorkspaceCommand};
+use foundry_mcp::cli::commands::workspace as workspace_command;
+use foundry_mcp::core::foundry::get_default_foundry;
+use foundry_mcp::core::workspace;
+use foundry_mcp::mcp::FoundryTools;
+use foundry_mcp::types::responses::WorkspaceListResponse;
+use serde_json::json;
+
+async fn project_names() -> Vec<String> {
+    get_default_foundry()
+        .unwrap()
+        .list_projects()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|project| project.name)
+        .collect()
+}
+
+#[test]
+fn test_workspace_param_routes_calls_to_its_directory() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let work_dir = env.join("work/.foundry");
+        workspace::add("work", &work_dir).unwrap();
+        env.create_test_project("home-app").await.unwrap();
+
+        FoundryTools::dispatch(
+            "create_project",
+            &json!({
+                "workspace": "work",
+                "project_name": "work-app",
+                "vision": "A work project vision. ".repeat(10),
+                "tech_stack": "Rust with tokio and serde. ".repeat(6),
+                "summary": "A work project summary. ".repeat(5),
+            }),
+        )
+        .await
+        .unwrap();
+
+        assert!(work_dir.join("work-app").join("vision.md").exists());
+        assert_eq!(project_names().await, vec!["home-app".to_string()]);
+        let listed = workspace::scoped("work", project_names()).await.unwrap();
+        assert_eq!(listed, vec!["work-app".to_string()]);
+
+        let error = FoundryTools::dispatch("list_projects", &json!({"workspace": "missing"}))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("Unknown workspace 'missing'"));
+    });
+}
+
+#[test]
+fn test_workspace_command_manages_registry() {
+    let env = TestEnvironment::new().unwrap();
+    env.with_env_async(|| async {
+        let run = |command| {
+            workspace_command::execute(WorkspaceArgs {
+                command,
+                json: true,
+            })
+        };
+        let output = run(WorkspaceCommand::Add {
+            name: "personal".to_string(),
+            path: env.join("personal/.foundry").display().to_string(),
+        })
+        .await
+        .unwrap();
+        let response: WorkspaceListResponse = serde_json::from_str(&output).unwrap();
+        let names: Vec<&str> = response
+            .workspaces
+            .iter()
+            .map(|w| w.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["default", "personal"]);
+        assert!(response.workspaces[0].current);
+        assert!(!response.workspaces[1].current);
+
+        assert!(
+            run(WorkspaceCommand::Add {
+                name: "default".to_string(),
+                path: env.join("other").display().to_string(),
+            })
+            .await
+            .is_err()
+        );
+        assert!(
+            run(WorkspaceCommand::Add {
+                name: "relative".to_string(),
+                path: "some/dir".to_string(),
+            })
+            .await
+            .is_err()
+        );
+
+        let output = run(WorkspaceCommand::Remove {
+            name: "personal".to_string(),
+        })
+        .await
+        .unwrap();
+        let response: WorkspaceListResponse = serde_json::from_str(&output).unwrap();
+        assert_eq!(response.workspaces.len(), 1);
+        assert!(
+            run(WorkspaceCommand::Remove {
+                name: "personal".to_string(),
+            })
+            .await
+            .is_err()
+        );
+    });
+}